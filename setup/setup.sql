@@ -12,6 +12,7 @@ DROP TABLE IF EXISTS double_angles;
 DROP TABLE IF EXISTS hollow_structural_sections;
 DROP TABLE IF EXISTS round_hollow_structural_sections;
 DROP TABLE IF EXISTS pipes;
+DROP TABLE IF EXISTS metric_designations;
 
 CREATE TABLE wide_flanges (
     shape_id SERIAL PRIMARY KEY,
@@ -550,6 +551,14 @@ CREATE TABLE pipes (
     j_upper DOUBLE PRECISION NOT NULL
 );
 
+CREATE TABLE metric_designations (
+    designation_id SERIAL PRIMARY KEY,
+    edi_std_nomenclature VARCHAR(255) NOT NULL,
+    aisc_manual_label VARCHAR(255) NOT NULL,
+    metric_edi_std_nomenclature VARCHAR(255) NOT NULL,
+    metric_aisc_manual_label VARCHAR(255) NOT NULL
+);
+
 INSERT INTO angles (
     edi_std_nomenclature,
     aisc_manual_label,
@@ -3384,4 +3393,2260 @@ VALUES
 ('Pipe4XXS','Pipe4XXS',27.6,7.66,4.5,3.15,0.674,0.628,7.17,14.7,9.5,6.53,1.39,14.7,9.5,6.53,1.39,29.4), 
 ('Pipe3XXS','Pipe3XXS',18.6,5.17,3.5,2.3,0.6,0.559,6.26,5.79,4.89,3.31,1.06,5.79,4.89,3.31,1.06,11.6), 
 ('Pipe2-1/2XXS','Pipe2-1/2XXS',13.7,3.83,2.875,1.77,0.552,0.514,5.59,2.78,2.91,1.94,0.854,2.78,2.91,1.94,0.854,5.56), 
-('Pipe2XXS','Pipe2XXS',9.04,2.51,2.375,1.5,0.436,0.406,5.85,1.27,1.6,1.07,0.711,1.27,1.6,1.07,0.711,2.54);
\ No newline at end of file
+('Pipe2XXS','Pipe2XXS',9.04,2.51,2.375,1.5,0.436,0.406,5.85,1.27,1.6,1.07,0.711,1.27,1.6,1.07,0.711,2.54);
+
+INSERT INTO metric_designations (
+    edi_std_nomenclature,
+    aisc_manual_label,
+    metric_edi_std_nomenclature,
+    metric_aisc_manual_label
+    ) 
+VALUES 
+('W44X408','W44X408','W1100X607','W1100X607'), 
+('W44X368','W44X368','W1100X548','W1100X548'), 
+('W44X335','W44X335','W1100X499','W1100X499'), 
+('W44X290','W44X290','W1100X432','W1100X432'), 
+('W44X262','W44X262','W1100X390','W1100X390'), 
+('W44X230','W44X230','W1100X342','W1100X342'), 
+('W40X655','W40X655','W1000X975','W1000X975'), 
+('W40X593','W40X593','W1000X882','W1000X882'), 
+('W40X503','W40X503','W1000X749','W1000X749'), 
+('W40X431','W40X431','W1000X641','W1000X641'), 
+('W40X397','W40X397','W1000X591','W1000X591'), 
+('W40X372','W40X372','W1000X554','W1000X554'), 
+('W40X362','W40X362','W1000X539','W1000X539'), 
+('W40X324','W40X324','W1000X482','W1000X482'), 
+('W40X297','W40X297','W1000X442','W1000X442'), 
+('W40X277','W40X277','W1000X412','W1000X412'), 
+('W40X249','W40X249','W1000X371','W1000X371'), 
+('W40X215','W40X215','W1000X320','W1000X320'), 
+('W40X199','W40X199','W1000X296','W1000X296'), 
+('W40X392','W40X392','W1000X583','W1000X583'), 
+('W40X331','W40X331','W1000X493','W1000X493'), 
+('W40X327','W40X327','W1000X487','W1000X487'), 
+('W40X294','W40X294','W1000X438','W1000X438'), 
+('W40X278','W40X278','W1000X414','W1000X414'), 
+('W40X264','W40X264','W1000X393','W1000X393'), 
+('W40X235','W40X235','W1000X350','W1000X350'), 
+('W40X211','W40X211','W1000X314','W1000X314'), 
+('W40X183','W40X183','W1000X272','W1000X272'), 
+('W40X167','W40X167','W1000X249','W1000X249'), 
+('W40X149','W40X149','W1000X222','W1000X222'), 
+('W36X925','W36X925','W920X1377','W920X1377'), 
+('W36X853','W36X853','W920X1269','W920X1269'), 
+('W36X802','W36X802','W920X1194','W920X1194'), 
+('W36X723','W36X723','W920X1076','W920X1076'), 
+('W36X652','W36X652','W920X970','W920X970'), 
+('W36X529','W36X529','W920X787','W920X787'), 
+('W36X487','W36X487','W920X725','W920X725'), 
+('W36X441','W36X441','W920X656','W920X656'), 
+('W36X395','W36X395','W920X588','W920X588'), 
+('W36X361','W36X361','W920X537','W920X537'), 
+('W36X330','W36X330','W920X491','W920X491'), 
+('W36X302','W36X302','W920X449','W920X449'), 
+('W36X282','W36X282','W920X420','W920X420'), 
+('W36X262','W36X262','W920X390','W920X390'), 
+('W36X247','W36X247','W920X368','W920X368'), 
+('W36X231','W36X231','W920X344','W920X344'), 
+('W36X387','W36X387','W920X576','W920X576'), 
+('W36X350','W36X350','W920X521','W920X521'), 
+('W36X318','W36X318','W920X473','W920X473'), 
+('W36X286','W36X286','W920X426','W920X426'), 
+('W36X256','W36X256','W920X381','W920X381'), 
+('W36X232','W36X232','W920X345','W920X345'), 
+('W36X210','W36X210','W920X313','W920X313'), 
+('W36X194','W36X194','W920X289','W920X289'), 
+('W36X182','W36X182','W920X271','W920X271'), 
+('W36X170','W36X170','W920X253','W920X253'), 
+('W36X160','W36X160','W920X238','W920X238'), 
+('W36X150','W36X150','W920X223','W920X223'), 
+('W36X135','W36X135','W920X201','W920X201'), 
+('W33X387','W33X387','W840X576','W840X576'), 
+('W33X354','W33X354','W840X527','W840X527'), 
+('W33X318','W33X318','W840X473','W840X473'), 
+('W33X291','W33X291','W840X433','W840X433'), 
+('W33X263','W33X263','W840X391','W840X391'), 
+('W33X241','W33X241','W840X359','W840X359'), 
+('W33X221','W33X221','W840X329','W840X329'), 
+('W33X201','W33X201','W840X299','W840X299'), 
+('W33X169','W33X169','W840X251','W840X251'), 
+('W33X152','W33X152','W840X226','W840X226'), 
+('W33X141','W33X141','W840X210','W840X210'), 
+('W33X130','W33X130','W840X193','W840X193'), 
+('W33X118','W33X118','W840X176','W840X176'), 
+('W30X391','W30X391','W760X582','W760X582'), 
+('W30X357','W30X357','W760X531','W760X531'), 
+('W30X326','W30X326','W760X485','W760X485'), 
+('W30X292','W30X292','W760X435','W760X435'), 
+('W30X261','W30X261','W760X388','W760X388'), 
+('W30X235','W30X235','W760X350','W760X350'), 
+('W30X211','W30X211','W760X314','W760X314'), 
+('W30X191','W30X191','W760X284','W760X284'), 
+('W30X173','W30X173','W760X257','W760X257'), 
+('W30X148','W30X148','W760X220','W760X220'), 
+('W30X132','W30X132','W760X196','W760X196'), 
+('W30X124','W30X124','W760X185','W760X185'), 
+('W30X116','W30X116','W760X173','W760X173'), 
+('W30X108','W30X108','W760X161','W760X161'), 
+('W30X99','W30X99','W760X147','W760X147'), 
+('W30X90','W30X90','W760X134','W760X134'), 
+('W27X539','W27X539','W690X802','W690X802'), 
+('W27X368','W27X368','W690X548','W690X548'), 
+('W27X336','W27X336','W690X500','W690X500'), 
+('W27X307','W27X307','W690X457','W690X457'), 
+('W27X281','W27X281','W690X418','W690X418'), 
+('W27X258','W27X258','W690X384','W690X384'), 
+('W27X235','W27X235','W690X350','W690X350'), 
+('W27X217','W27X217','W690X323','W690X323'), 
+('W27X194','W27X194','W690X289','W690X289'), 
+('W27X178','W27X178','W690X265','W690X265'), 
+('W27X161','W27X161','W690X240','W690X240'), 
+('W27X146','W27X146','W690X217','W690X217'), 
+('W27X129','W27X129','W690X192','W690X192'), 
+('W27X114','W27X114','W690X170','W690X170'), 
+('W27X102','W27X102','W690X152','W690X152'), 
+('W27X94','W27X94','W690X140','W690X140'), 
+('W27X84','W27X84','W690X125','W690X125'), 
+('W24X370','W24X370','W610X551','W610X551'), 
+('W24X335','W24X335','W610X499','W610X499'), 
+('W24X306','W24X306','W610X455','W610X455'), 
+('W24X279','W24X279','W610X415','W610X415'), 
+('W24X250','W24X250','W610X372','W610X372'), 
+('W24X229','W24X229','W610X341','W610X341'), 
+('W24X207','W24X207','W610X308','W610X308'), 
+('W24X192','W24X192','W610X286','W610X286'), 
+('W24X176','W24X176','W610X262','W610X262'), 
+('W24X162','W24X162','W610X241','W610X241'), 
+('W24X146','W24X146','W610X217','W610X217'), 
+('W24X131','W24X131','W610X195','W610X195'), 
+('W24X117','W24X117','W610X174','W610X174'), 
+('W24X104','W24X104','W610X155','W610X155'), 
+('W24X103','W24X103','W610X153','W610X153'), 
+('W24X94','W24X94','W610X140','W610X140'), 
+('W24X84','W24X84','W610X125','W610X125'), 
+('W24X76','W24X76','W610X113','W610X113'), 
+('W24X68','W24X68','W610X101','W610X101'), 
+('W24X62','W24X62','W610X92.3','W610X92.3'), 
+('W24X55','W24X55','W610X81.8','W610X81.8'), 
+('W21X275','W21X275','W530X409','W530X409'), 
+('W21X248','W21X248','W530X369','W530X369'), 
+('W21X223','W21X223','W530X332','W530X332'), 
+('W21X201','W21X201','W530X299','W530X299'), 
+('W21X182','W21X182','W530X271','W530X271'), 
+('W21X166','W21X166','W530X247','W530X247'), 
+('W21X147','W21X147','W530X219','W530X219'), 
+('W21X132','W21X132','W530X196','W530X196'), 
+('W21X122','W21X122','W530X182','W530X182'), 
+('W21X111','W21X111','W530X165','W530X165'), 
+('W21X101','W21X101','W530X150','W530X150'), 
+('W21X93','W21X93','W530X138','W530X138'), 
+('W21X83','W21X83','W530X124','W530X124'), 
+('W21X73','W21X73','W530X109','W530X109'), 
+('W21X68','W21X68','W530X101','W530X101'), 
+('W21X62','W21X62','W530X92.3','W530X92.3'), 
+('W21X55','W21X55','W530X81.8','W530X81.8'), 
+('W21X48','W21X48','W530X71.4','W530X71.4'), 
+('W21X57','W21X57','W530X84.8','W530X84.8'), 
+('W21X50','W21X50','W530X74.4','W530X74.4'), 
+('W21X44','W21X44','W530X65.5','W530X65.5'), 
+('W18X311','W18X311','W460X463','W460X463'), 
+('W18X283','W18X283','W460X421','W460X421'), 
+('W18X258','W18X258','W460X384','W460X384'), 
+('W18X234','W18X234','W460X348','W460X348'), 
+('W18X211','W18X211','W460X314','W460X314'), 
+('W18X192','W18X192','W460X286','W460X286'), 
+('W18X175','W18X175','W460X260','W460X260'), 
+('W18X158','W18X158','W460X235','W460X235'), 
+('W18X143','W18X143','W460X213','W460X213'), 
+('W18X130','W18X130','W460X193','W460X193'), 
+('W18X119','W18X119','W460X177','W460X177'), 
+('W18X106','W18X106','W460X158','W460X158'), 
+('W18X97','W18X97','W460X144','W460X144'), 
+('W18X86','W18X86','W460X128','W460X128'), 
+('W18X76','W18X76','W460X113','W460X113'), 
+('W18X71','W18X71','W460X106','W460X106'), 
+('W18X65','W18X65','W460X96.7','W460X96.7'), 
+('W18X60','W18X60','W460X89.3','W460X89.3'), 
+('W18X55','W18X55','W460X81.8','W460X81.8'), 
+('W18X50','W18X50','W460X74.4','W460X74.4'), 
+('W18X46','W18X46','W460X68.5','W460X68.5'), 
+('W18X40','W18X40','W460X59.5','W460X59.5'), 
+('W18X35','W18X35','W460X52.1','W460X52.1'), 
+('W16X100','W16X100','W410X149','W410X149'), 
+('W16X89','W16X89','W410X132','W410X132'), 
+('W16X77','W16X77','W410X115','W410X115'), 
+('W16X67','W16X67','W410X99.7','W410X99.7'), 
+('W16X57','W16X57','W410X84.8','W410X84.8'), 
+('W16X50','W16X50','W410X74.4','W410X74.4'), 
+('W16X45','W16X45','W410X67','W410X67'), 
+('W16X40','W16X40','W410X59.5','W410X59.5'), 
+('W16X36','W16X36','W410X53.6','W410X53.6'), 
+('W16X31','W16X31','W410X46.1','W410X46.1'), 
+('W16X26','W16X26','W410X38.7','W410X38.7'), 
+('W14X873','W14X873','W360X1299','W360X1299'), 
+('W14X808','W14X808','W360X1202','W360X1202'), 
+('W14X730','W14X730','W360X1086','W360X1086'), 
+('W14X665','W14X665','W360X990','W360X990'), 
+('W14X605','W14X605','W360X900','W360X900'), 
+('W14X550','W14X550','W360X818','W360X818'), 
+('W14X500','W14X500','W360X744','W360X744'), 
+('W14X455','W14X455','W360X677','W360X677'), 
+('W14X426','W14X426','W360X634','W360X634'), 
+('W14X398','W14X398','W360X592','W360X592'), 
+('W14X370','W14X370','W360X551','W360X551'), 
+('W14X342','W14X342','W360X509','W360X509'), 
+('W14X311','W14X311','W360X463','W360X463'), 
+('W14X283','W14X283','W360X421','W360X421'), 
+('W14X257','W14X257','W360X382','W360X382'), 
+('W14X233','W14X233','W360X347','W360X347'), 
+('W14X211','W14X211','W360X314','W360X314'), 
+('W14X193','W14X193','W360X287','W360X287'), 
+('W14X176','W14X176','W360X262','W360X262'), 
+('W14X159','W14X159','W360X237','W360X237'), 
+('W14X145','W14X145','W360X216','W360X216'), 
+('W14X132','W14X132','W360X196','W360X196'), 
+('W14X120','W14X120','W360X179','W360X179'), 
+('W14X109','W14X109','W360X162','W360X162'), 
+('W14X99','W14X99','W360X147','W360X147'), 
+('W14X90','W14X90','W360X134','W360X134'), 
+('W14X82','W14X82','W360X122','W360X122'), 
+('W14X74','W14X74','W360X110','W360X110'), 
+('W14X68','W14X68','W360X101','W360X101'), 
+('W14X61','W14X61','W360X90.8','W360X90.8'), 
+('W14X53','W14X53','W360X78.9','W360X78.9'), 
+('W14X48','W14X48','W360X71.4','W360X71.4'), 
+('W14X43','W14X43','W360X64','W360X64'), 
+('W14X38','W14X38','W360X56.6','W360X56.6'), 
+('W14X34','W14X34','W360X50.6','W360X50.6'), 
+('W14X30','W14X30','W360X44.6','W360X44.6'), 
+('W14X26','W14X26','W360X38.7','W360X38.7'), 
+('W14X22','W14X22','W360X32.7','W360X32.7'), 
+('W12X336','W12X336','W310X500','W310X500'), 
+('W12X305','W12X305','W310X454','W310X454'), 
+('W12X279','W12X279','W310X415','W310X415'), 
+('W12X252','W12X252','W310X375','W310X375'), 
+('W12X230','W12X230','W310X342','W310X342'), 
+('W12X210','W12X210','W310X313','W310X313'), 
+('W12X190','W12X190','W310X283','W310X283'), 
+('W12X170','W12X170','W310X253','W310X253'), 
+('W12X152','W12X152','W310X226','W310X226'), 
+('W12X136','W12X136','W310X202','W310X202'), 
+('W12X120','W12X120','W310X179','W310X179'), 
+('W12X106','W12X106','W310X158','W310X158'), 
+('W12X96','W12X96','W310X143','W310X143'), 
+('W12X87','W12X87','W310X129','W310X129'), 
+('W12X79','W12X79','W310X118','W310X118'), 
+('W12X72','W12X72','W310X107','W310X107'), 
+('W12X65','W12X65','W310X96.7','W310X96.7'), 
+('W12X58','W12X58','W310X86.3','W310X86.3'), 
+('W12X53','W12X53','W310X78.9','W310X78.9'), 
+('W12X50','W12X50','W310X74.4','W310X74.4'), 
+('W12X45','W12X45','W310X67','W310X67'), 
+('W12X40','W12X40','W310X59.5','W310X59.5'), 
+('W12X35','W12X35','W310X52.1','W310X52.1'), 
+('W12X30','W12X30','W310X44.6','W310X44.6'), 
+('W12X26','W12X26','W310X38.7','W310X38.7'), 
+('W12X22','W12X22','W310X32.7','W310X32.7'), 
+('W12X19','W12X19','W310X28.3','W310X28.3'), 
+('W12X16','W12X16','W310X23.8','W310X23.8'), 
+('W12X14','W12X14','W310X20.8','W310X20.8'), 
+('W10X112','W10X112','W250X167','W250X167'), 
+('W10X100','W10X100','W250X149','W250X149'), 
+('W10X88','W10X88','W250X131','W250X131'), 
+('W10X77','W10X77','W250X115','W250X115'), 
+('W10X68','W10X68','W250X101','W250X101'), 
+('W10X60','W10X60','W250X89.3','W250X89.3'), 
+('W10X54','W10X54','W250X80.4','W250X80.4'), 
+('W10X49','W10X49','W250X72.9','W250X72.9'), 
+('W10X45','W10X45','W250X67','W250X67'), 
+('W10X39','W10X39','W250X58','W250X58'), 
+('W10X33','W10X33','W250X49.1','W250X49.1'), 
+('W10X30','W10X30','W250X44.6','W250X44.6'), 
+('W10X26','W10X26','W250X38.7','W250X38.7'), 
+('W10X22','W10X22','W250X32.7','W250X32.7'), 
+('W10X19','W10X19','W250X28.3','W250X28.3'), 
+('W10X17','W10X17','W250X25.3','W250X25.3'), 
+('W10X15','W10X15','W250X22.3','W250X22.3'), 
+('W10X12','W10X12','W250X17.9','W250X17.9'), 
+('W8X67','W8X67','W200X99.7','W200X99.7'), 
+('W8X58','W8X58','W200X86.3','W200X86.3'), 
+('W8X48','W8X48','W200X71.4','W200X71.4'), 
+('W8X40','W8X40','W200X59.5','W200X59.5'), 
+('W8X35','W8X35','W200X52.1','W200X52.1'), 
+('W8X31','W8X31','W200X46.1','W200X46.1'), 
+('W8X28','W8X28','W200X41.7','W200X41.7'), 
+('W8X24','W8X24','W200X35.7','W200X35.7'), 
+('W8X21','W8X21','W200X31.3','W200X31.3'), 
+('W8X18','W8X18','W200X26.8','W200X26.8'), 
+('W8X15','W8X15','W200X22.3','W200X22.3'), 
+('W8X13','W8X13','W200X19.3','W200X19.3'), 
+('W8X10','W8X10','W200X14.9','W200X14.9'), 
+('W6X25','W6X25','W150X37.2','W150X37.2'), 
+('W6X20','W6X20','W150X29.8','W150X29.8'), 
+('W6X15','W6X15','W150X22.3','W150X22.3'), 
+('W6X16','W6X16','W150X23.8','W150X23.8'), 
+('W6X12','W6X12','W150X17.9','W150X17.9'), 
+('W6X9','W6X9','W150X13.4','W150X13.4'), 
+('W6X8.5','W6X8.5','W150X12.6','W150X12.6'), 
+('W5X19','W5X19','W130X28.3','W130X28.3'), 
+('W5X16','W5X16','W130X23.8','W130X23.8'), 
+('W4X13','W4X13','W100X19.3','W100X19.3'), 
+('M12.5X12.4','M12.5X12.4','M320X18.5','M320X18.5'), 
+('M12.5X11.6','M12.5X11.6','M320X17.3','M320X17.3'), 
+('M12X11.8','M12X11.8','M310X17.6','M310X17.6'), 
+('M12X10.8','M12X10.8','M310X16.1','M310X16.1'), 
+('M12X10','M12X10','M310X14.9','M310X14.9'), 
+('M10X9','M10X9','M250X13.4','M250X13.4'), 
+('M10X8','M10X8','M250X11.9','M250X11.9'), 
+('M10X7.5','M10X7.5','M250X11.2','M250X11.2'), 
+('M8X6.5','M8X6.5','M200X9.67','M200X9.67'), 
+('M8X6.2','M8X6.2','M200X9.23','M200X9.23'), 
+('M6X4.4','M6X4.4','M150X6.55','M150X6.55'), 
+('M6X3.7','M6X3.7','M150X5.51','M150X5.51'), 
+('M5X18.9','M5X18.9','M130X28.1','M130X28.1'), 
+('M4X6','M4X6','M100X8.93','M100X8.93'), 
+('M4X4.08','M4X4.08','M100X6.07','M100X6.07'), 
+('M3X2.9','M3X2.9','M75X4.32','M75X4.32'), 
+('S24X121','S24X121','S610X180','S610X180'), 
+('S24X106','S24X106','S610X158','S610X158'), 
+('S24X100','S24X100','S610X149','S610X149'), 
+('S24X90','S24X90','S610X134','S610X134'), 
+('S24X80','S24X80','S610X119','S610X119'), 
+('S20X96','S20X96','S510X143','S510X143'), 
+('S20X86','S20X86','S510X128','S510X128'), 
+('S20X75','S20X75','S510X112','S510X112'), 
+('S20X66','S20X66','S510X98.2','S510X98.2'), 
+('S18X70','S18X70','S460X104','S460X104'), 
+('S18X54.7','S18X54.7','S460X81.4','S460X81.4'), 
+('S15X50','S15X50','S380X74.4','S380X74.4'), 
+('S15X42.9','S15X42.9','S380X63.8','S380X63.8'), 
+('S12X50','S12X50','S310X74.4','S310X74.4'), 
+('S12X40.8','S12X40.8','S310X60.7','S310X60.7'), 
+('S12X35','S12X35','S310X52.1','S310X52.1'), 
+('S12X31.8','S12X31.8','S310X47.3','S310X47.3'), 
+('S10X35','S10X35','S250X52.1','S250X52.1'), 
+('S10X25.4','S10X25.4','S250X37.8','S250X37.8'), 
+('S8X23','S8X23','S200X34.2','S200X34.2'), 
+('S8X18.4','S8X18.4','S200X27.4','S200X27.4'), 
+('S6X17.25','S6X17.25','S150X25.7','S150X25.7'), 
+('S6X12.5','S6X12.5','S150X18.6','S150X18.6'), 
+('S5X10','S5X10','S130X14.9','S130X14.9'), 
+('S4X9.5','S4X9.5','S100X14.1','S100X14.1'), 
+('S4X7.7','S4X7.7','S100X11.5','S100X11.5'), 
+('S3X7.5','S3X7.5','S75X11.2','S75X11.2'), 
+('S3X5.7','S3X5.7','S75X8.48','S75X8.48'), 
+('HP18X204','HP18X204','HP460X304','HP460X304'), 
+('HP18X181','HP18X181','HP460X269','HP460X269'), 
+('HP18X157','HP18X157','HP460X234','HP460X234'), 
+('HP18X135','HP18X135','HP460X201','HP460X201'), 
+('HP16X183','HP16X183','HP410X272','HP410X272'), 
+('HP16X162','HP16X162','HP410X241','HP410X241'), 
+('HP16X141','HP16X141','HP410X210','HP410X210'), 
+('HP16X121','HP16X121','HP410X180','HP410X180'), 
+('HP16X101','HP16X101','HP410X150','HP410X150'), 
+('HP16X88','HP16X88','HP410X131','HP410X131'), 
+('HP14X117','HP14X117','HP360X174','HP360X174'), 
+('HP14X102','HP14X102','HP360X152','HP360X152'), 
+('HP14X89','HP14X89','HP360X132','HP360X132'), 
+('HP14X73','HP14X73','HP360X109','HP360X109'), 
+('HP12X89','HP12X89','HP310X132','HP310X132'), 
+('HP12X84','HP12X84','HP310X125','HP310X125'), 
+('HP12X74','HP12X74','HP310X110','HP310X110'), 
+('HP12X63','HP12X63','HP310X93.8','HP310X93.8'), 
+('HP12X53','HP12X53','HP310X78.9','HP310X78.9'), 
+('HP10X57','HP10X57','HP250X84.8','HP250X84.8'), 
+('HP10X42','HP10X42','HP250X62.5','HP250X62.5'), 
+('HP8X36','HP8X36','HP200X53.6','HP200X53.6'), 
+('C15X50','C15X50','C380X74.4','C380X74.4'), 
+('C15X40','C15X40','C380X59.5','C380X59.5'), 
+('C15X33.9','C15X33.9','C380X50.4','C380X50.4'), 
+('C12X30','C12X30','C310X44.6','C310X44.6'), 
+('C12X25','C12X25','C310X37.2','C310X37.2'), 
+('C12X20.7','C12X20.7','C310X30.8','C310X30.8'), 
+('C10X30','C10X30','C250X44.6','C250X44.6'), 
+('C10X25','C10X25','C250X37.2','C250X37.2'), 
+('C10X20','C10X20','C250X29.8','C250X29.8'), 
+('C10X15.3','C10X15.3','C250X22.8','C250X22.8'), 
+('C9X20','C9X20','C230X29.8','C230X29.8'), 
+('C9X15','C9X15','C230X22.3','C230X22.3'), 
+('C9X13.4','C9X13.4','C230X19.9','C230X19.9'), 
+('C8X18.75','C8X18.75','C200X27.9','C200X27.9'), 
+('C8X13.75','C8X13.75','C200X20.5','C200X20.5'), 
+('C8X11.5','C8X11.5','C200X17.1','C200X17.1'), 
+('C7X14.75','C7X14.75','C180X22','C180X22'), 
+('C7X12.25','C7X12.25','C180X18.2','C180X18.2'), 
+('C7X9.8','C7X9.8','C180X14.6','C180X14.6'), 
+('C6X13','C6X13','C150X19.3','C150X19.3'), 
+('C6X10.5','C6X10.5','C150X15.6','C150X15.6'), 
+('C6X8.2','C6X8.2','C150X12.2','C150X12.2'), 
+('C5X9','C5X9','C130X13.4','C130X13.4'), 
+('C5X6.7','C5X6.7','C130X9.97','C130X9.97'), 
+('C4X7.25','C4X7.25','C100X10.8','C100X10.8'), 
+('C4X6.25','C4X6.25','C100X9.3','C100X9.3'), 
+('C4X5.4','C4X5.4','C100X8.04','C100X8.04'), 
+('C4X4.5','C4X4.5','C100X6.7','C100X6.7'), 
+('C3X6','C3X6','C75X8.93','C75X8.93'), 
+('C3X5','C3X5','C75X7.44','C75X7.44'), 
+('C3X4.1','C3X4.1','C75X6.1','C75X6.1'), 
+('C3X3.5','C3X3.5','C75X5.21','C75X5.21'), 
+('MC18X58','MC18X58','MC460X86.3','MC460X86.3'), 
+('MC18X51.9','MC18X51.9','MC460X77.2','MC460X77.2'), 
+('MC18X45.8','MC18X45.8','MC460X68.2','MC460X68.2'), 
+('MC18X42.7','MC18X42.7','MC460X63.5','MC460X63.5'), 
+('MC13X50','MC13X50','MC330X74.4','MC330X74.4'), 
+('MC13X40','MC13X40','MC330X59.5','MC330X59.5'), 
+('MC13X35','MC13X35','MC330X52.1','MC330X52.1'), 
+('MC13X31.8','MC13X31.8','MC330X47.3','MC330X47.3'), 
+('MC12X50','MC12X50','MC310X74.4','MC310X74.4'), 
+('MC12X45','MC12X45','MC310X67','MC310X67'), 
+('MC12X40','MC12X40','MC310X59.5','MC310X59.5'), 
+('MC12X35','MC12X35','MC310X52.1','MC310X52.1'), 
+('MC12X31','MC12X31','MC310X46.1','MC310X46.1'), 
+('MC12X14.3','MC12X14.3','MC310X21.3','MC310X21.3'), 
+('MC12X10.6','MC12X10.6','MC310X15.8','MC310X15.8'), 
+('MC10X41.1','MC10X41.1','MC250X61.2','MC250X61.2'), 
+('MC10X33.6','MC10X33.6','MC250X50','MC250X50'), 
+('MC10X28.5','MC10X28.5','MC250X42.4','MC250X42.4'), 
+('MC10X25','MC10X25','MC250X37.2','MC250X37.2'), 
+('MC10X22','MC10X22','MC250X32.7','MC250X32.7'), 
+('MC10X8.4','MC10X8.4','MC250X12.5','MC250X12.5'), 
+('MC10X6.5','MC10X6.5','MC250X9.67','MC250X9.67'), 
+('MC9X25.4','MC9X25.4','MC230X37.8','MC230X37.8'), 
+('MC9X23.9','MC9X23.9','MC230X35.6','MC230X35.6'), 
+('MC8X22.8','MC8X22.8','MC200X33.9','MC200X33.9'), 
+('MC8X21.4','MC8X21.4','MC200X31.8','MC200X31.8'), 
+('MC8X20','MC8X20','MC200X29.8','MC200X29.8'), 
+('MC8X18.7','MC8X18.7','MC200X27.8','MC200X27.8'), 
+('MC8X8.5','MC8X8.5','MC200X12.6','MC200X12.6'), 
+('MC7X22.7','MC7X22.7','MC180X33.8','MC180X33.8'), 
+('MC7X19.1','MC7X19.1','MC180X28.4','MC180X28.4'), 
+('MC6X18','MC6X18','MC150X26.8','MC150X26.8'), 
+('MC6X15.3','MC6X15.3','MC150X22.8','MC150X22.8'), 
+('MC6X16.3','MC6X16.3','MC150X24.3','MC150X24.3'), 
+('MC6X15.1','MC6X15.1','MC150X22.5','MC150X22.5'), 
+('MC6X12','MC6X12','MC150X17.9','MC150X17.9'), 
+('MC6X7','MC6X7','MC150X10.4','MC150X10.4'), 
+('MC6X6.5','MC6X6.5','MC150X9.67','MC150X9.67'), 
+('MC4X13.8','MC4X13.8','MC100X20.5','MC100X20.5'), 
+('MC3X7.1','MC3X7.1','MC75X10.6','MC75X10.6'), 
+('L12X12X1-3/8','L12X12X1-3/8','L305X305X34.9','L305X305X34.9'), 
+('L12X12X1-1/4','L12X12X1-1/4','L305X305X31.8','L305X305X31.8'), 
+('L12X12X1-1/8','L12X12X1-1/8','L305X305X28.6','L305X305X28.6'), 
+('L12X12X1','L12X12X1','L305X305X25.4','L305X305X25.4'), 
+('L10X10X1-3/8','L10X10X1-3/8','L254X254X34.9','L254X254X34.9'), 
+('L10X10X1-1/4','L10X10X1-1/4','L254X254X31.8','L254X254X31.8'), 
+('L10X10X1-1/8','L10X10X1-1/8','L254X254X28.6','L254X254X28.6'), 
+('L10X10X1','L10X10X1','L254X254X25.4','L254X254X25.4'), 
+('L10X10X7/8','L10X10X7/8','L254X254X22.2','L254X254X22.2'), 
+('L10X10X3/4','L10X10X3/4','L254X254X19','L254X254X19'), 
+('L8X8X1-1/8','L8X8X1-1/8','L203X203X28.6','L203X203X28.6'), 
+('L8X8X1','L8X8X1','L203X203X25.4','L203X203X25.4'), 
+('L8X8X7/8','L8X8X7/8','L203X203X22.2','L203X203X22.2'), 
+('L8X8X3/4','L8X8X3/4','L203X203X19','L203X203X19'), 
+('L8X8X5/8','L8X8X5/8','L203X203X15.9','L203X203X15.9'), 
+('L8X8X9/16','L8X8X9/16','L203X203X14.3','L203X203X14.3'), 
+('L8X8X1/2','L8X8X1/2','L203X203X12.7','L203X203X12.7'), 
+('L8X6X1','L8X6X1','L203X152X25.4','L203X152X25.4'), 
+('L8X6X7/8','L8X6X7/8','L203X152X22.2','L203X152X22.2'), 
+('L8X6X3/4','L8X6X3/4','L203X152X19','L203X152X19'), 
+('L8X6X5/8','L8X6X5/8','L203X152X15.9','L203X152X15.9'), 
+('L8X6X9/16','L8X6X9/16','L203X152X14.3','L203X152X14.3'), 
+('L8X6X1/2','L8X6X1/2','L203X152X12.7','L203X152X12.7'), 
+('L8X6X7/16','L8X6X7/16','L203X152X11.1','L203X152X11.1'), 
+('L8X4X1','L8X4X1','L203X102X25.4','L203X102X25.4'), 
+('L8X4X7/8','L8X4X7/8','L203X102X22.2','L203X102X22.2'), 
+('L8X4X3/4','L8X4X3/4','L203X102X19','L203X102X19'), 
+('L8X4X5/8','L8X4X5/8','L203X102X15.9','L203X102X15.9'), 
+('L8X4X9/16','L8X4X9/16','L203X102X14.3','L203X102X14.3'), 
+('L8X4X1/2','L8X4X1/2','L203X102X12.7','L203X102X12.7'), 
+('L8X4X7/16','L8X4X7/16','L203X102X11.1','L203X102X11.1'), 
+('L7X4X3/4','L7X4X3/4','L178X102X19','L178X102X19'), 
+('L7X4X5/8','L7X4X5/8','L178X102X15.9','L178X102X15.9'), 
+('L7X4X1/2','L7X4X1/2','L178X102X12.7','L178X102X12.7'), 
+('L7X4X7/16','L7X4X7/16','L178X102X11.1','L178X102X11.1'), 
+('L7X4X3/8','L7X4X3/8','L178X102X9.5','L178X102X9.5'), 
+('L6X6X1','L6X6X1','L152X152X25.4','L152X152X25.4'), 
+('L6X6X7/8','L6X6X7/8','L152X152X22.2','L152X152X22.2'), 
+('L6X6X3/4','L6X6X3/4','L152X152X19','L152X152X19'), 
+('L6X6X5/8','L6X6X5/8','L152X152X15.9','L152X152X15.9'), 
+('L6X6X9/16','L6X6X9/16','L152X152X14.3','L152X152X14.3'), 
+('L6X6X1/2','L6X6X1/2','L152X152X12.7','L152X152X12.7'), 
+('L6X6X7/16','L6X6X7/16','L152X152X11.1','L152X152X11.1'), 
+('L6X6X3/8','L6X6X3/8','L152X152X9.5','L152X152X9.5'), 
+('L6X6X5/16','L6X6X5/16','L152X152X7.9','L152X152X7.9'), 
+('L6X4X7/8','L6X4X7/8','L152X102X22.2','L152X102X22.2'), 
+('L6X4X3/4','L6X4X3/4','L152X102X19','L152X102X19'), 
+('L6X4X5/8','L6X4X5/8','L152X102X15.9','L152X102X15.9'), 
+('L6X4X9/16','L6X4X9/16','L152X102X14.3','L152X102X14.3'), 
+('L6X4X1/2','L6X4X1/2','L152X102X12.7','L152X102X12.7'), 
+('L6X4X7/16','L6X4X7/16','L152X102X11.1','L152X102X11.1'), 
+('L6X4X3/8','L6X4X3/8','L152X102X9.5','L152X102X9.5'), 
+('L6X4X5/16','L6X4X5/16','L152X102X7.9','L152X102X7.9'), 
+('L6X3-1/2X1/2','L6X3-1/2X1/2','L152X89X12.7','L152X89X12.7'), 
+('L6X3-1/2X3/8','L6X3-1/2X3/8','L152X89X9.5','L152X89X9.5'), 
+('L6X3-1/2X5/16','L6X3-1/2X5/16','L152X89X7.9','L152X89X7.9'), 
+('L5X5X7/8','L5X5X7/8','L127X127X22.2','L127X127X22.2'), 
+('L5X5X3/4','L5X5X3/4','L127X127X19','L127X127X19'), 
+('L5X5X5/8','L5X5X5/8','L127X127X15.9','L127X127X15.9'), 
+('L5X5X1/2','L5X5X1/2','L127X127X12.7','L127X127X12.7'), 
+('L5X5X7/16','L5X5X7/16','L127X127X11.1','L127X127X11.1'), 
+('L5X5X3/8','L5X5X3/8','L127X127X9.5','L127X127X9.5'), 
+('L5X5X5/16','L5X5X5/16','L127X127X7.9','L127X127X7.9'), 
+('L5X3-1/2X3/4','L5X3-1/2X3/4','L127X89X19','L127X89X19'), 
+('L5X3-1/2X5/8','L5X3-1/2X5/8','L127X89X15.9','L127X89X15.9'), 
+('L5X3-1/2X1/2','L5X3-1/2X1/2','L127X89X12.7','L127X89X12.7'), 
+('L5X3-1/2X3/8','L5X3-1/2X3/8','L127X89X9.5','L127X89X9.5'), 
+('L5X3-1/2X5/16','L5X3-1/2X5/16','L127X89X7.9','L127X89X7.9'), 
+('L5X3-1/2X1/4','L5X3-1/2X1/4','L127X89X6.4','L127X89X6.4'), 
+('L5X3X1/2','L5X3X1/2','L127X76X12.7','L127X76X12.7'), 
+('L5X3X7/16','L5X3X7/16','L127X76X11.1','L127X76X11.1'), 
+('L5X3X3/8','L5X3X3/8','L127X76X9.5','L127X76X9.5'), 
+('L5X3X5/16','L5X3X5/16','L127X76X7.9','L127X76X7.9'), 
+('L5X3X1/4','L5X3X1/4','L127X76X6.4','L127X76X6.4'), 
+('L4X4X3/4','L4X4X3/4','L102X102X19','L102X102X19'), 
+('L4X4X5/8','L4X4X5/8','L102X102X15.9','L102X102X15.9'), 
+('L4X4X1/2','L4X4X1/2','L102X102X12.7','L102X102X12.7'), 
+('L4X4X7/16','L4X4X7/16','L102X102X11.1','L102X102X11.1'), 
+('L4X4X3/8','L4X4X3/8','L102X102X9.5','L102X102X9.5'), 
+('L4X4X5/16','L4X4X5/16','L102X102X7.9','L102X102X7.9'), 
+('L4X4X1/4','L4X4X1/4','L102X102X6.4','L102X102X6.4'), 
+('L4X3-1/2X1/2','L4X3-1/2X1/2','L102X89X12.7','L102X89X12.7'), 
+('L4X3-1/2X3/8','L4X3-1/2X3/8','L102X89X9.5','L102X89X9.5'), 
+('L4X3-1/2X5/16','L4X3-1/2X5/16','L102X89X7.9','L102X89X7.9'), 
+('L4X3-1/2X1/4','L4X3-1/2X1/4','L102X89X6.4','L102X89X6.4'), 
+('L4X3X5/8','L4X3X5/8','L102X76X15.9','L102X76X15.9'), 
+('L4X3X1/2','L4X3X1/2','L102X76X12.7','L102X76X12.7'), 
+('L4X3X3/8','L4X3X3/8','L102X76X9.5','L102X76X9.5'), 
+('L4X3X5/16','L4X3X5/16','L102X76X7.9','L102X76X7.9'), 
+('L4X3X1/4','L4X3X1/4','L102X76X6.4','L102X76X6.4'), 
+('L3-1/2X3-1/2X1/2','L3-1/2X3-1/2X1/2','L89X89X12.7','L89X89X12.7'), 
+('L3-1/2X3-1/2X7/16','L3-1/2X3-1/2X7/16','L89X89X11.1','L89X89X11.1'), 
+('L3-1/2X3-1/2X3/8','L3-1/2X3-1/2X3/8','L89X89X9.5','L89X89X9.5'), 
+('L3-1/2X3-1/2X5/16','L3-1/2X3-1/2X5/16','L89X89X7.9','L89X89X7.9'), 
+('L3-1/2X3-1/2X1/4','L3-1/2X3-1/2X1/4','L89X89X6.4','L89X89X6.4'), 
+('L3-1/2X3X1/2','L3-1/2X3X1/2','L89X76X12.7','L89X76X12.7'), 
+('L3-1/2X3X7/16','L3-1/2X3X7/16','L89X76X11.1','L89X76X11.1'), 
+('L3-1/2X3X3/8','L3-1/2X3X3/8','L89X76X9.5','L89X76X9.5'), 
+('L3-1/2X3X5/16','L3-1/2X3X5/16','L89X76X7.9','L89X76X7.9'), 
+('L3-1/2X3X1/4','L3-1/2X3X1/4','L89X76X6.4','L89X76X6.4'), 
+('L3-1/2X2-1/2X1/2','L3-1/2X2-1/2X1/2','L89X64X12.7','L89X64X12.7'), 
+('L3-1/2X2-1/2X3/8','L3-1/2X2-1/2X3/8','L89X64X9.5','L89X64X9.5'), 
+('L3-1/2X2-1/2X5/16','L3-1/2X2-1/2X5/16','L89X64X7.9','L89X64X7.9'), 
+('L3-1/2X2-1/2X1/4','L3-1/2X2-1/2X1/4','L89X64X6.4','L89X64X6.4'), 
+('L3X3X1/2','L3X3X1/2','L76X76X12.7','L76X76X12.7'), 
+('L3X3X7/16','L3X3X7/16','L76X76X11.1','L76X76X11.1'), 
+('L3X3X3/8','L3X3X3/8','L76X76X9.5','L76X76X9.5'), 
+('L3X3X5/16','L3X3X5/16','L76X76X7.9','L76X76X7.9'), 
+('L3X3X1/4','L3X3X1/4','L76X76X6.4','L76X76X6.4'), 
+('L3X3X3/16','L3X3X3/16','L76X76X4.8','L76X76X4.8'), 
+('L3X2-1/2X1/2','L3X2-1/2X1/2','L76X64X12.7','L76X64X12.7'), 
+('L3X2-1/2X7/16','L3X2-1/2X7/16','L76X64X11.1','L76X64X11.1'), 
+('L3X2-1/2X3/8','L3X2-1/2X3/8','L76X64X9.5','L76X64X9.5'), 
+('L3X2-1/2X5/16','L3X2-1/2X5/16','L76X64X7.9','L76X64X7.9'), 
+('L3X2-1/2X1/4','L3X2-1/2X1/4','L76X64X6.4','L76X64X6.4'), 
+('L3X2-1/2X3/16','L3X2-1/2X3/16','L76X64X4.8','L76X64X4.8'), 
+('L3X2X1/2','L3X2X1/2','L76X51X12.7','L76X51X12.7'), 
+('L3X2X3/8','L3X2X3/8','L76X51X9.5','L76X51X9.5'), 
+('L3X2X5/16','L3X2X5/16','L76X51X7.9','L76X51X7.9'), 
+('L3X2X1/4','L3X2X1/4','L76X51X6.4','L76X51X6.4'), 
+('L3X2X3/16','L3X2X3/16','L76X51X4.8','L76X51X4.8'), 
+('L2-1/2X2-1/2X1/2','L2-1/2X2-1/2X1/2','L64X64X12.7','L64X64X12.7'), 
+('L2-1/2X2-1/2X3/8','L2-1/2X2-1/2X3/8','L64X64X9.5','L64X64X9.5'), 
+('L2-1/2X2-1/2X5/16','L2-1/2X2-1/2X5/16','L64X64X7.9','L64X64X7.9'), 
+('L2-1/2X2-1/2X1/4','L2-1/2X2-1/2X1/4','L64X64X6.4','L64X64X6.4'), 
+('L2-1/2X2-1/2X3/16','L2-1/2X2-1/2X3/16','L64X64X4.8','L64X64X4.8'), 
+('L2-1/2X2X3/8','L2-1/2X2X3/8','L64X51X9.5','L64X51X9.5'), 
+('L2-1/2X2X5/16','L2-1/2X2X5/16','L64X51X7.9','L64X51X7.9'), 
+('L2-1/2X2X1/4','L2-1/2X2X1/4','L64X51X6.4','L64X51X6.4'), 
+('L2-1/2X2X3/16','L2-1/2X2X3/16','L64X51X4.8','L64X51X4.8'), 
+('L2-1/2X1-1/2X1/4','L2-1/2X1-1/2X1/4','L64X38X6.4','L64X38X6.4'), 
+('L2-1/2X1-1/2X3/16','L2-1/2X1-1/2X3/16','L64X38X4.8','L64X38X4.8'), 
+('L2X2X3/8','L2X2X3/8','L51X51X9.5','L51X51X9.5'), 
+('L2X2X5/16','L2X2X5/16','L51X51X7.9','L51X51X7.9'), 
+('L2X2X1/4','L2X2X1/4','L51X51X6.4','L51X51X6.4'), 
+('L2X2X3/16','L2X2X3/16','L51X51X4.8','L51X51X4.8'), 
+('L2X2X1/8','L2X2X1/8','L51X51X3.2','L51X51X3.2'), 
+('WT22X204','WT22X204','WT550X304','WT550X304'), 
+('WT22X184','WT22X184','WT550X274','WT550X274'), 
+('WT22X167.5','WT22X167.5','WT550X249','WT550X249'), 
+('WT22X145','WT22X145','WT550X216','WT550X216'), 
+('WT22X131','WT22X131','WT550X195','WT550X195'), 
+('WT22X115','WT22X115','WT550X171','WT550X171'), 
+('WT20X327.5','WT20X327.5','WT500X487','WT500X487'), 
+('WT20X296.5','WT20X296.5','WT500X441','WT500X441'), 
+('WT20X251.5','WT20X251.5','WT500X374','WT500X374'), 
+('WT20X215.5','WT20X215.5','WT500X321','WT500X321'), 
+('WT20X198.5','WT20X198.5','WT500X295','WT500X295'), 
+('WT20X186','WT20X186','WT500X277','WT500X277'), 
+('WT20X181','WT20X181','WT500X269','WT500X269'), 
+('WT20X162','WT20X162','WT500X241','WT500X241'), 
+('WT20X148.5','WT20X148.5','WT500X221','WT500X221'), 
+('WT20X138.5','WT20X138.5','WT500X206','WT500X206'), 
+('WT20X124.5','WT20X124.5','WT500X185','WT500X185'), 
+('WT20X107.5','WT20X107.5','WT500X160','WT500X160'), 
+('WT20X99.5','WT20X99.5','WT500X148','WT500X148'), 
+('WT20X196','WT20X196','WT500X292','WT500X292'), 
+('WT20X165.5','WT20X165.5','WT500X246','WT500X246'), 
+('WT20X163.5','WT20X163.5','WT500X243','WT500X243'), 
+('WT20X147','WT20X147','WT500X219','WT500X219'), 
+('WT20X139','WT20X139','WT500X207','WT500X207'), 
+('WT20X132','WT20X132','WT500X196','WT500X196'), 
+('WT20X117.5','WT20X117.5','WT500X175','WT500X175'), 
+('WT20X105.5','WT20X105.5','WT500X157','WT500X157'), 
+('WT20X91.5','WT20X91.5','WT500X136','WT500X136'), 
+('WT20X83.5','WT20X83.5','WT500X124','WT500X124'), 
+('WT20X74.5','WT20X74.5','WT500X111','WT500X111'), 
+('WT18X462.5','WT18X462.5','WT460X688','WT460X688'), 
+('WT18X426.5','WT18X426.5','WT460X635','WT460X635'), 
+('WT18X401','WT18X401','WT460X597','WT460X597'), 
+('WT18X361.5','WT18X361.5','WT460X538','WT460X538'), 
+('WT18X326','WT18X326','WT460X485','WT460X485'), 
+('WT18X264.5','WT18X264.5','WT460X394','WT460X394'), 
+('WT18X243.5','WT18X243.5','WT460X362','WT460X362'), 
+('WT18X220.5','WT18X220.5','WT460X328','WT460X328'), 
+('WT18X197.5','WT18X197.5','WT460X294','WT460X294'), 
+('WT18X180.5','WT18X180.5','WT460X269','WT460X269'), 
+('WT18X165','WT18X165','WT460X246','WT460X246'), 
+('WT18X151','WT18X151','WT460X225','WT460X225'), 
+('WT18X141','WT18X141','WT460X210','WT460X210'), 
+('WT18X131','WT18X131','WT460X195','WT460X195'), 
+('WT18X123.5','WT18X123.5','WT460X184','WT460X184'), 
+('WT18X115.5','WT18X115.5','WT460X172','WT460X172'), 
+('WT18X193.5','WT18X193.5','WT460X288','WT460X288'), 
+('WT18X175','WT18X175','WT460X260','WT460X260'), 
+('WT18X159','WT18X159','WT460X237','WT460X237'), 
+('WT18X143','WT18X143','WT460X213','WT460X213'), 
+('WT18X128','WT18X128','WT460X190','WT460X190'), 
+('WT18X116','WT18X116','WT460X173','WT460X173'), 
+('WT18X105','WT18X105','WT460X156','WT460X156'), 
+('WT18X97','WT18X97','WT460X144','WT460X144'), 
+('WT18X91','WT18X91','WT460X135','WT460X135'), 
+('WT18X85','WT18X85','WT460X126','WT460X126'), 
+('WT18X80','WT18X80','WT460X119','WT460X119'), 
+('WT18X75','WT18X75','WT460X112','WT460X112'), 
+('WT18X67.5','WT18X67.5','WT460X100','WT460X100'), 
+('WT16.5X193.5','WT16.5X193.5','WT420X288','WT420X288'), 
+('WT16.5X177','WT16.5X177','WT420X263','WT420X263'), 
+('WT16.5X159','WT16.5X159','WT420X237','WT420X237'), 
+('WT16.5X145.5','WT16.5X145.5','WT420X217','WT420X217'), 
+('WT16.5X131.5','WT16.5X131.5','WT420X196','WT420X196'), 
+('WT16.5X120.5','WT16.5X120.5','WT420X179','WT420X179'), 
+('WT16.5X110.5','WT16.5X110.5','WT420X164','WT420X164'), 
+('WT16.5X100.5','WT16.5X100.5','WT420X150','WT420X150'), 
+('WT16.5X84.5','WT16.5X84.5','WT420X126','WT420X126'), 
+('WT16.5X76','WT16.5X76','WT420X113','WT420X113'), 
+('WT16.5X70.5','WT16.5X70.5','WT420X105','WT420X105'), 
+('WT16.5X65','WT16.5X65','WT420X96.7','WT420X96.7'), 
+('WT16.5X59','WT16.5X59','WT420X87.8','WT420X87.8'), 
+('WT15X195.5','WT15X195.5','WT380X291','WT380X291'), 
+('WT15X178.5','WT15X178.5','WT380X266','WT380X266'), 
+('WT15X163','WT15X163','WT380X243','WT380X243'), 
+('WT15X146','WT15X146','WT380X217','WT380X217'), 
+('WT15X130.5','WT15X130.5','WT380X194','WT380X194'), 
+('WT15X117.5','WT15X117.5','WT380X175','WT380X175'), 
+('WT15X105.5','WT15X105.5','WT380X157','WT380X157'), 
+('WT15X95.5','WT15X95.5','WT380X142','WT380X142'), 
+('WT15X86.5','WT15X86.5','WT380X129','WT380X129'), 
+('WT15X74','WT15X74','WT380X110','WT380X110'), 
+('WT15X66','WT15X66','WT380X98.2','WT380X98.2'), 
+('WT15X62','WT15X62','WT380X92.3','WT380X92.3'), 
+('WT15X58','WT15X58','WT380X86.3','WT380X86.3'), 
+('WT15X54','WT15X54','WT380X80.4','WT380X80.4'), 
+('WT15X49.5','WT15X49.5','WT380X73.7','WT380X73.7'), 
+('WT15X45','WT15X45','WT380X67','WT380X67'), 
+('WT13.5X269.5','WT13.5X269.5','WT345X401','WT345X401'), 
+('WT13.5X184','WT13.5X184','WT345X274','WT345X274'), 
+('WT13.5X168','WT13.5X168','WT345X250','WT345X250'), 
+('WT13.5X153.5','WT13.5X153.5','WT345X228','WT345X228'), 
+('WT13.5X140.5','WT13.5X140.5','WT345X209','WT345X209'), 
+('WT13.5X129','WT13.5X129','WT345X192','WT345X192'), 
+('WT13.5X117.5','WT13.5X117.5','WT345X175','WT345X175'), 
+('WT13.5X108.5','WT13.5X108.5','WT345X161','WT345X161'), 
+('WT13.5X97','WT13.5X97','WT345X144','WT345X144'), 
+('WT13.5X89','WT13.5X89','WT345X132','WT345X132'), 
+('WT13.5X80.5','WT13.5X80.5','WT345X120','WT345X120'), 
+('WT13.5X73','WT13.5X73','WT345X109','WT345X109'), 
+('WT13.5X64.5','WT13.5X64.5','WT345X96','WT345X96'), 
+('WT13.5X57','WT13.5X57','WT345X84.8','WT345X84.8'), 
+('WT13.5X51','WT13.5X51','WT345X75.9','WT345X75.9'), 
+('WT13.5X47','WT13.5X47','WT345X69.9','WT345X69.9'), 
+('WT13.5X42','WT13.5X42','WT345X62.5','WT345X62.5'), 
+('WT12X185','WT12X185','WT305X275','WT305X275'), 
+('WT12X167.5','WT12X167.5','WT305X249','WT305X249'), 
+('WT12X153','WT12X153','WT305X228','WT305X228'), 
+('WT12X139.5','WT12X139.5','WT305X208','WT305X208'), 
+('WT12X125','WT12X125','WT305X186','WT305X186'), 
+('WT12X114.5','WT12X114.5','WT305X170','WT305X170'), 
+('WT12X103.5','WT12X103.5','WT305X154','WT305X154'), 
+('WT12X96','WT12X96','WT305X143','WT305X143'), 
+('WT12X88','WT12X88','WT305X131','WT305X131'), 
+('WT12X81','WT12X81','WT305X121','WT305X121'), 
+('WT12X73','WT12X73','WT305X109','WT305X109'), 
+('WT12X65.5','WT12X65.5','WT305X97.5','WT305X97.5'), 
+('WT12X58.5','WT12X58.5','WT305X87.1','WT305X87.1'), 
+('WT12X52','WT12X52','WT305X77.4','WT305X77.4'), 
+('WT12X51.5','WT12X51.5','WT305X76.6','WT305X76.6'), 
+('WT12X47','WT12X47','WT305X69.9','WT305X69.9'), 
+('WT12X42','WT12X42','WT305X62.5','WT305X62.5'), 
+('WT12X38','WT12X38','WT305X56.6','WT305X56.6'), 
+('WT12X34','WT12X34','WT305X50.6','WT305X50.6'), 
+('WT12X31','WT12X31','WT305X46.1','WT305X46.1'), 
+('WT12X27.5','WT12X27.5','WT305X40.9','WT305X40.9'), 
+('WT10.5X137.5','WT10.5X137.5','WT265X205','WT265X205'), 
+('WT10.5X124','WT10.5X124','WT265X185','WT265X185'), 
+('WT10.5X111.5','WT10.5X111.5','WT265X166','WT265X166'), 
+('WT10.5X100.5','WT10.5X100.5','WT265X150','WT265X150'), 
+('WT10.5X91','WT10.5X91','WT265X135','WT265X135'), 
+('WT10.5X83','WT10.5X83','WT265X124','WT265X124'), 
+('WT10.5X73.5','WT10.5X73.5','WT265X109','WT265X109'), 
+('WT10.5X66','WT10.5X66','WT265X98.2','WT265X98.2'), 
+('WT10.5X61','WT10.5X61','WT265X90.8','WT265X90.8'), 
+('WT10.5X55.5','WT10.5X55.5','WT265X82.6','WT265X82.6'), 
+('WT10.5X50.5','WT10.5X50.5','WT265X75.2','WT265X75.2'), 
+('WT10.5X46.5','WT10.5X46.5','WT265X69.2','WT265X69.2'), 
+('WT10.5X41.5','WT10.5X41.5','WT265X61.8','WT265X61.8'), 
+('WT10.5X36.5','WT10.5X36.5','WT265X54.3','WT265X54.3'), 
+('WT10.5X34','WT10.5X34','WT265X50.6','WT265X50.6'), 
+('WT10.5X31','WT10.5X31','WT265X46.1','WT265X46.1'), 
+('WT10.5X27.5','WT10.5X27.5','WT265X40.9','WT265X40.9'), 
+('WT10.5X24','WT10.5X24','WT265X35.7','WT265X35.7'), 
+('WT10.5X28.5','WT10.5X28.5','WT265X42.4','WT265X42.4'), 
+('WT10.5X25','WT10.5X25','WT265X37.2','WT265X37.2'), 
+('WT10.5X22','WT10.5X22','WT265X32.7','WT265X32.7'), 
+('WT9X155.5','WT9X155.5','WT230X231','WT230X231'), 
+('WT9X141.5','WT9X141.5','WT230X211','WT230X211'), 
+('WT9X129','WT9X129','WT230X192','WT230X192'), 
+('WT9X117','WT9X117','WT230X174','WT230X174'), 
+('WT9X105.5','WT9X105.5','WT230X157','WT230X157'), 
+('WT9X96','WT9X96','WT230X143','WT230X143'), 
+('WT9X87.5','WT9X87.5','WT230X130','WT230X130'), 
+('WT9X79','WT9X79','WT230X118','WT230X118'), 
+('WT9X71.5','WT9X71.5','WT230X106','WT230X106'), 
+('WT9X65','WT9X65','WT230X96.7','WT230X96.7'), 
+('WT9X59.5','WT9X59.5','WT230X88.5','WT230X88.5'), 
+('WT9X53','WT9X53','WT230X78.9','WT230X78.9'), 
+('WT9X48.5','WT9X48.5','WT230X72.2','WT230X72.2'), 
+('WT9X43','WT9X43','WT230X64','WT230X64'), 
+('WT9X38','WT9X38','WT230X56.6','WT230X56.6'), 
+('WT9X35.5','WT9X35.5','WT230X52.8','WT230X52.8'), 
+('WT9X32.5','WT9X32.5','WT230X48.4','WT230X48.4'), 
+('WT9X30','WT9X30','WT230X44.6','WT230X44.6'), 
+('WT9X27.5','WT9X27.5','WT230X40.9','WT230X40.9'), 
+('WT9X25','WT9X25','WT230X37.2','WT230X37.2'), 
+('WT9X23','WT9X23','WT230X34.2','WT230X34.2'), 
+('WT9X20','WT9X20','WT230X29.8','WT230X29.8'), 
+('WT9X17.5','WT9X17.5','WT230X26','WT230X26'), 
+('WT8X50','WT8X50','WT205X74.4','WT205X74.4'), 
+('WT8X44.5','WT8X44.5','WT205X66.2','WT205X66.2'), 
+('WT8X38.5','WT8X38.5','WT205X57.3','WT205X57.3'), 
+('WT8X33.5','WT8X33.5','WT205X49.9','WT205X49.9'), 
+('WT8X28.5','WT8X28.5','WT205X42.4','WT205X42.4'), 
+('WT8X25','WT8X25','WT205X37.2','WT205X37.2'), 
+('WT8X22.5','WT8X22.5','WT205X33.5','WT205X33.5'), 
+('WT8X20','WT8X20','WT205X29.8','WT205X29.8'), 
+('WT8X18','WT8X18','WT205X26.8','WT205X26.8'), 
+('WT8X15.5','WT8X15.5','WT205X23.1','WT205X23.1'), 
+('WT8X13','WT8X13','WT205X19.3','WT205X19.3'), 
+('WT7X436.5','WT7X436.5','WT180X650','WT180X650'), 
+('WT7X404','WT7X404','WT180X601','WT180X601'), 
+('WT7X365','WT7X365','WT180X543','WT180X543'), 
+('WT7X332.5','WT7X332.5','WT180X495','WT180X495'), 
+('WT7X302.5','WT7X302.5','WT180X450','WT180X450'), 
+('WT7X275','WT7X275','WT180X409','WT180X409'), 
+('WT7X250','WT7X250','WT180X372','WT180X372'), 
+('WT7X227.5','WT7X227.5','WT180X339','WT180X339'), 
+('WT7X213','WT7X213','WT180X317','WT180X317'), 
+('WT7X199','WT7X199','WT180X296','WT180X296'), 
+('WT7X185','WT7X185','WT180X275','WT180X275'), 
+('WT7X171','WT7X171','WT180X254','WT180X254'), 
+('WT7X155.5','WT7X155.5','WT180X231','WT180X231'), 
+('WT7X141.5','WT7X141.5','WT180X211','WT180X211'), 
+('WT7X128.5','WT7X128.5','WT180X191','WT180X191'), 
+('WT7X116.5','WT7X116.5','WT180X173','WT180X173'), 
+('WT7X105.5','WT7X105.5','WT180X157','WT180X157'), 
+('WT7X96.5','WT7X96.5','WT180X144','WT180X144'), 
+('WT7X88','WT7X88','WT180X131','WT180X131'), 
+('WT7X79.5','WT7X79.5','WT180X118','WT180X118'), 
+('WT7X72.5','WT7X72.5','WT180X108','WT180X108'), 
+('WT7X66','WT7X66','WT180X98.2','WT180X98.2'), 
+('WT7X60','WT7X60','WT180X89.3','WT180X89.3'), 
+('WT7X54.5','WT7X54.5','WT180X81.1','WT180X81.1'), 
+('WT7X49.5','WT7X49.5','WT180X73.7','WT180X73.7'), 
+('WT7X45','WT7X45','WT180X67','WT180X67'), 
+('WT7X41','WT7X41','WT180X61','WT180X61'), 
+('WT7X37','WT7X37','WT180X55.1','WT180X55.1'), 
+('WT7X34','WT7X34','WT180X50.6','WT180X50.6'), 
+('WT7X30.5','WT7X30.5','WT180X45.4','WT180X45.4'), 
+('WT7X26.5','WT7X26.5','WT180X39.4','WT180X39.4'), 
+('WT7X24','WT7X24','WT180X35.7','WT180X35.7'), 
+('WT7X21.5','WT7X21.5','WT180X32','WT180X32'), 
+('WT7X19','WT7X19','WT180X28.3','WT180X28.3'), 
+('WT7X17','WT7X17','WT180X25.3','WT180X25.3'), 
+('WT7X15','WT7X15','WT180X22.3','WT180X22.3'), 
+('WT7X13','WT7X13','WT180X19.3','WT180X19.3'), 
+('WT7X11','WT7X11','WT180X16.4','WT180X16.4'), 
+('WT6X168','WT6X168','WT155X250','WT155X250'), 
+('WT6X152.5','WT6X152.5','WT155X227','WT155X227'), 
+('WT6X139.5','WT6X139.5','WT155X208','WT155X208'), 
+('WT6X126','WT6X126','WT155X188','WT155X188'), 
+('WT6X115','WT6X115','WT155X171','WT155X171'), 
+('WT6X105','WT6X105','WT155X156','WT155X156'), 
+('WT6X95','WT6X95','WT155X141','WT155X141'), 
+('WT6X85','WT6X85','WT155X126','WT155X126'), 
+('WT6X76','WT6X76','WT155X113','WT155X113'), 
+('WT6X68','WT6X68','WT155X101','WT155X101'), 
+('WT6X60','WT6X60','WT155X89.3','WT155X89.3'), 
+('WT6X53','WT6X53','WT155X78.9','WT155X78.9'), 
+('WT6X48','WT6X48','WT155X71.4','WT155X71.4'), 
+('WT6X43.5','WT6X43.5','WT155X64.7','WT155X64.7'), 
+('WT6X39.5','WT6X39.5','WT155X58.8','WT155X58.8'), 
+('WT6X36','WT6X36','WT155X53.6','WT155X53.6'), 
+('WT6X32.5','WT6X32.5','WT155X48.4','WT155X48.4'), 
+('WT6X29','WT6X29','WT155X43.2','WT155X43.2'), 
+('WT6X26.5','WT6X26.5','WT155X39.4','WT155X39.4'), 
+('WT6X25','WT6X25','WT155X37.2','WT155X37.2'), 
+('WT6X22.5','WT6X22.5','WT155X33.5','WT155X33.5'), 
+('WT6X20','WT6X20','WT155X29.8','WT155X29.8'), 
+('WT6X17.5','WT6X17.5','WT155X26','WT155X26'), 
+('WT6X15','WT6X15','WT155X22.3','WT155X22.3'), 
+('WT6X13','WT6X13','WT155X19.3','WT155X19.3'), 
+('WT6X11','WT6X11','WT155X16.4','WT155X16.4'), 
+('WT6X9.5','WT6X9.5','WT155X14.1','WT155X14.1'), 
+('WT6X8','WT6X8','WT155X11.9','WT155X11.9'), 
+('WT6X7','WT6X7','WT155X10.4','WT155X10.4'), 
+('WT5X56','WT5X56','WT125X83.3','WT125X83.3'), 
+('WT5X50','WT5X50','WT125X74.4','WT125X74.4'), 
+('WT5X44','WT5X44','WT125X65.5','WT125X65.5'), 
+('WT5X38.5','WT5X38.5','WT125X57.3','WT125X57.3'), 
+('WT5X34','WT5X34','WT125X50.6','WT125X50.6'), 
+('WT5X30','WT5X30','WT125X44.6','WT125X44.6'), 
+('WT5X27','WT5X27','WT125X40.2','WT125X40.2'), 
+('WT5X24.5','WT5X24.5','WT125X36.5','WT125X36.5'), 
+('WT5X22.5','WT5X22.5','WT125X33.5','WT125X33.5'), 
+('WT5X19.5','WT5X19.5','WT125X29','WT125X29'), 
+('WT5X16.5','WT5X16.5','WT125X24.6','WT125X24.6'), 
+('WT5X15','WT5X15','WT125X22.3','WT125X22.3'), 
+('WT5X13','WT5X13','WT125X19.3','WT125X19.3'), 
+('WT5X11','WT5X11','WT125X16.4','WT125X16.4'), 
+('WT5X9.5','WT5X9.5','WT125X14.1','WT125X14.1'), 
+('WT5X8.5','WT5X8.5','WT125X12.6','WT125X12.6'), 
+('WT5X7.5','WT5X7.5','WT125X11.2','WT125X11.2'), 
+('WT5X6','WT5X6','WT125X8.93','WT125X8.93'), 
+('WT4X33.5','WT4X33.5','WT100X49.9','WT100X49.9'), 
+('WT4X29','WT4X29','WT100X43.2','WT100X43.2'), 
+('WT4X24','WT4X24','WT100X35.7','WT100X35.7'), 
+('WT4X20','WT4X20','WT100X29.8','WT100X29.8'), 
+('WT4X17.5','WT4X17.5','WT100X26','WT100X26'), 
+('WT4X15.5','WT4X15.5','WT100X23.1','WT100X23.1'), 
+('WT4X14','WT4X14','WT100X20.8','WT100X20.8'), 
+('WT4X12','WT4X12','WT100X17.9','WT100X17.9'), 
+('WT4X10.5','WT4X10.5','WT100X15.6','WT100X15.6'), 
+('WT4X9','WT4X9','WT100X13.4','WT100X13.4'), 
+('WT4X7.5','WT4X7.5','WT100X11.2','WT100X11.2'), 
+('WT4X6.5','WT4X6.5','WT100X9.67','WT100X9.67'), 
+('WT4X5','WT4X5','WT100X7.44','WT100X7.44'), 
+('WT3X12.5','WT3X12.5','WT75X18.6','WT75X18.6'), 
+('WT3X10','WT3X10','WT75X14.9','WT75X14.9'), 
+('WT3X7.5','WT3X7.5','WT75X11.2','WT75X11.2'), 
+('WT3X8','WT3X8','WT75X11.9','WT75X11.9'), 
+('WT3X6','WT3X6','WT75X8.93','WT75X8.93'), 
+('WT3X4.5','WT3X4.5','WT75X6.7','WT75X6.7'), 
+('WT3X4.25','WT3X4.25','WT75X6.32','WT75X6.32'), 
+('WT2.5X9.5','WT2.5X9.5','WT65X14.1','WT65X14.1'), 
+('WT2.5X8','WT2.5X8','WT65X11.9','WT65X11.9'), 
+('WT2X6.5','WT2X6.5','WT50X9.67','WT50X9.67'), 
+('MT6.25X6.2','MT6.25X6.2','MT160X9.23','MT160X9.23'), 
+('MT6.25X5.8','MT6.25X5.8','MT160X8.63','MT160X8.63'), 
+('MT6X5.9','MT6X5.9','MT155X8.78','MT155X8.78'), 
+('MT6X5.4','MT6X5.4','MT155X8.04','MT155X8.04'), 
+('MT6X5','MT6X5','MT155X7.44','MT155X7.44'), 
+('MT5X4.5','MT5X4.5','MT125X6.7','MT125X6.7'), 
+('MT5X4','MT5X4','MT125X5.95','MT125X5.95'), 
+('MT5X3.75','MT5X3.75','MT125X5.58','MT125X5.58'), 
+('MT4X3.25','MT4X3.25','MT100X4.84','MT100X4.84'), 
+('MT4X3.1','MT4X3.1','MT100X4.61','MT100X4.61'), 
+('MT3X2.2','MT3X2.2','MT75X3.27','MT75X3.27'), 
+('MT3X1.85','MT3X1.85','MT75X2.75','MT75X2.75'), 
+('MT2.5X9.45','MT2.5X9.45','MT65X14.1','MT65X14.1'), 
+('MT2X3','MT2X3','MT50X4.46','MT50X4.46'), 
+('ST12X60.5','ST12X60.5','ST305X90','ST305X90'), 
+('ST12X53','ST12X53','ST305X78.9','ST305X78.9'), 
+('ST12X50','ST12X50','ST305X74.4','ST305X74.4'), 
+('ST12X45','ST12X45','ST305X67','ST305X67'), 
+('ST12X40','ST12X40','ST305X59.5','ST305X59.5'), 
+('ST10X48','ST10X48','ST255X71.4','ST255X71.4'), 
+('ST10X43','ST10X43','ST255X64','ST255X64'), 
+('ST10X37.5','ST10X37.5','ST255X55.8','ST255X55.8'), 
+('ST10X33','ST10X33','ST255X49.1','ST255X49.1'), 
+('ST9X35','ST9X35','ST230X52.1','ST230X52.1'), 
+('ST9X27.35','ST9X27.35','ST230X40.7','ST230X40.7'), 
+('ST7.5X25','ST7.5X25','ST190X37.2','ST190X37.2'), 
+('ST7.5X21.45','ST7.5X21.45','ST190X31.9','ST190X31.9'), 
+('ST6X25','ST6X25','ST155X37.2','ST155X37.2'), 
+('ST6X20.4','ST6X20.4','ST155X30.4','ST155X30.4'), 
+('ST6X17.5','ST6X17.5','ST155X26','ST155X26'), 
+('ST6X15.9','ST6X15.9','ST155X23.7','ST155X23.7'), 
+('ST5X17.5','ST5X17.5','ST125X26','ST125X26'), 
+('ST5X12.7','ST5X12.7','ST125X18.9','ST125X18.9'), 
+('ST4X11.5','ST4X11.5','ST100X17.1','ST100X17.1'), 
+('ST4X9.2','ST4X9.2','ST100X13.7','ST100X13.7'), 
+('ST3X8.6','ST3X8.6','ST75X12.8','ST75X12.8'), 
+('ST3X6.25','ST3X6.25','ST75X9.3','ST75X9.3'), 
+('ST2.5X5','ST2.5X5','ST65X7.44','ST65X7.44'), 
+('ST2X4.75','ST2X4.75','ST50X7.07','ST50X7.07'), 
+('ST2X3.85','ST2X3.85','ST50X5.73','ST50X5.73'), 
+('ST1.5X3.75','ST1.5X3.75','ST37.5X5.58','ST37.5X5.58'), 
+('ST1.5X2.85','ST1.5X2.85','ST37.5X4.24','ST37.5X4.24'), 
+('2L12X12X1-3/8','2L12X12X1-3/8','2L305X305X34.9','2L305X305X34.9'), 
+('2L12X12X1-3/8X3/4','2L12X12X1-3/8X3/4','2L305X305X34.9X19','2L305X305X34.9X19'), 
+('2L12X12X1-3/8X1-1/2','2L12X12X1-3/8X1-1/2','2L305X305X34.9X38.1','2L305X305X34.9X38.1'), 
+('2L12X12X1-1/4','2L12X12X1-1/4','2L305X305X31.8','2L305X305X31.8'), 
+('2L12X12X1-1/4X3/4','2L12X12X1-1/4X3/4','2L305X305X31.8X19','2L305X305X31.8X19'), 
+('2L12X12X1-1/4X1-1/2','2L12X12X1-1/4X1-1/2','2L305X305X31.8X38.1','2L305X305X31.8X38.1'), 
+('2L12X12X1-1/8','2L12X12X1-1/8','2L305X305X28.6','2L305X305X28.6'), 
+('2L12X12X1-1/8X3/4','2L12X12X1-1/8X3/4','2L305X305X28.6X19','2L305X305X28.6X19'), 
+('2L12X12X1-1/8X1-1/2','2L12X12X1-1/8X1-1/2','2L305X305X28.6X38.1','2L305X305X28.6X38.1'), 
+('2L12X12X1','2L12X12X1','2L305X305X25.4','2L305X305X25.4'), 
+('2L12X12X1X3/4','2L12X12X1X3/4','2L305X305X25.4X19','2L305X305X25.4X19'), 
+('2L12X12X1X1-1/2','2L12X12X1X1-1/2','2L305X305X25.4X38.1','2L305X305X25.4X38.1'), 
+('2L10X10X1-3/8','2L10X10X1-3/8','2L254X254X34.9','2L254X254X34.9'), 
+('2L10X10X1-3/8X3/4','2L10X10X1-3/8X3/4','2L254X254X34.9X19','2L254X254X34.9X19'), 
+('2L10X10X1-3/8X1-1/2','2L10X10X1-3/8X1-1/2','2L254X254X34.9X38.1','2L254X254X34.9X38.1'), 
+('2L10X10X1-1/4','2L10X10X1-1/4','2L254X254X31.8','2L254X254X31.8'), 
+('2L10X10X1-1/4X3/4','2L10X10X1-1/4X3/4','2L254X254X31.8X19','2L254X254X31.8X19'), 
+('2L10X10X1-1/4X1-1/2','2L10X10X1-1/4X1-1/2','2L254X254X31.8X38.1','2L254X254X31.8X38.1'), 
+('2L10X10X1-1/8','2L10X10X1-1/8','2L254X254X28.6','2L254X254X28.6'), 
+('2L10X10X1-1/8X3/4','2L10X10X1-1/8X3/4','2L254X254X28.6X19','2L254X254X28.6X19'), 
+('2L10X10X1-1/8X1-1/2','2L10X10X1-1/8X1-1/2','2L254X254X28.6X38.1','2L254X254X28.6X38.1'), 
+('2L10X10X1','2L10X10X1','2L254X254X25.4','2L254X254X25.4'), 
+('2L10X10X1X3/4','2L10X10X1X3/4','2L254X254X25.4X19','2L254X254X25.4X19'), 
+('2L10X10X1X1-1/2','2L10X10X1X1-1/2','2L254X254X25.4X38.1','2L254X254X25.4X38.1'), 
+('2L10X10X7/8','2L10X10X7/8','2L254X254X22.2','2L254X254X22.2'), 
+('2L10X10X7/8X3/4','2L10X10X7/8X3/4','2L254X254X22.2X19','2L254X254X22.2X19'), 
+('2L10X10X7/8X1-1/2','2L10X10X7/8X1-1/2','2L254X254X22.2X38.1','2L254X254X22.2X38.1'), 
+('2L10X10X3/4','2L10X10X3/4','2L254X254X19','2L254X254X19'), 
+('2L10X10X3/4X3/4','2L10X10X3/4X3/4','2L254X254X19X19','2L254X254X19X19'), 
+('2L10X10X3/4X1-1/2','2L10X10X3/4X1-1/2','2L254X254X19X38.1','2L254X254X19X38.1'), 
+('2L8X8X1-1/8','2L8X8X1-1/8','2L203X203X28.6','2L203X203X28.6'), 
+('2L8X8X1-1/8X3/8','2L8X8X1-1/8X3/8','2L203X203X28.6X9.5','2L203X203X28.6X9.5'), 
+('2L8X8X1-1/8X3/4','2L8X8X1-1/8X3/4','2L203X203X28.6X19','2L203X203X28.6X19'), 
+('2L8X8X1','2L8X8X1','2L203X203X25.4','2L203X203X25.4'), 
+('2L8X8X1X3/8','2L8X8X1X3/8','2L203X203X25.4X9.5','2L203X203X25.4X9.5'), 
+('2L8X8X1X3/4','2L8X8X1X3/4','2L203X203X25.4X19','2L203X203X25.4X19'), 
+('2L8X8X7/8','2L8X8X7/8','2L203X203X22.2','2L203X203X22.2'), 
+('2L8X8X7/8X3/8','2L8X8X7/8X3/8','2L203X203X22.2X9.5','2L203X203X22.2X9.5'), 
+('2L8X8X7/8X3/4','2L8X8X7/8X3/4','2L203X203X22.2X19','2L203X203X22.2X19'), 
+('2L8X8X3/4','2L8X8X3/4','2L203X203X19','2L203X203X19'), 
+('2L8X8X3/4X3/8','2L8X8X3/4X3/8','2L203X203X19X9.5','2L203X203X19X9.5'), 
+('2L8X8X3/4X3/4','2L8X8X3/4X3/4','2L203X203X19X19','2L203X203X19X19'), 
+('2L8X8X5/8','2L8X8X5/8','2L203X203X15.9','2L203X203X15.9'), 
+('2L8X8X5/8X3/8','2L8X8X5/8X3/8','2L203X203X15.9X9.5','2L203X203X15.9X9.5'), 
+('2L8X8X5/8X3/4','2L8X8X5/8X3/4','2L203X203X15.9X19','2L203X203X15.9X19'), 
+('2L8X8X9/16','2L8X8X9/16','2L203X203X14.3','2L203X203X14.3'), 
+('2L8X8X9/16X3/8','2L8X8X9/16X3/8','2L203X203X14.3X9.5','2L203X203X14.3X9.5'), 
+('2L8X8X9/16X3/4','2L8X8X9/16X3/4','2L203X203X14.3X19','2L203X203X14.3X19'), 
+('2L8X8X1/2','2L8X8X1/2','2L203X203X12.7','2L203X203X12.7'), 
+('2L8X8X1/2X3/8','2L8X8X1/2X3/8','2L203X203X12.7X9.5','2L203X203X12.7X9.5'), 
+('2L8X8X1/2X3/4','2L8X8X1/2X3/4','2L203X203X12.7X19','2L203X203X12.7X19'), 
+('2L6X6X1','2L6X6X1','2L152X152X25.4','2L152X152X25.4'), 
+('2L6X6X1X3/8','2L6X6X1X3/8','2L152X152X25.4X9.5','2L152X152X25.4X9.5'), 
+('2L6X6X1X3/4','2L6X6X1X3/4','2L152X152X25.4X19','2L152X152X25.4X19'), 
+('2L6X6X7/8','2L6X6X7/8','2L152X152X22.2','2L152X152X22.2'), 
+('2L6X6X7/8X3/8','2L6X6X7/8X3/8','2L152X152X22.2X9.5','2L152X152X22.2X9.5'), 
+('2L6X6X7/8X3/4','2L6X6X7/8X3/4','2L152X152X22.2X19','2L152X152X22.2X19'), 
+('2L6X6X3/4','2L6X6X3/4','2L152X152X19','2L152X152X19'), 
+('2L6X6X3/4X3/8','2L6X6X3/4X3/8','2L152X152X19X9.5','2L152X152X19X9.5'), 
+('2L6X6X3/4X3/4','2L6X6X3/4X3/4','2L152X152X19X19','2L152X152X19X19'), 
+('2L6X6X5/8','2L6X6X5/8','2L152X152X15.9','2L152X152X15.9'), 
+('2L6X6X5/8X3/8','2L6X6X5/8X3/8','2L152X152X15.9X9.5','2L152X152X15.9X9.5'), 
+('2L6X6X5/8X3/4','2L6X6X5/8X3/4','2L152X152X15.9X19','2L152X152X15.9X19'), 
+('2L6X6X9/16','2L6X6X9/16','2L152X152X14.3','2L152X152X14.3'), 
+('2L6X6X9/16X3/8','2L6X6X9/16X3/8','2L152X152X14.3X9.5','2L152X152X14.3X9.5'), 
+('2L6X6X9/16X3/4','2L6X6X9/16X3/4','2L152X152X14.3X19','2L152X152X14.3X19'), 
+('2L6X6X1/2','2L6X6X1/2','2L152X152X12.7','2L152X152X12.7'), 
+('2L6X6X1/2X3/8','2L6X6X1/2X3/8','2L152X152X12.7X9.5','2L152X152X12.7X9.5'), 
+('2L6X6X1/2X3/4','2L6X6X1/2X3/4','2L152X152X12.7X19','2L152X152X12.7X19'), 
+('2L6X6X7/16','2L6X6X7/16','2L152X152X11.1','2L152X152X11.1'), 
+('2L6X6X7/16X3/8','2L6X6X7/16X3/8','2L152X152X11.1X9.5','2L152X152X11.1X9.5'), 
+('2L6X6X7/16X3/4','2L6X6X7/16X3/4','2L152X152X11.1X19','2L152X152X11.1X19'), 
+('2L6X6X3/8','2L6X6X3/8','2L152X152X9.5','2L152X152X9.5'), 
+('2L6X6X3/8X3/8','2L6X6X3/8X3/8','2L152X152X9.5X9.5','2L152X152X9.5X9.5'), 
+('2L6X6X3/8X3/4','2L6X6X3/8X3/4','2L152X152X9.5X19','2L152X152X9.5X19'), 
+('2L6X6X5/16','2L6X6X5/16','2L152X152X7.9','2L152X152X7.9'), 
+('2L6X6X5/16X3/8','2L6X6X5/16X3/8','2L152X152X7.9X9.5','2L152X152X7.9X9.5'), 
+('2L6X6X5/16X3/4','2L6X6X5/16X3/4','2L152X152X7.9X19','2L152X152X7.9X19'), 
+('2L5X5X7/8','2L5X5X7/8','2L127X127X22.2','2L127X127X22.2'), 
+('2L5X5X7/8X3/8','2L5X5X7/8X3/8','2L127X127X22.2X9.5','2L127X127X22.2X9.5'), 
+('2L5X5X7/8X3/4','2L5X5X7/8X3/4','2L127X127X22.2X19','2L127X127X22.2X19'), 
+('2L5X5X3/4','2L5X5X3/4','2L127X127X19','2L127X127X19'), 
+('2L5X5X3/4X3/8','2L5X5X3/4X3/8','2L127X127X19X9.5','2L127X127X19X9.5'), 
+('2L5X5X3/4X3/4','2L5X5X3/4X3/4','2L127X127X19X19','2L127X127X19X19'), 
+('2L5X5X5/8','2L5X5X5/8','2L127X127X15.9','2L127X127X15.9'), 
+('2L5X5X5/8X3/8','2L5X5X5/8X3/8','2L127X127X15.9X9.5','2L127X127X15.9X9.5'), 
+('2L5X5X5/8X3/4','2L5X5X5/8X3/4','2L127X127X15.9X19','2L127X127X15.9X19'), 
+('2L5X5X1/2','2L5X5X1/2','2L127X127X12.7','2L127X127X12.7'), 
+('2L5X5X1/2X3/8','2L5X5X1/2X3/8','2L127X127X12.7X9.5','2L127X127X12.7X9.5'), 
+('2L5X5X1/2X3/4','2L5X5X1/2X3/4','2L127X127X12.7X19','2L127X127X12.7X19'), 
+('2L5X5X7/16','2L5X5X7/16','2L127X127X11.1','2L127X127X11.1'), 
+('2L5X5X7/16X3/8','2L5X5X7/16X3/8','2L127X127X11.1X9.5','2L127X127X11.1X9.5'), 
+('2L5X5X7/16X3/4','2L5X5X7/16X3/4','2L127X127X11.1X19','2L127X127X11.1X19'), 
+('2L5X5X3/8','2L5X5X3/8','2L127X127X9.5','2L127X127X9.5'), 
+('2L5X5X3/8X3/8','2L5X5X3/8X3/8','2L127X127X9.5X9.5','2L127X127X9.5X9.5'), 
+('2L5X5X3/8X3/4','2L5X5X3/8X3/4','2L127X127X9.5X19','2L127X127X9.5X19'), 
+('2L5X5X5/16','2L5X5X5/16','2L127X127X7.9','2L127X127X7.9'), 
+('2L5X5X5/16X3/8','2L5X5X5/16X3/8','2L127X127X7.9X9.5','2L127X127X7.9X9.5'), 
+('2L5X5X5/16X3/4','2L5X5X5/16X3/4','2L127X127X7.9X19','2L127X127X7.9X19'), 
+('2L4X4X3/4','2L4X4X3/4','2L102X102X19','2L102X102X19'), 
+('2L4X4X3/4X3/8','2L4X4X3/4X3/8','2L102X102X19X9.5','2L102X102X19X9.5'), 
+('2L4X4X3/4X3/4','2L4X4X3/4X3/4','2L102X102X19X19','2L102X102X19X19'), 
+('2L4X4X5/8','2L4X4X5/8','2L102X102X15.9','2L102X102X15.9'), 
+('2L4X4X5/8X3/8','2L4X4X5/8X3/8','2L102X102X15.9X9.5','2L102X102X15.9X9.5'), 
+('2L4X4X5/8X3/4','2L4X4X5/8X3/4','2L102X102X15.9X19','2L102X102X15.9X19'), 
+('2L4X4X1/2','2L4X4X1/2','2L102X102X12.7','2L102X102X12.7'), 
+('2L4X4X1/2X3/8','2L4X4X1/2X3/8','2L102X102X12.7X9.5','2L102X102X12.7X9.5'), 
+('2L4X4X1/2X3/4','2L4X4X1/2X3/4','2L102X102X12.7X19','2L102X102X12.7X19'), 
+('2L4X4X7/16','2L4X4X7/16','2L102X102X11.1','2L102X102X11.1'), 
+('2L4X4X7/16X3/8','2L4X4X7/16X3/8','2L102X102X11.1X9.5','2L102X102X11.1X9.5'), 
+('2L4X4X7/16X3/4','2L4X4X7/16X3/4','2L102X102X11.1X19','2L102X102X11.1X19'), 
+('2L4X4X3/8','2L4X4X3/8','2L102X102X9.5','2L102X102X9.5'), 
+('2L4X4X3/8X3/8','2L4X4X3/8X3/8','2L102X102X9.5X9.5','2L102X102X9.5X9.5'), 
+('2L4X4X3/8X3/4','2L4X4X3/8X3/4','2L102X102X9.5X19','2L102X102X9.5X19'), 
+('2L4X4X5/16','2L4X4X5/16','2L102X102X7.9','2L102X102X7.9'), 
+('2L4X4X5/16X3/8','2L4X4X5/16X3/8','2L102X102X7.9X9.5','2L102X102X7.9X9.5'), 
+('2L4X4X5/16X3/4','2L4X4X5/16X3/4','2L102X102X7.9X19','2L102X102X7.9X19'), 
+('2L4X4X1/4','2L4X4X1/4','2L102X102X6.4','2L102X102X6.4'), 
+('2L4X4X1/4X3/8','2L4X4X1/4X3/8','2L102X102X6.4X9.5','2L102X102X6.4X9.5'), 
+('2L4X4X1/4X3/4','2L4X4X1/4X3/4','2L102X102X6.4X19','2L102X102X6.4X19'), 
+('2L3-1/2X3-1/2X1/2','2L3-1/2X3-1/2X1/2','2L89X89X12.7','2L89X89X12.7'), 
+('2L3-1/2X3-1/2X1/2X3/8','2L3-1/2X3-1/2X1/2X3/8','2L89X89X12.7X9.5','2L89X89X12.7X9.5'), 
+('2L3-1/2X3-1/2X1/2X3/4','2L3-1/2X3-1/2X1/2X3/4','2L89X89X12.7X19','2L89X89X12.7X19'), 
+('2L3-1/2X3-1/2X7/16','2L3-1/2X3-1/2X7/16','2L89X89X11.1','2L89X89X11.1'), 
+('2L3-1/2X3-1/2X7/16X3/8','2L3-1/2X3-1/2X7/16X3/8','2L89X89X11.1X9.5','2L89X89X11.1X9.5'), 
+('2L3-1/2X3-1/2X7/16X3/4','2L3-1/2X3-1/2X7/16X3/4','2L89X89X11.1X19','2L89X89X11.1X19'), 
+('2L3-1/2X3-1/2X3/8','2L3-1/2X3-1/2X3/8','2L89X89X9.5','2L89X89X9.5'), 
+('2L3-1/2X3-1/2X3/8X3/8','2L3-1/2X3-1/2X3/8X3/8','2L89X89X9.5X9.5','2L89X89X9.5X9.5'), 
+('2L3-1/2X3-1/2X3/8X3/4','2L3-1/2X3-1/2X3/8X3/4','2L89X89X9.5X19','2L89X89X9.5X19'), 
+('2L3-1/2X3-1/2X5/16','2L3-1/2X3-1/2X5/16','2L89X89X7.9','2L89X89X7.9'), 
+('2L3-1/2X3-1/2X5/16X3/8','2L3-1/2X3-1/2X5/16X3/8','2L89X89X7.9X9.5','2L89X89X7.9X9.5'), 
+('2L3-1/2X3-1/2X5/16X3/4','2L3-1/2X3-1/2X5/16X3/4','2L89X89X7.9X19','2L89X89X7.9X19'), 
+('2L3-1/2X3-1/2X1/4','2L3-1/2X3-1/2X1/4','2L89X89X6.4','2L89X89X6.4'), 
+('2L3-1/2X3-1/2X1/4X3/8','2L3-1/2X3-1/2X1/4X3/8','2L89X89X6.4X9.5','2L89X89X6.4X9.5'), 
+('2L3-1/2X3-1/2X1/4X3/4','2L3-1/2X3-1/2X1/4X3/4','2L89X89X6.4X19','2L89X89X6.4X19'), 
+('2L3X3X1/2','2L3X3X1/2','2L76X76X12.7','2L76X76X12.7'), 
+('2L3X3X1/2X3/8','2L3X3X1/2X3/8','2L76X76X12.7X9.5','2L76X76X12.7X9.5'), 
+('2L3X3X1/2X3/4','2L3X3X1/2X3/4','2L76X76X12.7X19','2L76X76X12.7X19'), 
+('2L3X3X7/16','2L3X3X7/16','2L76X76X11.1','2L76X76X11.1'), 
+('2L3X3X7/16X3/8','2L3X3X7/16X3/8','2L76X76X11.1X9.5','2L76X76X11.1X9.5'), 
+('2L3X3X7/16X3/4','2L3X3X7/16X3/4','2L76X76X11.1X19','2L76X76X11.1X19'), 
+('2L3X3X3/8','2L3X3X3/8','2L76X76X9.5','2L76X76X9.5'), 
+('2L3X3X3/8X3/8','2L3X3X3/8X3/8','2L76X76X9.5X9.5','2L76X76X9.5X9.5'), 
+('2L3X3X3/8X3/4','2L3X3X3/8X3/4','2L76X76X9.5X19','2L76X76X9.5X19'), 
+('2L3X3X5/16','2L3X3X5/16','2L76X76X7.9','2L76X76X7.9'), 
+('2L3X3X5/16X3/8','2L3X3X5/16X3/8','2L76X76X7.9X9.5','2L76X76X7.9X9.5'), 
+('2L3X3X5/16X3/4','2L3X3X5/16X3/4','2L76X76X7.9X19','2L76X76X7.9X19'), 
+('2L3X3X1/4','2L3X3X1/4','2L76X76X6.4','2L76X76X6.4'), 
+('2L3X3X1/4X3/8','2L3X3X1/4X3/8','2L76X76X6.4X9.5','2L76X76X6.4X9.5'), 
+('2L3X3X1/4X3/4','2L3X3X1/4X3/4','2L76X76X6.4X19','2L76X76X6.4X19'), 
+('2L3X3X3/16','2L3X3X3/16','2L76X76X4.8','2L76X76X4.8'), 
+('2L3X3X3/16X3/8','2L3X3X3/16X3/8','2L76X76X4.8X9.5','2L76X76X4.8X9.5'), 
+('2L3X3X3/16X3/4','2L3X3X3/16X3/4','2L76X76X4.8X19','2L76X76X4.8X19'), 
+('2L2-1/2X2-1/2X1/2','2L2-1/2X2-1/2X1/2','2L64X64X12.7','2L64X64X12.7'), 
+('2L2-1/2X2-1/2X1/2X3/8','2L2-1/2X2-1/2X1/2X3/8','2L64X64X12.7X9.5','2L64X64X12.7X9.5'), 
+('2L2-1/2X2-1/2X1/2X3/4','2L2-1/2X2-1/2X1/2X3/4','2L64X64X12.7X19','2L64X64X12.7X19'), 
+('2L2-1/2X2-1/2X3/8','2L2-1/2X2-1/2X3/8','2L64X64X9.5','2L64X64X9.5'), 
+('2L2-1/2X2-1/2X3/8X3/8','2L2-1/2X2-1/2X3/8X3/8','2L64X64X9.5X9.5','2L64X64X9.5X9.5'), 
+('2L2-1/2X2-1/2X3/8X3/4','2L2-1/2X2-1/2X3/8X3/4','2L64X64X9.5X19','2L64X64X9.5X19'), 
+('2L2-1/2X2-1/2X5/16','2L2-1/2X2-1/2X5/16','2L64X64X7.9','2L64X64X7.9'), 
+('2L2-1/2X2-1/2X5/16X3/8','2L2-1/2X2-1/2X5/16X3/8','2L64X64X7.9X9.5','2L64X64X7.9X9.5'), 
+('2L2-1/2X2-1/2X5/16X3/4','2L2-1/2X2-1/2X5/16X3/4','2L64X64X7.9X19','2L64X64X7.9X19'), 
+('2L2-1/2X2-1/2X1/4','2L2-1/2X2-1/2X1/4','2L64X64X6.4','2L64X64X6.4'), 
+('2L2-1/2X2-1/2X1/4X3/8','2L2-1/2X2-1/2X1/4X3/8','2L64X64X6.4X9.5','2L64X64X6.4X9.5'), 
+('2L2-1/2X2-1/2X1/4X3/4','2L2-1/2X2-1/2X1/4X3/4','2L64X64X6.4X19','2L64X64X6.4X19'), 
+('2L2-1/2X2-1/2X3/16','2L2-1/2X2-1/2X3/16','2L64X64X4.8','2L64X64X4.8'), 
+('2L2-1/2X2-1/2X3/16X3/8','2L2-1/2X2-1/2X3/16X3/8','2L64X64X4.8X9.5','2L64X64X4.8X9.5'), 
+('2L2-1/2X2-1/2X3/16X3/4','2L2-1/2X2-1/2X3/16X3/4','2L64X64X4.8X19','2L64X64X4.8X19'), 
+('2L2X2X3/8','2L2X2X3/8','2L51X51X9.5','2L51X51X9.5'), 
+('2L2X2X3/8X3/8','2L2X2X3/8X3/8','2L51X51X9.5X9.5','2L51X51X9.5X9.5'), 
+('2L2X2X3/8X3/4','2L2X2X3/8X3/4','2L51X51X9.5X19','2L51X51X9.5X19'), 
+('2L2X2X5/16','2L2X2X5/16','2L51X51X7.9','2L51X51X7.9'), 
+('2L2X2X5/16X3/8','2L2X2X5/16X3/8','2L51X51X7.9X9.5','2L51X51X7.9X9.5'), 
+('2L2X2X5/16X3/4','2L2X2X5/16X3/4','2L51X51X7.9X19','2L51X51X7.9X19'), 
+('2L2X2X1/4','2L2X2X1/4','2L51X51X6.4','2L51X51X6.4'), 
+('2L2X2X1/4X3/8','2L2X2X1/4X3/8','2L51X51X6.4X9.5','2L51X51X6.4X9.5'), 
+('2L2X2X1/4X3/4','2L2X2X1/4X3/4','2L51X51X6.4X19','2L51X51X6.4X19'), 
+('2L2X2X3/16','2L2X2X3/16','2L51X51X4.8','2L51X51X4.8'), 
+('2L2X2X3/16X3/8','2L2X2X3/16X3/8','2L51X51X4.8X9.5','2L51X51X4.8X9.5'), 
+('2L2X2X3/16X3/4','2L2X2X3/16X3/4','2L51X51X4.8X19','2L51X51X4.8X19'), 
+('2L2X2X1/8','2L2X2X1/8','2L51X51X3.2','2L51X51X3.2'), 
+('2L2X2X1/8X3/8','2L2X2X1/8X3/8','2L51X51X3.2X9.5','2L51X51X3.2X9.5'), 
+('2L2X2X1/8X3/4','2L2X2X1/8X3/4','2L51X51X3.2X19','2L51X51X3.2X19'), 
+('2L8X6X1LLBB','2L8X6X1LLBB','2L203X152X25.4LLBB','2L203X152X25.4LLBB'), 
+('2L8X6X1X3/8LLBB','2L8X6X1X3/8LLBB','2L203X152X25.4X9.5LLBB','2L203X152X25.4X9.5LLBB'), 
+('2L8X6X1X3/4LLBB','2L8X6X1X3/4LLBB','2L203X152X25.4X19LLBB','2L203X152X25.4X19LLBB'), 
+('2L8X6X7/8LLBB','2L8X6X7/8LLBB','2L203X152X22.2LLBB','2L203X152X22.2LLBB'), 
+('2L8X6X7/8X3/8LLBB','2L8X6X7/8X3/8LLBB','2L203X152X22.2X9.5LLBB','2L203X152X22.2X9.5LLBB'), 
+('2L8X6X7/8X3/4LLBB','2L8X6X7/8X3/4LLBB','2L203X152X22.2X19LLBB','2L203X152X22.2X19LLBB'), 
+('2L8X6X3/4LLBB','2L8X6X3/4LLBB','2L203X152X19LLBB','2L203X152X19LLBB'), 
+('2L8X6X3/4X3/8LLBB','2L8X6X3/4X3/8LLBB','2L203X152X19X9.5LLBB','2L203X152X19X9.5LLBB'), 
+('2L8X6X3/4X3/4LLBB','2L8X6X3/4X3/4LLBB','2L203X152X19X19LLBB','2L203X152X19X19LLBB'), 
+('2L8X6X5/8LLBB','2L8X6X5/8LLBB','2L203X152X15.9LLBB','2L203X152X15.9LLBB'), 
+('2L8X6X5/8X3/8LLBB','2L8X6X5/8X3/8LLBB','2L203X152X15.9X9.5LLBB','2L203X152X15.9X9.5LLBB'), 
+('2L8X6X5/8X3/4LLBB','2L8X6X5/8X3/4LLBB','2L203X152X15.9X19LLBB','2L203X152X15.9X19LLBB'), 
+('2L8X6X9/16LLBB','2L8X6X9/16LLBB','2L203X152X14.3LLBB','2L203X152X14.3LLBB'), 
+('2L8X6X9/16X3/8LLBB','2L8X6X9/16X3/8LLBB','2L203X152X14.3X9.5LLBB','2L203X152X14.3X9.5LLBB'), 
+('2L8X6X9/16X3/4LLBB','2L8X6X9/16X3/4LLBB','2L203X152X14.3X19LLBB','2L203X152X14.3X19LLBB'), 
+('2L8X6X1/2LLBB','2L8X6X1/2LLBB','2L203X152X12.7LLBB','2L203X152X12.7LLBB'), 
+('2L8X6X1/2X3/8LLBB','2L8X6X1/2X3/8LLBB','2L203X152X12.7X9.5LLBB','2L203X152X12.7X9.5LLBB'), 
+('2L8X6X1/2X3/4LLBB','2L8X6X1/2X3/4LLBB','2L203X152X12.7X19LLBB','2L203X152X12.7X19LLBB'), 
+('2L8X6X7/16LLBB','2L8X6X7/16LLBB','2L203X152X11.1LLBB','2L203X152X11.1LLBB'), 
+('2L8X6X7/16X3/8LLBB','2L8X6X7/16X3/8LLBB','2L203X152X11.1X9.5LLBB','2L203X152X11.1X9.5LLBB'), 
+('2L8X6X7/16X3/4LLBB','2L8X6X7/16X3/4LLBB','2L203X152X11.1X19LLBB','2L203X152X11.1X19LLBB'), 
+('2L8X4X1LLBB','2L8X4X1LLBB','2L203X102X25.4LLBB','2L203X102X25.4LLBB'), 
+('2L8X4X1X3/8LLBB','2L8X4X1X3/8LLBB','2L203X102X25.4X9.5LLBB','2L203X102X25.4X9.5LLBB'), 
+('2L8X4X1X3/4LLBB','2L8X4X1X3/4LLBB','2L203X102X25.4X19LLBB','2L203X102X25.4X19LLBB'), 
+('2L8X4X7/8LLBB','2L8X4X7/8LLBB','2L203X102X22.2LLBB','2L203X102X22.2LLBB'), 
+('2L8X4X7/8X3/8LLBB','2L8X4X7/8X3/8LLBB','2L203X102X22.2X9.5LLBB','2L203X102X22.2X9.5LLBB'), 
+('2L8X4X7/8X3/4LLBB','2L8X4X7/8X3/4LLBB','2L203X102X22.2X19LLBB','2L203X102X22.2X19LLBB'), 
+('2L8X4X3/4LLBB','2L8X4X3/4LLBB','2L203X102X19LLBB','2L203X102X19LLBB'), 
+('2L8X4X3/4X3/8LLBB','2L8X4X3/4X3/8LLBB','2L203X102X19X9.5LLBB','2L203X102X19X9.5LLBB'), 
+('2L8X4X3/4X3/4LLBB','2L8X4X3/4X3/4LLBB','2L203X102X19X19LLBB','2L203X102X19X19LLBB'), 
+('2L8X4X5/8LLBB','2L8X4X5/8LLBB','2L203X102X15.9LLBB','2L203X102X15.9LLBB'), 
+('2L8X4X5/8X3/8LLBB','2L8X4X5/8X3/8LLBB','2L203X102X15.9X9.5LLBB','2L203X102X15.9X9.5LLBB'), 
+('2L8X4X5/8X3/4LLBB','2L8X4X5/8X3/4LLBB','2L203X102X15.9X19LLBB','2L203X102X15.9X19LLBB'), 
+('2L8X4X9/16LLBB','2L8X4X9/16LLBB','2L203X102X14.3LLBB','2L203X102X14.3LLBB'), 
+('2L8X4X9/16X3/8LLBB','2L8X4X9/16X3/8LLBB','2L203X102X14.3X9.5LLBB','2L203X102X14.3X9.5LLBB'), 
+('2L8X4X9/16X3/4LLBB','2L8X4X9/16X3/4LLBB','2L203X102X14.3X19LLBB','2L203X102X14.3X19LLBB'), 
+('2L8X4X1/2LLBB','2L8X4X1/2LLBB','2L203X102X12.7LLBB','2L203X102X12.7LLBB'), 
+('2L8X4X1/2X3/8LLBB','2L8X4X1/2X3/8LLBB','2L203X102X12.7X9.5LLBB','2L203X102X12.7X9.5LLBB'), 
+('2L8X4X1/2X3/4LLBB','2L8X4X1/2X3/4LLBB','2L203X102X12.7X19LLBB','2L203X102X12.7X19LLBB'), 
+('2L8X4X7/16LLBB','2L8X4X7/16LLBB','2L203X102X11.1LLBB','2L203X102X11.1LLBB'), 
+('2L8X4X7/16X3/8LLBB','2L8X4X7/16X3/8LLBB','2L203X102X11.1X9.5LLBB','2L203X102X11.1X9.5LLBB'), 
+('2L8X4X7/16X3/4LLBB','2L8X4X7/16X3/4LLBB','2L203X102X11.1X19LLBB','2L203X102X11.1X19LLBB'), 
+('2L7X4X3/4LLBB','2L7X4X3/4LLBB','2L178X102X19LLBB','2L178X102X19LLBB'), 
+('2L7X4X3/4X3/8LLBB','2L7X4X3/4X3/8LLBB','2L178X102X19X9.5LLBB','2L178X102X19X9.5LLBB'), 
+('2L7X4X3/4X3/4LLBB','2L7X4X3/4X3/4LLBB','2L178X102X19X19LLBB','2L178X102X19X19LLBB'), 
+('2L7X4X5/8LLBB','2L7X4X5/8LLBB','2L178X102X15.9LLBB','2L178X102X15.9LLBB'), 
+('2L7X4X5/8X3/8LLBB','2L7X4X5/8X3/8LLBB','2L178X102X15.9X9.5LLBB','2L178X102X15.9X9.5LLBB'), 
+('2L7X4X5/8X3/4LLBB','2L7X4X5/8X3/4LLBB','2L178X102X15.9X19LLBB','2L178X102X15.9X19LLBB'), 
+('2L7X4X1/2LLBB','2L7X4X1/2LLBB','2L178X102X12.7LLBB','2L178X102X12.7LLBB'), 
+('2L7X4X1/2X3/8LLBB','2L7X4X1/2X3/8LLBB','2L178X102X12.7X9.5LLBB','2L178X102X12.7X9.5LLBB'), 
+('2L7X4X1/2X3/4LLBB','2L7X4X1/2X3/4LLBB','2L178X102X12.7X19LLBB','2L178X102X12.7X19LLBB'), 
+('2L7X4X7/16LLBB','2L7X4X7/16LLBB','2L178X102X11.1LLBB','2L178X102X11.1LLBB'), 
+('2L7X4X7/16X3/8LLBB','2L7X4X7/16X3/8LLBB','2L178X102X11.1X9.5LLBB','2L178X102X11.1X9.5LLBB'), 
+('2L7X4X7/16X3/4LLBB','2L7X4X7/16X3/4LLBB','2L178X102X11.1X19LLBB','2L178X102X11.1X19LLBB'), 
+('2L7X4X3/8LLBB','2L7X4X3/8LLBB','2L178X102X9.5LLBB','2L178X102X9.5LLBB'), 
+('2L7X4X3/8X3/8LLBB','2L7X4X3/8X3/8LLBB','2L178X102X9.5X9.5LLBB','2L178X102X9.5X9.5LLBB'), 
+('2L7X4X3/8X3/4LLBB','2L7X4X3/8X3/4LLBB','2L178X102X9.5X19LLBB','2L178X102X9.5X19LLBB'), 
+('2L6X4X7/8LLBB','2L6X4X7/8LLBB','2L152X102X22.2LLBB','2L152X102X22.2LLBB'), 
+('2L6X4X7/8X3/8LLBB','2L6X4X7/8X3/8LLBB','2L152X102X22.2X9.5LLBB','2L152X102X22.2X9.5LLBB'), 
+('2L6X4X7/8X3/4LLBB','2L6X4X7/8X3/4LLBB','2L152X102X22.2X19LLBB','2L152X102X22.2X19LLBB'), 
+('2L6X4X3/4LLBB','2L6X4X3/4LLBB','2L152X102X19LLBB','2L152X102X19LLBB'), 
+('2L6X4X3/4X3/8LLBB','2L6X4X3/4X3/8LLBB','2L152X102X19X9.5LLBB','2L152X102X19X9.5LLBB'), 
+('2L6X4X3/4X3/4LLBB','2L6X4X3/4X3/4LLBB','2L152X102X19X19LLBB','2L152X102X19X19LLBB'), 
+('2L6X4X5/8LLBB','2L6X4X5/8LLBB','2L152X102X15.9LLBB','2L152X102X15.9LLBB'), 
+('2L6X4X5/8X3/8LLBB','2L6X4X5/8X3/8LLBB','2L152X102X15.9X9.5LLBB','2L152X102X15.9X9.5LLBB'), 
+('2L6X4X5/8X3/4LLBB','2L6X4X5/8X3/4LLBB','2L152X102X15.9X19LLBB','2L152X102X15.9X19LLBB'), 
+('2L6X4X9/16LLBB','2L6X4X9/16LLBB','2L152X102X14.3LLBB','2L152X102X14.3LLBB'), 
+('2L6X4X9/16X3/8LLBB','2L6X4X9/16X3/8LLBB','2L152X102X14.3X9.5LLBB','2L152X102X14.3X9.5LLBB'), 
+('2L6X4X9/16X3/4LLBB','2L6X4X9/16X3/4LLBB','2L152X102X14.3X19LLBB','2L152X102X14.3X19LLBB'), 
+('2L6X4X1/2LLBB','2L6X4X1/2LLBB','2L152X102X12.7LLBB','2L152X102X12.7LLBB'), 
+('2L6X4X1/2X3/8LLBB','2L6X4X1/2X3/8LLBB','2L152X102X12.7X9.5LLBB','2L152X102X12.7X9.5LLBB'), 
+('2L6X4X1/2X3/4LLBB','2L6X4X1/2X3/4LLBB','2L152X102X12.7X19LLBB','2L152X102X12.7X19LLBB'), 
+('2L6X4X7/16LLBB','2L6X4X7/16LLBB','2L152X102X11.1LLBB','2L152X102X11.1LLBB'), 
+('2L6X4X7/16X3/8LLBB','2L6X4X7/16X3/8LLBB','2L152X102X11.1X9.5LLBB','2L152X102X11.1X9.5LLBB'), 
+('2L6X4X7/16X3/4LLBB','2L6X4X7/16X3/4LLBB','2L152X102X11.1X19LLBB','2L152X102X11.1X19LLBB'), 
+('2L6X4X3/8LLBB','2L6X4X3/8LLBB','2L152X102X9.5LLBB','2L152X102X9.5LLBB'), 
+('2L6X4X3/8X3/8LLBB','2L6X4X3/8X3/8LLBB','2L152X102X9.5X9.5LLBB','2L152X102X9.5X9.5LLBB'), 
+('2L6X4X3/8X3/4LLBB','2L6X4X3/8X3/4LLBB','2L152X102X9.5X19LLBB','2L152X102X9.5X19LLBB'), 
+('2L6X4X5/16LLBB','2L6X4X5/16LLBB','2L152X102X7.9LLBB','2L152X102X7.9LLBB'), 
+('2L6X4X5/16X3/8LLBB','2L6X4X5/16X3/8LLBB','2L152X102X7.9X9.5LLBB','2L152X102X7.9X9.5LLBB'), 
+('2L6X4X5/16X3/4LLBB','2L6X4X5/16X3/4LLBB','2L152X102X7.9X19LLBB','2L152X102X7.9X19LLBB'), 
+('2L6X3-1/2X1/2LLBB','2L6X3-1/2X1/2LLBB','2L152X89X12.7LLBB','2L152X89X12.7LLBB'), 
+('2L6X3-1/2X1/2X3/8LLBB','2L6X3-1/2X1/2X3/8LLBB','2L152X89X12.7X9.5LLBB','2L152X89X12.7X9.5LLBB'), 
+('2L6X3-1/2X1/2X3/4LLBB','2L6X3-1/2X1/2X3/4LLBB','2L152X89X12.7X19LLBB','2L152X89X12.7X19LLBB'), 
+('2L6X3-1/2X3/8LLBB','2L6X3-1/2X3/8LLBB','2L152X89X9.5LLBB','2L152X89X9.5LLBB'), 
+('2L6X3-1/2X3/8X3/8LLBB','2L6X3-1/2X3/8X3/8LLBB','2L152X89X9.5X9.5LLBB','2L152X89X9.5X9.5LLBB'), 
+('2L6X3-1/2X3/8X3/4LLBB','2L6X3-1/2X3/8X3/4LLBB','2L152X89X9.5X19LLBB','2L152X89X9.5X19LLBB'), 
+('2L6X3-1/2X5/16LLBB','2L6X3-1/2X5/16LLBB','2L152X89X7.9LLBB','2L152X89X7.9LLBB'), 
+('2L6X3-1/2X5/16X3/8LLBB','2L6X3-1/2X5/16X3/8LLBB','2L152X89X7.9X9.5LLBB','2L152X89X7.9X9.5LLBB'), 
+('2L6X3-1/2X5/16X3/4LLBB','2L6X3-1/2X5/16X3/4LLBB','2L152X89X7.9X19LLBB','2L152X89X7.9X19LLBB'), 
+('2L5X3-1/2X3/4LLBB','2L5X3-1/2X3/4LLBB','2L127X89X19LLBB','2L127X89X19LLBB'), 
+('2L5X3-1/2X3/4X3/8LLBB','2L5X3-1/2X3/4X3/8LLBB','2L127X89X19X9.5LLBB','2L127X89X19X9.5LLBB'), 
+('2L5X3-1/2X3/4X3/4LLBB','2L5X3-1/2X3/4X3/4LLBB','2L127X89X19X19LLBB','2L127X89X19X19LLBB'), 
+('2L5X3-1/2X5/8LLBB','2L5X3-1/2X5/8LLBB','2L127X89X15.9LLBB','2L127X89X15.9LLBB'), 
+('2L5X3-1/2X5/8X3/8LLBB','2L5X3-1/2X5/8X3/8LLBB','2L127X89X15.9X9.5LLBB','2L127X89X15.9X9.5LLBB'), 
+('2L5X3-1/2X5/8X3/4LLBB','2L5X3-1/2X5/8X3/4LLBB','2L127X89X15.9X19LLBB','2L127X89X15.9X19LLBB'), 
+('2L5X3-1/2X1/2LLBB','2L5X3-1/2X1/2LLBB','2L127X89X12.7LLBB','2L127X89X12.7LLBB'), 
+('2L5X3-1/2X1/2X3/8LLBB','2L5X3-1/2X1/2X3/8LLBB','2L127X89X12.7X9.5LLBB','2L127X89X12.7X9.5LLBB'), 
+('2L5X3-1/2X1/2X3/4LLBB','2L5X3-1/2X1/2X3/4LLBB','2L127X89X12.7X19LLBB','2L127X89X12.7X19LLBB'), 
+('2L5X3-1/2X3/8LLBB','2L5X3-1/2X3/8LLBB','2L127X89X9.5LLBB','2L127X89X9.5LLBB'), 
+('2L5X3-1/2X3/8X3/8LLBB','2L5X3-1/2X3/8X3/8LLBB','2L127X89X9.5X9.5LLBB','2L127X89X9.5X9.5LLBB'), 
+('2L5X3-1/2X3/8X3/4LLBB','2L5X3-1/2X3/8X3/4LLBB','2L127X89X9.5X19LLBB','2L127X89X9.5X19LLBB'), 
+('2L5X3-1/2X5/16LLBB','2L5X3-1/2X5/16LLBB','2L127X89X7.9LLBB','2L127X89X7.9LLBB'), 
+('2L5X3-1/2X5/16X3/8LLBB','2L5X3-1/2X5/16X3/8LLBB','2L127X89X7.9X9.5LLBB','2L127X89X7.9X9.5LLBB'), 
+('2L5X3-1/2X5/16X3/4LLBB','2L5X3-1/2X5/16X3/4LLBB','2L127X89X7.9X19LLBB','2L127X89X7.9X19LLBB'), 
+('2L5X3-1/2X1/4LLBB','2L5X3-1/2X1/4LLBB','2L127X89X6.4LLBB','2L127X89X6.4LLBB'), 
+('2L5X3-1/2X1/4X3/8LLBB','2L5X3-1/2X1/4X3/8LLBB','2L127X89X6.4X9.5LLBB','2L127X89X6.4X9.5LLBB'), 
+('2L5X3-1/2X1/4X3/4LLBB','2L5X3-1/2X1/4X3/4LLBB','2L127X89X6.4X19LLBB','2L127X89X6.4X19LLBB'), 
+('2L5X3X1/2LLBB','2L5X3X1/2LLBB','2L127X76X12.7LLBB','2L127X76X12.7LLBB'), 
+('2L5X3X1/2X3/8LLBB','2L5X3X1/2X3/8LLBB','2L127X76X12.7X9.5LLBB','2L127X76X12.7X9.5LLBB'), 
+('2L5X3X1/2X3/4LLBB','2L5X3X1/2X3/4LLBB','2L127X76X12.7X19LLBB','2L127X76X12.7X19LLBB'), 
+('2L5X3X7/16LLBB','2L5X3X7/16LLBB','2L127X76X11.1LLBB','2L127X76X11.1LLBB'), 
+('2L5X3X7/16X3/8LLBB','2L5X3X7/16X3/8LLBB','2L127X76X11.1X9.5LLBB','2L127X76X11.1X9.5LLBB'), 
+('2L5X3X7/16X3/4LLBB','2L5X3X7/16X3/4LLBB','2L127X76X11.1X19LLBB','2L127X76X11.1X19LLBB'), 
+('2L5X3X3/8LLBB','2L5X3X3/8LLBB','2L127X76X9.5LLBB','2L127X76X9.5LLBB'), 
+('2L5X3X3/8X3/8LLBB','2L5X3X3/8X3/8LLBB','2L127X76X9.5X9.5LLBB','2L127X76X9.5X9.5LLBB'), 
+('2L5X3X3/8X3/4LLBB','2L5X3X3/8X3/4LLBB','2L127X76X9.5X19LLBB','2L127X76X9.5X19LLBB'), 
+('2L5X3X5/16LLBB','2L5X3X5/16LLBB','2L127X76X7.9LLBB','2L127X76X7.9LLBB'), 
+('2L5X3X5/16X3/8LLBB','2L5X3X5/16X3/8LLBB','2L127X76X7.9X9.5LLBB','2L127X76X7.9X9.5LLBB'), 
+('2L5X3X5/16X3/4LLBB','2L5X3X5/16X3/4LLBB','2L127X76X7.9X19LLBB','2L127X76X7.9X19LLBB'), 
+('2L5X3X1/4LLBB','2L5X3X1/4LLBB','2L127X76X6.4LLBB','2L127X76X6.4LLBB'), 
+('2L5X3X1/4X3/8LLBB','2L5X3X1/4X3/8LLBB','2L127X76X6.4X9.5LLBB','2L127X76X6.4X9.5LLBB'), 
+('2L5X3X1/4X3/4LLBB','2L5X3X1/4X3/4LLBB','2L127X76X6.4X19LLBB','2L127X76X6.4X19LLBB'), 
+('2L4X3-1/2X1/2LLBB','2L4X3-1/2X1/2LLBB','2L102X89X12.7LLBB','2L102X89X12.7LLBB'), 
+('2L4X3-1/2X1/2X3/8LLBB','2L4X3-1/2X1/2X3/8LLBB','2L102X89X12.7X9.5LLBB','2L102X89X12.7X9.5LLBB'), 
+('2L4X3-1/2X1/2X3/4LLBB','2L4X3-1/2X1/2X3/4LLBB','2L102X89X12.7X19LLBB','2L102X89X12.7X19LLBB'), 
+('2L4X3-1/2X3/8LLBB','2L4X3-1/2X3/8LLBB','2L102X89X9.5LLBB','2L102X89X9.5LLBB'), 
+('2L4X3-1/2X3/8X3/8LLBB','2L4X3-1/2X3/8X3/8LLBB','2L102X89X9.5X9.5LLBB','2L102X89X9.5X9.5LLBB'), 
+('2L4X3-1/2X3/8X3/4LLBB','2L4X3-1/2X3/8X3/4LLBB','2L102X89X9.5X19LLBB','2L102X89X9.5X19LLBB'), 
+('2L4X3-1/2X5/16LLBB','2L4X3-1/2X5/16LLBB','2L102X89X7.9LLBB','2L102X89X7.9LLBB'), 
+('2L4X3-1/2X5/16X3/8LLBB','2L4X3-1/2X5/16X3/8LLBB','2L102X89X7.9X9.5LLBB','2L102X89X7.9X9.5LLBB'), 
+('2L4X3-1/2X5/16X3/4LLBB','2L4X3-1/2X5/16X3/4LLBB','2L102X89X7.9X19LLBB','2L102X89X7.9X19LLBB'), 
+('2L4X3-1/2X1/4LLBB','2L4X3-1/2X1/4LLBB','2L102X89X6.4LLBB','2L102X89X6.4LLBB'), 
+('2L4X3-1/2X1/4X3/8LLBB','2L4X3-1/2X1/4X3/8LLBB','2L102X89X6.4X9.5LLBB','2L102X89X6.4X9.5LLBB'), 
+('2L4X3-1/2X1/4X3/4LLBB','2L4X3-1/2X1/4X3/4LLBB','2L102X89X6.4X19LLBB','2L102X89X6.4X19LLBB'), 
+('2L4X3X5/8LLBB','2L4X3X5/8LLBB','2L102X76X15.9LLBB','2L102X76X15.9LLBB'), 
+('2L4X3X5/8X3/8LLBB','2L4X3X5/8X3/8LLBB','2L102X76X15.9X9.5LLBB','2L102X76X15.9X9.5LLBB'), 
+('2L4X3X5/8X3/4LLBB','2L4X3X5/8X3/4LLBB','2L102X76X15.9X19LLBB','2L102X76X15.9X19LLBB'), 
+('2L4X3X1/2LLBB','2L4X3X1/2LLBB','2L102X76X12.7LLBB','2L102X76X12.7LLBB'), 
+('2L4X3X1/2X3/8LLBB','2L4X3X1/2X3/8LLBB','2L102X76X12.7X9.5LLBB','2L102X76X12.7X9.5LLBB'), 
+('2L4X3X1/2X3/4LLBB','2L4X3X1/2X3/4LLBB','2L102X76X12.7X19LLBB','2L102X76X12.7X19LLBB'), 
+('2L4X3X3/8LLBB','2L4X3X3/8LLBB','2L102X76X9.5LLBB','2L102X76X9.5LLBB'), 
+('2L4X3X3/8X3/8LLBB','2L4X3X3/8X3/8LLBB','2L102X76X9.5X9.5LLBB','2L102X76X9.5X9.5LLBB'), 
+('2L4X3X3/8X3/4LLBB','2L4X3X3/8X3/4LLBB','2L102X76X9.5X19LLBB','2L102X76X9.5X19LLBB'), 
+('2L4X3X5/16LLBB','2L4X3X5/16LLBB','2L102X76X7.9LLBB','2L102X76X7.9LLBB'), 
+('2L4X3X5/16X3/8LLBB','2L4X3X5/16X3/8LLBB','2L102X76X7.9X9.5LLBB','2L102X76X7.9X9.5LLBB'), 
+('2L4X3X5/16X3/4LLBB','2L4X3X5/16X3/4LLBB','2L102X76X7.9X19LLBB','2L102X76X7.9X19LLBB'), 
+('2L4X3X1/4LLBB','2L4X3X1/4LLBB','2L102X76X6.4LLBB','2L102X76X6.4LLBB'), 
+('2L4X3X1/4X3/8LLBB','2L4X3X1/4X3/8LLBB','2L102X76X6.4X9.5LLBB','2L102X76X6.4X9.5LLBB'), 
+('2L4X3X1/4X3/4LLBB','2L4X3X1/4X3/4LLBB','2L102X76X6.4X19LLBB','2L102X76X6.4X19LLBB'), 
+('2L3-1/2X3X1/2LLBB','2L3-1/2X3X1/2LLBB','2L89X76X12.7LLBB','2L89X76X12.7LLBB'), 
+('2L3-1/2X3X1/2X3/8LLBB','2L3-1/2X3X1/2X3/8LLBB','2L89X76X12.7X9.5LLBB','2L89X76X12.7X9.5LLBB'), 
+('2L3-1/2X3X1/2X3/4LLBB','2L3-1/2X3X1/2X3/4LLBB','2L89X76X12.7X19LLBB','2L89X76X12.7X19LLBB'), 
+('2L3-1/2X3X7/16LLBB','2L3-1/2X3X7/16LLBB','2L89X76X11.1LLBB','2L89X76X11.1LLBB'), 
+('2L3-1/2X3X7/16X3/8LLBB','2L3-1/2X3X7/16X3/8LLBB','2L89X76X11.1X9.5LLBB','2L89X76X11.1X9.5LLBB'), 
+('2L3-1/2X3X7/16X3/4LLBB','2L3-1/2X3X7/16X3/4LLBB','2L89X76X11.1X19LLBB','2L89X76X11.1X19LLBB'), 
+('2L3-1/2X3X3/8LLBB','2L3-1/2X3X3/8LLBB','2L89X76X9.5LLBB','2L89X76X9.5LLBB'), 
+('2L3-1/2X3X3/8X3/8LLBB','2L3-1/2X3X3/8X3/8LLBB','2L89X76X9.5X9.5LLBB','2L89X76X9.5X9.5LLBB'), 
+('2L3-1/2X3X3/8X3/4LLBB','2L3-1/2X3X3/8X3/4LLBB','2L89X76X9.5X19LLBB','2L89X76X9.5X19LLBB'), 
+('2L3-1/2X3X5/16LLBB','2L3-1/2X3X5/16LLBB','2L89X76X7.9LLBB','2L89X76X7.9LLBB'), 
+('2L3-1/2X3X5/16X3/8LLBB','2L3-1/2X3X5/16X3/8LLBB','2L89X76X7.9X9.5LLBB','2L89X76X7.9X9.5LLBB'), 
+('2L3-1/2X3X5/16X3/4LLBB','2L3-1/2X3X5/16X3/4LLBB','2L89X76X7.9X19LLBB','2L89X76X7.9X19LLBB'), 
+('2L3-1/2X3X1/4LLBB','2L3-1/2X3X1/4LLBB','2L89X76X6.4LLBB','2L89X76X6.4LLBB'), 
+('2L3-1/2X3X1/4X3/8LLBB','2L3-1/2X3X1/4X3/8LLBB','2L89X76X6.4X9.5LLBB','2L89X76X6.4X9.5LLBB'), 
+('2L3-1/2X3X1/4X3/4LLBB','2L3-1/2X3X1/4X3/4LLBB','2L89X76X6.4X19LLBB','2L89X76X6.4X19LLBB'), 
+('2L3-1/2X2-1/2X1/2LLBB','2L3-1/2X2-1/2X1/2LLBB','2L89X64X12.7LLBB','2L89X64X12.7LLBB'), 
+('2L3-1/2X2-1/2X1/2X3/8LLBB','2L3-1/2X2-1/2X1/2X3/8LLBB','2L89X64X12.7X9.5LLBB','2L89X64X12.7X9.5LLBB'), 
+('2L3-1/2X2-1/2X1/2X3/4LLBB','2L3-1/2X2-1/2X1/2X3/4LLBB','2L89X64X12.7X19LLBB','2L89X64X12.7X19LLBB'), 
+('2L3-1/2X2-1/2X3/8LLBB','2L3-1/2X2-1/2X3/8LLBB','2L89X64X9.5LLBB','2L89X64X9.5LLBB'), 
+('2L3-1/2X2-1/2X3/8X3/8LLBB','2L3-1/2X2-1/2X3/8X3/8LLBB','2L89X64X9.5X9.5LLBB','2L89X64X9.5X9.5LLBB'), 
+('2L3-1/2X2-1/2X3/8X3/4LLBB','2L3-1/2X2-1/2X3/8X3/4LLBB','2L89X64X9.5X19LLBB','2L89X64X9.5X19LLBB'), 
+('2L3-1/2X2-1/2X5/16LLBB','2L3-1/2X2-1/2X5/16LLBB','2L89X64X7.9LLBB','2L89X64X7.9LLBB'), 
+('2L3-1/2X2-1/2X5/16X3/8LLBB','2L3-1/2X2-1/2X5/16X3/8LLBB','2L89X64X7.9X9.5LLBB','2L89X64X7.9X9.5LLBB'), 
+('2L3-1/2X2-1/2X5/16X3/4LLBB','2L3-1/2X2-1/2X5/16X3/4LLBB','2L89X64X7.9X19LLBB','2L89X64X7.9X19LLBB'), 
+('2L3-1/2X2-1/2X1/4LLBB','2L3-1/2X2-1/2X1/4LLBB','2L89X64X6.4LLBB','2L89X64X6.4LLBB'), 
+('2L3-1/2X2-1/2X1/4X3/8LLBB','2L3-1/2X2-1/2X1/4X3/8LLBB','2L89X64X6.4X9.5LLBB','2L89X64X6.4X9.5LLBB'), 
+('2L3-1/2X2-1/2X1/4X3/4LLBB','2L3-1/2X2-1/2X1/4X3/4LLBB','2L89X64X6.4X19LLBB','2L89X64X6.4X19LLBB'), 
+('2L3X2-1/2X1/2LLBB','2L3X2-1/2X1/2LLBB','2L76X64X12.7LLBB','2L76X64X12.7LLBB'), 
+('2L3X2-1/2X1/2X3/8LLBB','2L3X2-1/2X1/2X3/8LLBB','2L76X64X12.7X9.5LLBB','2L76X64X12.7X9.5LLBB'), 
+('2L3X2-1/2X1/2X3/4LLBB','2L3X2-1/2X1/2X3/4LLBB','2L76X64X12.7X19LLBB','2L76X64X12.7X19LLBB'), 
+('2L3X2-1/2X7/16LLBB','2L3X2-1/2X7/16LLBB','2L76X64X11.1LLBB','2L76X64X11.1LLBB'), 
+('2L3X2-1/2X7/16X3/8LLBB','2L3X2-1/2X7/16X3/8LLBB','2L76X64X11.1X9.5LLBB','2L76X64X11.1X9.5LLBB'), 
+('2L3X2-1/2X7/16X3/4LLBB','2L3X2-1/2X7/16X3/4LLBB','2L76X64X11.1X19LLBB','2L76X64X11.1X19LLBB'), 
+('2L3X2-1/2X3/8LLBB','2L3X2-1/2X3/8LLBB','2L76X64X9.5LLBB','2L76X64X9.5LLBB'), 
+('2L3X2-1/2X3/8X3/8LLBB','2L3X2-1/2X3/8X3/8LLBB','2L76X64X9.5X9.5LLBB','2L76X64X9.5X9.5LLBB'), 
+('2L3X2-1/2X3/8X3/4LLBB','2L3X2-1/2X3/8X3/4LLBB','2L76X64X9.5X19LLBB','2L76X64X9.5X19LLBB'), 
+('2L3X2-1/2X5/16LLBB','2L3X2-1/2X5/16LLBB','2L76X64X7.9LLBB','2L76X64X7.9LLBB'), 
+('2L3X2-1/2X5/16X3/8LLBB','2L3X2-1/2X5/16X3/8LLBB','2L76X64X7.9X9.5LLBB','2L76X64X7.9X9.5LLBB'), 
+('2L3X2-1/2X5/16X3/4LLBB','2L3X2-1/2X5/16X3/4LLBB','2L76X64X7.9X19LLBB','2L76X64X7.9X19LLBB'), 
+('2L3X2-1/2X1/4LLBB','2L3X2-1/2X1/4LLBB','2L76X64X6.4LLBB','2L76X64X6.4LLBB'), 
+('2L3X2-1/2X1/4X3/8LLBB','2L3X2-1/2X1/4X3/8LLBB','2L76X64X6.4X9.5LLBB','2L76X64X6.4X9.5LLBB'), 
+('2L3X2-1/2X1/4X3/4LLBB','2L3X2-1/2X1/4X3/4LLBB','2L76X64X6.4X19LLBB','2L76X64X6.4X19LLBB'), 
+('2L3X2-1/2X3/16LLBB','2L3X2-1/2X3/16LLBB','2L76X64X4.8LLBB','2L76X64X4.8LLBB'), 
+('2L3X2-1/2X3/16X3/8LLBB','2L3X2-1/2X3/16X3/8LLBB','2L76X64X4.8X9.5LLBB','2L76X64X4.8X9.5LLBB'), 
+('2L3X2-1/2X3/16X3/4LLBB','2L3X2-1/2X3/16X3/4LLBB','2L76X64X4.8X19LLBB','2L76X64X4.8X19LLBB'), 
+('2L3X2X1/2LLBB','2L3X2X1/2LLBB','2L76X51X12.7LLBB','2L76X51X12.7LLBB'), 
+('2L3X2X1/2X3/8LLBB','2L3X2X1/2X3/8LLBB','2L76X51X12.7X9.5LLBB','2L76X51X12.7X9.5LLBB'), 
+('2L3X2X1/2X3/4LLBB','2L3X2X1/2X3/4LLBB','2L76X51X12.7X19LLBB','2L76X51X12.7X19LLBB'), 
+('2L3X2X3/8LLBB','2L3X2X3/8LLBB','2L76X51X9.5LLBB','2L76X51X9.5LLBB'), 
+('2L3X2X3/8X3/8LLBB','2L3X2X3/8X3/8LLBB','2L76X51X9.5X9.5LLBB','2L76X51X9.5X9.5LLBB'), 
+('2L3X2X3/8X3/4LLBB','2L3X2X3/8X3/4LLBB','2L76X51X9.5X19LLBB','2L76X51X9.5X19LLBB'), 
+('2L3X2X5/16LLBB','2L3X2X5/16LLBB','2L76X51X7.9LLBB','2L76X51X7.9LLBB'), 
+('2L3X2X5/16X3/8LLBB','2L3X2X5/16X3/8LLBB','2L76X51X7.9X9.5LLBB','2L76X51X7.9X9.5LLBB'), 
+('2L3X2X5/16X3/4LLBB','2L3X2X5/16X3/4LLBB','2L76X51X7.9X19LLBB','2L76X51X7.9X19LLBB'), 
+('2L3X2X1/4LLBB','2L3X2X1/4LLBB','2L76X51X6.4LLBB','2L76X51X6.4LLBB'), 
+('2L3X2X1/4X3/8LLBB','2L3X2X1/4X3/8LLBB','2L76X51X6.4X9.5LLBB','2L76X51X6.4X9.5LLBB'), 
+('2L3X2X1/4X3/4LLBB','2L3X2X1/4X3/4LLBB','2L76X51X6.4X19LLBB','2L76X51X6.4X19LLBB'), 
+('2L3X2X3/16LLBB','2L3X2X3/16LLBB','2L76X51X4.8LLBB','2L76X51X4.8LLBB'), 
+('2L3X2X3/16X3/8LLBB','2L3X2X3/16X3/8LLBB','2L76X51X4.8X9.5LLBB','2L76X51X4.8X9.5LLBB'), 
+('2L3X2X3/16X3/4LLBB','2L3X2X3/16X3/4LLBB','2L76X51X4.8X19LLBB','2L76X51X4.8X19LLBB'), 
+('2L2-1/2X2X3/8LLBB','2L2-1/2X2X3/8LLBB','2L64X51X9.5LLBB','2L64X51X9.5LLBB'), 
+('2L2-1/2X2X3/8X3/8LLBB','2L2-1/2X2X3/8X3/8LLBB','2L64X51X9.5X9.5LLBB','2L64X51X9.5X9.5LLBB'), 
+('2L2-1/2X2X3/8X3/4LLBB','2L2-1/2X2X3/8X3/4LLBB','2L64X51X9.5X19LLBB','2L64X51X9.5X19LLBB'), 
+('2L2-1/2X2X5/16LLBB','2L2-1/2X2X5/16LLBB','2L64X51X7.9LLBB','2L64X51X7.9LLBB'), 
+('2L2-1/2X2X5/16X3/8LLBB','2L2-1/2X2X5/16X3/8LLBB','2L64X51X7.9X9.5LLBB','2L64X51X7.9X9.5LLBB'), 
+('2L2-1/2X2X5/16X3/4LLBB','2L2-1/2X2X5/16X3/4LLBB','2L64X51X7.9X19LLBB','2L64X51X7.9X19LLBB'), 
+('2L2-1/2X2X1/4LLBB','2L2-1/2X2X1/4LLBB','2L64X51X6.4LLBB','2L64X51X6.4LLBB'), 
+('2L2-1/2X2X1/4X3/8LLBB','2L2-1/2X2X1/4X3/8LLBB','2L64X51X6.4X9.5LLBB','2L64X51X6.4X9.5LLBB'), 
+('2L2-1/2X2X1/4X3/4LLBB','2L2-1/2X2X1/4X3/4LLBB','2L64X51X6.4X19LLBB','2L64X51X6.4X19LLBB'), 
+('2L2-1/2X2X3/16LLBB','2L2-1/2X2X3/16LLBB','2L64X51X4.8LLBB','2L64X51X4.8LLBB'), 
+('2L2-1/2X2X3/16X3/8LLBB','2L2-1/2X2X3/16X3/8LLBB','2L64X51X4.8X9.5LLBB','2L64X51X4.8X9.5LLBB'), 
+('2L2-1/2X2X3/16X3/4LLBB','2L2-1/2X2X3/16X3/4LLBB','2L64X51X4.8X19LLBB','2L64X51X4.8X19LLBB'), 
+('2L2-1/2X1-1/2X1/4LLBB','2L2-1/2X1-1/2X1/4LLBB','2L64X38X6.4LLBB','2L64X38X6.4LLBB'), 
+('2L2-1/2X1-1/2X1/4X3/8LLBB','2L2-1/2X1-1/2X1/4X3/8LLBB','2L64X38X6.4X9.5LLBB','2L64X38X6.4X9.5LLBB'), 
+('2L2-1/2X1-1/2X1/4X3/4LLBB','2L2-1/2X1-1/2X1/4X3/4LLBB','2L64X38X6.4X19LLBB','2L64X38X6.4X19LLBB'), 
+('2L2-1/2X1-1/2X3/16LLBB','2L2-1/2X1-1/2X3/16LLBB','2L64X38X4.8LLBB','2L64X38X4.8LLBB'), 
+('2L2-1/2X1-1/2X3/16X3/8LLBB','2L2-1/2X1-1/2X3/16X3/8LLBB','2L64X38X4.8X9.5LLBB','2L64X38X4.8X9.5LLBB'), 
+('2L2-1/2X1-1/2X3/16X3/4LLBB','2L2-1/2X1-1/2X3/16X3/4LLBB','2L64X38X4.8X19LLBB','2L64X38X4.8X19LLBB'), 
+('2L8X6X1SLBB','2L8X6X1SLBB','2L203X152X25.4SLBB','2L203X152X25.4SLBB'), 
+('2L8X6X1X3/8SLBB','2L8X6X1X3/8SLBB','2L203X152X25.4X9.5SLBB','2L203X152X25.4X9.5SLBB'), 
+('2L8X6X1X3/4SLBB','2L8X6X1X3/4SLBB','2L203X152X25.4X19SLBB','2L203X152X25.4X19SLBB'), 
+('2L8X6X7/8SLBB','2L8X6X7/8SLBB','2L203X152X22.2SLBB','2L203X152X22.2SLBB'), 
+('2L8X6X7/8X3/8SLBB','2L8X6X7/8X3/8SLBB','2L203X152X22.2X9.5SLBB','2L203X152X22.2X9.5SLBB'), 
+('2L8X6X7/8X3/4SLBB','2L8X6X7/8X3/4SLBB','2L203X152X22.2X19SLBB','2L203X152X22.2X19SLBB'), 
+('2L8X6X3/4SLBB','2L8X6X3/4SLBB','2L203X152X19SLBB','2L203X152X19SLBB'), 
+('2L8X6X3/4X3/8SLBB','2L8X6X3/4X3/8SLBB','2L203X152X19X9.5SLBB','2L203X152X19X9.5SLBB'), 
+('2L8X6X3/4X3/4SLBB','2L8X6X3/4X3/4SLBB','2L203X152X19X19SLBB','2L203X152X19X19SLBB'), 
+('2L8X6X5/8SLBB','2L8X6X5/8SLBB','2L203X152X15.9SLBB','2L203X152X15.9SLBB'), 
+('2L8X6X5/8X3/8SLBB','2L8X6X5/8X3/8SLBB','2L203X152X15.9X9.5SLBB','2L203X152X15.9X9.5SLBB'), 
+('2L8X6X5/8X3/4SLBB','2L8X6X5/8X3/4SLBB','2L203X152X15.9X19SLBB','2L203X152X15.9X19SLBB'), 
+('2L8X6X9/16SLBB','2L8X6X9/16SLBB','2L203X152X14.3SLBB','2L203X152X14.3SLBB'), 
+('2L8X6X9/16X3/8SLBB','2L8X6X9/16X3/8SLBB','2L203X152X14.3X9.5SLBB','2L203X152X14.3X9.5SLBB'), 
+('2L8X6X9/16X3/4SLBB','2L8X6X9/16X3/4SLBB','2L203X152X14.3X19SLBB','2L203X152X14.3X19SLBB'), 
+('2L8X6X1/2SLBB','2L8X6X1/2SLBB','2L203X152X12.7SLBB','2L203X152X12.7SLBB'), 
+('2L8X6X1/2X3/8SLBB','2L8X6X1/2X3/8SLBB','2L203X152X12.7X9.5SLBB','2L203X152X12.7X9.5SLBB'), 
+('2L8X6X1/2X3/4SLBB','2L8X6X1/2X3/4SLBB','2L203X152X12.7X19SLBB','2L203X152X12.7X19SLBB'), 
+('2L8X6X7/16SLBB','2L8X6X7/16SLBB','2L203X152X11.1SLBB','2L203X152X11.1SLBB'), 
+('2L8X6X7/16X3/8SLBB','2L8X6X7/16X3/8SLBB','2L203X152X11.1X9.5SLBB','2L203X152X11.1X9.5SLBB'), 
+('2L8X6X7/16X3/4SLBB','2L8X6X7/16X3/4SLBB','2L203X152X11.1X19SLBB','2L203X152X11.1X19SLBB'), 
+('2L8X4X1SLBB','2L8X4X1SLBB','2L203X102X25.4SLBB','2L203X102X25.4SLBB'), 
+('2L8X4X1X3/8SLBB','2L8X4X1X3/8SLBB','2L203X102X25.4X9.5SLBB','2L203X102X25.4X9.5SLBB'), 
+('2L8X4X1X3/4SLBB','2L8X4X1X3/4SLBB','2L203X102X25.4X19SLBB','2L203X102X25.4X19SLBB'), 
+('2L8X4X7/8SLBB','2L8X4X7/8SLBB','2L203X102X22.2SLBB','2L203X102X22.2SLBB'), 
+('2L8X4X7/8X3/8SLBB','2L8X4X7/8X3/8SLBB','2L203X102X22.2X9.5SLBB','2L203X102X22.2X9.5SLBB'), 
+('2L8X4X7/8X3/4SLBB','2L8X4X7/8X3/4SLBB','2L203X102X22.2X19SLBB','2L203X102X22.2X19SLBB'), 
+('2L8X4X3/4SLBB','2L8X4X3/4SLBB','2L203X102X19SLBB','2L203X102X19SLBB'), 
+('2L8X4X3/4X3/8SLBB','2L8X4X3/4X3/8SLBB','2L203X102X19X9.5SLBB','2L203X102X19X9.5SLBB'), 
+('2L8X4X3/4X3/4SLBB','2L8X4X3/4X3/4SLBB','2L203X102X19X19SLBB','2L203X102X19X19SLBB'), 
+('2L8X4X5/8SLBB','2L8X4X5/8SLBB','2L203X102X15.9SLBB','2L203X102X15.9SLBB'), 
+('2L8X4X5/8X3/8SLBB','2L8X4X5/8X3/8SLBB','2L203X102X15.9X9.5SLBB','2L203X102X15.9X9.5SLBB'), 
+('2L8X4X5/8X3/4SLBB','2L8X4X5/8X3/4SLBB','2L203X102X15.9X19SLBB','2L203X102X15.9X19SLBB'), 
+('2L8X4X9/16SLBB','2L8X4X9/16SLBB','2L203X102X14.3SLBB','2L203X102X14.3SLBB'), 
+('2L8X4X9/16X3/8SLBB','2L8X4X9/16X3/8SLBB','2L203X102X14.3X9.5SLBB','2L203X102X14.3X9.5SLBB'), 
+('2L8X4X9/16X3/4SLBB','2L8X4X9/16X3/4SLBB','2L203X102X14.3X19SLBB','2L203X102X14.3X19SLBB'), 
+('2L8X4X1/2SLBB','2L8X4X1/2SLBB','2L203X102X12.7SLBB','2L203X102X12.7SLBB'), 
+('2L8X4X1/2X3/8SLBB','2L8X4X1/2X3/8SLBB','2L203X102X12.7X9.5SLBB','2L203X102X12.7X9.5SLBB'), 
+('2L8X4X1/2X3/4SLBB','2L8X4X1/2X3/4SLBB','2L203X102X12.7X19SLBB','2L203X102X12.7X19SLBB'), 
+('2L8X4X7/16SLBB','2L8X4X7/16SLBB','2L203X102X11.1SLBB','2L203X102X11.1SLBB'), 
+('2L8X4X7/16X3/8SLBB','2L8X4X7/16X3/8SLBB','2L203X102X11.1X9.5SLBB','2L203X102X11.1X9.5SLBB'), 
+('2L8X4X7/16X3/4SLBB','2L8X4X7/16X3/4SLBB','2L203X102X11.1X19SLBB','2L203X102X11.1X19SLBB'), 
+('2L7X4X3/4SLBB','2L7X4X3/4SLBB','2L178X102X19SLBB','2L178X102X19SLBB'), 
+('2L7X4X3/4X3/8SLBB','2L7X4X3/4X3/8SLBB','2L178X102X19X9.5SLBB','2L178X102X19X9.5SLBB'), 
+('2L7X4X3/4X3/4SLBB','2L7X4X3/4X3/4SLBB','2L178X102X19X19SLBB','2L178X102X19X19SLBB'), 
+('2L7X4X5/8SLBB','2L7X4X5/8SLBB','2L178X102X15.9SLBB','2L178X102X15.9SLBB'), 
+('2L7X4X5/8X3/8SLBB','2L7X4X5/8X3/8SLBB','2L178X102X15.9X9.5SLBB','2L178X102X15.9X9.5SLBB'), 
+('2L7X4X5/8X3/4SLBB','2L7X4X5/8X3/4SLBB','2L178X102X15.9X19SLBB','2L178X102X15.9X19SLBB'), 
+('2L7X4X1/2SLBB','2L7X4X1/2SLBB','2L178X102X12.7SLBB','2L178X102X12.7SLBB'), 
+('2L7X4X1/2X3/8SLBB','2L7X4X1/2X3/8SLBB','2L178X102X12.7X9.5SLBB','2L178X102X12.7X9.5SLBB'), 
+('2L7X4X1/2X3/4SLBB','2L7X4X1/2X3/4SLBB','2L178X102X12.7X19SLBB','2L178X102X12.7X19SLBB'), 
+('2L7X4X7/16SLBB','2L7X4X7/16SLBB','2L178X102X11.1SLBB','2L178X102X11.1SLBB'), 
+('2L7X4X7/16X3/8SLBB','2L7X4X7/16X3/8SLBB','2L178X102X11.1X9.5SLBB','2L178X102X11.1X9.5SLBB'), 
+('2L7X4X7/16X3/4SLBB','2L7X4X7/16X3/4SLBB','2L178X102X11.1X19SLBB','2L178X102X11.1X19SLBB'), 
+('2L7X4X3/8SLBB','2L7X4X3/8SLBB','2L178X102X9.5SLBB','2L178X102X9.5SLBB'), 
+('2L7X4X3/8X3/8SLBB','2L7X4X3/8X3/8SLBB','2L178X102X9.5X9.5SLBB','2L178X102X9.5X9.5SLBB'), 
+('2L7X4X3/8X3/4SLBB','2L7X4X3/8X3/4SLBB','2L178X102X9.5X19SLBB','2L178X102X9.5X19SLBB'), 
+('2L6X4X7/8SLBB','2L6X4X7/8SLBB','2L152X102X22.2SLBB','2L152X102X22.2SLBB'), 
+('2L6X4X7/8X3/8SLBB','2L6X4X7/8X3/8SLBB','2L152X102X22.2X9.5SLBB','2L152X102X22.2X9.5SLBB'), 
+('2L6X4X7/8X3/4SLBB','2L6X4X7/8X3/4SLBB','2L152X102X22.2X19SLBB','2L152X102X22.2X19SLBB'), 
+('2L6X4X3/4SLBB','2L6X4X3/4SLBB','2L152X102X19SLBB','2L152X102X19SLBB'), 
+('2L6X4X3/4X3/8SLBB','2L6X4X3/4X3/8SLBB','2L152X102X19X9.5SLBB','2L152X102X19X9.5SLBB'), 
+('2L6X4X3/4X3/4SLBB','2L6X4X3/4X3/4SLBB','2L152X102X19X19SLBB','2L152X102X19X19SLBB'), 
+('2L6X4X5/8SLBB','2L6X4X5/8SLBB','2L152X102X15.9SLBB','2L152X102X15.9SLBB'), 
+('2L6X4X5/8X3/8SLBB','2L6X4X5/8X3/8SLBB','2L152X102X15.9X9.5SLBB','2L152X102X15.9X9.5SLBB'), 
+('2L6X4X5/8X3/4SLBB','2L6X4X5/8X3/4SLBB','2L152X102X15.9X19SLBB','2L152X102X15.9X19SLBB'), 
+('2L6X4X9/16SLBB','2L6X4X9/16SLBB','2L152X102X14.3SLBB','2L152X102X14.3SLBB'), 
+('2L6X4X9/16X3/8SLBB','2L6X4X9/16X3/8SLBB','2L152X102X14.3X9.5SLBB','2L152X102X14.3X9.5SLBB'), 
+('2L6X4X9/16X3/4SLBB','2L6X4X9/16X3/4SLBB','2L152X102X14.3X19SLBB','2L152X102X14.3X19SLBB'), 
+('2L6X4X1/2SLBB','2L6X4X1/2SLBB','2L152X102X12.7SLBB','2L152X102X12.7SLBB'), 
+('2L6X4X1/2X3/8SLBB','2L6X4X1/2X3/8SLBB','2L152X102X12.7X9.5SLBB','2L152X102X12.7X9.5SLBB'), 
+('2L6X4X1/2X3/4SLBB','2L6X4X1/2X3/4SLBB','2L152X102X12.7X19SLBB','2L152X102X12.7X19SLBB'), 
+('2L6X4X7/16SLBB','2L6X4X7/16SLBB','2L152X102X11.1SLBB','2L152X102X11.1SLBB'), 
+('2L6X4X7/16X3/8SLBB','2L6X4X7/16X3/8SLBB','2L152X102X11.1X9.5SLBB','2L152X102X11.1X9.5SLBB'), 
+('2L6X4X7/16X3/4SLBB','2L6X4X7/16X3/4SLBB','2L152X102X11.1X19SLBB','2L152X102X11.1X19SLBB'), 
+('2L6X4X3/8SLBB','2L6X4X3/8SLBB','2L152X102X9.5SLBB','2L152X102X9.5SLBB'), 
+('2L6X4X3/8X3/8SLBB','2L6X4X3/8X3/8SLBB','2L152X102X9.5X9.5SLBB','2L152X102X9.5X9.5SLBB'), 
+('2L6X4X3/8X3/4SLBB','2L6X4X3/8X3/4SLBB','2L152X102X9.5X19SLBB','2L152X102X9.5X19SLBB'), 
+('2L6X4X5/16SLBB','2L6X4X5/16SLBB','2L152X102X7.9SLBB','2L152X102X7.9SLBB'), 
+('2L6X4X5/16X3/8SLBB','2L6X4X5/16X3/8SLBB','2L152X102X7.9X9.5SLBB','2L152X102X7.9X9.5SLBB'), 
+('2L6X4X5/16X3/4SLBB','2L6X4X5/16X3/4SLBB','2L152X102X7.9X19SLBB','2L152X102X7.9X19SLBB'), 
+('2L6X3-1/2X1/2SLBB','2L6X3-1/2X1/2SLBB','2L152X89X12.7SLBB','2L152X89X12.7SLBB'), 
+('2L6X3-1/2X1/2X3/8SLBB','2L6X3-1/2X1/2X3/8SLBB','2L152X89X12.7X9.5SLBB','2L152X89X12.7X9.5SLBB'), 
+('2L6X3-1/2X1/2X3/4SLBB','2L6X3-1/2X1/2X3/4SLBB','2L152X89X12.7X19SLBB','2L152X89X12.7X19SLBB'), 
+('2L6X3-1/2X3/8SLBB','2L6X3-1/2X3/8SLBB','2L152X89X9.5SLBB','2L152X89X9.5SLBB'), 
+('2L6X3-1/2X3/8X3/8SLBB','2L6X3-1/2X3/8X3/8SLBB','2L152X89X9.5X9.5SLBB','2L152X89X9.5X9.5SLBB'), 
+('2L6X3-1/2X3/8X3/4SLBB','2L6X3-1/2X3/8X3/4SLBB','2L152X89X9.5X19SLBB','2L152X89X9.5X19SLBB'), 
+('2L6X3-1/2X5/16SLBB','2L6X3-1/2X5/16SLBB','2L152X89X7.9SLBB','2L152X89X7.9SLBB'), 
+('2L6X3-1/2X5/16X3/8SLBB','2L6X3-1/2X5/16X3/8SLBB','2L152X89X7.9X9.5SLBB','2L152X89X7.9X9.5SLBB'), 
+('2L6X3-1/2X5/16X3/4SLBB','2L6X3-1/2X5/16X3/4SLBB','2L152X89X7.9X19SLBB','2L152X89X7.9X19SLBB'), 
+('2L5X3-1/2X3/4SLBB','2L5X3-1/2X3/4SLBB','2L127X89X19SLBB','2L127X89X19SLBB'), 
+('2L5X3-1/2X3/4X3/8SLBB','2L5X3-1/2X3/4X3/8SLBB','2L127X89X19X9.5SLBB','2L127X89X19X9.5SLBB'), 
+('2L5X3-1/2X3/4X3/4SLBB','2L5X3-1/2X3/4X3/4SLBB','2L127X89X19X19SLBB','2L127X89X19X19SLBB'), 
+('2L5X3-1/2X5/8SLBB','2L5X3-1/2X5/8SLBB','2L127X89X15.9SLBB','2L127X89X15.9SLBB'), 
+('2L5X3-1/2X5/8X3/8SLBB','2L5X3-1/2X5/8X3/8SLBB','2L127X89X15.9X9.5SLBB','2L127X89X15.9X9.5SLBB'), 
+('2L5X3-1/2X5/8X3/4SLBB','2L5X3-1/2X5/8X3/4SLBB','2L127X89X15.9X19SLBB','2L127X89X15.9X19SLBB'), 
+('2L5X3-1/2X1/2SLBB','2L5X3-1/2X1/2SLBB','2L127X89X12.7SLBB','2L127X89X12.7SLBB'), 
+('2L5X3-1/2X1/2X3/8SLBB','2L5X3-1/2X1/2X3/8SLBB','2L127X89X12.7X9.5SLBB','2L127X89X12.7X9.5SLBB'), 
+('2L5X3-1/2X1/2X3/4SLBB','2L5X3-1/2X1/2X3/4SLBB','2L127X89X12.7X19SLBB','2L127X89X12.7X19SLBB'), 
+('2L5X3-1/2X3/8SLBB','2L5X3-1/2X3/8SLBB','2L127X89X9.5SLBB','2L127X89X9.5SLBB'), 
+('2L5X3-1/2X3/8X3/8SLBB','2L5X3-1/2X3/8X3/8SLBB','2L127X89X9.5X9.5SLBB','2L127X89X9.5X9.5SLBB'), 
+('2L5X3-1/2X3/8X3/4SLBB','2L5X3-1/2X3/8X3/4SLBB','2L127X89X9.5X19SLBB','2L127X89X9.5X19SLBB'), 
+('2L5X3-1/2X5/16SLBB','2L5X3-1/2X5/16SLBB','2L127X89X7.9SLBB','2L127X89X7.9SLBB'), 
+('2L5X3-1/2X5/16X3/8SLBB','2L5X3-1/2X5/16X3/8SLBB','2L127X89X7.9X9.5SLBB','2L127X89X7.9X9.5SLBB'), 
+('2L5X3-1/2X5/16X3/4SLBB','2L5X3-1/2X5/16X3/4SLBB','2L127X89X7.9X19SLBB','2L127X89X7.9X19SLBB'), 
+('2L5X3-1/2X1/4SLBB','2L5X3-1/2X1/4SLBB','2L127X89X6.4SLBB','2L127X89X6.4SLBB'), 
+('2L5X3-1/2X1/4X3/8SLBB','2L5X3-1/2X1/4X3/8SLBB','2L127X89X6.4X9.5SLBB','2L127X89X6.4X9.5SLBB'), 
+('2L5X3-1/2X1/4X3/4SLBB','2L5X3-1/2X1/4X3/4SLBB','2L127X89X6.4X19SLBB','2L127X89X6.4X19SLBB'), 
+('2L5X3X1/2SLBB','2L5X3X1/2SLBB','2L127X76X12.7SLBB','2L127X76X12.7SLBB'), 
+('2L5X3X1/2X3/8SLBB','2L5X3X1/2X3/8SLBB','2L127X76X12.7X9.5SLBB','2L127X76X12.7X9.5SLBB'), 
+('2L5X3X1/2X3/4SLBB','2L5X3X1/2X3/4SLBB','2L127X76X12.7X19SLBB','2L127X76X12.7X19SLBB'), 
+('2L5X3X7/16SLBB','2L5X3X7/16SLBB','2L127X76X11.1SLBB','2L127X76X11.1SLBB'), 
+('2L5X3X7/16X3/8SLBB','2L5X3X7/16X3/8SLBB','2L127X76X11.1X9.5SLBB','2L127X76X11.1X9.5SLBB'), 
+('2L5X3X7/16X3/4SLBB','2L5X3X7/16X3/4SLBB','2L127X76X11.1X19SLBB','2L127X76X11.1X19SLBB'), 
+('2L5X3X3/8SLBB','2L5X3X3/8SLBB','2L127X76X9.5SLBB','2L127X76X9.5SLBB'), 
+('2L5X3X3/8X3/8SLBB','2L5X3X3/8X3/8SLBB','2L127X76X9.5X9.5SLBB','2L127X76X9.5X9.5SLBB'), 
+('2L5X3X3/8X3/4SLBB','2L5X3X3/8X3/4SLBB','2L127X76X9.5X19SLBB','2L127X76X9.5X19SLBB'), 
+('2L5X3X5/16SLBB','2L5X3X5/16SLBB','2L127X76X7.9SLBB','2L127X76X7.9SLBB'), 
+('2L5X3X5/16X3/8SLBB','2L5X3X5/16X3/8SLBB','2L127X76X7.9X9.5SLBB','2L127X76X7.9X9.5SLBB'), 
+('2L5X3X5/16X3/4SLBB','2L5X3X5/16X3/4SLBB','2L127X76X7.9X19SLBB','2L127X76X7.9X19SLBB'), 
+('2L5X3X1/4SLBB','2L5X3X1/4SLBB','2L127X76X6.4SLBB','2L127X76X6.4SLBB'), 
+('2L5X3X1/4X3/8SLBB','2L5X3X1/4X3/8SLBB','2L127X76X6.4X9.5SLBB','2L127X76X6.4X9.5SLBB'), 
+('2L5X3X1/4X3/4SLBB','2L5X3X1/4X3/4SLBB','2L127X76X6.4X19SLBB','2L127X76X6.4X19SLBB'), 
+('2L4X3-1/2X1/2SLBB','2L4X3-1/2X1/2SLBB','2L102X89X12.7SLBB','2L102X89X12.7SLBB'), 
+('2L4X3-1/2X1/2X3/8SLBB','2L4X3-1/2X1/2X3/8SLBB','2L102X89X12.7X9.5SLBB','2L102X89X12.7X9.5SLBB'), 
+('2L4X3-1/2X1/2X3/4SLBB','2L4X3-1/2X1/2X3/4SLBB','2L102X89X12.7X19SLBB','2L102X89X12.7X19SLBB'), 
+('2L4X3-1/2X3/8SLBB','2L4X3-1/2X3/8SLBB','2L102X89X9.5SLBB','2L102X89X9.5SLBB'), 
+('2L4X3-1/2X3/8X3/8SLBB','2L4X3-1/2X3/8X3/8SLBB','2L102X89X9.5X9.5SLBB','2L102X89X9.5X9.5SLBB'), 
+('2L4X3-1/2X3/8X3/4SLBB','2L4X3-1/2X3/8X3/4SLBB','2L102X89X9.5X19SLBB','2L102X89X9.5X19SLBB'), 
+('2L4X3-1/2X5/16SLBB','2L4X3-1/2X5/16SLBB','2L102X89X7.9SLBB','2L102X89X7.9SLBB'), 
+('2L4X3-1/2X5/16X3/8SLBB','2L4X3-1/2X5/16X3/8SLBB','2L102X89X7.9X9.5SLBB','2L102X89X7.9X9.5SLBB'), 
+('2L4X3-1/2X5/16X3/4SLBB','2L4X3-1/2X5/16X3/4SLBB','2L102X89X7.9X19SLBB','2L102X89X7.9X19SLBB'), 
+('2L4X3-1/2X1/4SLBB','2L4X3-1/2X1/4SLBB','2L102X89X6.4SLBB','2L102X89X6.4SLBB'), 
+('2L4X3-1/2X1/4X3/8SLBB','2L4X3-1/2X1/4X3/8SLBB','2L102X89X6.4X9.5SLBB','2L102X89X6.4X9.5SLBB'), 
+('2L4X3-1/2X1/4X3/4SLBB','2L4X3-1/2X1/4X3/4SLBB','2L102X89X6.4X19SLBB','2L102X89X6.4X19SLBB'), 
+('2L4X3X5/8SLBB','2L4X3X5/8SLBB','2L102X76X15.9SLBB','2L102X76X15.9SLBB'), 
+('2L4X3X5/8X3/8SLBB','2L4X3X5/8X3/8SLBB','2L102X76X15.9X9.5SLBB','2L102X76X15.9X9.5SLBB'), 
+('2L4X3X5/8X3/4SLBB','2L4X3X5/8X3/4SLBB','2L102X76X15.9X19SLBB','2L102X76X15.9X19SLBB'), 
+('2L4X3X1/2SLBB','2L4X3X1/2SLBB','2L102X76X12.7SLBB','2L102X76X12.7SLBB'), 
+('2L4X3X1/2X3/8SLBB','2L4X3X1/2X3/8SLBB','2L102X76X12.7X9.5SLBB','2L102X76X12.7X9.5SLBB'), 
+('2L4X3X1/2X3/4SLBB','2L4X3X1/2X3/4SLBB','2L102X76X12.7X19SLBB','2L102X76X12.7X19SLBB'), 
+('2L4X3X3/8SLBB','2L4X3X3/8SLBB','2L102X76X9.5SLBB','2L102X76X9.5SLBB'), 
+('2L4X3X3/8X3/8SLBB','2L4X3X3/8X3/8SLBB','2L102X76X9.5X9.5SLBB','2L102X76X9.5X9.5SLBB'), 
+('2L4X3X3/8X3/4SLBB','2L4X3X3/8X3/4SLBB','2L102X76X9.5X19SLBB','2L102X76X9.5X19SLBB'), 
+('2L4X3X5/16SLBB','2L4X3X5/16SLBB','2L102X76X7.9SLBB','2L102X76X7.9SLBB'), 
+('2L4X3X5/16X3/8SLBB','2L4X3X5/16X3/8SLBB','2L102X76X7.9X9.5SLBB','2L102X76X7.9X9.5SLBB'), 
+('2L4X3X5/16X3/4SLBB','2L4X3X5/16X3/4SLBB','2L102X76X7.9X19SLBB','2L102X76X7.9X19SLBB'), 
+('2L4X3X1/4SLBB','2L4X3X1/4SLBB','2L102X76X6.4SLBB','2L102X76X6.4SLBB'), 
+('2L4X3X1/4X3/8SLBB','2L4X3X1/4X3/8SLBB','2L102X76X6.4X9.5SLBB','2L102X76X6.4X9.5SLBB'), 
+('2L4X3X1/4X3/4SLBB','2L4X3X1/4X3/4SLBB','2L102X76X6.4X19SLBB','2L102X76X6.4X19SLBB'), 
+('2L3-1/2X3X1/2SLBB','2L3-1/2X3X1/2SLBB','2L89X76X12.7SLBB','2L89X76X12.7SLBB'), 
+('2L3-1/2X3X1/2X3/8SLBB','2L3-1/2X3X1/2X3/8SLBB','2L89X76X12.7X9.5SLBB','2L89X76X12.7X9.5SLBB'), 
+('2L3-1/2X3X1/2X3/4SLBB','2L3-1/2X3X1/2X3/4SLBB','2L89X76X12.7X19SLBB','2L89X76X12.7X19SLBB'), 
+('2L3-1/2X3X7/16SLBB','2L3-1/2X3X7/16SLBB','2L89X76X11.1SLBB','2L89X76X11.1SLBB'), 
+('2L3-1/2X3X7/16X3/8SLBB','2L3-1/2X3X7/16X3/8SLBB','2L89X76X11.1X9.5SLBB','2L89X76X11.1X9.5SLBB'), 
+('2L3-1/2X3X7/16X3/4SLBB','2L3-1/2X3X7/16X3/4SLBB','2L89X76X11.1X19SLBB','2L89X76X11.1X19SLBB'), 
+('2L3-1/2X3X3/8SLBB','2L3-1/2X3X3/8SLBB','2L89X76X9.5SLBB','2L89X76X9.5SLBB'), 
+('2L3-1/2X3X3/8X3/8SLBB','2L3-1/2X3X3/8X3/8SLBB','2L89X76X9.5X9.5SLBB','2L89X76X9.5X9.5SLBB'), 
+('2L3-1/2X3X3/8X3/4SLBB','2L3-1/2X3X3/8X3/4SLBB','2L89X76X9.5X19SLBB','2L89X76X9.5X19SLBB'), 
+('2L3-1/2X3X5/16SLBB','2L3-1/2X3X5/16SLBB','2L89X76X7.9SLBB','2L89X76X7.9SLBB'), 
+('2L3-1/2X3X5/16X3/8SLBB','2L3-1/2X3X5/16X3/8SLBB','2L89X76X7.9X9.5SLBB','2L89X76X7.9X9.5SLBB'), 
+('2L3-1/2X3X5/16X3/4SLBB','2L3-1/2X3X5/16X3/4SLBB','2L89X76X7.9X19SLBB','2L89X76X7.9X19SLBB'), 
+('2L3-1/2X3X1/4SLBB','2L3-1/2X3X1/4SLBB','2L89X76X6.4SLBB','2L89X76X6.4SLBB'), 
+('2L3-1/2X3X1/4X3/8SLBB','2L3-1/2X3X1/4X3/8SLBB','2L89X76X6.4X9.5SLBB','2L89X76X6.4X9.5SLBB'), 
+('2L3-1/2X3X1/4X3/4SLBB','2L3-1/2X3X1/4X3/4SLBB','2L89X76X6.4X19SLBB','2L89X76X6.4X19SLBB'), 
+('2L3-1/2X2-1/2X1/2SLBB','2L3-1/2X2-1/2X1/2SLBB','2L89X64X12.7SLBB','2L89X64X12.7SLBB'), 
+('2L3-1/2X2-1/2X1/2X3/8SLBB','2L3-1/2X2-1/2X1/2X3/8SLBB','2L89X64X12.7X9.5SLBB','2L89X64X12.7X9.5SLBB'), 
+('2L3-1/2X2-1/2X1/2X3/4SLBB','2L3-1/2X2-1/2X1/2X3/4SLBB','2L89X64X12.7X19SLBB','2L89X64X12.7X19SLBB'), 
+('2L3-1/2X2-1/2X3/8SLBB','2L3-1/2X2-1/2X3/8SLBB','2L89X64X9.5SLBB','2L89X64X9.5SLBB'), 
+('2L3-1/2X2-1/2X3/8X3/8SLBB','2L3-1/2X2-1/2X3/8X3/8SLBB','2L89X64X9.5X9.5SLBB','2L89X64X9.5X9.5SLBB'), 
+('2L3-1/2X2-1/2X3/8X3/4SLBB','2L3-1/2X2-1/2X3/8X3/4SLBB','2L89X64X9.5X19SLBB','2L89X64X9.5X19SLBB'), 
+('2L3-1/2X2-1/2X5/16SLBB','2L3-1/2X2-1/2X5/16SLBB','2L89X64X7.9SLBB','2L89X64X7.9SLBB'), 
+('2L3-1/2X2-1/2X5/16X3/8SLBB','2L3-1/2X2-1/2X5/16X3/8SLBB','2L89X64X7.9X9.5SLBB','2L89X64X7.9X9.5SLBB'), 
+('2L3-1/2X2-1/2X5/16X3/4SLBB','2L3-1/2X2-1/2X5/16X3/4SLBB','2L89X64X7.9X19SLBB','2L89X64X7.9X19SLBB'), 
+('2L3-1/2X2-1/2X1/4SLBB','2L3-1/2X2-1/2X1/4SLBB','2L89X64X6.4SLBB','2L89X64X6.4SLBB'), 
+('2L3-1/2X2-1/2X1/4X3/8SLBB','2L3-1/2X2-1/2X1/4X3/8SLBB','2L89X64X6.4X9.5SLBB','2L89X64X6.4X9.5SLBB'), 
+('2L3-1/2X2-1/2X1/4X3/4SLBB','2L3-1/2X2-1/2X1/4X3/4SLBB','2L89X64X6.4X19SLBB','2L89X64X6.4X19SLBB'), 
+('2L3X2-1/2X1/2SLBB','2L3X2-1/2X1/2SLBB','2L76X64X12.7SLBB','2L76X64X12.7SLBB'), 
+('2L3X2-1/2X1/2X3/8SLBB','2L3X2-1/2X1/2X3/8SLBB','2L76X64X12.7X9.5SLBB','2L76X64X12.7X9.5SLBB'), 
+('2L3X2-1/2X1/2X3/4SLBB','2L3X2-1/2X1/2X3/4SLBB','2L76X64X12.7X19SLBB','2L76X64X12.7X19SLBB'), 
+('2L3X2-1/2X7/16SLBB','2L3X2-1/2X7/16SLBB','2L76X64X11.1SLBB','2L76X64X11.1SLBB'), 
+('2L3X2-1/2X7/16X3/8SLBB','2L3X2-1/2X7/16X3/8SLBB','2L76X64X11.1X9.5SLBB','2L76X64X11.1X9.5SLBB'), 
+('2L3X2-1/2X7/16X3/4SLBB','2L3X2-1/2X7/16X3/4SLBB','2L76X64X11.1X19SLBB','2L76X64X11.1X19SLBB'), 
+('2L3X2-1/2X3/8SLBB','2L3X2-1/2X3/8SLBB','2L76X64X9.5SLBB','2L76X64X9.5SLBB'), 
+('2L3X2-1/2X3/8X3/8SLBB','2L3X2-1/2X3/8X3/8SLBB','2L76X64X9.5X9.5SLBB','2L76X64X9.5X9.5SLBB'), 
+('2L3X2-1/2X3/8X3/4SLBB','2L3X2-1/2X3/8X3/4SLBB','2L76X64X9.5X19SLBB','2L76X64X9.5X19SLBB'), 
+('2L3X2-1/2X5/16SLBB','2L3X2-1/2X5/16SLBB','2L76X64X7.9SLBB','2L76X64X7.9SLBB'), 
+('2L3X2-1/2X5/16X3/8SLBB','2L3X2-1/2X5/16X3/8SLBB','2L76X64X7.9X9.5SLBB','2L76X64X7.9X9.5SLBB'), 
+('2L3X2-1/2X5/16X3/4SLBB','2L3X2-1/2X5/16X3/4SLBB','2L76X64X7.9X19SLBB','2L76X64X7.9X19SLBB'), 
+('2L3X2-1/2X1/4SLBB','2L3X2-1/2X1/4SLBB','2L76X64X6.4SLBB','2L76X64X6.4SLBB'), 
+('2L3X2-1/2X1/4X3/8SLBB','2L3X2-1/2X1/4X3/8SLBB','2L76X64X6.4X9.5SLBB','2L76X64X6.4X9.5SLBB'), 
+('2L3X2-1/2X1/4X3/4SLBB','2L3X2-1/2X1/4X3/4SLBB','2L76X64X6.4X19SLBB','2L76X64X6.4X19SLBB'), 
+('2L3X2-1/2X3/16SLBB','2L3X2-1/2X3/16SLBB','2L76X64X4.8SLBB','2L76X64X4.8SLBB'), 
+('2L3X2-1/2X3/16X3/8SLBB','2L3X2-1/2X3/16X3/8SLBB','2L76X64X4.8X9.5SLBB','2L76X64X4.8X9.5SLBB'), 
+('2L3X2-1/2X3/16X3/4SLBB','2L3X2-1/2X3/16X3/4SLBB','2L76X64X4.8X19SLBB','2L76X64X4.8X19SLBB'), 
+('2L3X2X1/2SLBB','2L3X2X1/2SLBB','2L76X51X12.7SLBB','2L76X51X12.7SLBB'), 
+('2L3X2X1/2X3/8SLBB','2L3X2X1/2X3/8SLBB','2L76X51X12.7X9.5SLBB','2L76X51X12.7X9.5SLBB'), 
+('2L3X2X1/2X3/4SLBB','2L3X2X1/2X3/4SLBB','2L76X51X12.7X19SLBB','2L76X51X12.7X19SLBB'), 
+('2L3X2X3/8SLBB','2L3X2X3/8SLBB','2L76X51X9.5SLBB','2L76X51X9.5SLBB'), 
+('2L3X2X3/8X3/8SLBB','2L3X2X3/8X3/8SLBB','2L76X51X9.5X9.5SLBB','2L76X51X9.5X9.5SLBB'), 
+('2L3X2X3/8X3/4SLBB','2L3X2X3/8X3/4SLBB','2L76X51X9.5X19SLBB','2L76X51X9.5X19SLBB'), 
+('2L3X2X5/16SLBB','2L3X2X5/16SLBB','2L76X51X7.9SLBB','2L76X51X7.9SLBB'), 
+('2L3X2X5/16X3/8SLBB','2L3X2X5/16X3/8SLBB','2L76X51X7.9X9.5SLBB','2L76X51X7.9X9.5SLBB'), 
+('2L3X2X5/16X3/4SLBB','2L3X2X5/16X3/4SLBB','2L76X51X7.9X19SLBB','2L76X51X7.9X19SLBB'), 
+('2L3X2X1/4SLBB','2L3X2X1/4SLBB','2L76X51X6.4SLBB','2L76X51X6.4SLBB'), 
+('2L3X2X1/4X3/8SLBB','2L3X2X1/4X3/8SLBB','2L76X51X6.4X9.5SLBB','2L76X51X6.4X9.5SLBB'), 
+('2L3X2X1/4X3/4SLBB','2L3X2X1/4X3/4SLBB','2L76X51X6.4X19SLBB','2L76X51X6.4X19SLBB'), 
+('2L3X2X3/16SLBB','2L3X2X3/16SLBB','2L76X51X4.8SLBB','2L76X51X4.8SLBB'), 
+('2L3X2X3/16X3/8SLBB','2L3X2X3/16X3/8SLBB','2L76X51X4.8X9.5SLBB','2L76X51X4.8X9.5SLBB'), 
+('2L3X2X3/16X3/4SLBB','2L3X2X3/16X3/4SLBB','2L76X51X4.8X19SLBB','2L76X51X4.8X19SLBB'), 
+('2L2-1/2X2X3/8SLBB','2L2-1/2X2X3/8SLBB','2L64X51X9.5SLBB','2L64X51X9.5SLBB'), 
+('2L2-1/2X2X3/8X3/8SLBB','2L2-1/2X2X3/8X3/8SLBB','2L64X51X9.5X9.5SLBB','2L64X51X9.5X9.5SLBB'), 
+('2L2-1/2X2X3/8X3/4SLBB','2L2-1/2X2X3/8X3/4SLBB','2L64X51X9.5X19SLBB','2L64X51X9.5X19SLBB'), 
+('2L2-1/2X2X5/16SLBB','2L2-1/2X2X5/16SLBB','2L64X51X7.9SLBB','2L64X51X7.9SLBB'), 
+('2L2-1/2X2X5/16X3/8SLBB','2L2-1/2X2X5/16X3/8SLBB','2L64X51X7.9X9.5SLBB','2L64X51X7.9X9.5SLBB'), 
+('2L2-1/2X2X5/16X3/4SLBB','2L2-1/2X2X5/16X3/4SLBB','2L64X51X7.9X19SLBB','2L64X51X7.9X19SLBB'), 
+('2L2-1/2X2X1/4SLBB','2L2-1/2X2X1/4SLBB','2L64X51X6.4SLBB','2L64X51X6.4SLBB'), 
+('2L2-1/2X2X1/4X3/8SLBB','2L2-1/2X2X1/4X3/8SLBB','2L64X51X6.4X9.5SLBB','2L64X51X6.4X9.5SLBB'), 
+('2L2-1/2X2X1/4X3/4SLBB','2L2-1/2X2X1/4X3/4SLBB','2L64X51X6.4X19SLBB','2L64X51X6.4X19SLBB'), 
+('2L2-1/2X2X3/16SLBB','2L2-1/2X2X3/16SLBB','2L64X51X4.8SLBB','2L64X51X4.8SLBB'), 
+('2L2-1/2X2X3/16X3/8SLBB','2L2-1/2X2X3/16X3/8SLBB','2L64X51X4.8X9.5SLBB','2L64X51X4.8X9.5SLBB'), 
+('2L2-1/2X2X3/16X3/4SLBB','2L2-1/2X2X3/16X3/4SLBB','2L64X51X4.8X19SLBB','2L64X51X4.8X19SLBB'), 
+('2L2-1/2X1-1/2X1/4SLBB','2L2-1/2X1-1/2X1/4SLBB','2L64X38X6.4SLBB','2L64X38X6.4SLBB'), 
+('2L2-1/2X1-1/2X1/4X3/8SLBB','2L2-1/2X1-1/2X1/4X3/8SLBB','2L64X38X6.4X9.5SLBB','2L64X38X6.4X9.5SLBB'), 
+('2L2-1/2X1-1/2X1/4X3/4SLBB','2L2-1/2X1-1/2X1/4X3/4SLBB','2L64X38X6.4X19SLBB','2L64X38X6.4X19SLBB'), 
+('2L2-1/2X1-1/2X3/16SLBB','2L2-1/2X1-1/2X3/16SLBB','2L64X38X4.8SLBB','2L64X38X4.8SLBB'), 
+('2L2-1/2X1-1/2X3/16X3/8SLBB','2L2-1/2X1-1/2X3/16X3/8SLBB','2L64X38X4.8X9.5SLBB','2L64X38X4.8X9.5SLBB'), 
+('2L2-1/2X1-1/2X3/16X3/4SLBB','2L2-1/2X1-1/2X3/16X3/4SLBB','2L64X38X4.8X19SLBB','2L64X38X4.8X19SLBB'), 
+('HSS34X10X1','HSS34X10X1','HSS863.6X254X25.4','HSS863.6X254X25.4'), 
+('HSS34X10X.875','HSS34X10X7/8','HSS863.6X254X22.2','HSS863.6X254X22.2'), 
+('HSS34X10X.750','HSS34X10X3/4','HSS863.6X254X19','HSS863.6X254X19'), 
+('HSS34X10X.625','HSS34X10X5/8','HSS863.6X254X15.9','HSS863.6X254X15.9'), 
+('HSS30X10X1','HSS30X10X1','HSS762X254X25.4','HSS762X254X25.4'), 
+('HSS30X10X.875','HSS30X10X7/8','HSS762X254X22.2','HSS762X254X22.2'), 
+('HSS30X10X.750','HSS30X10X3/4','HSS762X254X19','HSS762X254X19'), 
+('HSS30X10X.625','HSS30X10X5/8','HSS762X254X15.9','HSS762X254X15.9'), 
+('HSS30X10X.500','HSS30X10X1/2','HSS762X254X12.7','HSS762X254X12.7'), 
+('HSS24X20X.750','HSS24X20X3/4','HSS609.6X508X19','HSS609.6X508X19'), 
+('HSS24X20X.625','HSS24X20X5/8','HSS609.6X508X15.9','HSS609.6X508X15.9'), 
+('HSS24X20X.500','HSS24X20X1/2','HSS609.6X508X12.7','HSS609.6X508X12.7'), 
+('HSS24X20X.375','HSS24X20X3/8','HSS609.6X508X9.5','HSS609.6X508X9.5'), 
+('HSS24X20X.313','HSS24X20X5/16','HSS609.6X508X7.9','HSS609.6X508X7.9'), 
+('HSS24X18X.750','HSS24X18X3/4','HSS609.6X457.2X19','HSS609.6X457.2X19'), 
+('HSS24X18X.625','HSS24X18X5/8','HSS609.6X457.2X15.9','HSS609.6X457.2X15.9'), 
+('HSS24X18X.500','HSS24X18X1/2','HSS609.6X457.2X12.7','HSS609.6X457.2X12.7'), 
+('HSS24X18X.375','HSS24X18X3/8','HSS609.6X457.2X9.5','HSS609.6X457.2X9.5'), 
+('HSS24X18X.313','HSS24X18X5/16','HSS609.6X457.2X7.9','HSS609.6X457.2X7.9'), 
+('HSS24X16X.750','HSS24X16X3/4','HSS609.6X406.4X19','HSS609.6X406.4X19'), 
+('HSS24X16X.625','HSS24X16X5/8','HSS609.6X406.4X15.9','HSS609.6X406.4X15.9'), 
+('HSS24X16X.500','HSS24X16X1/2','HSS609.6X406.4X12.7','HSS609.6X406.4X12.7'), 
+('HSS24X16X.375','HSS24X16X3/8','HSS609.6X406.4X9.5','HSS609.6X406.4X9.5'), 
+('HSS24X16X.313','HSS24X16X5/16','HSS609.6X406.4X7.9','HSS609.6X406.4X7.9'), 
+('HSS24X14X.750','HSS24X14X3/4','HSS609.6X355.6X19','HSS609.6X355.6X19'), 
+('HSS24X14X.625','HSS24X14X5/8','HSS609.6X355.6X15.9','HSS609.6X355.6X15.9'), 
+('HSS24X14X.500','HSS24X14X1/2','HSS609.6X355.6X12.7','HSS609.6X355.6X12.7'), 
+('HSS24X14X.375','HSS24X14X3/8','HSS609.6X355.6X9.5','HSS609.6X355.6X9.5'), 
+('HSS24X14X.313','HSS24X14X5/16','HSS609.6X355.6X7.9','HSS609.6X355.6X7.9'), 
+('HSS24X14X.250','HSS24X14X1/4','HSS609.6X355.6X6.4','HSS609.6X355.6X6.4'), 
+('HSS24X12X1','HSS24X12X1','HSS609.6X304.8X25.4','HSS609.6X304.8X25.4'), 
+('HSS24X12X.875','HSS24X12X7/8','HSS609.6X304.8X22.2','HSS609.6X304.8X22.2'), 
+('HSS24X12X.750','HSS24X12X3/4','HSS609.6X304.8X19','HSS609.6X304.8X19'), 
+('HSS24X12X.625','HSS24X12X5/8','HSS609.6X304.8X15.9','HSS609.6X304.8X15.9'), 
+('HSS24X12X.500','HSS24X12X1/2','HSS609.6X304.8X12.7','HSS609.6X304.8X12.7'), 
+('HSS24X12X.375','HSS24X12X3/8','HSS609.6X304.8X9.5','HSS609.6X304.8X9.5'), 
+('HSS24X12X.313','HSS24X12X5/16','HSS609.6X304.8X7.9','HSS609.6X304.8X7.9'), 
+('HSS24X12X.250','HSS24X12X1/4','HSS609.6X304.8X6.4','HSS609.6X304.8X6.4'), 
+('HSS24X8X.500','HSS24X8X1/2','HSS609.6X203.2X12.7','HSS609.6X203.2X12.7'), 
+('HSS24X8X.375','HSS24X8X3/8','HSS609.6X203.2X9.5','HSS609.6X203.2X9.5'), 
+('HSS24X8X.313','HSS24X8X5/16','HSS609.6X203.2X7.9','HSS609.6X203.2X7.9'), 
+('HSS24X8X.250','HSS24X8X1/4','HSS609.6X203.2X6.4','HSS609.6X203.2X6.4'), 
+('HSS22X22X1','HSS22X22X1','HSS558.8X558.8X25.4','HSS558.8X558.8X25.4'), 
+('HSS22X22X.875','HSS22X22X7/8','HSS558.8X558.8X22.2','HSS558.8X558.8X22.2'), 
+('HSS22X22X.750','HSS22X22X3/4','HSS558.8X558.8X19','HSS558.8X558.8X19'), 
+('HSS22X22X.625','HSS22X22X5/8','HSS558.8X558.8X15.9','HSS558.8X558.8X15.9'), 
+('HSS22X22X.500','HSS22X22X1/2','HSS558.8X558.8X12.7','HSS558.8X558.8X12.7'), 
+('HSS22X20X.750','HSS22X20X3/4','HSS558.8X508X19','HSS558.8X508X19'), 
+('HSS22X20X.625','HSS22X20X5/8','HSS558.8X508X15.9','HSS558.8X508X15.9'), 
+('HSS22X20X.500','HSS22X20X1/2','HSS558.8X508X12.7','HSS558.8X508X12.7'), 
+('HSS22X20X.375','HSS22X20X3/8','HSS558.8X508X9.5','HSS558.8X508X9.5'), 
+('HSS22X20X.313','HSS22X20X5/16','HSS558.8X508X7.9','HSS558.8X508X7.9'), 
+('HSS22X18X.750','HSS22X18X3/4','HSS558.8X457.2X19','HSS558.8X457.2X19'), 
+('HSS22X18X.625','HSS22X18X5/8','HSS558.8X457.2X15.9','HSS558.8X457.2X15.9'), 
+('HSS22X18X.500','HSS22X18X1/2','HSS558.8X457.2X12.7','HSS558.8X457.2X12.7'), 
+('HSS22X18X.375','HSS22X18X3/8','HSS558.8X457.2X9.5','HSS558.8X457.2X9.5'), 
+('HSS22X18X.313','HSS22X18X5/16','HSS558.8X457.2X7.9','HSS558.8X457.2X7.9'), 
+('HSS22X16X.750','HSS22X16X3/4','HSS558.8X406.4X19','HSS558.8X406.4X19'), 
+('HSS22X16X.625','HSS22X16X5/8','HSS558.8X406.4X15.9','HSS558.8X406.4X15.9'), 
+('HSS22X16X.500','HSS22X16X1/2','HSS558.8X406.4X12.7','HSS558.8X406.4X12.7'), 
+('HSS22X16X.375','HSS22X16X3/8','HSS558.8X406.4X9.5','HSS558.8X406.4X9.5'), 
+('HSS22X16X.313','HSS22X16X5/16','HSS558.8X406.4X7.9','HSS558.8X406.4X7.9'), 
+('HSS22X16X.250','HSS22X16X1/4','HSS558.8X406.4X6.4','HSS558.8X406.4X6.4'), 
+('HSS22X14X.750','HSS22X14X3/4','HSS558.8X355.6X19','HSS558.8X355.6X19'), 
+('HSS22X14X.625','HSS22X14X5/8','HSS558.8X355.6X15.9','HSS558.8X355.6X15.9'), 
+('HSS22X14X.500','HSS22X14X1/2','HSS558.8X355.6X12.7','HSS558.8X355.6X12.7'), 
+('HSS22X14X.375','HSS22X14X3/8','HSS558.8X355.6X9.5','HSS558.8X355.6X9.5'), 
+('HSS22X14X.313','HSS22X14X5/16','HSS558.8X355.6X7.9','HSS558.8X355.6X7.9'), 
+('HSS22X14X.250','HSS22X14X1/4','HSS558.8X355.6X6.4','HSS558.8X355.6X6.4'), 
+('HSS22X10X.625','HSS22X10X5/8','HSS558.8X254X15.9','HSS558.8X254X15.9'), 
+('HSS22X10X.500','HSS22X10X1/2','HSS558.8X254X12.7','HSS558.8X254X12.7'), 
+('HSS22X10X.375','HSS22X10X3/8','HSS558.8X254X9.5','HSS558.8X254X9.5'), 
+('HSS22X10X.313','HSS22X10X5/16','HSS558.8X254X7.9','HSS558.8X254X7.9'), 
+('HSS22X10X.250','HSS22X10X1/4','HSS558.8X254X6.4','HSS558.8X254X6.4'), 
+('HSS20X20X1','HSS20X20X1','HSS508X508X25.4','HSS508X508X25.4'), 
+('HSS20X20X.875','HSS20X20X7/8','HSS508X508X22.2','HSS508X508X22.2'), 
+('HSS20X20X.750','HSS20X20X3/4','HSS508X508X19','HSS508X508X19'), 
+('HSS20X20X.625','HSS20X20X5/8','HSS508X508X15.9','HSS508X508X15.9'), 
+('HSS20X20X.500','HSS20X20X1/2','HSS508X508X12.7','HSS508X508X12.7'), 
+('HSS20X20X.375','HSS20X20X3/8','HSS508X508X9.5','HSS508X508X9.5'), 
+('HSS20X20X.313','HSS20X20X5/16','HSS508X508X7.9','HSS508X508X7.9'), 
+('HSS20X16X.750','HSS20X16X3/4','HSS508X406.4X19','HSS508X406.4X19'), 
+('HSS20X16X.625','HSS20X16X5/8','HSS508X406.4X15.9','HSS508X406.4X15.9'), 
+('HSS20X16X.500','HSS20X16X1/2','HSS508X406.4X12.7','HSS508X406.4X12.7'), 
+('HSS20X16X.375','HSS20X16X3/8','HSS508X406.4X9.5','HSS508X406.4X9.5'), 
+('HSS20X16X.313','HSS20X16X5/16','HSS508X406.4X7.9','HSS508X406.4X7.9'), 
+('HSS20X16X.250','HSS20X16X1/4','HSS508X406.4X6.4','HSS508X406.4X6.4'), 
+('HSS20X12X1','HSS20X12X1','HSS508X304.8X25.4','HSS508X304.8X25.4'), 
+('HSS20X12X.875','HSS20X12X7/8','HSS508X304.8X22.2','HSS508X304.8X22.2'), 
+('HSS20X12X.750','HSS20X12X3/4','HSS508X304.8X19','HSS508X304.8X19'), 
+('HSS20X12X.625','HSS20X12X5/8','HSS508X304.8X15.9','HSS508X304.8X15.9'), 
+('HSS20X12X.500','HSS20X12X1/2','HSS508X304.8X12.7','HSS508X304.8X12.7'), 
+('HSS20X12X.375','HSS20X12X3/8','HSS508X304.8X9.5','HSS508X304.8X9.5'), 
+('HSS20X12X.313','HSS20X12X5/16','HSS508X304.8X7.9','HSS508X304.8X7.9'), 
+('HSS20X8X1','HSS20X8X1','HSS508X203.2X25.4','HSS508X203.2X25.4'), 
+('HSS20X8X.875','HSS20X8X7/8','HSS508X203.2X22.2','HSS508X203.2X22.2'), 
+('HSS20X8X.750','HSS20X8X3/4','HSS508X203.2X19','HSS508X203.2X19'), 
+('HSS20X8X.625','HSS20X8X5/8','HSS508X203.2X15.9','HSS508X203.2X15.9'), 
+('HSS20X8X.500','HSS20X8X1/2','HSS508X203.2X12.7','HSS508X203.2X12.7'), 
+('HSS20X8X.375','HSS20X8X3/8','HSS508X203.2X9.5','HSS508X203.2X9.5'), 
+('HSS20X8X.313','HSS20X8X5/16','HSS508X203.2X7.9','HSS508X203.2X7.9'), 
+('HSS20X6X.625','HSS20X6X5/8','HSS508X152.4X15.9','HSS508X152.4X15.9'), 
+('HSS20X6X.500','HSS20X6X1/2','HSS508X152.4X12.7','HSS508X152.4X12.7'), 
+('HSS20X6X.375','HSS20X6X3/8','HSS508X152.4X9.5','HSS508X152.4X9.5'), 
+('HSS20X6X.313','HSS20X6X5/16','HSS508X152.4X7.9','HSS508X152.4X7.9'), 
+('HSS20X6X.250','HSS20X6X1/4','HSS508X152.4X6.4','HSS508X152.4X6.4'), 
+('HSS20X4X.500','HSS20X4X1/2','HSS508X101.6X12.7','HSS508X101.6X12.7'), 
+('HSS20X4X.375','HSS20X4X3/8','HSS508X101.6X9.5','HSS508X101.6X9.5'), 
+('HSS20X4X.313','HSS20X4X5/16','HSS508X101.6X7.9','HSS508X101.6X7.9'), 
+('HSS20X4X.250','HSS20X4X1/4','HSS508X101.6X6.4','HSS508X101.6X6.4'), 
+('HSS18X18X1','HSS18X18X1','HSS457.2X457.2X25.4','HSS457.2X457.2X25.4'), 
+('HSS18X18X.875','HSS18X18X7/8','HSS457.2X457.2X22.2','HSS457.2X457.2X22.2'), 
+('HSS18X18X.750','HSS18X18X3/4','HSS457.2X457.2X19','HSS457.2X457.2X19'), 
+('HSS18X18X.625','HSS18X18X5/8','HSS457.2X457.2X15.9','HSS457.2X457.2X15.9'), 
+('HSS18X18X.500','HSS18X18X1/2','HSS457.2X457.2X12.7','HSS457.2X457.2X12.7'), 
+('HSS18X18X.375','HSS18X18X3/8','HSS457.2X457.2X9.5','HSS457.2X457.2X9.5'), 
+('HSS18X18X.313','HSS18X18X5/16','HSS457.2X457.2X7.9','HSS457.2X457.2X7.9'), 
+('HSS18X18X.250','HSS18X18X1/4','HSS457.2X457.2X6.4','HSS457.2X457.2X6.4'), 
+('HSS18X10X.625','HSS18X10X5/8','HSS457.2X254X15.9','HSS457.2X254X15.9'), 
+('HSS18X10X.500','HSS18X10X1/2','HSS457.2X254X12.7','HSS457.2X254X12.7'), 
+('HSS18X10X.375','HSS18X10X3/8','HSS457.2X254X9.5','HSS457.2X254X9.5'), 
+('HSS18X10X.313','HSS18X10X5/16','HSS457.2X254X7.9','HSS457.2X254X7.9'), 
+('HSS18X10X.250','HSS18X10X1/4','HSS457.2X254X6.4','HSS457.2X254X6.4'), 
+('HSS18X8X.625','HSS18X8X5/8','HSS457.2X203.2X15.9','HSS457.2X203.2X15.9'), 
+('HSS18X8X.500','HSS18X8X1/2','HSS457.2X203.2X12.7','HSS457.2X203.2X12.7'), 
+('HSS18X8X.375','HSS18X8X3/8','HSS457.2X203.2X9.5','HSS457.2X203.2X9.5'), 
+('HSS18X8X.313','HSS18X8X5/16','HSS457.2X203.2X7.9','HSS457.2X203.2X7.9'), 
+('HSS18X8X.250','HSS18X8X1/4','HSS457.2X203.2X6.4','HSS457.2X203.2X6.4'), 
+('HSS18X6X.750','HSS18X6X3/4','HSS457.2X152.4X19','HSS457.2X152.4X19'), 
+('HSS18X6X.625','HSS18X6X5/8','HSS457.2X152.4X15.9','HSS457.2X152.4X15.9'), 
+('HSS18X6X.500','HSS18X6X1/2','HSS457.2X152.4X12.7','HSS457.2X152.4X12.7'), 
+('HSS18X6X.375','HSS18X6X3/8','HSS457.2X152.4X9.5','HSS457.2X152.4X9.5'), 
+('HSS18X6X.313','HSS18X6X5/16','HSS457.2X152.4X7.9','HSS457.2X152.4X7.9'), 
+('HSS18X6X.250','HSS18X6X1/4','HSS457.2X152.4X6.4','HSS457.2X152.4X6.4'), 
+('HSS16X16X1','HSS16X16X1','HSS406.4X406.4X25.4','HSS406.4X406.4X25.4'), 
+('HSS16X16X.875','HSS16X16X7/8','HSS406.4X406.4X22.2','HSS406.4X406.4X22.2'), 
+('HSS16X16X.750','HSS16X16X3/4','HSS406.4X406.4X19','HSS406.4X406.4X19'), 
+('HSS16X16X.625','HSS16X16X5/8','HSS406.4X406.4X15.9','HSS406.4X406.4X15.9'), 
+('HSS16X16X.500','HSS16X16X1/2','HSS406.4X406.4X12.7','HSS406.4X406.4X12.7'), 
+('HSS16X16X.375','HSS16X16X3/8','HSS406.4X406.4X9.5','HSS406.4X406.4X9.5'), 
+('HSS16X16X.313','HSS16X16X5/16','HSS406.4X406.4X7.9','HSS406.4X406.4X7.9'), 
+('HSS16X16X.250','HSS16X16X1/4','HSS406.4X406.4X6.4','HSS406.4X406.4X6.4'), 
+('HSS16X12X1','HSS16X12X1','HSS406.4X304.8X25.4','HSS406.4X304.8X25.4'), 
+('HSS16X12X.875','HSS16X12X7/8','HSS406.4X304.8X22.2','HSS406.4X304.8X22.2'), 
+('HSS16X12X.750','HSS16X12X3/4','HSS406.4X304.8X19','HSS406.4X304.8X19'), 
+('HSS16X12X.625','HSS16X12X5/8','HSS406.4X304.8X15.9','HSS406.4X304.8X15.9'), 
+('HSS16X12X.500','HSS16X12X1/2','HSS406.4X304.8X12.7','HSS406.4X304.8X12.7'), 
+('HSS16X12X.375','HSS16X12X3/8','HSS406.4X304.8X9.5','HSS406.4X304.8X9.5'), 
+('HSS16X12X.313','HSS16X12X5/16','HSS406.4X304.8X7.9','HSS406.4X304.8X7.9'), 
+('HSS16X10X.625','HSS16X10X5/8','HSS406.4X254X15.9','HSS406.4X254X15.9'), 
+('HSS16X10X.500','HSS16X10X1/2','HSS406.4X254X12.7','HSS406.4X254X12.7'), 
+('HSS16X10X.375','HSS16X10X3/8','HSS406.4X254X9.5','HSS406.4X254X9.5'), 
+('HSS16X10X.313','HSS16X10X5/16','HSS406.4X254X7.9','HSS406.4X254X7.9'), 
+('HSS16X10X.250','HSS16X10X1/4','HSS406.4X254X6.4','HSS406.4X254X6.4'), 
+('HSS16X8X.875','HSS16X8X7/8','HSS406.4X203.2X22.2','HSS406.4X203.2X22.2'), 
+('HSS16X8X.750','HSS16X8X3/4','HSS406.4X203.2X19','HSS406.4X203.2X19'), 
+('HSS16X8X.625','HSS16X8X5/8','HSS406.4X203.2X15.9','HSS406.4X203.2X15.9'), 
+('HSS16X8X.500','HSS16X8X1/2','HSS406.4X203.2X12.7','HSS406.4X203.2X12.7'), 
+('HSS16X8X.375','HSS16X8X3/8','HSS406.4X203.2X9.5','HSS406.4X203.2X9.5'), 
+('HSS16X8X.313','HSS16X8X5/16','HSS406.4X203.2X7.9','HSS406.4X203.2X7.9'), 
+('HSS16X8X.250','HSS16X8X1/4','HSS406.4X203.2X6.4','HSS406.4X203.2X6.4'), 
+('HSS16X6X.625','HSS16X6X5/8','HSS406.4X152.4X15.9','HSS406.4X152.4X15.9'), 
+('HSS16X6X.500','HSS16X6X1/2','HSS406.4X152.4X12.7','HSS406.4X152.4X12.7'), 
+('HSS16X6X.375','HSS16X6X3/8','HSS406.4X152.4X9.5','HSS406.4X152.4X9.5'), 
+('HSS16X6X.313','HSS16X6X5/16','HSS406.4X152.4X7.9','HSS406.4X152.4X7.9'), 
+('HSS16X6X.250','HSS16X6X1/4','HSS406.4X152.4X6.4','HSS406.4X152.4X6.4'), 
+('HSS16X6X.188','HSS16X6X3/16','HSS406.4X152.4X4.8','HSS406.4X152.4X4.8'), 
+('HSS16X4X.625','HSS16X4X5/8','HSS406.4X101.6X15.9','HSS406.4X101.6X15.9'), 
+('HSS16X4X.500','HSS16X4X1/2','HSS406.4X101.6X12.7','HSS406.4X101.6X12.7'), 
+('HSS16X4X.375','HSS16X4X3/8','HSS406.4X101.6X9.5','HSS406.4X101.6X9.5'), 
+('HSS16X4X.313','HSS16X4X5/16','HSS406.4X101.6X7.9','HSS406.4X101.6X7.9'), 
+('HSS16X4X.250','HSS16X4X1/4','HSS406.4X101.6X6.4','HSS406.4X101.6X6.4'), 
+('HSS16X4X.188','HSS16X4X3/16','HSS406.4X101.6X4.8','HSS406.4X101.6X4.8'), 
+('HSS14X14X1','HSS14X14X1','HSS355.6X355.6X25.4','HSS355.6X355.6X25.4'), 
+('HSS14X14X.875','HSS14X14X7/8','HSS355.6X355.6X22.2','HSS355.6X355.6X22.2'), 
+('HSS14X14X.750','HSS14X14X3/4','HSS355.6X355.6X19','HSS355.6X355.6X19'), 
+('HSS14X14X.625','HSS14X14X5/8','HSS355.6X355.6X15.9','HSS355.6X355.6X15.9'), 
+('HSS14X14X.500','HSS14X14X1/2','HSS355.6X355.6X12.7','HSS355.6X355.6X12.7'), 
+('HSS14X14X.375','HSS14X14X3/8','HSS355.6X355.6X9.5','HSS355.6X355.6X9.5'), 
+('HSS14X14X.313','HSS14X14X5/16','HSS355.6X355.6X7.9','HSS355.6X355.6X7.9'), 
+('HSS14X14X.250','HSS14X14X1/4','HSS355.6X355.6X6.4','HSS355.6X355.6X6.4'), 
+('HSS14X12X.625','HSS14X12X5/8','HSS355.6X304.8X15.9','HSS355.6X304.8X15.9'), 
+('HSS14X12X.500','HSS14X12X1/2','HSS355.6X304.8X12.7','HSS355.6X304.8X12.7'), 
+('HSS14X12X.375','HSS14X12X3/8','HSS355.6X304.8X9.5','HSS355.6X304.8X9.5'), 
+('HSS14X12X.313','HSS14X12X5/16','HSS355.6X304.8X7.9','HSS355.6X304.8X7.9'), 
+('HSS14X12X.250','HSS14X12X1/4','HSS355.6X304.8X6.4','HSS355.6X304.8X6.4'), 
+('HSS14X10X.875','HSS14X10X7/8','HSS355.6X254X22.2','HSS355.6X254X22.2'), 
+('HSS14X10X.750','HSS14X10X3/4','HSS355.6X254X19','HSS355.6X254X19'), 
+('HSS14X10X.625','HSS14X10X5/8','HSS355.6X254X15.9','HSS355.6X254X15.9'), 
+('HSS14X10X.500','HSS14X10X1/2','HSS355.6X254X12.7','HSS355.6X254X12.7'), 
+('HSS14X10X.375','HSS14X10X3/8','HSS355.6X254X9.5','HSS355.6X254X9.5'), 
+('HSS14X10X.313','HSS14X10X5/16','HSS355.6X254X7.9','HSS355.6X254X7.9'), 
+('HSS14X10X.250','HSS14X10X1/4','HSS355.6X254X6.4','HSS355.6X254X6.4'), 
+('HSS14X8X.625','HSS14X8X5/8','HSS355.6X203.2X15.9','HSS355.6X203.2X15.9'), 
+('HSS14X8X.500','HSS14X8X1/2','HSS355.6X203.2X12.7','HSS355.6X203.2X12.7'), 
+('HSS14X8X.375','HSS14X8X3/8','HSS355.6X203.2X9.5','HSS355.6X203.2X9.5'), 
+('HSS14X8X.313','HSS14X8X5/16','HSS355.6X203.2X7.9','HSS355.6X203.2X7.9'), 
+('HSS14X8X.250','HSS14X8X1/4','HSS355.6X203.2X6.4','HSS355.6X203.2X6.4'), 
+('HSS14X8X.188','HSS14X8X3/16','HSS355.6X203.2X4.8','HSS355.6X203.2X4.8'), 
+('HSS14X6X.625','HSS14X6X5/8','HSS355.6X152.4X15.9','HSS355.6X152.4X15.9'), 
+('HSS14X6X.500','HSS14X6X1/2','HSS355.6X152.4X12.7','HSS355.6X152.4X12.7'), 
+('HSS14X6X.375','HSS14X6X3/8','HSS355.6X152.4X9.5','HSS355.6X152.4X9.5'), 
+('HSS14X6X.313','HSS14X6X5/16','HSS355.6X152.4X7.9','HSS355.6X152.4X7.9'), 
+('HSS14X6X.250','HSS14X6X1/4','HSS355.6X152.4X6.4','HSS355.6X152.4X6.4'), 
+('HSS14X6X.188','HSS14X6X3/16','HSS355.6X152.4X4.8','HSS355.6X152.4X4.8'), 
+('HSS14X4X.625','HSS14X4X5/8','HSS355.6X101.6X15.9','HSS355.6X101.6X15.9'), 
+('HSS14X4X.500','HSS14X4X1/2','HSS355.6X101.6X12.7','HSS355.6X101.6X12.7'), 
+('HSS14X4X.375','HSS14X4X3/8','HSS355.6X101.6X9.5','HSS355.6X101.6X9.5'), 
+('HSS14X4X.313','HSS14X4X5/16','HSS355.6X101.6X7.9','HSS355.6X101.6X7.9'), 
+('HSS14X4X.250','HSS14X4X1/4','HSS355.6X101.6X6.4','HSS355.6X101.6X6.4'), 
+('HSS14X4X.188','HSS14X4X3/16','HSS355.6X101.6X4.8','HSS355.6X101.6X4.8'), 
+('HSS12X12X1','HSS12X12X1','HSS304.8X304.8X25.4','HSS304.8X304.8X25.4'), 
+('HSS12X12X.875','HSS12X12X7/8','HSS304.8X304.8X22.2','HSS304.8X304.8X22.2'), 
+('HSS12X12X.750','HSS12X12X3/4','HSS304.8X304.8X19','HSS304.8X304.8X19'), 
+('HSS12X12X.625','HSS12X12X5/8','HSS304.8X304.8X15.9','HSS304.8X304.8X15.9'), 
+('HSS12X12X.500','HSS12X12X1/2','HSS304.8X304.8X12.7','HSS304.8X304.8X12.7'), 
+('HSS12X12X.375','HSS12X12X3/8','HSS304.8X304.8X9.5','HSS304.8X304.8X9.5'), 
+('HSS12X12X.313','HSS12X12X5/16','HSS304.8X304.8X7.9','HSS304.8X304.8X7.9'), 
+('HSS12X12X.250','HSS12X12X1/4','HSS304.8X304.8X6.4','HSS304.8X304.8X6.4'), 
+('HSS12X12X.188','HSS12X12X3/16','HSS304.8X304.8X4.8','HSS304.8X304.8X4.8'), 
+('HSS12X10X.625','HSS12X10X5/8','HSS304.8X254X15.9','HSS304.8X254X15.9'), 
+('HSS12X10X.500','HSS12X10X1/2','HSS304.8X254X12.7','HSS304.8X254X12.7'), 
+('HSS12X10X.375','HSS12X10X3/8','HSS304.8X254X9.5','HSS304.8X254X9.5'), 
+('HSS12X10X.313','HSS12X10X5/16','HSS304.8X254X7.9','HSS304.8X254X7.9'), 
+('HSS12X10X.250','HSS12X10X1/4','HSS304.8X254X6.4','HSS304.8X254X6.4'), 
+('HSS12X10X.188','HSS12X10X3/16','HSS304.8X254X4.8','HSS304.8X254X4.8'), 
+('HSS12X8X.625','HSS12X8X5/8','HSS304.8X203.2X15.9','HSS304.8X203.2X15.9'), 
+('HSS12X8X.500','HSS12X8X1/2','HSS304.8X203.2X12.7','HSS304.8X203.2X12.7'), 
+('HSS12X8X.375','HSS12X8X3/8','HSS304.8X203.2X9.5','HSS304.8X203.2X9.5'), 
+('HSS12X8X.313','HSS12X8X5/16','HSS304.8X203.2X7.9','HSS304.8X203.2X7.9'), 
+('HSS12X8X.250','HSS12X8X1/4','HSS304.8X203.2X6.4','HSS304.8X203.2X6.4'), 
+('HSS12X8X.188','HSS12X8X3/16','HSS304.8X203.2X4.8','HSS304.8X203.2X4.8'), 
+('HSS12X6X.625','HSS12X6X5/8','HSS304.8X152.4X15.9','HSS304.8X152.4X15.9'), 
+('HSS12X6X.500','HSS12X6X1/2','HSS304.8X152.4X12.7','HSS304.8X152.4X12.7'), 
+('HSS12X6X.375','HSS12X6X3/8','HSS304.8X152.4X9.5','HSS304.8X152.4X9.5'), 
+('HSS12X6X.313','HSS12X6X5/16','HSS304.8X152.4X7.9','HSS304.8X152.4X7.9'), 
+('HSS12X6X.250','HSS12X6X1/4','HSS304.8X152.4X6.4','HSS304.8X152.4X6.4'), 
+('HSS12X6X.188','HSS12X6X3/16','HSS304.8X152.4X4.8','HSS304.8X152.4X4.8'), 
+('HSS12X4X.625','HSS12X4X5/8','HSS304.8X101.6X15.9','HSS304.8X101.6X15.9'), 
+('HSS12X4X.500','HSS12X4X1/2','HSS304.8X101.6X12.7','HSS304.8X101.6X12.7'), 
+('HSS12X4X.375','HSS12X4X3/8','HSS304.8X101.6X9.5','HSS304.8X101.6X9.5'), 
+('HSS12X4X.313','HSS12X4X5/16','HSS304.8X101.6X7.9','HSS304.8X101.6X7.9'), 
+('HSS12X4X.250','HSS12X4X1/4','HSS304.8X101.6X6.4','HSS304.8X101.6X6.4'), 
+('HSS12X4X.188','HSS12X4X3/16','HSS304.8X101.6X4.8','HSS304.8X101.6X4.8'), 
+('HSS12X3X.313','HSS12X3X5/16','HSS304.8X76.2X7.9','HSS304.8X76.2X7.9'), 
+('HSS12X3X.250','HSS12X3X1/4','HSS304.8X76.2X6.4','HSS304.8X76.2X6.4'), 
+('HSS12X3X.188','HSS12X3X3/16','HSS304.8X76.2X4.8','HSS304.8X76.2X4.8'), 
+('HSS12X2X.313','HSS12X2X5/16','HSS304.8X50.8X7.9','HSS304.8X50.8X7.9'), 
+('HSS12X2X.250','HSS12X2X1/4','HSS304.8X50.8X6.4','HSS304.8X50.8X6.4'), 
+('HSS12X2X.188','HSS12X2X3/16','HSS304.8X50.8X4.8','HSS304.8X50.8X4.8'), 
+('HSS10X10X.750','HSS10X10X3/4','HSS254X254X19','HSS254X254X19'), 
+('HSS10X10X.625','HSS10X10X5/8','HSS254X254X15.9','HSS254X254X15.9'), 
+('HSS10X10X.500','HSS10X10X1/2','HSS254X254X12.7','HSS254X254X12.7'), 
+('HSS10X10X.375','HSS10X10X3/8','HSS254X254X9.5','HSS254X254X9.5'), 
+('HSS10X10X.313','HSS10X10X5/16','HSS254X254X7.9','HSS254X254X7.9'), 
+('HSS10X10X.250','HSS10X10X1/4','HSS254X254X6.4','HSS254X254X6.4'), 
+('HSS10X10X.188','HSS10X10X3/16','HSS254X254X4.8','HSS254X254X4.8'), 
+('HSS10X8X.625','HSS10X8X5/8','HSS254X203.2X15.9','HSS254X203.2X15.9'), 
+('HSS10X8X.500','HSS10X8X1/2','HSS254X203.2X12.7','HSS254X203.2X12.7'), 
+('HSS10X8X.375','HSS10X8X3/8','HSS254X203.2X9.5','HSS254X203.2X9.5'), 
+('HSS10X8X.313','HSS10X8X5/16','HSS254X203.2X7.9','HSS254X203.2X7.9'), 
+('HSS10X8X.250','HSS10X8X1/4','HSS254X203.2X6.4','HSS254X203.2X6.4'), 
+('HSS10X8X.188','HSS10X8X3/16','HSS254X203.2X4.8','HSS254X203.2X4.8'), 
+('HSS10X6X.625','HSS10X6X5/8','HSS254X152.4X15.9','HSS254X152.4X15.9'), 
+('HSS10X6X.500','HSS10X6X1/2','HSS254X152.4X12.7','HSS254X152.4X12.7'), 
+('HSS10X6X.375','HSS10X6X3/8','HSS254X152.4X9.5','HSS254X152.4X9.5'), 
+('HSS10X6X.313','HSS10X6X5/16','HSS254X152.4X7.9','HSS254X152.4X7.9'), 
+('HSS10X6X.250','HSS10X6X1/4','HSS254X152.4X6.4','HSS254X152.4X6.4'), 
+('HSS10X6X.188','HSS10X6X3/16','HSS254X152.4X4.8','HSS254X152.4X4.8'), 
+('HSS10X5X.375','HSS10X5X3/8','HSS254X127X9.5','HSS254X127X9.5'), 
+('HSS10X5X.313','HSS10X5X5/16','HSS254X127X7.9','HSS254X127X7.9'), 
+('HSS10X5X.250','HSS10X5X1/4','HSS254X127X6.4','HSS254X127X6.4'), 
+('HSS10X4X.625','HSS10X4X5/8','HSS254X101.6X15.9','HSS254X101.6X15.9'), 
+('HSS10X4X.500','HSS10X4X1/2','HSS254X101.6X12.7','HSS254X101.6X12.7'), 
+('HSS10X4X.375','HSS10X4X3/8','HSS254X101.6X9.5','HSS254X101.6X9.5'), 
+('HSS10X4X.313','HSS10X4X5/16','HSS254X101.6X7.9','HSS254X101.6X7.9'), 
+('HSS10X4X.250','HSS10X4X1/4','HSS254X101.6X6.4','HSS254X101.6X6.4'), 
+('HSS10X4X.188','HSS10X4X3/16','HSS254X101.6X4.8','HSS254X101.6X4.8'), 
+('HSS10X4X.125','HSS10X4X1/8','HSS254X101.6X3.2','HSS254X101.6X3.2'), 
+('HSS10X3-1/2X.375','HSS10X3-1/2X3/8','HSS254X88.9X9.5','HSS254X88.9X9.5'), 
+('HSS10X3-1/2X.313','HSS10X3-1/2X5/16','HSS254X88.9X7.9','HSS254X88.9X7.9'), 
+('HSS10X3-1/2X.250','HSS10X3-1/2X1/4','HSS254X88.9X6.4','HSS254X88.9X6.4'), 
+('HSS10X3-1/2X.188','HSS10X3-1/2X3/16','HSS254X88.9X4.8','HSS254X88.9X4.8'), 
+('HSS10X3X.375','HSS10X3X3/8','HSS254X76.2X9.5','HSS254X76.2X9.5'), 
+('HSS10X3X.313','HSS10X3X5/16','HSS254X76.2X7.9','HSS254X76.2X7.9'), 
+('HSS10X3X.250','HSS10X3X1/4','HSS254X76.2X6.4','HSS254X76.2X6.4'), 
+('HSS10X3X.188','HSS10X3X3/16','HSS254X76.2X4.8','HSS254X76.2X4.8'), 
+('HSS10X3X.125','HSS10X3X1/8','HSS254X76.2X3.2','HSS254X76.2X3.2'), 
+('HSS10X2X.375','HSS10X2X3/8','HSS254X50.8X9.5','HSS254X50.8X9.5'), 
+('HSS10X2X.313','HSS10X2X5/16','HSS254X50.8X7.9','HSS254X50.8X7.9'), 
+('HSS10X2X.250','HSS10X2X1/4','HSS254X50.8X6.4','HSS254X50.8X6.4'), 
+('HSS10X2X.188','HSS10X2X3/16','HSS254X50.8X4.8','HSS254X50.8X4.8'), 
+('HSS10X2X.125','HSS10X2X1/8','HSS254X50.8X3.2','HSS254X50.8X3.2'), 
+('HSS9X9X.625','HSS9X9X5/8','HSS228.6X228.6X15.9','HSS228.6X228.6X15.9'), 
+('HSS9X9X.500','HSS9X9X1/2','HSS228.6X228.6X12.7','HSS228.6X228.6X12.7'), 
+('HSS9X9X.375','HSS9X9X3/8','HSS228.6X228.6X9.5','HSS228.6X228.6X9.5'), 
+('HSS9X9X.313','HSS9X9X5/16','HSS228.6X228.6X7.9','HSS228.6X228.6X7.9'), 
+('HSS9X9X.250','HSS9X9X1/4','HSS228.6X228.6X6.4','HSS228.6X228.6X6.4'), 
+('HSS9X9X.188','HSS9X9X3/16','HSS228.6X228.6X4.8','HSS228.6X228.6X4.8'), 
+('HSS9X9X.125','HSS9X9X1/8','HSS228.6X228.6X3.2','HSS228.6X228.6X3.2'), 
+('HSS9X7X.625','HSS9X7X5/8','HSS228.6X177.8X15.9','HSS228.6X177.8X15.9'), 
+('HSS9X7X.500','HSS9X7X1/2','HSS228.6X177.8X12.7','HSS228.6X177.8X12.7'), 
+('HSS9X7X.375','HSS9X7X3/8','HSS228.6X177.8X9.5','HSS228.6X177.8X9.5'), 
+('HSS9X7X.313','HSS9X7X5/16','HSS228.6X177.8X7.9','HSS228.6X177.8X7.9'), 
+('HSS9X7X.250','HSS9X7X1/4','HSS228.6X177.8X6.4','HSS228.6X177.8X6.4'), 
+('HSS9X7X.188','HSS9X7X3/16','HSS228.6X177.8X4.8','HSS228.6X177.8X4.8'), 
+('HSS9X5X.625','HSS9X5X5/8','HSS228.6X127X15.9','HSS228.6X127X15.9'), 
+('HSS9X5X.500','HSS9X5X1/2','HSS228.6X127X12.7','HSS228.6X127X12.7'), 
+('HSS9X5X.375','HSS9X5X3/8','HSS228.6X127X9.5','HSS228.6X127X9.5'), 
+('HSS9X5X.313','HSS9X5X5/16','HSS228.6X127X7.9','HSS228.6X127X7.9'), 
+('HSS9X5X.250','HSS9X5X1/4','HSS228.6X127X6.4','HSS228.6X127X6.4'), 
+('HSS9X5X.188','HSS9X5X3/16','HSS228.6X127X4.8','HSS228.6X127X4.8'), 
+('HSS9X3X.500','HSS9X3X1/2','HSS228.6X76.2X12.7','HSS228.6X76.2X12.7'), 
+('HSS9X3X.375','HSS9X3X3/8','HSS228.6X76.2X9.5','HSS228.6X76.2X9.5'), 
+('HSS9X3X.313','HSS9X3X5/16','HSS228.6X76.2X7.9','HSS228.6X76.2X7.9'), 
+('HSS9X3X.250','HSS9X3X1/4','HSS228.6X76.2X6.4','HSS228.6X76.2X6.4'), 
+('HSS9X3X.188','HSS9X3X3/16','HSS228.6X76.2X4.8','HSS228.6X76.2X4.8'), 
+('HSS8X8X.625','HSS8X8X5/8','HSS203.2X203.2X15.9','HSS203.2X203.2X15.9'), 
+('HSS8X8X.500','HSS8X8X1/2','HSS203.2X203.2X12.7','HSS203.2X203.2X12.7'), 
+('HSS8X8X.375','HSS8X8X3/8','HSS203.2X203.2X9.5','HSS203.2X203.2X9.5'), 
+('HSS8X8X.313','HSS8X8X5/16','HSS203.2X203.2X7.9','HSS203.2X203.2X7.9'), 
+('HSS8X8X.250','HSS8X8X1/4','HSS203.2X203.2X6.4','HSS203.2X203.2X6.4'), 
+('HSS8X8X.188','HSS8X8X3/16','HSS203.2X203.2X4.8','HSS203.2X203.2X4.8'), 
+('HSS8X8X.125','HSS8X8X1/8','HSS203.2X203.2X3.2','HSS203.2X203.2X3.2'), 
+('HSS8X6X.625','HSS8X6X5/8','HSS203.2X152.4X15.9','HSS203.2X152.4X15.9'), 
+('HSS8X6X.500','HSS8X6X1/2','HSS203.2X152.4X12.7','HSS203.2X152.4X12.7'), 
+('HSS8X6X.375','HSS8X6X3/8','HSS203.2X152.4X9.5','HSS203.2X152.4X9.5'), 
+('HSS8X6X.313','HSS8X6X5/16','HSS203.2X152.4X7.9','HSS203.2X152.4X7.9'), 
+('HSS8X6X.250','HSS8X6X1/4','HSS203.2X152.4X6.4','HSS203.2X152.4X6.4'), 
+('HSS8X6X.188','HSS8X6X3/16','HSS203.2X152.4X4.8','HSS203.2X152.4X4.8'), 
+('HSS8X4X.625','HSS8X4X5/8','HSS203.2X101.6X15.9','HSS203.2X101.6X15.9'), 
+('HSS8X4X.500','HSS8X4X1/2','HSS203.2X101.6X12.7','HSS203.2X101.6X12.7'), 
+('HSS8X4X.375','HSS8X4X3/8','HSS203.2X101.6X9.5','HSS203.2X101.6X9.5'), 
+('HSS8X4X.313','HSS8X4X5/16','HSS203.2X101.6X7.9','HSS203.2X101.6X7.9'), 
+('HSS8X4X.250','HSS8X4X1/4','HSS203.2X101.6X6.4','HSS203.2X101.6X6.4'), 
+('HSS8X4X.188','HSS8X4X3/16','HSS203.2X101.6X4.8','HSS203.2X101.6X4.8'), 
+('HSS8X4X.125','HSS8X4X1/8','HSS203.2X101.6X3.2','HSS203.2X101.6X3.2'), 
+('HSS8X3X.500','HSS8X3X1/2','HSS203.2X76.2X12.7','HSS203.2X76.2X12.7'), 
+('HSS8X3X.375','HSS8X3X3/8','HSS203.2X76.2X9.5','HSS203.2X76.2X9.5'), 
+('HSS8X3X.313','HSS8X3X5/16','HSS203.2X76.2X7.9','HSS203.2X76.2X7.9'), 
+('HSS8X3X.250','HSS8X3X1/4','HSS203.2X76.2X6.4','HSS203.2X76.2X6.4'), 
+('HSS8X3X.188','HSS8X3X3/16','HSS203.2X76.2X4.8','HSS203.2X76.2X4.8'), 
+('HSS8X3X.125','HSS8X3X1/8','HSS203.2X76.2X3.2','HSS203.2X76.2X3.2'), 
+('HSS8X2X.500','HSS8X2X1/2','HSS203.2X50.8X12.7','HSS203.2X50.8X12.7'), 
+('HSS8X2X.375','HSS8X2X3/8','HSS203.2X50.8X9.5','HSS203.2X50.8X9.5'), 
+('HSS8X2X.313','HSS8X2X5/16','HSS203.2X50.8X7.9','HSS203.2X50.8X7.9'), 
+('HSS8X2X.250','HSS8X2X1/4','HSS203.2X50.8X6.4','HSS203.2X50.8X6.4'), 
+('HSS8X2X.188','HSS8X2X3/16','HSS203.2X50.8X4.8','HSS203.2X50.8X4.8'), 
+('HSS8X2X.125','HSS8X2X1/8','HSS203.2X50.8X3.2','HSS203.2X50.8X3.2'), 
+('HSS7X7X.625','HSS7X7X5/8','HSS177.8X177.8X15.9','HSS177.8X177.8X15.9'), 
+('HSS7X7X.500','HSS7X7X1/2','HSS177.8X177.8X12.7','HSS177.8X177.8X12.7'), 
+('HSS7X7X.375','HSS7X7X3/8','HSS177.8X177.8X9.5','HSS177.8X177.8X9.5'), 
+('HSS7X7X.313','HSS7X7X5/16','HSS177.8X177.8X7.9','HSS177.8X177.8X7.9'), 
+('HSS7X7X.250','HSS7X7X1/4','HSS177.8X177.8X6.4','HSS177.8X177.8X6.4'), 
+('HSS7X7X.188','HSS7X7X3/16','HSS177.8X177.8X4.8','HSS177.8X177.8X4.8'), 
+('HSS7X7X.125','HSS7X7X1/8','HSS177.8X177.8X3.2','HSS177.8X177.8X3.2'), 
+('HSS7X5X.500','HSS7X5X1/2','HSS177.8X127X12.7','HSS177.8X127X12.7'), 
+('HSS7X5X.375','HSS7X5X3/8','HSS177.8X127X9.5','HSS177.8X127X9.5'), 
+('HSS7X5X.313','HSS7X5X5/16','HSS177.8X127X7.9','HSS177.8X127X7.9'), 
+('HSS7X5X.250','HSS7X5X1/4','HSS177.8X127X6.4','HSS177.8X127X6.4'), 
+('HSS7X5X.188','HSS7X5X3/16','HSS177.8X127X4.8','HSS177.8X127X4.8'), 
+('HSS7X5X.125','HSS7X5X1/8','HSS177.8X127X3.2','HSS177.8X127X3.2'), 
+('HSS7X4X.500','HSS7X4X1/2','HSS177.8X101.6X12.7','HSS177.8X101.6X12.7'), 
+('HSS7X4X.375','HSS7X4X3/8','HSS177.8X101.6X9.5','HSS177.8X101.6X9.5'), 
+('HSS7X4X.313','HSS7X4X5/16','HSS177.8X101.6X7.9','HSS177.8X101.6X7.9'), 
+('HSS7X4X.250','HSS7X4X1/4','HSS177.8X101.6X6.4','HSS177.8X101.6X6.4'), 
+('HSS7X4X.188','HSS7X4X3/16','HSS177.8X101.6X4.8','HSS177.8X101.6X4.8'), 
+('HSS7X4X.125','HSS7X4X1/8','HSS177.8X101.6X3.2','HSS177.8X101.6X3.2'), 
+('HSS7X3X.500','HSS7X3X1/2','HSS177.8X76.2X12.7','HSS177.8X76.2X12.7'), 
+('HSS7X3X.375','HSS7X3X3/8','HSS177.8X76.2X9.5','HSS177.8X76.2X9.5'), 
+('HSS7X3X.313','HSS7X3X5/16','HSS177.8X76.2X7.9','HSS177.8X76.2X7.9'), 
+('HSS7X3X.250','HSS7X3X1/4','HSS177.8X76.2X6.4','HSS177.8X76.2X6.4'), 
+('HSS7X3X.188','HSS7X3X3/16','HSS177.8X76.2X4.8','HSS177.8X76.2X4.8'), 
+('HSS7X3X.125','HSS7X3X1/8','HSS177.8X76.2X3.2','HSS177.8X76.2X3.2'), 
+('HSS7X2X.250','HSS7X2X1/4','HSS177.8X50.8X6.4','HSS177.8X50.8X6.4'), 
+('HSS7X2X.188','HSS7X2X3/16','HSS177.8X50.8X4.8','HSS177.8X50.8X4.8'), 
+('HSS7X2X.125','HSS7X2X1/8','HSS177.8X50.8X3.2','HSS177.8X50.8X3.2'), 
+('HSS6X6X.625','HSS6X6X5/8','HSS152.4X152.4X15.9','HSS152.4X152.4X15.9'), 
+('HSS6X6X.500','HSS6X6X1/2','HSS152.4X152.4X12.7','HSS152.4X152.4X12.7'), 
+('HSS6X6X.375','HSS6X6X3/8','HSS152.4X152.4X9.5','HSS152.4X152.4X9.5'), 
+('HSS6X6X.313','HSS6X6X5/16','HSS152.4X152.4X7.9','HSS152.4X152.4X7.9'), 
+('HSS6X6X.250','HSS6X6X1/4','HSS152.4X152.4X6.4','HSS152.4X152.4X6.4'), 
+('HSS6X6X.188','HSS6X6X3/16','HSS152.4X152.4X4.8','HSS152.4X152.4X4.8'), 
+('HSS6X6X.125','HSS6X6X1/8','HSS152.4X152.4X3.2','HSS152.4X152.4X3.2'), 
+('HSS6X5X.500','HSS6X5X1/2','HSS152.4X127X12.7','HSS152.4X127X12.7'), 
+('HSS6X5X.375','HSS6X5X3/8','HSS152.4X127X9.5','HSS152.4X127X9.5'), 
+('HSS6X5X.313','HSS6X5X5/16','HSS152.4X127X7.9','HSS152.4X127X7.9'), 
+('HSS6X5X.250','HSS6X5X1/4','HSS152.4X127X6.4','HSS152.4X127X6.4'), 
+('HSS6X5X.188','HSS6X5X3/16','HSS152.4X127X4.8','HSS152.4X127X4.8'), 
+('HSS6X5X.125','HSS6X5X1/8','HSS152.4X127X3.2','HSS152.4X127X3.2'), 
+('HSS6X4X.500','HSS6X4X1/2','HSS152.4X101.6X12.7','HSS152.4X101.6X12.7'), 
+('HSS6X4X.375','HSS6X4X3/8','HSS152.4X101.6X9.5','HSS152.4X101.6X9.5'), 
+('HSS6X4X.313','HSS6X4X5/16','HSS152.4X101.6X7.9','HSS152.4X101.6X7.9'), 
+('HSS6X4X.250','HSS6X4X1/4','HSS152.4X101.6X6.4','HSS152.4X101.6X6.4'), 
+('HSS6X4X.188','HSS6X4X3/16','HSS152.4X101.6X4.8','HSS152.4X101.6X4.8'), 
+('HSS6X4X.125','HSS6X4X1/8','HSS152.4X101.6X3.2','HSS152.4X101.6X3.2'), 
+('HSS6X3X.500','HSS6X3X1/2','HSS152.4X76.2X12.7','HSS152.4X76.2X12.7'), 
+('HSS6X3X.375','HSS6X3X3/8','HSS152.4X76.2X9.5','HSS152.4X76.2X9.5'), 
+('HSS6X3X.313','HSS6X3X5/16','HSS152.4X76.2X7.9','HSS152.4X76.2X7.9'), 
+('HSS6X3X.250','HSS6X3X1/4','HSS152.4X76.2X6.4','HSS152.4X76.2X6.4'), 
+('HSS6X3X.188','HSS6X3X3/16','HSS152.4X76.2X4.8','HSS152.4X76.2X4.8'), 
+('HSS6X3X.125','HSS6X3X1/8','HSS152.4X76.2X3.2','HSS152.4X76.2X3.2'), 
+('HSS6X2X.375','HSS6X2X3/8','HSS152.4X50.8X9.5','HSS152.4X50.8X9.5'), 
+('HSS6X2X.313','HSS6X2X5/16','HSS152.4X50.8X7.9','HSS152.4X50.8X7.9'), 
+('HSS6X2X.250','HSS6X2X1/4','HSS152.4X50.8X6.4','HSS152.4X50.8X6.4'), 
+('HSS6X2X.188','HSS6X2X3/16','HSS152.4X50.8X4.8','HSS152.4X50.8X4.8'), 
+('HSS6X2X.125','HSS6X2X1/8','HSS152.4X50.8X3.2','HSS152.4X50.8X3.2'), 
+('HSS5-1/2X5-1/2X.375','HSS5-1/2X5-1/2X3/8','HSS139.7X139.7X9.5','HSS139.7X139.7X9.5'), 
+('HSS5-1/2X5-1/2X.313','HSS5-1/2X5-1/2X5/16','HSS139.7X139.7X7.9','HSS139.7X139.7X7.9'), 
+('HSS5-1/2X5-1/2X.250','HSS5-1/2X5-1/2X1/4','HSS139.7X139.7X6.4','HSS139.7X139.7X6.4'), 
+('HSS5-1/2X5-1/2X.188','HSS5-1/2X5-1/2X3/16','HSS139.7X139.7X4.8','HSS139.7X139.7X4.8'), 
+('HSS5-1/2X5-1/2X.125','HSS5-1/2X5-1/2X1/8','HSS139.7X139.7X3.2','HSS139.7X139.7X3.2'), 
+('HSS5X5X.500','HSS5X5X1/2','HSS127X127X12.7','HSS127X127X12.7'), 
+('HSS5X5X.375','HSS5X5X3/8','HSS127X127X9.5','HSS127X127X9.5'), 
+('HSS5X5X.313','HSS5X5X5/16','HSS127X127X7.9','HSS127X127X7.9'), 
+('HSS5X5X.250','HSS5X5X1/4','HSS127X127X6.4','HSS127X127X6.4'), 
+('HSS5X5X.188','HSS5X5X3/16','HSS127X127X4.8','HSS127X127X4.8'), 
+('HSS5X5X.125','HSS5X5X1/8','HSS127X127X3.2','HSS127X127X3.2'), 
+('HSS5X4X.500','HSS5X4X1/2','HSS127X101.6X12.7','HSS127X101.6X12.7'), 
+('HSS5X4X.375','HSS5X4X3/8','HSS127X101.6X9.5','HSS127X101.6X9.5'), 
+('HSS5X4X.313','HSS5X4X5/16','HSS127X101.6X7.9','HSS127X101.6X7.9'), 
+('HSS5X4X.250','HSS5X4X1/4','HSS127X101.6X6.4','HSS127X101.6X6.4'), 
+('HSS5X4X.188','HSS5X4X3/16','HSS127X101.6X4.8','HSS127X101.6X4.8'), 
+('HSS5X4X.125','HSS5X4X1/8','HSS127X101.6X3.2','HSS127X101.6X3.2'), 
+('HSS5X3X.500','HSS5X3X1/2','HSS127X76.2X12.7','HSS127X76.2X12.7'), 
+('HSS5X3X.375','HSS5X3X3/8','HSS127X76.2X9.5','HSS127X76.2X9.5'), 
+('HSS5X3X.313','HSS5X3X5/16','HSS127X76.2X7.9','HSS127X76.2X7.9'), 
+('HSS5X3X.250','HSS5X3X1/4','HSS127X76.2X6.4','HSS127X76.2X6.4'), 
+('HSS5X3X.188','HSS5X3X3/16','HSS127X76.2X4.8','HSS127X76.2X4.8'), 
+('HSS5X3X.125','HSS5X3X1/8','HSS127X76.2X3.2','HSS127X76.2X3.2'), 
+('HSS5X2-1/2X.250','HSS5X2-1/2X1/4','HSS127X63.5X6.4','HSS127X63.5X6.4'), 
+('HSS5X2-1/2X.188','HSS5X2-1/2X3/16','HSS127X63.5X4.8','HSS127X63.5X4.8'), 
+('HSS5X2-1/2X.125','HSS5X2-1/2X1/8','HSS127X63.5X3.2','HSS127X63.5X3.2'), 
+('HSS5X2X.375','HSS5X2X3/8','HSS127X50.8X9.5','HSS127X50.8X9.5'), 
+('HSS5X2X.313','HSS5X2X5/16','HSS127X50.8X7.9','HSS127X50.8X7.9'), 
+('HSS5X2X.250','HSS5X2X1/4','HSS127X50.8X6.4','HSS127X50.8X6.4'), 
+('HSS5X2X.188','HSS5X2X3/16','HSS127X50.8X4.8','HSS127X50.8X4.8'), 
+('HSS5X2X.125','HSS5X2X1/8','HSS127X50.8X3.2','HSS127X50.8X3.2'), 
+('HSS4-1/2X4-1/2X.500','HSS4-1/2X4-1/2X1/2','HSS114.3X114.3X12.7','HSS114.3X114.3X12.7'), 
+('HSS4-1/2X4-1/2X.375','HSS4-1/2X4-1/2X3/8','HSS114.3X114.3X9.5','HSS114.3X114.3X9.5'), 
+('HSS4-1/2X4-1/2X.313','HSS4-1/2X4-1/2X5/16','HSS114.3X114.3X7.9','HSS114.3X114.3X7.9'), 
+('HSS4-1/2X4-1/2X.250','HSS4-1/2X4-1/2X1/4','HSS114.3X114.3X6.4','HSS114.3X114.3X6.4'), 
+('HSS4-1/2X4-1/2X.188','HSS4-1/2X4-1/2X3/16','HSS114.3X114.3X4.8','HSS114.3X114.3X4.8'), 
+('HSS4-1/2X4-1/2X.125','HSS4-1/2X4-1/2X1/8','HSS114.3X114.3X3.2','HSS114.3X114.3X3.2'), 
+('HSS4X4X.500','HSS4X4X1/2','HSS101.6X101.6X12.7','HSS101.6X101.6X12.7'), 
+('HSS4X4X.375','HSS4X4X3/8','HSS101.6X101.6X9.5','HSS101.6X101.6X9.5'), 
+('HSS4X4X.313','HSS4X4X5/16','HSS101.6X101.6X7.9','HSS101.6X101.6X7.9'), 
+('HSS4X4X.250','HSS4X4X1/4','HSS101.6X101.6X6.4','HSS101.6X101.6X6.4'), 
+('HSS4X4X.188','HSS4X4X3/16','HSS101.6X101.6X4.8','HSS101.6X101.6X4.8'), 
+('HSS4X4X.125','HSS4X4X1/8','HSS101.6X101.6X3.2','HSS101.6X101.6X3.2'), 
+('HSS4X3X.375','HSS4X3X3/8','HSS101.6X76.2X9.5','HSS101.6X76.2X9.5'), 
+('HSS4X3X.313','HSS4X3X5/16','HSS101.6X76.2X7.9','HSS101.6X76.2X7.9'), 
+('HSS4X3X.250','HSS4X3X1/4','HSS101.6X76.2X6.4','HSS101.6X76.2X6.4'), 
+('HSS4X3X.188','HSS4X3X3/16','HSS101.6X76.2X4.8','HSS101.6X76.2X4.8'), 
+('HSS4X3X.125','HSS4X3X1/8','HSS101.6X76.2X3.2','HSS101.6X76.2X3.2'), 
+('HSS4X2-1/2X.250','HSS4X2-1/2X1/4','HSS101.6X63.5X6.4','HSS101.6X63.5X6.4'), 
+('HSS4X2-1/2X.188','HSS4X2-1/2X3/16','HSS101.6X63.5X4.8','HSS101.6X63.5X4.8'), 
+('HSS4X2-1/2X.125','HSS4X2-1/2X1/8','HSS101.6X63.5X3.2','HSS101.6X63.5X3.2'), 
+('HSS4X2X.375','HSS4X2X3/8','HSS101.6X50.8X9.5','HSS101.6X50.8X9.5'), 
+('HSS4X2X.313','HSS4X2X5/16','HSS101.6X50.8X7.9','HSS101.6X50.8X7.9'), 
+('HSS4X2X.250','HSS4X2X1/4','HSS101.6X50.8X6.4','HSS101.6X50.8X6.4'), 
+('HSS4X2X.188','HSS4X2X3/16','HSS101.6X50.8X4.8','HSS101.6X50.8X4.8'), 
+('HSS4X2X.125','HSS4X2X1/8','HSS101.6X50.8X3.2','HSS101.6X50.8X3.2'), 
+('HSS4X1-1/2X.250','HSS4X1-1/2X1/4','HSS101.6X38.1X6.4','HSS101.6X38.1X6.4'), 
+('HSS4X1-1/2X.188','HSS4X1-1/2X3/16','HSS101.6X38.1X4.8','HSS101.6X38.1X4.8'), 
+('HSS4X1-1/2X.125','HSS4X1-1/2X1/8','HSS101.6X38.1X3.2','HSS101.6X38.1X3.2'), 
+('HSS3-1/2X3-1/2X.375','HSS3-1/2X3-1/2X3/8','HSS88.9X88.9X9.5','HSS88.9X88.9X9.5'), 
+('HSS3-1/2X3-1/2X.313','HSS3-1/2X3-1/2X5/16','HSS88.9X88.9X7.9','HSS88.9X88.9X7.9'), 
+('HSS3-1/2X3-1/2X.250','HSS3-1/2X3-1/2X1/4','HSS88.9X88.9X6.4','HSS88.9X88.9X6.4'), 
+('HSS3-1/2X3-1/2X188','HSS3-1/2X3-1/2X3/16','HSS88.9X88.9X4.8','HSS88.9X88.9X4.8'), 
+('HSS3-1/2X3-1/2X.125','HSS3-1/2X3-1/2X1/8','HSS88.9X88.9X3.2','HSS88.9X88.9X3.2'), 
+('HSS3-1/2X2-1/2X.375','HSS3-1/2X2-1/2X3/8','HSS88.9X63.5X9.5','HSS88.9X63.5X9.5'), 
+('HSS3-1/2X2-1/2X.313','HSS3-1/2X2-1/2X5/16','HSS88.9X63.5X7.9','HSS88.9X63.5X7.9'), 
+('HSS3-1/2X2-1/2X.250','HSS3-1/2X2-1/2X1/4','HSS88.9X63.5X6.4','HSS88.9X63.5X6.4'), 
+('HSS3-1/2X2-1/2X.188','HSS3-1/2X2-1/2X3/16','HSS88.9X63.5X4.8','HSS88.9X63.5X4.8'), 
+('HSS3-1/2X2-1/2X.125','HSS3-1/2X2-1/2X1/8','HSS88.9X63.5X3.2','HSS88.9X63.5X3.2'), 
+('HSS3-1/2X2X.250','HSS3-1/2X2X1/4','HSS88.9X50.8X6.4','HSS88.9X50.8X6.4'), 
+('HSS3-1/2X2X.188','HSS3-1/2X2X3/16','HSS88.9X50.8X4.8','HSS88.9X50.8X4.8'), 
+('HSS3-1/2X2X.125','HSS3-1/2X2X1/8','HSS88.9X50.8X3.2','HSS88.9X50.8X3.2'), 
+('HSS3-1/2X1-1/2X.250','HSS3-1/2X1-1/2X1/4','HSS88.9X38.1X6.4','HSS88.9X38.1X6.4'), 
+('HSS3-1/2X1-1/2X.188','HSS3-1/2X1-1/2X3/16','HSS88.9X38.1X4.8','HSS88.9X38.1X4.8'), 
+('HSS3-1/2X1-1/2X.125','HSS3-1/2X1-1/2X1/8','HSS88.9X38.1X3.2','HSS88.9X38.1X3.2'), 
+('HSS3X3X.375','HSS3X3X3/8','HSS76.2X76.2X9.5','HSS76.2X76.2X9.5'), 
+('HSS3X3X.313','HSS3X3X5/16','HSS76.2X76.2X7.9','HSS76.2X76.2X7.9'), 
+('HSS3X3X.250','HSS3X3X1/4','HSS76.2X76.2X6.4','HSS76.2X76.2X6.4'), 
+('HSS3X3X.188','HSS3X3X3/16','HSS76.2X76.2X4.8','HSS76.2X76.2X4.8'), 
+('HSS3X3X.125','HSS3X3X1/8','HSS76.2X76.2X3.2','HSS76.2X76.2X3.2'), 
+('HSS3X2-1/2X.313','HSS3X2-1/2X5/16','HSS76.2X63.5X7.9','HSS76.2X63.5X7.9'), 
+('HSS3X2-1/2X.250','HSS3X2-1/2X1/4','HSS76.2X63.5X6.4','HSS76.2X63.5X6.4'), 
+('HSS3X2-1/2X.188','HSS3X2-1/2X3/16','HSS76.2X63.5X4.8','HSS76.2X63.5X4.8'), 
+('HSS3X2-1/2X.125','HSS3X2-1/2X1/8','HSS76.2X63.5X3.2','HSS76.2X63.5X3.2'), 
+('HSS3X2X.313','HSS3X2X5/16','HSS76.2X50.8X7.9','HSS76.2X50.8X7.9'), 
+('HSS3X2X.250','HSS3X2X1/4','HSS76.2X50.8X6.4','HSS76.2X50.8X6.4'), 
+('HSS3X2X.188','HSS3X2X3/16','HSS76.2X50.8X4.8','HSS76.2X50.8X4.8'), 
+('HSS3X2X.125','HSS3X2X1/8','HSS76.2X50.8X3.2','HSS76.2X50.8X3.2'), 
+('HSS3X1-1/2X.250','HSS3X1-1/2X1/4','HSS76.2X38.1X6.4','HSS76.2X38.1X6.4'), 
+('HSS3X1-1/2X.188','HSS3X1-1/2X3/16','HSS76.2X38.1X4.8','HSS76.2X38.1X4.8'), 
+('HSS3X1-1/2X.125','HSS3X1-1/2X1/8','HSS76.2X38.1X3.2','HSS76.2X38.1X3.2'), 
+('HSS3X1X.188','HSS3X1X3/16','HSS76.2X25.4X4.8','HSS76.2X25.4X4.8'), 
+('HSS3X1X.125','HSS3X1X1/8','HSS76.2X25.4X3.2','HSS76.2X25.4X3.2'), 
+('HSS2-1/2X2-1/2X.313','HSS2-1/2X2-1/2X5/16','HSS63.5X63.5X7.9','HSS63.5X63.5X7.9'), 
+('HSS2-1/2X2-1/2X.250','HSS2-1/2X2-1/2X1/4','HSS63.5X63.5X6.4','HSS63.5X63.5X6.4'), 
+('HSS2-1/2X2-1/2X.188','HSS2-1/2X2-1/2X3/16','HSS63.5X63.5X4.8','HSS63.5X63.5X4.8'), 
+('HSS2-1/2X2-1/2X.125','HSS2-1/2X2-1/2X1/8','HSS63.5X63.5X3.2','HSS63.5X63.5X3.2'), 
+('HSS2-1/2X2X.250','HSS2-1/2X2X1/4','HSS63.5X50.8X6.4','HSS63.5X50.8X6.4'), 
+('HSS2-1/2X2X.188','HSS2-1/2X2X3/16','HSS63.5X50.8X4.8','HSS63.5X50.8X4.8'), 
+('HSS2-1/2X2X.125','HSS2-1/2X2X1/8','HSS63.5X50.8X3.2','HSS63.5X50.8X3.2'), 
+('HSS2-1/2X1-1/2X.250','HSS2-1/2X1-1/2X1/4','HSS63.5X38.1X6.4','HSS63.5X38.1X6.4'), 
+('HSS2-1/2X1-1/2X.188','HSS2-1/2X1-1/2X3/16','HSS63.5X38.1X4.8','HSS63.5X38.1X4.8'), 
+('HSS2-1/2X1-1/2X.125','HSS2-1/2X1-1/2X1/8','HSS63.5X38.1X3.2','HSS63.5X38.1X3.2'), 
+('HSS2-1/2X1X.188','HSS2-1/2X1X3/16','HSS63.5X25.4X4.8','HSS63.5X25.4X4.8'), 
+('HSS2-1/2X1X.125','HSS2-1/2X1X1/8','HSS63.5X25.4X3.2','HSS63.5X25.4X3.2'), 
+('HSS2-1/4X2-1/4X.250','HSS2-1/4X2-1/4X1/4','HSS57.2X57.2X6.4','HSS57.2X57.2X6.4'), 
+('HSS2-1/4X2-1/4X.188','HSS2-1/4X2-1/4X3/16','HSS57.2X57.2X4.8','HSS57.2X57.2X4.8'), 
+('HSS2-1/4X2-1/4X.125','HSS2-1/4X2-1/4X1/8','HSS57.2X57.2X3.2','HSS57.2X57.2X3.2'), 
+('HSS2X2X.250','HSS2X2X1/4','HSS50.8X50.8X6.4','HSS50.8X50.8X6.4'), 
+('HSS2X2X.188','HSS2X2X3/16','HSS50.8X50.8X4.8','HSS50.8X50.8X4.8'), 
+('HSS2X2X.125','HSS2X2X1/8','HSS50.8X50.8X3.2','HSS50.8X50.8X3.2'), 
+('HSS2X1-1/2X.188','HSS2X1-1/2X3/16','HSS50.8X38.1X4.8','HSS50.8X38.1X4.8'), 
+('HSS2X1-1/2X.125','HSS2X1-1/2X1/8','HSS50.8X38.1X3.2','HSS50.8X38.1X3.2'), 
+('HSS2X1X.188','HSS2X1X3/16','HSS50.8X25.4X4.8','HSS50.8X25.4X4.8'), 
+('HSS2X1X.125','HSS2X1X1/8','HSS50.8X25.4X3.2','HSS50.8X25.4X3.2'), 
+('HSS1-1/2X1-1/2X0.250','HSS1-1/2X1-1/2X1/4','HSS38.1X38.1X6.4','HSS38.1X38.1X6.4'), 
+('HSS1-1/2X1-1/2X0.188','HSS1-1/2X1-1/2X3/16','HSS38.1X38.1X4.8','HSS38.1X38.1X4.8'), 
+('HSS1-1/2X1-1/2X0.125','HSS1-1/2X1-1/2X1/8','HSS38.1X38.1X3.2','HSS38.1X38.1X3.2'), 
+('HSS28X1.000','HSS28.000X1.000','HSS711.2X25.4','HSS711.2X25.4'), 
+('HSS28X.875','HSS28.000X0.875','HSS711.2X22.2','HSS711.2X22.2'), 
+('HSS28X.750','HSS28.000X0.750','HSS711.2X19','HSS711.2X19'), 
+('HSS28X.625','HSS28.000X0.625','HSS711.2X15.9','HSS711.2X15.9'), 
+('HSS28X.500','HSS28.000X0.500','HSS711.2X12.7','HSS711.2X12.7'), 
+('HSS28X.375','HSS28.000X0.375','HSS711.2X9.5','HSS711.2X9.5'), 
+('HSS26X.750','HSS26.000X0.750','HSS660.4X19','HSS660.4X19'), 
+('HSS26X.625','HSS26.000X0.625','HSS660.4X15.9','HSS660.4X15.9'), 
+('HSS26X.500','HSS26.000X0.500','HSS660.4X12.7','HSS660.4X12.7'), 
+('HSS26X.375','HSS26.000X0.375','HSS660.4X9.5','HSS660.4X9.5'), 
+('HSS26X.313','HSS26.000X0.313','HSS660.4X8','HSS660.4X8'), 
+('HSS24X1.000','HSS24.000X1.000','HSS609.6X25.4','HSS609.6X25.4'), 
+('HSS24X.875','HSS24.000X0.875','HSS609.6X22.2','HSS609.6X22.2'), 
+('HSS24X.750','HSS24.000X0.750','HSS609.6X19','HSS609.6X19'), 
+('HSS24X.625','HSS24.000X0.625','HSS609.6X15.9','HSS609.6X15.9'), 
+('HSS24X.500','HSS24.000X0.500','HSS609.6X12.7','HSS609.6X12.7'), 
+('HSS24X.375','HSS24.000X0.375','HSS609.6X9.5','HSS609.6X9.5'), 
+('HSS24X.313','HSS24.000X0.313','HSS609.6X8','HSS609.6X8'), 
+('HSS22X.750','HSS22.000X0.750','HSS558.8X19','HSS558.8X19'), 
+('HSS22X.625','HSS22.000X0.625','HSS558.8X15.9','HSS558.8X15.9'), 
+('HSS22X.500','HSS22.000X0.500','HSS558.8X12.7','HSS558.8X12.7'), 
+('HSS22X.375','HSS22.000X0.375','HSS558.8X9.5','HSS558.8X9.5'), 
+('HSS22X.313','HSS22.000X0.313','HSS558.8X8','HSS558.8X8'), 
+('HSS20X1.000','HSS20.000X1.000','HSS508X25.4','HSS508X25.4'), 
+('HSS20X.875','HSS20.000X0.875','HSS508X22.2','HSS508X22.2'), 
+('HSS20X.750','HSS20.000X0.750','HSS508X19','HSS508X19'), 
+('HSS20X.625','HSS20.000X0.625','HSS508X15.9','HSS508X15.9'), 
+('HSS20X.500','HSS20.000X0.500','HSS508X12.7','HSS508X12.7'), 
+('HSS20X.375','HSS20.000X0.375','HSS508X9.5','HSS508X9.5'), 
+('HSS20X.313','HSS20.000X0.313','HSS508X8','HSS508X8'), 
+('HSS20X.250','HSS20.000X0.250','HSS508X6.4','HSS508X6.4'), 
+('HSS18X1.000','HSS18.000X1.000','HSS457.2X25.4','HSS457.2X25.4'), 
+('HSS18X.875','HSS18.000X0.875','HSS457.2X22.2','HSS457.2X22.2'), 
+('HSS18X.750','HSS18.000X0.750','HSS457.2X19','HSS457.2X19'), 
+('HSS18X.625','HSS18.000X0.625','HSS457.2X15.9','HSS457.2X15.9'), 
+('HSS18X.500','HSS18.000X0.500','HSS457.2X12.7','HSS457.2X12.7'), 
+('HSS18X.375','HSS18.000X0.375','HSS457.2X9.5','HSS457.2X9.5'), 
+('HSS18X.313','HSS18.000X0.313','HSS457.2X8','HSS457.2X8'), 
+('HSS18X.250','HSS18.000X0.250','HSS457.2X6.4','HSS457.2X6.4'), 
+('HSS16X1.000','HSS16.000X1.000','HSS406.4X25.4','HSS406.4X25.4'), 
+('HSS16X.875','HSS16.000X0.875','HSS406.4X22.2','HSS406.4X22.2'), 
+('HSS16X.750','HSS16.000X0.750','HSS406.4X19','HSS406.4X19'), 
+('HSS16X.625','HSS16.000X0.625','HSS406.4X15.9','HSS406.4X15.9'), 
+('HSS16X.500','HSS16.000X0.500','HSS406.4X12.7','HSS406.4X12.7'), 
+('HSS16X.438','HSS16.000X0.438','HSS406.4X11.1','HSS406.4X11.1'), 
+('HSS16X.375','HSS16.000X0.375','HSS406.4X9.5','HSS406.4X9.5'), 
+('HSS16X.312','HSS16.000X0.312','HSS406.4X7.9','HSS406.4X7.9'), 
+('HSS16X.250','HSS16.000X0.250','HSS406.4X6.4','HSS406.4X6.4'), 
+('HSS14X1.000','HSS14.000X1.000','HSS355.6X25.4','HSS355.6X25.4'), 
+('HSS14X.875','HSS14.000X0.875','HSS355.6X22.2','HSS355.6X22.2'), 
+('HSS14X.750','HSS14.000X0.750','HSS355.6X19','HSS355.6X19'), 
+('HSS14X.625','HSS14.000X0.625','HSS355.6X15.9','HSS355.6X15.9'), 
+('HSS14X.500','HSS14.000X0.500','HSS355.6X12.7','HSS355.6X12.7'), 
+('HSS14X.375','HSS14.000X0.375','HSS355.6X9.5','HSS355.6X9.5'), 
+('HSS14X.312','HSS14.000X0.312','HSS355.6X7.9','HSS355.6X7.9'), 
+('HSS14X.250','HSS14.000X0.250','HSS355.6X6.4','HSS355.6X6.4'), 
+('HSS14X.188','HSS14.000X0.188','HSS355.6X4.8','HSS355.6X4.8'), 
+('HSS13.375X.625','HSS13.375X0.625','HSS339.7X15.9','HSS339.7X15.9'), 
+('HSS13.375X.500','HSS13.375X0.500','HSS339.7X12.7','HSS339.7X12.7'), 
+('HSS13.375X.375','HSS13.375X0.375','HSS339.7X9.5','HSS339.7X9.5'), 
+('HSS13.375X.313','HSS13.375X0.313','HSS339.7X8','HSS339.7X8'), 
+('HSS13.375X.250','HSS13.375X0.250','HSS339.7X6.4','HSS339.7X6.4'), 
+('HSS13.375X.188','HSS13.375X0.188','HSS339.7X4.8','HSS339.7X4.8'), 
+('HSS12.75X.750','HSS12.750X0.750','HSS323.8X19','HSS323.8X19'), 
+('HSS12.75X.625','HSS12.750X0.625','HSS323.8X15.9','HSS323.8X15.9'), 
+('HSS12.75X.500','HSS12.750X0.500','HSS323.8X12.7','HSS323.8X12.7'), 
+('HSS12.75X.375','HSS12.750X0.375','HSS323.8X9.5','HSS323.8X9.5'), 
+('HSS12.75X.250','HSS12.750X0.250','HSS323.8X6.4','HSS323.8X6.4'), 
+('HSS12.75X.188','HSS12.750X0.188','HSS323.8X4.8','HSS323.8X4.8'), 
+('HSS12X.625','HSS12.000X0.625','HSS304.8X15.9','HSS304.8X15.9'), 
+('HSS12X.500','HSS12.000X0.500','HSS304.8X12.7','HSS304.8X12.7'), 
+('HSS12X.375','HSS12.000X0.375','HSS304.8X9.5','HSS304.8X9.5'), 
+('HSS12X.250','HSS12.000X0.250','HSS304.8X6.4','HSS304.8X6.4'), 
+('HSS11.75X.625','HSS11.750X0.625','HSS298.5X15.9','HSS298.5X15.9'), 
+('HSS11.75X.500','HSS11.750X0.500','HSS298.5X12.7','HSS298.5X12.7'), 
+('HSS11.75X.375','HSS11.750X0.375','HSS298.5X9.5','HSS298.5X9.5'), 
+('HSS11.75X.337','HSS11.750X0.337','HSS298.5X8.6','HSS298.5X8.6'), 
+('HSS11.75X.250','HSS11.750X0.250','HSS298.5X6.4','HSS298.5X6.4'), 
+('HSS10.75X.625','HSS10.750X0.625','HSS273.1X15.9','HSS273.1X15.9'), 
+('HSS10.75X.500','HSS10.750X0.500','HSS273.1X12.7','HSS273.1X12.7'), 
+('HSS10.75X.375','HSS10.750X0.375','HSS273.1X9.5','HSS273.1X9.5'), 
+('HSS10.75X.313','HSS10.750X0.313','HSS273.1X8','HSS273.1X8'), 
+('HSS10.75X.250','HSS10.750X0.250','HSS273.1X6.4','HSS273.1X6.4'), 
+('HSS10.75X.188','HSS10.750X0.188','HSS273.1X4.8','HSS273.1X4.8'), 
+('HSS10X.625','HSS10.000X0.625','HSS254X15.9','HSS254X15.9'), 
+('HSS10X.500','HSS10.000X0.500','HSS254X12.7','HSS254X12.7'), 
+('HSS10X.375','HSS10.000X0.375','HSS254X9.5','HSS254X9.5'), 
+('HSS10X.312','HSS10.000X0.312','HSS254X7.9','HSS254X7.9'), 
+('HSS10X.250','HSS10.000X0.250','HSS254X6.4','HSS254X6.4'), 
+('HSS10X.188','HSS10.000X0.188','HSS254X4.8','HSS254X4.8'), 
+('HSS9.625X.625','HSS9.625X0.625','HSS244.5X15.9','HSS244.5X15.9'), 
+('HSS9.625X.500','HSS9.625X0.500','HSS244.5X12.7','HSS244.5X12.7'), 
+('HSS9.625X.375','HSS9.625X0.375','HSS244.5X9.5','HSS244.5X9.5'), 
+('HSS9.625X.312','HSS9.625X0.312','HSS244.5X7.9','HSS244.5X7.9'), 
+('HSS9.625X.250','HSS9.625X0.250','HSS244.5X6.4','HSS244.5X6.4'), 
+('HSS9.625X.188','HSS9.625X0.188','HSS244.5X4.8','HSS244.5X4.8'), 
+('HSS8.625X.625','HSS8.625X0.625','HSS219.1X15.9','HSS219.1X15.9'), 
+('HSS8.625X.500','HSS8.625X0.500','HSS219.1X12.7','HSS219.1X12.7'), 
+('HSS8.625X.375','HSS8.625X0.375','HSS219.1X9.5','HSS219.1X9.5'), 
+('HSS8.625X.322','HSS8.625X0.322','HSS219.1X8.2','HSS219.1X8.2'), 
+('HSS8.625X.250','HSS8.625X0.250','HSS219.1X6.4','HSS219.1X6.4'), 
+('HSS8.625X.188','HSS8.625X0.188','HSS219.1X4.8','HSS219.1X4.8'), 
+('HSS7.5X.500','HSS7.500X0.500','HSS190.5X12.7','HSS190.5X12.7'), 
+('HSS7.5X.375','HSS7.500X0.375','HSS190.5X9.5','HSS190.5X9.5'), 
+('HSS7.5X.312','HSS7.500X0.312','HSS190.5X7.9','HSS190.5X7.9'), 
+('HSS7.5X.250','HSS7.500X0.250','HSS190.5X6.4','HSS190.5X6.4'), 
+('HSS7.5X.188','HSS7.500X0.188','HSS190.5X4.8','HSS190.5X4.8'), 
+('HSS7X.500','HSS7.000X0.500','HSS177.8X12.7','HSS177.8X12.7'), 
+('HSS7X.375','HSS7.000X0.375','HSS177.8X9.5','HSS177.8X9.5'), 
+('HSS7X.312','HSS7.000X0.312','HSS177.8X7.9','HSS177.8X7.9'), 
+('HSS7X.250','HSS7.000X0.250','HSS177.8X6.4','HSS177.8X6.4'), 
+('HSS7X.188','HSS7.000X0.188','HSS177.8X4.8','HSS177.8X4.8'), 
+('HSS7X.125','HSS7.000X0.125','HSS177.8X3.2','HSS177.8X3.2'), 
+('HSS6.875X.375','HSS6.875X0.375','HSS174.6X9.5','HSS174.6X9.5'), 
+('HSS6.875X.312','HSS6.875X0.312','HSS174.6X7.9','HSS174.6X7.9'), 
+('HSS6.875X.250','HSS6.875X0.250','HSS174.6X6.4','HSS174.6X6.4'), 
+('HSS6.875X.188','HSS6.875X0.188','HSS174.6X4.8','HSS174.6X4.8'), 
+('HSS6.625X.500','HSS6.625X0.500','HSS168.3X12.7','HSS168.3X12.7'), 
+('HSS6.625X.432','HSS6.625X0.432','HSS168.3X11','HSS168.3X11'), 
+('HSS6.625X.375','HSS6.625X0.375','HSS168.3X9.5','HSS168.3X9.5'), 
+('HSS6.625X.312','HSS6.625X0.312','HSS168.3X7.9','HSS168.3X7.9'), 
+('HSS6.625X.280','HSS6.625X0.280','HSS168.3X7.1','HSS168.3X7.1'), 
+('HSS6.625X.250','HSS6.625X0.250','HSS168.3X6.4','HSS168.3X6.4'), 
+('HSS6.625X.188','HSS6.625X0.188','HSS168.3X4.8','HSS168.3X4.8'), 
+('HSS6.625X.125','HSS6.625X0.125','HSS168.3X3.2','HSS168.3X3.2'), 
+('HSS6X.500','HSS6.000X0.500','HSS152.4X12.7','HSS152.4X12.7'), 
+('HSS6X.375','HSS6.000X0.375','HSS152.4X9.5','HSS152.4X9.5'), 
+('HSS6X.312','HSS6.000X0.312','HSS152.4X7.9','HSS152.4X7.9'), 
+('HSS6X.280','HSS6.000X0.280','HSS152.4X7.1','HSS152.4X7.1'), 
+('HSS6X.250','HSS6.000X0.250','HSS152.4X6.4','HSS152.4X6.4'), 
+('HSS6X.188','HSS6.000X0.188','HSS152.4X4.8','HSS152.4X4.8'), 
+('HSS6X.125','HSS6.000X0.125','HSS152.4X3.2','HSS152.4X3.2'), 
+('HSS5.563X.500','HSS5.563X0.500','HSS141.3X12.7','HSS141.3X12.7'), 
+('HSS5.563X.375','HSS5.563X0.375','HSS141.3X9.5','HSS141.3X9.5'), 
+('HSS5.563X.258','HSS5.563X0.258','HSS141.3X6.6','HSS141.3X6.6'), 
+('HSS5.563X.188','HSS5.563X0.188','HSS141.3X4.8','HSS141.3X4.8'), 
+('HSS5.563X.134','HSS5.563X0.134','HSS141.3X3.4','HSS141.3X3.4'), 
+('HSS5.5X.500','HSS5.500X0.500','HSS139.7X12.7','HSS139.7X12.7'), 
+('HSS5.5X.375','HSS5.500X0.375','HSS139.7X9.5','HSS139.7X9.5'), 
+('HSS5.5X.258','HSS5.500X0.258','HSS139.7X6.6','HSS139.7X6.6'), 
+('HSS5X.500','HSS5.000X0.500','HSS127X12.7','HSS127X12.7'), 
+('HSS5X.375','HSS5.000X0.375','HSS127X9.5','HSS127X9.5'), 
+('HSS5X.312','HSS5.000X0.312','HSS127X7.9','HSS127X7.9'), 
+('HSS5X.258','HSS5.000X0.258','HSS127X6.6','HSS127X6.6'), 
+('HSS5X.250','HSS5.000X0.250','HSS127X6.4','HSS127X6.4'), 
+('HSS5X.188','HSS5.000X0.188','HSS127X4.8','HSS127X4.8'), 
+('HSS5X.125','HSS5.000X0.125','HSS127X3.2','HSS127X3.2'), 
+('HSS4.5X.375','HSS4.500X0.375','HSS114.3X9.5','HSS114.3X9.5'), 
+('HSS4.5X.337','HSS4.500X0.337','HSS114.3X8.6','HSS114.3X8.6'), 
+('HSS4.5X.237','HSS4.500X0.237','HSS114.3X6','HSS114.3X6'), 
+('HSS4.5X.188','HSS4.500X0.188','HSS114.3X4.8','HSS114.3X4.8'), 
+('HSS4.5X.125','HSS4.500X0.125','HSS114.3X3.2','HSS114.3X3.2'), 
+('HSS4X.313','HSS4.000X0.313','HSS101.6X8','HSS101.6X8'), 
+('HSS4X.250','HSS4.000X0.250','HSS101.6X6.4','HSS101.6X6.4'), 
+('HSS4X.237','HSS4.000X0.237','HSS101.6X6','HSS101.6X6'), 
+('HSS4X.226','HSS4.000X0.226','HSS101.6X5.7','HSS101.6X5.7'), 
+('HSS4X.220','HSS4.000X0.220','HSS101.6X5.6','HSS101.6X5.6'), 
+('HSS4X.188','HSS4.000X0.188','HSS101.6X4.8','HSS101.6X4.8'), 
+('HSS4X.125','HSS4.000X0.125','HSS101.6X3.2','HSS101.6X3.2'), 
+('HSS3.5X.313','HSS3.500X0.313','HSS88.9X8','HSS88.9X8'), 
+('HSS3.5X.300','HSS3.500X0.300','HSS88.9X7.6','HSS88.9X7.6'), 
+('HSS3.5X.250','HSS3.500X0.250','HSS88.9X6.4','HSS88.9X6.4'), 
+('HSS3.5X.216','HSS3.500X0.216','HSS88.9X5.5','HSS88.9X5.5'), 
+('HSS3.5X.203','HSS3.500X0.203','HSS88.9X5.2','HSS88.9X5.2'), 
+('HSS3.5X.188','HSS3.500X0.188','HSS88.9X4.8','HSS88.9X4.8'), 
+('HSS3.5X.125','HSS3.500X0.125','HSS88.9X3.2','HSS88.9X3.2'), 
+('HSS3X.250','HSS3.000X0.250','HSS76.2X6.4','HSS76.2X6.4'), 
+('HSS3X.216','HSS3.000X0.216','HSS76.2X5.5','HSS76.2X5.5'), 
+('HSS3X.203','HSS3.000X0.203','HSS76.2X5.2','HSS76.2X5.2'), 
+('HSS3X.188','HSS3.000X0.188','HSS76.2X4.8','HSS76.2X4.8'), 
+('HSS3X.152','HSS3.000X0.152','HSS76.2X3.9','HSS76.2X3.9'), 
+('HSS3X.134','HSS3.000X0.134','HSS76.2X3.4','HSS76.2X3.4'), 
+('HSS3X.125','HSS3.000X0.125','HSS76.2X3.2','HSS76.2X3.2'), 
+('HSS2.875X.250','HSS2.875X0.250','HSS73X6.4','HSS73X6.4'), 
+('HSS2.875X.203','HSS2.875X0.203','HSS73X5.2','HSS73X5.2'), 
+('HSS2.875X.188','HSS2.875X0.188','HSS73X4.8','HSS73X4.8'), 
+('HSS2.875X.125','HSS2.875X0.125','HSS73X3.2','HSS73X3.2'), 
+('HSS2.5X.250','HSS2.500X0.250','HSS63.5X6.4','HSS63.5X6.4'), 
+('HSS2.5X.188','HSS2.500X0.188','HSS63.5X4.8','HSS63.5X4.8'), 
+('HSS2.5X.125','HSS2.500X0.125','HSS63.5X3.2','HSS63.5X3.2'), 
+('HSS2.375X.250','HSS2.375X0.250','HSS60.3X6.4','HSS60.3X6.4'), 
+('HSS2.375X.218','HSS2.375X0.218','HSS60.3X5.5','HSS60.3X5.5'), 
+('HSS2.375X.188','HSS2.375X0.188','HSS60.3X4.8','HSS60.3X4.8'), 
+('HSS2.375X.154','HSS2.375X0.154','HSS60.3X3.9','HSS60.3X3.9'), 
+('HSS2.375X.125','HSS2.375X0.125','HSS60.3X3.2','HSS60.3X3.2'), 
+('HSS1.9X.188','HSS1.900X0.188','HSS48.3X4.8','HSS48.3X4.8'), 
+('HSS1.9X.145','HSS1.900X0.145','HSS48.3X3.7','HSS48.3X3.7'), 
+('HSS1.9X.120','HSS1.900X0.120','HSS48.3X3','HSS48.3X3'), 
+('HSS1.66X.140','HSS1.660X0.140','HSS42.2X3.6','HSS42.2X3.6');
\ No newline at end of file
@@ -1,7 +1,10 @@
-use shapes::aisc_shapes::{Angle, ShapeBuilder, ShapeRepository};
-use sqlx::Row;
+use crate::repositories::sql_error::{TryGetColumn, database_error};
+use crate::repositories::sql_query::select_matching;
+use shapes::aisc_shapes::{
+    Angle, Designation, RepositoryError, ShapeBuilder, ShapeFamily, ShapeQuery, ShapeRepository,
+};
+use sqlx::Postgres;
 use sqlx::postgres::{PgPool, PgRow};
-use std::error::Error;
 use std::sync::Arc;
 
 /// Repository that manages data access for all angle shapes
@@ -18,7 +21,7 @@ impl AngleRepository {
 }
 
 impl ShapeRepository<Angle> for AngleRepository {
-    async fn all(&self) -> Result<Vec<Angle>, Box<dyn Error>> {
+    async fn all(&self) -> Result<Vec<Angle>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -70,17 +73,13 @@ impl ShapeRepository<Angle> for AngleRepository {
     FROM angles;",
         )
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
-        let results = rows
-            .into_iter()
-            .map(|r| angle_from_row(r))
-            .collect::<Vec<_>>();
+        let results = rows.into_iter().map(angle_from_row).collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -91,7 +90,7 @@ impl ShapeRepository<Angle> for AngleRepository {
     async fn shape_with_edi_std_nomenclature(
         &self,
         edi_std_nomenclature: String,
-    ) -> Result<Angle, Box<dyn Error>> {
+    ) -> Result<Angle, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -141,12 +140,21 @@ impl ShapeRepository<Angle> for AngleRepository {
     pa_2,
     pb
     FROM angles 
-	WHERE edi_std_nomenclature = $1
+	WHERE edi_std_nomenclature IN (
+		$1,
+		$2,
+		(SELECT edi_std_nomenclature FROM metric_designations WHERE metric_edi_std_nomenclature = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(edi_std_nomenclature)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&edi_std_nomenclature)
+        .bind(Designation::normalize_edi_std_nomenclature(&edi_std_nomenclature))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: edi_std_nomenclature,
+        })?;
 
         angle_from_row(row)
     }
@@ -154,7 +162,7 @@ impl ShapeRepository<Angle> for AngleRepository {
     async fn shape_with_aisc_manual_label(
         &self,
         aisc_manual_label: String,
-    ) -> Result<Angle, Box<dyn Error>> {
+    ) -> Result<Angle, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -204,17 +212,26 @@ impl ShapeRepository<Angle> for AngleRepository {
     pa_2,
     pb
     FROM angles 
-	WHERE aisc_manual_label = $1
+	WHERE aisc_manual_label IN (
+		$1,
+		$2,
+		(SELECT aisc_manual_label FROM metric_designations WHERE metric_aisc_manual_label = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(aisc_manual_label)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&aisc_manual_label)
+        .bind(Designation::normalize_aisc_manual_label(&aisc_manual_label))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: aisc_manual_label,
+        })?;
 
         angle_from_row(row)
     }
 
-    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<Angle>, Box<dyn Error>> {
+    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<Angle>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -268,17 +285,13 @@ impl ShapeRepository<Angle> for AngleRepository {
         )
         .bind(depth)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
-        let results = rows
-            .into_iter()
-            .map(|r| angle_from_row(r))
-            .collect::<Vec<_>>();
+        let results = rows.into_iter().map(angle_from_row).collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -286,7 +299,7 @@ impl ShapeRepository<Angle> for AngleRepository {
         }
     }
 
-    async fn shapes_with_width(&self, width: f64) -> Result<Vec<Angle>, Box<dyn Error>> {
+    async fn shapes_with_width(&self, width: f64) -> Result<Vec<Angle>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -340,75 +353,84 @@ impl ShapeRepository<Angle> for AngleRepository {
         )
         .bind(width)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
-        let results = rows
-            .into_iter()
-            .map(|r| angle_from_row(r))
-            .collect::<Vec<_>>();
+        let results = rows.into_iter().map(angle_from_row).collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
             Ok(results.into_iter().map(|r| r.unwrap()).collect::<Vec<_>>())
         }
     }
+
+    async fn shapes_matching(&self, query: ShapeQuery) -> Result<Vec<Angle>, RepositoryError> {
+        let Some(mut builder) = select_matching::<Postgres>(ShapeFamily::Angle, &query) else {
+            return Ok(Vec::new());
+        };
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.into_iter().map(angle_from_row).collect()
+    }
 }
 
 // Helper Functions
-fn angle_from_row(row: PgRow) -> Result<Angle, Box<dyn Error>> {
-    let maybe_h_upper: Option<f64> = row.try_get("h_upper")?;
-    let maybe_swb: Option<f64> = row.try_get("swb")?;
+fn angle_from_row(row: PgRow) -> Result<Angle, RepositoryError> {
+    let maybe_h_upper: Option<f64> = row.try_get_column("h_upper")?;
+    let maybe_swb: Option<f64> = row.try_get_column("swb")?;
 
     let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(row.try_get("edi_std_nomenclature")?)
-        .with_aisc_manual_label(row.try_get("aisc_manual_label")?)
-        .with_w_upper(row.try_get("w_upper")?)
-        .with_a_upper(row.try_get("a_upper")?)
-        .with_d_lower(row.try_get("d_lower")?)
-        .with_b_lower(row.try_get("b_lower")?)
-        .with_t_lower(row.try_get("t_lower")?)
-        .with_kdes(row.try_get("kdes")?)
-        .with_kdet(row.try_get("kdet")?)
-        .with_x_lower(row.try_get("x_lower")?)
-        .with_y_lower(row.try_get("y_lower")?)
-        .with_xp(row.try_get("xp")?)
-        .with_yp(row.try_get("yp")?)
-        .with_b_t(row.try_get("b_t")?)
-        .with_ix(row.try_get("ix")?)
-        .with_zx(row.try_get("zx")?)
-        .with_sx(row.try_get("sx")?)
-        .with_rx(row.try_get("rx")?)
-        .with_iy(row.try_get("iy")?)
-        .with_zy(row.try_get("zy")?)
-        .with_sy(row.try_get("sy")?)
-        .with_ry(row.try_get("ry")?)
-        .with_iz(row.try_get("iz")?)
-        .with_rz(row.try_get("rz")?)
-        .with_sz(row.try_get("sz")?)
-        .with_j_upper(row.try_get("j_upper")?)
-        .with_cw(row.try_get("cw")?)
-        .with_ro(row.try_get("ro")?)
-        .with_tan_a(row.try_get("tan_a")?)
-        .with_iw(row.try_get("iw")?)
-        .with_za(row.try_get("za")?)
-        .with_zb(row.try_get("zb")?)
-        .with_zc(row.try_get("zc")?)
-        .with_wa(row.try_get("wa")?)
-        .with_wb(row.try_get("wb")?)
-        .with_wc(row.try_get("wc")?)
-        .with_swa(row.try_get("swa")?)
-        .with_swc(row.try_get("swc")?)
-        .with_sza(row.try_get("sza")?)
-        .with_szb(row.try_get("szb")?)
-        .with_szc(row.try_get("szc")?)
-        .with_pa(row.try_get("pa")?)
-        .with_pa_2(row.try_get("pa_2")?)
-        .with_pb(row.try_get("pb")?);
+        .with_edi_std_nomenclature(row.try_get_column("edi_std_nomenclature")?)
+        .with_aisc_manual_label(row.try_get_column("aisc_manual_label")?)
+        .with_w_upper(row.try_get_column("w_upper")?)
+        .with_a_upper(row.try_get_column("a_upper")?)
+        .with_d_lower(row.try_get_column("d_lower")?)
+        .with_b_lower(row.try_get_column("b_lower")?)
+        .with_t_lower(row.try_get_column("t_lower")?)
+        .with_kdes(row.try_get_column("kdes")?)
+        .with_kdet(row.try_get_column("kdet")?)
+        .with_x_lower(row.try_get_column("x_lower")?)
+        .with_y_lower(row.try_get_column("y_lower")?)
+        .with_xp(row.try_get_column("xp")?)
+        .with_yp(row.try_get_column("yp")?)
+        .with_b_t(row.try_get_column("b_t")?)
+        .with_ix(row.try_get_column("ix")?)
+        .with_zx(row.try_get_column("zx")?)
+        .with_sx(row.try_get_column("sx")?)
+        .with_rx(row.try_get_column("rx")?)
+        .with_iy(row.try_get_column("iy")?)
+        .with_zy(row.try_get_column("zy")?)
+        .with_sy(row.try_get_column("sy")?)
+        .with_ry(row.try_get_column("ry")?)
+        .with_iz(row.try_get_column("iz")?)
+        .with_rz(row.try_get_column("rz")?)
+        .with_sz(row.try_get_column("sz")?)
+        .with_j_upper(row.try_get_column("j_upper")?)
+        .with_cw(row.try_get_column("cw")?)
+        .with_ro(row.try_get_column("ro")?)
+        .with_tan_a(row.try_get_column("tan_a")?)
+        .with_iw(row.try_get_column("iw")?)
+        .with_za(row.try_get_column("za")?)
+        .with_zb(row.try_get_column("zb")?)
+        .with_zc(row.try_get_column("zc")?)
+        .with_wa(row.try_get_column("wa")?)
+        .with_wb(row.try_get_column("wb")?)
+        .with_wc(row.try_get_column("wc")?)
+        .with_swa(row.try_get_column("swa")?)
+        .with_swc(row.try_get_column("swc")?)
+        .with_sza(row.try_get_column("sza")?)
+        .with_szb(row.try_get_column("szb")?)
+        .with_szc(row.try_get_column("szc")?)
+        .with_pa(row.try_get_column("pa")?)
+        .with_pa_2(row.try_get_column("pa_2")?)
+        .with_pb(row.try_get_column("pb")?);
 
     let builder = optional_h_upper(builder, maybe_h_upper);
     let buider = optional_swb(builder, maybe_swb);
@@ -1,7 +1,11 @@
-use shapes::aisc_shapes::{CeeChannel, ShapeBuilder, ShapeRepository};
-use sqlx::Row;
+use crate::repositories::sql_error::{TryGetColumn, database_error};
+use crate::repositories::sql_query::select_matching;
+use shapes::aisc_shapes::{
+    CeeChannel, Designation, RepositoryError, ShapeBuilder, ShapeFamily, ShapeQuery,
+    ShapeRepository,
+};
+use sqlx::Postgres;
 use sqlx::postgres::{PgPool, PgRow};
-use std::error::Error;
 use std::sync::Arc;
 
 /// Repository that manages data access for all cee channel shapes
@@ -18,7 +22,7 @@ impl CeeChannelRepository {
 }
 
 impl ShapeRepository<CeeChannel> for CeeChannelRepository {
-    async fn all(&self) -> Result<Vec<CeeChannel>, Box<dyn Error>> {
+    async fn all(&self) -> Result<Vec<CeeChannel>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -70,17 +74,16 @@ impl ShapeRepository<CeeChannel> for CeeChannelRepository {
     FROM cee_channels;",
         )
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let results = rows
             .into_iter()
-            .map(|r| cee_channel_from_row(r))
+            .map(cee_channel_from_row)
             .collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -91,7 +94,7 @@ impl ShapeRepository<CeeChannel> for CeeChannelRepository {
     async fn shape_with_edi_std_nomenclature(
         &self,
         edi_std_nomenclature: String,
-    ) -> Result<CeeChannel, Box<dyn Error>> {
+    ) -> Result<CeeChannel, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -141,12 +144,21 @@ impl ShapeRepository<CeeChannel> for CeeChannelRepository {
     t,
     wgi
     FROM cee_channels 
-	WHERE edi_std_nomenclature = $1
+	WHERE edi_std_nomenclature IN (
+		$1,
+		$2,
+		(SELECT edi_std_nomenclature FROM metric_designations WHERE metric_edi_std_nomenclature = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(edi_std_nomenclature)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&edi_std_nomenclature)
+        .bind(Designation::normalize_edi_std_nomenclature(&edi_std_nomenclature))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: edi_std_nomenclature,
+        })?;
 
         cee_channel_from_row(row)
     }
@@ -154,7 +166,7 @@ impl ShapeRepository<CeeChannel> for CeeChannelRepository {
     async fn shape_with_aisc_manual_label(
         &self,
         aisc_manual_label: String,
-    ) -> Result<CeeChannel, Box<dyn Error>> {
+    ) -> Result<CeeChannel, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -204,17 +216,26 @@ impl ShapeRepository<CeeChannel> for CeeChannelRepository {
     t,
     wgi
     FROM cee_channels 
-	WHERE aisc_manual_label = $1
+	WHERE aisc_manual_label IN (
+		$1,
+		$2,
+		(SELECT aisc_manual_label FROM metric_designations WHERE metric_aisc_manual_label = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(aisc_manual_label)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&aisc_manual_label)
+        .bind(Designation::normalize_aisc_manual_label(&aisc_manual_label))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: aisc_manual_label,
+        })?;
 
         cee_channel_from_row(row)
     }
 
-    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<CeeChannel>, Box<dyn Error>> {
+    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<CeeChannel>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -268,17 +289,16 @@ impl ShapeRepository<CeeChannel> for CeeChannelRepository {
         )
         .bind(depth)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let results = rows
             .into_iter()
-            .map(|r| cee_channel_from_row(r))
+            .map(cee_channel_from_row)
             .collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -286,7 +306,7 @@ impl ShapeRepository<CeeChannel> for CeeChannelRepository {
         }
     }
 
-    async fn shapes_with_width(&self, width: f64) -> Result<Vec<CeeChannel>, Box<dyn Error>> {
+    async fn shapes_with_width(&self, width: f64) -> Result<Vec<CeeChannel>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -340,74 +360,86 @@ impl ShapeRepository<CeeChannel> for CeeChannelRepository {
         )
         .bind(width)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let results = rows
             .into_iter()
-            .map(|r| cee_channel_from_row(r))
+            .map(cee_channel_from_row)
             .collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
             Ok(results.into_iter().map(|r| r.unwrap()).collect::<Vec<_>>())
         }
     }
+
+    async fn shapes_matching(&self, query: ShapeQuery) -> Result<Vec<CeeChannel>, RepositoryError> {
+        let Some(mut builder) = select_matching::<Postgres>(ShapeFamily::CeeChannel, &query) else {
+            return Ok(Vec::new());
+        };
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.into_iter().map(cee_channel_from_row).collect()
+    }
 }
 
 // Helper Functions
-fn cee_channel_from_row(row: PgRow) -> Result<CeeChannel, Box<dyn Error>> {
-    let maybe_wgi: Option<f64> = row.try_get("wgi")?;
+fn cee_channel_from_row(row: PgRow) -> Result<CeeChannel, RepositoryError> {
+    let maybe_wgi: Option<f64> = row.try_get_column("wgi")?;
     let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(row.try_get("edi_std_nomenclature")?)
-        .with_aisc_manual_label(row.try_get("aisc_manual_label")?)
-        .with_w_upper(row.try_get("w_upper")?)
-        .with_a_upper(row.try_get("a_upper")?)
-        .with_d_lower(row.try_get("d_lower")?)
-        .with_ddet(row.try_get("ddet")?)
-        .with_bf(row.try_get("bf")?)
-        .with_bfdet(row.try_get("bfdet")?)
-        .with_tw(row.try_get("tw")?)
-        .with_twdet(row.try_get("twdet")?)
-        .with_twdet_2(row.try_get("twdet_2")?)
-        .with_tf(row.try_get("tf")?)
-        .with_tfdet(row.try_get("tfdet")?)
-        .with_kdes(row.try_get("kdes")?)
-        .with_kdet(row.try_get("kdet")?)
-        .with_x_lower(row.try_get("x_lower")?)
-        .with_eo(row.try_get("eo")?)
-        .with_xp(row.try_get("xp")?)
-        .with_b_t(row.try_get("b_t")?)
-        .with_h_tw(row.try_get("h_tw")?)
-        .with_ix(row.try_get("ix")?)
-        .with_zx(row.try_get("zx")?)
-        .with_sx(row.try_get("sx")?)
-        .with_rx(row.try_get("rx")?)
-        .with_iy(row.try_get("iy")?)
-        .with_zy(row.try_get("zy")?)
-        .with_sy(row.try_get("sy")?)
-        .with_ry(row.try_get("ry")?)
-        .with_j_upper(row.try_get("j_upper")?)
-        .with_cw(row.try_get("cw")?)
-        .with_wno(row.try_get("wno")?)
-        .with_sw1(row.try_get("sw1")?)
-        .with_sw2(row.try_get("sw2")?)
-        .with_sw3(row.try_get("sw3")?)
-        .with_qf(row.try_get("qf")?)
-        .with_qw(row.try_get("qw")?)
-        .with_ro(row.try_get("ro")?)
-        .with_h_upper(row.try_get("h_upper")?)
-        .with_rts(row.try_get("rts")?)
-        .with_ho(row.try_get("ho")?)
-        .with_pa(row.try_get("pa")?)
-        .with_pb(row.try_get("pb")?)
-        .with_pc(row.try_get("pc")?)
-        .with_pd(row.try_get("pd")?)
-        .with_t(row.try_get("t")?);
+        .with_edi_std_nomenclature(row.try_get_column("edi_std_nomenclature")?)
+        .with_aisc_manual_label(row.try_get_column("aisc_manual_label")?)
+        .with_w_upper(row.try_get_column("w_upper")?)
+        .with_a_upper(row.try_get_column("a_upper")?)
+        .with_d_lower(row.try_get_column("d_lower")?)
+        .with_ddet(row.try_get_column("ddet")?)
+        .with_bf(row.try_get_column("bf")?)
+        .with_bfdet(row.try_get_column("bfdet")?)
+        .with_tw(row.try_get_column("tw")?)
+        .with_twdet(row.try_get_column("twdet")?)
+        .with_twdet_2(row.try_get_column("twdet_2")?)
+        .with_tf(row.try_get_column("tf")?)
+        .with_tfdet(row.try_get_column("tfdet")?)
+        .with_kdes(row.try_get_column("kdes")?)
+        .with_kdet(row.try_get_column("kdet")?)
+        .with_x_lower(row.try_get_column("x_lower")?)
+        .with_eo(row.try_get_column("eo")?)
+        .with_xp(row.try_get_column("xp")?)
+        .with_b_t(row.try_get_column("b_t")?)
+        .with_h_tw(row.try_get_column("h_tw")?)
+        .with_ix(row.try_get_column("ix")?)
+        .with_zx(row.try_get_column("zx")?)
+        .with_sx(row.try_get_column("sx")?)
+        .with_rx(row.try_get_column("rx")?)
+        .with_iy(row.try_get_column("iy")?)
+        .with_zy(row.try_get_column("zy")?)
+        .with_sy(row.try_get_column("sy")?)
+        .with_ry(row.try_get_column("ry")?)
+        .with_j_upper(row.try_get_column("j_upper")?)
+        .with_cw(row.try_get_column("cw")?)
+        .with_wno(row.try_get_column("wno")?)
+        .with_sw1(row.try_get_column("sw1")?)
+        .with_sw2(row.try_get_column("sw2")?)
+        .with_sw3(row.try_get_column("sw3")?)
+        .with_qf(row.try_get_column("qf")?)
+        .with_qw(row.try_get_column("qw")?)
+        .with_ro(row.try_get_column("ro")?)
+        .with_h_upper(row.try_get_column("h_upper")?)
+        .with_rts(row.try_get_column("rts")?)
+        .with_ho(row.try_get_column("ho")?)
+        .with_pa(row.try_get_column("pa")?)
+        .with_pb(row.try_get_column("pb")?)
+        .with_pc(row.try_get_column("pc")?)
+        .with_pd(row.try_get_column("pd")?)
+        .with_t(row.try_get_column("t")?);
 
     match maybe_wgi {
         Some(wgi) => Ok(builder.with_wgi(wgi).try_build::<CeeChannel>()?),
@@ -1,7 +1,11 @@
-use shapes::aisc_shapes::{DoubleAngle, ShapeBuilder, ShapeRepository};
-use sqlx::Row;
+use crate::repositories::sql_error::{TryGetColumn, database_error};
+use crate::repositories::sql_query::select_matching;
+use shapes::aisc_shapes::{
+    Designation, DoubleAngle, RepositoryError, ShapeBuilder, ShapeFamily, ShapeQuery,
+    ShapeRepository,
+};
+use sqlx::Postgres;
 use sqlx::postgres::{PgPool, PgRow};
-use std::error::Error;
 use std::sync::Arc;
 
 /// Repository that manages data access for all double angle shapes
@@ -18,7 +22,7 @@ impl DoubleAngleRepository {
 }
 
 impl ShapeRepository<DoubleAngle> for DoubleAngleRepository {
-    async fn all(&self) -> Result<Vec<DoubleAngle>, Box<dyn Error>> {
+    async fn all(&self) -> Result<Vec<DoubleAngle>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -44,17 +48,16 @@ impl ShapeRepository<DoubleAngle> for DoubleAngleRepository {
     FROM double_angles;",
         )
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let results = rows
             .into_iter()
-            .map(|r| double_angle_from_row(r))
+            .map(double_angle_from_row)
             .collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -65,7 +68,7 @@ impl ShapeRepository<DoubleAngle> for DoubleAngleRepository {
     async fn shape_with_edi_std_nomenclature(
         &self,
         edi_std_nomenclature: String,
-    ) -> Result<DoubleAngle, Box<dyn Error>> {
+    ) -> Result<DoubleAngle, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -89,12 +92,21 @@ impl ShapeRepository<DoubleAngle> for DoubleAngleRepository {
     ro,
     h_upper
     FROM double_angles 
-	WHERE edi_std_nomenclature = $1
+	WHERE edi_std_nomenclature IN (
+		$1,
+		$2,
+		(SELECT edi_std_nomenclature FROM metric_designations WHERE metric_edi_std_nomenclature = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(edi_std_nomenclature)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&edi_std_nomenclature)
+        .bind(Designation::normalize_edi_std_nomenclature(&edi_std_nomenclature))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: edi_std_nomenclature,
+        })?;
 
         double_angle_from_row(row)
     }
@@ -102,7 +114,7 @@ impl ShapeRepository<DoubleAngle> for DoubleAngleRepository {
     async fn shape_with_aisc_manual_label(
         &self,
         aisc_manual_label: String,
-    ) -> Result<DoubleAngle, Box<dyn Error>> {
+    ) -> Result<DoubleAngle, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -126,17 +138,26 @@ impl ShapeRepository<DoubleAngle> for DoubleAngleRepository {
     ro,
     h_upper
     FROM double_angles 
-	WHERE aisc_manual_label = $1
+	WHERE aisc_manual_label IN (
+		$1,
+		$2,
+		(SELECT aisc_manual_label FROM metric_designations WHERE metric_aisc_manual_label = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(aisc_manual_label)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&aisc_manual_label)
+        .bind(Designation::normalize_aisc_manual_label(&aisc_manual_label))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: aisc_manual_label,
+        })?;
 
         double_angle_from_row(row)
     }
 
-    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<DoubleAngle>, Box<dyn Error>> {
+    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<DoubleAngle>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -164,17 +185,16 @@ impl ShapeRepository<DoubleAngle> for DoubleAngleRepository {
         )
         .bind(depth)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let results = rows
             .into_iter()
-            .map(|r| double_angle_from_row(r))
+            .map(double_angle_from_row)
             .collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -182,7 +202,7 @@ impl ShapeRepository<DoubleAngle> for DoubleAngleRepository {
         }
     }
 
-    async fn shapes_with_width(&self, width: f64) -> Result<Vec<DoubleAngle>, Box<dyn Error>> {
+    async fn shapes_with_width(&self, width: f64) -> Result<Vec<DoubleAngle>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -210,47 +230,63 @@ impl ShapeRepository<DoubleAngle> for DoubleAngleRepository {
         )
         .bind(width)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let results = rows
             .into_iter()
-            .map(|r| double_angle_from_row(r))
+            .map(double_angle_from_row)
             .collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
             Ok(results.into_iter().map(|r| r.unwrap()).collect::<Vec<_>>())
         }
     }
+
+    async fn shapes_matching(
+        &self,
+        query: ShapeQuery,
+    ) -> Result<Vec<DoubleAngle>, RepositoryError> {
+        let Some(mut builder) = select_matching::<Postgres>(ShapeFamily::DoubleAngle, &query)
+        else {
+            return Ok(Vec::new());
+        };
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.into_iter().map(double_angle_from_row).collect()
+    }
 }
 
 // Helper Functions
-fn double_angle_from_row(row: PgRow) -> Result<DoubleAngle, Box<dyn Error>> {
+fn double_angle_from_row(row: PgRow) -> Result<DoubleAngle, RepositoryError> {
     Ok(ShapeBuilder::new()
-        .with_edi_std_nomenclature(row.try_get("edi_std_nomenclature")?)
-        .with_aisc_manual_label(row.try_get("aisc_manual_label")?)
-        .with_w_upper(row.try_get("w_upper")?)
-        .with_a_upper(row.try_get("a_upper")?)
-        .with_d_lower(row.try_get("d_lower")?)
-        .with_b_lower(row.try_get("b_lower")?)
-        .with_t_lower(row.try_get("t_lower")?)
-        .with_y_lower(row.try_get("y_lower")?)
-        .with_yp(row.try_get("yp")?)
-        .with_b_t(row.try_get("b_t")?)
-        .with_ix(row.try_get("ix")?)
-        .with_zx(row.try_get("zx")?)
-        .with_sx(row.try_get("sx")?)
-        .with_rx(row.try_get("rx")?)
-        .with_iy(row.try_get("iy")?)
-        .with_zy(row.try_get("zy")?)
-        .with_sy(row.try_get("sy")?)
-        .with_ry(row.try_get("ry")?)
-        .with_ro(row.try_get("ro")?)
-        .with_h_upper(row.try_get("h_upper")?)
+        .with_edi_std_nomenclature(row.try_get_column("edi_std_nomenclature")?)
+        .with_aisc_manual_label(row.try_get_column("aisc_manual_label")?)
+        .with_w_upper(row.try_get_column("w_upper")?)
+        .with_a_upper(row.try_get_column("a_upper")?)
+        .with_d_lower(row.try_get_column("d_lower")?)
+        .with_b_lower(row.try_get_column("b_lower")?)
+        .with_t_lower(row.try_get_column("t_lower")?)
+        .with_y_lower(row.try_get_column("y_lower")?)
+        .with_yp(row.try_get_column("yp")?)
+        .with_b_t(row.try_get_column("b_t")?)
+        .with_ix(row.try_get_column("ix")?)
+        .with_zx(row.try_get_column("zx")?)
+        .with_sx(row.try_get_column("sx")?)
+        .with_rx(row.try_get_column("rx")?)
+        .with_iy(row.try_get_column("iy")?)
+        .with_zy(row.try_get_column("zy")?)
+        .with_sy(row.try_get_column("sy")?)
+        .with_ry(row.try_get_column("ry")?)
+        .with_ro(row.try_get_column("ro")?)
+        .with_h_upper(row.try_get_column("h_upper")?)
         .try_build::<DoubleAngle>()?)
 }
@@ -1,7 +1,10 @@
-use shapes::aisc_shapes::{HPile, ShapeBuilder, ShapeRepository};
-use sqlx::Row;
+use crate::repositories::sql_error::{TryGetColumn, database_error};
+use crate::repositories::sql_query::select_matching;
+use shapes::aisc_shapes::{
+    Designation, HPile, RepositoryError, ShapeBuilder, ShapeFamily, ShapeQuery, ShapeRepository,
+};
+use sqlx::Postgres;
 use sqlx::postgres::{PgPool, PgRow};
-use std::error::Error;
 use std::sync::Arc;
 
 /// Repository that manages data access for all h pile shapes
@@ -18,7 +21,7 @@ impl HPileRepository {
 }
 
 impl ShapeRepository<HPile> for HPileRepository {
-    async fn all(&self) -> Result<Vec<HPile>, Box<dyn Error>> {
+    async fn all(&self) -> Result<Vec<HPile>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -64,17 +67,13 @@ impl ShapeRepository<HPile> for HPileRepository {
     FROM h_piles;",
         )
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
-        let results = rows
-            .into_iter()
-            .map(|r| h_pile_from_row(r))
-            .collect::<Vec<_>>();
+        let results = rows.into_iter().map(h_pile_from_row).collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -85,7 +84,7 @@ impl ShapeRepository<HPile> for HPileRepository {
     async fn shape_with_edi_std_nomenclature(
         &self,
         edi_std_nomenclature: String,
-    ) -> Result<HPile, Box<dyn Error>> {
+    ) -> Result<HPile, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -129,12 +128,21 @@ impl ShapeRepository<HPile> for HPileRepository {
     t,
     wgi
     FROM h_piles
-	WHERE edi_std_nomenclature = $1
+	WHERE edi_std_nomenclature IN (
+		$1,
+		$2,
+		(SELECT edi_std_nomenclature FROM metric_designations WHERE metric_edi_std_nomenclature = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(edi_std_nomenclature)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&edi_std_nomenclature)
+        .bind(Designation::normalize_edi_std_nomenclature(&edi_std_nomenclature))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: edi_std_nomenclature,
+        })?;
 
         h_pile_from_row(row)
     }
@@ -142,7 +150,7 @@ impl ShapeRepository<HPile> for HPileRepository {
     async fn shape_with_aisc_manual_label(
         &self,
         aisc_manual_label: String,
-    ) -> Result<HPile, Box<dyn Error>> {
+    ) -> Result<HPile, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -186,17 +194,26 @@ impl ShapeRepository<HPile> for HPileRepository {
     t,
     wgi
     FROM h_piles
-	WHERE aisc_manual_label = $1
+	WHERE aisc_manual_label IN (
+		$1,
+		$2,
+		(SELECT aisc_manual_label FROM metric_designations WHERE metric_aisc_manual_label = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(aisc_manual_label)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&aisc_manual_label)
+        .bind(Designation::normalize_aisc_manual_label(&aisc_manual_label))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: aisc_manual_label,
+        })?;
 
         h_pile_from_row(row)
     }
 
-    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<HPile>, Box<dyn Error>> {
+    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<HPile>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -244,17 +261,13 @@ impl ShapeRepository<HPile> for HPileRepository {
         )
         .bind(depth)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
-        let results = rows
-            .into_iter()
-            .map(|r| h_pile_from_row(r))
-            .collect::<Vec<_>>();
+        let results = rows.into_iter().map(h_pile_from_row).collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -262,7 +275,7 @@ impl ShapeRepository<HPile> for HPileRepository {
         }
     }
 
-    async fn shapes_with_width(&self, width: f64) -> Result<Vec<HPile>, Box<dyn Error>> {
+    async fn shapes_with_width(&self, width: f64) -> Result<Vec<HPile>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -310,72 +323,81 @@ impl ShapeRepository<HPile> for HPileRepository {
         )
         .bind(width)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
-        let results = rows
-            .into_iter()
-            .map(|r| h_pile_from_row(r))
-            .collect::<Vec<_>>();
+        let results = rows.into_iter().map(h_pile_from_row).collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
             Ok(results.into_iter().map(|r| r.unwrap()).collect::<Vec<_>>())
         }
     }
+
+    async fn shapes_matching(&self, query: ShapeQuery) -> Result<Vec<HPile>, RepositoryError> {
+        let Some(mut builder) = select_matching::<Postgres>(ShapeFamily::HPile, &query) else {
+            return Ok(Vec::new());
+        };
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.into_iter().map(h_pile_from_row).collect()
+    }
 }
 
 // Helper Functions
-fn h_pile_from_row(row: PgRow) -> Result<HPile, Box<dyn Error>> {
+fn h_pile_from_row(row: PgRow) -> Result<HPile, RepositoryError> {
     let maybe_hp = ShapeBuilder::new()
-        .with_edi_std_nomenclature(row.try_get("edi_std_nomenclature")?)
-        .with_aisc_manual_label(row.try_get("aisc_manual_label")?)
-        .with_w_upper(row.try_get("w_upper")?)
-        .with_a_upper(row.try_get("a_upper")?)
-        .with_d_lower(row.try_get("d_lower")?)
-        .with_ddet(row.try_get("ddet")?)
-        .with_bf(row.try_get("bf")?)
-        .with_bfdet(row.try_get("bfdet")?)
-        .with_tw(row.try_get("tw")?)
-        .with_twdet(row.try_get("twdet")?)
-        .with_twdet_2(row.try_get("twdet_2")?)
-        .with_tf(row.try_get("tf")?)
-        .with_tfdet(row.try_get("tfdet")?)
-        .with_kdes(row.try_get("kdes")?)
-        .with_kdet(row.try_get("kdet")?)
-        .with_k1(row.try_get("k1")?)
-        .with_bf_2tf(row.try_get("bf_2tf")?)
-        .with_h_tw(row.try_get("h_tw")?)
-        .with_ix(row.try_get("ix")?)
-        .with_zx(row.try_get("zx")?)
-        .with_sx(row.try_get("sx")?)
-        .with_rx(row.try_get("rx")?)
-        .with_iy(row.try_get("iy")?)
-        .with_zy(row.try_get("zy")?)
-        .with_sy(row.try_get("sy")?)
-        .with_ry(row.try_get("ry")?)
-        .with_j_upper(row.try_get("j_upper")?)
-        .with_cw(row.try_get("cw")?)
-        .with_wno(row.try_get("wno")?)
-        .with_sw1(row.try_get("sw1")?)
-        .with_qf(row.try_get("qf")?)
-        .with_qw(row.try_get("qw")?)
-        .with_rts(row.try_get("rts")?)
-        .with_ho(row.try_get("ho")?)
-        .with_pa(row.try_get("pa")?)
-        .with_pb(row.try_get("pb")?)
-        .with_pc(row.try_get("pc")?)
-        .with_pd(row.try_get("pd")?)
-        .with_t(row.try_get("t")?)
-        .with_wgi(row.try_get("wgi")?)
+        .with_edi_std_nomenclature(row.try_get_column("edi_std_nomenclature")?)
+        .with_aisc_manual_label(row.try_get_column("aisc_manual_label")?)
+        .with_w_upper(row.try_get_column("w_upper")?)
+        .with_a_upper(row.try_get_column("a_upper")?)
+        .with_d_lower(row.try_get_column("d_lower")?)
+        .with_ddet(row.try_get_column("ddet")?)
+        .with_bf(row.try_get_column("bf")?)
+        .with_bfdet(row.try_get_column("bfdet")?)
+        .with_tw(row.try_get_column("tw")?)
+        .with_twdet(row.try_get_column("twdet")?)
+        .with_twdet_2(row.try_get_column("twdet_2")?)
+        .with_tf(row.try_get_column("tf")?)
+        .with_tfdet(row.try_get_column("tfdet")?)
+        .with_kdes(row.try_get_column("kdes")?)
+        .with_kdet(row.try_get_column("kdet")?)
+        .with_k1(row.try_get_column("k1")?)
+        .with_bf_2tf(row.try_get_column("bf_2tf")?)
+        .with_h_tw(row.try_get_column("h_tw")?)
+        .with_ix(row.try_get_column("ix")?)
+        .with_zx(row.try_get_column("zx")?)
+        .with_sx(row.try_get_column("sx")?)
+        .with_rx(row.try_get_column("rx")?)
+        .with_iy(row.try_get_column("iy")?)
+        .with_zy(row.try_get_column("zy")?)
+        .with_sy(row.try_get_column("sy")?)
+        .with_ry(row.try_get_column("ry")?)
+        .with_j_upper(row.try_get_column("j_upper")?)
+        .with_cw(row.try_get_column("cw")?)
+        .with_wno(row.try_get_column("wno")?)
+        .with_sw1(row.try_get_column("sw1")?)
+        .with_qf(row.try_get_column("qf")?)
+        .with_qw(row.try_get_column("qw")?)
+        .with_rts(row.try_get_column("rts")?)
+        .with_ho(row.try_get_column("ho")?)
+        .with_pa(row.try_get_column("pa")?)
+        .with_pb(row.try_get_column("pb")?)
+        .with_pc(row.try_get_column("pc")?)
+        .with_pd(row.try_get_column("pd")?)
+        .with_t(row.try_get_column("t")?)
+        .with_wgi(row.try_get_column("wgi")?)
         .try_build::<HPile>();
 
     match maybe_hp {
         Ok(hp) => Ok(hp),
-        Err(err) => Err(Box::new(err)),
+        Err(err) => Err(err.into()),
     }
 }
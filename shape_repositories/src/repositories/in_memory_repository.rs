@@ -0,0 +1,381 @@
+use shapes::aisc_shapes::aisc_database::{read_shapes, read_shapes_from_path, shapes_of};
+use shapes::aisc_shapes::*;
+use std::error::Error;
+use std::io::Read;
+use std::path::Path;
+
+/// Repository that manages data access for shapes held in memory,
+/// implements [ShapeRepository] or [RoundShapeRepository] for every shape type
+/// with the same query semantics as the Postgres repositories
+///
+/// ```
+/// use shape_repositories::repositories::InMemoryShapeRepository;
+/// use shapes::aisc_shapes::WideFlange;
+///
+/// let repository = InMemoryShapeRepository::<WideFlange>::from_snapshot();
+/// ```
+#[derive(Debug, Clone)]
+pub struct InMemoryShapeRepository<T> {
+    shapes: Vec<T>,
+    metric_designations: Vec<MetricDesignation>,
+}
+
+impl<T> InMemoryShapeRepository<T> {
+    /// Creates a new instance of InMemoryShapeRepository type
+    /// Takes the shapes served by the repository
+    pub fn new(shapes: Vec<T>) -> Self {
+        InMemoryShapeRepository {
+            shapes,
+            metric_designations: Vec::new(),
+        }
+    }
+
+    /// Allows shapes to also be retrieved by their metric designations,
+    /// see [aisc_database::read_metric_designations]
+    pub fn with_metric_designations(
+        mut self,
+        designations: impl IntoIterator<Item = MetricDesignation>,
+    ) -> Self {
+        self.metric_designations.extend(designations);
+        self
+    }
+}
+
+impl<T: TryFrom<AiscShape>> InMemoryShapeRepository<T> {
+    /// Creates a repository containing the shapes of type `T`, which can also be retrieved
+    /// by their derived metric designations (see [MetricDesignation::derive]),
+    /// shapes of every other family are discarded
+    pub fn from_aisc_shapes(shapes: impl IntoIterator<Item = AiscShape>) -> Self {
+        let shapes: Vec<AiscShape> = shapes.into_iter().collect();
+        let metric_designations = MetricDesignation::derive_all(&shapes);
+        InMemoryShapeRepository::new(shapes_of(shapes))
+            .with_metric_designations(metric_designations)
+    }
+
+    /// Creates a repository from a CSV export of the AISC shapes database,
+    /// with the same columns as `aisc-shapes-database-v16.0.csv`
+    pub fn from_csv<R: Read>(reader: R) -> Result<Self, Box<dyn Error>> {
+        Ok(InMemoryShapeRepository::from_aisc_shapes(read_shapes(
+            reader,
+        )?))
+    }
+
+    /// Creates a repository from the CSV file at the path, see [InMemoryShapeRepository::from_csv]
+    pub fn from_csv_path<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        Ok(InMemoryShapeRepository::from_aisc_shapes(
+            read_shapes_from_path(path)?,
+        ))
+    }
+
+    /// Creates a repository from the snapshot of the
+    /// AISC shapes database v16.0 compiled into the library
+    #[cfg(feature = "snapshot")]
+    pub fn from_snapshot() -> Self {
+        InMemoryShapeRepository::from_aisc_shapes(
+            shapes::aisc_shapes::aisc_database::snapshot()
+                .iter()
+                .cloned(),
+        )
+    }
+}
+
+impl<T: SectionProperties + Clone> InMemoryShapeRepository<T> {
+    fn all_shapes(&self) -> Vec<T> {
+        self.shapes.clone()
+    }
+
+    fn shape_with_edi(&self, edi_std_nomenclature: String) -> Result<T, RepositoryError> {
+        let metric = self
+            .metric_designations
+            .iter()
+            .find(|d| d.metric_edi_std_nomenclature == edi_std_nomenclature)
+            .map(|d| d.edi_std_nomenclature.as_str());
+        let normalized = Designation::normalize_edi_std_nomenclature(&edi_std_nomenclature);
+        self.first_shape(edi_std_nomenclature.clone(), |s| {
+            s.edi_std_nomenclature() == edi_std_nomenclature
+                || s.edi_std_nomenclature() == normalized
+                || Some(s.edi_std_nomenclature()) == metric
+        })
+    }
+
+    fn shape_with_label(&self, aisc_manual_label: String) -> Result<T, RepositoryError> {
+        let metric = self
+            .metric_designations
+            .iter()
+            .find(|d| d.metric_aisc_manual_label == aisc_manual_label)
+            .map(|d| d.aisc_manual_label.as_str());
+        let normalized = Designation::normalize_aisc_manual_label(&aisc_manual_label);
+        self.first_shape(aisc_manual_label.clone(), |s| {
+            s.aisc_manual_label() == aisc_manual_label
+                || s.aisc_manual_label() == normalized
+                || Some(s.aisc_manual_label()) == metric
+        })
+    }
+
+    fn first_shape(
+        &self,
+        key: String,
+        condition: impl Fn(&T) -> bool,
+    ) -> Result<T, RepositoryError> {
+        self.shapes
+            .iter()
+            .find(|s| condition(s))
+            .cloned()
+            .ok_or(RepositoryError::NotFound { key })
+    }
+
+    fn shapes_with(&self, property: ShapeProperty, value: f64) -> Vec<T> {
+        self.shapes
+            .iter()
+            .filter(|s| s.property(property) == Some(value))
+            .cloned()
+            .collect::<Vec<_>>()
+    }
+
+    fn shapes_matching_query(&self, query: &ShapeQuery) -> Vec<T> {
+        query.apply(self.all_shapes())
+    }
+}
+
+macro_rules! impl_shape_repository {
+    ($shape:ident, $family:expr) => {
+        impl ShapeRepository<$shape> for InMemoryShapeRepository<$shape> {
+            async fn all(&self) -> Result<Vec<$shape>, RepositoryError> {
+                Ok(self.all_shapes())
+            }
+
+            async fn shape_with_edi_std_nomenclature(
+                &self,
+                edi_std_nomenclature: String,
+            ) -> Result<$shape, RepositoryError> {
+                self.shape_with_edi(edi_std_nomenclature)
+            }
+
+            async fn shape_with_aisc_manual_label(
+                &self,
+                aisc_manual_label: String,
+            ) -> Result<$shape, RepositoryError> {
+                self.shape_with_label(aisc_manual_label)
+            }
+
+            async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<$shape>, RepositoryError> {
+                Ok(self.shapes_with($family.depth_property(), depth))
+            }
+
+            async fn shapes_with_width(&self, width: f64) -> Result<Vec<$shape>, RepositoryError> {
+                Ok(self.shapes_with($family.width_property(), width))
+            }
+
+            async fn shapes_matching(
+                &self,
+                query: ShapeQuery,
+            ) -> Result<Vec<$shape>, RepositoryError> {
+                Ok(self.shapes_matching_query(&query))
+            }
+        }
+    };
+}
+
+macro_rules! impl_round_shape_repository {
+    ($shape:ident) => {
+        impl RoundShapeRepository<$shape> for InMemoryShapeRepository<$shape> {
+            async fn all(&self) -> Result<Vec<$shape>, RepositoryError> {
+                Ok(self.all_shapes())
+            }
+
+            async fn shape_with_edi_std_nomenclature(
+                &self,
+                edi_std_nomenclature: String,
+            ) -> Result<$shape, RepositoryError> {
+                self.shape_with_edi(edi_std_nomenclature)
+            }
+
+            async fn shape_with_aisc_manual_label(
+                &self,
+                aisc_manual_label: String,
+            ) -> Result<$shape, RepositoryError> {
+                self.shape_with_label(aisc_manual_label)
+            }
+
+            async fn shapes_with_diameter(
+                &self,
+                diameter: f64,
+            ) -> Result<Vec<$shape>, RepositoryError> {
+                Ok(self.shapes_with(ShapeProperty::Od, diameter))
+            }
+
+            async fn shapes_matching(
+                &self,
+                query: ShapeQuery,
+            ) -> Result<Vec<$shape>, RepositoryError> {
+                Ok(self.shapes_matching_query(&query))
+            }
+        }
+    };
+}
+
+impl_shape_repository!(WideFlange, ShapeFamily::WideFlange);
+impl_shape_repository!(MiscBeam, ShapeFamily::MiscBeam);
+impl_shape_repository!(StructuralBeam, ShapeFamily::StructuralBeam);
+impl_shape_repository!(HPile, ShapeFamily::HPile);
+impl_shape_repository!(CeeChannel, ShapeFamily::CeeChannel);
+impl_shape_repository!(MiscChannel, ShapeFamily::MiscChannel);
+impl_shape_repository!(Angle, ShapeFamily::Angle);
+impl_shape_repository!(WideFlangeTee, ShapeFamily::WideFlangeTee);
+impl_shape_repository!(MiscTee, ShapeFamily::MiscTee);
+impl_shape_repository!(StructuralTee, ShapeFamily::StructuralTee);
+impl_shape_repository!(DoubleAngle, ShapeFamily::DoubleAngle);
+impl_shape_repository!(
+    HollowStructuralSection,
+    ShapeFamily::HollowStructuralSection
+);
+impl_round_shape_repository!(RoundHollowStructuralSection);
+impl_round_shape_repository!(Pipe);
+
+#[cfg(all(test, feature = "snapshot"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn snapshot_contains_every_shape_of_the_family() {
+        let repository = InMemoryShapeRepository::<Pipe>::from_snapshot();
+        assert_eq!(51, repository.all().await.unwrap().len());
+        let repository = InMemoryShapeRepository::<HollowStructuralSection>::from_snapshot();
+        assert_eq!(525, repository.all().await.unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn shapes_are_found_by_label() {
+        let repository = InMemoryShapeRepository::<WideFlange>::from_snapshot();
+        let shape = repository
+            .shape_with_aisc_manual_label(String::from("W14X90"))
+            .await
+            .unwrap();
+        assert_eq!(14.0, shape.d_lower);
+        let err = repository
+            .shape_with_edi_std_nomenclature(String::from("W14X91"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RepositoryError::NotFound { key } if key == "W14X91"));
+    }
+
+    #[tokio::test]
+    async fn shapes_are_found_by_loosely_written_designations() {
+        let repository = InMemoryShapeRepository::<WideFlange>::from_snapshot();
+        let shape = repository
+            .shape_with_aisc_manual_label(String::from("w 14 x 90"))
+            .await
+            .unwrap();
+        assert_eq!("W14X90", shape.aisc_manual_label);
+        let repository = InMemoryShapeRepository::<Angle>::from_snapshot();
+        let shape = repository
+            .shape_with_aisc_manual_label(String::from("L4X4X.5"))
+            .await
+            .unwrap();
+        assert_eq!("L4X4X1/2", shape.aisc_manual_label);
+        let repository = InMemoryShapeRepository::<HollowStructuralSection>::from_snapshot();
+        let shape = repository
+            .shape_with_edi_std_nomenclature(String::from("hss6x4x1/4"))
+            .await
+            .unwrap();
+        assert_eq!("HSS6X4X.250", shape.edi_std_nomenclature);
+    }
+
+    #[tokio::test]
+    async fn shapes_are_found_by_metric_designation() {
+        let repository = InMemoryShapeRepository::<WideFlange>::from_snapshot();
+        let shape = repository
+            .shape_with_aisc_manual_label(String::from("W360X134"))
+            .await
+            .unwrap();
+        assert_eq!("W14X90", shape.aisc_manual_label);
+        let shape = repository
+            .shape_with_edi_std_nomenclature(String::from("W360X134"))
+            .await
+            .unwrap();
+        assert_eq!("W14X90", shape.edi_std_nomenclature);
+        let repository = InMemoryShapeRepository::<HollowStructuralSection>::from_snapshot();
+        let shape = repository
+            .shape_with_aisc_manual_label(String::from("HSS152.4X101.6X6.4"))
+            .await
+            .unwrap();
+        assert_eq!("HSS6X4X1/4", shape.aisc_manual_label);
+    }
+
+    #[tokio::test]
+    async fn angle_depth_is_the_longer_leg() {
+        let repository = InMemoryShapeRepository::<Angle>::from_snapshot();
+        let shapes = repository.shapes_with_depth(8.0).await.unwrap();
+        assert!(!shapes.is_empty());
+        assert!(shapes.iter().all(|s| s.b_lower == 8.0));
+    }
+
+    #[tokio::test]
+    async fn lightest_shape_satisfies_requirements() {
+        let repository = InMemoryShapeRepository::<WideFlange>::from_snapshot();
+        let requirements = ShapeQuery::new()
+            .with_min(ShapeProperty::Zx, 120.0)
+            .with_max(ShapeProperty::DLower, 16.0);
+        let selection = repository
+            .lightest_shape_matching(requirements, 2)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!("W14X74", selection.lightest.aisc_manual_label);
+        let alternatives = selection
+            .alternatives
+            .iter()
+            .map(|s| s.aisc_manual_label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["W14X82", "W12X87"], alternatives);
+    }
+
+    #[tokio::test]
+    async fn pages_cover_every_shape_once() {
+        let repository = InMemoryShapeRepository::<HollowStructuralSection>::from_snapshot();
+        let all = repository.all().await.unwrap();
+        let mut request = PageRequest::new()
+            .with_limit(40)
+            .sorted_by(SortKey::Depth, SortDirection::Descending);
+        let mut labels = Vec::new();
+        loop {
+            let page = repository.page(request).await.unwrap();
+            assert!(page.shapes.len() <= 40);
+            labels.extend(page.shapes.into_iter().map(|s| s.aisc_manual_label));
+            match page.next_offset {
+                Some(offset) => request = request.with_offset(offset),
+                None => break,
+            }
+        }
+        assert_eq!(all.len(), labels.len());
+        let deepest = all.iter().map(|s| s.ht).fold(0.0, f64::max);
+        let first = all
+            .iter()
+            .find(|s| s.aisc_manual_label == labels[0])
+            .unwrap();
+        assert_eq!(deepest, first.ht);
+        labels.sort();
+        labels.dedup();
+        assert_eq!(all.len(), labels.len());
+
+        let repository = InMemoryShapeRepository::<Pipe>::from_snapshot();
+        let page = RoundShapeRepository::page(
+            &repository,
+            PageRequest::new()
+                .with_limit(2)
+                .sorted_by(SortKey::Weight, SortDirection::Ascending),
+        )
+        .await
+        .unwrap();
+        assert_eq!(Some(2), page.next_offset);
+        assert!(page.shapes[0].w_upper <= page.shapes[1].w_upper);
+    }
+
+    #[tokio::test]
+    async fn round_shapes_are_found_by_diameter() {
+        let repository = InMemoryShapeRepository::<Pipe>::from_snapshot();
+        let shapes = repository.shapes_with_diameter(4.5).await.unwrap();
+        assert!(shapes.iter().all(|s| s.od == 4.5));
+        assert_eq!(3, shapes.len());
+    }
+}
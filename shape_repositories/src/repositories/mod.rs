@@ -17,6 +17,9 @@ pub mod h_pile_repository;
 /// managing data access for hollow structural section (HSS) shapes
 pub mod hollow_structural_section_repository;
 /// The repository responsible for
+/// managing data access for shapes held in memory
+pub mod in_memory_repository;
+/// The repository responsible for
 /// managing data access for misc. beam shapes
 pub mod misc_beam_repository;
 /// The repository responsible for
@@ -32,6 +35,15 @@ pub mod pipe_repository;
 /// managing data access for round hollow structural section (HSS) shapes
 pub mod round_hollow_structural_section_repository;
 /// The repository responsible for
+/// searching shapes across every shape family
+pub mod shape_catalog_repository;
+mod sql_error;
+mod sql_query;
+/// The repository responsible for
+/// managing data access for shapes stored in SQLite
+#[cfg(feature = "sqlite")]
+pub mod sqlite_repository;
+/// The repository responsible for
 /// managing data access for structural beam shapes
 pub mod structural_beam_repository;
 /// The repository responsible for
@@ -49,11 +61,18 @@ pub use cee_channel_repository::CeeChannelRepository;
 pub use double_angle_repository::DoubleAngleRepository;
 pub use h_pile_repository::HPileRepository;
 pub use hollow_structural_section_repository::HollowStructuralSectionRepository;
+pub use in_memory_repository::InMemoryShapeRepository;
 pub use misc_beam_repository::MiscBeamRepository;
 pub use misc_channel_repository::MiscChannelRepository;
 pub use misc_tee_repository::MiscTeeRepository;
 pub use pipe_repository::PipeRepository;
 pub use round_hollow_structural_section_repository::RoundHollowStructuralSectionRepository;
+pub use shape_catalog_repository::{CatalogSearch, ShapeCatalogRepository};
+#[cfg(feature = "sqlite")]
+pub use sqlite_repository::{
+    SqliteShapeRepository, create_sqlite_schema, insert_sqlite_metric_designations,
+    insert_sqlite_shapes, sqlite_schema,
+};
 pub use structural_beam_repository::StructuralBeamRepository;
 pub use structural_tee_repository::StructuralTeeRepository;
 pub use wide_flange_repository::WideFlangeRepository;
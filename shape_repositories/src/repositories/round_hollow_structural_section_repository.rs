@@ -1,7 +1,11 @@
-use shapes::aisc_shapes::{RoundHollowStructuralSection, RoundShapeRepository, ShapeBuilder};
-use sqlx::Row;
+use crate::repositories::sql_error::{TryGetColumn, database_error};
+use crate::repositories::sql_query::select_matching;
+use shapes::aisc_shapes::{
+    Designation, RepositoryError, RoundHollowStructuralSection, RoundShapeRepository, ShapeBuilder,
+    ShapeFamily, ShapeQuery,
+};
+use sqlx::Postgres;
 use sqlx::postgres::{PgPool, PgRow};
-use std::error::Error;
 use std::sync::Arc;
 
 /// Repository that manages data access for all round HSS shapes
@@ -18,7 +22,7 @@ impl RoundHollowStructuralSectionRepository {
 }
 
 impl RoundShapeRepository<RoundHollowStructuralSection> for RoundHollowStructuralSectionRepository {
-    async fn all(&self) -> Result<Vec<RoundHollowStructuralSection>, Box<dyn Error>> {
+    async fn all(&self) -> Result<Vec<RoundHollowStructuralSection>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -42,17 +46,16 @@ impl RoundShapeRepository<RoundHollowStructuralSection> for RoundHollowStructura
     FROM round_hollow_structural_sections;",
         )
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let results = rows
             .into_iter()
-            .map(|r| round_hollow_structural_section_from_row(r))
+            .map(round_hollow_structural_section_from_row)
             .collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -63,7 +66,7 @@ impl RoundShapeRepository<RoundHollowStructuralSection> for RoundHollowStructura
     async fn shape_with_edi_std_nomenclature(
         &self,
         edi_std_nomenclature: String,
-    ) -> Result<RoundHollowStructuralSection, Box<dyn Error>> {
+    ) -> Result<RoundHollowStructuralSection, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -85,12 +88,21 @@ impl RoundShapeRepository<RoundHollowStructuralSection> for RoundHollowStructura
     j_upper,
     c_upper
     FROM round_hollow_structural_sections 
-	WHERE edi_std_nomenclature = $1
+	WHERE edi_std_nomenclature IN (
+		$1,
+		$2,
+		(SELECT edi_std_nomenclature FROM metric_designations WHERE metric_edi_std_nomenclature = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(edi_std_nomenclature)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&edi_std_nomenclature)
+        .bind(Designation::normalize_edi_std_nomenclature(&edi_std_nomenclature))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: edi_std_nomenclature,
+        })?;
 
         round_hollow_structural_section_from_row(row)
     }
@@ -98,7 +110,7 @@ impl RoundShapeRepository<RoundHollowStructuralSection> for RoundHollowStructura
     async fn shape_with_aisc_manual_label(
         &self,
         aisc_manual_label: String,
-    ) -> Result<RoundHollowStructuralSection, Box<dyn Error>> {
+    ) -> Result<RoundHollowStructuralSection, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -120,12 +132,21 @@ impl RoundShapeRepository<RoundHollowStructuralSection> for RoundHollowStructura
     j_upper,
     c_upper
     FROM round_hollow_structural_sections 
-	WHERE aisc_manual_label = $1
+	WHERE aisc_manual_label IN (
+		$1,
+		$2,
+		(SELECT aisc_manual_label FROM metric_designations WHERE metric_aisc_manual_label = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(aisc_manual_label)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&aisc_manual_label)
+        .bind(Designation::normalize_aisc_manual_label(&aisc_manual_label))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: aisc_manual_label,
+        })?;
 
         round_hollow_structural_section_from_row(row)
     }
@@ -133,7 +154,7 @@ impl RoundShapeRepository<RoundHollowStructuralSection> for RoundHollowStructura
     async fn shapes_with_diameter(
         &self,
         diameter: f64,
-    ) -> Result<Vec<RoundHollowStructuralSection>, Box<dyn Error>> {
+    ) -> Result<Vec<RoundHollowStructuralSection>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -159,47 +180,66 @@ impl RoundShapeRepository<RoundHollowStructuralSection> for RoundHollowStructura
         )
         .bind(diameter)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let results = rows
             .into_iter()
-            .map(|r| round_hollow_structural_section_from_row(r))
+            .map(round_hollow_structural_section_from_row)
             .collect::<Vec<_>>();
         if results.iter().any(|r| r.is_err()) {
             for result in results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
             Ok(results.into_iter().map(|r| r.unwrap()).collect::<Vec<_>>())
         }
     }
+
+    async fn shapes_matching(
+        &self,
+        query: ShapeQuery,
+    ) -> Result<Vec<RoundHollowStructuralSection>, RepositoryError> {
+        let Some(mut builder) =
+            select_matching::<Postgres>(ShapeFamily::RoundHollowStructuralSection, &query)
+        else {
+            return Ok(Vec::new());
+        };
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.into_iter()
+            .map(round_hollow_structural_section_from_row)
+            .collect()
+    }
 }
 
 // Helper Functions
 fn round_hollow_structural_section_from_row(
     row: PgRow,
-) -> Result<RoundHollowStructuralSection, Box<dyn Error>> {
+) -> Result<RoundHollowStructuralSection, RepositoryError> {
     Ok(ShapeBuilder::new()
-        .with_edi_std_nomenclature(row.try_get("edi_std_nomenclature")?)
-        .with_aisc_manual_label(row.try_get("aisc_manual_label")?)
-        .with_w_upper(row.try_get("w_upper")?)
-        .with_a_upper(row.try_get("a_upper")?)
-        .with_od(row.try_get("od")?)
-        .with_t_nom(row.try_get("t_nom")?)
-        .with_tdes(row.try_get("tdes")?)
-        .with_d_t(row.try_get("d_t")?)
-        .with_ix(row.try_get("ix")?)
-        .with_zx(row.try_get("zx")?)
-        .with_sx(row.try_get("sx")?)
-        .with_rx(row.try_get("rx")?)
-        .with_iy(row.try_get("iy")?)
-        .with_zy(row.try_get("zy")?)
-        .with_sy(row.try_get("sy")?)
-        .with_ry(row.try_get("ry")?)
-        .with_j_upper(row.try_get("j_upper")?)
-        .with_c_upper(row.try_get("c_upper")?)
+        .with_edi_std_nomenclature(row.try_get_column("edi_std_nomenclature")?)
+        .with_aisc_manual_label(row.try_get_column("aisc_manual_label")?)
+        .with_w_upper(row.try_get_column("w_upper")?)
+        .with_a_upper(row.try_get_column("a_upper")?)
+        .with_od(row.try_get_column("od")?)
+        .with_t_nom(row.try_get_column("t_nom")?)
+        .with_tdes(row.try_get_column("tdes")?)
+        .with_d_t(row.try_get_column("d_t")?)
+        .with_ix(row.try_get_column("ix")?)
+        .with_zx(row.try_get_column("zx")?)
+        .with_sx(row.try_get_column("sx")?)
+        .with_rx(row.try_get_column("rx")?)
+        .with_iy(row.try_get_column("iy")?)
+        .with_zy(row.try_get_column("zy")?)
+        .with_sy(row.try_get_column("sy")?)
+        .with_ry(row.try_get_column("ry")?)
+        .with_j_upper(row.try_get_column("j_upper")?)
+        .with_c_upper(row.try_get_column("c_upper")?)
         .try_build::<RoundHollowStructuralSection>()?)
 }
@@ -0,0 +1,682 @@
+use crate::repositories::sql_error::{TryGetColumn, database_error};
+use crate::repositories::sql_query::table_name;
+use crate::repositories::*;
+use shapes::aisc_shapes::{
+    AiscShape, DesignationMatch, DesignationSearch, MetricDesignation, RepositoryError,
+    RoundShapeRepository, SectionProperties, ShapeFamily, ShapeProperty, ShapeQuery,
+    ShapeRepository,
+};
+use sqlx::postgres::PgPool;
+use std::sync::{Arc, OnceLock};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// Criteria for searching shapes across every shape family,
+/// a shape must satisfy every criterion that has been set
+pub struct CatalogSearch {
+    /// Only include shapes whose AISC Manual Label or
+    /// EDI Std Nomenclature starts with this prefix (case-insensitive)
+    pub label_prefix: Option<String>,
+    /// Only include shapes from these families, all families when `None`
+    pub families: Option<Vec<ShapeFamily>>,
+    /// Minimum depth, in., see [AiscShape::depth]
+    pub min_depth: Option<f64>,
+    /// Maximum depth, in., see [AiscShape::depth]
+    pub max_depth: Option<f64>,
+    /// Minimum width, in., see [AiscShape::width]
+    pub min_width: Option<f64>,
+    /// Maximum width, in., see [AiscShape::width]
+    pub max_width: Option<f64>,
+    /// Minimum nominal weight, lb/ft
+    pub min_weight: Option<f64>,
+    /// Maximum nominal weight, lb/ft
+    pub max_weight: Option<f64>,
+}
+
+impl CatalogSearch {
+    /// Creates a search without any criteria, matching every shape
+    pub fn new() -> Self {
+        CatalogSearch::default()
+    }
+
+    /// Restricts the search to shapes whose label starts with the prefix
+    pub fn with_label_prefix(mut self, prefix: &str) -> Self {
+        self.label_prefix = Some(String::from(prefix));
+        self
+    }
+
+    /// Restricts the search to the given shape families
+    pub fn with_families(mut self, families: Vec<ShapeFamily>) -> Self {
+        self.families = Some(families);
+        self
+    }
+
+    /// Restricts the search to shapes with a depth within the inclusive range
+    pub fn with_depth_between(mut self, min_depth: f64, max_depth: f64) -> Self {
+        self.min_depth = Some(min_depth);
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Restricts the search to shapes with a width within the inclusive range
+    pub fn with_width_between(mut self, min_width: f64, max_width: f64) -> Self {
+        self.min_width = Some(min_width);
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Restricts the search to shapes with a nominal weight within the inclusive range
+    pub fn with_weight_between(mut self, min_weight: f64, max_weight: f64) -> Self {
+        self.min_weight = Some(min_weight);
+        self.max_weight = Some(max_weight);
+        self
+    }
+
+    /// Whether shapes of the family can be returned by the search
+    pub fn includes_family(&self, family: ShapeFamily) -> bool {
+        match &self.families {
+            Some(families) => families.contains(&family),
+            None => true,
+        }
+    }
+
+    /// Whether the shape satisfies every criterion of the search
+    pub fn matches(&self, shape: &AiscShape) -> bool {
+        self.includes_family(shape.family())
+            && self.matches_label(shape)
+            && within(shape.depth(), self.min_depth, self.max_depth)
+            && within(shape.width(), self.min_width, self.max_width)
+            && within(shape.w_upper(), self.min_weight, self.max_weight)
+    }
+
+    /// Converts the criteria of the search into a query on the shapes of the family,
+    /// depth and width map to the family's depth and width properties
+    pub fn query_for(&self, family: ShapeFamily) -> ShapeQuery {
+        let mut query = ShapeQuery::new();
+        let ranges = [
+            (family.depth_property(), self.min_depth, self.max_depth),
+            (family.width_property(), self.min_width, self.max_width),
+            (ShapeProperty::WUpper, self.min_weight, self.max_weight),
+        ];
+        for (property, min, max) in ranges {
+            if let Some(min) = min {
+                query = query.with_min(property, min);
+            }
+            if let Some(max) = max {
+                query = query.with_max(property, max);
+            }
+        }
+        if let Some(prefix) = &self.label_prefix {
+            query = query.with_label_prefix(prefix);
+        }
+        query
+    }
+
+    fn matches_label(&self, shape: &AiscShape) -> bool {
+        match &self.label_prefix {
+            Some(prefix) => {
+                let prefix = prefix.to_uppercase();
+                shape
+                    .aisc_manual_label()
+                    .to_uppercase()
+                    .starts_with(&prefix)
+                    || shape
+                        .edi_std_nomenclature()
+                        .to_uppercase()
+                        .starts_with(&prefix)
+            }
+            None => true,
+        }
+    }
+}
+
+/// Repository that manages data access for shapes of every family,
+/// results are returned as [AiscShape] tagged with their family
+pub struct ShapeCatalogRepository {
+    pool: Arc<PgPool>,
+    /// The family, AISC Manual Label and EDI Std Nomenclature of every shape,
+    /// read once by the first designation search
+    designations: OnceLock<Vec<(ShapeFamily, String, String)>>,
+    /// The metric designations of the shapes, read once by the first designation search
+    metric_designations: OnceLock<Vec<MetricDesignation>>,
+}
+
+impl ShapeCatalogRepository {
+    /// Creates a new instance of ShapeCatalogRepository type
+    /// Takes a pool containing the Postgres database connection
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        ShapeCatalogRepository {
+            pool,
+            designations: OnceLock::new(),
+            metric_designations: OnceLock::new(),
+        }
+    }
+
+    /// Retrieves all shapes matching the search, ordered by family
+    pub async fn search(&self, search: &CatalogSearch) -> Result<Vec<AiscShape>, RepositoryError> {
+        let mut results = Vec::new();
+        for family in ShapeFamily::ALL {
+            if !search.includes_family(family) {
+                continue;
+            }
+            let shapes = self
+                .shapes_in_family(family, search.query_for(family))
+                .await?;
+            results.extend(shapes);
+        }
+        Ok(results)
+    }
+
+    /// Retrieves shapes of every family whose label starts with the prefix
+    pub async fn shapes_with_label_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<AiscShape>, RepositoryError> {
+        self.search(&CatalogSearch::new().with_label_prefix(prefix))
+            .await
+    }
+
+    /// Retrieves shapes of every family with a depth within the inclusive range
+    pub async fn shapes_with_depth_between(
+        &self,
+        min_depth: f64,
+        max_depth: f64,
+    ) -> Result<Vec<AiscShape>, RepositoryError> {
+        self.search(&CatalogSearch::new().with_depth_between(min_depth, max_depth))
+            .await
+    }
+
+    /// Retrieves shapes of every family with a width within the inclusive range
+    pub async fn shapes_with_width_between(
+        &self,
+        min_width: f64,
+        max_width: f64,
+    ) -> Result<Vec<AiscShape>, RepositoryError> {
+        self.search(&CatalogSearch::new().with_width_between(min_width, max_width))
+            .await
+    }
+
+    /// Retrieves shapes of every family with a nominal weight within the inclusive range
+    pub async fn shapes_with_weight_between(
+        &self,
+        min_weight: f64,
+        max_weight: f64,
+    ) -> Result<Vec<AiscShape>, RepositoryError> {
+        self.search(&CatalogSearch::new().with_weight_between(min_weight, max_weight))
+            .await
+    }
+
+    /// Retrieves the shape of any family with the AISC Manual Label,
+    /// the family is determined from the designation, see [ShapeFamily::from_designation]
+    pub async fn shape_with_aisc_manual_label(
+        &self,
+        aisc_manual_label: String,
+    ) -> Result<AiscShape, RepositoryError> {
+        let Some(family) = ShapeFamily::from_designation(&aisc_manual_label) else {
+            return Err(RepositoryError::NotFound {
+                key: aisc_manual_label,
+            });
+        };
+        let pool = Arc::clone(&self.pool);
+        Ok(match family {
+            ShapeFamily::WideFlange => WideFlangeRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::MiscBeam => MiscBeamRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::StructuralBeam => StructuralBeamRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::HPile => HPileRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::CeeChannel => CeeChannelRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::MiscChannel => MiscChannelRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::Angle => AngleRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::WideFlangeTee => WideFlangeTeeRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::MiscTee => MiscTeeRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::StructuralTee => StructuralTeeRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::DoubleAngle => DoubleAngleRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::HollowStructuralSection => HollowStructuralSectionRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+            ShapeFamily::RoundHollowStructuralSection => {
+                RoundHollowStructuralSectionRepository::new(pool)
+                    .shape_with_aisc_manual_label(aisc_manual_label)
+                    .await?
+                    .into()
+            }
+            ShapeFamily::Pipe => PipeRepository::new(pool)
+                .shape_with_aisc_manual_label(aisc_manual_label)
+                .await?
+                .into(),
+        })
+    }
+
+    /// Retrieves the shape of any family with the EDI Std Nomenclature,
+    /// the family is determined from the designation, see [ShapeFamily::from_designation]
+    pub async fn shape_with_edi_std_nomenclature(
+        &self,
+        edi_std_nomenclature: String,
+    ) -> Result<AiscShape, RepositoryError> {
+        let Some(family) = ShapeFamily::from_designation(&edi_std_nomenclature) else {
+            return Err(RepositoryError::NotFound {
+                key: edi_std_nomenclature,
+            });
+        };
+        let pool = Arc::clone(&self.pool);
+        Ok(match family {
+            ShapeFamily::WideFlange => WideFlangeRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::MiscBeam => MiscBeamRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::StructuralBeam => StructuralBeamRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::HPile => HPileRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::CeeChannel => CeeChannelRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::MiscChannel => MiscChannelRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::Angle => AngleRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::WideFlangeTee => WideFlangeTeeRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::MiscTee => MiscTeeRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::StructuralTee => StructuralTeeRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::DoubleAngle => DoubleAngleRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::HollowStructuralSection => HollowStructuralSectionRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+            ShapeFamily::RoundHollowStructuralSection => {
+                RoundHollowStructuralSectionRepository::new(pool)
+                    .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                    .await?
+                    .into()
+            }
+            ShapeFamily::Pipe => PipeRepository::new(pool)
+                .shape_with_edi_std_nomenclature(edi_std_nomenclature)
+                .await?
+                .into(),
+        })
+    }
+
+    /// Ranks the shapes of every family whose AISC Manual Label, EDI Std Nomenclature
+    /// or metric designations match the search, see [DesignationSearch::rank_with_metric]
+    pub async fn search_designations(
+        &self,
+        search: &DesignationSearch,
+    ) -> Result<Vec<DesignationMatch>, RepositoryError> {
+        let designations = self.designations().await?;
+        let metric_designations = self.metric_designations().await?;
+        Ok(search.rank_with_metric(
+            designations
+                .iter()
+                .map(|(family, label, edi)| (*family, label.as_str(), edi.as_str())),
+            metric_designations,
+        ))
+    }
+
+    /// The designations of every shape, read from the database on the first call only
+    /// as the shapes database doesn't change while the repository is in use
+    async fn designations(&self) -> Result<&[(ShapeFamily, String, String)], RepositoryError> {
+        if let Some(designations) = self.designations.get() {
+            return Ok(designations);
+        }
+        let sql = ShapeFamily::ALL
+            .into_iter()
+            .map(|family| {
+                format!(
+                    "SELECT '{}' AS family, aisc_manual_label, edi_std_nomenclature FROM {}",
+                    family.name(),
+                    table_name(family)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" UNION ALL ");
+        let rows = sqlx::query(&sql)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(database_error)?;
+        let mut designations = Vec::with_capacity(rows.len());
+        for row in rows {
+            let family: String = row.try_get_column("family")?;
+            let family =
+                family
+                    .parse::<ShapeFamily>()
+                    .map_err(|err| RepositoryError::InvalidRow {
+                        column: String::from("family"),
+                        source: Box::new(err),
+                    })?;
+            let aisc_manual_label: String = row.try_get_column("aisc_manual_label")?;
+            let edi_std_nomenclature: String = row.try_get_column("edi_std_nomenclature")?;
+            designations.push((family, aisc_manual_label, edi_std_nomenclature));
+        }
+        Ok(self.designations.get_or_init(|| designations))
+    }
+
+    /// The metric designations of the shapes, read from the database on the first call only
+    async fn metric_designations(&self) -> Result<&[MetricDesignation], RepositoryError> {
+        if let Some(designations) = self.metric_designations.get() {
+            return Ok(designations);
+        }
+        let rows = sqlx::query(
+            "SELECT edi_std_nomenclature, aisc_manual_label,
+    metric_edi_std_nomenclature, metric_aisc_manual_label
+    FROM metric_designations",
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(database_error)?;
+        let mut designations = Vec::with_capacity(rows.len());
+        for row in rows {
+            designations.push(MetricDesignation {
+                edi_std_nomenclature: row.try_get_column("edi_std_nomenclature")?,
+                aisc_manual_label: row.try_get_column("aisc_manual_label")?,
+                metric_edi_std_nomenclature: row.try_get_column("metric_edi_std_nomenclature")?,
+                metric_aisc_manual_label: row.try_get_column("metric_aisc_manual_label")?,
+            });
+        }
+        Ok(self.metric_designations.get_or_init(|| designations))
+    }
+
+    /// The AISC Manual Labels of the shapes closest to a designation
+    /// no shape has, e.g. W14X90 for W14X91
+    pub async fn suggestions(&self, designation: &str) -> Result<Vec<String>, RepositoryError> {
+        let matches = self
+            .search_designations(&DesignationSearch::suggestions_for(designation))
+            .await?;
+        Ok(DesignationMatch::labels(&matches))
+    }
+
+    /// The AISC Manual Labels of the shapes of a family closest to a designation
+    /// no shape of the family has, e.g. W14X90 for W14X91 among the wide flanges
+    pub async fn suggestions_in_family(
+        &self,
+        designation: &str,
+        family: ShapeFamily,
+    ) -> Result<Vec<String>, RepositoryError> {
+        Ok(family_suggestions(
+            self.designations().await?,
+            designation,
+            family,
+        ))
+    }
+
+    /// Retrieves the shapes of a single family matching the query
+    pub async fn shapes_in_family(
+        &self,
+        family: ShapeFamily,
+        query: ShapeQuery,
+    ) -> Result<Vec<AiscShape>, RepositoryError> {
+        let pool = Arc::clone(&self.pool);
+        Ok(match family {
+            ShapeFamily::WideFlange => into_aisc_shapes(
+                WideFlangeRepository::new(pool)
+                    .shapes_matching(query)
+                    .await?,
+            ),
+            ShapeFamily::MiscBeam => {
+                into_aisc_shapes(MiscBeamRepository::new(pool).shapes_matching(query).await?)
+            }
+            ShapeFamily::StructuralBeam => into_aisc_shapes(
+                StructuralBeamRepository::new(pool)
+                    .shapes_matching(query)
+                    .await?,
+            ),
+            ShapeFamily::HPile => {
+                into_aisc_shapes(HPileRepository::new(pool).shapes_matching(query).await?)
+            }
+            ShapeFamily::CeeChannel => into_aisc_shapes(
+                CeeChannelRepository::new(pool)
+                    .shapes_matching(query)
+                    .await?,
+            ),
+            ShapeFamily::MiscChannel => into_aisc_shapes(
+                MiscChannelRepository::new(pool)
+                    .shapes_matching(query)
+                    .await?,
+            ),
+            ShapeFamily::Angle => {
+                into_aisc_shapes(AngleRepository::new(pool).shapes_matching(query).await?)
+            }
+            ShapeFamily::WideFlangeTee => into_aisc_shapes(
+                WideFlangeTeeRepository::new(pool)
+                    .shapes_matching(query)
+                    .await?,
+            ),
+            ShapeFamily::MiscTee => {
+                into_aisc_shapes(MiscTeeRepository::new(pool).shapes_matching(query).await?)
+            }
+            ShapeFamily::StructuralTee => into_aisc_shapes(
+                StructuralTeeRepository::new(pool)
+                    .shapes_matching(query)
+                    .await?,
+            ),
+            ShapeFamily::DoubleAngle => into_aisc_shapes(
+                DoubleAngleRepository::new(pool)
+                    .shapes_matching(query)
+                    .await?,
+            ),
+            ShapeFamily::HollowStructuralSection => into_aisc_shapes(
+                HollowStructuralSectionRepository::new(pool)
+                    .shapes_matching(query)
+                    .await?,
+            ),
+            ShapeFamily::RoundHollowStructuralSection => into_aisc_shapes(
+                RoundHollowStructuralSectionRepository::new(pool)
+                    .shapes_matching(query)
+                    .await?,
+            ),
+            ShapeFamily::Pipe => {
+                into_aisc_shapes(PipeRepository::new(pool).shapes_matching(query).await?)
+            }
+        })
+    }
+}
+
+// Helper Functions
+fn into_aisc_shapes<T: Into<AiscShape>>(shapes: Vec<T>) -> Vec<AiscShape> {
+    shapes.into_iter().map(|s| s.into()).collect::<Vec<_>>()
+}
+
+fn within(value: f64, min: Option<f64>, max: Option<f64>) -> bool {
+    min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+}
+
+/// The AISC Manual Labels of the designations of the family closest to the designation,
+/// see [DesignationSearch::suggestions_for]
+fn family_suggestions(
+    designations: &[(ShapeFamily, String, String)],
+    designation: &str,
+    family: ShapeFamily,
+) -> Vec<String> {
+    let matches = DesignationSearch::suggestions_for(designation).rank(
+        designations
+            .iter()
+            .filter(|(shape_family, _, _)| *shape_family == family)
+            .map(|(family, label, edi)| (*family, label.as_str(), edi.as_str())),
+    );
+    DesignationMatch::labels(&matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shapes::aisc_shapes::{Pipe, ShapeBuilder};
+
+    fn pipe() -> AiscShape {
+        ShapeBuilder::new()
+            .with_edi_std_nomenclature(String::from("Pipe2SCH40"))
+            .with_aisc_manual_label(String::from("Pipe2STD"))
+            .with_w_upper(3.66)
+            .with_a_upper(1.02)
+            .with_od(2.375)
+            .with_id(2.07)
+            .with_t_nom(0.154)
+            .with_tdes(0.143)
+            .with_d_t(16.6)
+            .with_ix(0.627)
+            .with_zx(0.713)
+            .with_sx(0.528)
+            .with_rx(0.791)
+            .with_iy(0.627)
+            .with_zy(0.713)
+            .with_sy(0.528)
+            .with_ry(0.791)
+            .with_j_upper(1.25)
+            .try_build::<Pipe>()
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn empty_search_matches_every_shape() {
+        assert!(CatalogSearch::new().matches(&pipe()));
+    }
+
+    #[test]
+    fn label_prefix_matches_either_designation() {
+        let shape = pipe();
+        assert!(
+            CatalogSearch::new()
+                .with_label_prefix("pipe2s")
+                .matches(&shape)
+        );
+        assert!(
+            CatalogSearch::new()
+                .with_label_prefix("Pipe2SCH")
+                .matches(&shape)
+        );
+        assert!(
+            !CatalogSearch::new()
+                .with_label_prefix("W14")
+                .matches(&shape)
+        );
+    }
+
+    #[test]
+    fn ranges_are_inclusive() {
+        let shape = pipe();
+        assert!(
+            CatalogSearch::new()
+                .with_depth_between(2.375, 2.375)
+                .with_width_between(2.0, 3.0)
+                .with_weight_between(3.0, 3.66)
+                .matches(&shape)
+        );
+        assert!(
+            !CatalogSearch::new()
+                .with_weight_between(3.67, 10.0)
+                .matches(&shape)
+        );
+    }
+
+    #[test]
+    fn families_restrict_the_search() {
+        let shape = pipe();
+        let search = CatalogSearch::new().with_families(vec![ShapeFamily::WideFlange]);
+        assert!(!search.includes_family(ShapeFamily::Pipe));
+        assert!(!search.matches(&shape));
+    }
+
+    #[test]
+    fn query_uses_the_family_depth_and_width_properties() {
+        let search = CatalogSearch::new()
+            .with_depth_between(4.0, 6.0)
+            .with_weight_between(10.0, 20.0);
+        let query = search.query_for(ShapeFamily::Angle);
+        let properties = query
+            .filters
+            .iter()
+            .map(|f| f.property())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ShapeProperty::BLower,
+                ShapeProperty::BLower,
+                ShapeProperty::WUpper,
+                ShapeProperty::WUpper
+            ],
+            properties
+        );
+        let shape = pipe();
+        assert_eq!(
+            search.matches(&shape),
+            search.query_for(ShapeFamily::Pipe).matches(&shape)
+        );
+    }
+
+    #[test]
+    fn family_suggestions_only_include_the_family() {
+        let designations: Vec<(ShapeFamily, String, String)> = [
+            (ShapeFamily::HPile, "HP12X53"),
+            (ShapeFamily::WideFlange, "W12X58"),
+            (ShapeFamily::WideFlange, "W12X53"),
+        ]
+        .into_iter()
+        .map(|(family, label)| (family, String::from(label), String::from(label)))
+        .collect();
+        assert_eq!(
+            vec!["HP12X53"],
+            family_suggestions(&designations, "HP12X58", ShapeFamily::HPile)
+        );
+        assert_eq!(
+            vec!["W12X58"],
+            family_suggestions(&designations, "HP12X58", ShapeFamily::WideFlange)
+        );
+        assert!(family_suggestions(&designations, "HP12X58", ShapeFamily::Pipe).is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+use shapes::aisc_shapes::RepositoryError;
+use sqlx::{ColumnIndex, Decode, Row, Type};
+
+/// Converts an error returned by sqlx into a [RepositoryError],
+/// a value that cannot be read from its column is an invalid row
+pub(crate) fn database_error(err: sqlx::Error) -> RepositoryError {
+    match err {
+        sqlx::Error::ColumnDecode { index, source } => RepositoryError::InvalidRow {
+            column: index,
+            source,
+        },
+        sqlx::Error::ColumnNotFound(column) => RepositoryError::InvalidRow {
+            column: column.clone(),
+            source: Box::new(sqlx::Error::ColumnNotFound(column)),
+        },
+        err => RepositoryError::Database(Box::new(err)),
+    }
+}
+
+/// Reads the values of a row's columns, returning a [RepositoryError]
+/// naming the column when the value cannot be read
+pub(crate) trait TryGetColumn: Row {
+    /// Reads the value of the column, see [Row::try_get]
+    fn try_get_column<'r, T>(&'r self, column: &str) -> Result<T, RepositoryError>
+    where
+        T: Decode<'r, Self::Database> + Type<Self::Database>;
+}
+
+impl<R: Row> TryGetColumn for R
+where
+    for<'c> &'c str: ColumnIndex<R>,
+{
+    fn try_get_column<'r, T>(&'r self, column: &str) -> Result<T, RepositoryError>
+    where
+        T: Decode<'r, Self::Database> + Type<Self::Database>,
+    {
+        self.try_get(column).map_err(database_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undecodable_columns_are_invalid_rows() {
+        let err = database_error(sqlx::Error::ColumnDecode {
+            index: String::from("w_upper"),
+            source: "not a number".into(),
+        });
+        assert!(matches!(err, RepositoryError::InvalidRow { column, .. } if column == "w_upper"));
+        let err = database_error(sqlx::Error::ColumnNotFound(String::from("t_f")));
+        assert!(matches!(err, RepositoryError::InvalidRow { column, .. } if column == "t_f"));
+    }
+
+    #[test]
+    fn other_errors_are_database_errors() {
+        let err = database_error(sqlx::Error::PoolTimedOut);
+        assert!(matches!(err, RepositoryError::Database(_)));
+    }
+
+    #[test]
+    fn errors_can_be_sent_between_threads() {
+        fn assert_send_sync<E: Send + Sync + 'static>() {}
+        assert_send_sync::<RepositoryError>();
+    }
+}
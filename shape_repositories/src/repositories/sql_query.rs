@@ -0,0 +1,222 @@
+use shapes::aisc_shapes::{ShapeFamily, ShapeQuery, SortDirection};
+use sqlx::{Database, Encode, QueryBuilder, Type};
+
+/// The table the shapes of the family are stored in
+pub(crate) fn table_name(family: ShapeFamily) -> &'static str {
+    match family {
+        ShapeFamily::WideFlange => "wide_flanges",
+        ShapeFamily::MiscBeam => "misc_beams",
+        ShapeFamily::StructuralBeam => "structural_beams",
+        ShapeFamily::HPile => "h_piles",
+        ShapeFamily::CeeChannel => "cee_channels",
+        ShapeFamily::MiscChannel => "misc_channels",
+        ShapeFamily::Angle => "angles",
+        ShapeFamily::WideFlangeTee => "wide_flange_tees",
+        ShapeFamily::MiscTee => "misc_tees",
+        ShapeFamily::StructuralTee => "structural_tees",
+        ShapeFamily::DoubleAngle => "double_angles",
+        ShapeFamily::HollowStructuralSection => "hollow_structural_sections",
+        ShapeFamily::RoundHollowStructuralSection => "round_hollow_structural_sections",
+        ShapeFamily::Pipe => "pipes",
+    }
+}
+
+/// The columns read by the family's `*_from_row` helper, in table order
+pub(crate) fn select_columns(family: ShapeFamily) -> Vec<&'static str> {
+    let mut columns = vec!["edi_std_nomenclature", "aisc_manual_label"];
+    if has_special_fabrication_flag(family) {
+        columns.push("t_f");
+    }
+    columns.extend(family.properties().iter().map(|p| p.column_name()));
+    columns
+}
+
+/// Builds a select statement for the shapes of the family
+/// satisfying the condition, e.g. `od = ?`
+#[cfg(feature = "sqlite")]
+pub(crate) fn select_where(family: ShapeFamily, condition: &str) -> String {
+    format!(
+        "SELECT {} FROM {} WHERE {condition};",
+        select_columns(family).join(", "),
+        table_name(family)
+    )
+}
+
+/// Whether the family stores the special fabrication flag `T_F`
+pub(crate) fn has_special_fabrication_flag(family: ShapeFamily) -> bool {
+    matches!(
+        family,
+        ShapeFamily::WideFlange
+            | ShapeFamily::MiscBeam
+            | ShapeFamily::WideFlangeTee
+            | ShapeFamily::MiscTee
+    )
+}
+
+/// Builds a select statement for the shapes of the family matching the query,
+/// returns `None` when the query filters on a property the family does not
+/// define, as no shape of the family can match it
+pub(crate) fn select_matching<DB>(
+    family: ShapeFamily,
+    query: &ShapeQuery,
+) -> Option<QueryBuilder<'static, DB>>
+where
+    DB: Database,
+    f64: Encode<'static, DB> + Type<DB>,
+    i64: Encode<'static, DB> + Type<DB>,
+    String: Encode<'static, DB> + Type<DB>,
+{
+    if query
+        .filters
+        .iter()
+        .any(|filter| !family.has_property(filter.property()))
+    {
+        return None;
+    }
+
+    let mut builder = QueryBuilder::new("SELECT ");
+    builder.push(select_columns(family).join(", "));
+    builder.push(" FROM ");
+    builder.push(table_name(family));
+    builder.push(" WHERE TRUE");
+
+    for filter in &query.filters {
+        let column = filter.property().column_name();
+        let (min, max) = filter.bounds();
+        if let Some(min) = min {
+            builder.push(format!(" AND {column} >= ")).push_bind(min);
+        }
+        if let Some(max) = max {
+            builder.push(format!(" AND {column} <= ")).push_bind(max);
+        }
+    }
+
+    if let Some(prefix) = &query.label_prefix {
+        let pattern = format!("{}%", escape_like(&prefix.to_uppercase()));
+        builder
+            .push(" AND (UPPER(aisc_manual_label) LIKE ")
+            .push_bind(pattern.clone())
+            .push(" ESCAPE '\\' OR UPPER(edi_std_nomenclature) LIKE ")
+            .push_bind(pattern)
+            .push(" ESCAPE '\\')");
+    }
+
+    let order = query
+        .order
+        .iter()
+        .filter(|order| family.has_property(order.property))
+        .map(|order| {
+            let direction = match order.direction {
+                SortDirection::Ascending => "ASC",
+                SortDirection::Descending => "DESC",
+            };
+            format!("{} {direction} NULLS LAST", order.property.column_name())
+        })
+        .chain(query.label_order.map(|direction| {
+            let direction = match direction {
+                SortDirection::Ascending => "ASC",
+                SortDirection::Descending => "DESC",
+            };
+            format!(
+                "aisc_manual_label COLLATE {} {direction}",
+                binary_collation::<DB>()
+            )
+        }))
+        .collect::<Vec<_>>();
+    if !order.is_empty() {
+        builder.push(" ORDER BY ");
+        builder.push(order.join(", "));
+    }
+
+    if let Some(limit) = query.limit {
+        builder
+            .push(" LIMIT ")
+            .push_bind(i64::try_from(limit).unwrap_or(i64::MAX));
+    } else if query.offset.is_some() && is_sqlite::<DB>() {
+        // SQLite only accepts an offset after a limit, a negative one meaning no limit
+        builder.push(" LIMIT -1");
+    }
+    if let Some(offset) = query.offset {
+        builder
+            .push(" OFFSET ")
+            .push_bind(i64::try_from(offset).unwrap_or(i64::MAX));
+    }
+
+    builder.push(";");
+    Some(builder)
+}
+
+// Helper Functions
+
+/// Whether the statement is built for SQLite rather than Postgres
+fn is_sqlite<DB: Database>() -> bool {
+    DB::NAME == "SQLite"
+}
+
+/// The collation comparing text byte by byte, the order
+/// labels are sorted in by [ShapeQuery::apply]
+fn binary_collation<DB: Database>() -> &'static str {
+    if is_sqlite::<DB>() { "BINARY" } else { "\"C\"" }
+}
+
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shapes::aisc_shapes::ShapeProperty;
+
+    #[test]
+    fn filters_are_bound_as_parameters() {
+        let query = ShapeQuery::new()
+            .with_range(ShapeProperty::Zx, 100.0, 200.0)
+            .with_label_prefix("w14")
+            .with_order(ShapeProperty::WUpper, SortDirection::Ascending)
+            .with_limit(5);
+        let builder = select_matching::<sqlx::Postgres>(ShapeFamily::WideFlange, &query).unwrap();
+        let sql = builder.sql();
+        assert!(sql.starts_with("SELECT edi_std_nomenclature, aisc_manual_label, t_f, w_upper,"));
+        assert!(sql.contains(" FROM wide_flanges WHERE TRUE AND zx >= $1 AND zx <= $2"));
+        assert!(sql.contains("UPPER(aisc_manual_label) LIKE $3 ESCAPE '\\'"));
+        assert!(sql.ends_with(" ORDER BY w_upper ASC NULLS LAST LIMIT $5;"));
+    }
+
+    #[test]
+    fn pages_are_ordered_by_label_and_offset() {
+        let query = ShapeQuery::new()
+            .with_order(ShapeProperty::WUpper, SortDirection::Descending)
+            .with_label_order(SortDirection::Ascending)
+            .with_offset(50);
+        let builder = select_matching::<sqlx::Postgres>(ShapeFamily::Pipe, &query).unwrap();
+        assert!(builder.sql().ends_with(
+            " FROM pipes WHERE TRUE ORDER BY w_upper DESC NULLS LAST, aisc_manual_label COLLATE \"C\" ASC OFFSET $1;"
+        ));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn sqlite_offsets_follow_an_unbounded_limit() {
+        let query = ShapeQuery::new().with_offset(50);
+        let builder = select_matching::<sqlx::Sqlite>(ShapeFamily::Pipe, &query).unwrap();
+        assert!(builder.sql().ends_with(" WHERE TRUE LIMIT -1 OFFSET ?;"));
+        let builder =
+            select_matching::<sqlx::Sqlite>(ShapeFamily::Pipe, &query.with_limit(10)).unwrap();
+        assert!(builder.sql().ends_with(" WHERE TRUE LIMIT ? OFFSET ?;"));
+    }
+
+    #[test]
+    fn undefined_properties_match_nothing() {
+        let query = ShapeQuery::new().with_min(ShapeProperty::Bf, 1.0);
+        assert!(select_matching::<sqlx::Postgres>(ShapeFamily::Pipe, &query).is_none());
+    }
+
+    #[test]
+    fn like_wildcards_are_escaped() {
+        assert_eq!("HSS\\_1\\%\\\\", escape_like("HSS_1%\\"));
+    }
+}
@@ -0,0 +1,511 @@
+use crate::repositories::sql_error::{TryGetColumn, database_error};
+use crate::repositories::sql_query::{
+    has_special_fabrication_flag, select_matching, select_where, table_name,
+};
+use shapes::aisc_shapes::*;
+use sqlx::sqlite::{SqlitePool, SqliteRow};
+use sqlx::{QueryBuilder, Sqlite, Transaction};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Repository that manages data access for shapes stored in an SQLite database,
+/// implements [ShapeRepository] or [RoundShapeRepository] for every shape type
+/// with the same query semantics as the Postgres repositories.
+/// The database is created with the statements returned by [sqlite_schema].
+pub struct SqliteShapeRepository<T> {
+    pool: Arc<SqlitePool>,
+    shape: PhantomData<fn() -> T>,
+}
+
+impl<T> SqliteShapeRepository<T> {
+    /// Creates a new instance of SqliteShapeRepository type
+    /// Takes a pool containing the SQLite database connection
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        SqliteShapeRepository {
+            pool,
+            shape: PhantomData,
+        }
+    }
+}
+
+impl<T> SqliteShapeRepository<T>
+where
+    T: TryFrom<ShapeBuilder, Error = MissingPropertyError>,
+{
+    async fn fetch_all_where(
+        &self,
+        family: ShapeFamily,
+        condition: &str,
+        value: Option<f64>,
+    ) -> Result<Vec<T>, RepositoryError> {
+        let sql = select_where(family, condition);
+        let mut query = sqlx::query(&sql);
+        if let Some(value) = value {
+            query = query.bind(value);
+        }
+        let rows = query.fetch_all(&*self.pool).await.map_err(database_error)?;
+
+        rows.into_iter()
+            .map(|r| shape_from_row(family, r))
+            .collect()
+    }
+
+    /// Retrieves the shape with the designation in the column,
+    /// or with the metric designation in the `metric_` column
+    async fn fetch_by_designation(
+        &self,
+        family: ShapeFamily,
+        column: &str,
+        value: String,
+    ) -> Result<T, RepositoryError> {
+        let condition = format!(
+            "{column} IN (?1, ?2, (SELECT {column} FROM metric_designations WHERE metric_{column} = ?1 LIMIT 1)) LIMIT 1"
+        );
+        let normalized = match column {
+            "edi_std_nomenclature" => Designation::normalize_edi_std_nomenclature(&value),
+            _ => Designation::normalize_aisc_manual_label(&value),
+        };
+        let sql = select_where(family, &condition);
+        let row = sqlx::query(&sql)
+            .bind(&value)
+            .bind(normalized)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(database_error)?
+            .ok_or(RepositoryError::NotFound { key: value })?;
+
+        shape_from_row(family, row)
+    }
+
+    async fn fetch_matching(
+        &self,
+        family: ShapeFamily,
+        query: ShapeQuery,
+    ) -> Result<Vec<T>, RepositoryError> {
+        let Some(mut builder) = select_matching::<Sqlite>(family, &query) else {
+            return Ok(Vec::new());
+        };
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.into_iter()
+            .map(|r| shape_from_row(family, r))
+            .collect()
+    }
+}
+
+macro_rules! impl_shape_repository {
+    ($shape:ident, $family:expr) => {
+        impl ShapeRepository<$shape> for SqliteShapeRepository<$shape> {
+            async fn all(&self) -> Result<Vec<$shape>, RepositoryError> {
+                self.fetch_all_where($family, "TRUE", None).await
+            }
+
+            async fn shape_with_edi_std_nomenclature(
+                &self,
+                edi_std_nomenclature: String,
+            ) -> Result<$shape, RepositoryError> {
+                self.fetch_by_designation($family, "edi_std_nomenclature", edi_std_nomenclature)
+                    .await
+            }
+
+            async fn shape_with_aisc_manual_label(
+                &self,
+                aisc_manual_label: String,
+            ) -> Result<$shape, RepositoryError> {
+                self.fetch_by_designation($family, "aisc_manual_label", aisc_manual_label)
+                    .await
+            }
+
+            async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<$shape>, RepositoryError> {
+                let condition = format!("{} = ?", $family.depth_property().column_name());
+                self.fetch_all_where($family, &condition, Some(depth)).await
+            }
+
+            async fn shapes_with_width(&self, width: f64) -> Result<Vec<$shape>, RepositoryError> {
+                let condition = format!("{} = ?", $family.width_property().column_name());
+                self.fetch_all_where($family, &condition, Some(width)).await
+            }
+
+            async fn shapes_matching(
+                &self,
+                query: ShapeQuery,
+            ) -> Result<Vec<$shape>, RepositoryError> {
+                self.fetch_matching($family, query).await
+            }
+        }
+    };
+}
+
+macro_rules! impl_round_shape_repository {
+    ($shape:ident, $family:expr) => {
+        impl RoundShapeRepository<$shape> for SqliteShapeRepository<$shape> {
+            async fn all(&self) -> Result<Vec<$shape>, RepositoryError> {
+                self.fetch_all_where($family, "TRUE", None).await
+            }
+
+            async fn shape_with_edi_std_nomenclature(
+                &self,
+                edi_std_nomenclature: String,
+            ) -> Result<$shape, RepositoryError> {
+                self.fetch_by_designation($family, "edi_std_nomenclature", edi_std_nomenclature)
+                    .await
+            }
+
+            async fn shape_with_aisc_manual_label(
+                &self,
+                aisc_manual_label: String,
+            ) -> Result<$shape, RepositoryError> {
+                self.fetch_by_designation($family, "aisc_manual_label", aisc_manual_label)
+                    .await
+            }
+
+            async fn shapes_with_diameter(
+                &self,
+                diameter: f64,
+            ) -> Result<Vec<$shape>, RepositoryError> {
+                self.fetch_all_where($family, "od = ?", Some(diameter))
+                    .await
+            }
+
+            async fn shapes_matching(
+                &self,
+                query: ShapeQuery,
+            ) -> Result<Vec<$shape>, RepositoryError> {
+                self.fetch_matching($family, query).await
+            }
+        }
+    };
+}
+
+impl_shape_repository!(WideFlange, ShapeFamily::WideFlange);
+impl_shape_repository!(MiscBeam, ShapeFamily::MiscBeam);
+impl_shape_repository!(StructuralBeam, ShapeFamily::StructuralBeam);
+impl_shape_repository!(HPile, ShapeFamily::HPile);
+impl_shape_repository!(CeeChannel, ShapeFamily::CeeChannel);
+impl_shape_repository!(MiscChannel, ShapeFamily::MiscChannel);
+impl_shape_repository!(Angle, ShapeFamily::Angle);
+impl_shape_repository!(WideFlangeTee, ShapeFamily::WideFlangeTee);
+impl_shape_repository!(MiscTee, ShapeFamily::MiscTee);
+impl_shape_repository!(StructuralTee, ShapeFamily::StructuralTee);
+impl_shape_repository!(DoubleAngle, ShapeFamily::DoubleAngle);
+impl_shape_repository!(
+    HollowStructuralSection,
+    ShapeFamily::HollowStructuralSection
+);
+impl_round_shape_repository!(
+    RoundHollowStructuralSection,
+    ShapeFamily::RoundHollowStructuralSection
+);
+impl_round_shape_repository!(Pipe, ShapeFamily::Pipe);
+
+/// The SQLite statements that create a table for every shape family and the metric designations,
+/// with the same tables and columns as the Postgres database in `setup/setup.sql`
+pub fn sqlite_schema() -> String {
+    let mut sql = String::new();
+    for family in ShapeFamily::ALL {
+        sql.push_str(&format!("DROP TABLE IF EXISTS {};\n", table_name(family)));
+    }
+    sql.push_str("DROP TABLE IF EXISTS metric_designations;\n");
+    for family in ShapeFamily::ALL {
+        let mut columns = vec![
+            String::from("shape_id INTEGER PRIMARY KEY"),
+            String::from("edi_std_nomenclature TEXT NOT NULL"),
+            String::from("aisc_manual_label TEXT NOT NULL"),
+        ];
+        if has_special_fabrication_flag(family) {
+            columns.push(match family {
+                ShapeFamily::MiscBeam => String::from("t_f BOOLEAN"),
+                _ => String::from("t_f BOOLEAN NOT NULL"),
+            });
+        }
+        for property in family.properties() {
+            let column = property.column_name();
+            columns.push(match family.optional_properties().contains(property) {
+                true => format!("{column} REAL"),
+                false => format!("{column} REAL NOT NULL"),
+            });
+        }
+        sql.push_str(&format!(
+            "\nCREATE TABLE {} (\n    {}\n);\n",
+            table_name(family),
+            columns.join(",\n    ")
+        ));
+    }
+    sql.push_str(
+        "
+CREATE TABLE metric_designations (
+    designation_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    metric_edi_std_nomenclature TEXT NOT NULL,
+    metric_aisc_manual_label TEXT NOT NULL
+);
+",
+    );
+    sql
+}
+
+/// Creates the tables of every shape family in the SQLite database,
+/// dropping any existing shape tables
+pub async fn create_sqlite_schema(pool: &SqlitePool) -> Result<(), RepositoryError> {
+    sqlx::raw_sql(&sqlite_schema())
+        .execute(pool)
+        .await
+        .map_err(database_error)?;
+    Ok(())
+}
+
+/// Inserts the shapes into the tables of their families in the SQLite database,
+/// along with their derived metric designations (see [MetricDesignation::derive])
+pub async fn insert_sqlite_shapes(
+    pool: &SqlitePool,
+    shapes: &[AiscShape],
+) -> Result<(), RepositoryError> {
+    let mut transaction = pool.begin().await.map_err(database_error)?;
+    for shape in shapes {
+        let family = shape.family();
+        let mut builder = QueryBuilder::<Sqlite>::new(format!(
+            "INSERT INTO {} (edi_std_nomenclature, aisc_manual_label",
+            table_name(family)
+        ));
+        if has_special_fabrication_flag(family) {
+            builder.push(", t_f");
+        }
+        for property in family.properties() {
+            builder.push(", ").push(property.column_name());
+        }
+        builder.push(") VALUES (");
+        let mut values = builder.separated(", ");
+        values.push_bind(String::from(shape.edi_std_nomenclature()));
+        values.push_bind(String::from(shape.aisc_manual_label()));
+        if has_special_fabrication_flag(family) {
+            values.push_bind(shape.t_f());
+        }
+        for property in family.properties() {
+            values.push_bind(shape.property(*property));
+        }
+        builder.push(");");
+        builder
+            .build()
+            .execute(&mut *transaction)
+            .await
+            .map_err(database_error)?;
+    }
+    insert_metric_designations(&mut transaction, &MetricDesignation::derive_all(shapes)).await?;
+    transaction.commit().await.map_err(database_error)?;
+    Ok(())
+}
+
+/// Inserts the metric designations of shapes into the SQLite database, shapes can then
+/// also be retrieved by their metric designations, e.g. the ones of the metric sheet
+/// read by [aisc_database::read_metric_designations]
+pub async fn insert_sqlite_metric_designations(
+    pool: &SqlitePool,
+    designations: &[MetricDesignation],
+) -> Result<(), RepositoryError> {
+    let mut transaction = pool.begin().await.map_err(database_error)?;
+    insert_metric_designations(&mut transaction, designations).await?;
+    transaction.commit().await.map_err(database_error)?;
+    Ok(())
+}
+
+async fn insert_metric_designations(
+    transaction: &mut Transaction<'_, Sqlite>,
+    designations: &[MetricDesignation],
+) -> Result<(), RepositoryError> {
+    for designation in designations {
+        sqlx::query(
+            "INSERT INTO metric_designations (
+    edi_std_nomenclature,
+    aisc_manual_label,
+    metric_edi_std_nomenclature,
+    metric_aisc_manual_label
+) VALUES (?, ?, ?, ?);",
+        )
+        .bind(&designation.edi_std_nomenclature)
+        .bind(&designation.aisc_manual_label)
+        .bind(&designation.metric_edi_std_nomenclature)
+        .bind(&designation.metric_aisc_manual_label)
+        .execute(&mut **transaction)
+        .await
+        .map_err(database_error)?;
+    }
+    Ok(())
+}
+
+// Helper Functions
+fn shape_from_row<T>(family: ShapeFamily, row: SqliteRow) -> Result<T, RepositoryError>
+where
+    T: TryFrom<ShapeBuilder, Error = MissingPropertyError>,
+{
+    let mut builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(row.try_get_column("edi_std_nomenclature")?)
+        .with_aisc_manual_label(row.try_get_column("aisc_manual_label")?);
+    if has_special_fabrication_flag(family)
+        && let Some(t_f) = row.try_get_column::<Option<bool>>("t_f")?
+    {
+        builder = builder.with_t_f(t_f);
+    }
+    for property in family.properties() {
+        if let Some(value) = row.try_get_column::<Option<f64>>(property.column_name())? {
+            builder = builder.with_property(*property, value);
+        }
+    }
+    Ok(builder.try_build::<T>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn pool() -> Arc<SqlitePool> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        create_sqlite_schema(&pool).await.unwrap();
+        Arc::new(pool)
+    }
+
+    #[tokio::test]
+    async fn generated_insert_scripts_are_sqlite_compatible() {
+        let pool = pool().await;
+        let directory = concat!(env!("CARGO_MANIFEST_DIR"), "/../sql_generation");
+        for family in ShapeFamily::ALL {
+            let path = format!("{directory}/{}.sql", table_name(family));
+            let sql = std::fs::read_to_string(path).unwrap();
+            sqlx::raw_sql(&sql).execute(&*pool).await.unwrap();
+        }
+        let sql = std::fs::read_to_string(format!("{directory}/metric_designations.sql")).unwrap();
+        sqlx::raw_sql(&sql).execute(&*pool).await.unwrap();
+        let repository = SqliteShapeRepository::<WideFlange>::new(Arc::clone(&pool));
+        let shape = repository
+            .shape_with_aisc_manual_label(String::from("W14X90"))
+            .await
+            .unwrap();
+        assert_eq!(14.0, shape.d_lower);
+        let shape = repository
+            .shape_with_aisc_manual_label(String::from("W360X134"))
+            .await
+            .unwrap();
+        assert_eq!("W14X90", shape.aisc_manual_label);
+        let repository = SqliteShapeRepository::<MiscBeam>::new(pool);
+        assert!(!repository.all().await.unwrap().is_empty());
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[tokio::test]
+    async fn inserted_shapes_round_trip() {
+        let pool = pool().await;
+        let shapes = shapes::aisc_shapes::aisc_database::snapshot();
+        insert_sqlite_shapes(&pool, shapes).await.unwrap();
+
+        let repository = SqliteShapeRepository::<Angle>::new(Arc::clone(&pool));
+        let angles: Vec<Angle> = aisc_database::shapes_of(shapes.iter().cloned());
+        assert_eq!(angles, repository.all().await.unwrap());
+        let shapes_with_depth = repository.shapes_with_depth(8.0).await.unwrap();
+        assert!(shapes_with_depth.iter().all(|s| s.b_lower == 8.0));
+
+        let repository = SqliteShapeRepository::<Pipe>::new(Arc::clone(&pool));
+        assert_eq!(3, repository.shapes_with_diameter(4.5).await.unwrap().len());
+        let err = repository
+            .shape_with_edi_std_nomenclature(String::from("Pipe5STD"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RepositoryError::NotFound { key } if key == "Pipe5STD"));
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[tokio::test]
+    async fn shapes_are_found_by_metric_designation() {
+        let pool = pool().await;
+        let shapes = shapes::aisc_shapes::aisc_database::snapshot();
+        insert_sqlite_shapes(&pool, shapes).await.unwrap();
+        let repository = SqliteShapeRepository::<WideFlange>::new(Arc::clone(&pool));
+        let shape = repository
+            .shape_with_aisc_manual_label(String::from("W360X134"))
+            .await
+            .unwrap();
+        assert_eq!("W14X90", shape.aisc_manual_label);
+        let shape = repository
+            .shape_with_edi_std_nomenclature(String::from("W360X134"))
+            .await
+            .unwrap();
+        assert_eq!("W14X90", shape.edi_std_nomenclature);
+
+        let designation = MetricDesignation {
+            edi_std_nomenclature: String::from("L4X4X1/2"),
+            aisc_manual_label: String::from("L4X4X1/2"),
+            metric_edi_std_nomenclature: String::from("L102X102X13"),
+            metric_aisc_manual_label: String::from("L102X102X13"),
+        };
+        insert_sqlite_metric_designations(&pool, &[designation])
+            .await
+            .unwrap();
+        let repository = SqliteShapeRepository::<Angle>::new(pool);
+        let shape = repository
+            .shape_with_aisc_manual_label(String::from("L102X102X13"))
+            .await
+            .unwrap();
+        assert_eq!("L4X4X1/2", shape.aisc_manual_label);
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[tokio::test]
+    async fn queries_match_the_in_memory_repository() {
+        let pool = pool().await;
+        let shapes = shapes::aisc_shapes::aisc_database::snapshot();
+        insert_sqlite_shapes(&pool, shapes).await.unwrap();
+        let sqlite = SqliteShapeRepository::<WideFlange>::new(pool);
+        let in_memory = crate::repositories::InMemoryShapeRepository::<WideFlange>::from_snapshot();
+        let query = ShapeQuery::new()
+            .with_min(ShapeProperty::Zx, 120.0)
+            .with_max(ShapeProperty::DLower, 16.0)
+            .with_label_prefix("w1")
+            .with_order(ShapeProperty::WUpper, SortDirection::Descending)
+            .with_limit(10);
+        assert_eq!(
+            in_memory.shapes_matching(query.clone()).await.unwrap(),
+            sqlite.shapes_matching(query).await.unwrap()
+        );
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[tokio::test]
+    async fn labels_are_ordered_like_the_in_memory_repository() {
+        let pool = pool().await;
+        let shapes = shapes::aisc_shapes::aisc_database::snapshot();
+        insert_sqlite_shapes(&pool, shapes).await.unwrap();
+        let sqlite = SqliteShapeRepository::<Pipe>::new(pool);
+        let in_memory = crate::repositories::InMemoryShapeRepository::<Pipe>::from_snapshot();
+        for direction in [SortDirection::Ascending, SortDirection::Descending] {
+            let query = ShapeQuery::new().with_label_order(direction);
+            assert_eq!(
+                in_memory.shapes_matching(query.clone()).await.unwrap(),
+                sqlite.shapes_matching(query).await.unwrap()
+            );
+        }
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[tokio::test]
+    async fn offsets_apply_without_a_limit() {
+        let pool = pool().await;
+        let shapes = shapes::aisc_shapes::aisc_database::snapshot();
+        insert_sqlite_shapes(&pool, shapes).await.unwrap();
+        let sqlite = SqliteShapeRepository::<Pipe>::new(pool);
+        let in_memory = crate::repositories::InMemoryShapeRepository::<Pipe>::from_snapshot();
+        let query = ShapeQuery::new()
+            .with_label_order(SortDirection::Ascending)
+            .with_offset(5);
+        let pipes = sqlite.shapes_matching(query.clone()).await.unwrap();
+        assert_eq!(in_memory.shapes_matching(query).await.unwrap(), pipes);
+        assert_eq!(in_memory.all().await.unwrap().len() - 5, pipes.len());
+    }
+}
@@ -1,7 +1,11 @@
-use shapes::aisc_shapes::{ShapeBuilder, ShapeRepository, StructuralTee};
-use sqlx::Row;
+use crate::repositories::sql_error::{TryGetColumn, database_error};
+use crate::repositories::sql_query::select_matching;
+use shapes::aisc_shapes::{
+    Designation, RepositoryError, ShapeBuilder, ShapeFamily, ShapeQuery, ShapeRepository,
+    StructuralTee,
+};
+use sqlx::Postgres;
 use sqlx::postgres::{PgPool, PgRow};
-use std::error::Error;
 use std::sync::Arc;
 
 /// Repository that manages data access for all structural tee shapes
@@ -18,7 +22,7 @@ impl StructuralTeeRepository {
 }
 
 impl ShapeRepository<StructuralTee> for StructuralTeeRepository {
-    async fn all(&self) -> Result<Vec<StructuralTee>, Box<dyn Error>> {
+    async fn all(&self) -> Result<Vec<StructuralTee>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -56,17 +60,16 @@ impl ShapeRepository<StructuralTee> for StructuralTeeRepository {
 	FROM structural_tees;",
         )
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let wf_results = rows
             .into_iter()
-            .map(|r| structural_tee_from_row(r))
+            .map(structural_tee_from_row)
             .collect::<Vec<_>>();
         if wf_results.iter().any(|r| r.is_err()) {
             for result in wf_results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -80,7 +83,7 @@ impl ShapeRepository<StructuralTee> for StructuralTeeRepository {
     async fn shape_with_edi_std_nomenclature(
         &self,
         edi_std_nomenclature: String,
-    ) -> Result<StructuralTee, Box<dyn Error>> {
+    ) -> Result<StructuralTee, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -116,12 +119,21 @@ impl ShapeRepository<StructuralTee> for StructuralTeeRepository {
     h_upper,
     wgi
 	FROM structural_tees
-	WHERE edi_std_nomenclature = $1
+	WHERE edi_std_nomenclature IN (
+		$1,
+		$2,
+		(SELECT edi_std_nomenclature FROM metric_designations WHERE metric_edi_std_nomenclature = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(edi_std_nomenclature)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&edi_std_nomenclature)
+        .bind(Designation::normalize_edi_std_nomenclature(&edi_std_nomenclature))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: edi_std_nomenclature,
+        })?;
 
         structural_tee_from_row(row)
     }
@@ -129,7 +141,7 @@ impl ShapeRepository<StructuralTee> for StructuralTeeRepository {
     async fn shape_with_aisc_manual_label(
         &self,
         aisc_manual_label: String,
-    ) -> Result<StructuralTee, Box<dyn Error>> {
+    ) -> Result<StructuralTee, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -165,17 +177,26 @@ impl ShapeRepository<StructuralTee> for StructuralTeeRepository {
     h_upper,
     wgi
 	FROM structural_tees
-	WHERE aisc_manual_label = $1
+	WHERE aisc_manual_label IN (
+		$1,
+		$2,
+		(SELECT aisc_manual_label FROM metric_designations WHERE metric_aisc_manual_label = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(aisc_manual_label)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&aisc_manual_label)
+        .bind(Designation::normalize_aisc_manual_label(&aisc_manual_label))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: aisc_manual_label,
+        })?;
 
         structural_tee_from_row(row)
     }
 
-    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<StructuralTee>, Box<dyn Error>> {
+    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<StructuralTee>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -215,17 +236,16 @@ impl ShapeRepository<StructuralTee> for StructuralTeeRepository {
         )
         .bind(depth)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let wf_results = rows
             .into_iter()
-            .map(|r| structural_tee_from_row(r))
+            .map(structural_tee_from_row)
             .collect::<Vec<_>>();
         if wf_results.iter().any(|r| r.is_err()) {
             for result in wf_results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -236,7 +256,7 @@ impl ShapeRepository<StructuralTee> for StructuralTeeRepository {
         }
     }
 
-    async fn shapes_with_width(&self, width: f64) -> Result<Vec<StructuralTee>, Box<dyn Error>> {
+    async fn shapes_with_width(&self, width: f64) -> Result<Vec<StructuralTee>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -276,17 +296,16 @@ impl ShapeRepository<StructuralTee> for StructuralTeeRepository {
         )
         .bind(width)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let wf_results = rows
             .into_iter()
-            .map(|r| structural_tee_from_row(r))
+            .map(structural_tee_from_row)
             .collect::<Vec<_>>();
         if wf_results.iter().any(|r| r.is_err()) {
             for result in wf_results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -296,43 +315,60 @@ impl ShapeRepository<StructuralTee> for StructuralTeeRepository {
                 .collect::<Vec<_>>())
         }
     }
+
+    async fn shapes_matching(
+        &self,
+        query: ShapeQuery,
+    ) -> Result<Vec<StructuralTee>, RepositoryError> {
+        let Some(mut builder) = select_matching::<Postgres>(ShapeFamily::StructuralTee, &query)
+        else {
+            return Ok(Vec::new());
+        };
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.into_iter().map(structural_tee_from_row).collect()
+    }
 }
 
 // Helper Functions
-fn structural_tee_from_row(row: PgRow) -> Result<StructuralTee, Box<dyn Error>> {
-    let maybe_wgi: Option<f64> = row.try_get("wgi")?;
+fn structural_tee_from_row(row: PgRow) -> Result<StructuralTee, RepositoryError> {
+    let maybe_wgi: Option<f64> = row.try_get_column("wgi")?;
     let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(row.try_get("edi_std_nomenclature")?)
-        .with_aisc_manual_label(row.try_get("aisc_manual_label")?)
-        .with_w_upper(row.try_get("w_upper")?)
-        .with_a_upper(row.try_get("a_upper")?)
-        .with_d_lower(row.try_get("d_lower")?)
-        .with_ddet(row.try_get("ddet")?)
-        .with_bf(row.try_get("bf")?)
-        .with_bfdet(row.try_get("bfdet")?)
-        .with_tw(row.try_get("tw")?)
-        .with_twdet(row.try_get("twdet")?)
-        .with_twdet_2(row.try_get("twdet_2")?)
-        .with_tf(row.try_get("tf")?)
-        .with_tfdet(row.try_get("tfdet")?)
-        .with_kdes(row.try_get("kdes")?)
-        .with_kdet(row.try_get("kdet")?)
-        .with_y_lower(row.try_get("y_lower")?)
-        .with_yp(row.try_get("yp")?)
-        .with_bf_2tf(row.try_get("bf_2tf")?)
-        .with_d_t(row.try_get("d_t")?)
-        .with_ix(row.try_get("ix")?)
-        .with_zx(row.try_get("zx")?)
-        .with_sx(row.try_get("sx")?)
-        .with_rx(row.try_get("rx")?)
-        .with_iy(row.try_get("iy")?)
-        .with_zy(row.try_get("zy")?)
-        .with_sy(row.try_get("sy")?)
-        .with_ry(row.try_get("ry")?)
-        .with_j_upper(row.try_get("j_upper")?)
-        .with_cw(row.try_get("cw")?)
-        .with_ro(row.try_get("ro")?)
-        .with_h_upper(row.try_get("h_upper")?);
+        .with_edi_std_nomenclature(row.try_get_column("edi_std_nomenclature")?)
+        .with_aisc_manual_label(row.try_get_column("aisc_manual_label")?)
+        .with_w_upper(row.try_get_column("w_upper")?)
+        .with_a_upper(row.try_get_column("a_upper")?)
+        .with_d_lower(row.try_get_column("d_lower")?)
+        .with_ddet(row.try_get_column("ddet")?)
+        .with_bf(row.try_get_column("bf")?)
+        .with_bfdet(row.try_get_column("bfdet")?)
+        .with_tw(row.try_get_column("tw")?)
+        .with_twdet(row.try_get_column("twdet")?)
+        .with_twdet_2(row.try_get_column("twdet_2")?)
+        .with_tf(row.try_get_column("tf")?)
+        .with_tfdet(row.try_get_column("tfdet")?)
+        .with_kdes(row.try_get_column("kdes")?)
+        .with_kdet(row.try_get_column("kdet")?)
+        .with_y_lower(row.try_get_column("y_lower")?)
+        .with_yp(row.try_get_column("yp")?)
+        .with_bf_2tf(row.try_get_column("bf_2tf")?)
+        .with_d_t(row.try_get_column("d_t")?)
+        .with_ix(row.try_get_column("ix")?)
+        .with_zx(row.try_get_column("zx")?)
+        .with_sx(row.try_get_column("sx")?)
+        .with_rx(row.try_get_column("rx")?)
+        .with_iy(row.try_get_column("iy")?)
+        .with_zy(row.try_get_column("zy")?)
+        .with_sy(row.try_get_column("sy")?)
+        .with_ry(row.try_get_column("ry")?)
+        .with_j_upper(row.try_get_column("j_upper")?)
+        .with_cw(row.try_get_column("cw")?)
+        .with_ro(row.try_get_column("ro")?)
+        .with_h_upper(row.try_get_column("h_upper")?);
 
     let builder = add_optional_wgi(builder, maybe_wgi);
     Ok(builder.try_build::<StructuralTee>()?)
@@ -1,7 +1,11 @@
-use shapes::aisc_shapes::{ShapeBuilder, ShapeRepository, WideFlangeTee};
-use sqlx::Row;
+use crate::repositories::sql_error::{TryGetColumn, database_error};
+use crate::repositories::sql_query::select_matching;
+use shapes::aisc_shapes::{
+    Designation, RepositoryError, ShapeBuilder, ShapeFamily, ShapeQuery, ShapeRepository,
+    WideFlangeTee,
+};
+use sqlx::Postgres;
 use sqlx::postgres::{PgPool, PgRow};
-use std::error::Error;
 use std::sync::Arc;
 
 /// Repository that manages data access for all wide flange tee shapes
@@ -18,7 +22,7 @@ impl WideFlangeTeeRepository {
 }
 
 impl ShapeRepository<WideFlangeTee> for WideFlangeTeeRepository {
-    async fn all(&self) -> Result<Vec<WideFlangeTee>, Box<dyn Error>> {
+    async fn all(&self) -> Result<Vec<WideFlangeTee>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -62,17 +66,16 @@ impl ShapeRepository<WideFlangeTee> for WideFlangeTeeRepository {
 	FROM wide_flange_tees;",
         )
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let wf_results = rows
             .into_iter()
-            .map(|r| wide_flange_tee_from_row(r))
+            .map(wide_flange_tee_from_row)
             .collect::<Vec<_>>();
         if wf_results.iter().any(|r| r.is_err()) {
             for result in wf_results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -86,7 +89,7 @@ impl ShapeRepository<WideFlangeTee> for WideFlangeTeeRepository {
     async fn shape_with_edi_std_nomenclature(
         &self,
         edi_std_nomenclature: String,
-    ) -> Result<WideFlangeTee, Box<dyn Error>> {
+    ) -> Result<WideFlangeTee, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -128,12 +131,21 @@ impl ShapeRepository<WideFlangeTee> for WideFlangeTeeRepository {
     wgi,
     wgo
 	FROM wide_flange_tees
-	WHERE edi_std_nomenclature = $1
+	WHERE edi_std_nomenclature IN (
+		$1,
+		$2,
+		(SELECT edi_std_nomenclature FROM metric_designations WHERE metric_edi_std_nomenclature = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(edi_std_nomenclature)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&edi_std_nomenclature)
+        .bind(Designation::normalize_edi_std_nomenclature(&edi_std_nomenclature))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: edi_std_nomenclature,
+        })?;
 
         wide_flange_tee_from_row(row)
     }
@@ -141,7 +153,7 @@ impl ShapeRepository<WideFlangeTee> for WideFlangeTeeRepository {
     async fn shape_with_aisc_manual_label(
         &self,
         aisc_manual_label: String,
-    ) -> Result<WideFlangeTee, Box<dyn Error>> {
+    ) -> Result<WideFlangeTee, RepositoryError> {
         let row = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -183,17 +195,26 @@ impl ShapeRepository<WideFlangeTee> for WideFlangeTeeRepository {
     wgi,
     wgo
 	FROM wide_flange_tees
-	WHERE aisc_manual_label = $1
+	WHERE aisc_manual_label IN (
+		$1,
+		$2,
+		(SELECT aisc_manual_label FROM metric_designations WHERE metric_aisc_manual_label = $1 LIMIT 1)
+	)
 	LIMIT 1;",
         )
-        .bind(aisc_manual_label)
-        .fetch_one(&*self.pool)
-        .await?;
+        .bind(&aisc_manual_label)
+        .bind(Designation::normalize_aisc_manual_label(&aisc_manual_label))
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(database_error)?
+        .ok_or(RepositoryError::NotFound {
+            key: aisc_manual_label,
+        })?;
 
         wide_flange_tee_from_row(row)
     }
 
-    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<WideFlangeTee>, Box<dyn Error>> {
+    async fn shapes_with_depth(&self, depth: f64) -> Result<Vec<WideFlangeTee>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -239,17 +260,16 @@ impl ShapeRepository<WideFlangeTee> for WideFlangeTeeRepository {
         )
         .bind(depth)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let wf_results = rows
             .into_iter()
-            .map(|r| wide_flange_tee_from_row(r))
+            .map(wide_flange_tee_from_row)
             .collect::<Vec<_>>();
         if wf_results.iter().any(|r| r.is_err()) {
             for result in wf_results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -260,7 +280,7 @@ impl ShapeRepository<WideFlangeTee> for WideFlangeTeeRepository {
         }
     }
 
-    async fn shapes_with_width(&self, width: f64) -> Result<Vec<WideFlangeTee>, Box<dyn Error>> {
+    async fn shapes_with_width(&self, width: f64) -> Result<Vec<WideFlangeTee>, RepositoryError> {
         let rows = sqlx::query(
             "SELECT 
     edi_std_nomenclature,
@@ -306,17 +326,16 @@ impl ShapeRepository<WideFlangeTee> for WideFlangeTeeRepository {
         )
         .bind(width)
         .fetch_all(&*self.pool)
-        .await?;
+        .await
+        .map_err(database_error)?;
 
         let wf_results = rows
             .into_iter()
-            .map(|r| wide_flange_tee_from_row(r))
+            .map(wide_flange_tee_from_row)
             .collect::<Vec<_>>();
         if wf_results.iter().any(|r| r.is_err()) {
             for result in wf_results.into_iter() {
-                if let Err(err) = result {
-                    return Err(err);
-                }
+                result?;
             }
             unreachable!()
         } else {
@@ -326,55 +345,72 @@ impl ShapeRepository<WideFlangeTee> for WideFlangeTeeRepository {
                 .collect::<Vec<_>>())
         }
     }
+
+    async fn shapes_matching(
+        &self,
+        query: ShapeQuery,
+    ) -> Result<Vec<WideFlangeTee>, RepositoryError> {
+        let Some(mut builder) = select_matching::<Postgres>(ShapeFamily::WideFlangeTee, &query)
+        else {
+            return Ok(Vec::new());
+        };
+        let rows = builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.into_iter().map(wide_flange_tee_from_row).collect()
+    }
 }
 
 // Helper Functions
-fn wide_flange_tee_from_row(row: PgRow) -> Result<WideFlangeTee, Box<dyn Error>> {
-    let maybe_wgo: Option<f64> = row.try_get("wgo")?;
+fn wide_flange_tee_from_row(row: PgRow) -> Result<WideFlangeTee, RepositoryError> {
+    let maybe_wgo: Option<f64> = row.try_get_column("wgo")?;
     let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(row.try_get("edi_std_nomenclature")?)
-        .with_aisc_manual_label(row.try_get("aisc_manual_label")?)
-        .with_t_f(row.try_get("t_f")?)
-        .with_w_upper(row.try_get("w_upper")?)
-        .with_a_upper(row.try_get("a_upper")?)
-        .with_d_lower(row.try_get("d_lower")?)
-        .with_ddet(row.try_get("ddet")?)
-        .with_bf(row.try_get("bf")?)
-        .with_bfdet(row.try_get("bfdet")?)
-        .with_tw(row.try_get("tw")?)
-        .with_twdet(row.try_get("twdet")?)
-        .with_twdet_2(row.try_get("twdet_2")?)
-        .with_tf(row.try_get("tf")?)
-        .with_tfdet(row.try_get("tfdet")?)
-        .with_kdes(row.try_get("kdes")?)
-        .with_kdet(row.try_get("kdet")?)
-        .with_y_lower(row.try_get("y_lower")?)
-        .with_yp(row.try_get("yp")?)
-        .with_bf_2tf(row.try_get("bf_2tf")?)
-        .with_d_t(row.try_get("d_t")?)
-        .with_ix(row.try_get("ix")?)
-        .with_zx(row.try_get("zx")?)
-        .with_sx(row.try_get("sx")?)
-        .with_rx(row.try_get("rx")?)
-        .with_iy(row.try_get("iy")?)
-        .with_zy(row.try_get("zy")?)
-        .with_sy(row.try_get("sy")?)
-        .with_ry(row.try_get("ry")?)
-        .with_j_upper(row.try_get("j_upper")?)
-        .with_cw(row.try_get("cw")?)
-        .with_ro(row.try_get("ro")?)
-        .with_h_upper(row.try_get("h_upper")?)
-        .with_pa(row.try_get("pa")?)
-        .with_pb(row.try_get("pb")?)
-        .with_pc(row.try_get("pc")?)
-        .with_pd(row.try_get("pd")?)
-        .with_wgi(row.try_get("wgi")?);
+        .with_edi_std_nomenclature(row.try_get_column("edi_std_nomenclature")?)
+        .with_aisc_manual_label(row.try_get_column("aisc_manual_label")?)
+        .with_t_f(row.try_get_column("t_f")?)
+        .with_w_upper(row.try_get_column("w_upper")?)
+        .with_a_upper(row.try_get_column("a_upper")?)
+        .with_d_lower(row.try_get_column("d_lower")?)
+        .with_ddet(row.try_get_column("ddet")?)
+        .with_bf(row.try_get_column("bf")?)
+        .with_bfdet(row.try_get_column("bfdet")?)
+        .with_tw(row.try_get_column("tw")?)
+        .with_twdet(row.try_get_column("twdet")?)
+        .with_twdet_2(row.try_get_column("twdet_2")?)
+        .with_tf(row.try_get_column("tf")?)
+        .with_tfdet(row.try_get_column("tfdet")?)
+        .with_kdes(row.try_get_column("kdes")?)
+        .with_kdet(row.try_get_column("kdet")?)
+        .with_y_lower(row.try_get_column("y_lower")?)
+        .with_yp(row.try_get_column("yp")?)
+        .with_bf_2tf(row.try_get_column("bf_2tf")?)
+        .with_d_t(row.try_get_column("d_t")?)
+        .with_ix(row.try_get_column("ix")?)
+        .with_zx(row.try_get_column("zx")?)
+        .with_sx(row.try_get_column("sx")?)
+        .with_rx(row.try_get_column("rx")?)
+        .with_iy(row.try_get_column("iy")?)
+        .with_zy(row.try_get_column("zy")?)
+        .with_sy(row.try_get_column("sy")?)
+        .with_ry(row.try_get_column("ry")?)
+        .with_j_upper(row.try_get_column("j_upper")?)
+        .with_cw(row.try_get_column("cw")?)
+        .with_ro(row.try_get_column("ro")?)
+        .with_h_upper(row.try_get_column("h_upper")?)
+        .with_pa(row.try_get_column("pa")?)
+        .with_pb(row.try_get_column("pb")?)
+        .with_pc(row.try_get_column("pc")?)
+        .with_pd(row.try_get_column("pd")?)
+        .with_wgi(row.try_get_column("wgi")?);
 
     let builder = add_optional_wgo(builder, maybe_wgo);
     let maybe_wft = builder.try_build::<WideFlangeTee>();
     match maybe_wft {
         Ok(wf) => Ok(wf),
-        Err(err) => Err(Box::new(err)),
+        Err(err) => Err(err.into()),
     }
 }
 
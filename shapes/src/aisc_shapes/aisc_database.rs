@@ -0,0 +1,1351 @@
+use crate::aisc_shapes::errors::{
+    InvalidValueError, MetricSheetMismatchError, MissingPropertyError,
+};
+use crate::aisc_shapes::*;
+use csv::StringRecord;
+use std::error::Error;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// Reads every shape from a CSV export of the AISC shapes database,
+/// with the same columns as `aisc-shapes-database-v16.0.csv`.
+/// Rows are returned in file order, rows of types that are not modeled are skipped.
+pub fn read_shapes<R: Read>(reader: R) -> Result<Vec<AiscShape>, Box<dyn Error>> {
+    let mut shapes = Vec::new();
+    for record in csv::Reader::from_reader(reader).records() {
+        let record = record?;
+        let shape: AiscShape = match family_of_record(&record) {
+            Some(ShapeFamily::WideFlange) => parse_wide_flange(&record)?.into(),
+            Some(ShapeFamily::MiscBeam) => parse_misc_beam(&record)?.into(),
+            Some(ShapeFamily::StructuralBeam) => parse_structural_beam(&record)?.into(),
+            Some(ShapeFamily::HPile) => parse_h_pile(&record)?.into(),
+            Some(ShapeFamily::CeeChannel) => parse_cee_channel(&record)?.into(),
+            Some(ShapeFamily::MiscChannel) => parse_misc_channel(&record)?.into(),
+            Some(ShapeFamily::Angle) => parse_angle(&record)?.into(),
+            Some(ShapeFamily::WideFlangeTee) => parse_wide_flange_tee(&record)?.into(),
+            Some(ShapeFamily::MiscTee) => parse_misc_tee(&record)?.into(),
+            Some(ShapeFamily::StructuralTee) => parse_structural_tee(&record)?.into(),
+            Some(ShapeFamily::DoubleAngle) => parse_double_angle(&record)?.into(),
+            Some(ShapeFamily::HollowStructuralSection) => {
+                parse_hollow_structural_section(&record)?.into()
+            }
+            Some(ShapeFamily::RoundHollowStructuralSection) => {
+                parse_round_hollow_structural_section(&record)?.into()
+            }
+            Some(ShapeFamily::Pipe) => parse_pipe(&record)?.into(),
+            None => continue,
+        };
+        shapes.push(shape);
+    }
+    Ok(shapes)
+}
+
+/// Reads every shape from the CSV file at the path, see [read_shapes]
+pub fn read_shapes_from_path<P: AsRef<Path>>(path: P) -> Result<Vec<AiscShape>, Box<dyn Error>> {
+    read_shapes(File::open(path)?)
+}
+
+/// Keeps the shapes of type `T`, discarding shapes of every other family
+///
+/// ```
+/// use shapes::aisc_shapes::{AiscShape, Pipe, WideFlange};
+/// use shapes::aisc_shapes::aisc_database::shapes_of;
+///
+/// let shapes: Vec<AiscShape> = Vec::new();
+/// let pipes: Vec<Pipe> = shapes_of(shapes);
+/// assert!(pipes.is_empty());
+/// ```
+pub fn shapes_of<T: TryFrom<AiscShape>>(shapes: impl IntoIterator<Item = AiscShape>) -> Vec<T> {
+    shapes
+        .into_iter()
+        .filter_map(|s| T::try_from(s).ok())
+        .collect::<Vec<_>>()
+}
+
+/// Reads the metric designation of every shape from a CSV export of the metric sheet
+/// of the AISC shapes database, with the same columns as `aisc-shapes-database-v16.0.csv`.
+/// The metric sheet lists the same shapes in the same order as the US customary sheet
+/// the `shapes` were read from, each shape is paired with the metric row in the same position.
+pub fn read_metric_designations<R: Read>(
+    shapes: &[AiscShape],
+    metric: R,
+) -> Result<Vec<MetricDesignation>, Box<dyn Error>> {
+    let metric_shapes = read_shapes(metric)?;
+    if metric_shapes.len() != shapes.len() {
+        let row = metric_shapes.len().min(shapes.len());
+        return Err(Box::new(MetricSheetMismatchError { row }));
+    }
+    shapes
+        .iter()
+        .zip(metric_shapes)
+        .enumerate()
+        .map(|(row, (shape, metric_shape))| {
+            if shape.family() != metric_shape.family() {
+                return Err(Box::new(MetricSheetMismatchError { row }) as Box<dyn Error>);
+            }
+            Ok(MetricDesignation {
+                edi_std_nomenclature: String::from(shape.edi_std_nomenclature()),
+                aisc_manual_label: String::from(shape.aisc_manual_label()),
+                metric_edi_std_nomenclature: String::from(metric_shape.edi_std_nomenclature()),
+                metric_aisc_manual_label: String::from(metric_shape.aisc_manual_label()),
+            })
+        })
+        .collect()
+}
+
+/// Reads the metric designations from the CSV file at the path, see [read_metric_designations]
+pub fn read_metric_designations_from_path<P: AsRef<Path>>(
+    shapes: &[AiscShape],
+    path: P,
+) -> Result<Vec<MetricDesignation>, Box<dyn Error>> {
+    read_metric_designations(shapes, File::open(path)?)
+}
+
+/// The metric designations of every shape of the [snapshot],
+/// see [MetricDesignation::derive_all]
+#[cfg(feature = "snapshot")]
+pub fn snapshot_metric_designations() -> &'static [MetricDesignation] {
+    static DESIGNATIONS: std::sync::OnceLock<Vec<MetricDesignation>> = std::sync::OnceLock::new();
+    DESIGNATIONS.get_or_init(|| MetricDesignation::derive_all(snapshot()))
+}
+
+/// Every shape of the AISC shapes database v16.0, parsed from
+/// a snapshot of the database compiled into the library
+#[cfg(feature = "snapshot")]
+pub fn snapshot() -> &'static [AiscShape] {
+    static SNAPSHOT: std::sync::OnceLock<Vec<AiscShape>> = std::sync::OnceLock::new();
+    SNAPSHOT.get_or_init(|| {
+        read_shapes(SNAPSHOT_CSV.as_bytes()).expect("The compiled-in snapshot is valid")
+    })
+}
+
+#[cfg(feature = "snapshot")]
+static SNAPSHOT_CSV: &str = include_str!("../../aisc-shapes-database-v16.0.csv");
+
+// Helper Functions
+fn family_of_record(record: &StringRecord) -> Option<ShapeFamily> {
+    match &record[TYPE_INDEX.index] {
+        "W" => Some(ShapeFamily::WideFlange),
+        "M" => Some(ShapeFamily::MiscBeam),
+        "S" => Some(ShapeFamily::StructuralBeam),
+        "HP" => Some(ShapeFamily::HPile),
+        "C" => Some(ShapeFamily::CeeChannel),
+        "MC" => Some(ShapeFamily::MiscChannel),
+        "L" => Some(ShapeFamily::Angle),
+        "WT" => Some(ShapeFamily::WideFlangeTee),
+        "MT" => Some(ShapeFamily::MiscTee),
+        "ST" => Some(ShapeFamily::StructuralTee),
+        "2L" => Some(ShapeFamily::DoubleAngle),
+        "HSS" => match record[EDI_NOM.index].matches('X').count() {
+            1 => Some(ShapeFamily::RoundHollowStructuralSection),
+            2 => Some(ShapeFamily::HollowStructuralSection),
+            _ => None,
+        },
+        "PIPE" => Some(ShapeFamily::Pipe),
+        _ => None,
+    }
+}
+
+fn float(record: &StringRecord, column: Column) -> Result<f64, Box<dyn Error>> {
+    optional_float(record, column)?
+        .ok_or_else(|| Box::new(MissingPropertyError::from(column.name)) as Box<dyn Error>)
+}
+
+fn boolean(record: &StringRecord, column: Column) -> Result<bool, Box<dyn Error>> {
+    optional_bool(record, column)?
+        .ok_or_else(|| Box::new(MissingPropertyError::from(column.name)) as Box<dyn Error>)
+}
+
+fn optional_bool(record: &StringRecord, column: Column) -> Result<Option<bool>, Box<dyn Error>> {
+    match record[column.index].trim() {
+        "T" => Ok(Some(true)),
+        "F" => Ok(Some(false)),
+        "" | "–" => Ok(None),
+        value => Err(invalid_value(column, value)),
+    }
+}
+
+/// Parses a decimal, a fraction (`3/4`) or a mixed number (`1 1/2`),
+/// a dash marks a property that the shape does not define
+fn optional_float(record: &StringRecord, column: Column) -> Result<Option<f64>, Box<dyn Error>> {
+    let value = record[column.index].trim();
+    if value.is_empty() || value == "–" {
+        return Ok(None);
+    }
+    let parsed = match value.split_whitespace().collect::<Vec<_>>()[..] {
+        [number] if number.contains('/') => parse_fraction(number),
+        [number] => number.parse::<f64>().ok(),
+        [whole, fraction] => whole
+            .parse::<f64>()
+            .ok()
+            .zip(parse_fraction(fraction))
+            .map(|(whole, fraction)| whole + fraction),
+        _ => None,
+    };
+    parsed.map(Some).ok_or_else(|| invalid_value(column, value))
+}
+
+fn parse_fraction(fraction: &str) -> Option<f64> {
+    let (numerator, denominator) = fraction.split_once('/')?;
+    let numerator = numerator.parse::<f64>().ok()?;
+    let denominator = denominator.parse::<f64>().ok()?;
+    (denominator != 0.0).then(|| numerator / denominator)
+}
+
+fn invalid_value(column: Column, value: &str) -> Box<dyn Error> {
+    Box::new(InvalidValueError {
+        column: column.name,
+        value: String::from(value),
+    })
+}
+
+fn parse_wide_flange(record: &StringRecord) -> Result<WideFlange, Box<dyn Error>> {
+    let mut builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_t_f(boolean(record, T_F)?)
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_d_lower(float(record, D_LOWER)?)
+        .with_ddet(float(record, DDET)?)
+        .with_bf(float(record, BF)?)
+        .with_bfdet(float(record, BFDET)?)
+        .with_tw(float(record, TW)?)
+        .with_twdet(float(record, TWDET)?)
+        .with_twdet_2(float(record, TWDET_2)?)
+        .with_tf(float(record, TF)?)
+        .with_tfdet(float(record, TFDET)?)
+        .with_kdes(float(record, K_DES)?)
+        .with_kdet(float(record, K_DET)?)
+        .with_k1(float(record, K1)?)
+        .with_bf_2tf(float(record, BF_2TF)?)
+        .with_h_tw(float(record, H_TW)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_cw(float(record, CW)?)
+        .with_wno(float(record, WNO)?)
+        .with_sw1(float(record, SW1)?)
+        .with_qf(float(record, QF)?)
+        .with_qw(float(record, QW)?)
+        .with_rts(float(record, RTS)?)
+        .with_ho(float(record, HO)?)
+        .with_pa(float(record, PA)?)
+        .with_pb(float(record, PB)?)
+        .with_pc(float(record, PC)?)
+        .with_pd(float(record, PD)?)
+        .with_t(float(record, T)?)
+        .with_wgi(float(record, WGI)?);
+    if let Some(wgo) = optional_float(record, WGO)? {
+        builder = builder.with_wgo(wgo);
+    }
+    Ok(builder.try_build::<WideFlange>()?)
+}
+
+fn parse_misc_beam(record: &StringRecord) -> Result<MiscBeam, Box<dyn Error>> {
+    let mut builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_d_lower(float(record, D_LOWER)?)
+        .with_ddet(float(record, DDET)?)
+        .with_bf(float(record, BF)?)
+        .with_bfdet(float(record, BFDET)?)
+        .with_tw(float(record, TW)?)
+        .with_twdet(float(record, TWDET)?)
+        .with_twdet_2(float(record, TWDET_2)?)
+        .with_tf(float(record, TF)?)
+        .with_tfdet(float(record, TFDET)?)
+        .with_kdes(float(record, K_DES)?)
+        .with_kdet(float(record, K_DET)?)
+        .with_k1(float(record, K1)?)
+        .with_bf_2tf(float(record, BF_2TF)?)
+        .with_h_tw(float(record, H_TW)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_cw(float(record, CW)?)
+        .with_wno(float(record, WNO)?)
+        .with_sw1(float(record, SW1)?)
+        .with_qf(float(record, QF)?)
+        .with_qw(float(record, QW)?)
+        .with_rts(float(record, RTS)?)
+        .with_ho(float(record, HO)?)
+        .with_pa(float(record, PA)?)
+        .with_pb(float(record, PB)?)
+        .with_pc(float(record, PC)?)
+        .with_pd(float(record, PD)?)
+        .with_t(float(record, T)?);
+    if let Some(wgi) = optional_float(record, WGI)? {
+        builder = builder.with_wgi(wgi);
+    }
+    if let Some(t_f) = optional_bool(record, T_F)? {
+        builder = builder.with_t_f(t_f);
+    }
+    Ok(builder.try_build::<MiscBeam>()?)
+}
+
+fn parse_structural_beam(record: &StringRecord) -> Result<StructuralBeam, Box<dyn Error>> {
+    let mut builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_d_lower(float(record, D_LOWER)?)
+        .with_ddet(float(record, DDET)?)
+        .with_bf(float(record, BF)?)
+        .with_bfdet(float(record, BFDET)?)
+        .with_tw(float(record, TW)?)
+        .with_twdet(float(record, TWDET)?)
+        .with_twdet_2(float(record, TWDET_2)?)
+        .with_tf(float(record, TF)?)
+        .with_tfdet(float(record, TFDET)?)
+        .with_kdes(float(record, K_DES)?)
+        .with_kdet(float(record, K_DET)?)
+        .with_bf_2tf(float(record, BF_2TF)?)
+        .with_h_tw(float(record, H_TW)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_cw(float(record, CW)?)
+        .with_wno(float(record, WNO)?)
+        .with_sw1(float(record, SW1)?)
+        .with_qf(float(record, QF)?)
+        .with_qw(float(record, QW)?)
+        .with_rts(float(record, RTS)?)
+        .with_ho(float(record, HO)?)
+        .with_pa(float(record, PA)?)
+        .with_pb(float(record, PB)?)
+        .with_pc(float(record, PC)?)
+        .with_pd(float(record, PD)?)
+        .with_t(float(record, T)?);
+    if let Some(wgi) = optional_float(record, WGI)? {
+        builder = builder.with_wgi(wgi);
+    }
+    Ok(builder.try_build::<StructuralBeam>()?)
+}
+
+fn parse_h_pile(record: &StringRecord) -> Result<HPile, Box<dyn Error>> {
+    let builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_d_lower(float(record, D_LOWER)?)
+        .with_ddet(float(record, DDET)?)
+        .with_bf(float(record, BF)?)
+        .with_bfdet(float(record, BFDET)?)
+        .with_tw(float(record, TW)?)
+        .with_twdet(float(record, TWDET)?)
+        .with_twdet_2(float(record, TWDET_2)?)
+        .with_tf(float(record, TF)?)
+        .with_tfdet(float(record, TFDET)?)
+        .with_kdes(float(record, K_DES)?)
+        .with_kdet(float(record, K_DET)?)
+        .with_k1(float(record, K1)?)
+        .with_bf_2tf(float(record, BF_2TF)?)
+        .with_h_tw(float(record, H_TW)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_cw(float(record, CW)?)
+        .with_wno(float(record, WNO)?)
+        .with_sw1(float(record, SW1)?)
+        .with_qf(float(record, QF)?)
+        .with_qw(float(record, QW)?)
+        .with_rts(float(record, RTS)?)
+        .with_ho(float(record, HO)?)
+        .with_pa(float(record, PA)?)
+        .with_pb(float(record, PB)?)
+        .with_pc(float(record, PC)?)
+        .with_pd(float(record, PD)?)
+        .with_t(float(record, T)?)
+        .with_wgi(float(record, WGI)?);
+    Ok(builder.try_build::<HPile>()?)
+}
+
+fn parse_cee_channel(record: &StringRecord) -> Result<CeeChannel, Box<dyn Error>> {
+    let mut builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_d_lower(float(record, D_LOWER)?)
+        .with_ddet(float(record, DDET)?)
+        .with_bf(float(record, BF)?)
+        .with_bfdet(float(record, BFDET)?)
+        .with_tw(float(record, TW)?)
+        .with_twdet(float(record, TWDET)?)
+        .with_twdet_2(float(record, TWDET_2)?)
+        .with_tf(float(record, TF)?)
+        .with_tfdet(float(record, TFDET)?)
+        .with_kdes(float(record, K_DES)?)
+        .with_kdet(float(record, K_DET)?)
+        .with_x_lower(float(record, X_LOWER)?)
+        .with_eo(float(record, EO)?)
+        .with_xp(float(record, XP)?)
+        .with_b_t(float(record, B_T)?)
+        .with_h_tw(float(record, H_TW)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_cw(float(record, CW)?)
+        .with_wno(float(record, WNO)?)
+        .with_sw1(float(record, SW1)?)
+        .with_sw2(float(record, SW2)?)
+        .with_sw3(float(record, SW3)?)
+        .with_qf(float(record, QF)?)
+        .with_qw(float(record, QW)?)
+        .with_ro(float(record, RO)?)
+        .with_h_upper(float(record, H_UPPER)?)
+        .with_rts(float(record, RTS)?)
+        .with_ho(float(record, HO)?)
+        .with_pa(float(record, PA)?)
+        .with_pb(float(record, PB)?)
+        .with_pc(float(record, PC)?)
+        .with_pd(float(record, PD)?)
+        .with_t(float(record, T)?);
+    if let Some(wgi) = optional_float(record, WGI)? {
+        builder = builder.with_wgi(wgi);
+    }
+    Ok(builder.try_build::<CeeChannel>()?)
+}
+
+fn parse_misc_channel(record: &StringRecord) -> Result<MiscChannel, Box<dyn Error>> {
+    let mut builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_d_lower(float(record, D_LOWER)?)
+        .with_ddet(float(record, DDET)?)
+        .with_bf(float(record, BF)?)
+        .with_bfdet(float(record, BFDET)?)
+        .with_tw(float(record, TW)?)
+        .with_twdet(float(record, TWDET)?)
+        .with_twdet_2(float(record, TWDET_2)?)
+        .with_tf(float(record, TF)?)
+        .with_tfdet(float(record, TFDET)?)
+        .with_kdes(float(record, K_DES)?)
+        .with_kdet(float(record, K_DET)?)
+        .with_x_lower(float(record, X_LOWER)?)
+        .with_eo(float(record, EO)?)
+        .with_xp(float(record, XP)?)
+        .with_b_t(float(record, B_T)?)
+        .with_h_tw(float(record, H_TW)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_cw(float(record, CW)?)
+        .with_wno(float(record, WNO)?)
+        .with_sw1(float(record, SW1)?)
+        .with_sw2(float(record, SW2)?)
+        .with_sw3(float(record, SW3)?)
+        .with_qf(float(record, QF)?)
+        .with_qw(float(record, QW)?)
+        .with_ro(float(record, RO)?)
+        .with_h_upper(float(record, H_UPPER)?)
+        .with_rts(float(record, RTS)?)
+        .with_ho(float(record, HO)?)
+        .with_pa(float(record, PA)?)
+        .with_pb(float(record, PB)?)
+        .with_pc(float(record, PC)?)
+        .with_pd(float(record, PD)?)
+        .with_t(float(record, T)?);
+    if let Some(wgi) = optional_float(record, WGI)? {
+        builder = builder.with_wgi(wgi);
+    }
+    Ok(builder.try_build::<MiscChannel>()?)
+}
+
+fn parse_angle(record: &StringRecord) -> Result<Angle, Box<dyn Error>> {
+    let mut builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_d_lower(float(record, D_LOWER)?)
+        .with_b_lower(float(record, B_LOWER)?)
+        .with_t_lower(float(record, T_LOWER)?)
+        .with_kdes(float(record, K_DES)?)
+        .with_kdet(float(record, K_DET)?)
+        .with_x_lower(float(record, X_LOWER)?)
+        .with_y_lower(float(record, Y_LOWER)?)
+        .with_xp(float(record, XP)?)
+        .with_yp(float(record, YP)?)
+        .with_b_t(float(record, B_T)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_iz(float(record, IZ)?)
+        .with_rz(float(record, RZ)?)
+        .with_sz(float(record, SZ)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_cw(float(record, CW)?)
+        .with_ro(float(record, RO)?)
+        .with_tan_a(float(record, TAN_A)?)
+        .with_iw(float(record, IW)?)
+        .with_za(float(record, ZA)?)
+        .with_zb(float(record, ZB)?)
+        .with_zc(float(record, ZC)?)
+        .with_wa(float(record, WA)?)
+        .with_wb(float(record, WB)?)
+        .with_wc(float(record, WC)?)
+        .with_swa(float(record, SWA)?)
+        .with_swc(float(record, SWC)?)
+        .with_sza(float(record, SZA)?)
+        .with_szb(float(record, SZB)?)
+        .with_szc(float(record, SZC)?)
+        .with_pa(float(record, PA)?)
+        .with_pa_2(float(record, PA2)?)
+        .with_pb(float(record, PB)?);
+    if let Some(h_upper) = optional_float(record, H_UPPER)? {
+        builder = builder.with_h_upper(h_upper);
+    }
+    if let Some(swb) = optional_float(record, SWB)? {
+        builder = builder.with_swb(swb);
+    }
+    Ok(builder.try_build::<Angle>()?)
+}
+
+fn parse_wide_flange_tee(record: &StringRecord) -> Result<WideFlangeTee, Box<dyn Error>> {
+    let mut builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_t_f(boolean(record, T_F)?)
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_d_lower(float(record, D_LOWER)?)
+        .with_ddet(float(record, DDET)?)
+        .with_bf(float(record, BF)?)
+        .with_bfdet(float(record, BFDET)?)
+        .with_tw(float(record, TW)?)
+        .with_twdet(float(record, TWDET)?)
+        .with_twdet_2(float(record, TWDET_2)?)
+        .with_tf(float(record, TF)?)
+        .with_tfdet(float(record, TFDET)?)
+        .with_kdes(float(record, K_DES)?)
+        .with_kdet(float(record, K_DET)?)
+        .with_y_lower(float(record, Y_LOWER)?)
+        .with_yp(float(record, YP)?)
+        .with_bf_2tf(float(record, BF_2TF)?)
+        .with_d_t(float(record, D_T)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_cw(float(record, CW)?)
+        .with_ro(float(record, RO)?)
+        .with_h_upper(float(record, H_UPPER)?)
+        .with_pa(float(record, PA)?)
+        .with_pb(float(record, PB)?)
+        .with_pc(float(record, PC)?)
+        .with_pd(float(record, PD)?)
+        .with_wgi(float(record, WGI)?);
+    if let Some(wgo) = optional_float(record, WGO)? {
+        builder = builder.with_wgo(wgo);
+    }
+    Ok(builder.try_build::<WideFlangeTee>()?)
+}
+
+fn parse_misc_tee(record: &StringRecord) -> Result<MiscTee, Box<dyn Error>> {
+    let mut builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_t_f(boolean(record, T_F)?)
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_d_lower(float(record, D_LOWER)?)
+        .with_ddet(float(record, DDET)?)
+        .with_bf(float(record, BF)?)
+        .with_bfdet(float(record, BFDET)?)
+        .with_tw(float(record, TW)?)
+        .with_twdet(float(record, TWDET)?)
+        .with_twdet_2(float(record, TWDET_2)?)
+        .with_tf(float(record, TF)?)
+        .with_tfdet(float(record, TFDET)?)
+        .with_kdes(float(record, K_DES)?)
+        .with_kdet(float(record, K_DET)?)
+        .with_y_lower(float(record, Y_LOWER)?)
+        .with_yp(float(record, YP)?)
+        .with_bf_2tf(float(record, BF_2TF)?)
+        .with_d_t(float(record, D_T)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_cw(float(record, CW)?)
+        .with_ro(float(record, RO)?)
+        .with_h_upper(float(record, H_UPPER)?);
+    if let Some(wgi) = optional_float(record, WGI)? {
+        builder = builder.with_wgi(wgi);
+    }
+    Ok(builder.try_build::<MiscTee>()?)
+}
+
+fn parse_structural_tee(record: &StringRecord) -> Result<StructuralTee, Box<dyn Error>> {
+    let mut builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_d_lower(float(record, D_LOWER)?)
+        .with_ddet(float(record, DDET)?)
+        .with_bf(float(record, BF)?)
+        .with_bfdet(float(record, BFDET)?)
+        .with_tw(float(record, TW)?)
+        .with_twdet(float(record, TWDET)?)
+        .with_twdet_2(float(record, TWDET_2)?)
+        .with_tf(float(record, TF)?)
+        .with_tfdet(float(record, TFDET)?)
+        .with_kdes(float(record, K_DES)?)
+        .with_kdet(float(record, K_DET)?)
+        .with_y_lower(float(record, Y_LOWER)?)
+        .with_yp(float(record, YP)?)
+        .with_bf_2tf(float(record, BF_2TF)?)
+        .with_d_t(float(record, D_T)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_cw(float(record, CW)?)
+        .with_ro(float(record, RO)?)
+        .with_h_upper(float(record, H_UPPER)?);
+    if let Some(wgi) = optional_float(record, WGI)? {
+        builder = builder.with_wgi(wgi);
+    }
+    Ok(builder.try_build::<StructuralTee>()?)
+}
+
+fn parse_double_angle(record: &StringRecord) -> Result<DoubleAngle, Box<dyn Error>> {
+    let builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_d_lower(float(record, D_LOWER)?)
+        .with_b_lower(float(record, B_LOWER)?)
+        .with_t_lower(float(record, T_LOWER)?)
+        .with_y_lower(float(record, Y_LOWER)?)
+        .with_yp(float(record, YP)?)
+        .with_b_t(float(record, B_T)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_ro(float(record, RO)?)
+        .with_h_upper(float(record, H_UPPER)?);
+    Ok(builder.try_build::<DoubleAngle>()?)
+}
+
+fn parse_hollow_structural_section(
+    record: &StringRecord,
+) -> Result<HollowStructuralSection, Box<dyn Error>> {
+    let builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_ht(float(record, HT)?)
+        .with_h(float(record, H)?)
+        .with_b_upper(float(record, B_UPPER)?)
+        .with_b_lower(float(record, B_LOWER)?)
+        .with_t_nom(float(record, T_NOM)?)
+        .with_tdes(float(record, T_DES)?)
+        .with_b_tdes(float(record, B_TDES)?)
+        .with_h_tdes(float(record, H_TDES)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_c_upper(float(record, C_UPPER)?);
+    Ok(builder.try_build::<HollowStructuralSection>()?)
+}
+
+fn parse_round_hollow_structural_section(
+    record: &StringRecord,
+) -> Result<RoundHollowStructuralSection, Box<dyn Error>> {
+    let builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_od(float(record, OD)?)
+        .with_t_nom(float(record, T_NOM)?)
+        .with_tdes(float(record, T_DES)?)
+        .with_d_t(float(record, D_T)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?)
+        .with_c_upper(float(record, C_UPPER)?);
+    Ok(builder.try_build::<RoundHollowStructuralSection>()?)
+}
+
+fn parse_pipe(record: &StringRecord) -> Result<Pipe, Box<dyn Error>> {
+    let builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(&record[EDI_NOM.index]))
+        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL.index]))
+        .with_w_upper(float(record, W_UPPER)?)
+        .with_a_upper(float(record, A_UPPER)?)
+        .with_od(float(record, OD)?)
+        .with_id(float(record, ID)?)
+        .with_t_nom(float(record, T_NOM)?)
+        .with_tdes(float(record, T_DES)?)
+        .with_d_t(float(record, D_T)?)
+        .with_ix(float(record, IX)?)
+        .with_zx(float(record, ZX)?)
+        .with_sx(float(record, SX)?)
+        .with_rx(float(record, RX)?)
+        .with_iy(float(record, IY)?)
+        .with_zy(float(record, ZY)?)
+        .with_sy(float(record, SY)?)
+        .with_ry(float(record, RY)?)
+        .with_j_upper(float(record, J_UPPER)?);
+    Ok(builder.try_build::<Pipe>()?)
+}
+
+// columns of the CSV file
+#[derive(Clone, Copy)]
+struct Column {
+    index: usize,
+    name: &'static str,
+}
+
+const TYPE_INDEX: Column = Column {
+    index: 0,
+    name: "type",
+};
+const EDI_NOM: Column = Column {
+    index: 1,
+    name: "edi_std_nomenclature",
+};
+const AISC_MAN_LBL: Column = Column {
+    index: 2,
+    name: "aisc_manual_label",
+};
+const T_F: Column = Column {
+    index: 3,
+    name: "t_f",
+};
+const W_UPPER: Column = Column {
+    index: 4,
+    name: "w_upper",
+};
+const A_UPPER: Column = Column {
+    index: 5,
+    name: "a_upper",
+};
+const D_LOWER: Column = Column {
+    index: 6,
+    name: "d_lower",
+};
+const DDET: Column = Column {
+    index: 7,
+    name: "ddet",
+};
+const HT: Column = Column {
+    index: 8,
+    name: "ht",
+};
+const H: Column = Column {
+    index: 9,
+    name: "h",
+};
+const OD: Column = Column {
+    index: 10,
+    name: "od",
+};
+const BF: Column = Column {
+    index: 11,
+    name: "bf",
+};
+const BFDET: Column = Column {
+    index: 12,
+    name: "bfdet",
+};
+const B_UPPER: Column = Column {
+    index: 13,
+    name: "b_upper",
+};
+const B_LOWER: Column = Column {
+    index: 14,
+    name: "b_lower",
+};
+const ID: Column = Column {
+    index: 15,
+    name: "id",
+};
+const TW: Column = Column {
+    index: 16,
+    name: "tw",
+};
+const TWDET: Column = Column {
+    index: 17,
+    name: "twdet",
+};
+const TWDET_2: Column = Column {
+    index: 18,
+    name: "twdet_2",
+};
+const TF: Column = Column {
+    index: 19,
+    name: "tf",
+};
+const TFDET: Column = Column {
+    index: 20,
+    name: "tfdet",
+};
+const T_LOWER: Column = Column {
+    index: 21,
+    name: "t_lower",
+};
+const T_NOM: Column = Column {
+    index: 22,
+    name: "t_nom",
+};
+const T_DES: Column = Column {
+    index: 23,
+    name: "tdes",
+};
+const K_DES: Column = Column {
+    index: 24,
+    name: "kdes",
+};
+const K_DET: Column = Column {
+    index: 25,
+    name: "kdet",
+};
+const K1: Column = Column {
+    index: 26,
+    name: "k1",
+};
+const X_LOWER: Column = Column {
+    index: 27,
+    name: "x_lower",
+};
+const Y_LOWER: Column = Column {
+    index: 28,
+    name: "y_lower",
+};
+const EO: Column = Column {
+    index: 29,
+    name: "eo",
+};
+const XP: Column = Column {
+    index: 30,
+    name: "xp",
+};
+const YP: Column = Column {
+    index: 31,
+    name: "yp",
+};
+const BF_2TF: Column = Column {
+    index: 32,
+    name: "bf_2tf",
+};
+const B_T: Column = Column {
+    index: 33,
+    name: "b_t",
+};
+const B_TDES: Column = Column {
+    index: 34,
+    name: "b_tdes",
+};
+const H_TW: Column = Column {
+    index: 35,
+    name: "h_tw",
+};
+const H_TDES: Column = Column {
+    index: 36,
+    name: "h_tdes",
+};
+const D_T: Column = Column {
+    index: 37,
+    name: "d_t",
+};
+const IX: Column = Column {
+    index: 38,
+    name: "ix",
+};
+const ZX: Column = Column {
+    index: 39,
+    name: "zx",
+};
+const SX: Column = Column {
+    index: 40,
+    name: "sx",
+};
+const RX: Column = Column {
+    index: 41,
+    name: "rx",
+};
+const IY: Column = Column {
+    index: 42,
+    name: "iy",
+};
+const ZY: Column = Column {
+    index: 43,
+    name: "zy",
+};
+const SY: Column = Column {
+    index: 44,
+    name: "sy",
+};
+const RY: Column = Column {
+    index: 45,
+    name: "ry",
+};
+const IZ: Column = Column {
+    index: 46,
+    name: "iz",
+};
+const RZ: Column = Column {
+    index: 47,
+    name: "rz",
+};
+const SZ: Column = Column {
+    index: 48,
+    name: "sz",
+};
+const J_UPPER: Column = Column {
+    index: 49,
+    name: "j_upper",
+};
+const CW: Column = Column {
+    index: 50,
+    name: "cw",
+};
+const C_UPPER: Column = Column {
+    index: 51,
+    name: "c_upper",
+};
+const WNO: Column = Column {
+    index: 52,
+    name: "wno",
+};
+const SW1: Column = Column {
+    index: 53,
+    name: "sw1",
+};
+const SW2: Column = Column {
+    index: 54,
+    name: "sw2",
+};
+const SW3: Column = Column {
+    index: 55,
+    name: "sw3",
+};
+const QF: Column = Column {
+    index: 56,
+    name: "qf",
+};
+const QW: Column = Column {
+    index: 57,
+    name: "qw",
+};
+const RO: Column = Column {
+    index: 58,
+    name: "ro",
+};
+const H_UPPER: Column = Column {
+    index: 59,
+    name: "h_upper",
+};
+const TAN_A: Column = Column {
+    index: 60,
+    name: "tan_a",
+};
+const IW: Column = Column {
+    index: 61,
+    name: "iw",
+};
+const ZA: Column = Column {
+    index: 62,
+    name: "za",
+};
+const ZB: Column = Column {
+    index: 63,
+    name: "zb",
+};
+const ZC: Column = Column {
+    index: 64,
+    name: "zc",
+};
+const WA: Column = Column {
+    index: 65,
+    name: "wa",
+};
+const WB: Column = Column {
+    index: 66,
+    name: "wb",
+};
+const WC: Column = Column {
+    index: 67,
+    name: "wc",
+};
+const SWA: Column = Column {
+    index: 68,
+    name: "swa",
+};
+const SWB: Column = Column {
+    index: 69,
+    name: "swb",
+};
+const SWC: Column = Column {
+    index: 70,
+    name: "swc",
+};
+const SZA: Column = Column {
+    index: 71,
+    name: "sza",
+};
+const SZB: Column = Column {
+    index: 72,
+    name: "szb",
+};
+const SZC: Column = Column {
+    index: 73,
+    name: "szc",
+};
+const RTS: Column = Column {
+    index: 74,
+    name: "rts",
+};
+const HO: Column = Column {
+    index: 75,
+    name: "ho",
+};
+const PA: Column = Column {
+    index: 76,
+    name: "pa",
+};
+const PA2: Column = Column {
+    index: 77,
+    name: "pa_2",
+};
+const PB: Column = Column {
+    index: 78,
+    name: "pb",
+};
+const PC: Column = Column {
+    index: 79,
+    name: "pc",
+};
+const PD: Column = Column {
+    index: 80,
+    name: "pd",
+};
+const T: Column = Column {
+    index: 81,
+    name: "t",
+};
+const WGI: Column = Column {
+    index: 82,
+    name: "wgi",
+};
+const WGO: Column = Column {
+    index: 83,
+    name: "wgo",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header() -> String {
+        let columns = [
+            "type",
+            "edi_std_nomenclature",
+            "aisc_manual_label",
+            "t_f",
+            "w_upper",
+            "a_upper",
+            "d_lower",
+            "ddet",
+            "ht",
+            "h",
+            "od",
+            "bf",
+            "bfdet",
+            "b_upper",
+            "b_lower",
+            "id",
+            "tw",
+            "twdet",
+            "twdet_2",
+            "tf",
+            "tfdet",
+            "t_lower",
+            "tnom",
+            "tdes",
+            "kdes",
+            "kdet",
+            "k1",
+            "x_lower",
+            "y_lower",
+            "eo",
+            "xp",
+            "yp",
+            "bf_2tf",
+            "b_t",
+            "b_tdes",
+            "h_tw",
+            "h_tdes",
+            "d_t",
+            "ix",
+            "zx",
+            "sx",
+            "rx",
+            "Iy",
+            "zy",
+            "sy",
+            "ry",
+            "iz",
+            "rz",
+            "sz",
+            "j_upper",
+            "cw",
+            "c_upper",
+            "wno",
+            "sw1",
+            "sw2",
+            "sw3",
+            "qf",
+            "qw",
+            "ro",
+            "h_upper",
+            "tan_a",
+            "iw",
+            "za",
+            "zb",
+            "zc",
+            "wa",
+            "wb",
+            "wc",
+            "swa",
+            "swb",
+            "swc",
+            "sza",
+            "szb",
+            "szc",
+            "rts",
+            "ho",
+            "pa",
+            "pa2",
+            "pb",
+            "pc",
+            "pd",
+            "t",
+            "wgi",
+            "wgo",
+        ];
+        columns.join(",")
+    }
+
+    fn pipe_row(t_nom: &str) -> String {
+        let mut values = vec!["–"; 84];
+        values[TYPE_INDEX.index] = "PIPE";
+        values[EDI_NOM.index] = "Pipe1/2STD";
+        values[AISC_MAN_LBL.index] = "Pipe1/2STD";
+        values[W_UPPER.index] = "0.851";
+        values[A_UPPER.index] = "0.236";
+        values[OD.index] = "0.840";
+        values[ID.index] = "0.622";
+        values[T_NOM.index] = t_nom;
+        values[T_DES.index] = "0.101";
+        values[D_T.index] = "8.32";
+        for column in [IX, IY] {
+            values[column.index] = "0.0171";
+        }
+        for column in [ZX, ZY] {
+            values[column.index] = "0.0395";
+        }
+        for column in [SX, SY] {
+            values[column.index] = "0.0407";
+        }
+        for column in [RX, RY] {
+            values[column.index] = "0.269";
+        }
+        values[J_UPPER.index] = "0.0342";
+        values.join(",")
+    }
+
+    #[test]
+    fn parses_rows_of_modeled_types() {
+        let csv = format!("{}\n{}\n", header(), pipe_row("0.109"));
+        let shapes = read_shapes(csv.as_bytes()).unwrap();
+        assert_eq!(1, shapes.len());
+        let pipes: Vec<Pipe> = shapes_of(shapes);
+        assert_eq!("Pipe1/2STD", pipes[0].aisc_manual_label);
+        assert_eq!(0.109, pipes[0].t_nom);
+    }
+
+    #[test]
+    fn parses_fractions_and_mixed_numbers() {
+        let csv = format!(
+            "{}\n{}\n{}\n",
+            header(),
+            pipe_row("3/4"),
+            pipe_row("1 1/16")
+        );
+        let pipes: Vec<Pipe> = shapes_of(read_shapes(csv.as_bytes()).unwrap());
+        assert_eq!(0.75, pipes[0].t_nom);
+        assert_eq!(1.0625, pipes[1].t_nom);
+    }
+
+    #[test]
+    fn missing_required_values_are_errors() {
+        let csv = format!("{}\n{}\n", header(), pipe_row("–"));
+        let err = read_shapes(csv.as_bytes()).unwrap_err();
+        let err = err.downcast_ref::<MissingPropertyError>().unwrap();
+        assert_eq!("t_nom", err.property_name);
+    }
+
+    #[test]
+    fn invalid_values_are_errors() {
+        let csv = format!("{}\n{}\n", header(), pipe_row("thick"));
+        let err = read_shapes(csv.as_bytes()).unwrap_err();
+        let err = err.downcast_ref::<InvalidValueError>().unwrap();
+        assert_eq!("t_nom", err.column);
+        assert_eq!("thick", err.value);
+    }
+
+    #[test]
+    fn metric_designations_are_paired_by_row() {
+        let csv = format!("{}\n{}\n", header(), pipe_row("0.109"));
+        let shapes = read_shapes(csv.as_bytes()).unwrap();
+        let metric_csv = csv.replace("Pipe1/2STD", "Pipe13STD");
+        let designations = read_metric_designations(&shapes, metric_csv.as_bytes()).unwrap();
+        assert_eq!(1, designations.len());
+        assert_eq!("Pipe1/2STD", designations[0].aisc_manual_label);
+        assert_eq!("Pipe13STD", designations[0].metric_aisc_manual_label);
+        assert!(designations[0].matches("Pipe13STD"));
+    }
+
+    #[test]
+    fn metric_sheets_with_different_shapes_are_errors() {
+        let csv = format!("{}\n{}\n", header(), pipe_row("0.109"));
+        let shapes = read_shapes(csv.as_bytes()).unwrap();
+        let err = read_metric_designations(&shapes, header().as_bytes()).unwrap_err();
+        let err = err.downcast_ref::<MetricSheetMismatchError>().unwrap();
+        assert_eq!(0, err.row);
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn snapshot_contains_every_family() {
+        let shapes = snapshot();
+        assert_eq!(2299, shapes.len());
+        for family in ShapeFamily::ALL {
+            assert!(shapes.iter().any(|s| s.family() == family));
+        }
+    }
+
+    #[cfg(all(feature = "serde", feature = "snapshot"))]
+    #[test]
+    fn serde_round_trip_is_lossless() {
+        for shape in snapshot() {
+            let json = serde_json::to_string(shape).unwrap();
+            let round_tripped: AiscShape = serde_json::from_str(&json).unwrap();
+            assert_eq!(*shape, round_tripped);
+        }
+        let w6x9 = snapshot()
+            .iter()
+            .find(|shape| shape.aisc_manual_label() == "W6X9")
+            .unwrap();
+        assert_eq!(None, w6x9.property(ShapeProperty::Wgo));
+        let json = serde_json::to_value(w6x9).unwrap();
+        assert_eq!(serde_json::Value::Null, json["wgo"]);
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn snapshot_metric_designations_are_unique() {
+        let designations = snapshot_metric_designations();
+        let pipes = snapshot()
+            .iter()
+            .filter(|shape| shape.family() == ShapeFamily::Pipe)
+            .count();
+        assert_eq!(snapshot().len() - pipes, designations.len());
+        let labels: std::collections::HashSet<&str> = designations
+            .iter()
+            .map(|d| d.metric_aisc_manual_label.as_str())
+            .collect();
+        assert_eq!(designations.len(), labels.len());
+        let w14x90 = designations.iter().find(|d| d.matches("W360X134")).unwrap();
+        assert_eq!("W14X90", w14x90.aisc_manual_label);
+    }
+}
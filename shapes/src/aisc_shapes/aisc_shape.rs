@@ -0,0 +1,630 @@
+use crate::aisc_shapes::geometry::{CrossSection, SectionGeometry};
+use crate::aisc_shapes::section_properties::{
+    FlangedSection, RectangularTubularSection, SectionProperties, TorsionalProperties,
+    TubularSection,
+};
+use crate::aisc_shapes::{
+    Angle, CeeChannel, DoubleAngle, HPile, HollowStructuralSection, MiscBeam, MiscChannel, MiscTee,
+    Pipe, RoundHollowStructuralSection, ShapeFamily, ShapeProperty, StructuralBeam, StructuralTee,
+    UnitSystem, WideFlange, WideFlangeTee,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "family", rename_all = "snake_case")
+)]
+/// An AISC steel shape of any family, allows shapes of
+/// different families to be stored and searched together
+pub enum AiscShape {
+    /// A wide flange (W) shape
+    WideFlange(WideFlange),
+    /// A misc. beam (M) shape
+    MiscBeam(MiscBeam),
+    /// A structural beam (S) shape
+    StructuralBeam(StructuralBeam),
+    /// A h-pile (HP) shape
+    HPile(HPile),
+    /// A cee channel (C) shape
+    CeeChannel(CeeChannel),
+    /// A misc. channel (MC) shape
+    MiscChannel(MiscChannel),
+    /// A angle (L) shape
+    Angle(Angle),
+    /// A wide flange tee (WT) shape
+    WideFlangeTee(WideFlangeTee),
+    /// A misc. tee (MT) shape
+    MiscTee(MiscTee),
+    /// A structural tee (ST) shape
+    StructuralTee(StructuralTee),
+    /// A double angle (2L) shape
+    DoubleAngle(DoubleAngle),
+    /// A square or rectangular HSS shape
+    HollowStructuralSection(HollowStructuralSection),
+    /// A round HSS shape
+    RoundHollowStructuralSection(RoundHollowStructuralSection),
+    /// A pipe shape
+    Pipe(Pipe),
+}
+
+// Applies the same expression to the shape held by any variant
+macro_rules! with_shape {
+    ($aisc_shape:expr, $shape:ident => $body:expr) => {
+        match $aisc_shape {
+            AiscShape::WideFlange($shape) => $body,
+            AiscShape::MiscBeam($shape) => $body,
+            AiscShape::StructuralBeam($shape) => $body,
+            AiscShape::HPile($shape) => $body,
+            AiscShape::CeeChannel($shape) => $body,
+            AiscShape::MiscChannel($shape) => $body,
+            AiscShape::Angle($shape) => $body,
+            AiscShape::WideFlangeTee($shape) => $body,
+            AiscShape::MiscTee($shape) => $body,
+            AiscShape::StructuralTee($shape) => $body,
+            AiscShape::DoubleAngle($shape) => $body,
+            AiscShape::HollowStructuralSection($shape) => $body,
+            AiscShape::RoundHollowStructuralSection($shape) => $body,
+            AiscShape::Pipe($shape) => $body,
+        }
+    };
+}
+
+impl AiscShape {
+    /// The family the shape belongs to
+    pub fn family(&self) -> ShapeFamily {
+        match self {
+            AiscShape::WideFlange(_) => ShapeFamily::WideFlange,
+            AiscShape::MiscBeam(_) => ShapeFamily::MiscBeam,
+            AiscShape::StructuralBeam(_) => ShapeFamily::StructuralBeam,
+            AiscShape::HPile(_) => ShapeFamily::HPile,
+            AiscShape::CeeChannel(_) => ShapeFamily::CeeChannel,
+            AiscShape::MiscChannel(_) => ShapeFamily::MiscChannel,
+            AiscShape::Angle(_) => ShapeFamily::Angle,
+            AiscShape::WideFlangeTee(_) => ShapeFamily::WideFlangeTee,
+            AiscShape::MiscTee(_) => ShapeFamily::MiscTee,
+            AiscShape::StructuralTee(_) => ShapeFamily::StructuralTee,
+            AiscShape::DoubleAngle(_) => ShapeFamily::DoubleAngle,
+            AiscShape::HollowStructuralSection(_) => ShapeFamily::HollowStructuralSection,
+            AiscShape::RoundHollowStructuralSection(_) => ShapeFamily::RoundHollowStructuralSection,
+            AiscShape::Pipe(_) => ShapeFamily::Pipe,
+        }
+    }
+
+    /// The dimension matched by `shapes_with_depth` in the shape's repository, in.,
+    /// see [ShapeFamily::depth_property]
+    pub fn depth(&self) -> f64 {
+        self.property(self.family().depth_property())
+            .expect("Every shape defines its depth property")
+    }
+
+    /// The dimension matched by `shapes_with_width` in the shape's repository, in.,
+    /// see [ShapeFamily::width_property]
+    pub fn width(&self) -> f64 {
+        self.property(self.family().width_property())
+            .expect("Every shape defines its width property")
+    }
+
+    /// The shape as a [FlangedSection], `None` for shapes without flanges and a web
+    pub fn as_flanged(&self) -> Option<&dyn FlangedSection> {
+        match self {
+            AiscShape::WideFlange(shape) => Some(shape),
+            AiscShape::MiscBeam(shape) => Some(shape),
+            AiscShape::StructuralBeam(shape) => Some(shape),
+            AiscShape::HPile(shape) => Some(shape),
+            AiscShape::CeeChannel(shape) => Some(shape),
+            AiscShape::MiscChannel(shape) => Some(shape),
+            AiscShape::WideFlangeTee(shape) => Some(shape),
+            AiscShape::MiscTee(shape) => Some(shape),
+            AiscShape::StructuralTee(shape) => Some(shape),
+            _ => None,
+        }
+    }
+
+    /// The shape as a [TubularSection], `None` unless the shape is a round HSS or pipe
+    pub fn as_tubular(&self) -> Option<&dyn TubularSection> {
+        match self {
+            AiscShape::RoundHollowStructuralSection(shape) => Some(shape),
+            AiscShape::Pipe(shape) => Some(shape),
+            _ => None,
+        }
+    }
+
+    /// The shape as a [RectangularTubularSection], `None` unless the shape is a square or rectangular HSS
+    pub fn as_rectangular_tubular(&self) -> Option<&dyn RectangularTubularSection> {
+        match self {
+            AiscShape::HollowStructuralSection(shape) => Some(shape),
+            _ => None,
+        }
+    }
+
+    /// The shape as [TorsionalProperties], `None` for double angles
+    pub fn as_torsional(&self) -> Option<&dyn TorsionalProperties> {
+        match self {
+            AiscShape::WideFlange(shape) => Some(shape),
+            AiscShape::MiscBeam(shape) => Some(shape),
+            AiscShape::StructuralBeam(shape) => Some(shape),
+            AiscShape::HPile(shape) => Some(shape),
+            AiscShape::CeeChannel(shape) => Some(shape),
+            AiscShape::MiscChannel(shape) => Some(shape),
+            AiscShape::Angle(shape) => Some(shape),
+            AiscShape::WideFlangeTee(shape) => Some(shape),
+            AiscShape::MiscTee(shape) => Some(shape),
+            AiscShape::StructuralTee(shape) => Some(shape),
+            AiscShape::HollowStructuralSection(shape) => Some(shape),
+            AiscShape::RoundHollowStructuralSection(shape) => Some(shape),
+            AiscShape::Pipe(shape) => Some(shape),
+            _ => None,
+        }
+    }
+
+    /// (J) Torsional constant, in.4, `None` for double angles
+    /// which do not tabulate a torsional constant
+    pub fn j_upper(&self) -> Option<f64> {
+        self.as_torsional().map(|shape| shape.j_upper())
+    }
+
+    /// (T_F) Whether the shape is a special fabrication, `None` for families
+    /// that do not tabulate the flag and M shapes where it is missing
+    pub fn t_f(&self) -> Option<bool> {
+        match self {
+            AiscShape::WideFlange(shape) => Some(shape.t_f),
+            AiscShape::MiscBeam(shape) => shape.t_f,
+            AiscShape::WideFlangeTee(shape) => Some(shape.t_f),
+            AiscShape::MiscTee(shape) => Some(shape.t_f),
+            _ => None,
+        }
+    }
+
+    /// Every tabulated property of the shape in the unit system, in the order of
+    /// [ShapeFamily::properties], each value scaled according to its [ShapeProperty::unit].
+    /// Shapes are stored in [UnitSystem::UsCustomary] units, so the fields of the shape
+    /// itself are never converted.
+    ///
+    /// ```
+    /// use shapes::aisc_shapes::{AiscShape, ShapeProperty, UnitSystem};
+    /// # use shapes::aisc_shapes::{Pipe, ShapeBuilder};
+    /// # let pipe: AiscShape = ShapeBuilder::new()
+    /// #     .with_edi_std_nomenclature(String::from("Pipe4STD"))
+    /// #     .with_aisc_manual_label(String::from("Pipe4STD"))
+    /// #     .with_w_upper(10.8).with_a_upper(2.96).with_od(4.5).with_id(4.03)
+    /// #     .with_t_nom(0.237).with_tdes(0.221).with_d_t(20.4).with_ix(6.82)
+    /// #     .with_zx(4.05).with_sx(3.03).with_rx(1.51).with_iy(6.82).with_zy(4.05)
+    /// #     .with_sy(3.03).with_ry(1.51).with_j_upper(13.6)
+    /// #     .try_build::<Pipe>().unwrap().into();
+    /// let metric = pipe.to_unit_system(UnitSystem::Metric);
+    /// let (_, od) = metric.iter().find(|(property, _)| *property == ShapeProperty::Od).unwrap();
+    /// assert!((od - 114.3).abs() < 1e-9);
+    /// ```
+    pub fn to_unit_system(&self, system: UnitSystem) -> Vec<(ShapeProperty, f64)> {
+        self.family()
+            .properties()
+            .iter()
+            .filter_map(|property| {
+                self.property(*property).map(|value| {
+                    let value = property
+                        .unit()
+                        .convert(value, UnitSystem::UsCustomary, system);
+                    (*property, value)
+                })
+            })
+            .collect()
+    }
+}
+
+impl SectionProperties for AiscShape {
+    fn edi_std_nomenclature(&self) -> &str {
+        with_shape!(self, shape => &shape.edi_std_nomenclature)
+    }
+    fn aisc_manual_label(&self) -> &str {
+        with_shape!(self, shape => &shape.aisc_manual_label)
+    }
+    fn w_upper(&self) -> f64 {
+        with_shape!(self, shape => shape.w_upper)
+    }
+    fn a_upper(&self) -> f64 {
+        with_shape!(self, shape => shape.a_upper)
+    }
+    fn ix(&self) -> f64 {
+        with_shape!(self, shape => shape.ix)
+    }
+    fn zx(&self) -> f64 {
+        with_shape!(self, shape => shape.zx)
+    }
+    fn sx(&self) -> f64 {
+        with_shape!(self, shape => shape.sx)
+    }
+    fn rx(&self) -> f64 {
+        with_shape!(self, shape => shape.rx)
+    }
+    fn iy(&self) -> f64 {
+        with_shape!(self, shape => shape.iy)
+    }
+    fn zy(&self) -> f64 {
+        with_shape!(self, shape => shape.zy)
+    }
+    fn sy(&self) -> f64 {
+        with_shape!(self, shape => shape.sy)
+    }
+    fn ry(&self) -> f64 {
+        with_shape!(self, shape => shape.ry)
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        with_shape!(self, shape => shape.property(property))
+    }
+}
+
+impl SectionGeometry for AiscShape {
+    fn cross_section_with(&self, arc_segments: usize) -> CrossSection {
+        with_shape!(self, shape => shape.cross_section_with(arc_segments))
+    }
+}
+
+impl From<WideFlange> for AiscShape {
+    fn from(shape: WideFlange) -> Self {
+        AiscShape::WideFlange(shape)
+    }
+}
+
+impl From<MiscBeam> for AiscShape {
+    fn from(shape: MiscBeam) -> Self {
+        AiscShape::MiscBeam(shape)
+    }
+}
+
+impl From<StructuralBeam> for AiscShape {
+    fn from(shape: StructuralBeam) -> Self {
+        AiscShape::StructuralBeam(shape)
+    }
+}
+
+impl From<HPile> for AiscShape {
+    fn from(shape: HPile) -> Self {
+        AiscShape::HPile(shape)
+    }
+}
+
+impl From<CeeChannel> for AiscShape {
+    fn from(shape: CeeChannel) -> Self {
+        AiscShape::CeeChannel(shape)
+    }
+}
+
+impl From<MiscChannel> for AiscShape {
+    fn from(shape: MiscChannel) -> Self {
+        AiscShape::MiscChannel(shape)
+    }
+}
+
+impl From<Angle> for AiscShape {
+    fn from(shape: Angle) -> Self {
+        AiscShape::Angle(shape)
+    }
+}
+
+impl From<WideFlangeTee> for AiscShape {
+    fn from(shape: WideFlangeTee) -> Self {
+        AiscShape::WideFlangeTee(shape)
+    }
+}
+
+impl From<MiscTee> for AiscShape {
+    fn from(shape: MiscTee) -> Self {
+        AiscShape::MiscTee(shape)
+    }
+}
+
+impl From<StructuralTee> for AiscShape {
+    fn from(shape: StructuralTee) -> Self {
+        AiscShape::StructuralTee(shape)
+    }
+}
+
+impl From<DoubleAngle> for AiscShape {
+    fn from(shape: DoubleAngle) -> Self {
+        AiscShape::DoubleAngle(shape)
+    }
+}
+
+impl From<HollowStructuralSection> for AiscShape {
+    fn from(shape: HollowStructuralSection) -> Self {
+        AiscShape::HollowStructuralSection(shape)
+    }
+}
+
+impl From<RoundHollowStructuralSection> for AiscShape {
+    fn from(shape: RoundHollowStructuralSection) -> Self {
+        AiscShape::RoundHollowStructuralSection(shape)
+    }
+}
+
+impl From<Pipe> for AiscShape {
+    fn from(shape: Pipe) -> Self {
+        AiscShape::Pipe(shape)
+    }
+}
+
+impl TryFrom<AiscShape> for WideFlange {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::WideFlange(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for MiscBeam {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::MiscBeam(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for StructuralBeam {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::StructuralBeam(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for HPile {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::HPile(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for CeeChannel {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::CeeChannel(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for MiscChannel {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::MiscChannel(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for Angle {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::Angle(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for WideFlangeTee {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::WideFlangeTee(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for MiscTee {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::MiscTee(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for StructuralTee {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::StructuralTee(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for DoubleAngle {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::DoubleAngle(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for HollowStructuralSection {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::HollowStructuralSection(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for RoundHollowStructuralSection {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::RoundHollowStructuralSection(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+impl TryFrom<AiscShape> for Pipe {
+    type Error = AiscShape;
+    fn try_from(shape: AiscShape) -> Result<Self, Self::Error> {
+        match shape {
+            AiscShape::Pipe(shape) => Ok(shape),
+            shape => Err(shape),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::ShapeBuilder;
+
+    fn pipe() -> Pipe {
+        ShapeBuilder::new()
+            .with_edi_std_nomenclature(String::from("Pipe26STD"))
+            .with_aisc_manual_label(String::from("Pipe26STD"))
+            .with_w_upper(103.0)
+            .with_a_upper(28.2)
+            .with_od(26.0)
+            .with_id(25.3)
+            .with_t_nom(0.375)
+            .with_tdes(0.349)
+            .with_d_t(74.5)
+            .with_ix(2320.0)
+            .with_zx(230.0)
+            .with_sx(178.0)
+            .with_rx(9.07)
+            .with_iy(191.0)
+            .with_zy(230.0)
+            .with_sy(178.0)
+            .with_ry(9.07)
+            .with_j_upper(4640.0)
+            .try_build::<Pipe>()
+            .unwrap()
+    }
+
+    fn double_angle() -> DoubleAngle {
+        ShapeBuilder::new()
+            .with_edi_std_nomenclature(String::from("2L5X5X3/4"))
+            .with_aisc_manual_label(String::from("2L5X5X3/4"))
+            .with_w_upper(47.2)
+            .with_a_upper(14.0)
+            .with_d_lower(5.0)
+            .with_b_lower(5.0)
+            .with_t_lower(1.38)
+            .with_y_lower(3.50)
+            .with_yp(1.3)
+            .with_b_t(8.7)
+            .with_ix(826.0)
+            .with_zx(176.0)
+            .with_sx(97.2)
+            .with_rx(3.64)
+            .with_iy(1590.0)
+            .with_zy(218.0)
+            .with_sy(133.0)
+            .with_ry(5.06)
+            .with_ro(6.84)
+            .with_h_upper(0.831)
+            .try_build::<DoubleAngle>()
+            .unwrap()
+    }
+
+    #[test]
+    fn accessors_return_values_of_wrapped_shape() {
+        let shape = AiscShape::from(pipe());
+        assert_eq!(ShapeFamily::Pipe, shape.family());
+        assert_eq!("Pipe26STD", shape.edi_std_nomenclature());
+        assert_eq!("Pipe26STD", shape.aisc_manual_label());
+        assert_eq!(103.0, shape.w_upper());
+        assert_eq!(28.2, shape.a_upper());
+        assert_eq!(2320.0, shape.ix());
+        assert_eq!(230.0, shape.zx());
+        assert_eq!(178.0, shape.sx());
+        assert_eq!(9.07, shape.rx());
+        assert_eq!(Some(4640.0), shape.j_upper());
+    }
+
+    #[test]
+    fn depth_and_width_match_repository_dimensions() {
+        let pipe = AiscShape::from(pipe());
+        assert_eq!(26.0, pipe.depth());
+        assert_eq!(26.0, pipe.width());
+        let double_angle = double_angle();
+        let (b, d) = (double_angle.b_lower, double_angle.d_lower);
+        let double_angle = AiscShape::from(double_angle);
+        assert_eq!(b, double_angle.depth());
+        assert_eq!(d, double_angle.width());
+    }
+
+    #[test]
+    fn metric_conversion_scales_each_property_by_its_unit() {
+        let shape = AiscShape::from(pipe());
+        let metric = shape.to_unit_system(UnitSystem::Metric);
+        let value = |property| {
+            metric
+                .iter()
+                .find(|(p, _)| *p == property)
+                .map(|(_, value)| *value)
+                .unwrap()
+        };
+        assert_eq!(shape.family().properties().len(), metric.len());
+        assert!((value(ShapeProperty::Od) - 660.4).abs() < 1e-9);
+        assert!((value(ShapeProperty::AUpper) - 28.2 * 645.16).abs() < 1e-9);
+        assert!((value(ShapeProperty::Ix) - 965.66).abs() < 0.01);
+        assert_eq!(
+            shape.property(ShapeProperty::DT),
+            Some(value(ShapeProperty::DT))
+        );
+        assert_eq!(26.0, shape.depth());
+        let us = shape.to_unit_system(UnitSystem::UsCustomary);
+        assert!(
+            us.iter()
+                .all(|(property, value)| shape.property(*property) == Some(*value))
+        );
+    }
+
+    #[test]
+    fn double_angle_has_no_torsional_constant() {
+        let shape = AiscShape::from(double_angle());
+        assert_eq!(ShapeFamily::DoubleAngle, shape.family());
+        assert_eq!(None, shape.j_upper());
+    }
+
+    #[test]
+    fn shapes_of_different_families_can_be_stored_together() {
+        let shapes = [AiscShape::from(pipe()), AiscShape::from(double_angle())];
+        let lightest = shapes
+            .iter()
+            .min_by(|a, b| a.w_upper().total_cmp(&b.w_upper()))
+            .unwrap();
+        assert_eq!(ShapeFamily::DoubleAngle, lightest.family());
+    }
+
+    #[test]
+    fn shapes_can_be_unwrapped_to_their_family_type() {
+        let shape = AiscShape::from(pipe());
+        assert_eq!(Ok(pipe()), Pipe::try_from(shape.clone()));
+        assert_eq!(Err(shape.clone()), DoubleAngle::try_from(shape));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_with_family_tag() {
+        let shape = AiscShape::from(pipe());
+        let json = serde_json::to_value(&shape).unwrap();
+        assert_eq!(serde_json::json!("pipe"), json["family"]);
+        assert_eq!(serde_json::json!("Pipe26STD"), json["aisc_manual_label"]);
+        let round_tripped: AiscShape = serde_json::from_value(json).unwrap();
+        assert_eq!(shape, round_tripped);
+    }
+}
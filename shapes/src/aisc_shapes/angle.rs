@@ -1,7 +1,8 @@
 use crate::aisc_shapes::{MissingPropertyError, ShapeBuilder};
 use std::convert::TryFrom;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 /// A struct that models the data for angle (L) steel profiles
 pub struct Angle {
@@ -146,57 +147,57 @@ impl TryFrom<ShapeBuilder> for Angle {
                     return Err(MissingPropertyError::from("A"));
                 }
             },
-            d_lower: match *&builder.d_lower {
+            d_lower: match builder.d_lower {
                 Some(d_lower) => d_lower,
                 None => {
                     return Err(MissingPropertyError::from("d"));
                 }
             },
-            b_lower: match *&builder.b_lower {
+            b_lower: match builder.b_lower {
                 Some(b_lower) => b_lower,
                 None => {
                     return Err(MissingPropertyError::from("b"));
                 }
             },
-            t_lower: match *&builder.t_lower {
+            t_lower: match builder.t_lower {
                 Some(t_lower) => t_lower,
                 None => return Err(MissingPropertyError::from("t")),
             },
-            kdes: match *&builder.kdes {
+            kdes: match builder.kdes {
                 Some(kdes) => kdes,
                 None => {
                     return Err(MissingPropertyError::from("kdes"));
                 }
             },
-            kdet: match *&builder.kdet {
+            kdet: match builder.kdet {
                 Some(kdet) => kdet,
                 None => return Err(MissingPropertyError::from("kdet")),
             },
-            x_lower: match *&builder.x_lower {
+            x_lower: match builder.x_lower {
                 Some(x_lower) => x_lower,
                 None => {
                     return Err(MissingPropertyError::from("x"));
                 }
             },
-            y_lower: match *&builder.y_lower {
+            y_lower: match builder.y_lower {
                 Some(y_lower) => y_lower,
                 None => {
                     return Err(MissingPropertyError::from("y"));
                 }
             },
-            xp: match *&builder.xp {
+            xp: match builder.xp {
                 Some(xp) => xp,
                 None => {
                     return Err(MissingPropertyError::from("xp"));
                 }
             },
-            yp: match *&builder.yp {
+            yp: match builder.yp {
                 Some(yp) => yp,
                 None => {
                     return Err(MissingPropertyError::from("yp"));
                 }
             },
-            b_t: match *&builder.b_t {
+            b_t: match builder.b_t {
                 Some(b_t) => b_t,
                 None => return Err(MissingPropertyError::from("b/t")),
             },
@@ -266,109 +267,109 @@ impl TryFrom<ShapeBuilder> for Angle {
                     return Err(MissingPropertyError::from("Sz"));
                 }
             },
-            j_upper: match *&builder.j_upper {
+            j_upper: match builder.j_upper {
                 Some(j_upper) => j_upper,
                 None => return Err(MissingPropertyError::from("J")),
             },
-            cw: match *&builder.cw {
+            cw: match builder.cw {
                 Some(cw) => cw,
                 None => {
                     return Err(MissingPropertyError::from("Cw"));
                 }
             },
-            ro: match *&builder.ro {
+            ro: match builder.ro {
                 Some(ro) => ro,
                 None => {
                     return Err(MissingPropertyError::from("ro"));
                 }
             },
-            h_upper: *&builder.h_upper,
-            tan_a: match *&builder.tan_a {
+            h_upper: builder.h_upper,
+            tan_a: match builder.tan_a {
                 Some(tan_a) => tan_a,
                 None => return Err(MissingPropertyError::from("tan(α)")),
             },
-            iw: match *&builder.iw {
+            iw: match builder.iw {
                 Some(iw) => iw,
                 None => {
                     return Err(MissingPropertyError::from("Iw"));
                 }
             },
-            za: match *&builder.za {
+            za: match builder.za {
                 Some(za) => za,
                 None => {
                     return Err(MissingPropertyError::from("zA"));
                 }
             },
-            zb: match *&builder.zb {
+            zb: match builder.zb {
                 Some(zb) => zb,
                 None => {
                     return Err(MissingPropertyError::from("zB"));
                 }
             },
-            zc: match *&builder.zc {
+            zc: match builder.zc {
                 Some(zc) => zc,
                 None => {
                     return Err(MissingPropertyError::from("zC"));
                 }
             },
-            wa: match *&builder.wa {
+            wa: match builder.wa {
                 Some(wa) => wa,
                 None => {
                     return Err(MissingPropertyError::from("wA"));
                 }
             },
-            wb: match *&builder.wb {
+            wb: match builder.wb {
                 Some(wb) => wb,
                 None => {
                     return Err(MissingPropertyError::from("wB"));
                 }
             },
-            wc: match *&builder.wc {
+            wc: match builder.wc {
                 Some(wc) => wc,
                 None => {
                     return Err(MissingPropertyError::from("wC"));
                 }
             },
-            swa: match *&builder.swa {
+            swa: match builder.swa {
                 Some(swa) => swa,
                 None => {
                     return Err(MissingPropertyError::from("SwA"));
                 }
             },
-            swb: *&builder.swb,
-            swc: match *&builder.swc {
+            swb: builder.swb,
+            swc: match builder.swc {
                 Some(swc) => swc,
                 None => {
                     return Err(MissingPropertyError::from("SwC"));
                 }
             },
-            sza: match *&builder.sza {
+            sza: match builder.sza {
                 Some(sza) => sza,
                 None => {
                     return Err(MissingPropertyError::from("SzA"));
                 }
             },
-            szb: match *&builder.szb {
+            szb: match builder.szb {
                 Some(szb) => szb,
                 None => {
                     return Err(MissingPropertyError::from("SzB"));
                 }
             },
-            szc: match *&builder.szc {
+            szc: match builder.szc {
                 Some(szc) => szc,
                 None => {
                     return Err(MissingPropertyError::from("SzC"));
                 }
             },
-            pa: match *&builder.pa {
+            pa: match builder.pa {
                 Some(pa) => pa,
                 None => return Err(MissingPropertyError::from("PA")),
             },
-            pa_2: match *&builder.pa_2 {
+            pa_2: match builder.pa_2 {
                 Some(pa_2) => pa_2,
                 None => return Err(MissingPropertyError::from("PA2")),
             },
-            pb: match *&builder.pb {
+            pb: match builder.pb {
                 Some(pb) => pb,
                 None => return Err(MissingPropertyError::from("PB")),
             },
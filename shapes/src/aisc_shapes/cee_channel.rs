@@ -1,7 +1,8 @@
 use crate::aisc_shapes::{MissingPropertyError, ShapeBuilder};
 use std::convert::TryFrom;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 /// A struct that models the data for cee channel (C) steel profiles
 pub struct CeeChannel {
@@ -146,93 +147,93 @@ impl TryFrom<ShapeBuilder> for CeeChannel {
                     return Err(MissingPropertyError::from("A"));
                 }
             },
-            d_lower: match *&builder.d_lower {
+            d_lower: match builder.d_lower {
                 Some(d_lower) => d_lower,
                 None => {
                     return Err(MissingPropertyError::from("d"));
                 }
             },
-            ddet: match *&builder.ddet {
+            ddet: match builder.ddet {
                 Some(ddet) => ddet,
                 None => {
                     return Err(MissingPropertyError::from("ddet"));
                 }
             },
-            bf: match *&builder.bf {
+            bf: match builder.bf {
                 Some(bf) => bf,
                 None => {
                     return Err(MissingPropertyError::from("bf"));
                 }
             },
-            bfdet: match *&builder.bfdet {
+            bfdet: match builder.bfdet {
                 Some(bfdet) => bfdet,
                 None => {
                     return Err(MissingPropertyError::from("bfdet"));
                 }
             },
-            tw: match *&builder.tw {
+            tw: match builder.tw {
                 Some(tw) => tw,
                 None => {
                     return Err(MissingPropertyError::from("tw"));
                 }
             },
-            twdet: match *&builder.twdet {
+            twdet: match builder.twdet {
                 Some(twdet) => twdet,
                 None => {
                     return Err(MissingPropertyError::from("twdet"));
                 }
             },
-            twdet_2: match *&builder.twdet_2 {
+            twdet_2: match builder.twdet_2 {
                 Some(twdet) => twdet,
                 None => {
                     return Err(MissingPropertyError::from("twdet/2"));
                 }
             },
-            tf: match *&builder.tf {
+            tf: match builder.tf {
                 Some(tf) => tf,
                 None => {
                     return Err(MissingPropertyError::from("tf"));
                 }
             },
-            tfdet: match *&builder.tfdet {
+            tfdet: match builder.tfdet {
                 Some(tfdet) => tfdet,
                 None => {
                     return Err(MissingPropertyError::from("tfdet"));
                 }
             },
-            kdes: match *&builder.kdes {
+            kdes: match builder.kdes {
                 Some(kdes) => kdes,
                 None => {
                     return Err(MissingPropertyError::from("kdes"));
                 }
             },
-            kdet: match *&builder.kdet {
+            kdet: match builder.kdet {
                 Some(kdet) => kdet,
                 None => return Err(MissingPropertyError::from("kdet")),
             },
-            x_lower: match *&builder.x_lower {
+            x_lower: match builder.x_lower {
                 Some(x_lower) => x_lower,
                 None => {
                     return Err(MissingPropertyError::from("x"));
                 }
             },
-            eo: match *&builder.eo {
+            eo: match builder.eo {
                 Some(eo) => eo,
                 None => {
                     return Err(MissingPropertyError::from("eo"));
                 }
             },
-            xp: match *&builder.xp {
+            xp: match builder.xp {
                 Some(xp) => xp,
                 None => {
                     return Err(MissingPropertyError::from("xp"));
                 }
             },
-            b_t: match *&builder.b_t {
+            b_t: match builder.b_t {
                 Some(b_t) => b_t,
                 None => return Err(MissingPropertyError::from("b/t")),
             },
-            h_tw: match *&builder.h_tw {
+            h_tw: match builder.h_tw {
                 Some(h_tw) => h_tw,
                 None => return Err(MissingPropertyError::from("h/tw")),
             },
@@ -284,89 +285,89 @@ impl TryFrom<ShapeBuilder> for CeeChannel {
                     return Err(MissingPropertyError::from("ry"));
                 }
             },
-            j_upper: match *&builder.j_upper {
+            j_upper: match builder.j_upper {
                 Some(j_upper) => j_upper,
                 None => return Err(MissingPropertyError::from("J")),
             },
-            cw: match *&builder.cw {
+            cw: match builder.cw {
                 Some(cw) => cw,
                 None => {
                     return Err(MissingPropertyError::from("Cw"));
                 }
             },
-            wno: match *&builder.wno {
+            wno: match builder.wno {
                 Some(wno) => wno,
                 None => return Err(MissingPropertyError::from("Wno")),
             },
-            sw1: match *&builder.sw1 {
+            sw1: match builder.sw1 {
                 Some(sw1) => sw1,
                 None => {
                     return Err(MissingPropertyError::from("Sw1"));
                 }
             },
-            sw2: match *&builder.sw2 {
+            sw2: match builder.sw2 {
                 Some(sw2) => sw2,
                 None => {
                     return Err(MissingPropertyError::from("Sw2"));
                 }
             },
-            sw3: match *&builder.sw3 {
+            sw3: match builder.sw3 {
                 Some(sw3) => sw3,
                 None => {
                     return Err(MissingPropertyError::from("Sw3"));
                 }
             },
-            qf: match *&builder.qf {
+            qf: match builder.qf {
                 Some(qf) => qf,
                 None => return Err(MissingPropertyError::from("Qf")),
             },
-            qw: match *&builder.qw {
+            qw: match builder.qw {
                 Some(qw) => qw,
                 None => return Err(MissingPropertyError::from("Qw")),
             },
-            ro: match *&builder.ro {
+            ro: match builder.ro {
                 Some(ro) => ro,
                 None => {
                     return Err(MissingPropertyError::from("ro"));
                 }
             },
-            h_upper: match *&builder.h_upper {
+            h_upper: match builder.h_upper {
                 Some(h_upper) => h_upper,
                 None => {
                     return Err(MissingPropertyError::from("H"));
                 }
             },
-            rts: match *&builder.rts {
+            rts: match builder.rts {
                 Some(rts) => rts,
                 None => return Err(MissingPropertyError::from("rts")),
             },
-            ho: match *&builder.ho {
+            ho: match builder.ho {
                 Some(ho) => ho,
                 None => {
                     return Err(MissingPropertyError::from("ho"));
                 }
             },
-            pa: match *&builder.pa {
+            pa: match builder.pa {
                 Some(pa) => pa,
                 None => return Err(MissingPropertyError::from("PA")),
             },
-            pb: match *&builder.pb {
+            pb: match builder.pb {
                 Some(pb) => pb,
                 None => return Err(MissingPropertyError::from("PB")),
             },
-            pc: match *&builder.pc {
+            pc: match builder.pc {
                 Some(pc) => pc,
                 None => return Err(MissingPropertyError::from("PC")),
             },
-            pd: match *&builder.pd {
+            pd: match builder.pd {
                 Some(pd) => pd,
                 None => return Err(MissingPropertyError::from("PD")),
             },
-            t: match *&builder.t {
+            t: match builder.t {
                 Some(t) => t,
                 None => return Err(MissingPropertyError::from("T")),
             },
-            wgi: *&builder.wgi,
+            wgi: builder.wgi,
         })
     }
 }
@@ -0,0 +1,649 @@
+use self::sealed::SlendernessRatio;
+use crate::aisc_shapes::{
+    AiscShape, Angle, CeeChannel, DoubleAngle, HPile, HollowStructuralSection, MiscBeam,
+    MiscChannel, MiscTee, Pipe, RoundHollowStructuralSection, Steel, StructuralBeam, StructuralTee,
+    WideFlange, WideFlangeTee,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// An element of a cross-section with its own width-to-thickness ratio
+pub enum Element {
+    /// The flange of an I-shape, channel or tee, or the walls of an HSS
+    /// parallel to the axis of bending (of width B)
+    Flange,
+    /// The web of an I-shape or channel, or the walls of an HSS
+    /// perpendicular to the axis of bending (of height H)
+    Web,
+    /// The stem of a tee
+    Stem,
+    /// The longer leg of an angle, either leg of an equal leg angle
+    LongLeg,
+    /// The shorter leg of an angle
+    ShortLeg,
+    /// The wall of a round HSS or pipe
+    Wall,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The classification of an element in flexure (AISC 360 Table B4.1b),
+/// ordered from the least to the most slender
+pub enum FlexureClass {
+    /// λ ≤ λp
+    Compact,
+    /// λp < λ ≤ λr
+    Noncompact,
+    /// λ > λr
+    Slender,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The classification of an element in axial compression (AISC 360 Table B4.1a),
+/// ordered from the least to the most slender
+pub enum CompressionClass {
+    /// λ ≤ λr
+    Nonslender,
+    /// λ > λr
+    Slender,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The width-to-thickness limits and classification of a single element
+pub struct ElementClassification<C> {
+    /// The classified element
+    pub element: Element,
+    /// The case of AISC 360 Table B4.1a or B4.1b the limits are taken from
+    pub case: u8,
+    /// (λ) The width-to-thickness ratio of the element
+    pub ratio: f64,
+    /// (λp) The limiting ratio of compact elements, only given in flexure
+    pub lambda_p: Option<f64>,
+    /// (λr) The limiting ratio of noncompact (or nonslender) elements
+    pub lambda_r: f64,
+    /// The classification of the element
+    pub class: C,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The classification of every element of a shape, and the governing classification
+/// (that of its most slender element)
+pub struct Classification<C> {
+    /// The elements of the shape, in the order of [Element]
+    pub elements: Vec<ElementClassification<C>>,
+    /// The classification of the most slender element
+    pub governing: C,
+}
+
+impl<C: Copy + Ord> Classification<C> {
+    fn new(elements: Vec<ElementClassification<C>>) -> Self {
+        let governing = elements
+            .iter()
+            .map(|element| element.class)
+            .max()
+            .expect("every shape has at least one element");
+        Classification {
+            elements,
+            governing,
+        }
+    }
+
+    /// The classification of the given element, if the shape has it
+    pub fn element(&self, element: Element) -> Option<&ElementClassification<C>> {
+        self.elements.iter().find(|e| e.element == element)
+    }
+}
+
+/// Classifies the elements of a shape for local buckling per AISC 360 Section B4.1.
+///
+/// Flexure is classified for bending about the x-axis (the major axis of I-shapes,
+/// channels and HSS, and with the flange or outstanding legs in compression for tees
+/// and double angles), compression for members subject to axial compression.
+pub trait SlendernessClassification: sealed::SlendernessRatios {
+    /// Classifies each element for flexure per AISC 360 Table B4.1b
+    fn flexure_classification(&self, steel: &Steel) -> Classification<FlexureClass> {
+        Classification::new(
+            self.slenderness_ratios()
+                .into_iter()
+                .map(|ratio| ratio.flexure(steel))
+                .collect(),
+        )
+    }
+
+    /// Classifies each element for axial compression per AISC 360 Table B4.1a
+    fn compression_classification(&self, steel: &Steel) -> Classification<CompressionClass> {
+        Classification::new(
+            self.slenderness_ratios()
+                .into_iter()
+                .map(|ratio| ratio.compression(steel))
+                .collect(),
+        )
+    }
+}
+
+impl<T: sealed::SlendernessRatios> SlendernessClassification for T {}
+
+mod sealed {
+    use super::{CompressionCase, Element, FlexureCase};
+
+    /// The width-to-thickness ratio of each element with the cases of
+    /// Table B4.1a (compression) and Table B4.1b (flexure) that apply to it
+    pub trait SlendernessRatios {
+        fn slenderness_ratios(&self) -> Vec<SlendernessRatio>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    /// The width-to-thickness ratio of an element and the cases of Table B4.1 that apply to it
+    pub struct SlendernessRatio {
+        pub(super) element: Element,
+        pub(super) ratio: f64,
+        pub(super) compression: CompressionCase,
+        pub(super) flexure: FlexureCase,
+    }
+}
+
+impl SlendernessRatio {
+    fn new(
+        element: Element,
+        ratio: f64,
+        compression: CompressionCase,
+        flexure: FlexureCase,
+    ) -> Self {
+        SlendernessRatio {
+            element,
+            ratio,
+            compression,
+            flexure,
+        }
+    }
+
+    fn compression(&self, steel: &Steel) -> ElementClassification<CompressionClass> {
+        let lambda_r = self.compression.lambda_r(steel);
+        ElementClassification {
+            element: self.element,
+            case: self.compression as u8,
+            ratio: self.ratio,
+            lambda_p: None,
+            lambda_r,
+            class: if self.ratio <= lambda_r {
+                CompressionClass::Nonslender
+            } else {
+                CompressionClass::Slender
+            },
+        }
+    }
+
+    fn flexure(&self, steel: &Steel) -> ElementClassification<FlexureClass> {
+        let (lambda_p, lambda_r) = self.flexure.limits(steel);
+        ElementClassification {
+            element: self.element,
+            case: self.flexure as u8,
+            ratio: self.ratio,
+            lambda_p: Some(lambda_p),
+            lambda_r,
+            class: if self.ratio <= lambda_p {
+                FlexureClass::Compact
+            } else if self.ratio <= lambda_r {
+                FlexureClass::Noncompact
+            } else {
+                FlexureClass::Slender
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The cases of AISC 360 Table B4.1a that apply to hot-rolled shapes
+enum CompressionCase {
+    /// Flanges of rolled I-shapes, channels and tees, outstanding legs
+    /// of pairs of angles connected with continuous contact
+    RolledFlange = 1,
+    /// Legs of single angles, legs of double angles with separators
+    /// and all other unstiffened elements
+    AngleLeg = 3,
+    /// Stems of tees
+    TeeStem = 4,
+    /// Webs of doubly symmetric rolled I-shapes and channels
+    RolledWeb = 5,
+    /// Walls of rectangular HSS
+    RectangularWall = 6,
+    /// Round HSS
+    RoundWall = 9,
+}
+
+impl CompressionCase {
+    fn lambda_r(self, steel: &Steel) -> f64 {
+        match self {
+            CompressionCase::RolledFlange => 0.56 * steel.root_e_fy(),
+            CompressionCase::AngleLeg => 0.45 * steel.root_e_fy(),
+            CompressionCase::TeeStem => 0.75 * steel.root_e_fy(),
+            CompressionCase::RolledWeb => 1.49 * steel.root_e_fy(),
+            CompressionCase::RectangularWall => 1.40 * steel.root_e_fy(),
+            CompressionCase::RoundWall => 0.11 * steel.e / steel.fy,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The cases of AISC 360 Table B4.1b that apply to hot-rolled shapes
+enum FlexureCase {
+    /// Flanges of rolled I-shapes, channels and tees
+    RolledFlange = 10,
+    /// Legs of single angles
+    AngleLeg = 12,
+    /// Stems of tees
+    TeeStem = 14,
+    /// Webs of doubly symmetric I-shapes and channels
+    DoublySymmetricWeb = 15,
+    /// Flanges of rectangular HSS
+    RectangularFlange = 17,
+    /// Webs of rectangular HSS
+    RectangularWeb = 19,
+    /// Round HSS
+    RoundWall = 20,
+}
+
+impl FlexureCase {
+    /// (λp, λr)
+    fn limits(self, steel: &Steel) -> (f64, f64) {
+        let root_e_fy = steel.root_e_fy();
+        match self {
+            FlexureCase::RolledFlange => (0.38 * root_e_fy, 1.0 * root_e_fy),
+            FlexureCase::AngleLeg => (0.54 * root_e_fy, 0.91 * root_e_fy),
+            FlexureCase::TeeStem => (0.84 * root_e_fy, 1.52 * root_e_fy),
+            FlexureCase::DoublySymmetricWeb => (3.76 * root_e_fy, 5.70 * root_e_fy),
+            FlexureCase::RectangularFlange => (1.12 * root_e_fy, 1.40 * root_e_fy),
+            FlexureCase::RectangularWeb => (2.42 * root_e_fy, 5.70 * root_e_fy),
+            FlexureCase::RoundWall => (0.07 * steel.e / steel.fy, 0.31 * steel.e / steel.fy),
+        }
+    }
+}
+
+/// Implements [SlendernessClassification] for shapes with a flange ratio
+/// and a web or stem ratio
+macro_rules! impl_flanged_classification {
+    ($shape:ident, $flange:ident, $web:ident, $web_element:expr, $web_compression:expr, $web_flexure:expr) => {
+        impl sealed::SlendernessRatios for $shape {
+            fn slenderness_ratios(&self) -> Vec<SlendernessRatio> {
+                vec![
+                    SlendernessRatio::new(
+                        Element::Flange,
+                        self.$flange,
+                        CompressionCase::RolledFlange,
+                        FlexureCase::RolledFlange,
+                    ),
+                    SlendernessRatio::new($web_element, self.$web, $web_compression, $web_flexure),
+                ]
+            }
+        }
+    };
+}
+
+macro_rules! impl_i_shape_classification {
+    ($shape:ident, $flange:ident) => {
+        impl_flanged_classification!(
+            $shape,
+            $flange,
+            h_tw,
+            Element::Web,
+            CompressionCase::RolledWeb,
+            FlexureCase::DoublySymmetricWeb
+        );
+    };
+}
+
+macro_rules! impl_tee_classification {
+    ($shape:ident) => {
+        impl_flanged_classification!(
+            $shape,
+            bf_2tf,
+            d_t,
+            Element::Stem,
+            CompressionCase::TeeStem,
+            FlexureCase::TeeStem
+        );
+    };
+}
+
+impl_i_shape_classification!(WideFlange, bf_2tf);
+impl_i_shape_classification!(MiscBeam, bf_2tf);
+impl_i_shape_classification!(StructuralBeam, bf_2tf);
+impl_i_shape_classification!(HPile, bf_2tf);
+impl_i_shape_classification!(CeeChannel, b_t);
+impl_i_shape_classification!(MiscChannel, b_t);
+impl_tee_classification!(WideFlangeTee);
+impl_tee_classification!(MiscTee);
+impl_tee_classification!(StructuralTee);
+
+/// The ratios of the legs of an angle, the long leg taken from the tabulated b/t
+fn angle_legs(
+    b_t: f64,
+    b: f64,
+    d: f64,
+    t: f64,
+    compression: CompressionCase,
+) -> Vec<SlendernessRatio> {
+    vec![
+        SlendernessRatio::new(Element::LongLeg, b_t, compression, FlexureCase::AngleLeg),
+        SlendernessRatio::new(
+            Element::ShortLeg,
+            b.min(d) / t,
+            compression,
+            FlexureCase::AngleLeg,
+        ),
+    ]
+}
+
+impl sealed::SlendernessRatios for Angle {
+    fn slenderness_ratios(&self) -> Vec<SlendernessRatio> {
+        angle_legs(
+            self.b_t,
+            self.b_lower,
+            self.d_lower,
+            self.t_lower,
+            CompressionCase::AngleLeg,
+        )
+    }
+}
+
+impl sealed::SlendernessRatios for DoubleAngle {
+    /// Both legs of double angles with separators are classified as legs of single angles.
+    /// Angles in continuous contact have their outstanding legs classified as flanges,
+    /// the legs back-to-back remain unstiffened elements.
+    fn slenderness_ratios(&self) -> Vec<SlendernessRatio> {
+        let mut legs = angle_legs(
+            self.b_t,
+            self.b_lower,
+            self.d_lower,
+            self.t_lower,
+            CompressionCase::AngleLeg,
+        );
+        if self.spacing() == 0.0 {
+            // b_lower is the outstanding leg, the long leg unless the short legs are back-to-back
+            let outstanding = if self.b_lower > self.d_lower {
+                Element::LongLeg
+            } else {
+                Element::ShortLeg
+            };
+            for leg in legs.iter_mut().filter(|leg| leg.element == outstanding) {
+                leg.compression = CompressionCase::RolledFlange;
+            }
+        }
+        legs
+    }
+}
+
+impl sealed::SlendernessRatios for HollowStructuralSection {
+    fn slenderness_ratios(&self) -> Vec<SlendernessRatio> {
+        vec![
+            SlendernessRatio::new(
+                Element::Flange,
+                self.b_tdes,
+                CompressionCase::RectangularWall,
+                FlexureCase::RectangularFlange,
+            ),
+            SlendernessRatio::new(
+                Element::Web,
+                self.h_tdes,
+                CompressionCase::RectangularWall,
+                FlexureCase::RectangularWeb,
+            ),
+        ]
+    }
+}
+
+macro_rules! impl_round_classification {
+    ($shape:ident) => {
+        impl sealed::SlendernessRatios for $shape {
+            fn slenderness_ratios(&self) -> Vec<SlendernessRatio> {
+                vec![SlendernessRatio::new(
+                    Element::Wall,
+                    self.d_t,
+                    CompressionCase::RoundWall,
+                    FlexureCase::RoundWall,
+                )]
+            }
+        }
+    };
+}
+
+impl_round_classification!(RoundHollowStructuralSection);
+impl_round_classification!(Pipe);
+
+impl sealed::SlendernessRatios for AiscShape {
+    fn slenderness_ratios(&self) -> Vec<SlendernessRatio> {
+        match self {
+            AiscShape::WideFlange(shape) => shape.slenderness_ratios(),
+            AiscShape::MiscBeam(shape) => shape.slenderness_ratios(),
+            AiscShape::StructuralBeam(shape) => shape.slenderness_ratios(),
+            AiscShape::HPile(shape) => shape.slenderness_ratios(),
+            AiscShape::CeeChannel(shape) => shape.slenderness_ratios(),
+            AiscShape::MiscChannel(shape) => shape.slenderness_ratios(),
+            AiscShape::Angle(shape) => shape.slenderness_ratios(),
+            AiscShape::WideFlangeTee(shape) => shape.slenderness_ratios(),
+            AiscShape::MiscTee(shape) => shape.slenderness_ratios(),
+            AiscShape::StructuralTee(shape) => shape.slenderness_ratios(),
+            AiscShape::DoubleAngle(shape) => shape.slenderness_ratios(),
+            AiscShape::HollowStructuralSection(shape) => shape.slenderness_ratios(),
+            AiscShape::RoundHollowStructuralSection(shape) => shape.slenderness_ratios(),
+            AiscShape::Pipe(shape) => shape.slenderness_ratios(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::ShapeProperty;
+    use crate::aisc_shapes::test_support::shape;
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() < 0.01,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    fn i_shape(label: &str, bf_2tf: f64, h_tw: f64) -> WideFlange {
+        shape(
+            label,
+            &[(ShapeProperty::Bf2tf, bf_2tf), (ShapeProperty::HTw, h_tw)],
+        )
+    }
+
+    fn double_angle(label: &str) -> DoubleAngle {
+        shape(
+            label,
+            &[
+                (ShapeProperty::DLower, 5.0),
+                (ShapeProperty::BLower, 3.0),
+                (ShapeProperty::TLower, 0.3125),
+                (ShapeProperty::BT, 16.0),
+            ],
+        )
+    }
+
+    #[test]
+    fn w14x90_has_noncompact_flanges_in_flexure() {
+        let w14x90 = i_shape("W14X90", 10.2, 25.9);
+        let flexure = w14x90.flexure_classification(&Steel::A992);
+        assert_eq!(FlexureClass::Noncompact, flexure.governing);
+
+        let flange = flexure.element(Element::Flange).unwrap();
+        assert_eq!(10, flange.case);
+        assert_eq!(10.2, flange.ratio);
+        assert_close(9.15, flange.lambda_p.unwrap());
+        assert_close(24.08, flange.lambda_r);
+        assert_eq!(FlexureClass::Noncompact, flange.class);
+
+        let web = flexure.element(Element::Web).unwrap();
+        assert_eq!(15, web.case);
+        assert_close(90.55, web.lambda_p.unwrap());
+        assert_close(137.27, web.lambda_r);
+        assert_eq!(FlexureClass::Compact, web.class);
+
+        let compression = w14x90.compression_classification(&Steel::A992);
+        assert_eq!(CompressionClass::Nonslender, compression.governing);
+        let flange = compression.element(Element::Flange).unwrap();
+        assert_eq!(1, flange.case);
+        assert_eq!(None, flange.lambda_p);
+        assert_close(13.49, flange.lambda_r);
+    }
+
+    #[test]
+    fn w21x48_has_a_slender_web_in_compression() {
+        let w21x48 = i_shape("W21X48", 9.47, 53.6);
+        let compression = w21x48.compression_classification(&Steel::A992);
+        assert_eq!(CompressionClass::Slender, compression.governing);
+        let web = compression.element(Element::Web).unwrap();
+        assert_eq!(5, web.case);
+        assert_close(35.88, web.lambda_r);
+        assert_eq!(CompressionClass::Slender, web.class);
+        assert_eq!(
+            CompressionClass::Nonslender,
+            compression.element(Element::Flange).unwrap().class
+        );
+    }
+
+    #[test]
+    fn limits_depend_on_the_yield_stress() {
+        let w14x90 = i_shape("W14X90", 10.2, 25.9);
+        let a36 = w14x90.flexure_classification(&Steel::A36);
+        assert_eq!(FlexureClass::Compact, a36.governing);
+        assert_close(
+            10.79,
+            a36.element(Element::Flange).unwrap().lambda_p.unwrap(),
+        );
+    }
+
+    #[test]
+    fn tees_classify_their_stems() {
+        let wt12x27_5: WideFlangeTee = shape(
+            "WT12X27.5",
+            &[(ShapeProperty::Bf2tf, 5.04), (ShapeProperty::DT, 30.4)],
+        );
+        let compression = wt12x27_5.compression_classification(&Steel::A992);
+        let stem = compression.element(Element::Stem).unwrap();
+        assert_eq!(4, stem.case);
+        assert_close(18.06, stem.lambda_r);
+        assert_eq!(CompressionClass::Slender, compression.governing);
+        assert!(compression.element(Element::Web).is_none());
+
+        let flexure = wt12x27_5.flexure_classification(&Steel::A992);
+        let stem = flexure.element(Element::Stem).unwrap();
+        assert_eq!(14, stem.case);
+        assert_eq!(FlexureClass::Noncompact, stem.class);
+    }
+
+    #[test]
+    fn angles_classify_both_legs() {
+        let l4x4x1_4: Angle = shape(
+            "L4X4X1/4",
+            &[
+                (ShapeProperty::DLower, 4.0),
+                (ShapeProperty::BLower, 4.0),
+                (ShapeProperty::TLower, 0.25),
+                (ShapeProperty::BT, 16.0),
+            ],
+        );
+        let compression = l4x4x1_4.compression_classification(&Steel::A36);
+        assert_eq!(2, compression.elements.len());
+        for leg in &compression.elements {
+            assert_eq!(3, leg.case);
+            assert_eq!(16.0, leg.ratio);
+            assert_close(12.77, leg.lambda_r);
+        }
+        assert_eq!(CompressionClass::Slender, compression.governing);
+
+        let flexure = l4x4x1_4.flexure_classification(&Steel::A36);
+        let leg = flexure.element(Element::LongLeg).unwrap();
+        assert_eq!(12, leg.case);
+        assert_close(15.32, leg.lambda_p.unwrap());
+        assert_close(25.83, leg.lambda_r);
+        assert_eq!(FlexureClass::Noncompact, flexure.governing);
+    }
+
+    #[test]
+    fn double_angles_in_contact_classify_outstanding_legs_as_flanges() {
+        let in_contact = double_angle("2L5X3X5/16LLBB").compression_classification(&Steel::A36);
+        let outstanding = in_contact.element(Element::ShortLeg).unwrap();
+        assert_eq!(1, outstanding.case);
+        assert_eq!(9.6, outstanding.ratio);
+        assert_eq!(3, in_contact.element(Element::LongLeg).unwrap().case);
+
+        let separated = double_angle("2L5X3X5/16X3/8LLBB").compression_classification(&Steel::A36);
+        for leg in &separated.elements {
+            assert_eq!(3, leg.case);
+        }
+    }
+
+    #[test]
+    fn hss_walls_are_classified_as_flanges_and_webs() {
+        let hss8x4x1_8: HollowStructuralSection = shape(
+            "HSS8X4X1/8",
+            &[(ShapeProperty::BTdes, 31.5), (ShapeProperty::HTdes, 66.0)],
+        );
+        let compression = hss8x4x1_8.compression_classification(&Steel::A500_GR_C_RECTANGULAR);
+        assert_eq!(
+            CompressionClass::Nonslender,
+            compression.element(Element::Flange).unwrap().class
+        );
+        assert_eq!(
+            CompressionClass::Slender,
+            compression.element(Element::Web).unwrap().class
+        );
+
+        let flexure = hss8x4x1_8.flexure_classification(&Steel::A500_GR_C_RECTANGULAR);
+        let flange = flexure.element(Element::Flange).unwrap();
+        assert_eq!(17, flange.case);
+        assert_eq!(FlexureClass::Noncompact, flange.class);
+        let web = flexure.element(Element::Web).unwrap();
+        assert_eq!(19, web.case);
+        assert_eq!(FlexureClass::Noncompact, web.class);
+        assert_eq!(FlexureClass::Noncompact, flexure.governing);
+    }
+
+    #[test]
+    fn round_walls_are_limited_by_e_over_fy() {
+        let pipe: AiscShape = shape::<Pipe>("Pipe12STD", &[(ShapeProperty::DT, 36.5)]).into();
+        let compression = pipe.compression_classification(&Steel::A53_GR_B);
+        let wall = compression.element(Element::Wall).unwrap();
+        assert_eq!(9, wall.case);
+        assert_close(91.14, wall.lambda_r);
+
+        let flexure = pipe.flexure_classification(&Steel::A53_GR_B);
+        let wall = flexure.element(Element::Wall).unwrap();
+        assert_eq!(20, wall.case);
+        assert_close(58.0, wall.lambda_p.unwrap());
+        assert_close(256.86, wall.lambda_r);
+        assert_eq!(FlexureClass::Compact, flexure.governing);
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn w_shapes_with_noncompact_flanges_match_the_manual() {
+        use crate::aisc_shapes::SectionProperties;
+
+        let noncompact: Vec<&str> = crate::aisc_shapes::aisc_database::snapshot()
+            .iter()
+            .filter(|shape| matches!(shape, AiscShape::WideFlange(_)))
+            .filter(|shape| {
+                shape.flexure_classification(&Steel::A992).governing == FlexureClass::Noncompact
+            })
+            .map(|shape| shape.aisc_manual_label())
+            .collect();
+        assert_eq!(
+            vec![
+                "W21X48", "W14X99", "W14X90", "W12X65", "W10X12", "W8X31", "W8X10", "W6X15",
+                "W6X9", "W6X8.5"
+            ],
+            noncompact
+        );
+    }
+}
@@ -0,0 +1,572 @@
+use crate::aisc_shapes::{InvalidDesignationError, ShapeFamily};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The legs of double angles placed back-to-back
+pub enum BackToBack {
+    /// Long legs back-to-back (`LLBB`)
+    LongLegs,
+    /// Short legs back-to-back (`SLBB`)
+    ShortLegs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The wall thickness of pipe, as a weight class or a schedule number
+pub enum PipeWeight {
+    /// Standard weight (`STD`)
+    Standard,
+    /// Extra strong (`XS`)
+    ExtraStrong,
+    /// Double-extra strong (`XXS`)
+    DoubleExtraStrong,
+    /// Schedule number (e.g. `SCH40`), used by the EDI Std Nomenclature
+    Schedule(u16),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "snake_case"))]
+/// The structured form of the designation of a shape, e.g. the AISC Manual Label
+/// `L4X4X1/2` is an angle with 4 in. legs and a thickness of 1/2 in.
+///
+/// Designations are parsed regardless of case and whitespace, with dimensions written
+/// as decimals or as fractions, so `w14x90`, `W 14 x 90`, `L4X4X.5` and `L4X4X1/2`
+/// are all parsed, and printed in their canonical form:
+///
+/// ```
+/// use shapes::aisc_shapes::Designation;
+///
+/// let designation = "l 4 x 4 x .5".parse::<Designation>().unwrap();
+/// assert_eq!("L4X4X1/2", designation.aisc_manual_label());
+/// let designation = "HSS6X4X1/4".parse::<Designation>().unwrap();
+/// assert_eq!("HSS6X4X.250", designation.edi_std_nomenclature());
+/// ```
+pub enum Designation {
+    /// W, M, S, HP, C, MC, WT, MT and ST shapes, designated by their
+    /// nominal depth, in., and nominal weight, lb/ft (e.g. `W14X90`)
+    Rolled {
+        /// The family of the shape
+        family: ShapeFamily,
+        /// Nominal depth, in.
+        depth: f64,
+        /// Nominal weight, lb/ft
+        weight: f64,
+    },
+    /// Single angles (e.g. `L4X3X1/2`)
+    Angle {
+        /// Length of the longer leg, in.
+        long_leg: f64,
+        /// Length of the shorter leg, in.
+        short_leg: f64,
+        /// Thickness of the legs, in.
+        thickness: f64,
+    },
+    /// Double angles, with the spacing between the angles and,
+    /// for unequal legs, the legs placed back-to-back (e.g. `2L4X3X1/2X3/8LLBB`)
+    DoubleAngle {
+        /// Length of the longer leg, in.
+        long_leg: f64,
+        /// Length of the shorter leg, in.
+        short_leg: f64,
+        /// Thickness of the legs, in.
+        thickness: f64,
+        /// Spacing between the angles, in., zero when the angles are in contact
+        spacing: f64,
+        /// The legs placed back-to-back, none for equal legs
+        back_to_back: Option<BackToBack>,
+    },
+    /// Square and rectangular HSS (e.g. `HSS6X4X1/4`)
+    RectangularHss {
+        /// Nominal height, in.
+        height: f64,
+        /// Nominal width, in.
+        width: f64,
+        /// Nominal wall thickness, in.
+        thickness: f64,
+    },
+    /// Round HSS (e.g. `HSS6.625X0.500`)
+    RoundHss {
+        /// Outside diameter, in.
+        diameter: f64,
+        /// Nominal wall thickness, in.
+        thickness: f64,
+    },
+    /// Pipe (e.g. `Pipe2-1/2STD`)
+    Pipe {
+        /// Nominal pipe size, in.
+        size: f64,
+        /// Weight class or schedule
+        weight: PipeWeight,
+    },
+}
+
+impl Designation {
+    /// The family of shapes the designation belongs to
+    pub fn family(&self) -> ShapeFamily {
+        match self {
+            Designation::Rolled { family, .. } => *family,
+            Designation::Angle { .. } => ShapeFamily::Angle,
+            Designation::DoubleAngle { .. } => ShapeFamily::DoubleAngle,
+            Designation::RectangularHss { .. } => ShapeFamily::HollowStructuralSection,
+            Designation::RoundHss { .. } => ShapeFamily::RoundHollowStructuralSection,
+            Designation::Pipe { .. } => ShapeFamily::Pipe,
+        }
+    }
+
+    /// The designation in the form of the AISC Manual Label, e.g. `HSS6X4X1/4` or `HSS6.625X0.500`
+    pub fn aisc_manual_label(&self) -> String {
+        match self {
+            Designation::RectangularHss {
+                height,
+                width,
+                thickness,
+            } => format!(
+                "HSS{}X{}X{}",
+                fraction(*height),
+                fraction(*width),
+                fraction(*thickness)
+            ),
+            Designation::RoundHss {
+                diameter,
+                thickness,
+            } => format!("HSS{diameter:.3}X{thickness:.3}"),
+            _ => self.to_string(),
+        }
+    }
+
+    /// The designation in the form of the EDI Std Nomenclature, e.g. `HSS6X4X.250` or `HSS6.625X.500`
+    pub fn edi_std_nomenclature(&self) -> String {
+        match self {
+            Designation::RectangularHss {
+                height,
+                width,
+                thickness,
+            } => format!(
+                "HSS{}X{}X{}",
+                fraction(*height),
+                fraction(*width),
+                if thickness.fract() == 0.0 {
+                    decimal(*thickness)
+                } else {
+                    thousandths(*thickness)
+                }
+            ),
+            Designation::RoundHss {
+                diameter,
+                thickness,
+            } => format!("HSS{}X{}", decimal(*diameter), thousandths(*thickness)),
+            _ => self.to_string(),
+        }
+    }
+
+    /// The canonical AISC Manual Label of the designation,
+    /// or the designation as given when it cannot be parsed
+    pub fn normalize_aisc_manual_label(designation: &str) -> String {
+        designation
+            .parse::<Designation>()
+            .map_or_else(|_| String::from(designation), |d| d.aisc_manual_label())
+    }
+
+    /// The canonical EDI Std Nomenclature of the designation,
+    /// or the designation as given when it cannot be parsed
+    pub fn normalize_edi_std_nomenclature(designation: &str) -> String {
+        designation
+            .parse::<Designation>()
+            .map_or_else(|_| String::from(designation), |d| d.edi_std_nomenclature())
+    }
+}
+
+impl fmt::Display for Designation {
+    /// Prints the designation in the form shared by the AISC Manual Label
+    /// and EDI Std Nomenclature, HSS are printed as their AISC Manual Label
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Designation::Rolled {
+                family,
+                depth,
+                weight,
+            } => write!(
+                f,
+                "{}{}X{}",
+                family.shape_type(),
+                decimal(*depth),
+                decimal(*weight)
+            ),
+            Designation::Angle {
+                long_leg,
+                short_leg,
+                thickness,
+            } => write!(
+                f,
+                "L{}X{}X{}",
+                fraction(*long_leg),
+                fraction(*short_leg),
+                fraction(*thickness)
+            ),
+            Designation::DoubleAngle {
+                long_leg,
+                short_leg,
+                thickness,
+                spacing,
+                back_to_back,
+            } => {
+                write!(
+                    f,
+                    "2L{}X{}X{}",
+                    fraction(*long_leg),
+                    fraction(*short_leg),
+                    fraction(*thickness)
+                )?;
+                if *spacing > 0.0 {
+                    write!(f, "X{}", fraction(*spacing))?;
+                }
+                match back_to_back {
+                    Some(BackToBack::LongLegs) => write!(f, "LLBB"),
+                    Some(BackToBack::ShortLegs) => write!(f, "SLBB"),
+                    None => Ok(()),
+                }
+            }
+            Designation::RectangularHss { .. } | Designation::RoundHss { .. } => {
+                write!(f, "{}", self.aisc_manual_label())
+            }
+            Designation::Pipe { size, weight } => {
+                write!(f, "Pipe{}", fraction(*size))?;
+                match weight {
+                    PipeWeight::Standard => write!(f, "STD"),
+                    PipeWeight::ExtraStrong => write!(f, "XS"),
+                    PipeWeight::DoubleExtraStrong => write!(f, "XXS"),
+                    PipeWeight::Schedule(schedule) => write!(f, "SCH{schedule}"),
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Designation {
+    type Err = InvalidDesignationError;
+    fn from_str(designation: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidDesignationError {
+            designation: String::from(designation),
+        };
+        let normalized = join_mixed_numbers(designation)
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| if c == '×' { 'X' } else { c })
+            .collect::<String>()
+            .to_uppercase();
+        let family = ShapeFamily::from_designation(&normalized).ok_or_else(invalid)?;
+        let body = &normalized[family.shape_type().len()..];
+
+        let parsed = match family {
+            ShapeFamily::Pipe => parse_pipe(body),
+            ShapeFamily::DoubleAngle => parse_double_angle(body),
+            _ => {
+                let dimensions = dimensions(body);
+                match (family, dimensions.as_deref()) {
+                    (ShapeFamily::Angle, Some(&[a, b, thickness])) => Some(Designation::Angle {
+                        long_leg: a.max(b),
+                        short_leg: a.min(b),
+                        thickness,
+                    }),
+                    (ShapeFamily::HollowStructuralSection, Some(&[height, width, thickness])) => {
+                        Some(Designation::RectangularHss {
+                            height,
+                            width,
+                            thickness,
+                        })
+                    }
+                    (ShapeFamily::RoundHollowStructuralSection, Some(&[diameter, thickness])) => {
+                        Some(Designation::RoundHss {
+                            diameter,
+                            thickness,
+                        })
+                    }
+                    (
+                        ShapeFamily::Angle
+                        | ShapeFamily::HollowStructuralSection
+                        | ShapeFamily::RoundHollowStructuralSection,
+                        _,
+                    ) => None,
+                    (family, Some(&[depth, weight])) => Some(Designation::Rolled {
+                        family,
+                        depth,
+                        weight,
+                    }),
+                    _ => None,
+                }
+            }
+        };
+        parsed.ok_or_else(invalid)
+    }
+}
+
+/// Parses the legs, thickness, optional spacing and optional
+/// `LLBB` or `SLBB` suffix of a double angle
+fn parse_double_angle(body: &str) -> Option<Designation> {
+    let (body, back_to_back) = if let Some(body) = body.strip_suffix("LLBB") {
+        (body, Some(BackToBack::LongLegs))
+    } else if let Some(body) = body.strip_suffix("SLBB") {
+        (body, Some(BackToBack::ShortLegs))
+    } else {
+        (body, None)
+    };
+    let (a, b, thickness, spacing) = match *dimensions(body)?.as_slice() {
+        [a, b, thickness] => (a, b, thickness, 0.0),
+        [a, b, thickness, spacing] => (a, b, thickness, spacing),
+        _ => return None,
+    };
+    Some(Designation::DoubleAngle {
+        long_leg: a.max(b),
+        short_leg: a.min(b),
+        thickness,
+        spacing,
+        back_to_back: back_to_back.filter(|_| a != b),
+    })
+}
+
+/// Parses the nominal size and weight class or schedule of a pipe, e.g. `2-1/2XS`
+fn parse_pipe(body: &str) -> Option<Designation> {
+    let (size, weight) = if let Some(size) = body.strip_suffix("XXS") {
+        (size, PipeWeight::DoubleExtraStrong)
+    } else if let Some(size) = body.strip_suffix("XS") {
+        (size, PipeWeight::ExtraStrong)
+    } else if let Some(size) = body.strip_suffix("STD") {
+        (size, PipeWeight::Standard)
+    } else {
+        let (size, schedule) = body.rsplit_once("SCH")?;
+        (size, PipeWeight::Schedule(schedule.parse().ok()?))
+    };
+    Some(Designation::Pipe {
+        size: dimension(size)?,
+        weight,
+    })
+}
+
+/// Parses the dimensions separated by `X`
+fn dimensions(body: &str) -> Option<Vec<f64>> {
+    body.split('X').map(dimension).collect()
+}
+
+/// Parses a positive dimension written as a decimal (`0.5`, `.5`),
+/// a fraction (`1/2`) or a mixed number (`1-1/2`)
+fn dimension(text: &str) -> Option<f64> {
+    let value = match text.split_once('/') {
+        Some((whole_numerator, denominator)) => {
+            let (whole, numerator) = match whole_numerator.split_once('-') {
+                Some((whole, numerator)) => (whole.parse::<u32>().ok()?, numerator),
+                None => (0, whole_numerator),
+            };
+            let numerator = numerator.parse::<u32>().ok()?;
+            let denominator = denominator.parse::<u32>().ok().filter(|d| *d > 0)?;
+            f64::from(whole) + f64::from(numerator) / f64::from(denominator)
+        }
+        None if text.starts_with(|c: char| c.is_ascii_digit() || c == '.') => {
+            text.parse::<f64>().ok()?
+        }
+        None => return None,
+    };
+    (value.is_finite() && value > 0.0).then_some(value)
+}
+
+/// Writes the whitespace separating the whole number from the fraction
+/// of mixed numbers as a hyphen, so that `1 1/2` is read as `1-1/2`
+fn join_mixed_numbers(designation: &str) -> String {
+    let mut joined = String::with_capacity(designation.len());
+    let mut chars = designation.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        joined.push(c);
+        if !c.is_ascii_digit() {
+            continue;
+        }
+        let rest = &designation[index + c.len_utf8()..];
+        let next = rest.trim_start();
+        let is_fraction = next
+            .split(|c: char| !c.is_ascii_digit())
+            .next()
+            .is_some_and(|numerator| {
+                !numerator.is_empty() && next[numerator.len()..].starts_with('/')
+            });
+        if rest.len() != next.len() && is_fraction {
+            joined.push('-');
+            while chars.peek().is_some_and(|(_, c)| c.is_whitespace()) {
+                chars.next();
+            }
+        }
+    }
+    joined
+}
+
+/// Prints a dimension as a mixed number in sixteenths of an inch, e.g. `1-1/2`,
+/// or as a decimal when it is not a multiple of 1/16 in.
+fn fraction(value: f64) -> String {
+    let sixteenths = (value * 16.0).round();
+    if (value * 16.0 - sixteenths).abs() > 1e-6 {
+        return decimal(value);
+    }
+    let sixteenths = sixteenths as u64;
+    let (whole, mut numerator, mut denominator) = (sixteenths / 16, sixteenths % 16, 16);
+    while numerator > 0 && numerator % 2 == 0 {
+        numerator /= 2;
+        denominator /= 2;
+    }
+    match (whole, numerator) {
+        (whole, 0) => whole.to_string(),
+        (0, numerator) => format!("{numerator}/{denominator}"),
+        (whole, numerator) => format!("{whole}-{numerator}/{denominator}"),
+    }
+}
+
+/// Prints a dimension as a decimal without trailing zeros, e.g. `12.5`
+fn decimal(value: f64) -> String {
+    ((value * 1e4).round() / 1e4).to_string()
+}
+
+/// Prints a thickness to the thousandth of an inch without the leading zero, e.g. `.313`
+fn thousandths(value: f64) -> String {
+    let text = format!("{:.3}", (value * 1000.0).round() / 1000.0);
+    match text.strip_prefix('0') {
+        Some(text) => String::from(text),
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(designation: &str) -> String {
+        designation
+            .parse::<Designation>()
+            .unwrap()
+            .aisc_manual_label()
+    }
+
+    #[test]
+    fn case_and_whitespace_are_ignored() {
+        for designation in ["w14x90", "W 14 x 90", " W14X90.0 ", "W14×90"] {
+            assert_eq!("W14X90", label(designation));
+        }
+        assert_eq!("WT22X167.5", label("wt22 x 167.50"));
+        assert_eq!("MC18X51.9", label("mc18x51.9"));
+        assert_eq!(
+            Designation::Rolled {
+                family: ShapeFamily::MiscTee,
+                depth: 6.25,
+                weight: 6.2
+            },
+            "MT6.25X6.2".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn angle_thicknesses_are_written_as_fractions() {
+        for designation in ["L4X4X.5", "L4x4x1/2", "L 4 X 4 X 0.500", "l4x4x8/16"] {
+            assert_eq!("L4X4X1/2", label(designation));
+        }
+        assert_eq!("L12X12X1-3/8", label("L12X12X1 3/8"));
+        assert_eq!("L6X3-1/2X5/16", label("L6X3.5X.3125"));
+        assert_eq!("L6X3-1/2X5/16", label("L3-1/2X6X5/16"));
+    }
+
+    #[test]
+    fn double_angles_keep_their_spacing_and_orientation() {
+        let designation = "2L4x3-1/2x3/8 x 3/4 llbb".parse::<Designation>().unwrap();
+        assert_eq!(
+            Designation::DoubleAngle {
+                long_leg: 4.0,
+                short_leg: 3.5,
+                thickness: 0.375,
+                spacing: 0.75,
+                back_to_back: Some(BackToBack::LongLegs)
+            },
+            designation
+        );
+        assert_eq!("2L4X3-1/2X3/8X3/4LLBB", designation.to_string());
+        assert_eq!("2L8X8X1X3/4", label("2L8X8X1X.75"));
+        assert_eq!("2L2X2X3/16", label("2l2x2x3/16"));
+    }
+
+    #[test]
+    fn hss_thicknesses_differ_between_label_and_edi_name() {
+        let rectangular = "HSS34X10X.875".parse::<Designation>().unwrap();
+        assert_eq!("HSS34X10X7/8", rectangular.aisc_manual_label());
+        assert_eq!("HSS34X10X.875", rectangular.edi_std_nomenclature());
+        let rectangular = "hss3x3x5/16".parse::<Designation>().unwrap();
+        assert_eq!("HSS3X3X.313", rectangular.edi_std_nomenclature());
+        assert_eq!(
+            "HSS3X3X5/16",
+            Designation::normalize_aisc_manual_label("HSS3X3X.3125")
+        );
+        assert_eq!("HSS34X10X1", label("HSS34X10X1.000"));
+
+        let round = "HSS1.9X.145".parse::<Designation>().unwrap();
+        assert_eq!("HSS1.900X0.145", round.aisc_manual_label());
+        assert_eq!("HSS1.9X.145", round.edi_std_nomenclature());
+        assert_eq!("HSS14.000X0.250", label("HSS14X1/4"));
+    }
+
+    #[test]
+    fn pipes_have_weight_classes_or_schedules() {
+        assert_eq!("Pipe2STD", label("PIPE 2 STD"));
+        assert_eq!("Pipe2-1/2XXS", label("pipe2.5xxs"));
+        assert_eq!("Pipe1/2XS", label("Pipe1/2XS"));
+        assert_eq!(
+            Designation::Pipe {
+                size: 24.0,
+                weight: PipeWeight::Schedule(20)
+            },
+            "Pipe24SCH20".parse().unwrap()
+        );
+        assert!("Pipe24".parse::<Designation>().is_err());
+    }
+
+    #[test]
+    fn invalid_designations_are_rejected() {
+        for designation in [
+            "",
+            "W14",
+            "W14X90X2",
+            "X14X90",
+            "L4X4",
+            "HSS6X4X3X2",
+            "W14X-90",
+            "L4X4X1/0",
+        ] {
+            let err = designation.parse::<Designation>().unwrap_err();
+            assert_eq!(designation, err.designation);
+        }
+        assert_eq!("w14", Designation::normalize_aisc_manual_label("w14"));
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn every_designation_in_the_database_is_canonical() {
+        use crate::aisc_shapes::SectionProperties;
+        // EDI names of the database written differently from the rest of their family
+        let irregular = [
+            "HSS3-1/2X3-1/2X188",
+            "HSS1-1/2X1-1/2X0.250",
+            "HSS1-1/2X1-1/2X0.188",
+            "HSS1-1/2X1-1/2X0.125",
+        ];
+        for shape in crate::aisc_shapes::aisc_database::snapshot() {
+            let label = shape.aisc_manual_label();
+            let designation = label.parse::<Designation>().unwrap();
+            assert_eq!(shape.family(), designation.family(), "{label}");
+            assert_eq!(label, designation.aisc_manual_label());
+            let edi = shape.edi_std_nomenclature();
+            if irregular.contains(&edi) {
+                continue;
+            }
+            assert_eq!(edi, Designation::normalize_edi_std_nomenclature(edi));
+            if shape.family() != ShapeFamily::Pipe {
+                assert_eq!(edi, designation.edi_std_nomenclature(), "{label}");
+            }
+        }
+    }
+}
@@ -0,0 +1,439 @@
+use crate::aisc_shapes::{
+    AiscShape, Designation, MetricDesignation, SectionProperties, ShapeFamily,
+};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+/// How a designation matches the searched text, from the closest to the loosest match
+pub enum MatchKind {
+    /// The designation is the searched one, ignoring case, whitespace and notation
+    Exact,
+    /// The designation starts with the searched text, e.g. W14X90 for W14
+    Prefix,
+    /// The designation is within a few edits of the searched text, e.g. W14X90 for W14X91
+    Fuzzy,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A shape whose AISC Manual Label or EDI Std Nomenclature matches a [DesignationSearch]
+pub struct DesignationMatch {
+    /// The family of the shape
+    pub family: ShapeFamily,
+    /// The AISC Manual Label of the shape
+    pub aisc_manual_label: String,
+    /// The EDI Std Nomenclature of the shape
+    pub edi_std_nomenclature: String,
+    /// How the closer of the two designations matches the searched text
+    pub kind: MatchKind,
+    /// The number of characters following the searched text for prefix matches,
+    /// the number of characters inserted, removed or substituted for fuzzy matches
+    pub distance: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A search for shapes by partial or misspelled designations, e.g. "W14" or "HSS6X",
+/// ranking exact matches first, then prefix matches and then fuzzy matches
+pub struct DesignationSearch {
+    /// The searched text, compared ignoring case and whitespace
+    pub text: String,
+    /// The largest edit distance of fuzzy matches, zero to only match prefixes
+    pub max_distance: usize,
+    /// The largest number of matches returned, all of them when `None`
+    pub limit: Option<usize>,
+}
+
+/// The number of matches returned by a search when no limit is given
+pub const DEFAULT_SEARCH_LIMIT: usize = 10;
+
+/// The number of designations suggested when an exact lookup fails
+pub const DEFAULT_SUGGESTIONS: usize = 3;
+
+impl DesignationSearch {
+    /// Creates a search for the text, allowing an edit distance of about
+    /// one per four characters (between 1 and 3) and returning
+    /// at most [DEFAULT_SEARCH_LIMIT] matches
+    pub fn new(text: &str) -> Self {
+        let length = compact(text).chars().count();
+        DesignationSearch {
+            text: String::from(text),
+            max_distance: ((length + 2) / 4).clamp(1, 3),
+            limit: Some(DEFAULT_SEARCH_LIMIT),
+        }
+    }
+
+    /// Creates a search for the designations to suggest when no shape
+    /// has the designation, returning at most [DEFAULT_SUGGESTIONS] matches
+    pub fn suggestions_for(designation: &str) -> Self {
+        DesignationSearch::new(designation).with_limit(DEFAULT_SUGGESTIONS)
+    }
+
+    /// Sets the largest edit distance of fuzzy matches
+    pub fn with_max_distance(mut self, max_distance: usize) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Sets the largest number of matches returned
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns every match instead of the closest ones
+    pub fn without_limit(mut self) -> Self {
+        self.limit = None;
+        self
+    }
+
+    /// How the shape with the designations matches the search, none when it does not
+    pub fn match_designations(
+        &self,
+        family: ShapeFamily,
+        aisc_manual_label: &str,
+        edi_std_nomenclature: &str,
+    ) -> Option<DesignationMatch> {
+        self.match_searched_text(
+            &SearchedText::new(&self.text),
+            family,
+            aisc_manual_label,
+            edi_std_nomenclature,
+        )
+    }
+
+    /// [DesignationSearch::match_designations] with the searched text
+    /// prepared once for every shape ranked
+    fn match_searched_text(
+        &self,
+        searched: &SearchedText,
+        family: ShapeFamily,
+        aisc_manual_label: &str,
+        edi_std_nomenclature: &str,
+    ) -> Option<DesignationMatch> {
+        let text = &searched.compact;
+        if text.is_empty() {
+            return None;
+        }
+        let (kind, distance) = [aisc_manual_label, edi_std_nomenclature]
+            .into_iter()
+            .filter_map(|designation| {
+                let designation = compact(designation);
+                if designation == *text || searched.normalized.contains(&designation) {
+                    Some((MatchKind::Exact, 0))
+                } else if designation.starts_with(text.as_str()) {
+                    let remaining = designation.chars().count() - searched.length;
+                    Some((MatchKind::Prefix, remaining))
+                } else {
+                    let distance = edit_distance(text, &designation, self.max_distance)?;
+                    Some((MatchKind::Fuzzy, distance))
+                }
+            })
+            .min()?;
+        Some(DesignationMatch {
+            family,
+            aisc_manual_label: String::from(aisc_manual_label),
+            edi_std_nomenclature: String::from(edi_std_nomenclature),
+            kind,
+            distance,
+        })
+    }
+
+    /// Ranks the designations matching the search, given as the family,
+    /// AISC Manual Label and EDI Std Nomenclature of each shape.
+    /// Matches of the same kind and distance are ranked by the length of the text
+    /// they share with the search from the start, then by AISC Manual Label.
+    pub fn rank<'a, I>(&self, designations: I) -> Vec<DesignationMatch>
+    where
+        I: IntoIterator<Item = (ShapeFamily, &'a str, &'a str)>,
+    {
+        self.rank_with_metric(designations, &[])
+    }
+
+    /// Ranks the designations matching the search as [DesignationSearch::rank], also
+    /// matching the metric designations of the shapes. A shape matched by its metric
+    /// designation is reported with its US customary designations, once per shape.
+    pub fn rank_with_metric<'a, I>(
+        &self,
+        designations: I,
+        metric_designations: &[MetricDesignation],
+    ) -> Vec<DesignationMatch>
+    where
+        I: IntoIterator<Item = (ShapeFamily, &'a str, &'a str)>,
+    {
+        let searched = SearchedText::new(&self.text);
+        let mut matches: Vec<DesignationMatch> = designations
+            .into_iter()
+            .filter_map(|(family, label, edi)| {
+                self.match_searched_text(&searched, family, label, edi)
+            })
+            .collect();
+        let mut shapes: HashMap<String, usize> = matches
+            .iter()
+            .enumerate()
+            .map(|(index, m)| (m.aisc_manual_label.clone(), index))
+            .collect();
+        for metric in metric_designations {
+            let Some(family) = ShapeFamily::from_designation(&metric.aisc_manual_label) else {
+                continue;
+            };
+            let Some(metric_match) = self.match_searched_text(
+                &searched,
+                family,
+                &metric.metric_aisc_manual_label,
+                &metric.metric_edi_std_nomenclature,
+            ) else {
+                continue;
+            };
+            let metric_match = DesignationMatch {
+                aisc_manual_label: metric.aisc_manual_label.clone(),
+                edi_std_nomenclature: metric.edi_std_nomenclature.clone(),
+                ..metric_match
+            };
+            match shapes.get(&metric.aisc_manual_label) {
+                Some(&index) => {
+                    let m = &mut matches[index];
+                    if (metric_match.kind, metric_match.distance) < (m.kind, m.distance) {
+                        *m = metric_match;
+                    }
+                }
+                None => {
+                    shapes.insert(metric.aisc_manual_label.clone(), matches.len());
+                    matches.push(metric_match);
+                }
+            }
+        }
+        let text = &searched.compact;
+        matches.sort_by_cached_key(|m| {
+            let shared = common_prefix(text, &compact(&m.aisc_manual_label))
+                .max(common_prefix(text, &compact(&m.edi_std_nomenclature)));
+            (
+                m.kind,
+                m.distance,
+                Reverse(shared),
+                m.aisc_manual_label.clone(),
+            )
+        });
+        if let Some(limit) = self.limit {
+            matches.truncate(limit);
+        }
+        matches
+    }
+
+    /// Ranks the shapes whose designations, or derived metric designations
+    /// (see [MetricDesignation::derive]), match the search, see [DesignationSearch::rank]
+    pub fn rank_shapes(&self, shapes: &[AiscShape]) -> Vec<DesignationMatch> {
+        self.rank_with_metric(
+            shapes.iter().map(|shape| {
+                (
+                    shape.family(),
+                    shape.aisc_manual_label(),
+                    shape.edi_std_nomenclature(),
+                )
+            }),
+            &MetricDesignation::derive_all(shapes),
+        )
+    }
+}
+
+impl DesignationMatch {
+    /// The AISC Manual Labels of the matches, e.g. to suggest them after a failed lookup
+    pub fn labels(matches: &[DesignationMatch]) -> Vec<String> {
+        matches
+            .iter()
+            .map(|m| m.aisc_manual_label.clone())
+            .collect()
+    }
+}
+
+/// The searched text in the forms designations are compared with
+struct SearchedText {
+    /// The text in upper case without whitespace
+    compact: String,
+    /// The number of characters of the compact text
+    length: usize,
+    /// The text normalized as an AISC Manual Label and as an EDI Std Nomenclature
+    normalized: [String; 2],
+}
+
+impl SearchedText {
+    fn new(text: &str) -> Self {
+        let compact = compact(text);
+        SearchedText {
+            length: compact.chars().count(),
+            compact,
+            normalized: [
+                Designation::normalize_aisc_manual_label(text),
+                Designation::normalize_edi_std_nomenclature(text),
+            ],
+        }
+    }
+}
+
+/// The designation in upper case without whitespace
+fn compact(designation: &str) -> String {
+    designation
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// The number of leading characters the strings share
+fn common_prefix(a: &str, b: &str) -> usize {
+    a.chars().zip(b.chars()).take_while(|(a, b)| a == b).count()
+}
+
+/// The Levenshtein distance between the strings, none when it exceeds the maximum
+fn edit_distance(a: &str, b: &str, max_distance: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max_distance {
+        return None;
+    }
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != b);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        if current.iter().min().is_some_and(|min| *min > max_distance) {
+            return None;
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    Some(previous[b.len()]).filter(|distance| *distance <= max_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(matches: &[DesignationMatch]) -> Vec<&str> {
+        matches
+            .iter()
+            .map(|m| m.aisc_manual_label.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn edit_distances_count_insertions_removals_and_substitutions() {
+        assert_eq!(Some(0), edit_distance("W14X90", "W14X90", 2));
+        assert_eq!(Some(1), edit_distance("W14X91", "W14X90", 2));
+        assert_eq!(Some(1), edit_distance("W14X9", "W14X90", 2));
+        assert_eq!(Some(2), edit_distance("W41X90", "W14X90", 2));
+        assert_eq!(None, edit_distance("W14X90", "W12X26", 2));
+        assert_eq!(None, edit_distance("W14", "W14X90", 2));
+    }
+
+    #[test]
+    fn designations_match_exactly_by_prefix_or_fuzzily() {
+        let search = DesignationSearch::new("hss6x");
+        let m = search
+            .match_designations(
+                ShapeFamily::HollowStructuralSection,
+                "HSS6X4X1/4",
+                "HSS6X4X.250",
+            )
+            .unwrap();
+        assert_eq!((MatchKind::Prefix, 5), (m.kind, m.distance));
+
+        let search = DesignationSearch::new("L4X4X.5");
+        let m = search
+            .match_designations(ShapeFamily::Angle, "L4X4X1/2", "L4X4X1/2")
+            .unwrap();
+        assert_eq!((MatchKind::Exact, 0), (m.kind, m.distance));
+
+        let search = DesignationSearch::new("W14X91");
+        let m = search
+            .match_designations(ShapeFamily::WideFlange, "W14X90", "W14X90")
+            .unwrap();
+        assert_eq!((MatchKind::Fuzzy, 1), (m.kind, m.distance));
+        assert!(
+            search
+                .with_max_distance(0)
+                .match_designations(ShapeFamily::WideFlange, "W14X90", "W14X90")
+                .is_none()
+        );
+        assert!(
+            DesignationSearch::new(" ")
+                .match_designations(ShapeFamily::WideFlange, "W14X90", "W14X90")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn matches_are_ranked_by_closeness() {
+        let designations = [
+            (ShapeFamily::WideFlange, "W4X13", "W4X13"),
+            (ShapeFamily::WideFlange, "W12X14", "W12X14"),
+            (ShapeFamily::WideFlange, "W14X53", "W14X53"),
+            (ShapeFamily::WideFlange, "W14X132", "W14X132"),
+            (ShapeFamily::WideFlange, "W36X231", "W36X231"),
+        ];
+        let matches = DesignationSearch::new("W14X13").rank(designations);
+        assert_eq!(
+            vec!["W14X132", "W14X53", "W4X13", "W12X14"],
+            labels(&matches)
+        );
+        assert_eq!(MatchKind::Prefix, matches[0].kind);
+        assert_eq!(MatchKind::Fuzzy, matches[1].kind);
+        let matches = DesignationSearch::new("W14X13")
+            .with_limit(2)
+            .rank(designations);
+        assert_eq!(vec!["W14X132", "W14X53"], labels(&matches));
+    }
+
+    #[test]
+    #[cfg(feature = "snapshot")]
+    fn failed_lookups_suggest_the_closest_designations() {
+        let shapes = crate::aisc_shapes::aisc_database::snapshot();
+        let matches = DesignationSearch::suggestions_for("W14X91").rank_shapes(shapes);
+        assert_eq!(vec!["W14X90", "W14X99", "W14X61"], labels(&matches));
+        assert!(matches.iter().all(|m| m.kind == MatchKind::Fuzzy));
+
+        let matches = DesignationSearch::new("HSS6X").rank_shapes(shapes);
+        assert_eq!(DEFAULT_SEARCH_LIMIT, matches.len());
+        assert!(matches.iter().all(|m| m.kind == MatchKind::Prefix));
+        assert_eq!("HSS6X.125", matches[0].edi_std_nomenclature);
+        assert_eq!(ShapeFamily::HollowStructuralSection, matches[9].family);
+
+        let matches = DesignationSearch::new("w 18 x 35").rank_shapes(shapes);
+        assert_eq!("W18X35", matches[0].aisc_manual_label);
+        assert_eq!(MatchKind::Exact, matches[0].kind);
+        assert_eq!(
+            vec![String::from("W18X35")],
+            DesignationMatch::labels(&matches[..1])
+        );
+    }
+
+    #[test]
+    fn metric_designations_match_with_their_us_customary_designations() {
+        let designations = [
+            (ShapeFamily::WideFlange, "W14X90", "W14X90"),
+            (ShapeFamily::WideFlange, "W14X99", "W14X99"),
+        ];
+        let metric = [
+            MetricDesignation::derive("W14X90", "W14X90").unwrap(),
+            MetricDesignation::derive("W14X99", "W14X99").unwrap(),
+        ];
+        let matches = DesignationSearch::new("W360X134").rank_with_metric(designations, &metric);
+        assert_eq!(vec!["W14X90", "W14X99"], labels(&matches));
+        assert_eq!(MatchKind::Exact, matches[0].kind);
+        assert_eq!("W14X90", matches[0].edi_std_nomenclature);
+        let matches = DesignationSearch::new("W14X9").rank_with_metric(designations, &metric);
+        assert_eq!(vec!["W14X90", "W14X99"], labels(&matches));
+        assert!(
+            DesignationSearch::new("W360X134")
+                .rank(designations)
+                .is_empty()
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use crate::aisc_shapes::{MissingPropertyError, ShapeBuilder};
 use std::convert::TryFrom;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 /// A struct that models the data for double angle (2L) steel profiles
 pub struct DoubleAngle {
@@ -59,6 +60,34 @@ pub struct DoubleAngle {
     pub h_upper: f64,
 }
 
+impl DoubleAngle {
+    /// The spacing between the back-to-back legs, in., as given at the end of the
+    /// designation (e.g. 3/8 for 2L8X6X1X3/8LLBB), zero for angles in contact
+    pub fn spacing(&self) -> f64 {
+        let dimensions = self
+            .aisc_manual_label
+            .trim_start_matches("2L")
+            .trim_end_matches("LLBB")
+            .trim_end_matches("SLBB");
+        dimensions
+            .split('X')
+            .nth(3)
+            .and_then(parse_fraction)
+            .unwrap_or(0.0)
+    }
+}
+
+/// Parses a dimension such as `1`, `3/8` or `1-1/2`
+fn parse_fraction(dimension: &str) -> Option<f64> {
+    let (whole, fraction) = match dimension.split_once('-') {
+        Some((whole, fraction)) => (whole.parse::<f64>().ok()?, fraction),
+        None if dimension.contains('/') => (0.0, dimension),
+        None => return dimension.parse().ok(),
+    };
+    let (numerator, denominator) = fraction.split_once('/')?;
+    Some(whole + numerator.parse::<f64>().ok()? / denominator.parse::<f64>().ok()?)
+}
+
 impl TryFrom<ShapeBuilder> for DoubleAngle {
     type Error = MissingPropertyError;
     fn try_from(builder: ShapeBuilder) -> Result<Self, MissingPropertyError> {
@@ -87,35 +116,35 @@ impl TryFrom<ShapeBuilder> for DoubleAngle {
                     return Err(MissingPropertyError::from("A"));
                 }
             },
-            d_lower: match *&builder.d_lower {
+            d_lower: match builder.d_lower {
                 Some(d_lower) => d_lower,
                 None => {
                     return Err(MissingPropertyError::from("d"));
                 }
             },
-            b_lower: match *&builder.b_lower {
+            b_lower: match builder.b_lower {
                 Some(b_lower) => b_lower,
                 None => {
                     return Err(MissingPropertyError::from("b"));
                 }
             },
-            t_lower: match *&builder.t_lower {
+            t_lower: match builder.t_lower {
                 Some(t_lower) => t_lower,
                 None => return Err(MissingPropertyError::from("t")),
             },
-            y_lower: match *&builder.y_lower {
+            y_lower: match builder.y_lower {
                 Some(y_lower) => y_lower,
                 None => {
                     return Err(MissingPropertyError::from("y"));
                 }
             },
-            yp: match *&builder.yp {
+            yp: match builder.yp {
                 Some(yp) => yp,
                 None => {
                     return Err(MissingPropertyError::from("yp"));
                 }
             },
-            b_t: match *&builder.b_t {
+            b_t: match builder.b_t {
                 Some(b_t) => b_t,
                 None => return Err(MissingPropertyError::from("b/t")),
             },
@@ -167,13 +196,13 @@ impl TryFrom<ShapeBuilder> for DoubleAngle {
                     return Err(MissingPropertyError::from("ry"));
                 }
             },
-            ro: match *&builder.ro {
+            ro: match builder.ro {
                 Some(ro) => ro,
                 None => {
                     return Err(MissingPropertyError::from("ro"));
                 }
             },
-            h_upper: match *&builder.h_upper {
+            h_upper: match builder.h_upper {
                 Some(h_upper) => h_upper,
                 None => {
                     return Err(MissingPropertyError::from("H"));
@@ -237,6 +266,39 @@ mod tests {
         assert_eq!(0.831, shape.h_upper);
     }
 
+    #[test]
+    fn spacing_is_read_from_the_designation() {
+        let mut shape = ShapeBuilder::new()
+            .with_edi_std_nomenclature(String::from("2L8X6X1LLBB"))
+            .with_aisc_manual_label(String::from("2L8X6X1LLBB"))
+            .with_w_upper(88.4)
+            .with_a_upper(26.2)
+            .with_d_lower(8.0)
+            .with_b_lower(6.0)
+            .with_t_lower(1.0)
+            .with_y_lower(2.65)
+            .with_yp(1.45)
+            .with_b_t(8.0)
+            .with_ix(162.0)
+            .with_zx(54.6)
+            .with_sx(30.2)
+            .with_rx(2.49)
+            .with_iy(150.0)
+            .with_zy(43.2)
+            .with_sy(25.0)
+            .with_ry(2.39)
+            .with_ro(4.06)
+            .with_h_upper(0.721)
+            .try_build::<DoubleAngle>()
+            .unwrap();
+        assert_eq!(0.0, shape.spacing());
+        shape.aisc_manual_label = String::from("2L8X6X1X3/8LLBB");
+        assert_eq!(0.375, shape.spacing());
+        shape.aisc_manual_label = String::from("2L12X12X1-3/8X1-1/2");
+        assert_eq!(1.5, shape.spacing());
+        shape.aisc_manual_label = String::from("2L4X4X1/2X3/4");
+        assert_eq!(0.75, shape.spacing());
+    }
     #[test]
     fn missing_edi_std_nom_returns_error() {
         let shape_result = ShapeBuilder::new()
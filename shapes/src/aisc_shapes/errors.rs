@@ -25,3 +25,223 @@ impl fmt::Display for MissingPropertyError {
 }
 
 impl Error for MissingPropertyError {}
+
+#[derive(Debug, PartialEq)]
+/// An error returned when a name does not
+/// correspond to any shape family
+pub struct UnknownShapeFamilyError {
+    /// The name that could not be parsed
+    pub name: String,
+}
+
+impl fmt::Display for UnknownShapeFamilyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = &self.name;
+        write!(f, "{name} is not a known shape family.")
+    }
+}
+
+impl Error for UnknownShapeFamilyError {}
+
+#[derive(Debug, PartialEq)]
+/// An error returned when a name does not
+/// correspond to any numeric shape property
+pub struct UnknownShapePropertyError {
+    /// The name that could not be parsed
+    pub name: String,
+}
+
+impl fmt::Display for UnknownShapePropertyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = &self.name;
+        write!(f, "{name} is not a known shape property.")
+    }
+}
+
+impl Error for UnknownShapePropertyError {}
+
+#[derive(Debug, PartialEq)]
+/// An error returned when a name does not
+/// correspond to any unit system
+pub struct UnknownUnitSystemError {
+    /// The name that could not be parsed
+    pub name: String,
+}
+
+impl fmt::Display for UnknownUnitSystemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = &self.name;
+        write!(f, "{name} is not a known unit system.")
+    }
+}
+
+impl Error for UnknownUnitSystemError {}
+
+#[derive(Debug, PartialEq)]
+/// An error returned when a value in the AISC
+/// shapes database cannot be parsed
+pub struct InvalidValueError {
+    /// The name of the column containing the value
+    pub column: &'static str,
+    /// The value that could not be parsed
+    pub value: String,
+}
+
+impl fmt::Display for InvalidValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let column = self.column;
+        let value = &self.value;
+        write!(f, "{value} is not a valid value for {column}.")
+    }
+}
+
+impl Error for InvalidValueError {}
+
+#[derive(Debug)]
+/// An error returned by a shape repository
+pub enum RepositoryError {
+    /// No shape is stored under the key, e.g. an AISC Manual Label
+    NotFound {
+        /// The key that was looked up
+        key: String,
+    },
+    /// The data source failed, e.g. the database connection was lost
+    Database(Box<dyn Error + Send + Sync>),
+    /// A stored value could not be read as the type of its column
+    InvalidRow {
+        /// The name of the column containing the value
+        column: String,
+        /// The error returned when reading the value
+        source: Box<dyn Error + Send + Sync>,
+    },
+    /// A stored shape is missing a property required to build it
+    MissingProperty(MissingPropertyError),
+}
+
+impl RepositoryError {
+    /// Whether the error was returned because no shape is stored under the key
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, RepositoryError::NotFound { .. })
+    }
+}
+
+impl fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepositoryError::NotFound { key } => write!(f, "No shape was found for {key}."),
+            RepositoryError::Database(err) => write!(f, "The shape data source failed: {err}"),
+            RepositoryError::InvalidRow { column, source } => {
+                write!(f, "The value of {column} could not be read: {source}")
+            }
+            RepositoryError::MissingProperty(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for RepositoryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RepositoryError::NotFound { .. } => None,
+            RepositoryError::Database(err) => Some(err.as_ref()),
+            RepositoryError::InvalidRow { source, .. } => Some(source.as_ref()),
+            RepositoryError::MissingProperty(err) => Some(err),
+        }
+    }
+}
+
+impl From<MissingPropertyError> for RepositoryError {
+    fn from(err: MissingPropertyError) -> Self {
+        RepositoryError::MissingProperty(err)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+/// An error returned when a row of the metric sheet of the AISC shapes database
+/// does not describe the same shape as the row of the US customary sheet
+pub struct MetricSheetMismatchError {
+    /// The index of the shape in both sheets, starting at zero
+    pub row: usize,
+}
+
+impl fmt::Display for MetricSheetMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let row = self.row;
+        write!(
+            f,
+            "Shape {row} of the metric sheet does not match shape {row} of the US customary sheet."
+        )
+    }
+}
+
+impl Error for MetricSheetMismatchError {}
+
+#[derive(Debug, PartialEq)]
+/// An error returned when a name does not
+/// correspond to any design table
+pub struct UnknownDesignTableError {
+    /// The name that could not be parsed
+    pub name: String,
+}
+
+impl fmt::Display for UnknownDesignTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = &self.name;
+        write!(f, "{name} is not a known design table.")
+    }
+}
+
+impl Error for UnknownDesignTableError {}
+
+#[derive(Debug, PartialEq)]
+/// An error returned when a range of lengths of a
+/// design table has no lengths or too many of them
+pub struct InvalidLengthRangeError {
+    /// The name of the bound that makes the range invalid, `start`, `end` or `step`
+    pub bound: &'static str,
+    /// The value of the bound, ft
+    pub value: f64,
+}
+
+impl fmt::Display for InvalidLengthRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bound = self.bound;
+        let value = self.value;
+        write!(f, "{value} is not a valid {bound} of a range of lengths.")
+    }
+}
+
+impl Error for InvalidLengthRangeError {}
+
+#[derive(Debug, PartialEq)]
+/// An error returned when a designation does not
+/// follow the format of any shape family
+pub struct InvalidDesignationError {
+    /// The designation that could not be parsed
+    pub designation: String,
+}
+
+impl fmt::Display for InvalidDesignationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let designation = &self.designation;
+        write!(f, "{designation} is not a valid shape designation.")
+    }
+}
+
+impl Error for InvalidDesignationError {}
+
+#[derive(Debug, PartialEq)]
+/// An error returned when a name does not
+/// correspond to any key shapes can be paged by
+pub struct UnknownSortKeyError {
+    /// The name that could not be parsed
+    pub name: String,
+}
+
+impl fmt::Display for UnknownSortKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = &self.name;
+        write!(f, "{name} is not a known sort key.")
+    }
+}
+
+impl Error for UnknownSortKeyError {}
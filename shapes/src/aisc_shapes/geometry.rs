@@ -0,0 +1,684 @@
+use crate::aisc_shapes::{
+    Angle, CeeChannel, DoubleAngle, HPile, HollowStructuralSection, MiscBeam, MiscChannel, MiscTee,
+    Pipe, RoundHollowStructuralSection, StructuralBeam, StructuralTee, WideFlange, WideFlangeTee,
+};
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+/// The number of segments a quarter circle is approximated with by
+/// [SectionGeometry::cross_section]
+pub const DEFAULT_ARC_SEGMENTS: usize = 16;
+
+/// The slope of the inner flange faces of S shapes, ST shapes and
+/// C channels, 2 in 12 (16.67%), whose tabulated tf is the flange
+/// thickness halfway between the toe and the face of the web
+pub const AMERICAN_STANDARD_FLANGE_SLOPE: f64 = 2.0 / 12.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A point of a cross-section, in.
+pub struct Point {
+    /// The horizontal coordinate, in.
+    pub x: f64,
+    /// The vertical coordinate, in.
+    pub y: f64,
+}
+
+impl Point {
+    /// Creates a point from its coordinates
+    pub const fn new(x: f64, y: f64) -> Self {
+        Point { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A closed polygon, the last vertex connects back to the first
+pub struct Polygon {
+    /// The vertices of the polygon, without repeating the first vertex
+    pub vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// Creates a polygon from its vertices
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Polygon { vertices }
+    }
+
+    /// The area enclosed by the polygon, positive when the
+    /// vertices are counterclockwise and negative when clockwise
+    pub fn signed_area(&self) -> f64 {
+        self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<f64>()
+            / 2.0
+    }
+
+    /// Whether the vertices of the polygon are counterclockwise
+    pub fn is_counterclockwise(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+
+    /// The edges of the polygon as pairs of consecutive vertices,
+    /// including the edge from the last vertex back to the first
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(a, b)| (*a, *b))
+    }
+
+    /// The polygon with its vertices in the opposite order
+    pub fn reversed(mut self) -> Self {
+        self.vertices.reverse();
+        self
+    }
+
+    /// The polygon mirrored about the vertical line `x = axis`, keeping its orientation
+    pub fn mirrored(&self, axis: f64) -> Self {
+        Polygon::new(
+            self.vertices
+                .iter()
+                .rev()
+                .map(|point| Point::new(2.0 * axis - point.x, point.y))
+                .collect(),
+        )
+    }
+
+    /// The polygon moved by `dx` horizontally and `dy` vertically
+    pub fn translated(&self, dx: f64, dy: f64) -> Self {
+        Polygon::new(
+            self.vertices
+                .iter()
+                .map(|point| Point::new(point.x + dx, point.y + dy))
+                .collect(),
+        )
+    }
+
+    /// A polygon through the corners, each rounded by a tangent arc
+    /// of its radius, a radius of zero leaves the corner sharp
+    pub fn rounded(corners: &[(Point, f64)], arc_segments: usize) -> Self {
+        let mut vertices = Vec::new();
+        for (i, &(corner, radius)) in corners.iter().enumerate() {
+            let previous = corners[(i + corners.len() - 1) % corners.len()].0;
+            let next = corners[(i + 1) % corners.len()].0;
+            vertices.extend(fillet(previous, corner, next, radius, arc_segments));
+        }
+        Polygon::new(vertices)
+    }
+
+    /// A polygon approximating a counterclockwise circle with `segments` sides
+    pub fn circle(center: Point, radius: f64, segments: usize) -> Self {
+        Polygon::new(
+            (0..segments)
+                .map(|i| {
+                    let angle = TAU * i as f64 / segments as f64;
+                    Point::new(
+                        center.x + radius * angle.cos(),
+                        center.y + radius * angle.sin(),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The vertices replacing `corner` when it is rounded by a tangent arc of the radius
+fn fillet(previous: Point, corner: Point, next: Point, radius: f64, segments: usize) -> Vec<Point> {
+    let unit = |to: Point| {
+        let (dx, dy) = (to.x - corner.x, to.y - corner.y);
+        let length = dx.hypot(dy);
+        (dx / length, dy / length, length)
+    };
+    let (ax, ay, a_length) = unit(previous);
+    let (bx, by, b_length) = unit(next);
+    let angle = (ax * bx + ay * by).clamp(-1.0, 1.0).acos();
+    if radius <= 0.0 || segments == 0 || angle <= f64::EPSILON || angle >= PI - f64::EPSILON {
+        return vec![corner];
+    }
+    // The arc is tangent to both edges, it cannot extend past either of them
+    let tangent = (radius / (angle / 2.0).tan()).min(a_length).min(b_length);
+    let radius = tangent * (angle / 2.0).tan();
+    let (bisector_x, bisector_y) = (ax + bx, ay + by);
+    let bisector_length = bisector_x.hypot(bisector_y);
+    let center_distance = radius / (angle / 2.0).sin();
+    let center = Point::new(
+        corner.x + bisector_x / bisector_length * center_distance,
+        corner.y + bisector_y / bisector_length * center_distance,
+    );
+    let start = Point::new(corner.x + ax * tangent, corner.y + ay * tangent);
+    let end = Point::new(corner.x + bx * tangent, corner.y + by * tangent);
+    let start_angle = (start.y - center.y).atan2(start.x - center.x);
+    let mut sweep = (end.y - center.y).atan2(end.x - center.x) - start_angle;
+    if sweep > PI {
+        sweep -= TAU;
+    } else if sweep < -PI {
+        sweep += TAU;
+    }
+    // Keep the number of segments per quarter circle
+    let count = ((sweep.abs() / FRAC_PI_2) * segments as f64)
+        .ceil()
+        .max(1.0) as usize;
+    (0..=count)
+        .map(|i| {
+            let angle = start_angle + sweep * i as f64 / count as f64;
+            Point::new(
+                center.x + radius * angle.cos(),
+                center.y + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A connected piece of a cross-section: a counterclockwise outline
+/// and the clockwise outlines of the holes within it
+pub struct Region {
+    /// The outer boundary of the region, counterclockwise
+    pub outline: Polygon,
+    /// The boundaries of the holes of the region, clockwise
+    pub holes: Vec<Polygon>,
+}
+
+impl Region {
+    /// Creates a region without holes
+    pub fn solid(outline: Polygon) -> Self {
+        Region {
+            outline,
+            holes: Vec::new(),
+        }
+    }
+
+    /// The area of the region, excluding its holes
+    pub fn area(&self) -> f64 {
+        self.polygons().map(Polygon::signed_area).sum()
+    }
+
+    /// The outline of the region followed by its holes
+    pub fn polygons(&self) -> impl Iterator<Item = &Polygon> {
+        std::iter::once(&self.outline).chain(self.holes.iter())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The smallest rectangle containing a cross-section, in.
+pub struct BoundingBox {
+    /// The lower left corner
+    pub min: Point,
+    /// The upper right corner
+    pub max: Point,
+}
+
+impl BoundingBox {
+    /// The horizontal extent of the box, in.
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    /// The vertical extent of the box, in.
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The outline of a shape's cross-section, made of one region for most shapes
+/// and two for double angles
+pub struct CrossSection {
+    /// The connected pieces of the cross-section
+    pub regions: Vec<Region>,
+}
+
+impl CrossSection {
+    /// Creates a cross-section of a single region
+    pub fn from_region(region: Region) -> Self {
+        CrossSection {
+            regions: vec![region],
+        }
+    }
+
+    /// The area of the cross-section, in.²
+    pub fn area(&self) -> f64 {
+        self.regions.iter().map(Region::area).sum()
+    }
+
+    /// Every outline and hole of the cross-section
+    pub fn polygons(&self) -> impl Iterator<Item = &Polygon> {
+        self.regions.iter().flat_map(Region::polygons)
+    }
+
+    /// The smallest rectangle containing the cross-section
+    pub fn bounding_box(&self) -> BoundingBox {
+        let mut points = self.polygons().flat_map(|polygon| &polygon.vertices);
+        let first = points.next().copied().unwrap_or_default();
+        points.fold(
+            BoundingBox {
+                min: first,
+                max: first,
+            },
+            |bounds, point| BoundingBox {
+                min: Point::new(bounds.min.x.min(point.x), bounds.min.y.min(point.y)),
+                max: Point::new(bounds.max.x.max(point.x), bounds.max.y.max(point.y)),
+            },
+        )
+    }
+
+    /// The cross-section moved by `dx` horizontally and `dy` vertically
+    pub fn translated(&self, dx: f64, dy: f64) -> Self {
+        CrossSection {
+            regions: self
+                .regions
+                .iter()
+                .map(|region| Region {
+                    outline: region.outline.translated(dx, dy),
+                    holes: region
+                        .holes
+                        .iter()
+                        .map(|hole| hole.translated(dx, dy))
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Generates the outline of a shape's cross-section from its dimensions.
+///
+/// Coordinates are in in., with the origin at the lower left corner of the
+/// bounding box and y pointing up. Beams and channels stand with the web
+/// vertical (channels open to the right, so x is measured from the back of
+/// the web), tees have the flange on top, single angles have the heel at
+/// the origin with the longer leg vertical and double angles stand on their
+/// outstanding legs with the back-to-back legs vertical. Fillets are
+/// approximated from the k-dimension, HSS corners have an outside radius
+/// of 2 tdes and sloped flanges are modeled for S, ST and C shapes.
+/// Double angles, whose k-dimension isn't tabulated, have sharp corners.
+pub trait SectionGeometry {
+    /// The cross-section, approximating each quarter circle with `arc_segments` segments
+    fn cross_section_with(&self, arc_segments: usize) -> CrossSection;
+
+    /// The cross-section, approximating each quarter circle
+    /// with [DEFAULT_ARC_SEGMENTS] segments
+    fn cross_section(&self) -> CrossSection {
+        self.cross_section_with(DEFAULT_ARC_SEGMENTS)
+    }
+}
+
+/// The dimensions of a doubly symmetric I-shaped section
+struct ISection {
+    d: f64,
+    bf: f64,
+    tf: f64,
+    tw: f64,
+    kdes: f64,
+    slope: f64,
+}
+
+/// The thickness of a flange at its toe and at the face of the web, and the radius
+/// of the fillet between them reaching down to the k-dimension
+fn flange_thicknesses(tf: f64, kdes: f64, outstand: f64, slope: f64) -> (f64, f64, f64) {
+    let toe = tf - slope * outstand / 2.0;
+    let root = tf + slope * outstand / 2.0;
+    (toe, root, (kdes - root).max(0.0))
+}
+
+fn i_section(section: ISection, arc_segments: usize) -> CrossSection {
+    let ISection { d, bf, tw, .. } = section;
+    let (toe, root, radius) =
+        flange_thicknesses(section.tf, section.kdes, (bf - tw) / 2.0, section.slope);
+    let (left, right) = ((bf - tw) / 2.0, (bf + tw) / 2.0);
+    CrossSection::from_region(Region::solid(Polygon::rounded(
+        &[
+            (Point::new(0.0, 0.0), 0.0),
+            (Point::new(bf, 0.0), 0.0),
+            (Point::new(bf, toe), 0.0),
+            (Point::new(right, root), radius),
+            (Point::new(right, d - root), radius),
+            (Point::new(bf, d - toe), 0.0),
+            (Point::new(bf, d), 0.0),
+            (Point::new(0.0, d), 0.0),
+            (Point::new(0.0, d - toe), 0.0),
+            (Point::new(left, d - root), radius),
+            (Point::new(left, root), radius),
+            (Point::new(0.0, toe), 0.0),
+        ],
+        arc_segments,
+    )))
+}
+
+fn channel(section: ISection, arc_segments: usize) -> CrossSection {
+    let ISection { d, bf, tw, .. } = section;
+    let (toe, root, radius) = flange_thicknesses(section.tf, section.kdes, bf - tw, section.slope);
+    CrossSection::from_region(Region::solid(Polygon::rounded(
+        &[
+            (Point::new(0.0, 0.0), 0.0),
+            (Point::new(bf, 0.0), 0.0),
+            (Point::new(bf, toe), 0.0),
+            (Point::new(tw, root), radius),
+            (Point::new(tw, d - root), radius),
+            (Point::new(bf, d - toe), 0.0),
+            (Point::new(bf, d), 0.0),
+            (Point::new(0.0, d), 0.0),
+        ],
+        arc_segments,
+    )))
+}
+
+fn tee(section: ISection, arc_segments: usize) -> CrossSection {
+    let ISection { d, bf, tw, .. } = section;
+    let (toe, root, radius) =
+        flange_thicknesses(section.tf, section.kdes, (bf - tw) / 2.0, section.slope);
+    let (left, right) = ((bf - tw) / 2.0, (bf + tw) / 2.0);
+    CrossSection::from_region(Region::solid(Polygon::rounded(
+        &[
+            (Point::new(left, 0.0), 0.0),
+            (Point::new(right, 0.0), 0.0),
+            (Point::new(right, d - root), radius),
+            (Point::new(bf, d - toe), 0.0),
+            (Point::new(bf, d), 0.0),
+            (Point::new(0.0, d), 0.0),
+            (Point::new(0.0, d - toe), 0.0),
+            (Point::new(left, d - root), radius),
+        ],
+        arc_segments,
+    )))
+}
+
+/// An angle with its heel at the origin, `vertical` and `horizontal` are the leg lengths
+fn angle_outline(
+    vertical: f64,
+    horizontal: f64,
+    t: f64,
+    radius: f64,
+    arc_segments: usize,
+) -> Polygon {
+    Polygon::rounded(
+        &[
+            (Point::new(0.0, 0.0), 0.0),
+            (Point::new(horizontal, 0.0), 0.0),
+            (Point::new(horizontal, t), 0.0),
+            (Point::new(t, t), radius),
+            (Point::new(t, vertical), 0.0),
+            (Point::new(0.0, vertical), 0.0),
+        ],
+        arc_segments,
+    )
+}
+
+fn rectangular_tube(height: f64, width: f64, tdes: f64, arc_segments: usize) -> CrossSection {
+    let rectangle = |offset: f64, radius: f64| {
+        let (right, top) = (width - offset, height - offset);
+        Polygon::rounded(
+            &[
+                (Point::new(offset, offset), radius),
+                (Point::new(right, offset), radius),
+                (Point::new(right, top), radius),
+                (Point::new(offset, top), radius),
+            ],
+            arc_segments,
+        )
+    };
+    let outside_radius = (2.0 * tdes).min(width / 2.0).min(height / 2.0);
+    CrossSection::from_region(Region {
+        outline: rectangle(0.0, outside_radius),
+        holes: vec![rectangle(tdes, outside_radius - tdes).reversed()],
+    })
+}
+
+fn round_tube(od: f64, tdes: f64, arc_segments: usize) -> CrossSection {
+    let radius = od / 2.0;
+    let center = Point::new(radius, radius);
+    let segments = 4 * arc_segments.max(1);
+    CrossSection::from_region(Region {
+        outline: Polygon::circle(center, radius, segments),
+        holes: vec![Polygon::circle(center, radius - tdes, segments).reversed()],
+    })
+}
+
+/// Implements [SectionGeometry] for a family with the dimensions of an I-shaped section
+macro_rules! impl_flanged_geometry {
+    ($shape:ident, $section:ident, $slope:expr) => {
+        impl SectionGeometry for $shape {
+            fn cross_section_with(&self, arc_segments: usize) -> CrossSection {
+                $section(
+                    ISection {
+                        d: self.d_lower,
+                        bf: self.bf,
+                        tf: self.tf,
+                        tw: self.tw,
+                        kdes: self.kdes,
+                        slope: $slope,
+                    },
+                    arc_segments,
+                )
+            }
+        }
+    };
+}
+
+impl_flanged_geometry!(WideFlange, i_section, 0.0);
+impl_flanged_geometry!(MiscBeam, i_section, 0.0);
+impl_flanged_geometry!(StructuralBeam, i_section, AMERICAN_STANDARD_FLANGE_SLOPE);
+impl_flanged_geometry!(HPile, i_section, 0.0);
+impl_flanged_geometry!(CeeChannel, channel, AMERICAN_STANDARD_FLANGE_SLOPE);
+impl_flanged_geometry!(MiscChannel, channel, 0.0);
+impl_flanged_geometry!(WideFlangeTee, tee, 0.0);
+impl_flanged_geometry!(MiscTee, tee, 0.0);
+impl_flanged_geometry!(StructuralTee, tee, AMERICAN_STANDARD_FLANGE_SLOPE);
+
+impl SectionGeometry for Angle {
+    fn cross_section_with(&self, arc_segments: usize) -> CrossSection {
+        let radius = (self.kdes - self.t_lower).max(0.0);
+        CrossSection::from_region(Region::solid(angle_outline(
+            self.b_lower,
+            self.d_lower,
+            self.t_lower,
+            radius,
+            arc_segments,
+        )))
+    }
+}
+
+impl SectionGeometry for DoubleAngle {
+    fn cross_section_with(&self, arc_segments: usize) -> CrossSection {
+        // The left angle, its heel against the back-to-back plane at x = b
+        let left = angle_outline(self.d_lower, self.b_lower, self.t_lower, 0.0, arc_segments)
+            .mirrored(0.0)
+            .translated(self.b_lower, 0.0);
+        let right = left.mirrored(self.b_lower + self.spacing() / 2.0);
+        CrossSection {
+            regions: vec![Region::solid(left), Region::solid(right)],
+        }
+    }
+}
+
+impl SectionGeometry for HollowStructuralSection {
+    fn cross_section_with(&self, arc_segments: usize) -> CrossSection {
+        rectangular_tube(self.ht, self.b_upper, self.tdes, arc_segments)
+    }
+}
+
+impl SectionGeometry for RoundHollowStructuralSection {
+    fn cross_section_with(&self, arc_segments: usize) -> CrossSection {
+        round_tube(self.od, self.tdes, arc_segments)
+    }
+}
+
+impl SectionGeometry for Pipe {
+    fn cross_section_with(&self, arc_segments: usize) -> CrossSection {
+        round_tube(self.od, self.tdes, arc_segments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::ShapeProperty;
+    use crate::aisc_shapes::test_support::{flanged, shape};
+
+    fn assert_area(expected: f64, cross_section: &CrossSection) {
+        let area = cross_section.area();
+        assert!(
+            (area - expected).abs() <= expected * 0.02,
+            "expected an area of {expected}, got {area}"
+        );
+        for region in &cross_section.regions {
+            assert!(region.outline.is_counterclockwise());
+            assert!(region.holes.iter().all(|hole| !hole.is_counterclockwise()));
+        }
+    }
+
+    fn assert_bounds(width: f64, height: f64, cross_section: &CrossSection) {
+        let bounds = cross_section.bounding_box();
+        assert!((bounds.min.x).abs() < 1e-9 && (bounds.min.y).abs() < 1e-9);
+        assert!((bounds.width() - width).abs() < 1e-9);
+        assert!((bounds.height() - height).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rectangles_have_the_area_of_their_sides() {
+        let rectangle = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 2.0),
+            Point::new(0.0, 2.0),
+        ]);
+        assert_eq!(8.0, rectangle.signed_area());
+        assert_eq!(-8.0, rectangle.clone().reversed().signed_area());
+        assert_eq!(8.0, rectangle.mirrored(1.0).signed_area());
+    }
+
+    #[test]
+    fn rounded_corners_remove_the_corner_of_the_square() {
+        let corners = [
+            (Point::new(0.0, 0.0), 1.0),
+            (Point::new(2.0, 0.0), 1.0),
+            (Point::new(2.0, 2.0), 1.0),
+            (Point::new(0.0, 2.0), 1.0),
+        ];
+        // A square with corners rounded by half its side is a circle
+        let circle = Polygon::rounded(&corners, 64);
+        assert!((circle.signed_area() - PI).abs() < 1e-3);
+        for point in circle.vertices {
+            assert!(((point.x - 1.0).hypot(point.y - 1.0) - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn wide_flanges_match_their_tabulated_area() {
+        let w14x90: WideFlange = shape("W14X90", &flanged(14.0, 14.5, 0.44, 0.71, 1.31));
+        let cross_section = w14x90.cross_section();
+        assert_area(26.5, &cross_section);
+        assert_bounds(14.5, 14.0, &cross_section);
+        let hp14x117: HPile = shape("HP14X117", &flanged(14.2, 14.9, 0.805, 0.805, 1.5));
+        assert_area(34.4, &hp14x117.cross_section());
+        let m12x11_8: MiscBeam = shape("M12X11.8", &flanged(12.0, 3.07, 0.177, 0.225, 0.563));
+        assert_area(3.47, &m12x11_8.cross_section());
+    }
+
+    #[test]
+    fn sloped_flanges_keep_their_average_thickness() {
+        let s24x121: StructuralBeam = shape("S24X121", &flanged(24.5, 8.05, 0.8, 1.09, 2.0));
+        let cross_section = s24x121.cross_section();
+        assert_area(35.5, &cross_section);
+        assert_bounds(8.05, 24.5, &cross_section);
+        let st12x60_5: StructuralTee = shape("ST12X60.5", &flanged(12.3, 8.05, 0.8, 1.09, 2.0));
+        assert_area(17.8, &st12x60_5.cross_section());
+    }
+
+    #[test]
+    fn channels_open_to_the_right() {
+        let c15x50: CeeChannel = shape("C15X50", &flanged(15.0, 3.72, 0.716, 0.65, 1.44));
+        let cross_section = c15x50.cross_section();
+        assert_area(14.7, &cross_section);
+        assert_bounds(3.72, 15.0, &cross_section);
+        let outline = &cross_section.regions[0].outline;
+        assert!(outline.vertices.contains(&Point::new(0.0, 15.0)));
+        let mc18x58: MiscChannel = shape("MC18X58", &flanged(18.0, 4.2, 0.7, 0.625, 1.44));
+        assert_area(17.1, &mc18x58.cross_section());
+    }
+
+    #[test]
+    fn tees_have_the_flange_on_top() {
+        let wt7x45: WideFlangeTee = shape("WT7X45", &flanged(7.01, 14.5, 0.44, 0.71, 1.31));
+        let cross_section = wt7x45.cross_section();
+        assert_area(13.2, &cross_section);
+        assert_bounds(14.5, 7.01, &cross_section);
+        let outline = &cross_section.regions[0].outline;
+        assert!(outline.vertices.contains(&Point::new(0.0, 7.01)));
+        assert!(!outline.vertices.contains(&Point::new(0.0, 0.0)));
+        let mt6x5_9: MiscTee = shape("MT6X5.9", &flanged(6.0, 3.07, 0.177, 0.225, 0.563));
+        assert_area(1.74, &mt6x5_9.cross_section());
+    }
+
+    #[test]
+    fn angles_stand_on_their_shorter_leg() {
+        let l8x6x1: Angle = shape(
+            "L8X6X1",
+            &[
+                (ShapeProperty::DLower, 6.0),
+                (ShapeProperty::BLower, 8.0),
+                (ShapeProperty::TLower, 1.0),
+                (ShapeProperty::Kdes, 1.5),
+            ],
+        );
+        let cross_section = l8x6x1.cross_section();
+        assert_area(13.1, &cross_section);
+        assert_bounds(6.0, 8.0, &cross_section);
+    }
+
+    #[test]
+    fn double_angles_are_two_regions_apart_by_their_spacing() {
+        let dimensions = [
+            (ShapeProperty::DLower, 8.0),
+            (ShapeProperty::BLower, 6.0),
+            (ShapeProperty::TLower, 1.0),
+        ];
+        let llbb: DoubleAngle = shape("2L8X6X1X3/8LLBB", &dimensions);
+        let cross_section = llbb.cross_section();
+        assert_eq!(2, cross_section.regions.len());
+        assert_area(26.2, &cross_section);
+        assert_bounds(12.375, 8.0, &cross_section);
+        let touching: DoubleAngle = shape("2L8X6X1LLBB", &dimensions);
+        assert_bounds(12.0, 8.0, &touching.cross_section());
+    }
+
+    #[test]
+    fn tubes_have_a_hole() {
+        let hss8x4x1_2: HollowStructuralSection = shape(
+            "HSS8X4X1/2",
+            &[
+                (ShapeProperty::Ht, 8.0),
+                (ShapeProperty::BUpper, 4.0),
+                (ShapeProperty::Tdes, 0.465),
+            ],
+        );
+        let cross_section = hss8x4x1_2.cross_section();
+        assert_eq!(1, cross_section.regions[0].holes.len());
+        assert_area(9.74, &cross_section);
+        assert_bounds(4.0, 8.0, &cross_section);
+        let dimensions = [(ShapeProperty::Od, 6.625), (ShapeProperty::Tdes, 0.261)];
+        let pipe: Pipe = shape("Pipe6STD", &dimensions);
+        assert_area(5.2, &pipe.cross_section());
+        let round: RoundHollowStructuralSection = shape("HSS6.625X.280", &dimensions);
+        assert_area(5.2, &round.cross_section_with(64));
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn every_shape_in_the_database_matches_its_tabulated_area() {
+        use crate::aisc_shapes::SectionProperties;
+
+        for shape in crate::aisc_shapes::aisc_database::snapshot() {
+            let area = shape.cross_section().area();
+            let expected = shape.a_upper();
+            assert!(
+                (area - expected).abs() <= expected * 0.05,
+                "{}: expected an area of {expected}, got {area}",
+                shape.aisc_manual_label()
+            );
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::aisc_shapes::{MissingPropertyError, ShapeBuilder};
 use std::convert::TryFrom;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 /// A struct that models the data for h-pile beam (HP) steel profiles
 pub struct HPile {
@@ -126,79 +127,79 @@ impl TryFrom<ShapeBuilder> for HPile {
                     return Err(MissingPropertyError::from("A"));
                 }
             },
-            d_lower: match *&builder.d_lower {
+            d_lower: match builder.d_lower {
                 Some(d_lower) => d_lower,
                 None => {
                     return Err(MissingPropertyError::from("d"));
                 }
             },
-            ddet: match *&builder.ddet {
+            ddet: match builder.ddet {
                 Some(ddet) => ddet,
                 None => {
                     return Err(MissingPropertyError::from("ddet"));
                 }
             },
-            bf: match *&builder.bf {
+            bf: match builder.bf {
                 Some(bf) => bf,
                 None => {
                     return Err(MissingPropertyError::from("bf"));
                 }
             },
-            bfdet: match *&builder.bfdet {
+            bfdet: match builder.bfdet {
                 Some(bfdet) => bfdet,
                 None => {
                     return Err(MissingPropertyError::from("bfdet"));
                 }
             },
-            tw: match *&builder.tw {
+            tw: match builder.tw {
                 Some(tw) => tw,
                 None => {
                     return Err(MissingPropertyError::from("tw"));
                 }
             },
-            twdet: match *&builder.twdet {
+            twdet: match builder.twdet {
                 Some(twdet) => twdet,
                 None => {
                     return Err(MissingPropertyError::from("twdet"));
                 }
             },
-            twdet_2: match *&builder.twdet_2 {
+            twdet_2: match builder.twdet_2 {
                 Some(twdet) => twdet,
                 None => {
                     return Err(MissingPropertyError::from("twdet/2"));
                 }
             },
-            tf: match *&builder.tf {
+            tf: match builder.tf {
                 Some(tf) => tf,
                 None => {
                     return Err(MissingPropertyError::from("tf"));
                 }
             },
-            tfdet: match *&builder.tfdet {
+            tfdet: match builder.tfdet {
                 Some(tfdet) => tfdet,
                 None => {
                     return Err(MissingPropertyError::from("tfdet"));
                 }
             },
-            kdes: match *&builder.kdes {
+            kdes: match builder.kdes {
                 Some(kdes) => kdes,
                 None => {
                     return Err(MissingPropertyError::from("kdes"));
                 }
             },
-            kdet: match *&builder.kdet {
+            kdet: match builder.kdet {
                 Some(kdet) => kdet,
                 None => return Err(MissingPropertyError::from("kdet")),
             },
-            k1: match *&builder.k1 {
+            k1: match builder.k1 {
                 Some(k1) => k1,
                 None => return Err(MissingPropertyError::from("k1")),
             },
-            bf_2tf: match *&builder.bf_2tf {
+            bf_2tf: match builder.bf_2tf {
                 Some(bf_2tf) => bf_2tf,
                 None => return Err(MissingPropertyError::from("bf/2tf")),
             },
-            h_tw: match *&builder.h_tw {
+            h_tw: match builder.h_tw {
                 Some(h_tw) => h_tw,
                 None => return Err(MissingPropertyError::from("h/tw")),
             },
@@ -250,65 +251,65 @@ impl TryFrom<ShapeBuilder> for HPile {
                     return Err(MissingPropertyError::from("ry"));
                 }
             },
-            j_upper: match *&builder.j_upper {
+            j_upper: match builder.j_upper {
                 Some(j_upper) => j_upper,
                 None => return Err(MissingPropertyError::from("J")),
             },
-            cw: match *&builder.cw {
+            cw: match builder.cw {
                 Some(cw) => cw,
                 None => {
                     return Err(MissingPropertyError::from("Cw"));
                 }
             },
-            wno: match *&builder.wno {
+            wno: match builder.wno {
                 Some(wno) => wno,
                 None => return Err(MissingPropertyError::from("Wno")),
             },
-            sw1: match *&builder.sw1 {
+            sw1: match builder.sw1 {
                 Some(sw1) => sw1,
                 None => {
                     return Err(MissingPropertyError::from("Sw1"));
                 }
             },
-            qf: match *&builder.qf {
+            qf: match builder.qf {
                 Some(qf) => qf,
                 None => return Err(MissingPropertyError::from("Qf")),
             },
-            qw: match *&builder.qw {
+            qw: match builder.qw {
                 Some(qw) => qw,
                 None => return Err(MissingPropertyError::from("Qw")),
             },
-            rts: match *&builder.rts {
+            rts: match builder.rts {
                 Some(rts) => rts,
                 None => return Err(MissingPropertyError::from("rts")),
             },
-            ho: match *&builder.ho {
+            ho: match builder.ho {
                 Some(ho) => ho,
                 None => {
                     return Err(MissingPropertyError::from("ho"));
                 }
             },
-            pa: match *&builder.pa {
+            pa: match builder.pa {
                 Some(pa) => pa,
                 None => return Err(MissingPropertyError::from("PA")),
             },
-            pb: match *&builder.pb {
+            pb: match builder.pb {
                 Some(pb) => pb,
                 None => return Err(MissingPropertyError::from("PB")),
             },
-            pc: match *&builder.pc {
+            pc: match builder.pc {
                 Some(pc) => pc,
                 None => return Err(MissingPropertyError::from("PC")),
             },
-            pd: match *&builder.pd {
+            pd: match builder.pd {
                 Some(pd) => pd,
                 None => return Err(MissingPropertyError::from("PD")),
             },
-            t: match *&builder.t {
+            t: match builder.t {
                 Some(t) => t,
                 None => return Err(MissingPropertyError::from("T")),
             },
-            wgi: match *&builder.wgi {
+            wgi: match builder.wgi {
                 Some(wgi) => wgi,
                 None => return Err(MissingPropertyError::from("WGi")),
             },
@@ -317,6 +318,7 @@ impl TryFrom<ShapeBuilder> for HPile {
 }
 
 #[cfg(test)]
+#[allow(clippy::approx_constant)]
 mod tests {
     use super::*;
     use crate::aisc_shapes::shape_builder::ShapeBuilder;
@@ -1,9 +1,9 @@
 use crate::aisc_shapes::{MissingPropertyError, ShapeBuilder};
 use std::convert::TryFrom;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
-
 /// A struct that models the data for square and rectangular HSS steel profiles
 pub struct HollowStructuralSection {
     /// The shape designation according to the AISC Naming Convention
@@ -86,49 +86,49 @@ impl TryFrom<ShapeBuilder> for HollowStructuralSection {
                     return Err(MissingPropertyError::from("A"));
                 }
             },
-            ht: match *&builder.ht {
+            ht: match builder.ht {
                 Some(ht) => ht,
                 None => {
                     return Err(MissingPropertyError::from("Ht"));
                 }
             },
-            h: match *&builder.h {
+            h: match builder.h {
                 Some(h) => h,
                 None => {
                     return Err(MissingPropertyError::from("h"));
                 }
             },
-            b_upper: match *&builder.b_upper {
+            b_upper: match builder.b_upper {
                 Some(b_upper) => b_upper,
                 None => {
                     return Err(MissingPropertyError::from("B"));
                 }
             },
-            b_lower: match *&builder.b_lower {
+            b_lower: match builder.b_lower {
                 Some(b_lower) => b_lower,
                 None => {
                     return Err(MissingPropertyError::from("b"));
                 }
             },
-            t_nom: match *&builder.t_nom {
+            t_nom: match builder.t_nom {
                 Some(t_nom) => t_nom,
                 None => {
                     return Err(MissingPropertyError::from("tnom"));
                 }
             },
-            tdes: match *&builder.tdes {
+            tdes: match builder.tdes {
                 Some(tdes) => tdes,
                 None => {
                     return Err(MissingPropertyError::from("tdes"));
                 }
             },
-            b_tdes: match *&builder.b_tdes {
+            b_tdes: match builder.b_tdes {
                 Some(b_tdes) => b_tdes,
                 None => {
                     return Err(MissingPropertyError::from("b/tdes"));
                 }
             },
-            h_tdes: match *&builder.h_tdes {
+            h_tdes: match builder.h_tdes {
                 Some(h_tdes) => h_tdes,
                 None => {
                     return Err(MissingPropertyError::from("h/tdes"));
@@ -182,11 +182,11 @@ impl TryFrom<ShapeBuilder> for HollowStructuralSection {
                     return Err(MissingPropertyError::from("ry"));
                 }
             },
-            j_upper: match *&builder.j_upper {
+            j_upper: match builder.j_upper {
                 Some(j_upper) => j_upper,
                 None => return Err(MissingPropertyError::from("J")),
             },
-            c_upper: match *&builder.c_upper {
+            c_upper: match builder.c_upper {
                 Some(c_upper) => c_upper,
                 None => return Err(MissingPropertyError::from("C")),
             },
@@ -0,0 +1,50 @@
+/// (E) Modulus of elasticity of steel, ksi
+pub const MODULUS_OF_ELASTICITY: f64 = 29_000.0;
+
+/// (G) Shear modulus of elasticity of steel, ksi
+pub const SHEAR_MODULUS: f64 = 11_200.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The mechanical properties of a structural steel, ksi
+pub struct Steel {
+    /// (Fy) Specified minimum yield stress, ksi
+    pub fy: f64,
+    /// (Fu) Specified minimum tensile strength, ksi
+    pub fu: f64,
+    /// (E) Modulus of elasticity, ksi
+    pub e: f64,
+    /// (G) Shear modulus of elasticity, ksi
+    pub g: f64,
+}
+
+impl Steel {
+    /// ASTM A992, the preferred material of W shapes
+    pub const A992: Steel = Steel::new(50.0, 65.0);
+    /// ASTM A36
+    pub const A36: Steel = Steel::new(36.0, 58.0);
+    /// ASTM A572 Grade 50
+    pub const A572_GR_50: Steel = Steel::new(50.0, 65.0);
+    /// ASTM A500 Grade C, square and rectangular HSS
+    pub const A500_GR_C_RECTANGULAR: Steel = Steel::new(50.0, 62.0);
+    /// ASTM A500 Grade C, round HSS
+    pub const A500_GR_C_ROUND: Steel = Steel::new(46.0, 62.0);
+    /// ASTM A53 Grade B, pipe
+    pub const A53_GR_B: Steel = Steel::new(35.0, 60.0);
+
+    /// A steel with the given yield stress and tensile strength, ksi,
+    /// and the moduli of elasticity of AISC 360
+    pub const fn new(fy: f64, fu: f64) -> Self {
+        Steel {
+            fy,
+            fu,
+            e: MODULUS_OF_ELASTICITY,
+            g: SHEAR_MODULUS,
+        }
+    }
+
+    /// √(E/Fy), the factor of most width-to-thickness limits
+    pub fn root_e_fy(&self) -> f64 {
+        (self.e / self.fy).sqrt()
+    }
+}
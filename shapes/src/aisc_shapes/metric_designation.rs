@@ -0,0 +1,213 @@
+use crate::aisc_shapes::{AiscShape, Designation, SectionProperties, ShapeFamily};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The metric designations of a shape, e.g. W360X134 for W14X90, either read from
+/// the metric sheet of the AISC shapes database or derived from the US customary
+/// designation (see [MetricDesignation::derive])
+pub struct MetricDesignation {
+    /// The EDI Std Nomenclature of the shape in US customary units
+    pub edi_std_nomenclature: String,
+    /// The AISC Manual Label of the shape in US customary units
+    pub aisc_manual_label: String,
+    /// The EDI Std Nomenclature of the shape in metric units
+    pub metric_edi_std_nomenclature: String,
+    /// The AISC Manual Label of the shape in metric units
+    pub metric_aisc_manual_label: String,
+}
+
+/// Pounds per foot to kilograms per meter
+const KG_PER_M_PER_LB_PER_FT: f64 = 0.45359237 / 0.3048;
+
+/// Nominal depths, in., whose metric nominal depth, mm, isn't
+/// the depth converted to millimeters and rounded to the nearest 10 mm
+const NOMINAL_DEPTH_EXCEPTIONS: [(f64, f64); 5] = [
+    (3.0, 75.0),
+    (12.0, 310.0),
+    (36.0, 920.0),
+    (40.0, 1000.0),
+    (44.0, 1100.0),
+];
+
+impl MetricDesignation {
+    /// Whether the designation is either metric designation of the shape
+    pub fn matches(&self, designation: &str) -> bool {
+        self.metric_aisc_manual_label == designation
+            || self.metric_edi_std_nomenclature == designation
+    }
+
+    /// Derives the metric designation of a shape from its AISC Manual Label,
+    /// following the conventions of the metric sheet of the AISC shapes database:
+    ///
+    /// - W, M, S, HP, C, MC, WT, MT and ST shapes are designated by their metric nominal
+    ///   depth, mm (half the depth of the parent beam for tees), and their nominal mass,
+    ///   kg/m, to three significant figures (e.g. W360X134 for W14X90)
+    /// - Angles, double angles and HSS are designated by their dimensions in mm, legs to
+    ///   the millimeter and the other dimensions to a tenth of a millimeter
+    ///   (e.g. L102X102X12.7 for L4X4X1/2 and HSS152.4X101.6X6.4 for HSS6X4X1/4)
+    ///
+    /// Both metric designations are the same. The mass tabulated in the metric sheet
+    /// for a few light shapes differs from the converted weight in its last digit, read
+    /// the metric sheet with [crate::aisc_shapes::aisc_database::read_metric_designations]
+    /// for those. Pipes are designated by nominal pipe sizes, which have no metric
+    /// equivalent, and have no metric designation.
+    ///
+    /// ```
+    /// use shapes::aisc_shapes::MetricDesignation;
+    ///
+    /// let designation = MetricDesignation::derive("W14X90", "W14X90").unwrap();
+    /// assert_eq!("W360X134", designation.metric_aisc_manual_label);
+    /// ```
+    pub fn derive(edi_std_nomenclature: &str, aisc_manual_label: &str) -> Option<Self> {
+        let metric = match aisc_manual_label.parse::<Designation>().ok()? {
+            Designation::Rolled {
+                family,
+                depth,
+                weight,
+            } => format!(
+                "{}{}X{}",
+                family.shape_type(),
+                millimeters(metric_nominal_depth(family, depth), 1),
+                significant_figures(weight * KG_PER_M_PER_LB_PER_FT, 3)
+            ),
+            Designation::Angle {
+                long_leg,
+                short_leg,
+                thickness,
+            } => format!(
+                "L{}X{}X{}",
+                millimeters(long_leg * 25.4, 0),
+                millimeters(short_leg * 25.4, 0),
+                millimeters(thickness * 25.4, 1)
+            ),
+            Designation::DoubleAngle {
+                long_leg,
+                short_leg,
+                thickness,
+                spacing,
+                ..
+            } => {
+                let back_to_back = ["LLBB", "SLBB"]
+                    .into_iter()
+                    .find(|suffix| aisc_manual_label.ends_with(suffix))
+                    .unwrap_or("");
+                let spacing = if spacing > 0.0 {
+                    format!("X{}", millimeters(spacing * 25.4, 1))
+                } else {
+                    String::new()
+                };
+                format!(
+                    "2L{}X{}X{}{spacing}{back_to_back}",
+                    millimeters(long_leg * 25.4, 0),
+                    millimeters(short_leg * 25.4, 0),
+                    millimeters(thickness * 25.4, 1)
+                )
+            }
+            Designation::RectangularHss {
+                height,
+                width,
+                thickness,
+            } => format!(
+                "HSS{}X{}X{}",
+                millimeters(height * 25.4, 1),
+                millimeters(width * 25.4, 1),
+                millimeters(thickness * 25.4, 1)
+            ),
+            Designation::RoundHss {
+                diameter,
+                thickness,
+            } => format!(
+                "HSS{}X{}",
+                millimeters(diameter * 25.4, 1),
+                millimeters(thickness * 25.4, 1)
+            ),
+            Designation::Pipe { .. } => return None,
+        };
+        Some(MetricDesignation {
+            edi_std_nomenclature: String::from(edi_std_nomenclature),
+            aisc_manual_label: String::from(aisc_manual_label),
+            metric_edi_std_nomenclature: metric.clone(),
+            metric_aisc_manual_label: metric,
+        })
+    }
+
+    /// The metric designations of the shapes, see [MetricDesignation::derive], pipes have none
+    pub fn derive_all(shapes: &[AiscShape]) -> Vec<Self> {
+        shapes
+            .iter()
+            .filter_map(|shape| {
+                MetricDesignation::derive(shape.edi_std_nomenclature(), shape.aisc_manual_label())
+            })
+            .collect()
+    }
+}
+
+// Helper Functions
+/// The metric nominal depth, mm, of a rolled shape with the nominal depth, in.
+fn metric_nominal_depth(family: ShapeFamily, depth: f64) -> f64 {
+    let is_tee = matches!(
+        family,
+        ShapeFamily::WideFlangeTee | ShapeFamily::MiscTee | ShapeFamily::StructuralTee
+    );
+    if is_tee {
+        return metric_nominal_depth(ShapeFamily::WideFlange, depth * 2.0) / 2.0;
+    }
+    NOMINAL_DEPTH_EXCEPTIONS
+        .iter()
+        .find(|(inches, _)| *inches == depth)
+        .map_or_else(|| (depth * 25.4 / 10.0).round() * 10.0, |(_, mm)| *mm)
+}
+
+/// The value rounded to the number of decimals, without trailing zeros
+fn millimeters(value: f64, decimals: i32) -> String {
+    let scale = 10f64.powi(decimals);
+    let rounded = (value * scale).round() / scale;
+    format!("{rounded}")
+}
+
+/// The value rounded to the number of significant figures, without trailing zeros
+fn significant_figures(value: f64, figures: i32) -> String {
+    let digits = value.abs().log10().floor() as i32 + 1;
+    millimeters(value, (figures - digits).max(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn derived(aisc_manual_label: &str) -> Option<String> {
+        MetricDesignation::derive(aisc_manual_label, aisc_manual_label)
+            .map(|designation| designation.metric_aisc_manual_label)
+    }
+
+    #[test]
+    fn rolled_shapes_use_the_nominal_depth_and_mass() {
+        assert_eq!(Some("W360X134"), derived("W14X90").as_deref());
+        assert_eq!(Some("W1100X499"), derived("W44X335").as_deref());
+        assert_eq!(Some("W310X38.7"), derived("W12X26").as_deref());
+        assert_eq!(Some("S75X11.2"), derived("S3X7.5").as_deref());
+        assert_eq!(Some("WT180X67"), derived("WT7X45").as_deref());
+        assert_eq!(Some("ST37.5X5.58"), derived("ST1.5X3.75").as_deref());
+        assert_eq!(Some("C250X44.6"), derived("C10X30").as_deref());
+    }
+
+    #[test]
+    fn angles_and_hss_use_their_dimensions() {
+        assert_eq!(Some("L102X102X12.7"), derived("L4X4X1/2").as_deref());
+        assert_eq!(
+            Some("2L102X76X9.5X9.5LLBB"),
+            derived("2L4X3X3/8X3/8LLBB").as_deref()
+        );
+        assert_eq!(Some("HSS152.4X101.6X6.4"), derived("HSS6X4X1/4").as_deref());
+        assert_eq!(Some("HSS168.3X12.7"), derived("HSS6.625X0.500").as_deref());
+    }
+
+    #[test]
+    fn derived_designations_keep_the_us_customary_designations() {
+        let designation = MetricDesignation::derive("HSS6X4X.250", "HSS6X4X1/4").unwrap();
+        assert_eq!("HSS6X4X.250", designation.edi_std_nomenclature);
+        assert_eq!("HSS6X4X1/4", designation.aisc_manual_label);
+        assert!(designation.matches("HSS152.4X101.6X6.4"));
+        assert_eq!(None, MetricDesignation::derive("Pipe2SCH40", "Pipe2STD"));
+    }
+}
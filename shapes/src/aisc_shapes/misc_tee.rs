@@ -1,7 +1,8 @@
 use crate::aisc_shapes::{MissingPropertyError, ShapeBuilder};
 use std::convert::TryFrom;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 /// A struct that models the data for misc. tee (MT) steel profiles
 pub struct MiscTee {
@@ -100,7 +101,7 @@ impl TryFrom<ShapeBuilder> for MiscTee {
                     return Err(MissingPropertyError::from("AISC Manual Label"));
                 }
             },
-            t_f: match *&builder.t_f {
+            t_f: match builder.t_f {
                 Some(t_f) => t_f,
                 None => {
                     return Err(MissingPropertyError::from("T_F"));
@@ -118,87 +119,87 @@ impl TryFrom<ShapeBuilder> for MiscTee {
                     return Err(MissingPropertyError::from("A"));
                 }
             },
-            d_lower: match *&builder.d_lower {
+            d_lower: match builder.d_lower {
                 Some(d_lower) => d_lower,
                 None => {
                     return Err(MissingPropertyError::from("d"));
                 }
             },
-            ddet: match *&builder.ddet {
+            ddet: match builder.ddet {
                 Some(ddet) => ddet,
                 None => {
                     return Err(MissingPropertyError::from("ddet"));
                 }
             },
-            bf: match *&builder.bf {
+            bf: match builder.bf {
                 Some(bf) => bf,
                 None => {
                     return Err(MissingPropertyError::from("bf"));
                 }
             },
-            bfdet: match *&builder.bfdet {
+            bfdet: match builder.bfdet {
                 Some(bfdet) => bfdet,
                 None => {
                     return Err(MissingPropertyError::from("bfdet"));
                 }
             },
-            tw: match *&builder.tw {
+            tw: match builder.tw {
                 Some(tw) => tw,
                 None => {
                     return Err(MissingPropertyError::from("tw"));
                 }
             },
-            twdet: match *&builder.twdet {
+            twdet: match builder.twdet {
                 Some(twdet) => twdet,
                 None => {
                     return Err(MissingPropertyError::from("twdet"));
                 }
             },
-            twdet_2: match *&builder.twdet_2 {
+            twdet_2: match builder.twdet_2 {
                 Some(twdet) => twdet,
                 None => {
                     return Err(MissingPropertyError::from("twdet/2"));
                 }
             },
-            tf: match *&builder.tf {
+            tf: match builder.tf {
                 Some(tf) => tf,
                 None => {
                     return Err(MissingPropertyError::from("tf"));
                 }
             },
-            tfdet: match *&builder.tfdet {
+            tfdet: match builder.tfdet {
                 Some(tfdet) => tfdet,
                 None => {
                     return Err(MissingPropertyError::from("tfdet"));
                 }
             },
-            kdes: match *&builder.kdes {
+            kdes: match builder.kdes {
                 Some(kdes) => kdes,
                 None => {
                     return Err(MissingPropertyError::from("kdes"));
                 }
             },
-            kdet: match *&builder.kdet {
+            kdet: match builder.kdet {
                 Some(kdet) => kdet,
                 None => return Err(MissingPropertyError::from("kdet")),
             },
-            y_lower: match *&builder.y_lower {
+            y_lower: match builder.y_lower {
                 Some(y_lower) => y_lower,
                 None => {
                     return Err(MissingPropertyError::from("y"));
                 }
             },
-            yp: match *&builder.yp {
+            yp: match builder.yp {
                 Some(yp) => yp,
                 None => {
                     return Err(MissingPropertyError::from("yp"));
                 }
             },
-            bf_2tf: match *&builder.bf_2tf {
+            bf_2tf: match builder.bf_2tf {
                 Some(bf_2tf) => bf_2tf,
                 None => return Err(MissingPropertyError::from("bf/2tf")),
             },
-            d_t: match *&builder.d_t {
+            d_t: match builder.d_t {
                 Some(d_t) => d_t,
                 None => {
                     return Err(MissingPropertyError::from("d/t"));
@@ -252,29 +253,29 @@ impl TryFrom<ShapeBuilder> for MiscTee {
                     return Err(MissingPropertyError::from("ry"));
                 }
             },
-            j_upper: match *&builder.j_upper {
+            j_upper: match builder.j_upper {
                 Some(j_upper) => j_upper,
                 None => return Err(MissingPropertyError::from("J")),
             },
-            cw: match *&builder.cw {
+            cw: match builder.cw {
                 Some(cw) => cw,
                 None => {
                     return Err(MissingPropertyError::from("Cw"));
                 }
             },
-            ro: match *&builder.ro {
+            ro: match builder.ro {
                 Some(ro) => ro,
                 None => {
                     return Err(MissingPropertyError::from("ro"));
                 }
             },
-            h_upper: match *&builder.h_upper {
+            h_upper: match builder.h_upper {
                 Some(h_upper) => h_upper,
                 None => {
                     return Err(MissingPropertyError::from("H"));
                 }
             },
-            wgi: *&builder.wgi,
+            wgi: builder.wgi,
         })
     }
 }
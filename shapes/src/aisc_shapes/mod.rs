@@ -1,18 +1,35 @@
 //! This module is responsible for creating and modeling
 //! AISC steel shapes
 
+/// Reads shapes from a CSV export of the AISC shapes database
+#[cfg(feature = "csv")]
+pub mod aisc_database;
+/// Models an AISC steel shape of any family
+pub mod aisc_shape;
 /// Models hot-rolled angles
 pub mod angle;
 /// Models cee channels
 pub mod cee_channel;
+/// Classifies the elements of shapes for local buckling per AISC 360 Table B4.1
+pub mod classification;
+/// Parses and normalizes the designations of shapes
+pub mod designation;
+/// Searches shapes by partial or misspelled designations
+pub mod designation_search;
 /// Models double angle shapes
 pub mod double_angle;
 /// Manages errors for constructing steel profiles
 pub mod errors;
+/// Generates the outline of the cross-section of shapes
+pub mod geometry;
 /// Models h-pile beams or "hp" steel profiles
 pub mod h_pile;
 /// Models square and rectangular HSS shapes
 pub mod hollow_structural_section;
+/// Models the mechanical properties of structural steels
+pub mod material;
+/// Models the metric designations of shapes
+pub mod metric_designation;
 /// Models misc beams or "m" steel profiles
 pub mod misc_beam;
 /// Models miscelaneous channels
@@ -21,36 +38,90 @@ pub mod misc_channel;
 pub mod misc_tee;
 ///Struct that models pipe shapes
 pub mod pipe;
+/// Dimensioned quantities for shape properties, checked at compile time
+pub mod quantities;
 /// Models round HSS shapes
 pub mod round_hollow_structural_section;
+/// Computes section properties numerically from a cross-section outline
+pub mod section_analysis;
+/// Traits for section properties shared between shape families
+pub mod section_properties;
 /// Manages the construction of steel shapes
 pub mod shape_builder;
+/// Compares the properties of shapes side by side
+pub mod shape_comparison;
+/// Identifies the family of a steel shape
+pub mod shape_family;
+/// Pages and sorts listings of shapes retrieved from a data source
+pub mod shape_page;
+/// Identifies the numeric properties of steel shapes
+pub mod shape_property;
+/// Filters, orders and limits shapes retrieved from a data source
+pub mod shape_query;
 /// Trait for retrieving shapes from the database
 pub mod shape_repository;
+/// Selects the lightest shape satisfying a set of requirements
+pub mod shape_selection;
 /// Models structural beams or "s" steel profiles
 pub mod structural_beam;
 /// Models structural tee shapes
 pub mod structural_tee;
+/// Renders the cross-section of shapes as SVG
+pub mod svg;
+#[cfg(test)]
+pub(crate) mod test_support;
+/// Converts shape properties between US customary and SI units
+pub mod units;
 /// Models wide flange steel profiles
 pub mod wide_flange;
 /// Models wide-flange tee shapes
 pub mod wide_flange_tee;
 
+pub use self::aisc_shape::AiscShape;
 pub use self::angle::Angle;
 pub use self::cee_channel::CeeChannel;
+pub use self::classification::{
+    Classification, CompressionClass, Element, ElementClassification, FlexureClass,
+    SlendernessClassification,
+};
+pub use self::designation::{BackToBack, Designation, PipeWeight};
+pub use self::designation_search::{DesignationMatch, DesignationSearch, MatchKind};
 pub use self::double_angle::DoubleAngle;
-pub use self::errors::MissingPropertyError;
+pub use self::errors::{
+    InvalidDesignationError, InvalidLengthRangeError, InvalidValueError, MetricSheetMismatchError,
+    MissingPropertyError, RepositoryError, UnknownDesignTableError, UnknownShapeFamilyError,
+    UnknownShapePropertyError, UnknownSortKeyError, UnknownUnitSystemError,
+};
+pub use self::geometry::{BoundingBox, CrossSection, Point, Polygon, Region, SectionGeometry};
 pub use self::h_pile::HPile;
 pub use self::hollow_structural_section::HollowStructuralSection;
+pub use self::material::Steel;
+pub use self::metric_designation::MetricDesignation;
 pub use self::misc_beam::MiscBeam;
 pub use self::misc_channel::MiscChannel;
 pub use self::misc_tee::MiscTee;
 pub use self::pipe::Pipe;
+pub use self::quantities::{
+    Area, Length, LinearWeight, MomentOfInertia, SectionModulus, SectionQuantities,
+    TorsionalConstant, WarpingConstant,
+};
 pub use self::round_hollow_structural_section::RoundHollowStructuralSection;
+pub use self::section_analysis::SectionAnalysis;
+pub use self::section_properties::{
+    FlangedSection, RectangularTubularSection, SectionProperties, TorsionalProperties,
+    TubularSection,
+};
 pub use self::shape_builder::ShapeBuilder;
+pub use self::shape_comparison::{PropertyComparison, ShapeComparison, WeightEfficiency};
+pub use self::shape_family::ShapeFamily;
+pub use self::shape_page::{PageRequest, ShapePage, SortKey};
+pub use self::shape_property::ShapeProperty;
+pub use self::shape_query::{PropertyFilter, ShapeQuery, SortDirection, SortOrder};
 pub use self::shape_repository::RoundShapeRepository;
 pub use self::shape_repository::ShapeRepository;
+pub use self::shape_selection::ShapeSelection;
 pub use self::structural_beam::StructuralBeam;
 pub use self::structural_tee::StructuralTee;
+pub use self::units::{PropertyUnit, UnitSystem};
 pub use self::wide_flange::WideFlange;
 pub use self::wide_flange_tee::WideFlangeTee;
@@ -0,0 +1,858 @@
+use crate::aisc_shapes::units::{PropertyUnit, UnitSystem};
+use crate::aisc_shapes::{
+    Angle, CeeChannel, DoubleAngle, HPile, HollowStructuralSection, MiscBeam, MiscChannel, MiscTee,
+    Pipe, RoundHollowStructuralSection, StructuralBeam, StructuralTee, WideFlange, WideFlangeTee,
+};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Defines a quantity stored in US customary units, with the arithmetic
+/// shared by all quantities: adding and subtracting quantities of the same
+/// kind, scaling by a number and dividing two quantities into a ratio
+macro_rules! quantity {
+    ($(#[$meta:meta])* $name:ident, $unit:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(transparent)
+        )]
+        pub struct $name(f64);
+
+        impl $name {
+            /// The kind of unit the quantity is measured in
+            pub const UNIT: PropertyUnit = $unit;
+
+            /// Creates the quantity from a value in US customary units
+            pub const fn from_us_customary(value: f64) -> Self {
+                $name(value)
+            }
+
+            /// Creates the quantity from a value in the units of the unit system,
+            /// see [UnitSystem] for the units of each system
+            pub fn new(value: f64, system: UnitSystem) -> Self {
+                $name(Self::UNIT.convert(value, system, UnitSystem::UsCustomary))
+            }
+
+            /// The value of the quantity in US customary units
+            pub const fn us_customary(&self) -> f64 {
+                self.0
+            }
+
+            /// The value of the quantity in the units of the unit system
+            pub fn value(&self, system: UnitSystem) -> f64 {
+                Self::UNIT.convert(self.0, UnitSystem::UsCustomary, system)
+            }
+
+            /// The absolute value of the quantity
+            pub fn abs(self) -> Self {
+                $name(self.0.abs())
+            }
+        }
+
+        impl fmt::Display for $name {
+            /// Formats the quantity in US customary units, e.g. `999 in.⁴`
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} {}", self.0, Self::UNIT.symbol(UnitSystem::UsCustomary))
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, rhs: $name) -> $name {
+                $name(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: $name) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, rhs: $name) -> $name {
+                $name(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: $name) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name(-self.0)
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = $name;
+
+            fn mul(self, rhs: f64) -> $name {
+                $name(self.0 * rhs)
+            }
+        }
+
+        impl Mul<$name> for f64 {
+            type Output = $name;
+
+            fn mul(self, rhs: $name) -> $name {
+                $name(self * rhs.0)
+            }
+        }
+
+        impl Div<f64> for $name {
+            type Output = $name;
+
+            fn div(self, rhs: f64) -> $name {
+                $name(self.0 / rhs)
+            }
+        }
+
+        impl Div for $name {
+            type Output = f64;
+
+            fn div(self, rhs: $name) -> f64 {
+                self.0 / rhs.0
+            }
+        }
+
+        impl Sum for $name {
+            fn sum<I: Iterator<Item = $name>>(iter: I) -> $name {
+                $name(iter.map(|quantity| quantity.0).sum())
+            }
+        }
+    };
+}
+
+/// Implements the product of two quantities, in both orders
+macro_rules! product {
+    ($lhs:ident * $rhs:ident = $output:ident) => {
+        impl Mul<$rhs> for $lhs {
+            type Output = $output;
+
+            fn mul(self, rhs: $rhs) -> $output {
+                $output(self.0 * rhs.0)
+            }
+        }
+    };
+    ($lhs:ident * $rhs:ident = $output:ident, commutative) => {
+        product!($lhs * $rhs = $output);
+        product!($rhs * $lhs = $output);
+    };
+}
+
+/// Implements the quotient of two quantities
+macro_rules! quotient {
+    ($lhs:ident / $rhs:ident = $output:ident) => {
+        impl Div<$rhs> for $lhs {
+            type Output = $output;
+
+            fn div(self, rhs: $rhs) -> $output {
+                $output(self.0 / rhs.0)
+            }
+        }
+    };
+}
+
+quantity!(
+    /// A length, stored in in. (mm)
+    Length,
+    PropertyUnit::Length
+);
+quantity!(
+    /// An area, stored in in.² (mm²)
+    Area,
+    PropertyUnit::Area
+);
+quantity!(
+    /// A section modulus or statical moment, stored in in.³ (10³ mm³)
+    SectionModulus,
+    PropertyUnit::SectionModulus
+);
+quantity!(
+    /// A moment of inertia, stored in in.⁴ (10⁶ mm⁴)
+    MomentOfInertia,
+    PropertyUnit::MomentOfInertia
+);
+quantity!(
+    /// The torsional constant J, stored in in.⁴ (10³ mm⁴ as tabulated in the metric sheet)
+    TorsionalConstant,
+    PropertyUnit::TorsionalConstant
+);
+quantity!(
+    /// A warping constant, stored in in.⁶ (10⁹ mm⁶)
+    WarpingConstant,
+    PropertyUnit::WarpingConstant
+);
+quantity!(
+    /// A weight per unit length, stored in lb/ft (kg/m)
+    LinearWeight,
+    PropertyUnit::LinearWeight
+);
+
+product!(Length * Length = Area);
+product!(Length * Area = SectionModulus, commutative);
+product!(Length * SectionModulus = MomentOfInertia, commutative);
+product!(Area * Area = MomentOfInertia);
+product!(Area * MomentOfInertia = WarpingConstant, commutative);
+product!(SectionModulus * SectionModulus = WarpingConstant);
+
+quotient!(Area / Length = Length);
+quotient!(SectionModulus / Length = Area);
+quotient!(SectionModulus / Area = Length);
+quotient!(MomentOfInertia / Length = SectionModulus);
+quotient!(MomentOfInertia / Area = Area);
+quotient!(MomentOfInertia / SectionModulus = Length);
+quotient!(WarpingConstant / Area = MomentOfInertia);
+quotient!(WarpingConstant / MomentOfInertia = Area);
+quotient!(WarpingConstant / SectionModulus = SectionModulus);
+
+impl Length {
+    /// Creates a length in inches
+    pub const fn from_inches(inches: f64) -> Self {
+        Length(inches)
+    }
+
+    /// Creates a length in millimeters
+    pub fn from_millimeters(millimeters: f64) -> Self {
+        Length::new(millimeters, UnitSystem::Metric)
+    }
+
+    /// The length in inches
+    pub const fn inches(&self) -> f64 {
+        self.0
+    }
+
+    /// The length in millimeters
+    pub fn millimeters(&self) -> f64 {
+        self.value(UnitSystem::Metric)
+    }
+}
+
+impl Area {
+    /// The side of a square of the area, e.g. the radius of
+    /// gyration from the quotient of a moment of inertia and an area
+    pub fn sqrt(self) -> Length {
+        Length(self.0.sqrt())
+    }
+}
+
+/// The dimensioned properties of a shape, see [WideFlange::quantities]
+/// and the `quantities` method of the other shape families. Dimensionless
+/// properties such as bf/2tf are only available from the raw fields.
+#[derive(Debug, Clone, Copy)]
+pub struct SectionQuantities<'a, T> {
+    shape: &'a T,
+}
+
+/// Adds a `quantities` method to a shape family, returning accessors named after
+/// its fields which wrap each field in its quantity
+macro_rules! impl_section_quantities {
+    ($shape:ident { $($field:ident: $quantity:ident),* $(,)? }
+     $(optional { $($optional:ident: $optional_quantity:ident),* $(,)? })?) => {
+        impl $shape {
+            /// The dimensioned properties of the shape, as typed quantities
+            /// that cannot be mixed up with one another
+            pub fn quantities(&self) -> SectionQuantities<'_, $shape> {
+                SectionQuantities { shape: self }
+            }
+        }
+
+        impl SectionQuantities<'_, $shape> {
+            $(
+                #[doc = concat!("`", stringify!($field), "` as a [", stringify!($quantity), "]")]
+                pub fn $field(&self) -> $quantity {
+                    $quantity::from_us_customary(self.shape.$field)
+                }
+            )*
+            $($(
+                #[doc = concat!(
+                    "`", stringify!($optional), "` as a [", stringify!($optional_quantity),
+                    "], if tabulated for the shape"
+                )]
+                pub fn $optional(&self) -> Option<$optional_quantity> {
+                    self.shape.$optional.map($optional_quantity::from_us_customary)
+                }
+            )*)?
+        }
+    };
+}
+
+impl_section_quantities!(WideFlange {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    d_lower: Length,
+    ddet: Length,
+    bf: Length,
+    bfdet: Length,
+    tw: Length,
+    twdet: Length,
+    twdet_2: Length,
+    tf: Length,
+    tfdet: Length,
+    kdes: Length,
+    kdet: Length,
+    k1: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+    cw: WarpingConstant,
+    wno: Area,
+    sw1: MomentOfInertia,
+    qf: SectionModulus,
+    qw: SectionModulus,
+    rts: Length,
+    ho: Length,
+    pa: Length,
+    pb: Length,
+    pc: Length,
+    pd: Length,
+    t: Length,
+    wgi: Length,
+} optional {
+    wgo: Length,
+});
+
+impl_section_quantities!(MiscBeam {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    d_lower: Length,
+    ddet: Length,
+    bf: Length,
+    bfdet: Length,
+    tw: Length,
+    twdet: Length,
+    twdet_2: Length,
+    tf: Length,
+    tfdet: Length,
+    kdes: Length,
+    kdet: Length,
+    k1: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+    cw: WarpingConstant,
+    wno: Area,
+    sw1: MomentOfInertia,
+    qf: SectionModulus,
+    qw: SectionModulus,
+    rts: Length,
+    ho: Length,
+    pa: Length,
+    pb: Length,
+    pc: Length,
+    pd: Length,
+    t: Length,
+} optional {
+    wgi: Length,
+});
+
+impl_section_quantities!(StructuralBeam {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    d_lower: Length,
+    ddet: Length,
+    bf: Length,
+    bfdet: Length,
+    tw: Length,
+    twdet: Length,
+    twdet_2: Length,
+    tf: Length,
+    tfdet: Length,
+    kdes: Length,
+    kdet: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+    cw: WarpingConstant,
+    wno: Area,
+    sw1: MomentOfInertia,
+    qf: SectionModulus,
+    qw: SectionModulus,
+    rts: Length,
+    ho: Length,
+    pa: Length,
+    pb: Length,
+    pc: Length,
+    pd: Length,
+    t: Length,
+} optional {
+    wgi: Length,
+});
+
+impl_section_quantities!(HPile {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    d_lower: Length,
+    ddet: Length,
+    bf: Length,
+    bfdet: Length,
+    tw: Length,
+    twdet: Length,
+    twdet_2: Length,
+    tf: Length,
+    tfdet: Length,
+    kdes: Length,
+    kdet: Length,
+    k1: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+    cw: WarpingConstant,
+    wno: Area,
+    sw1: MomentOfInertia,
+    qf: SectionModulus,
+    qw: SectionModulus,
+    rts: Length,
+    ho: Length,
+    pa: Length,
+    pb: Length,
+    pc: Length,
+    pd: Length,
+    t: Length,
+    wgi: Length,
+});
+
+impl_section_quantities!(CeeChannel {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    d_lower: Length,
+    ddet: Length,
+    bf: Length,
+    bfdet: Length,
+    tw: Length,
+    twdet: Length,
+    twdet_2: Length,
+    tf: Length,
+    tfdet: Length,
+    kdes: Length,
+    kdet: Length,
+    x_lower: Length,
+    eo: Length,
+    xp: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+    cw: WarpingConstant,
+    wno: Area,
+    sw1: MomentOfInertia,
+    sw2: MomentOfInertia,
+    sw3: MomentOfInertia,
+    qf: SectionModulus,
+    qw: SectionModulus,
+    ro: Length,
+    rts: Length,
+    ho: Length,
+    pa: Length,
+    pb: Length,
+    pc: Length,
+    pd: Length,
+    t: Length,
+} optional {
+    wgi: Length,
+});
+
+impl_section_quantities!(MiscChannel {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    d_lower: Length,
+    ddet: Length,
+    bf: Length,
+    bfdet: Length,
+    tw: Length,
+    twdet: Length,
+    twdet_2: Length,
+    tf: Length,
+    tfdet: Length,
+    kdes: Length,
+    kdet: Length,
+    x_lower: Length,
+    eo: Length,
+    xp: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+    cw: WarpingConstant,
+    wno: Area,
+    sw1: MomentOfInertia,
+    sw2: MomentOfInertia,
+    sw3: MomentOfInertia,
+    qf: SectionModulus,
+    qw: SectionModulus,
+    ro: Length,
+    rts: Length,
+    ho: Length,
+    pa: Length,
+    pb: Length,
+    pc: Length,
+    pd: Length,
+    t: Length,
+} optional {
+    wgi: Length,
+});
+
+impl_section_quantities!(Angle {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    d_lower: Length,
+    b_lower: Length,
+    t_lower: Length,
+    kdes: Length,
+    kdet: Length,
+    x_lower: Length,
+    y_lower: Length,
+    xp: Length,
+    yp: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    iz: MomentOfInertia,
+    rz: Length,
+    sz: SectionModulus,
+    j_upper: TorsionalConstant,
+    cw: WarpingConstant,
+    ro: Length,
+    iw: MomentOfInertia,
+    za: Length,
+    zb: Length,
+    zc: Length,
+    wa: Length,
+    wb: Length,
+    wc: Length,
+    swa: SectionModulus,
+    swc: SectionModulus,
+    sza: SectionModulus,
+    szb: SectionModulus,
+    szc: SectionModulus,
+    pa: Length,
+    pa_2: Length,
+    pb: Length,
+} optional {
+    swb: SectionModulus,
+});
+
+impl_section_quantities!(WideFlangeTee {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    d_lower: Length,
+    ddet: Length,
+    bf: Length,
+    bfdet: Length,
+    tw: Length,
+    twdet: Length,
+    twdet_2: Length,
+    tf: Length,
+    tfdet: Length,
+    kdes: Length,
+    kdet: Length,
+    y_lower: Length,
+    yp: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+    cw: WarpingConstant,
+    ro: Length,
+    pa: Length,
+    pb: Length,
+    pc: Length,
+    pd: Length,
+    wgi: Length,
+} optional {
+    wgo: Length,
+});
+
+impl_section_quantities!(MiscTee {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    d_lower: Length,
+    ddet: Length,
+    bf: Length,
+    bfdet: Length,
+    tw: Length,
+    twdet: Length,
+    twdet_2: Length,
+    tf: Length,
+    tfdet: Length,
+    kdes: Length,
+    kdet: Length,
+    y_lower: Length,
+    yp: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+    cw: WarpingConstant,
+    ro: Length,
+} optional {
+    wgi: Length,
+});
+
+impl_section_quantities!(StructuralTee {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    d_lower: Length,
+    ddet: Length,
+    bf: Length,
+    bfdet: Length,
+    tw: Length,
+    twdet: Length,
+    twdet_2: Length,
+    tf: Length,
+    tfdet: Length,
+    kdes: Length,
+    kdet: Length,
+    y_lower: Length,
+    yp: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+    cw: WarpingConstant,
+    ro: Length,
+} optional {
+    wgi: Length,
+});
+
+impl_section_quantities!(DoubleAngle {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    d_lower: Length,
+    b_lower: Length,
+    t_lower: Length,
+    y_lower: Length,
+    yp: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    ro: Length,
+});
+
+impl_section_quantities!(HollowStructuralSection {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    ht: Length,
+    h: Length,
+    b_upper: Length,
+    b_lower: Length,
+    t_nom: Length,
+    tdes: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+    c_upper: SectionModulus,
+});
+
+impl_section_quantities!(RoundHollowStructuralSection {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    od: Length,
+    t_nom: Length,
+    tdes: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+    c_upper: SectionModulus,
+});
+
+impl_section_quantities!(Pipe {
+    w_upper: LinearWeight,
+    a_upper: Area,
+    od: Length,
+    id: Length,
+    t_nom: Length,
+    tdes: Length,
+    ix: MomentOfInertia,
+    zx: SectionModulus,
+    sx: SectionModulus,
+    rx: Length,
+    iy: MomentOfInertia,
+    zy: SectionModulus,
+    sy: SectionModulus,
+    ry: Length,
+    j_upper: TorsionalConstant,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::test_support::shape;
+    use crate::aisc_shapes::{AiscShape, ShapeBuilder, ShapeProperty};
+
+    fn pipe() -> Pipe {
+        Pipe::try_from(
+            ShapeBuilder::new()
+                .with_edi_std_nomenclature(String::from("Pipe26STD"))
+                .with_aisc_manual_label(String::from("Pipe26STD"))
+                .with_w_upper(103.0)
+                .with_a_upper(28.2)
+                .with_od(26.0)
+                .with_id(25.3)
+                .with_t_nom(0.375)
+                .with_tdes(0.349)
+                .with_d_t(74.5)
+                .with_ix(2320.0)
+                .with_zx(230.0)
+                .with_sx(178.0)
+                .with_rx(9.07)
+                .with_iy(2320.0)
+                .with_zy(230.0)
+                .with_sy(178.0)
+                .with_ry(9.07)
+                .with_j_upper(4640.0),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn quantities_of_the_same_kind_add_and_compare() {
+        let flange = Length::from_inches(0.75);
+        let depth = Length::from_inches(14.0);
+        assert_eq!(Length::from_inches(12.5), depth - flange - flange);
+        assert!(flange < depth);
+        assert_eq!(2.0, (depth * 2.0) / depth);
+        assert_eq!(
+            Area::from_us_customary(3.0),
+            [1.0, 2.0].into_iter().map(Area::from_us_customary).sum()
+        );
+    }
+
+    #[test]
+    fn products_and_quotients_have_the_right_dimensions() {
+        let b = Length::from_inches(2.0);
+        let h = Length::from_inches(6.0);
+        let area: Area = b * h;
+        let ix: MomentOfInertia = area * h * h / 12.0;
+        let sx: SectionModulus = ix / (h / 2.0);
+        assert_eq!(MomentOfInertia::from_us_customary(36.0), ix);
+        assert_eq!(SectionModulus::from_us_customary(12.0), sx);
+        assert_eq!(h / 2.0, ix / sx);
+        assert_eq!(Length::from_inches(3.0f64.sqrt()), (ix / area).sqrt());
+        let cw: WarpingConstant = ix * area;
+        assert_eq!(ix, cw / area);
+    }
+
+    #[test]
+    fn quantities_convert_between_unit_systems() {
+        assert_eq!(25.4, Length::from_inches(1.0).millimeters());
+        assert!((Length::from_millimeters(356.0).inches() - 14.02).abs() < 0.01);
+        let ix = MomentOfInertia::new(416.0, UnitSystem::Metric);
+        assert!((ix.us_customary() - 999.0).abs() < 1.0);
+        let w = LinearWeight::from_us_customary(90.0);
+        assert!((w.value(UnitSystem::Metric) - 134.0).abs() < 0.5);
+        assert_eq!(
+            "999 in.⁴",
+            MomentOfInertia::from_us_customary(999.0).to_string()
+        );
+    }
+
+    #[test]
+    fn shapes_expose_their_properties_as_quantities() {
+        let pipe = pipe();
+        let quantities = pipe.quantities();
+        assert_eq!(LinearWeight::from_us_customary(103.0), quantities.w_upper());
+        assert_eq!(Length::from_inches(26.0), quantities.od());
+        assert_eq!(pipe.ix, quantities.ix().us_customary());
+        let rx = (quantities.ix() / quantities.a_upper()).sqrt();
+        assert!((rx - quantities.rx()).abs() < Length::from_inches(0.01));
+    }
+
+    #[test]
+    fn torsional_constants_convert_like_the_metric_sheet() {
+        // W14X90 is listed as W360X134 with J = 1690 10³ mm⁴ in the metric sheet
+        let w14x90: WideFlange = shape("W14X90", &[(ShapeProperty::JUpper, 4.06)]);
+        let j = w14x90.quantities().j_upper();
+        assert!((j.value(UnitSystem::Metric) - 1690.0).abs() < 0.5);
+        let metric = AiscShape::from(w14x90).to_unit_system(UnitSystem::Metric);
+        let (_, j_metric) = metric
+            .into_iter()
+            .find(|(property, _)| *property == ShapeProperty::JUpper)
+            .unwrap();
+        assert!((j_metric - j.value(UnitSystem::Metric)).abs() < 1e-9);
+        assert_eq!("4.06 in.⁴", j.to_string());
+    }
+}
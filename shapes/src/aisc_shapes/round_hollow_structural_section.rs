@@ -1,9 +1,9 @@
 use crate::aisc_shapes::{MissingPropertyError, ShapeBuilder};
 use std::convert::TryFrom;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
-
 /// A struct that models the data for round HSS steel profiles
 pub struct RoundHollowStructuralSection {
     /// The shape designation according to the AISC Naming Convention
@@ -63,91 +63,91 @@ impl TryFrom<ShapeBuilder> for RoundHollowStructuralSection {
                     return Err(MissingPropertyError::from("AISC Manual Label"));
                 }
             },
-            w_upper: match *&builder.w_upper {
+            w_upper: match builder.w_upper {
                 Some(w) => w,
                 None => {
                     return Err(MissingPropertyError::from("W"));
                 }
             },
-            a_upper: match *&builder.a_upper {
+            a_upper: match builder.a_upper {
                 Some(a_upper) => a_upper,
                 None => {
                     return Err(MissingPropertyError::from("A"));
                 }
             },
-            od: match *&builder.od {
+            od: match builder.od {
                 Some(od) => od,
                 None => return Err(MissingPropertyError::from("OD")),
             },
-            t_nom: match *&builder.t_nom {
+            t_nom: match builder.t_nom {
                 Some(t_nom) => t_nom,
                 None => {
                     return Err(MissingPropertyError::from("tnom"));
                 }
             },
-            tdes: match *&builder.tdes {
+            tdes: match builder.tdes {
                 Some(tdes) => tdes,
                 None => {
                     return Err(MissingPropertyError::from("tdes"));
                 }
             },
-            d_t: match *&builder.d_t {
+            d_t: match builder.d_t {
                 Some(d_t) => d_t,
                 None => return Err(MissingPropertyError::from("D/t")),
             },
-            ix: match *&builder.ix {
+            ix: match builder.ix {
                 Some(ix) => ix,
                 None => {
                     return Err(MissingPropertyError::from("Ix"));
                 }
             },
-            zx: match *&builder.zx {
+            zx: match builder.zx {
                 Some(zx) => zx,
                 None => {
                     return Err(MissingPropertyError::from("Zx"));
                 }
             },
-            sx: match *&builder.sx {
+            sx: match builder.sx {
                 Some(sx) => sx,
                 None => {
                     return Err(MissingPropertyError::from("Sx"));
                 }
             },
-            rx: match *&builder.rx {
+            rx: match builder.rx {
                 Some(rx) => rx,
                 None => {
                     return Err(MissingPropertyError::from("rx"));
                 }
             },
-            iy: match *&builder.iy {
+            iy: match builder.iy {
                 Some(iy) => iy,
                 None => {
                     return Err(MissingPropertyError::from("Iy"));
                 }
             },
-            zy: match *&builder.zy {
+            zy: match builder.zy {
                 Some(zy) => zy,
                 None => {
                     return Err(MissingPropertyError::from("Zy"));
                 }
             },
-            sy: match *&builder.sy {
+            sy: match builder.sy {
                 Some(sy) => sy,
                 None => {
                     return Err(MissingPropertyError::from("Sy"));
                 }
             },
-            ry: match *&builder.ry {
+            ry: match builder.ry {
                 Some(ry) => ry,
                 None => {
                     return Err(MissingPropertyError::from("ry"));
                 }
             },
-            j_upper: match *&builder.j_upper {
+            j_upper: match builder.j_upper {
                 Some(j_upper) => j_upper,
                 None => return Err(MissingPropertyError::from("J")),
             },
-            c_upper: match *&builder.c_upper {
+            c_upper: match builder.c_upper {
                 Some(c_upper) => c_upper,
                 None => return Err(MissingPropertyError::from("C")),
             },
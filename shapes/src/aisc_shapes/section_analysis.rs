@@ -0,0 +1,356 @@
+use crate::aisc_shapes::geometry::{CrossSection, Point, Polygon};
+
+/// The tolerance, relative to the depth of the cross-section,
+/// the plastic neutral axes are located within
+const NEUTRAL_AXIS_TOLERANCE: f64 = 1e-9;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The section properties of a cross-section computed numerically from its outline,
+/// in the units and coordinates of the [CrossSection] (in., y pointing up).
+///
+/// Moments of inertia are taken about the centroidal axes parallel to x and y,
+/// elastic section moduli are the smaller of both extreme fibers and plastic
+/// section moduli are taken about the equal-area axes parallel to x and y.
+pub struct SectionAnalysis {
+    /// (A) Cross-sectional area, in.²
+    pub area: f64,
+    /// The center of gravity, in.
+    pub centroid: Point,
+    /// (Ix) Moment of inertia about the centroidal x-axis, in.⁴
+    pub ix: f64,
+    /// (Iy) Moment of inertia about the centroidal y-axis, in.⁴
+    pub iy: f64,
+    /// (Ixy) Product of inertia about the centroidal axes, in.⁴
+    pub ixy: f64,
+    /// Moment of inertia about the major principal axis, in.⁴
+    /// (Iw for single angles)
+    pub i_major: f64,
+    /// Moment of inertia about the minor principal axis, in.⁴
+    /// (Iz for single angles)
+    pub i_minor: f64,
+    /// The angle of the major principal axis, counterclockwise from the x-axis, radians
+    pub principal_angle: f64,
+    /// (Sx) Elastic section modulus about the x-axis, in.³
+    pub sx: f64,
+    /// (Sy) Elastic section modulus about the y-axis, in.³
+    pub sy: f64,
+    /// (Zx) Plastic section modulus about the x-axis, in.³
+    pub zx: f64,
+    /// (Zy) Plastic section modulus about the y-axis, in.³
+    pub zy: f64,
+    /// The position of the plastic neutral axes: the x coordinate of the
+    /// vertical axis and the y coordinate of the horizontal axis, in.
+    pub plastic_neutral_axis: Point,
+    /// (rx) Radius of gyration about the x-axis, in.
+    pub rx: f64,
+    /// (ry) Radius of gyration about the y-axis, in.
+    pub ry: f64,
+    /// Radius of gyration about the major principal axis, in.
+    pub r_major: f64,
+    /// Radius of gyration about the minor principal axis, in.
+    /// (rz for single angles)
+    pub r_minor: f64,
+}
+
+impl SectionAnalysis {
+    /// Computes the section properties of the cross-section,
+    /// holes are subtracted from the regions containing them
+    pub fn of(cross_section: &CrossSection) -> Self {
+        let moments = cross_section
+            .polygons()
+            .map(Moments::of)
+            .fold(Moments::default(), Moments::add);
+        let area = moments.area;
+        let centroid = Point::new(moments.qy / area, moments.qx / area);
+        // Parallel axis theorem, from the origin to the centroid
+        let ix = moments.ixx - area * centroid.y * centroid.y;
+        let iy = moments.iyy - area * centroid.x * centroid.x;
+        let ixy = moments.ixy - area * centroid.x * centroid.y;
+        let average = (ix + iy) / 2.0;
+        let radius = ((ix - iy) / 2.0).hypot(ixy);
+        let bounds = cross_section.bounding_box();
+        let x_fiber = (bounds.max.x - centroid.x).max(centroid.x - bounds.min.x);
+        let y_fiber = (bounds.max.y - centroid.y).max(centroid.y - bounds.min.y);
+        let (yp, zx) = plastic_modulus(cross_section, Axis::Horizontal);
+        let (xp, zy) = plastic_modulus(cross_section, Axis::Vertical);
+        SectionAnalysis {
+            area,
+            centroid,
+            ix,
+            iy,
+            ixy,
+            i_major: average + radius,
+            i_minor: average - radius,
+            principal_angle: 0.5 * (-2.0 * ixy).atan2(ix - iy),
+            sx: ix / y_fiber,
+            sy: iy / x_fiber,
+            zx,
+            zy,
+            plastic_neutral_axis: Point::new(xp, yp),
+            rx: (ix / area).sqrt(),
+            ry: (iy / area).sqrt(),
+            r_major: ((average + radius) / area).sqrt(),
+            r_minor: ((average - radius) / area).sqrt(),
+        }
+    }
+
+    /// (tan(α)) The tangent of the angle between the geometric and principal
+    /// axes, as tabulated for single angles
+    pub fn tan_alpha(&self) -> f64 {
+        self.principal_angle.tan().abs()
+    }
+}
+
+/// The integrals of a region over the cross-section, about the origin
+#[derive(Debug, Clone, Copy, Default)]
+struct Moments {
+    /// ∫ dA
+    area: f64,
+    /// ∫ y dA
+    qx: f64,
+    /// ∫ x dA
+    qy: f64,
+    /// ∫ y² dA
+    ixx: f64,
+    /// ∫ x² dA
+    iyy: f64,
+    /// ∫ xy dA
+    ixy: f64,
+}
+
+impl Moments {
+    /// The integrals over the area enclosed by the polygon by Green's theorem,
+    /// negative for clockwise polygons so that holes are subtracted
+    fn of(polygon: &Polygon) -> Self {
+        polygon.edges().fold(Moments::default(), |moments, (a, b)| {
+            let cross = a.x * b.y - b.x * a.y;
+            Moments {
+                area: moments.area + cross / 2.0,
+                qx: moments.qx + (a.y + b.y) * cross / 6.0,
+                qy: moments.qy + (a.x + b.x) * cross / 6.0,
+                ixx: moments.ixx + (a.y * a.y + a.y * b.y + b.y * b.y) * cross / 12.0,
+                iyy: moments.iyy + (a.x * a.x + a.x * b.x + b.x * b.x) * cross / 12.0,
+                ixy: moments.ixy
+                    + (a.x * b.y + 2.0 * a.x * a.y + 2.0 * b.x * b.y + b.x * a.y) * cross / 24.0,
+            }
+        })
+    }
+
+    fn add(self, other: Moments) -> Moments {
+        Moments {
+            area: self.area + other.area,
+            qx: self.qx + other.qx,
+            qy: self.qy + other.qy,
+            ixx: self.ixx + other.ixx,
+            iyy: self.iyy + other.iyy,
+            ixy: self.ixy + other.ixy,
+        }
+    }
+}
+
+/// The direction of an axis of bending
+#[derive(Debug, Clone, Copy)]
+enum Axis {
+    /// Parallel to x, the axis is located by its y coordinate
+    Horizontal,
+    /// Parallel to y, the axis is located by its x coordinate
+    Vertical,
+}
+
+impl Axis {
+    /// The coordinate of the point measured perpendicular to the axis
+    fn coordinate(&self, point: Point) -> f64 {
+        match self {
+            Axis::Horizontal => point.y,
+            Axis::Vertical => point.x,
+        }
+    }
+
+    /// The point of the segment from `a` to `b` with the coordinate
+    fn intersection(&self, a: Point, b: Point, coordinate: f64) -> Point {
+        let t = (coordinate - self.coordinate(a)) / (self.coordinate(b) - self.coordinate(a));
+        Point::new(a.x + t * (b.x - a.x), a.y + t * (b.y - a.y))
+    }
+}
+
+/// The part of the polygon on the far side of the axis at `coordinate`,
+/// by Sutherland–Hodgman clipping against a single half-plane
+fn clip_beyond(polygon: &Polygon, axis: Axis, coordinate: f64) -> Polygon {
+    let inside = |point: Point| axis.coordinate(point) >= coordinate;
+    let mut vertices = Vec::new();
+    for (a, b) in polygon.edges() {
+        match (inside(a), inside(b)) {
+            (true, true) => vertices.push(b),
+            (true, false) => vertices.push(axis.intersection(a, b, coordinate)),
+            (false, true) => {
+                vertices.push(axis.intersection(a, b, coordinate));
+                vertices.push(b);
+            }
+            (false, false) => {}
+        }
+    }
+    Polygon::new(vertices)
+}
+
+/// The moments of the part of the cross-section beyond the axis at `coordinate`
+fn moments_beyond(cross_section: &CrossSection, axis: Axis, coordinate: f64) -> Moments {
+    cross_section
+        .polygons()
+        .map(|polygon| Moments::of(&clip_beyond(polygon, axis, coordinate)))
+        .fold(Moments::default(), Moments::add)
+}
+
+/// Locates the plastic neutral axis dividing the cross-section in two equal areas,
+/// returning its coordinate and the plastic section modulus about it
+fn plastic_modulus(cross_section: &CrossSection, axis: Axis) -> (f64, f64) {
+    let bounds = cross_section.bounding_box();
+    let (mut low, mut high) = (axis.coordinate(bounds.min), axis.coordinate(bounds.max));
+    let tolerance = (high - low) * NEUTRAL_AXIS_TOLERANCE;
+    let total = moments_beyond(cross_section, axis, low);
+    // The area beyond the axis decreases as the axis moves, bisect for half of it
+    while high - low > tolerance {
+        let middle = (low + high) / 2.0;
+        if moments_beyond(cross_section, axis, middle).area > total.area / 2.0 {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    let neutral_axis = (low + high) / 2.0;
+    let first_moment = |moments: Moments| match axis {
+        Axis::Horizontal => moments.qx,
+        Axis::Vertical => moments.qy,
+    };
+    // ∫ |c - c_p| dA, the first moment of the far part about the axis
+    // less the first moment of the near part about the axis
+    let beyond = moments_beyond(cross_section, axis, neutral_axis);
+    let far = first_moment(beyond) - beyond.area * neutral_axis;
+    let near =
+        (first_moment(total) - first_moment(beyond)) - (total.area - beyond.area) * neutral_axis;
+    (neutral_axis, far - near)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::test_support::{flanged, shape};
+    use crate::aisc_shapes::{
+        Angle, HollowStructuralSection, SectionGeometry, ShapeProperty, WideFlange,
+    };
+
+    /// Asserts the computed value is within 2% of the tabulated value
+    fn assert_tabulated(name: &str, tabulated: f64, computed: f64) {
+        assert!(
+            (computed - tabulated).abs() <= tabulated.abs() * 0.02,
+            "{name}: tabulated {tabulated}, computed {computed}"
+        );
+    }
+
+    fn rectangle(width: f64, height: f64) -> CrossSection {
+        CrossSection::from_region(crate::aisc_shapes::Region::solid(Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(width, 0.0),
+            Point::new(width, height),
+            Point::new(0.0, height),
+        ])))
+    }
+
+    #[test]
+    fn rectangles_match_closed_form_properties() {
+        let analysis = SectionAnalysis::of(&rectangle(2.0, 6.0));
+        assert!((analysis.area - 12.0).abs() < 1e-9);
+        assert!((analysis.centroid.x - 1.0).abs() < 1e-9);
+        assert!((analysis.centroid.y - 3.0).abs() < 1e-9);
+        // bh³/12, bh²/6 and bh²/4
+        assert!((analysis.ix - 36.0).abs() < 1e-9);
+        assert!((analysis.iy - 4.0).abs() < 1e-9);
+        assert!(analysis.ixy.abs() < 1e-9);
+        assert!((analysis.sx - 12.0).abs() < 1e-9);
+        assert!((analysis.zx - 18.0).abs() < 1e-6);
+        assert!((analysis.zy - 6.0).abs() < 1e-6);
+        assert!((analysis.plastic_neutral_axis.y - 3.0).abs() < 1e-6);
+        assert!((analysis.i_major - 36.0).abs() < 1e-9);
+        assert!(analysis.principal_angle.abs() < 1e-9);
+        assert!((analysis.rx - 3.0f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn translating_a_section_keeps_its_properties() {
+        let analysis = SectionAnalysis::of(&rectangle(2.0, 6.0));
+        let moved = SectionAnalysis::of(&rectangle(2.0, 6.0).translated(10.0, -4.0));
+        assert!((moved.centroid.x - 11.0).abs() < 1e-9);
+        assert!((moved.ix - analysis.ix).abs() < 1e-6);
+        assert!((moved.zy - analysis.zy).abs() < 1e-6);
+    }
+
+    #[test]
+    fn wide_flanges_match_their_tabulated_properties() {
+        let w14x90: WideFlange = shape("W14X90", &flanged(14.0, 14.5, 0.44, 0.71, 1.31));
+        let analysis = SectionAnalysis::of(&w14x90.cross_section());
+        assert_tabulated("A", 26.5, analysis.area);
+        assert_tabulated("Ix", 999.0, analysis.ix);
+        assert_tabulated("Zx", 157.0, analysis.zx);
+        assert_tabulated("Sx", 143.0, analysis.sx);
+        assert_tabulated("rx", 6.14, analysis.rx);
+        assert_tabulated("Iy", 362.0, analysis.iy);
+        assert_tabulated("Zy", 75.6, analysis.zy);
+        assert_tabulated("Sy", 49.9, analysis.sy);
+        assert_tabulated("ry", 3.70, analysis.ry);
+        assert!(analysis.ixy.abs() < 1e-6);
+        assert!((analysis.plastic_neutral_axis.y - 7.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn angles_match_their_tabulated_properties() {
+        let l8x6x1: Angle = shape(
+            "L8X6X1",
+            &[
+                (ShapeProperty::DLower, 6.0),
+                (ShapeProperty::BLower, 8.0),
+                (ShapeProperty::TLower, 1.0),
+                (ShapeProperty::Kdes, 1.5),
+            ],
+        );
+        let analysis = SectionAnalysis::of(&l8x6x1.cross_section());
+        assert_tabulated("A", 13.1, analysis.area);
+        assert_tabulated("x", 1.65, analysis.centroid.x);
+        assert_tabulated("y", 2.65, analysis.centroid.y);
+        assert_tabulated("Ix", 80.9, analysis.ix);
+        assert_tabulated("Zx", 27.3, analysis.zx);
+        assert_tabulated("Sx", 15.1, analysis.sx);
+        assert_tabulated("Iy", 38.8, analysis.iy);
+        assert_tabulated("Zy", 16.2, analysis.zy);
+        assert_tabulated("Sy", 8.92, analysis.sy);
+        assert_tabulated("Iz", 21.3, analysis.i_minor);
+        assert_tabulated("rz", 1.28, analysis.r_minor);
+        assert_tabulated("tan(α)", 0.542, analysis.tan_alpha());
+        assert!((analysis.plastic_neutral_axis.y - 1.45).abs() < 0.1);
+        assert!((analysis.plastic_neutral_axis.x - 0.819).abs() < 0.1);
+        // The principal axes are rotated from the geometric axes, Iz + Iw = Ix + Iy
+        assert!(analysis.ixy.abs() > 1.0);
+        assert!((analysis.i_major + analysis.i_minor - analysis.ix - analysis.iy).abs() < 1e-6);
+    }
+
+    #[test]
+    fn hollow_structural_sections_match_their_tabulated_properties() {
+        let hss8x4x1_2: HollowStructuralSection = shape(
+            "HSS8X4X1/2",
+            &[
+                (ShapeProperty::Ht, 8.0),
+                (ShapeProperty::BUpper, 4.0),
+                (ShapeProperty::Tdes, 0.465),
+            ],
+        );
+        let analysis = SectionAnalysis::of(&hss8x4x1_2.cross_section());
+        assert_tabulated("A", 9.74, analysis.area);
+        assert_tabulated("Ix", 71.8, analysis.ix);
+        assert_tabulated("Zx", 23.5, analysis.zx);
+        assert_tabulated("Sx", 17.9, analysis.sx);
+        assert_tabulated("rx", 2.71, analysis.rx);
+        assert_tabulated("Iy", 23.6, analysis.iy);
+        assert_tabulated("Zy", 14.3, analysis.zy);
+        assert_tabulated("Sy", 11.8, analysis.sy);
+        assert_tabulated("ry", 1.56, analysis.ry);
+    }
+}
@@ -0,0 +1,1696 @@
+use crate::aisc_shapes::{
+    Angle, CeeChannel, DoubleAngle, HPile, HollowStructuralSection, MiscBeam, MiscChannel, MiscTee,
+    Pipe, RoundHollowStructuralSection, ShapeProperty, StructuralBeam, StructuralTee, WideFlange,
+    WideFlangeTee,
+};
+
+/// Designations and section properties shared by every AISC steel shape
+pub trait SectionProperties {
+    /// The shape designation according to the AISC Naming Convention
+    /// for Structural Steel Products for Use in Electronic Data Interchange (EDI)
+    fn edi_std_nomenclature(&self) -> &str;
+    /// The shape designation as seen in the AISC Steel Construction Manual, 16th Edition
+    fn aisc_manual_label(&self) -> &str;
+    /// (W) Nominal weight, lb/ft (kg/m)
+    fn w_upper(&self) -> f64;
+    /// (A) Cross-sectional area, in.2 (mm2)
+    fn a_upper(&self) -> f64;
+    /// (Ix) Moment of inertia about the x-axis, in.4 (´106 mm4)
+    fn ix(&self) -> f64;
+    /// (Zx) Plastic section modulus about the x-axis, in.3 (´103 mm3)
+    fn zx(&self) -> f64;
+    /// (Sx) Elastic section modulus about the x-axis, in.3 (´103 mm3)
+    fn sx(&self) -> f64;
+    /// Radius of gyration about the x-axis, in. (mm)
+    fn rx(&self) -> f64;
+    /// (Iy) Moment of inertia about the y-axis, in.4 (´106 mm4)
+    fn iy(&self) -> f64;
+    /// (Zy) Plastic section modulus about the y-axis, in.3 (´103 mm3)
+    fn zy(&self) -> f64;
+    /// (Sy) Elastic section modulus about the y-axis, in.3 (´103 mm3)
+    fn sy(&self) -> f64;
+    /// Radius of gyration about the y-axis, in. (mm)
+    fn ry(&self) -> f64;
+    /// The value of a numeric property, `None` when the shape
+    /// does not define the property or an optional property is missing
+    fn property(&self, property: ShapeProperty) -> Option<f64>;
+}
+
+/// Dimensions of shapes made up of flanges and a web,
+/// implemented by W, M, S, HP, C, MC, WT, MT and ST shapes
+pub trait FlangedSection: SectionProperties {
+    /// (d) Overall depth of member, in. (mm)
+    fn d_lower(&self) -> f64;
+    /// Width of flange, in. (mm)
+    fn bf(&self) -> f64;
+    /// Thickness of flange, in. (mm)
+    fn tf(&self) -> f64;
+    /// Thickness of web, in. (mm)
+    fn tw(&self) -> f64;
+    /// Distance from outer face of flange to web toe of fillet used for design, in. (mm)
+    fn kdes(&self) -> f64;
+}
+
+/// Dimensions of round tubular shapes, implemented by round HSS and pipe
+pub trait TubularSection: SectionProperties {
+    /// (OD) Outside diameter, in. (mm)
+    fn od(&self) -> f64;
+    /// Nominal wall thickness, in. (mm)
+    fn t_nom(&self) -> f64;
+    /// Design wall thickness, in. (mm)
+    fn tdes(&self) -> f64;
+    /// (D/t) Slenderness ratio of the wall
+    fn d_t(&self) -> f64;
+}
+
+/// Dimensions of square and rectangular HSS shapes
+pub trait RectangularTubularSection: SectionProperties {
+    /// (Ht) Overall depth of square HSS or longer wall of rectangular HSS, in. (mm)
+    fn ht(&self) -> f64;
+    /// (B) Overall width of square HSS or shorter wall of rectangular HSS, in. (mm)
+    fn b_upper(&self) -> f64;
+    /// Nominal wall thickness, in. (mm)
+    fn t_nom(&self) -> f64;
+    /// Design wall thickness, in. (mm)
+    fn tdes(&self) -> f64;
+    /// (b/tdes) Slenderness ratio for square HSS or shorter wall of rectangular HSS
+    fn b_tdes(&self) -> f64;
+    /// (h/tdes) Slenderness ratio for square HSS or longer wall of rectangular HSS
+    fn h_tdes(&self) -> f64;
+}
+
+/// Torsional properties, implemented by every shape family that
+/// tabulates a torsional constant (all families except double angles)
+pub trait TorsionalProperties: SectionProperties {
+    /// (J) Torsional constant, in.4 (´103 mm4)
+    fn j_upper(&self) -> f64;
+    /// (Cw) Warping constant, in.6 (´109 mm6), `None` for HSS and pipe
+    fn cw(&self) -> Option<f64>;
+    /// Polar radius of gyration about the shear center, in. (mm),
+    /// `None` for doubly symmetric shapes where it is not tabulated
+    fn ro(&self) -> Option<f64>;
+    /// (H) Flexural constant, `None` for doubly symmetric shapes where it is not tabulated
+    fn h_upper(&self) -> Option<f64>;
+}
+
+impl SectionProperties for WideFlange {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::DLower => Some(self.d_lower),
+            ShapeProperty::Ddet => Some(self.ddet),
+            ShapeProperty::Bf => Some(self.bf),
+            ShapeProperty::Bfdet => Some(self.bfdet),
+            ShapeProperty::Tw => Some(self.tw),
+            ShapeProperty::Twdet => Some(self.twdet),
+            ShapeProperty::Twdet2 => Some(self.twdet_2),
+            ShapeProperty::Tf => Some(self.tf),
+            ShapeProperty::Tfdet => Some(self.tfdet),
+            ShapeProperty::Kdes => Some(self.kdes),
+            ShapeProperty::Kdet => Some(self.kdet),
+            ShapeProperty::K1 => Some(self.k1),
+            ShapeProperty::Bf2tf => Some(self.bf_2tf),
+            ShapeProperty::HTw => Some(self.h_tw),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::Cw => Some(self.cw),
+            ShapeProperty::Wno => Some(self.wno),
+            ShapeProperty::Sw1 => Some(self.sw1),
+            ShapeProperty::Qf => Some(self.qf),
+            ShapeProperty::Qw => Some(self.qw),
+            ShapeProperty::Rts => Some(self.rts),
+            ShapeProperty::Ho => Some(self.ho),
+            ShapeProperty::Pa => Some(self.pa),
+            ShapeProperty::Pb => Some(self.pb),
+            ShapeProperty::Pc => Some(self.pc),
+            ShapeProperty::Pd => Some(self.pd),
+            ShapeProperty::T => Some(self.t),
+            ShapeProperty::Wgi => Some(self.wgi),
+            ShapeProperty::Wgo => self.wgo,
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for MiscBeam {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::DLower => Some(self.d_lower),
+            ShapeProperty::Ddet => Some(self.ddet),
+            ShapeProperty::Bf => Some(self.bf),
+            ShapeProperty::Bfdet => Some(self.bfdet),
+            ShapeProperty::Tw => Some(self.tw),
+            ShapeProperty::Twdet => Some(self.twdet),
+            ShapeProperty::Twdet2 => Some(self.twdet_2),
+            ShapeProperty::Tf => Some(self.tf),
+            ShapeProperty::Tfdet => Some(self.tfdet),
+            ShapeProperty::Kdes => Some(self.kdes),
+            ShapeProperty::Kdet => Some(self.kdet),
+            ShapeProperty::K1 => Some(self.k1),
+            ShapeProperty::Bf2tf => Some(self.bf_2tf),
+            ShapeProperty::HTw => Some(self.h_tw),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::Cw => Some(self.cw),
+            ShapeProperty::Wno => Some(self.wno),
+            ShapeProperty::Sw1 => Some(self.sw1),
+            ShapeProperty::Qf => Some(self.qf),
+            ShapeProperty::Qw => Some(self.qw),
+            ShapeProperty::Rts => Some(self.rts),
+            ShapeProperty::Ho => Some(self.ho),
+            ShapeProperty::Pa => Some(self.pa),
+            ShapeProperty::Pb => Some(self.pb),
+            ShapeProperty::Pc => Some(self.pc),
+            ShapeProperty::Pd => Some(self.pd),
+            ShapeProperty::T => Some(self.t),
+            ShapeProperty::Wgi => self.wgi,
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for StructuralBeam {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::DLower => Some(self.d_lower),
+            ShapeProperty::Ddet => Some(self.ddet),
+            ShapeProperty::Bf => Some(self.bf),
+            ShapeProperty::Bfdet => Some(self.bfdet),
+            ShapeProperty::Tw => Some(self.tw),
+            ShapeProperty::Twdet => Some(self.twdet),
+            ShapeProperty::Twdet2 => Some(self.twdet_2),
+            ShapeProperty::Tf => Some(self.tf),
+            ShapeProperty::Tfdet => Some(self.tfdet),
+            ShapeProperty::Kdes => Some(self.kdes),
+            ShapeProperty::Kdet => Some(self.kdet),
+            ShapeProperty::Bf2tf => Some(self.bf_2tf),
+            ShapeProperty::HTw => Some(self.h_tw),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::Cw => Some(self.cw),
+            ShapeProperty::Wno => Some(self.wno),
+            ShapeProperty::Sw1 => Some(self.sw1),
+            ShapeProperty::Qf => Some(self.qf),
+            ShapeProperty::Qw => Some(self.qw),
+            ShapeProperty::Rts => Some(self.rts),
+            ShapeProperty::Ho => Some(self.ho),
+            ShapeProperty::Pa => Some(self.pa),
+            ShapeProperty::Pb => Some(self.pb),
+            ShapeProperty::Pc => Some(self.pc),
+            ShapeProperty::Pd => Some(self.pd),
+            ShapeProperty::T => Some(self.t),
+            ShapeProperty::Wgi => self.wgi,
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for HPile {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::DLower => Some(self.d_lower),
+            ShapeProperty::Ddet => Some(self.ddet),
+            ShapeProperty::Bf => Some(self.bf),
+            ShapeProperty::Bfdet => Some(self.bfdet),
+            ShapeProperty::Tw => Some(self.tw),
+            ShapeProperty::Twdet => Some(self.twdet),
+            ShapeProperty::Twdet2 => Some(self.twdet_2),
+            ShapeProperty::Tf => Some(self.tf),
+            ShapeProperty::Tfdet => Some(self.tfdet),
+            ShapeProperty::Kdes => Some(self.kdes),
+            ShapeProperty::Kdet => Some(self.kdet),
+            ShapeProperty::K1 => Some(self.k1),
+            ShapeProperty::Bf2tf => Some(self.bf_2tf),
+            ShapeProperty::HTw => Some(self.h_tw),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::Cw => Some(self.cw),
+            ShapeProperty::Wno => Some(self.wno),
+            ShapeProperty::Sw1 => Some(self.sw1),
+            ShapeProperty::Qf => Some(self.qf),
+            ShapeProperty::Qw => Some(self.qw),
+            ShapeProperty::Rts => Some(self.rts),
+            ShapeProperty::Ho => Some(self.ho),
+            ShapeProperty::Pa => Some(self.pa),
+            ShapeProperty::Pb => Some(self.pb),
+            ShapeProperty::Pc => Some(self.pc),
+            ShapeProperty::Pd => Some(self.pd),
+            ShapeProperty::T => Some(self.t),
+            ShapeProperty::Wgi => Some(self.wgi),
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for CeeChannel {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::DLower => Some(self.d_lower),
+            ShapeProperty::Ddet => Some(self.ddet),
+            ShapeProperty::Bf => Some(self.bf),
+            ShapeProperty::Bfdet => Some(self.bfdet),
+            ShapeProperty::Tw => Some(self.tw),
+            ShapeProperty::Twdet => Some(self.twdet),
+            ShapeProperty::Twdet2 => Some(self.twdet_2),
+            ShapeProperty::Tf => Some(self.tf),
+            ShapeProperty::Tfdet => Some(self.tfdet),
+            ShapeProperty::Kdes => Some(self.kdes),
+            ShapeProperty::Kdet => Some(self.kdet),
+            ShapeProperty::XLower => Some(self.x_lower),
+            ShapeProperty::Eo => Some(self.eo),
+            ShapeProperty::Xp => Some(self.xp),
+            ShapeProperty::BT => Some(self.b_t),
+            ShapeProperty::HTw => Some(self.h_tw),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::Cw => Some(self.cw),
+            ShapeProperty::Wno => Some(self.wno),
+            ShapeProperty::Sw1 => Some(self.sw1),
+            ShapeProperty::Sw2 => Some(self.sw2),
+            ShapeProperty::Sw3 => Some(self.sw3),
+            ShapeProperty::Qf => Some(self.qf),
+            ShapeProperty::Qw => Some(self.qw),
+            ShapeProperty::Ro => Some(self.ro),
+            ShapeProperty::HUpper => Some(self.h_upper),
+            ShapeProperty::Rts => Some(self.rts),
+            ShapeProperty::Ho => Some(self.ho),
+            ShapeProperty::Pa => Some(self.pa),
+            ShapeProperty::Pb => Some(self.pb),
+            ShapeProperty::Pc => Some(self.pc),
+            ShapeProperty::Pd => Some(self.pd),
+            ShapeProperty::T => Some(self.t),
+            ShapeProperty::Wgi => self.wgi,
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for MiscChannel {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::DLower => Some(self.d_lower),
+            ShapeProperty::Ddet => Some(self.ddet),
+            ShapeProperty::Bf => Some(self.bf),
+            ShapeProperty::Bfdet => Some(self.bfdet),
+            ShapeProperty::Tw => Some(self.tw),
+            ShapeProperty::Twdet => Some(self.twdet),
+            ShapeProperty::Twdet2 => Some(self.twdet_2),
+            ShapeProperty::Tf => Some(self.tf),
+            ShapeProperty::Tfdet => Some(self.tfdet),
+            ShapeProperty::Kdes => Some(self.kdes),
+            ShapeProperty::Kdet => Some(self.kdet),
+            ShapeProperty::XLower => Some(self.x_lower),
+            ShapeProperty::Eo => Some(self.eo),
+            ShapeProperty::Xp => Some(self.xp),
+            ShapeProperty::BT => Some(self.b_t),
+            ShapeProperty::HTw => Some(self.h_tw),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::Cw => Some(self.cw),
+            ShapeProperty::Wno => Some(self.wno),
+            ShapeProperty::Sw1 => Some(self.sw1),
+            ShapeProperty::Sw2 => Some(self.sw2),
+            ShapeProperty::Sw3 => Some(self.sw3),
+            ShapeProperty::Qf => Some(self.qf),
+            ShapeProperty::Qw => Some(self.qw),
+            ShapeProperty::Ro => Some(self.ro),
+            ShapeProperty::HUpper => Some(self.h_upper),
+            ShapeProperty::Rts => Some(self.rts),
+            ShapeProperty::Ho => Some(self.ho),
+            ShapeProperty::Pa => Some(self.pa),
+            ShapeProperty::Pb => Some(self.pb),
+            ShapeProperty::Pc => Some(self.pc),
+            ShapeProperty::Pd => Some(self.pd),
+            ShapeProperty::T => Some(self.t),
+            ShapeProperty::Wgi => self.wgi,
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for Angle {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::DLower => Some(self.d_lower),
+            ShapeProperty::BLower => Some(self.b_lower),
+            ShapeProperty::TLower => Some(self.t_lower),
+            ShapeProperty::Kdes => Some(self.kdes),
+            ShapeProperty::Kdet => Some(self.kdet),
+            ShapeProperty::XLower => Some(self.x_lower),
+            ShapeProperty::YLower => Some(self.y_lower),
+            ShapeProperty::Xp => Some(self.xp),
+            ShapeProperty::Yp => Some(self.yp),
+            ShapeProperty::BT => Some(self.b_t),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::Iz => Some(self.iz),
+            ShapeProperty::Rz => Some(self.rz),
+            ShapeProperty::Sz => Some(self.sz),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::Cw => Some(self.cw),
+            ShapeProperty::Ro => Some(self.ro),
+            ShapeProperty::HUpper => self.h_upper,
+            ShapeProperty::TanA => Some(self.tan_a),
+            ShapeProperty::Iw => Some(self.iw),
+            ShapeProperty::Za => Some(self.za),
+            ShapeProperty::Zb => Some(self.zb),
+            ShapeProperty::Zc => Some(self.zc),
+            ShapeProperty::Wa => Some(self.wa),
+            ShapeProperty::Wb => Some(self.wb),
+            ShapeProperty::Wc => Some(self.wc),
+            ShapeProperty::Swa => Some(self.swa),
+            ShapeProperty::Swb => self.swb,
+            ShapeProperty::Swc => Some(self.swc),
+            ShapeProperty::Sza => Some(self.sza),
+            ShapeProperty::Szb => Some(self.szb),
+            ShapeProperty::Szc => Some(self.szc),
+            ShapeProperty::Pa => Some(self.pa),
+            ShapeProperty::Pa2 => Some(self.pa_2),
+            ShapeProperty::Pb => Some(self.pb),
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for WideFlangeTee {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::DLower => Some(self.d_lower),
+            ShapeProperty::Ddet => Some(self.ddet),
+            ShapeProperty::Bf => Some(self.bf),
+            ShapeProperty::Bfdet => Some(self.bfdet),
+            ShapeProperty::Tw => Some(self.tw),
+            ShapeProperty::Twdet => Some(self.twdet),
+            ShapeProperty::Twdet2 => Some(self.twdet_2),
+            ShapeProperty::Tf => Some(self.tf),
+            ShapeProperty::Tfdet => Some(self.tfdet),
+            ShapeProperty::Kdes => Some(self.kdes),
+            ShapeProperty::Kdet => Some(self.kdet),
+            ShapeProperty::YLower => Some(self.y_lower),
+            ShapeProperty::Yp => Some(self.yp),
+            ShapeProperty::Bf2tf => Some(self.bf_2tf),
+            ShapeProperty::DT => Some(self.d_t),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::Cw => Some(self.cw),
+            ShapeProperty::Ro => Some(self.ro),
+            ShapeProperty::HUpper => Some(self.h_upper),
+            ShapeProperty::Pa => Some(self.pa),
+            ShapeProperty::Pb => Some(self.pb),
+            ShapeProperty::Pc => Some(self.pc),
+            ShapeProperty::Pd => Some(self.pd),
+            ShapeProperty::Wgi => Some(self.wgi),
+            ShapeProperty::Wgo => self.wgo,
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for MiscTee {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::DLower => Some(self.d_lower),
+            ShapeProperty::Ddet => Some(self.ddet),
+            ShapeProperty::Bf => Some(self.bf),
+            ShapeProperty::Bfdet => Some(self.bfdet),
+            ShapeProperty::Tw => Some(self.tw),
+            ShapeProperty::Twdet => Some(self.twdet),
+            ShapeProperty::Twdet2 => Some(self.twdet_2),
+            ShapeProperty::Tf => Some(self.tf),
+            ShapeProperty::Tfdet => Some(self.tfdet),
+            ShapeProperty::Kdes => Some(self.kdes),
+            ShapeProperty::Kdet => Some(self.kdet),
+            ShapeProperty::YLower => Some(self.y_lower),
+            ShapeProperty::Yp => Some(self.yp),
+            ShapeProperty::Bf2tf => Some(self.bf_2tf),
+            ShapeProperty::DT => Some(self.d_t),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::Cw => Some(self.cw),
+            ShapeProperty::Ro => Some(self.ro),
+            ShapeProperty::HUpper => Some(self.h_upper),
+            ShapeProperty::Wgi => self.wgi,
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for StructuralTee {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::DLower => Some(self.d_lower),
+            ShapeProperty::Ddet => Some(self.ddet),
+            ShapeProperty::Bf => Some(self.bf),
+            ShapeProperty::Bfdet => Some(self.bfdet),
+            ShapeProperty::Tw => Some(self.tw),
+            ShapeProperty::Twdet => Some(self.twdet),
+            ShapeProperty::Twdet2 => Some(self.twdet_2),
+            ShapeProperty::Tf => Some(self.tf),
+            ShapeProperty::Tfdet => Some(self.tfdet),
+            ShapeProperty::Kdes => Some(self.kdes),
+            ShapeProperty::Kdet => Some(self.kdet),
+            ShapeProperty::YLower => Some(self.y_lower),
+            ShapeProperty::Yp => Some(self.yp),
+            ShapeProperty::Bf2tf => Some(self.bf_2tf),
+            ShapeProperty::DT => Some(self.d_t),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::Cw => Some(self.cw),
+            ShapeProperty::Ro => Some(self.ro),
+            ShapeProperty::HUpper => Some(self.h_upper),
+            ShapeProperty::Wgi => self.wgi,
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for DoubleAngle {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::DLower => Some(self.d_lower),
+            ShapeProperty::BLower => Some(self.b_lower),
+            ShapeProperty::TLower => Some(self.t_lower),
+            ShapeProperty::YLower => Some(self.y_lower),
+            ShapeProperty::Yp => Some(self.yp),
+            ShapeProperty::BT => Some(self.b_t),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::Ro => Some(self.ro),
+            ShapeProperty::HUpper => Some(self.h_upper),
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for HollowStructuralSection {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::Ht => Some(self.ht),
+            ShapeProperty::H => Some(self.h),
+            ShapeProperty::BUpper => Some(self.b_upper),
+            ShapeProperty::BLower => Some(self.b_lower),
+            ShapeProperty::TNom => Some(self.t_nom),
+            ShapeProperty::Tdes => Some(self.tdes),
+            ShapeProperty::BTdes => Some(self.b_tdes),
+            ShapeProperty::HTdes => Some(self.h_tdes),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::CUpper => Some(self.c_upper),
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for RoundHollowStructuralSection {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::Od => Some(self.od),
+            ShapeProperty::TNom => Some(self.t_nom),
+            ShapeProperty::Tdes => Some(self.tdes),
+            ShapeProperty::DT => Some(self.d_t),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            ShapeProperty::CUpper => Some(self.c_upper),
+            _ => None,
+        }
+    }
+}
+
+impl SectionProperties for Pipe {
+    fn edi_std_nomenclature(&self) -> &str {
+        &self.edi_std_nomenclature
+    }
+    fn aisc_manual_label(&self) -> &str {
+        &self.aisc_manual_label
+    }
+    fn w_upper(&self) -> f64 {
+        self.w_upper
+    }
+    fn a_upper(&self) -> f64 {
+        self.a_upper
+    }
+    fn ix(&self) -> f64 {
+        self.ix
+    }
+    fn zx(&self) -> f64 {
+        self.zx
+    }
+    fn sx(&self) -> f64 {
+        self.sx
+    }
+    fn rx(&self) -> f64 {
+        self.rx
+    }
+    fn iy(&self) -> f64 {
+        self.iy
+    }
+    fn zy(&self) -> f64 {
+        self.zy
+    }
+    fn sy(&self) -> f64 {
+        self.sy
+    }
+    fn ry(&self) -> f64 {
+        self.ry
+    }
+    fn property(&self, property: ShapeProperty) -> Option<f64> {
+        match property {
+            ShapeProperty::WUpper => Some(self.w_upper),
+            ShapeProperty::AUpper => Some(self.a_upper),
+            ShapeProperty::Od => Some(self.od),
+            ShapeProperty::Id => Some(self.id),
+            ShapeProperty::TNom => Some(self.t_nom),
+            ShapeProperty::Tdes => Some(self.tdes),
+            ShapeProperty::DT => Some(self.d_t),
+            ShapeProperty::Ix => Some(self.ix),
+            ShapeProperty::Zx => Some(self.zx),
+            ShapeProperty::Sx => Some(self.sx),
+            ShapeProperty::Rx => Some(self.rx),
+            ShapeProperty::Iy => Some(self.iy),
+            ShapeProperty::Zy => Some(self.zy),
+            ShapeProperty::Sy => Some(self.sy),
+            ShapeProperty::Ry => Some(self.ry),
+            ShapeProperty::JUpper => Some(self.j_upper),
+            _ => None,
+        }
+    }
+}
+
+impl FlangedSection for WideFlange {
+    fn d_lower(&self) -> f64 {
+        self.d_lower
+    }
+    fn bf(&self) -> f64 {
+        self.bf
+    }
+    fn tf(&self) -> f64 {
+        self.tf
+    }
+    fn tw(&self) -> f64 {
+        self.tw
+    }
+    fn kdes(&self) -> f64 {
+        self.kdes
+    }
+}
+
+impl FlangedSection for MiscBeam {
+    fn d_lower(&self) -> f64 {
+        self.d_lower
+    }
+    fn bf(&self) -> f64 {
+        self.bf
+    }
+    fn tf(&self) -> f64 {
+        self.tf
+    }
+    fn tw(&self) -> f64 {
+        self.tw
+    }
+    fn kdes(&self) -> f64 {
+        self.kdes
+    }
+}
+
+impl FlangedSection for StructuralBeam {
+    fn d_lower(&self) -> f64 {
+        self.d_lower
+    }
+    fn bf(&self) -> f64 {
+        self.bf
+    }
+    fn tf(&self) -> f64 {
+        self.tf
+    }
+    fn tw(&self) -> f64 {
+        self.tw
+    }
+    fn kdes(&self) -> f64 {
+        self.kdes
+    }
+}
+
+impl FlangedSection for HPile {
+    fn d_lower(&self) -> f64 {
+        self.d_lower
+    }
+    fn bf(&self) -> f64 {
+        self.bf
+    }
+    fn tf(&self) -> f64 {
+        self.tf
+    }
+    fn tw(&self) -> f64 {
+        self.tw
+    }
+    fn kdes(&self) -> f64 {
+        self.kdes
+    }
+}
+
+impl FlangedSection for CeeChannel {
+    fn d_lower(&self) -> f64 {
+        self.d_lower
+    }
+    fn bf(&self) -> f64 {
+        self.bf
+    }
+    fn tf(&self) -> f64 {
+        self.tf
+    }
+    fn tw(&self) -> f64 {
+        self.tw
+    }
+    fn kdes(&self) -> f64 {
+        self.kdes
+    }
+}
+
+impl FlangedSection for MiscChannel {
+    fn d_lower(&self) -> f64 {
+        self.d_lower
+    }
+    fn bf(&self) -> f64 {
+        self.bf
+    }
+    fn tf(&self) -> f64 {
+        self.tf
+    }
+    fn tw(&self) -> f64 {
+        self.tw
+    }
+    fn kdes(&self) -> f64 {
+        self.kdes
+    }
+}
+
+impl FlangedSection for WideFlangeTee {
+    fn d_lower(&self) -> f64 {
+        self.d_lower
+    }
+    fn bf(&self) -> f64 {
+        self.bf
+    }
+    fn tf(&self) -> f64 {
+        self.tf
+    }
+    fn tw(&self) -> f64 {
+        self.tw
+    }
+    fn kdes(&self) -> f64 {
+        self.kdes
+    }
+}
+
+impl FlangedSection for MiscTee {
+    fn d_lower(&self) -> f64 {
+        self.d_lower
+    }
+    fn bf(&self) -> f64 {
+        self.bf
+    }
+    fn tf(&self) -> f64 {
+        self.tf
+    }
+    fn tw(&self) -> f64 {
+        self.tw
+    }
+    fn kdes(&self) -> f64 {
+        self.kdes
+    }
+}
+
+impl FlangedSection for StructuralTee {
+    fn d_lower(&self) -> f64 {
+        self.d_lower
+    }
+    fn bf(&self) -> f64 {
+        self.bf
+    }
+    fn tf(&self) -> f64 {
+        self.tf
+    }
+    fn tw(&self) -> f64 {
+        self.tw
+    }
+    fn kdes(&self) -> f64 {
+        self.kdes
+    }
+}
+
+impl TubularSection for RoundHollowStructuralSection {
+    fn od(&self) -> f64 {
+        self.od
+    }
+    fn t_nom(&self) -> f64 {
+        self.t_nom
+    }
+    fn tdes(&self) -> f64 {
+        self.tdes
+    }
+    fn d_t(&self) -> f64 {
+        self.d_t
+    }
+}
+
+impl TubularSection for Pipe {
+    fn od(&self) -> f64 {
+        self.od
+    }
+    fn t_nom(&self) -> f64 {
+        self.t_nom
+    }
+    fn tdes(&self) -> f64 {
+        self.tdes
+    }
+    fn d_t(&self) -> f64 {
+        self.d_t
+    }
+}
+
+impl RectangularTubularSection for HollowStructuralSection {
+    fn ht(&self) -> f64 {
+        self.ht
+    }
+    fn b_upper(&self) -> f64 {
+        self.b_upper
+    }
+    fn t_nom(&self) -> f64 {
+        self.t_nom
+    }
+    fn tdes(&self) -> f64 {
+        self.tdes
+    }
+    fn b_tdes(&self) -> f64 {
+        self.b_tdes
+    }
+    fn h_tdes(&self) -> f64 {
+        self.h_tdes
+    }
+}
+
+impl TorsionalProperties for WideFlange {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        Some(self.cw)
+    }
+    fn ro(&self) -> Option<f64> {
+        None
+    }
+    fn h_upper(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl TorsionalProperties for MiscBeam {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        Some(self.cw)
+    }
+    fn ro(&self) -> Option<f64> {
+        None
+    }
+    fn h_upper(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl TorsionalProperties for StructuralBeam {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        Some(self.cw)
+    }
+    fn ro(&self) -> Option<f64> {
+        None
+    }
+    fn h_upper(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl TorsionalProperties for HPile {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        Some(self.cw)
+    }
+    fn ro(&self) -> Option<f64> {
+        None
+    }
+    fn h_upper(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl TorsionalProperties for CeeChannel {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        Some(self.cw)
+    }
+    fn ro(&self) -> Option<f64> {
+        Some(self.ro)
+    }
+    fn h_upper(&self) -> Option<f64> {
+        Some(self.h_upper)
+    }
+}
+
+impl TorsionalProperties for MiscChannel {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        Some(self.cw)
+    }
+    fn ro(&self) -> Option<f64> {
+        Some(self.ro)
+    }
+    fn h_upper(&self) -> Option<f64> {
+        Some(self.h_upper)
+    }
+}
+
+impl TorsionalProperties for Angle {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        Some(self.cw)
+    }
+    fn ro(&self) -> Option<f64> {
+        Some(self.ro)
+    }
+    fn h_upper(&self) -> Option<f64> {
+        self.h_upper
+    }
+}
+
+impl TorsionalProperties for WideFlangeTee {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        Some(self.cw)
+    }
+    fn ro(&self) -> Option<f64> {
+        Some(self.ro)
+    }
+    fn h_upper(&self) -> Option<f64> {
+        Some(self.h_upper)
+    }
+}
+
+impl TorsionalProperties for MiscTee {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        Some(self.cw)
+    }
+    fn ro(&self) -> Option<f64> {
+        Some(self.ro)
+    }
+    fn h_upper(&self) -> Option<f64> {
+        Some(self.h_upper)
+    }
+}
+
+impl TorsionalProperties for StructuralTee {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        Some(self.cw)
+    }
+    fn ro(&self) -> Option<f64> {
+        Some(self.ro)
+    }
+    fn h_upper(&self) -> Option<f64> {
+        Some(self.h_upper)
+    }
+}
+
+impl TorsionalProperties for HollowStructuralSection {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        None
+    }
+    fn ro(&self) -> Option<f64> {
+        None
+    }
+    fn h_upper(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl TorsionalProperties for RoundHollowStructuralSection {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        None
+    }
+    fn ro(&self) -> Option<f64> {
+        None
+    }
+    fn h_upper(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl TorsionalProperties for Pipe {
+    fn j_upper(&self) -> f64 {
+        self.j_upper
+    }
+    fn cw(&self) -> Option<f64> {
+        None
+    }
+    fn ro(&self) -> Option<f64> {
+        None
+    }
+    fn h_upper(&self) -> Option<f64> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::{AiscShape, ShapeBuilder};
+
+    fn wide_flange() -> WideFlange {
+        ShapeBuilder::new()
+            .with_edi_std_nomenclature(String::from("W6X9"))
+            .with_aisc_manual_label(String::from("W6X9"))
+            .with_t_f(false)
+            .with_w_upper(9.0)
+            .with_a_upper(2.68)
+            .with_d_lower(5.9)
+            .with_ddet(5.875)
+            .with_bf(3.94)
+            .with_bfdet(4.0)
+            .with_tw(0.17)
+            .with_twdet(0.1875)
+            .with_twdet_2(0.125)
+            .with_tf(0.215)
+            .with_tfdet(0.1875)
+            .with_kdes(0.465)
+            .with_kdet(0.6875)
+            .with_k1(0.5)
+            .with_bf_2tf(9.16)
+            .with_h_tw(29.2)
+            .with_ix(16.4)
+            .with_zx(6.23)
+            .with_sx(5.56)
+            .with_rx(2.47)
+            .with_iy(2.2)
+            .with_zy(1.72)
+            .with_sy(1.11)
+            .with_ry(0.905)
+            .with_j_upper(0.0405)
+            .with_cw(17.7)
+            .with_wno(5.6)
+            .with_sw1(1.19)
+            .with_qf(1.15)
+            .with_qw(3.04)
+            .with_rts(1.06)
+            .with_ho(5.69)
+            .with_pa(22.9)
+            .with_pb(26.8)
+            .with_pc(15.7)
+            .with_pd(19.7)
+            .with_t(4.5)
+            .with_wgi(2.25)
+            .try_build::<WideFlange>()
+            .unwrap()
+    }
+
+    fn pipe() -> Pipe {
+        ShapeBuilder::new()
+            .with_edi_std_nomenclature(String::from("Pipe26STD"))
+            .with_aisc_manual_label(String::from("Pipe26STD"))
+            .with_w_upper(103.0)
+            .with_a_upper(28.2)
+            .with_od(26.0)
+            .with_id(25.3)
+            .with_t_nom(0.375)
+            .with_tdes(0.349)
+            .with_d_t(74.5)
+            .with_ix(2320.0)
+            .with_zx(230.0)
+            .with_sx(178.0)
+            .with_rx(9.07)
+            .with_iy(191.0)
+            .with_zy(230.0)
+            .with_sy(178.0)
+            .with_ry(9.07)
+            .with_j_upper(4640.0)
+            .try_build::<Pipe>()
+            .unwrap()
+    }
+
+    fn slenderness_x<T: SectionProperties>(shape: &T, length: f64) -> f64 {
+        length / shape.rx()
+    }
+
+    #[test]
+    fn generic_code_works_for_any_section() {
+        let wide_flange = wide_flange();
+        let pipe = pipe();
+        assert_eq!(120.0 / wide_flange.rx, slenderness_x(&wide_flange, 120.0));
+        assert_eq!(120.0 / pipe.rx, slenderness_x(&pipe, 120.0));
+        assert_eq!(
+            120.0 / pipe.rx,
+            slenderness_x(&AiscShape::from(pipe.clone()), 120.0)
+        );
+    }
+
+    #[test]
+    fn flanged_section_returns_flange_and_web_dimensions() {
+        let shape = wide_flange();
+        let flanged: &dyn FlangedSection = &shape;
+        assert_eq!(shape.d_lower, flanged.d_lower());
+        assert_eq!(shape.bf, flanged.bf());
+        assert_eq!(shape.tf, flanged.tf());
+        assert_eq!(shape.tw, flanged.tw());
+        assert_eq!(shape.kdes, flanged.kdes());
+    }
+
+    #[test]
+    fn torsional_properties_omit_untabulated_values() {
+        let wide_flange = wide_flange();
+        assert_eq!(
+            wide_flange.j_upper,
+            TorsionalProperties::j_upper(&wide_flange)
+        );
+        assert_eq!(Some(wide_flange.cw), TorsionalProperties::cw(&wide_flange));
+        assert_eq!(None, TorsionalProperties::ro(&wide_flange));
+        let pipe = pipe();
+        assert_eq!(pipe.j_upper, TorsionalProperties::j_upper(&pipe));
+        assert_eq!(None, TorsionalProperties::cw(&pipe));
+    }
+
+    #[test]
+    fn aisc_shape_exposes_family_specific_traits() {
+        let wide_flange = AiscShape::from(wide_flange());
+        let pipe = AiscShape::from(pipe());
+        assert!(wide_flange.as_flanged().is_some());
+        assert!(wide_flange.as_tubular().is_none());
+        assert!(pipe.as_flanged().is_none());
+        assert_eq!(Some(26.0), pipe.as_tubular().map(|p| p.od()));
+        assert!(pipe.as_rectangular_tubular().is_none());
+    }
+}
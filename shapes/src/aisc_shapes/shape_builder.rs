@@ -1,6 +1,8 @@
+use crate::aisc_shapes::ShapeProperty;
 use std::convert::TryFrom;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 /// [ShapeBuilder] is use to build AISC steel shapes,
 /// it contains a superset of the required fields for
@@ -873,6 +875,92 @@ impl ShapeBuilder {
         self
     }
 
+    /// Assigns a value for the numeric property
+    pub fn with_property(self, property: ShapeProperty, value: f64) -> Self {
+        match property {
+            ShapeProperty::WUpper => self.with_w_upper(value),
+            ShapeProperty::AUpper => self.with_a_upper(value),
+            ShapeProperty::DLower => self.with_d_lower(value),
+            ShapeProperty::Ddet => self.with_ddet(value),
+            ShapeProperty::Ht => self.with_ht(value),
+            ShapeProperty::H => self.with_h(value),
+            ShapeProperty::Od => self.with_od(value),
+            ShapeProperty::Bf => self.with_bf(value),
+            ShapeProperty::Bfdet => self.with_bfdet(value),
+            ShapeProperty::BUpper => self.with_b_upper(value),
+            ShapeProperty::BLower => self.with_b_lower(value),
+            ShapeProperty::Id => self.with_id(value),
+            ShapeProperty::Tw => self.with_tw(value),
+            ShapeProperty::Twdet => self.with_twdet(value),
+            ShapeProperty::Twdet2 => self.with_twdet_2(value),
+            ShapeProperty::Tf => self.with_tf(value),
+            ShapeProperty::Tfdet => self.with_tfdet(value),
+            ShapeProperty::TLower => self.with_t_lower(value),
+            ShapeProperty::TNom => self.with_t_nom(value),
+            ShapeProperty::Tdes => self.with_tdes(value),
+            ShapeProperty::Kdes => self.with_kdes(value),
+            ShapeProperty::Kdet => self.with_kdet(value),
+            ShapeProperty::K1 => self.with_k1(value),
+            ShapeProperty::XLower => self.with_x_lower(value),
+            ShapeProperty::YLower => self.with_y_lower(value),
+            ShapeProperty::Eo => self.with_eo(value),
+            ShapeProperty::Xp => self.with_xp(value),
+            ShapeProperty::Yp => self.with_yp(value),
+            ShapeProperty::Bf2tf => self.with_bf_2tf(value),
+            ShapeProperty::BT => self.with_b_t(value),
+            ShapeProperty::BTdes => self.with_b_tdes(value),
+            ShapeProperty::HTw => self.with_h_tw(value),
+            ShapeProperty::HTdes => self.with_h_tdes(value),
+            ShapeProperty::DT => self.with_d_t(value),
+            ShapeProperty::Ix => self.with_ix(value),
+            ShapeProperty::Zx => self.with_zx(value),
+            ShapeProperty::Sx => self.with_sx(value),
+            ShapeProperty::Rx => self.with_rx(value),
+            ShapeProperty::Iy => self.with_iy(value),
+            ShapeProperty::Zy => self.with_zy(value),
+            ShapeProperty::Sy => self.with_sy(value),
+            ShapeProperty::Ry => self.with_ry(value),
+            ShapeProperty::Iz => self.with_iz(value),
+            ShapeProperty::Rz => self.with_rz(value),
+            ShapeProperty::Sz => self.with_sz(value),
+            ShapeProperty::JUpper => self.with_j_upper(value),
+            ShapeProperty::Cw => self.with_cw(value),
+            ShapeProperty::CUpper => self.with_c_upper(value),
+            ShapeProperty::Wno => self.with_wno(value),
+            ShapeProperty::Sw1 => self.with_sw1(value),
+            ShapeProperty::Sw2 => self.with_sw2(value),
+            ShapeProperty::Sw3 => self.with_sw3(value),
+            ShapeProperty::Qf => self.with_qf(value),
+            ShapeProperty::Qw => self.with_qw(value),
+            ShapeProperty::Ro => self.with_ro(value),
+            ShapeProperty::HUpper => self.with_h_upper(value),
+            ShapeProperty::TanA => self.with_tan_a(value),
+            ShapeProperty::Iw => self.with_iw(value),
+            ShapeProperty::Za => self.with_za(value),
+            ShapeProperty::Zb => self.with_zb(value),
+            ShapeProperty::Zc => self.with_zc(value),
+            ShapeProperty::Wa => self.with_wa(value),
+            ShapeProperty::Wb => self.with_wb(value),
+            ShapeProperty::Wc => self.with_wc(value),
+            ShapeProperty::Swa => self.with_swa(value),
+            ShapeProperty::Swb => self.with_swb(value),
+            ShapeProperty::Swc => self.with_swc(value),
+            ShapeProperty::Sza => self.with_sza(value),
+            ShapeProperty::Szb => self.with_szb(value),
+            ShapeProperty::Szc => self.with_szc(value),
+            ShapeProperty::Rts => self.with_rts(value),
+            ShapeProperty::Ho => self.with_ho(value),
+            ShapeProperty::Pa => self.with_pa(value),
+            ShapeProperty::Pa2 => self.with_pa_2(value),
+            ShapeProperty::Pb => self.with_pb(value),
+            ShapeProperty::Pc => self.with_pc(value),
+            ShapeProperty::Pd => self.with_pd(value),
+            ShapeProperty::T => self.with_t(value),
+            ShapeProperty::Wgi => self.with_wgi(value),
+            ShapeProperty::Wgo => self.with_wgo(value),
+        }
+    }
+
     #[allow(dead_code)]
     /// Attempts to build a shape with populated shape data fields,
     /// takes a type of [T: TryFrom<ShapeBuilder>]
@@ -884,3 +972,9 @@ impl ShapeBuilder {
         T::try_from(self)
     }
 }
+
+impl Default for ShapeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,204 @@
+use crate::aisc_shapes::{SectionProperties, ShapeProperty};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The values of a property for each compared shape, and their differences
+/// from the value of the first shape
+pub struct PropertyComparison {
+    /// The compared property
+    pub property: ShapeProperty,
+    /// The value of the property for each shape, in the order the shapes were given
+    pub values: Vec<f64>,
+    /// The difference of each value from the value of the first shape
+    pub differences: Vec<f64>,
+    /// The difference of each value from the value of the first shape, in percent
+    /// of the value of the first shape, none when the first shape has a value of zero
+    pub percent_differences: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The nominal weight of each compared shape per unit of a property,
+/// the lightest shape for an equal value of the property having the lowest
+pub struct WeightEfficiency {
+    /// The property the weight is compared for, Zx or Ix
+    pub property: ShapeProperty,
+    /// (W/Zx or W/Ix) Nominal weight per unit of the property of each shape
+    pub weight_ratios: Vec<f64>,
+    /// The AISC Manual Label of the lightest shape for an equal value of the property
+    pub lightest: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A side-by-side comparison of shapes, possibly of different families,
+/// taking the first shape as the baseline
+pub struct ShapeComparison {
+    /// The AISC Manual Labels of the compared shapes
+    pub labels: Vec<String>,
+    /// The properties defined by every compared shape, in database column order
+    pub properties: Vec<PropertyComparison>,
+    /// The AISC Manual Label of the shape with the lowest nominal weight
+    pub lightest: Option<String>,
+    /// The lightest shapes for an equal Zx and an equal Ix,
+    /// when every compared shape defines the property
+    pub efficiencies: Vec<WeightEfficiency>,
+}
+
+impl ShapeComparison {
+    /// Compares the properties defined by every shape, which may be of different families.
+    /// The lightest shape for an equal Zx or Ix is the one with the lowest weight per unit of it.
+    pub fn new<T: SectionProperties>(shapes: &[T]) -> Self {
+        let properties = ShapeProperty::ALL
+            .into_iter()
+            .filter_map(|property| {
+                let values = shapes
+                    .iter()
+                    .map(|shape| shape.property(property))
+                    .collect::<Option<Vec<f64>>>()?;
+                let baseline = *values.first()?;
+                Some(PropertyComparison {
+                    property,
+                    differences: values.iter().map(|value| value - baseline).collect(),
+                    percent_differences: values
+                        .iter()
+                        .map(|value| {
+                            (baseline != 0.0).then(|| (value - baseline) / baseline * 100.0)
+                        })
+                        .collect(),
+                    values,
+                })
+            })
+            .collect::<Vec<_>>();
+        let values = |property| {
+            properties
+                .iter()
+                .find(|comparison| comparison.property == property)
+                .map(|comparison| comparison.values.as_slice())
+        };
+        let label = |index: usize| String::from(shapes[index].aisc_manual_label());
+
+        let weights = values(ShapeProperty::WUpper);
+        let efficiencies = weights
+            .map(|weights| {
+                [ShapeProperty::Zx, ShapeProperty::Ix]
+                    .into_iter()
+                    .filter_map(|property| {
+                        let values = values(property)?;
+                        if values.iter().any(|value| *value <= 0.0) {
+                            return None;
+                        }
+                        let weight_ratios: Vec<f64> =
+                            weights.iter().zip(values).map(|(w, v)| w / v).collect();
+                        Some(WeightEfficiency {
+                            property,
+                            lightest: label(lowest(&weight_ratios)?),
+                            weight_ratios,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        ShapeComparison {
+            labels: shapes
+                .iter()
+                .map(|shape| String::from(shape.aisc_manual_label()))
+                .collect(),
+            lightest: weights.and_then(lowest).map(label),
+            properties,
+            efficiencies,
+        }
+    }
+
+    /// The comparison of a property, none when a compared shape does not define it
+    pub fn property(&self, property: ShapeProperty) -> Option<&PropertyComparison> {
+        self.properties
+            .iter()
+            .find(|comparison| comparison.property == property)
+    }
+}
+
+/// The index of the lowest value, the first one when several are equal
+fn lowest(values: &[f64]) -> Option<usize> {
+    values
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::ShapeProperty::*;
+    use crate::aisc_shapes::test_support::shape;
+    use crate::aisc_shapes::{AiscShape, HollowStructuralSection, WideFlange};
+
+    fn w(label: &str, w: f64, zx: f64, ix: f64) -> AiscShape {
+        AiscShape::WideFlange(shape::<WideFlange>(
+            label,
+            &[(WUpper, w), (Zx, zx), (Ix, ix)],
+        ))
+    }
+
+    #[test]
+    fn differences_are_taken_from_the_first_shape() {
+        let comparison = ShapeComparison::new(&[
+            w("W16X31", 31.0, 54.0, 375.0),
+            w("W14X34", 34.0, 54.6, 340.0),
+            w("W12X35", 35.0, 51.2, 285.0),
+        ]);
+        assert_eq!(vec!["W16X31", "W14X34", "W12X35"], comparison.labels);
+        let zx = comparison.property(Zx).unwrap();
+        assert_eq!(vec![54.0, 54.6, 51.2], zx.values);
+        assert!((0.6 - zx.differences[1]).abs() < 1e-9);
+        assert!((-2.8 - zx.differences[2]).abs() < 1e-9);
+        assert_eq!(Some(0.0), zx.percent_differences[0]);
+        assert!((0.6 / 54.0 * 100.0 - zx.percent_differences[1].unwrap()).abs() < 1e-9);
+
+        assert_eq!(Some(String::from("W16X31")), comparison.lightest);
+        assert_eq!(2, comparison.efficiencies.len());
+        assert_eq!(Zx, comparison.efficiencies[0].property);
+        assert_eq!("W16X31", comparison.efficiencies[0].lightest);
+        assert!((31.0 / 54.0 - comparison.efficiencies[0].weight_ratios[0]).abs() < 1e-12);
+        assert_eq!("W16X31", comparison.efficiencies[1].lightest);
+    }
+
+    #[test]
+    fn the_lightest_shape_for_equal_zx_may_be_heavier() {
+        let comparison = ShapeComparison::new(&[
+            w("W12X26", 26.0, 37.2, 204.0),
+            w("W8X24", 24.0, 23.1, 82.7),
+            w("W16X26", 26.0, 44.2, 301.0),
+        ]);
+        assert_eq!(Some(String::from("W8X24")), comparison.lightest);
+        assert_eq!("W16X26", comparison.efficiencies[0].lightest);
+        assert_eq!("W16X26", comparison.efficiencies[1].lightest);
+    }
+
+    #[test]
+    fn only_properties_common_to_every_family_are_compared() {
+        let hss = AiscShape::HollowStructuralSection(shape::<HollowStructuralSection>(
+            "HSS8X8X1/2",
+            &[(WUpper, 48.9), (Zx, 48.5), (Ix, 131.0)],
+        ));
+        let comparison = ShapeComparison::new(&[w("W10X49", 49.0, 60.4, 272.0), hss]);
+        assert!(comparison.property(Bf).is_none());
+        assert!(comparison.property(Tdes).is_none());
+        assert_eq!(vec![272.0, 131.0], comparison.property(Ix).unwrap().values);
+        assert_eq!(Some(String::from("HSS8X8X1/2")), comparison.lightest);
+        assert_eq!("W10X49", comparison.efficiencies[0].lightest);
+        assert_eq!("W10X49", comparison.efficiencies[1].lightest);
+    }
+
+    #[test]
+    fn zero_baselines_have_no_percent_difference() {
+        let comparison = ShapeComparison::new(&[w("A", 0.0, 1.0, 1.0), w("B", 2.0, 1.0, 1.0)]);
+        assert_eq!(
+            vec![None, None],
+            comparison.property(WUpper).unwrap().percent_differences
+        );
+        assert!(ShapeComparison::new::<AiscShape>(&[]).properties.is_empty());
+    }
+}
@@ -0,0 +1,838 @@
+use crate::aisc_shapes::ShapeProperty;
+use crate::aisc_shapes::errors::UnknownShapeFamilyError;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+/// The family of an AISC steel shape, each family
+/// corresponds to one of the shape types in the AISC shapes database
+pub enum ShapeFamily {
+    /// Wide flange (W) shapes
+    WideFlange,
+    /// Misc. beam (M) shapes
+    MiscBeam,
+    /// Structural beam (S) shapes
+    StructuralBeam,
+    /// H-pile (HP) shapes
+    HPile,
+    /// Cee channel (C) shapes
+    CeeChannel,
+    /// Misc. channel (MC) shapes
+    MiscChannel,
+    /// Angle (L) shapes
+    Angle,
+    /// Wide flange tee (WT) shapes
+    WideFlangeTee,
+    /// Misc. tee (MT) shapes
+    MiscTee,
+    /// Structural tee (ST) shapes
+    StructuralTee,
+    /// Double angle (2L) shapes
+    DoubleAngle,
+    /// Square and rectangular HSS shapes
+    HollowStructuralSection,
+    /// Round HSS shapes
+    RoundHollowStructuralSection,
+    /// Pipe shapes
+    Pipe,
+}
+
+impl ShapeFamily {
+    /// Every shape family, in the order they appear in the AISC shapes database
+    pub const ALL: [ShapeFamily; 14] = [
+        ShapeFamily::WideFlange,
+        ShapeFamily::MiscBeam,
+        ShapeFamily::StructuralBeam,
+        ShapeFamily::HPile,
+        ShapeFamily::CeeChannel,
+        ShapeFamily::MiscChannel,
+        ShapeFamily::Angle,
+        ShapeFamily::WideFlangeTee,
+        ShapeFamily::MiscTee,
+        ShapeFamily::StructuralTee,
+        ShapeFamily::DoubleAngle,
+        ShapeFamily::HollowStructuralSection,
+        ShapeFamily::RoundHollowStructuralSection,
+        ShapeFamily::Pipe,
+    ];
+
+    /// The snake_case name of the family (e.g. `wide_flange`, `round_hollow_structural_section`),
+    /// matches the serialized form and is accepted by [ShapeFamily::from_str]
+    pub fn name(&self) -> &'static str {
+        match self {
+            ShapeFamily::WideFlange => "wide_flange",
+            ShapeFamily::MiscBeam => "misc_beam",
+            ShapeFamily::StructuralBeam => "structural_beam",
+            ShapeFamily::HPile => "h_pile",
+            ShapeFamily::CeeChannel => "cee_channel",
+            ShapeFamily::MiscChannel => "misc_channel",
+            ShapeFamily::Angle => "angle",
+            ShapeFamily::WideFlangeTee => "wide_flange_tee",
+            ShapeFamily::MiscTee => "misc_tee",
+            ShapeFamily::StructuralTee => "structural_tee",
+            ShapeFamily::DoubleAngle => "double_angle",
+            ShapeFamily::HollowStructuralSection => "hollow_structural_section",
+            ShapeFamily::RoundHollowStructuralSection => "round_hollow_structural_section",
+            ShapeFamily::Pipe => "pipe",
+        }
+    }
+
+    /// The numeric properties defined by shapes of the family,
+    /// in the order of the fields of the family's shape struct
+    pub fn properties(&self) -> &'static [ShapeProperty] {
+        match self {
+            ShapeFamily::WideFlange => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::DLower,
+                ShapeProperty::Ddet,
+                ShapeProperty::Bf,
+                ShapeProperty::Bfdet,
+                ShapeProperty::Tw,
+                ShapeProperty::Twdet,
+                ShapeProperty::Twdet2,
+                ShapeProperty::Tf,
+                ShapeProperty::Tfdet,
+                ShapeProperty::Kdes,
+                ShapeProperty::Kdet,
+                ShapeProperty::K1,
+                ShapeProperty::Bf2tf,
+                ShapeProperty::HTw,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+                ShapeProperty::Cw,
+                ShapeProperty::Wno,
+                ShapeProperty::Sw1,
+                ShapeProperty::Qf,
+                ShapeProperty::Qw,
+                ShapeProperty::Rts,
+                ShapeProperty::Ho,
+                ShapeProperty::Pa,
+                ShapeProperty::Pb,
+                ShapeProperty::Pc,
+                ShapeProperty::Pd,
+                ShapeProperty::T,
+                ShapeProperty::Wgi,
+                ShapeProperty::Wgo,
+            ],
+            ShapeFamily::MiscBeam => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::DLower,
+                ShapeProperty::Ddet,
+                ShapeProperty::Bf,
+                ShapeProperty::Bfdet,
+                ShapeProperty::Tw,
+                ShapeProperty::Twdet,
+                ShapeProperty::Twdet2,
+                ShapeProperty::Tf,
+                ShapeProperty::Tfdet,
+                ShapeProperty::Kdes,
+                ShapeProperty::Kdet,
+                ShapeProperty::K1,
+                ShapeProperty::Bf2tf,
+                ShapeProperty::HTw,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+                ShapeProperty::Cw,
+                ShapeProperty::Wno,
+                ShapeProperty::Sw1,
+                ShapeProperty::Qf,
+                ShapeProperty::Qw,
+                ShapeProperty::Rts,
+                ShapeProperty::Ho,
+                ShapeProperty::Pa,
+                ShapeProperty::Pb,
+                ShapeProperty::Pc,
+                ShapeProperty::Pd,
+                ShapeProperty::T,
+                ShapeProperty::Wgi,
+            ],
+            ShapeFamily::StructuralBeam => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::DLower,
+                ShapeProperty::Ddet,
+                ShapeProperty::Bf,
+                ShapeProperty::Bfdet,
+                ShapeProperty::Tw,
+                ShapeProperty::Twdet,
+                ShapeProperty::Twdet2,
+                ShapeProperty::Tf,
+                ShapeProperty::Tfdet,
+                ShapeProperty::Kdes,
+                ShapeProperty::Kdet,
+                ShapeProperty::Bf2tf,
+                ShapeProperty::HTw,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+                ShapeProperty::Cw,
+                ShapeProperty::Wno,
+                ShapeProperty::Sw1,
+                ShapeProperty::Qf,
+                ShapeProperty::Qw,
+                ShapeProperty::Rts,
+                ShapeProperty::Ho,
+                ShapeProperty::Pa,
+                ShapeProperty::Pb,
+                ShapeProperty::Pc,
+                ShapeProperty::Pd,
+                ShapeProperty::T,
+                ShapeProperty::Wgi,
+            ],
+            ShapeFamily::HPile => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::DLower,
+                ShapeProperty::Ddet,
+                ShapeProperty::Bf,
+                ShapeProperty::Bfdet,
+                ShapeProperty::Tw,
+                ShapeProperty::Twdet,
+                ShapeProperty::Twdet2,
+                ShapeProperty::Tf,
+                ShapeProperty::Tfdet,
+                ShapeProperty::Kdes,
+                ShapeProperty::Kdet,
+                ShapeProperty::K1,
+                ShapeProperty::Bf2tf,
+                ShapeProperty::HTw,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+                ShapeProperty::Cw,
+                ShapeProperty::Wno,
+                ShapeProperty::Sw1,
+                ShapeProperty::Qf,
+                ShapeProperty::Qw,
+                ShapeProperty::Rts,
+                ShapeProperty::Ho,
+                ShapeProperty::Pa,
+                ShapeProperty::Pb,
+                ShapeProperty::Pc,
+                ShapeProperty::Pd,
+                ShapeProperty::T,
+                ShapeProperty::Wgi,
+            ],
+            ShapeFamily::CeeChannel => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::DLower,
+                ShapeProperty::Ddet,
+                ShapeProperty::Bf,
+                ShapeProperty::Bfdet,
+                ShapeProperty::Tw,
+                ShapeProperty::Twdet,
+                ShapeProperty::Twdet2,
+                ShapeProperty::Tf,
+                ShapeProperty::Tfdet,
+                ShapeProperty::Kdes,
+                ShapeProperty::Kdet,
+                ShapeProperty::XLower,
+                ShapeProperty::Eo,
+                ShapeProperty::Xp,
+                ShapeProperty::BT,
+                ShapeProperty::HTw,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+                ShapeProperty::Cw,
+                ShapeProperty::Wno,
+                ShapeProperty::Sw1,
+                ShapeProperty::Sw2,
+                ShapeProperty::Sw3,
+                ShapeProperty::Qf,
+                ShapeProperty::Qw,
+                ShapeProperty::Ro,
+                ShapeProperty::HUpper,
+                ShapeProperty::Rts,
+                ShapeProperty::Ho,
+                ShapeProperty::Pa,
+                ShapeProperty::Pb,
+                ShapeProperty::Pc,
+                ShapeProperty::Pd,
+                ShapeProperty::T,
+                ShapeProperty::Wgi,
+            ],
+            ShapeFamily::MiscChannel => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::DLower,
+                ShapeProperty::Ddet,
+                ShapeProperty::Bf,
+                ShapeProperty::Bfdet,
+                ShapeProperty::Tw,
+                ShapeProperty::Twdet,
+                ShapeProperty::Twdet2,
+                ShapeProperty::Tf,
+                ShapeProperty::Tfdet,
+                ShapeProperty::Kdes,
+                ShapeProperty::Kdet,
+                ShapeProperty::XLower,
+                ShapeProperty::Eo,
+                ShapeProperty::Xp,
+                ShapeProperty::BT,
+                ShapeProperty::HTw,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+                ShapeProperty::Cw,
+                ShapeProperty::Wno,
+                ShapeProperty::Sw1,
+                ShapeProperty::Sw2,
+                ShapeProperty::Sw3,
+                ShapeProperty::Qf,
+                ShapeProperty::Qw,
+                ShapeProperty::Ro,
+                ShapeProperty::HUpper,
+                ShapeProperty::Rts,
+                ShapeProperty::Ho,
+                ShapeProperty::Pa,
+                ShapeProperty::Pb,
+                ShapeProperty::Pc,
+                ShapeProperty::Pd,
+                ShapeProperty::T,
+                ShapeProperty::Wgi,
+            ],
+            ShapeFamily::Angle => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::DLower,
+                ShapeProperty::BLower,
+                ShapeProperty::TLower,
+                ShapeProperty::Kdes,
+                ShapeProperty::Kdet,
+                ShapeProperty::XLower,
+                ShapeProperty::YLower,
+                ShapeProperty::Xp,
+                ShapeProperty::Yp,
+                ShapeProperty::BT,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::Iz,
+                ShapeProperty::Rz,
+                ShapeProperty::Sz,
+                ShapeProperty::JUpper,
+                ShapeProperty::Cw,
+                ShapeProperty::Ro,
+                ShapeProperty::HUpper,
+                ShapeProperty::TanA,
+                ShapeProperty::Iw,
+                ShapeProperty::Za,
+                ShapeProperty::Zb,
+                ShapeProperty::Zc,
+                ShapeProperty::Wa,
+                ShapeProperty::Wb,
+                ShapeProperty::Wc,
+                ShapeProperty::Swa,
+                ShapeProperty::Swb,
+                ShapeProperty::Swc,
+                ShapeProperty::Sza,
+                ShapeProperty::Szb,
+                ShapeProperty::Szc,
+                ShapeProperty::Pa,
+                ShapeProperty::Pa2,
+                ShapeProperty::Pb,
+            ],
+            ShapeFamily::WideFlangeTee => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::DLower,
+                ShapeProperty::Ddet,
+                ShapeProperty::Bf,
+                ShapeProperty::Bfdet,
+                ShapeProperty::Tw,
+                ShapeProperty::Twdet,
+                ShapeProperty::Twdet2,
+                ShapeProperty::Tf,
+                ShapeProperty::Tfdet,
+                ShapeProperty::Kdes,
+                ShapeProperty::Kdet,
+                ShapeProperty::YLower,
+                ShapeProperty::Yp,
+                ShapeProperty::Bf2tf,
+                ShapeProperty::DT,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+                ShapeProperty::Cw,
+                ShapeProperty::Ro,
+                ShapeProperty::HUpper,
+                ShapeProperty::Pa,
+                ShapeProperty::Pb,
+                ShapeProperty::Pc,
+                ShapeProperty::Pd,
+                ShapeProperty::Wgi,
+                ShapeProperty::Wgo,
+            ],
+            ShapeFamily::MiscTee => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::DLower,
+                ShapeProperty::Ddet,
+                ShapeProperty::Bf,
+                ShapeProperty::Bfdet,
+                ShapeProperty::Tw,
+                ShapeProperty::Twdet,
+                ShapeProperty::Twdet2,
+                ShapeProperty::Tf,
+                ShapeProperty::Tfdet,
+                ShapeProperty::Kdes,
+                ShapeProperty::Kdet,
+                ShapeProperty::YLower,
+                ShapeProperty::Yp,
+                ShapeProperty::Bf2tf,
+                ShapeProperty::DT,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+                ShapeProperty::Cw,
+                ShapeProperty::Ro,
+                ShapeProperty::HUpper,
+                ShapeProperty::Wgi,
+            ],
+            ShapeFamily::StructuralTee => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::DLower,
+                ShapeProperty::Ddet,
+                ShapeProperty::Bf,
+                ShapeProperty::Bfdet,
+                ShapeProperty::Tw,
+                ShapeProperty::Twdet,
+                ShapeProperty::Twdet2,
+                ShapeProperty::Tf,
+                ShapeProperty::Tfdet,
+                ShapeProperty::Kdes,
+                ShapeProperty::Kdet,
+                ShapeProperty::YLower,
+                ShapeProperty::Yp,
+                ShapeProperty::Bf2tf,
+                ShapeProperty::DT,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+                ShapeProperty::Cw,
+                ShapeProperty::Ro,
+                ShapeProperty::HUpper,
+                ShapeProperty::Wgi,
+            ],
+            ShapeFamily::DoubleAngle => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::DLower,
+                ShapeProperty::BLower,
+                ShapeProperty::TLower,
+                ShapeProperty::YLower,
+                ShapeProperty::Yp,
+                ShapeProperty::BT,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::Ro,
+                ShapeProperty::HUpper,
+            ],
+            ShapeFamily::HollowStructuralSection => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::Ht,
+                ShapeProperty::H,
+                ShapeProperty::BUpper,
+                ShapeProperty::BLower,
+                ShapeProperty::TNom,
+                ShapeProperty::Tdes,
+                ShapeProperty::BTdes,
+                ShapeProperty::HTdes,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+                ShapeProperty::CUpper,
+            ],
+            ShapeFamily::RoundHollowStructuralSection => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::Od,
+                ShapeProperty::TNom,
+                ShapeProperty::Tdes,
+                ShapeProperty::DT,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+                ShapeProperty::CUpper,
+            ],
+            ShapeFamily::Pipe => &[
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::Od,
+                ShapeProperty::Id,
+                ShapeProperty::TNom,
+                ShapeProperty::Tdes,
+                ShapeProperty::DT,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Rx,
+                ShapeProperty::Iy,
+                ShapeProperty::Zy,
+                ShapeProperty::Sy,
+                ShapeProperty::Ry,
+                ShapeProperty::JUpper,
+            ],
+        }
+    }
+
+    /// Whether shapes of the family define the property
+    pub fn has_property(&self, property: ShapeProperty) -> bool {
+        self.properties().contains(&property)
+    }
+
+    /// The properties of the family that are not defined for every shape,
+    /// e.g. the outer workable gage `WGo` is only defined for shapes with wide flanges
+    pub fn optional_properties(&self) -> &'static [ShapeProperty] {
+        match self {
+            ShapeFamily::WideFlange => &[ShapeProperty::Wgo],
+            ShapeFamily::MiscBeam => &[ShapeProperty::Wgi],
+            ShapeFamily::StructuralBeam => &[ShapeProperty::Wgi],
+            ShapeFamily::HPile => &[],
+            ShapeFamily::CeeChannel => &[ShapeProperty::Wgi],
+            ShapeFamily::MiscChannel => &[ShapeProperty::Wgi],
+            ShapeFamily::Angle => &[ShapeProperty::HUpper, ShapeProperty::Swb],
+            ShapeFamily::WideFlangeTee => &[ShapeProperty::Wgo],
+            ShapeFamily::MiscTee => &[ShapeProperty::Wgi],
+            ShapeFamily::StructuralTee => &[ShapeProperty::Wgi],
+            ShapeFamily::DoubleAngle => &[],
+            ShapeFamily::HollowStructuralSection => &[],
+            ShapeFamily::RoundHollowStructuralSection => &[],
+            ShapeFamily::Pipe => &[],
+        }
+    }
+
+    /// The property matched by `shapes_with_depth` in the family's repository:
+    /// `d` for flanged shapes, the longer leg `b` for angles and double angles,
+    /// `Ht` for rectangular HSS and `OD` for round HSS and pipe
+    pub fn depth_property(&self) -> ShapeProperty {
+        match self {
+            ShapeFamily::WideFlange => ShapeProperty::DLower,
+            ShapeFamily::MiscBeam => ShapeProperty::DLower,
+            ShapeFamily::StructuralBeam => ShapeProperty::DLower,
+            ShapeFamily::HPile => ShapeProperty::DLower,
+            ShapeFamily::CeeChannel => ShapeProperty::DLower,
+            ShapeFamily::MiscChannel => ShapeProperty::DLower,
+            ShapeFamily::Angle => ShapeProperty::BLower,
+            ShapeFamily::WideFlangeTee => ShapeProperty::DLower,
+            ShapeFamily::MiscTee => ShapeProperty::DLower,
+            ShapeFamily::StructuralTee => ShapeProperty::DLower,
+            ShapeFamily::DoubleAngle => ShapeProperty::BLower,
+            ShapeFamily::HollowStructuralSection => ShapeProperty::Ht,
+            ShapeFamily::RoundHollowStructuralSection => ShapeProperty::Od,
+            ShapeFamily::Pipe => ShapeProperty::Od,
+        }
+    }
+
+    /// The property matched by `shapes_with_width` in the family's repository:
+    /// `bf` for flanged shapes, the shorter leg `d` for angles and double angles,
+    /// `B` for rectangular HSS and `OD` for round HSS and pipe
+    pub fn width_property(&self) -> ShapeProperty {
+        match self {
+            ShapeFamily::WideFlange => ShapeProperty::Bf,
+            ShapeFamily::MiscBeam => ShapeProperty::Bf,
+            ShapeFamily::StructuralBeam => ShapeProperty::Bf,
+            ShapeFamily::HPile => ShapeProperty::Bf,
+            ShapeFamily::CeeChannel => ShapeProperty::Bf,
+            ShapeFamily::MiscChannel => ShapeProperty::Bf,
+            ShapeFamily::Angle => ShapeProperty::DLower,
+            ShapeFamily::WideFlangeTee => ShapeProperty::Bf,
+            ShapeFamily::MiscTee => ShapeProperty::Bf,
+            ShapeFamily::StructuralTee => ShapeProperty::Bf,
+            ShapeFamily::DoubleAngle => ShapeProperty::DLower,
+            ShapeFamily::HollowStructuralSection => ShapeProperty::BUpper,
+            ShapeFamily::RoundHollowStructuralSection => ShapeProperty::Od,
+            ShapeFamily::Pipe => ShapeProperty::Od,
+        }
+    }
+
+    /// The shape type as it appears in the `Type` column
+    /// of the AISC shapes database (e.g. `W`, `2L`, `HSS`)
+    pub fn shape_type(&self) -> &'static str {
+        match self {
+            ShapeFamily::WideFlange => "W",
+            ShapeFamily::MiscBeam => "M",
+            ShapeFamily::StructuralBeam => "S",
+            ShapeFamily::HPile => "HP",
+            ShapeFamily::CeeChannel => "C",
+            ShapeFamily::MiscChannel => "MC",
+            ShapeFamily::Angle => "L",
+            ShapeFamily::WideFlangeTee => "WT",
+            ShapeFamily::MiscTee => "MT",
+            ShapeFamily::StructuralTee => "ST",
+            ShapeFamily::DoubleAngle => "2L",
+            ShapeFamily::HollowStructuralSection => "HSS",
+            ShapeFamily::RoundHollowStructuralSection => "HSS",
+            ShapeFamily::Pipe => "PIPE",
+        }
+    }
+
+    /// Determines the family of a shape from its designation,
+    /// either the EDI Std Nomenclature or the AISC Manual Label
+    /// (e.g. `W14X90`, `2L4X4X1/2`, `HSS6.625X.500`, `Pipe2STD`).
+    /// Returns `None` when the designation does not belong to any family.
+    pub fn from_designation(designation: &str) -> Option<ShapeFamily> {
+        let designation = designation
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_uppercase();
+        let has_prefix = |prefix: &str| {
+            designation.starts_with(prefix)
+                && designation[prefix.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_digit())
+        };
+
+        if has_prefix("2L") {
+            Some(ShapeFamily::DoubleAngle)
+        } else if has_prefix("HSS") {
+            match designation.matches('X').count() {
+                1 => Some(ShapeFamily::RoundHollowStructuralSection),
+                2 => Some(ShapeFamily::HollowStructuralSection),
+                _ => None,
+            }
+        } else if has_prefix("PIPE") {
+            Some(ShapeFamily::Pipe)
+        } else if has_prefix("HP") {
+            Some(ShapeFamily::HPile)
+        } else if has_prefix("WT") {
+            Some(ShapeFamily::WideFlangeTee)
+        } else if has_prefix("W") {
+            Some(ShapeFamily::WideFlange)
+        } else if has_prefix("MC") {
+            Some(ShapeFamily::MiscChannel)
+        } else if has_prefix("MT") {
+            Some(ShapeFamily::MiscTee)
+        } else if has_prefix("M") {
+            Some(ShapeFamily::MiscBeam)
+        } else if has_prefix("ST") {
+            Some(ShapeFamily::StructuralTee)
+        } else if has_prefix("S") {
+            Some(ShapeFamily::StructuralBeam)
+        } else if has_prefix("C") {
+            Some(ShapeFamily::CeeChannel)
+        } else if has_prefix("L") {
+            Some(ShapeFamily::Angle)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for ShapeFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ShapeFamily::WideFlange => "wide flange",
+            ShapeFamily::MiscBeam => "misc. beam",
+            ShapeFamily::StructuralBeam => "structural beam",
+            ShapeFamily::HPile => "h-pile",
+            ShapeFamily::CeeChannel => "cee channel",
+            ShapeFamily::MiscChannel => "misc. channel",
+            ShapeFamily::Angle => "angle",
+            ShapeFamily::WideFlangeTee => "wide flange tee",
+            ShapeFamily::MiscTee => "misc. tee",
+            ShapeFamily::StructuralTee => "structural tee",
+            ShapeFamily::DoubleAngle => "double angle",
+            ShapeFamily::HollowStructuralSection => "HSS",
+            ShapeFamily::RoundHollowStructuralSection => "round HSS",
+            ShapeFamily::Pipe => "pipe",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for ShapeFamily {
+    type Err = UnknownShapeFamilyError;
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.trim().to_lowercase().replace('-', "_");
+        ShapeFamily::ALL
+            .into_iter()
+            .find(|family| family.name() == name)
+            .ok_or(UnknownShapeFamilyError { name })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::{Pipe, SectionProperties, ShapeBuilder};
+
+    #[test]
+    fn optional_properties_belong_to_the_family() {
+        for family in ShapeFamily::ALL {
+            for property in family.optional_properties() {
+                assert!(family.has_property(*property));
+            }
+        }
+    }
+
+    #[test]
+    fn builder_assigns_every_property_of_the_family() {
+        let mut builder = ShapeBuilder::new()
+            .with_edi_std_nomenclature(String::from("Pipe2STD"))
+            .with_aisc_manual_label(String::from("Pipe2STD"));
+        let properties = ShapeFamily::Pipe.properties();
+        for (i, property) in properties.iter().enumerate() {
+            builder = builder.with_property(*property, i as f64);
+        }
+        let pipe = builder.try_build::<Pipe>().unwrap();
+        for (i, property) in properties.iter().enumerate() {
+            assert_eq!(Some(i as f64), pipe.property(*property));
+        }
+    }
+
+    #[test]
+    fn family_from_designation_works_for_every_family() {
+        let cases = [
+            ("W14X90", ShapeFamily::WideFlange),
+            ("M12.5X12.4", ShapeFamily::MiscBeam),
+            ("S24X121", ShapeFamily::StructuralBeam),
+            ("HP14X117", ShapeFamily::HPile),
+            ("C15X50", ShapeFamily::CeeChannel),
+            ("MC18X58", ShapeFamily::MiscChannel),
+            ("L8X6X1", ShapeFamily::Angle),
+            ("WT7X45", ShapeFamily::WideFlangeTee),
+            ("MT6X5.9", ShapeFamily::MiscTee),
+            ("ST12X60.5", ShapeFamily::StructuralTee),
+            ("2L8X6X1X3/4LLBB", ShapeFamily::DoubleAngle),
+            ("HSS6X6X1/2", ShapeFamily::HollowStructuralSection),
+            ("HSS6.625X.500", ShapeFamily::RoundHollowStructuralSection),
+            ("Pipe2STD", ShapeFamily::Pipe),
+        ];
+        for (designation, family) in cases {
+            assert_eq!(Some(family), ShapeFamily::from_designation(designation));
+        }
+    }
+
+    #[test]
+    fn family_from_designation_ignores_case_and_whitespace() {
+        assert_eq!(
+            Some(ShapeFamily::WideFlange),
+            ShapeFamily::from_designation("w 14 x 90")
+        );
+        assert_eq!(
+            Some(ShapeFamily::Pipe),
+            ShapeFamily::from_designation("PIPE2SCH40")
+        );
+    }
+
+    #[test]
+    fn family_from_name_works() {
+        for family in ShapeFamily::ALL {
+            assert_eq!(Ok(family), family.name().parse::<ShapeFamily>());
+        }
+        assert_eq!(
+            Ok(ShapeFamily::WideFlangeTee),
+            "wide-flange-tee".parse::<ShapeFamily>()
+        );
+        assert!("wide flange".parse::<ShapeFamily>().is_err());
+    }
+
+    #[test]
+    fn depth_and_width_are_properties_of_the_family() {
+        for family in ShapeFamily::ALL {
+            assert!(family.has_property(family.depth_property()));
+            assert!(family.has_property(family.width_property()));
+        }
+    }
+
+    #[test]
+    fn unknown_designation_returns_none() {
+        assert_eq!(None, ShapeFamily::from_designation("X14X90"));
+        assert_eq!(None, ShapeFamily::from_designation("W"));
+        assert_eq!(None, ShapeFamily::from_designation(""));
+        assert_eq!(None, ShapeFamily::from_designation("HSS6"));
+    }
+}
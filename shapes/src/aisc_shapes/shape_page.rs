@@ -0,0 +1,239 @@
+use crate::aisc_shapes::errors::UnknownSortKeyError;
+use crate::aisc_shapes::{ShapeProperty, ShapeQuery, SortDirection};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+/// The keys a listing of shapes can be sorted by
+pub enum SortKey {
+    /// The AISC Manual Label, alphabetically
+    #[default]
+    Label,
+    /// The nominal weight W
+    Weight,
+    /// The depth of the family, see [crate::aisc_shapes::ShapeFamily::depth_property]
+    Depth,
+    /// The moment of inertia about the x-axis Ix
+    Ix,
+}
+
+impl SortKey {
+    /// The properties sorted by, the first one a family defines deciding the order.
+    /// Depth lists the depth property of every family ahead of the properties
+    /// other families use for it, e.g. `Ht` of rectangular HSS before `b` of angles,
+    /// so that only the depth of the family is used by repositories.
+    pub fn properties(&self) -> &'static [ShapeProperty] {
+        match self {
+            SortKey::Label => &[],
+            SortKey::Weight => &[ShapeProperty::WUpper],
+            SortKey::Depth => &[
+                ShapeProperty::Ht,
+                ShapeProperty::Od,
+                ShapeProperty::BLower,
+                ShapeProperty::DLower,
+            ],
+            SortKey::Ix => &[ShapeProperty::Ix],
+        }
+    }
+}
+
+impl FromStr for SortKey {
+    type Err = UnknownSortKeyError;
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.trim().to_lowercase().as_str() {
+            "label" | "aisc_manual_label" => Ok(SortKey::Label),
+            "weight" | "w_upper" => Ok(SortKey::Weight),
+            "depth" => Ok(SortKey::Depth),
+            "ix" => Ok(SortKey::Ix),
+            _ => Err(UnknownSortKeyError {
+                name: String::from(name),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A page of a listing of shapes, `limit` shapes starting at `offset`
+/// in the order of the sort key and direction
+pub struct PageRequest {
+    /// The number of shapes preceding the page
+    pub offset: usize,
+    /// The largest number of shapes on the page
+    pub limit: usize,
+    /// The key the shapes are sorted by, ties are broken by AISC Manual Label
+    pub sort: SortKey,
+    /// The direction the shapes are sorted in
+    pub direction: SortDirection,
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        PageRequest {
+            offset: 0,
+            limit: PageRequest::DEFAULT_LIMIT,
+            sort: SortKey::default(),
+            direction: SortDirection::default(),
+        }
+    }
+}
+
+impl PageRequest {
+    /// The number of shapes on a page when no limit is given
+    pub const DEFAULT_LIMIT: usize = 50;
+
+    /// The largest number of shapes on a page
+    pub const MAX_LIMIT: usize = 500;
+
+    /// Creates a request for the first page of shapes sorted by label
+    pub fn new() -> Self {
+        PageRequest::default()
+    }
+
+    /// Starts the page after `offset` shapes
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the largest number of shapes on the page, between 1 and [PageRequest::MAX_LIMIT]
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit.clamp(1, PageRequest::MAX_LIMIT);
+        self
+    }
+
+    /// Sorts the shapes by the key in the direction
+    pub fn sorted_by(mut self, sort: SortKey, direction: SortDirection) -> Self {
+        self.sort = sort;
+        self.direction = direction;
+        self
+    }
+
+    /// The request for the page following this one
+    pub fn next(&self) -> Self {
+        PageRequest {
+            offset: self.offset.saturating_add(self.limit),
+            ..*self
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A page of shapes, with the offset of the next page when there are more shapes
+pub struct ShapePage<T> {
+    /// The shapes on the page, in the requested order
+    pub shapes: Vec<T>,
+    /// The number of shapes preceding the page
+    pub offset: usize,
+    /// The largest number of shapes on the page
+    pub limit: usize,
+    /// The offset of the next page, `None` on the last page
+    pub next_offset: Option<usize>,
+}
+
+impl<T> ShapePage<T> {
+    /// Converts the request into a query returning the shapes of the page
+    /// and the first shape of the next page, telling whether there is one.
+    ///
+    /// ```
+    /// use shapes::aisc_shapes::{PageRequest, ShapePage, SortDirection, SortKey, WideFlange};
+    ///
+    /// let request = PageRequest::new()
+    ///     .with_offset(100)
+    ///     .with_limit(50)
+    ///     .sorted_by(SortKey::Weight, SortDirection::Descending);
+    /// let query = ShapePage::<WideFlange>::query(&request);
+    /// assert_eq!(Some(100), query.offset);
+    /// assert_eq!(Some(51), query.limit);
+    /// ```
+    pub fn query(request: &PageRequest) -> ShapeQuery {
+        let mut query = ShapeQuery::new();
+        for property in request.sort.properties() {
+            query = query.with_order(*property, request.direction);
+        }
+        let label_order = match request.sort {
+            SortKey::Label => request.direction,
+            _ => SortDirection::Ascending,
+        };
+        query
+            .with_label_order(label_order)
+            .with_offset(request.offset)
+            .with_limit(request.limit.saturating_add(1))
+    }
+
+    /// Creates the page from the shapes returned by [ShapePage::query]
+    pub fn from_shapes(request: &PageRequest, mut shapes: Vec<T>) -> Self {
+        let next_offset = (shapes.len() > request.limit).then(|| request.next().offset);
+        shapes.truncate(request.limit);
+        ShapePage {
+            shapes,
+            offset: request.offset,
+            limit: request.limit,
+            next_offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::ShapeFamily;
+
+    #[test]
+    fn depth_sorts_by_the_depth_of_every_family() {
+        for family in ShapeFamily::ALL {
+            let property = SortKey::Depth
+                .properties()
+                .iter()
+                .find(|property| family.has_property(**property));
+            assert_eq!(Some(&family.depth_property()), property, "{family}");
+        }
+    }
+
+    #[test]
+    fn query_fetches_one_more_shape_than_the_page() {
+        let request = PageRequest::new()
+            .with_offset(10)
+            .with_limit(5)
+            .sorted_by(SortKey::Ix, SortDirection::Descending);
+        let query = ShapePage::<()>::query(&request);
+        assert_eq!(ShapeProperty::Ix, query.order[0].property);
+        assert_eq!(SortDirection::Descending, query.order[0].direction);
+        assert_eq!(Some(SortDirection::Ascending), query.label_order);
+        assert_eq!((Some(10), Some(6)), (query.offset, query.limit));
+
+        let query = ShapePage::<()>::query(
+            &PageRequest::new().sorted_by(SortKey::Label, SortDirection::Descending),
+        );
+        assert!(query.order.is_empty());
+        assert_eq!(Some(SortDirection::Descending), query.label_order);
+        assert_eq!(
+            PageRequest::MAX_LIMIT,
+            PageRequest::new().with_limit(10_000).limit
+        );
+        assert_eq!(1, PageRequest::new().with_limit(0).limit);
+    }
+
+    #[test]
+    fn pages_know_whether_more_shapes_follow() {
+        let request = PageRequest::new().with_offset(4).with_limit(2);
+        let page = ShapePage::from_shapes(&request, vec![5, 6, 7]);
+        assert_eq!(vec![5, 6], page.shapes);
+        assert_eq!(Some(6), page.next_offset);
+        let page = ShapePage::from_shapes(&request.next(), vec![7]);
+        assert_eq!((6, None), (page.offset, page.next_offset));
+    }
+
+    #[test]
+    fn sort_keys_parse_from_names() {
+        assert_eq!(Ok(SortKey::Weight), "Weight".parse());
+        assert_eq!(Ok(SortKey::Ix), "ix".parse());
+        assert_eq!(Ok(SortKey::Label), "aisc_manual_label".parse());
+        assert!("zx".parse::<SortKey>().is_err());
+    }
+}
@@ -0,0 +1,456 @@
+use crate::aisc_shapes::errors::UnknownShapePropertyError;
+use crate::aisc_shapes::units::PropertyUnit;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+/// A numeric property of a steel shape, each property corresponds to
+/// the shape field and database column of the same name (e.g. [ShapeProperty::Zx] is `zx`)
+pub enum ShapeProperty {
+    /// (W) Nominal weight, lb/ft (kg/m)
+    WUpper,
+    /// (A) Cross-sectional area, in.2 (mm2)
+    AUpper,
+    /// (d) Overall depth of member, or width of shorter leg for angles,
+    /// or width of the outstanding legs of long legs back-to-back double angles,
+    /// or the width of the back-to-back legs of short legs back-to-back double angles, in. (mm)
+    DLower,
+    /// Detailing value of member depth, in. (mm)
+    Ddet,
+    /// (Ht) Overall depth of square HSS or longer wall of rectangular HSS, in. (mm)
+    Ht,
+    /// Depth of the flat wall of square HSS or longer flat wall of rectangular HSS, in. (mm)
+    H,
+    /// (OD) Outside diameter of round HSS or pipe, in. (mm)
+    Od,
+    /// Width of flange, in. (mm)
+    Bf,
+    /// Detailing value of flange width, in. (mm)
+    Bfdet,
+    /// (B) Overall width of square HSS or shorter wall of rectangular HSS, in. (mm)
+    BUpper,
+    /// (b) Width of the flat wall of square HSS or the shorter flat wall of rectangular HSS,
+    /// or width of the longer leg for angles, or width of the back-to-back legs of long legs back-to-back double angles,
+    /// or width of the outstanding legs of short legs back-to-back double angles, in. (mm)
+    BLower,
+    /// (ID) Inside diameter of pipe, in. (mm)
+    Id,
+    /// Thickness of web, in. (mm)
+    Tw,
+    /// Detailing value of web thickness, in. (mm)
+    Twdet,
+    /// (twdet/2) Detailing value of tw/2, in. (mm)
+    #[cfg_attr(feature = "serde", serde(rename = "twdet_2"))]
+    Twdet2,
+    /// Thickness of flange, in. (mm)
+    Tf,
+    /// Detailing value of flange thickness, in. (mm)
+    Tfdet,
+    /// Thickness of angle leg, in. (mm)
+    TLower,
+    /// Nominal thickness of HSS and pipe wall, in. (mm)
+    TNom,
+    /// Design thickness of HSS and pipe wall, in. (mm)
+    Tdes,
+    /// Distance from outer face of flange to web toe of fillet used for design, in. (mm)
+    Kdes,
+    /// Distance from outer face of flange to web toe of fillet used for detailing, in. (mm)
+    Kdet,
+    /// Distance from web center line to flange toe of fillet used for detailing, in. (mm)
+    K1,
+    /// Horizontal distance from designated edge of member,
+    /// as defined in the AISC Steel Construction Manual Part 1,
+    /// to center of gravity of member, in. (mm)
+    XLower,
+    /// Vertical distance from designated edge of member,
+    /// as defined in the AISC Steel Construction Manual Part 1,
+    /// to center of gravity of member, in. (mm)
+    YLower,
+    /// Horizontal distance from designated edge of member,
+    /// as defined in the AISC Steel Construction Manual Part 1,
+    /// to shear center of member, in. (mm)
+    Eo,
+    /// Horizontal distance from designated edge of member,
+    /// as defined in the AISC Steel Construction Manual Part 1,
+    /// to plastic neutral axis of member, in. (mm)
+    Xp,
+    /// Vertical distance from designated edge of member,
+    /// as defined in the AISC Steel Construction Manual Part 1,
+    /// to plastic neutral axis of member, in. (mm)
+    Yp,
+    /// (bf/2tf) Slenderness ratio for W, M, S, HP, WT, and ST flange
+    #[cfg_attr(feature = "serde", serde(rename = "bf_2tf"))]
+    Bf2tf,
+    /// (b/t) Slenderness ratio for angles and channel flange
+    BT,
+    /// (b/tdes) Slenderness ratio for square HSS or shorter wall of rectangular HSS
+    BTdes,
+    /// (h/tw) Slenderness ratio for W, M, S, HP, or channel web
+    HTw,
+    /// (h/tdes) Slenderness ratio for square HSS or longer wall of rectangular HSS
+    HTdes,
+    /// (D/t) Slenderness ratio for round HSS and pipe (D = ID), or tee shapes (D = d)
+    DT,
+    /// (Ix) Moment of inertia about the x-axis, in.4 (´106 mm4)
+    Ix,
+    /// (Zx) Plastic section modulus about the x-axis, in.3 (´103 mm3)
+    Zx,
+    /// (Sx) Elastic section modulus about the x-axis, in.3 (´103 mm3)
+    Sx,
+    /// Radius of gyration about the x-axis, in. (mm)
+    Rx,
+    /// (Iy) Moment of inertia about the y-axis, in.4 (´106 mm4)
+    Iy,
+    /// (Zy) Plastic section modulus about the y-axis, in.3 (´103 mm3)
+    Zy,
+    /// (Sy) Elastic section modulus about the y-axis, in.3 (´103 mm3)
+    Sy,
+    /// Radius of gyration about the y-axis (with no separation for double angles back-to-back), in. (mm)
+    Ry,
+    /// (Iz) Moment of inertia about the z-axis, in.4 (´106 mm4)
+    Iz,
+    /// Radius of gyration about the z-axis, in. (mm)
+    Rz,
+    /// (Sz) Elastic section modulus about the z-axis, in.3 (´103 mm3). For single angles, see SzA, SzB, and SzC.
+    Sz,
+    /// (J) Torsional constant, in.4 (´103 mm4)
+    JUpper,
+    /// (Cw) Warping constant, in.6 (´109 mm6)
+    Cw,
+    /// (C) HSS torsional constant, in.3 (´103 mm3)
+    CUpper,
+    /// (WNo) Normalized warping function, as used in Design Guide 9, in.2 (mm2)
+    Wno,
+    /// (Sw1) Warping statical moment at point 1 on cross section,
+    /// as used in AISC Design Guide 9 and shown in Figures 1 and 2, in.4 (´106 mm4)
+    Sw1,
+    /// (Sw2) Warping statical moment at point 2 on cross section,
+    /// as used in AISC Design Guide 9 and shown in Figure 2, in.4 (´106 mm4)
+    Sw2,
+    /// (Sw3) Warping statical moment at point 3 on cross section,
+    /// as used in AISC Design Guide 9 and shown in Figure 2, in.4 (´106 mm4)
+    Sw3,
+    /// (Qf) Statical moment for a point in the flange directly above the vertical edge of the web,
+    /// as used in AISC Design Guide 9, in.3 (´103 mm3)
+    Qf,
+    /// (Qw) Statical moment for a point at mid-depth of the cross section,
+    /// as used in AISC Design Guide 9, in.3 (´103 mm3)
+    Qw,
+    /// Polar radius of gyration about the shear center, in. (mm)
+    Ro,
+    /// (H) Flexural constant
+    HUpper,
+    /// (tan(α)) Tangent of the angle between the y-y and z-z axes for single angles,
+    /// where a is shown in Figure 3
+    TanA,
+    /// (Iw) Moment of inertia about the w-axis for single angles, in.4 (´106 mm4)
+    Iw,
+    /// (zA) Distance from point A to center of gravity along z-axis
+    Za,
+    /// (zB) Distance from point B to center of gravity along z-axis
+    Zb,
+    /// (zC) Distance from point C to center of gravity along z-axis
+    Zc,
+    /// (wA) Distance from point A to center of gravity along w-axis
+    Wa,
+    /// (wB) Distance from point B to center of gravity along w-axis
+    Wb,
+    /// (wC) Distance from point C to center of gravity along w-axis
+    Wc,
+    /// (SwA) Elastic section modulus about the w-axis at point A on cross section
+    Swa,
+    /// (SwB) Elastic section modulus about the w-axis at point B on cross section
+    Swb,
+    /// (SwC) Elastic section modulus about the w-axis at point C on cross section
+    Swc,
+    /// (SzA) Elastic section modulus about the z-axis at point A on cross section
+    Sza,
+    /// (SzB) Elastic section modulus about the z-axis at point B on cross section
+    Szb,
+    /// (SzC) Elastic section modulus about the z-axis at point C on cross section
+    Szc,
+    /// Effective radius of gyration, in. (mm)
+    Rts,
+    /// Distance between the flange centroids, in. (mm)
+    Ho,
+    /// (PA) Shape perimeter minus one flange surface (or short leg surface for a single angle),
+    /// as used in Design Guide 19, in. (mm)
+    Pa,
+    /// (PA2) Single angle shape perimeter minus long leg surface,
+    /// as used in AISC Design Guide 19, in. (mm)
+    #[cfg_attr(feature = "serde", serde(rename = "pa_2"))]
+    Pa2,
+    /// (PB) Shape perimeter, as used in AISC Design Guide 19, in. (mm)
+    Pb,
+    /// (PC) Box perimeter minus one flange surface, as used in Design Guide 19, in. (mm)
+    Pc,
+    /// (PD) Box perimeter, as used in AISC Design Guide 19, in. (mm)
+    Pd,
+    /// (T) Distance between web toes of fillets at top and bottom of web, in. (mm)
+    T,
+    /// (WGi) The workable gage for the inner fastener holes in the flange that provides for entering and tightening clearances and edge distance and spacing requirements.
+    /// The actual size, combination, and orientation of fastener components should be compared with the geometry of the cross section to ensure compatibility.
+    /// See AISC Manual Part 1 for additional information, in. (mm)
+    Wgi,
+    /// (WGo) The bolt spacing between inner and outer fastener holes when the workable gage is compatible with four holes across the flange. See AISC Manual Part 1 for additional information, in. (mm)
+    Wgo,
+}
+
+impl ShapeProperty {
+    /// Every numeric shape property, in the order of the AISC shapes database columns
+    pub const ALL: [ShapeProperty; 80] = [
+        ShapeProperty::WUpper,
+        ShapeProperty::AUpper,
+        ShapeProperty::DLower,
+        ShapeProperty::Ddet,
+        ShapeProperty::Ht,
+        ShapeProperty::H,
+        ShapeProperty::Od,
+        ShapeProperty::Bf,
+        ShapeProperty::Bfdet,
+        ShapeProperty::BUpper,
+        ShapeProperty::BLower,
+        ShapeProperty::Id,
+        ShapeProperty::Tw,
+        ShapeProperty::Twdet,
+        ShapeProperty::Twdet2,
+        ShapeProperty::Tf,
+        ShapeProperty::Tfdet,
+        ShapeProperty::TLower,
+        ShapeProperty::TNom,
+        ShapeProperty::Tdes,
+        ShapeProperty::Kdes,
+        ShapeProperty::Kdet,
+        ShapeProperty::K1,
+        ShapeProperty::XLower,
+        ShapeProperty::YLower,
+        ShapeProperty::Eo,
+        ShapeProperty::Xp,
+        ShapeProperty::Yp,
+        ShapeProperty::Bf2tf,
+        ShapeProperty::BT,
+        ShapeProperty::BTdes,
+        ShapeProperty::HTw,
+        ShapeProperty::HTdes,
+        ShapeProperty::DT,
+        ShapeProperty::Ix,
+        ShapeProperty::Zx,
+        ShapeProperty::Sx,
+        ShapeProperty::Rx,
+        ShapeProperty::Iy,
+        ShapeProperty::Zy,
+        ShapeProperty::Sy,
+        ShapeProperty::Ry,
+        ShapeProperty::Iz,
+        ShapeProperty::Rz,
+        ShapeProperty::Sz,
+        ShapeProperty::JUpper,
+        ShapeProperty::Cw,
+        ShapeProperty::CUpper,
+        ShapeProperty::Wno,
+        ShapeProperty::Sw1,
+        ShapeProperty::Sw2,
+        ShapeProperty::Sw3,
+        ShapeProperty::Qf,
+        ShapeProperty::Qw,
+        ShapeProperty::Ro,
+        ShapeProperty::HUpper,
+        ShapeProperty::TanA,
+        ShapeProperty::Iw,
+        ShapeProperty::Za,
+        ShapeProperty::Zb,
+        ShapeProperty::Zc,
+        ShapeProperty::Wa,
+        ShapeProperty::Wb,
+        ShapeProperty::Wc,
+        ShapeProperty::Swa,
+        ShapeProperty::Swb,
+        ShapeProperty::Swc,
+        ShapeProperty::Sza,
+        ShapeProperty::Szb,
+        ShapeProperty::Szc,
+        ShapeProperty::Rts,
+        ShapeProperty::Ho,
+        ShapeProperty::Pa,
+        ShapeProperty::Pa2,
+        ShapeProperty::Pb,
+        ShapeProperty::Pc,
+        ShapeProperty::Pd,
+        ShapeProperty::T,
+        ShapeProperty::Wgi,
+        ShapeProperty::Wgo,
+    ];
+
+    /// The name of the shape field and database column holding the property
+    pub fn column_name(&self) -> &'static str {
+        match self {
+            ShapeProperty::WUpper => "w_upper",
+            ShapeProperty::AUpper => "a_upper",
+            ShapeProperty::DLower => "d_lower",
+            ShapeProperty::Ddet => "ddet",
+            ShapeProperty::Ht => "ht",
+            ShapeProperty::H => "h",
+            ShapeProperty::Od => "od",
+            ShapeProperty::Bf => "bf",
+            ShapeProperty::Bfdet => "bfdet",
+            ShapeProperty::BUpper => "b_upper",
+            ShapeProperty::BLower => "b_lower",
+            ShapeProperty::Id => "id",
+            ShapeProperty::Tw => "tw",
+            ShapeProperty::Twdet => "twdet",
+            ShapeProperty::Twdet2 => "twdet_2",
+            ShapeProperty::Tf => "tf",
+            ShapeProperty::Tfdet => "tfdet",
+            ShapeProperty::TLower => "t_lower",
+            ShapeProperty::TNom => "t_nom",
+            ShapeProperty::Tdes => "tdes",
+            ShapeProperty::Kdes => "kdes",
+            ShapeProperty::Kdet => "kdet",
+            ShapeProperty::K1 => "k1",
+            ShapeProperty::XLower => "x_lower",
+            ShapeProperty::YLower => "y_lower",
+            ShapeProperty::Eo => "eo",
+            ShapeProperty::Xp => "xp",
+            ShapeProperty::Yp => "yp",
+            ShapeProperty::Bf2tf => "bf_2tf",
+            ShapeProperty::BT => "b_t",
+            ShapeProperty::BTdes => "b_tdes",
+            ShapeProperty::HTw => "h_tw",
+            ShapeProperty::HTdes => "h_tdes",
+            ShapeProperty::DT => "d_t",
+            ShapeProperty::Ix => "ix",
+            ShapeProperty::Zx => "zx",
+            ShapeProperty::Sx => "sx",
+            ShapeProperty::Rx => "rx",
+            ShapeProperty::Iy => "iy",
+            ShapeProperty::Zy => "zy",
+            ShapeProperty::Sy => "sy",
+            ShapeProperty::Ry => "ry",
+            ShapeProperty::Iz => "iz",
+            ShapeProperty::Rz => "rz",
+            ShapeProperty::Sz => "sz",
+            ShapeProperty::JUpper => "j_upper",
+            ShapeProperty::Cw => "cw",
+            ShapeProperty::CUpper => "c_upper",
+            ShapeProperty::Wno => "wno",
+            ShapeProperty::Sw1 => "sw1",
+            ShapeProperty::Sw2 => "sw2",
+            ShapeProperty::Sw3 => "sw3",
+            ShapeProperty::Qf => "qf",
+            ShapeProperty::Qw => "qw",
+            ShapeProperty::Ro => "ro",
+            ShapeProperty::HUpper => "h_upper",
+            ShapeProperty::TanA => "tan_a",
+            ShapeProperty::Iw => "iw",
+            ShapeProperty::Za => "za",
+            ShapeProperty::Zb => "zb",
+            ShapeProperty::Zc => "zc",
+            ShapeProperty::Wa => "wa",
+            ShapeProperty::Wb => "wb",
+            ShapeProperty::Wc => "wc",
+            ShapeProperty::Swa => "swa",
+            ShapeProperty::Swb => "swb",
+            ShapeProperty::Swc => "swc",
+            ShapeProperty::Sza => "sza",
+            ShapeProperty::Szb => "szb",
+            ShapeProperty::Szc => "szc",
+            ShapeProperty::Rts => "rts",
+            ShapeProperty::Ho => "ho",
+            ShapeProperty::Pa => "pa",
+            ShapeProperty::Pa2 => "pa_2",
+            ShapeProperty::Pb => "pb",
+            ShapeProperty::Pc => "pc",
+            ShapeProperty::Pd => "pd",
+            ShapeProperty::T => "t",
+            ShapeProperty::Wgi => "wgi",
+            ShapeProperty::Wgo => "wgo",
+        }
+    }
+    /// The kind of quantity the property measures, see [PropertyUnit]
+    pub fn unit(&self) -> PropertyUnit {
+        match self {
+            ShapeProperty::Bf2tf
+            | ShapeProperty::BT
+            | ShapeProperty::BTdes
+            | ShapeProperty::HTw
+            | ShapeProperty::HTdes
+            | ShapeProperty::DT
+            | ShapeProperty::HUpper
+            | ShapeProperty::TanA => PropertyUnit::Dimensionless,
+            ShapeProperty::AUpper | ShapeProperty::Wno => PropertyUnit::Area,
+            ShapeProperty::Zx
+            | ShapeProperty::Sx
+            | ShapeProperty::Zy
+            | ShapeProperty::Sy
+            | ShapeProperty::Sz
+            | ShapeProperty::CUpper
+            | ShapeProperty::Qf
+            | ShapeProperty::Qw
+            | ShapeProperty::Swa
+            | ShapeProperty::Swb
+            | ShapeProperty::Swc
+            | ShapeProperty::Sza
+            | ShapeProperty::Szb
+            | ShapeProperty::Szc => PropertyUnit::SectionModulus,
+            ShapeProperty::Ix
+            | ShapeProperty::Iy
+            | ShapeProperty::Iz
+            | ShapeProperty::Iw
+            | ShapeProperty::Sw1
+            | ShapeProperty::Sw2
+            | ShapeProperty::Sw3 => PropertyUnit::MomentOfInertia,
+            ShapeProperty::JUpper => PropertyUnit::TorsionalConstant,
+            ShapeProperty::Cw => PropertyUnit::WarpingConstant,
+            ShapeProperty::WUpper => PropertyUnit::LinearWeight,
+            _ => PropertyUnit::Length,
+        }
+    }
+}
+
+impl fmt::Display for ShapeProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.column_name())
+    }
+}
+
+impl FromStr for ShapeProperty {
+    type Err = UnknownShapePropertyError;
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.trim().to_lowercase();
+        ShapeProperty::ALL
+            .into_iter()
+            .find(|property| property.column_name() == name)
+            .ok_or(UnknownShapePropertyError { name })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn property_from_column_name_works() {
+        for property in ShapeProperty::ALL {
+            assert_eq!(
+                Ok(property),
+                property.column_name().parse::<ShapeProperty>()
+            );
+        }
+        assert_eq!(Ok(ShapeProperty::Zx), "ZX".parse::<ShapeProperty>());
+        assert!("depth".parse::<ShapeProperty>().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialized_name_matches_column_name() {
+        for property in ShapeProperty::ALL {
+            let json = serde_json::to_value(property).unwrap();
+            assert_eq!(serde_json::json!(property.column_name()), json);
+        }
+    }
+}
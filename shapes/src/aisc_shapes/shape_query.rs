@@ -0,0 +1,372 @@
+use crate::aisc_shapes::{SectionProperties, ShapeProperty};
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+/// A bound on the value of a numeric shape property,
+/// shapes that do not define the property never satisfy the filter
+pub enum PropertyFilter {
+    /// The property must be greater than or equal to the value
+    AtLeast(ShapeProperty, f64),
+    /// The property must be less than or equal to the value
+    AtMost(ShapeProperty, f64),
+    /// The property must be within `tolerance` of `value`
+    Approximately {
+        /// The filtered property
+        property: ShapeProperty,
+        /// The expected value of the property
+        value: f64,
+        /// The largest allowed absolute difference from `value`
+        tolerance: f64,
+    },
+}
+
+impl PropertyFilter {
+    /// The property the filter applies to
+    pub fn property(&self) -> ShapeProperty {
+        match self {
+            PropertyFilter::AtLeast(property, _) => *property,
+            PropertyFilter::AtMost(property, _) => *property,
+            PropertyFilter::Approximately { property, .. } => *property,
+        }
+    }
+
+    /// The inclusive lower and upper bounds of the filter
+    pub fn bounds(&self) -> (Option<f64>, Option<f64>) {
+        match self {
+            PropertyFilter::AtLeast(_, min) => (Some(*min), None),
+            PropertyFilter::AtMost(_, max) => (None, Some(*max)),
+            PropertyFilter::Approximately {
+                value, tolerance, ..
+            } => (Some(value - tolerance), Some(value + tolerance)),
+        }
+    }
+
+    /// Whether the value of the property satisfies the filter
+    pub fn matches(&self, value: f64) -> bool {
+        let (min, max) = self.bounds();
+        min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+/// The direction in which shapes are sorted
+pub enum SortDirection {
+    /// Smallest value first
+    #[default]
+    Ascending,
+    /// Largest value first
+    Descending,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Sorts shapes by the value of a property, shapes that
+/// do not define the property are sorted last in either direction
+pub struct SortOrder {
+    /// The property to sort by
+    pub property: ShapeProperty,
+    /// The direction to sort in
+    pub direction: SortDirection,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A composable query used to filter, order and limit the shapes
+/// retrieved from a repository. A shape must satisfy every filter.
+///
+/// ```
+/// use shapes::aisc_shapes::{ShapeProperty, ShapeQuery, SortDirection};
+///
+/// let query = ShapeQuery::new()
+///     .with_min(ShapeProperty::Zx, 120.0)
+///     .with_max(ShapeProperty::DLower, 16.0)
+///     .with_order(ShapeProperty::WUpper, SortDirection::Ascending)
+///     .with_limit(5);
+/// assert_eq!(2, query.filters.len());
+/// ```
+pub struct ShapeQuery {
+    /// Bounds on numeric properties
+    pub filters: Vec<PropertyFilter>,
+    /// Only include shapes whose AISC Manual Label or
+    /// EDI Std Nomenclature starts with this prefix (case-insensitive)
+    pub label_prefix: Option<String>,
+    /// Sort orders applied in sequence, the first order has the highest priority
+    pub order: Vec<SortOrder>,
+    /// Sorts shapes by AISC Manual Label after every sort order,
+    /// giving a stable order to shapes with equal properties.
+    /// Labels are compared byte by byte, e.g. `Pipe12XS` before `Pipe1STD`,
+    /// which SQL repositories match with a binary collation.
+    pub label_order: Option<SortDirection>,
+    /// The number of shapes to skip, after filtering and sorting
+    pub offset: Option<usize>,
+    /// The maximum number of shapes to return
+    pub limit: Option<usize>,
+}
+
+impl ShapeQuery {
+    /// The tolerance used by [ShapeQuery::with_value]
+    pub const DEFAULT_TOLERANCE: f64 = 0.001;
+
+    /// Creates a query without filters that matches every shape
+    pub fn new() -> Self {
+        ShapeQuery::default()
+    }
+
+    /// Adds a filter to the query
+    pub fn with_filter(mut self, filter: PropertyFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Requires the property to be greater than or equal to `min`
+    pub fn with_min(self, property: ShapeProperty, min: f64) -> Self {
+        self.with_filter(PropertyFilter::AtLeast(property, min))
+    }
+
+    /// Requires the property to be less than or equal to `max`
+    pub fn with_max(self, property: ShapeProperty, max: f64) -> Self {
+        self.with_filter(PropertyFilter::AtMost(property, max))
+    }
+
+    /// Requires the property to be within the inclusive range
+    pub fn with_range(self, property: ShapeProperty, min: f64, max: f64) -> Self {
+        self.with_min(property, min).with_max(property, max)
+    }
+
+    /// Requires the property to equal `value` within [ShapeQuery::DEFAULT_TOLERANCE]
+    pub fn with_value(self, property: ShapeProperty, value: f64) -> Self {
+        self.with_approximate_value(property, value, ShapeQuery::DEFAULT_TOLERANCE)
+    }
+
+    /// Requires the property to be within `tolerance` of `value`
+    pub fn with_approximate_value(
+        self,
+        property: ShapeProperty,
+        value: f64,
+        tolerance: f64,
+    ) -> Self {
+        self.with_filter(PropertyFilter::Approximately {
+            property,
+            value,
+            tolerance,
+        })
+    }
+
+    /// Requires the label of the shape to start with the prefix
+    pub fn with_label_prefix(mut self, prefix: &str) -> Self {
+        self.label_prefix = Some(String::from(prefix));
+        self
+    }
+
+    /// Sorts the shapes by the property, after any previously added orders
+    pub fn with_order(mut self, property: ShapeProperty, direction: SortDirection) -> Self {
+        self.order.push(SortOrder {
+            property,
+            direction,
+        });
+        self
+    }
+
+    /// Sorts the shapes by AISC Manual Label after every sort order
+    pub fn with_label_order(mut self, direction: SortDirection) -> Self {
+        self.label_order = Some(direction);
+        self
+    }
+
+    /// Skips the first shapes, e.g. to retrieve the next page of a listing
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Limits the number of shapes returned
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Whether the shape satisfies the label prefix and every filter of the query
+    pub fn matches<T: SectionProperties>(&self, shape: &T) -> bool {
+        self.matches_label(shape)
+            && self.filters.iter().all(|filter| {
+                shape
+                    .property(filter.property())
+                    .is_some_and(|value| filter.matches(value))
+            })
+    }
+
+    /// Compares two shapes according to the sort orders of the query
+    pub fn compare<T: SectionProperties>(&self, a: &T, b: &T) -> Ordering {
+        for order in &self.order {
+            let ordering = match (a.property(order.property), b.property(order.property)) {
+                (Some(a), Some(b)) => match order.direction {
+                    SortDirection::Ascending => a.total_cmp(&b),
+                    SortDirection::Descending => b.total_cmp(&a),
+                },
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        match self.label_order {
+            Some(SortDirection::Ascending) => a.aisc_manual_label().cmp(b.aisc_manual_label()),
+            Some(SortDirection::Descending) => b.aisc_manual_label().cmp(a.aisc_manual_label()),
+            None => Ordering::Equal,
+        }
+    }
+
+    /// Filters, sorts, offsets and limits a list of shapes in memory,
+    /// with the same semantics repositories apply in the data source
+    pub fn apply<T: SectionProperties>(&self, shapes: Vec<T>) -> Vec<T> {
+        let mut shapes = shapes
+            .into_iter()
+            .filter(|shape| self.matches(shape))
+            .collect::<Vec<_>>();
+        shapes.sort_by(|a, b| self.compare(a, b));
+        if let Some(offset) = self.offset {
+            shapes.drain(..offset.min(shapes.len()));
+        }
+        if let Some(limit) = self.limit {
+            shapes.truncate(limit);
+        }
+        shapes
+    }
+
+    fn matches_label<T: SectionProperties>(&self, shape: &T) -> bool {
+        match &self.label_prefix {
+            Some(prefix) => {
+                let prefix = prefix.to_uppercase();
+                shape
+                    .aisc_manual_label()
+                    .to_uppercase()
+                    .starts_with(&prefix)
+                    || shape
+                        .edi_std_nomenclature()
+                        .to_uppercase()
+                        .starts_with(&prefix)
+            }
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::{Pipe, ShapeBuilder};
+
+    fn pipe(label: &str, w_upper: f64, od: f64) -> Pipe {
+        ShapeBuilder::new()
+            .with_edi_std_nomenclature(String::from(label))
+            .with_aisc_manual_label(String::from(label))
+            .with_w_upper(w_upper)
+            .with_a_upper(1.0)
+            .with_od(od)
+            .with_id(od - 0.25)
+            .with_t_nom(0.154)
+            .with_tdes(0.143)
+            .with_d_t(16.6)
+            .with_ix(0.627)
+            .with_zx(0.713)
+            .with_sx(0.528)
+            .with_rx(0.791)
+            .with_iy(0.627)
+            .with_zy(0.713)
+            .with_sy(0.528)
+            .with_ry(0.791)
+            .with_j_upper(1.25)
+            .try_build::<Pipe>()
+            .unwrap()
+    }
+
+    fn pipes() -> Vec<Pipe> {
+        vec![
+            pipe("Pipe4STD", 10.8, 4.5),
+            pipe("Pipe2STD", 3.66, 2.375),
+            pipe("Pipe3STD", 7.58, 3.5),
+            pipe("Pipe3XS", 10.3, 3.5),
+        ]
+    }
+
+    fn labels(shapes: &[Pipe]) -> Vec<&str> {
+        shapes
+            .iter()
+            .map(|s| s.aisc_manual_label.as_str())
+            .collect::<Vec<_>>()
+    }
+
+    #[test]
+    fn empty_query_returns_every_shape() {
+        assert_eq!(4, ShapeQuery::new().apply(pipes()).len());
+    }
+
+    #[test]
+    fn range_filters_are_inclusive() {
+        let query = ShapeQuery::new().with_range(ShapeProperty::WUpper, 3.66, 10.3);
+        let shapes = query.apply(pipes());
+        assert_eq!(vec!["Pipe2STD", "Pipe3STD", "Pipe3XS"], labels(&shapes));
+    }
+
+    #[test]
+    fn approximate_value_uses_tolerance() {
+        let shapes = ShapeQuery::new()
+            .with_value(ShapeProperty::Od, 3.5004)
+            .apply(pipes());
+        assert_eq!(vec!["Pipe3STD", "Pipe3XS"], labels(&shapes));
+        let shapes = ShapeQuery::new()
+            .with_approximate_value(ShapeProperty::Od, 3.6, 0.05)
+            .apply(pipes());
+        assert!(shapes.is_empty());
+    }
+
+    #[test]
+    fn undefined_properties_never_match() {
+        let shapes = ShapeQuery::new()
+            .with_min(ShapeProperty::Bf, 0.0)
+            .apply(pipes());
+        assert!(shapes.is_empty());
+    }
+
+    #[test]
+    fn shapes_are_ordered_and_limited() {
+        let shapes = ShapeQuery::new()
+            .with_order(ShapeProperty::Od, SortDirection::Descending)
+            .with_order(ShapeProperty::WUpper, SortDirection::Ascending)
+            .with_limit(3)
+            .apply(pipes());
+        assert_eq!(vec!["Pipe4STD", "Pipe3STD", "Pipe3XS"], labels(&shapes));
+    }
+
+    #[test]
+    fn labels_break_ties_and_offsets_skip_shapes() {
+        let query = ShapeQuery::new()
+            .with_order(ShapeProperty::Od, SortDirection::Ascending)
+            .with_label_order(SortDirection::Descending);
+        assert_eq!(
+            vec!["Pipe2STD", "Pipe3XS", "Pipe3STD", "Pipe4STD"],
+            labels(&query.apply(pipes()))
+        );
+        let shapes = query.clone().with_offset(1).with_limit(2).apply(pipes());
+        assert_eq!(vec!["Pipe3XS", "Pipe3STD"], labels(&shapes));
+        assert!(query.with_offset(10).apply(pipes()).is_empty());
+    }
+
+    #[test]
+    fn label_prefix_is_case_insensitive() {
+        let shapes = ShapeQuery::new().with_label_prefix("pipe3").apply(pipes());
+        assert_eq!(vec!["Pipe3STD", "Pipe3XS"], labels(&shapes));
+    }
+}
@@ -1,47 +1,133 @@
-use std::error::Error;
+use crate::aisc_shapes::{PageRequest, RepositoryError, ShapePage, ShapeQuery, ShapeSelection};
 /// Trait to manage retrieving shapes from a data source
 pub trait ShapeRepository<T> {
     /// Retrieves all shapes
-    fn all(&self) -> impl Future<Output = Result<Vec<T>, Box<dyn Error>>> + Send;
-    /// Retrieves a shape based on it's EDI Std Nomenclature
+    fn all(&self) -> impl Future<Output = Result<Vec<T>, RepositoryError>> + Send;
+    /// Retrieves a shape based on it's EDI Std Nomenclature,
+    /// or its metric EDI Std Nomenclature (see [crate::aisc_shapes::MetricDesignation])
     fn shape_with_edi_std_nomenclature(
         &self,
         edi_std_nomenclature: String,
-    ) -> impl Future<Output = Result<T, Box<dyn Error>>> + Send;
-    /// Retrieves a shape based on it's AISC Manual Label
+    ) -> impl Future<Output = Result<T, RepositoryError>> + Send;
+    /// Retrieves a shape based on it's AISC Manual Label,
+    /// or its metric AISC Manual Label (e.g. W360X134 for W14X90)
     fn shape_with_aisc_manual_label(
         &self,
         aisc_manual_label: String,
-    ) -> impl Future<Output = Result<T, Box<dyn Error>>> + Send;
+    ) -> impl Future<Output = Result<T, RepositoryError>> + Send;
     /// Retrieves shapes based on their depth
     fn shapes_with_depth(
         &self,
         depth: f64,
-    ) -> impl Future<Output = Result<Vec<T>, Box<dyn Error>>> + Send;
+    ) -> impl Future<Output = Result<Vec<T>, RepositoryError>> + Send;
     /// Retrieves shapes based on their width
     fn shapes_with_width(
         &self,
         width: f64,
-    ) -> impl Future<Output = Result<Vec<T>, Box<dyn Error>>> + Send;
+    ) -> impl Future<Output = Result<Vec<T>, RepositoryError>> + Send;
+    /// Retrieves shapes matching every filter of the query,
+    /// ordered and limited as the query specifies
+    fn shapes_matching(
+        &self,
+        query: ShapeQuery,
+    ) -> impl Future<Output = Result<Vec<T>, RepositoryError>> + Send;
+    /// Retrieves the lightest shape satisfying every filter of the requirements,
+    /// plus up to `alternatives` of the next heavier shapes that also satisfy them,
+    /// returns `None` when no shape satisfies the requirements
+    fn lightest_shape_matching(
+        &self,
+        requirements: ShapeQuery,
+        alternatives: usize,
+    ) -> impl Future<Output = Result<Option<ShapeSelection<T>>, RepositoryError>> + Send
+    where
+        Self: Sync,
+        T: Send,
+    {
+        async move {
+            let query = ShapeSelection::<T>::query(requirements, alternatives);
+            let shapes = self.shapes_matching(query).await?;
+            Ok(ShapeSelection::from_shapes(shapes))
+        }
+    }
+    /// Retrieves a page of the shapes, sorted by the key of the request
+    /// and then by AISC Manual Label, instead of loading every shape with `all`
+    fn page(
+        &self,
+        request: PageRequest,
+    ) -> impl Future<Output = Result<ShapePage<T>, RepositoryError>> + Send
+    where
+        Self: Sync,
+        T: Send,
+    {
+        async move {
+            let shapes = self
+                .shapes_matching(ShapePage::<T>::query(&request))
+                .await?;
+            Ok(ShapePage::from_shapes(&request, shapes))
+        }
+    }
 }
 
 /// Trait to manage retrieving round shapes from a data source
 pub trait RoundShapeRepository<T> {
     /// Retrieves all shapes
-    fn all(&self) -> impl Future<Output = Result<Vec<T>, Box<dyn Error>>> + Send;
-    /// Retrieves a shape based on it's EDI Std Nomenclature
+    fn all(&self) -> impl Future<Output = Result<Vec<T>, RepositoryError>> + Send;
+    /// Retrieves a shape based on it's EDI Std Nomenclature,
+    /// or its metric EDI Std Nomenclature (see [crate::aisc_shapes::MetricDesignation])
     fn shape_with_edi_std_nomenclature(
         &self,
         edi_std_nomenclature: String,
-    ) -> impl Future<Output = Result<T, Box<dyn Error>>> + Send;
-    /// Retrieves a shape based on it's AISC Manual Label
+    ) -> impl Future<Output = Result<T, RepositoryError>> + Send;
+    /// Retrieves a shape based on it's AISC Manual Label,
+    /// or its metric AISC Manual Label (e.g. W360X134 for W14X90)
     fn shape_with_aisc_manual_label(
         &self,
         aisc_manual_label: String,
-    ) -> impl Future<Output = Result<T, Box<dyn Error>>> + Send;
+    ) -> impl Future<Output = Result<T, RepositoryError>> + Send;
     /// Retrieves shapes based on their diameter
     fn shapes_with_diameter(
         &self,
         diameter: f64,
-    ) -> impl Future<Output = Result<Vec<T>, Box<dyn Error>>> + Send;
+    ) -> impl Future<Output = Result<Vec<T>, RepositoryError>> + Send;
+    /// Retrieves shapes matching every filter of the query,
+    /// ordered and limited as the query specifies
+    fn shapes_matching(
+        &self,
+        query: ShapeQuery,
+    ) -> impl Future<Output = Result<Vec<T>, RepositoryError>> + Send;
+    /// Retrieves the lightest shape satisfying every filter of the requirements,
+    /// plus up to `alternatives` of the next heavier shapes that also satisfy them,
+    /// returns `None` when no shape satisfies the requirements
+    fn lightest_shape_matching(
+        &self,
+        requirements: ShapeQuery,
+        alternatives: usize,
+    ) -> impl Future<Output = Result<Option<ShapeSelection<T>>, RepositoryError>> + Send
+    where
+        Self: Sync,
+        T: Send,
+    {
+        async move {
+            let query = ShapeSelection::<T>::query(requirements, alternatives);
+            let shapes = self.shapes_matching(query).await?;
+            Ok(ShapeSelection::from_shapes(shapes))
+        }
+    }
+    /// Retrieves a page of the shapes, sorted by the key of the request
+    /// and then by AISC Manual Label, instead of loading every shape with `all`
+    fn page(
+        &self,
+        request: PageRequest,
+    ) -> impl Future<Output = Result<ShapePage<T>, RepositoryError>> + Send
+    where
+        Self: Sync,
+        T: Send,
+    {
+        async move {
+            let shapes = self
+                .shapes_matching(ShapePage::<T>::query(&request))
+                .await?;
+            Ok(ShapePage::from_shapes(&request, shapes))
+        }
+    }
 }
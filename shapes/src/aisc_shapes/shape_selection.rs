@@ -0,0 +1,80 @@
+use crate::aisc_shapes::{ShapeProperty, ShapeQuery, SortDirection, SortOrder};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The lightest shape satisfying a set of requirements,
+/// along with the next heavier shapes that also satisfy them
+pub struct ShapeSelection<T> {
+    /// The shape with the lowest nominal weight
+    pub lightest: T,
+    /// The next heavier shapes, lightest first
+    pub alternatives: Vec<T>,
+}
+
+impl<T> ShapeSelection<T> {
+    /// Converts the requirements into a query returning the lightest shape
+    /// and `alternatives` heavier shapes. Shapes are ordered by nominal weight,
+    /// any order on the requirements is only used to break ties.
+    ///
+    /// ```
+    /// use shapes::aisc_shapes::{ShapeProperty, ShapeQuery, ShapeSelection, WideFlange};
+    ///
+    /// let requirements = ShapeQuery::new()
+    ///     .with_min(ShapeProperty::Zx, 120.0)
+    ///     .with_max(ShapeProperty::DLower, 16.0);
+    /// let query = ShapeSelection::<WideFlange>::query(requirements, 3);
+    /// assert_eq!(Some(4), query.limit);
+    /// assert_eq!(ShapeProperty::WUpper, query.order[0].property);
+    /// ```
+    pub fn query(requirements: ShapeQuery, alternatives: usize) -> ShapeQuery {
+        let mut order = vec![SortOrder {
+            property: ShapeProperty::WUpper,
+            direction: SortDirection::Ascending,
+        }];
+        order.extend(requirements.order);
+        ShapeQuery {
+            order,
+            limit: Some(alternatives.saturating_add(1)),
+            ..requirements
+        }
+    }
+
+    /// Creates a selection from shapes ordered lightest first,
+    /// returns `None` when there are no shapes
+    pub fn from_shapes(shapes: Vec<T>) -> Option<Self> {
+        let mut shapes = shapes.into_iter();
+        let lightest = shapes.next()?;
+        Some(ShapeSelection {
+            lightest,
+            alternatives: shapes.collect::<Vec<_>>(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_orders_by_weight_before_requested_order() {
+        let requirements = ShapeQuery::new()
+            .with_min(ShapeProperty::Zx, 120.0)
+            .with_order(ShapeProperty::DLower, SortDirection::Descending)
+            .with_limit(50);
+        let query = ShapeSelection::<()>::query(requirements, 2);
+        assert_eq!(1, query.filters.len());
+        assert_eq!(Some(3), query.limit);
+        assert_eq!(
+            vec![ShapeProperty::WUpper, ShapeProperty::DLower],
+            query.order.iter().map(|o| o.property).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn first_shape_is_the_lightest() {
+        let selection = ShapeSelection::from_shapes(vec![1, 2, 3]).unwrap();
+        assert_eq!(1, selection.lightest);
+        assert_eq!(vec![2, 3], selection.alternatives);
+        assert!(ShapeSelection::<i32>::from_shapes(Vec::new()).is_none());
+    }
+}
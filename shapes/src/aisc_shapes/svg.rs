@@ -0,0 +1,420 @@
+use crate::aisc_shapes::geometry::{CrossSection, Point, SectionGeometry};
+use crate::aisc_shapes::{AiscShape, FlangedSection, SectionProperties};
+use std::fmt::Write;
+
+/// The size of the longest side of the cross-section in the drawing, px
+const DRAWING_SIZE: f64 = 320.0;
+/// The space around the cross-section left for dimensions, px
+const MARGIN: f64 = 110.0;
+/// The distance between the cross-section and its outermost dimension lines, px
+const DIMENSION_OFFSET: f64 = 40.0;
+/// The distance between the cross-section and dimension lines of thicknesses, px
+const DETAIL_OFFSET: f64 = 20.0;
+/// The radius of the centroid and shear center markers, px
+const MARKER_RADIUS: f64 = 5.0;
+
+/// Renders the cross-section of a shape as a standalone SVG document, with dimension
+/// lines for its depth, width, thicknesses and k-dimension, and markers for its centroid
+/// and shear center where the AISC shapes database locates them (`x_lower`, `y_lower`
+/// and `eo`). The drawing follows the orientation of [SectionGeometry], y pointing up.
+///
+/// ```
+/// use shapes::aisc_shapes::svg::section_svg;
+/// # use shapes::aisc_shapes::{AiscShape, Pipe, ShapeBuilder};
+/// # let pipe: AiscShape = ShapeBuilder::new()
+/// #     .with_edi_std_nomenclature(String::from("Pipe4STD"))
+/// #     .with_aisc_manual_label(String::from("Pipe4STD"))
+/// #     .with_w_upper(10.8).with_a_upper(2.96).with_od(4.5).with_id(4.03)
+/// #     .with_t_nom(0.237).with_tdes(0.221).with_d_t(20.4).with_ix(6.82)
+/// #     .with_zx(4.05).with_sx(3.03).with_rx(1.51).with_iy(6.82).with_zy(4.05)
+/// #     .with_sy(3.03).with_ry(1.51).with_j_upper(13.6)
+/// #     .try_build::<Pipe>().unwrap().into();
+/// let svg = section_svg(&pipe);
+/// assert!(svg.starts_with("<svg"));
+/// assert!(svg.contains("OD = 4.5 in."));
+/// ```
+pub fn section_svg(shape: &AiscShape) -> String {
+    let cross_section = shape.cross_section();
+    let mut drawing = Drawing::new(&cross_section);
+    drawing.title(shape.aisc_manual_label());
+    drawing.section(&cross_section);
+    let bounds = cross_section.bounding_box();
+    let (width, height) = (bounds.width(), bounds.height());
+    match shape {
+        AiscShape::WideFlange(s) => drawing.i_shape(s),
+        AiscShape::MiscBeam(s) => drawing.i_shape(s),
+        AiscShape::StructuralBeam(s) => drawing.i_shape(s),
+        AiscShape::HPile(s) => drawing.i_shape(s),
+        AiscShape::CeeChannel(s) => drawing.channel(s, s.x_lower, s.eo),
+        AiscShape::MiscChannel(s) => drawing.channel(s, s.x_lower, s.eo),
+        AiscShape::WideFlangeTee(s) => drawing.tee(s, s.y_lower),
+        AiscShape::MiscTee(s) => drawing.tee(s, s.y_lower),
+        AiscShape::StructuralTee(s) => drawing.tee(s, s.y_lower),
+        AiscShape::Angle(s) => {
+            drawing.horizontal(0.0, s.d_lower, 0.0, -DIMENSION_OFFSET, "d", s.d_lower);
+            drawing.vertical(0.0, s.b_lower, 0.0, -DIMENSION_OFFSET, "b", s.b_lower);
+            drawing.horizontal(0.0, s.t_lower, s.b_lower, DETAIL_OFFSET, "t", s.t_lower);
+            drawing.vertical(0.0, s.kdes, s.d_lower, DETAIL_OFFSET, "k", s.kdes);
+            drawing.centroid(Point::new(s.x_lower, s.y_lower));
+            // The shear center of an angle is where the centerlines of its legs meet
+            drawing.shear_center(Point::new(s.t_lower / 2.0, s.t_lower / 2.0));
+        }
+        AiscShape::DoubleAngle(s) => {
+            let axis = width / 2.0;
+            drawing.vertical(0.0, s.d_lower, 0.0, -DIMENSION_OFFSET, "d", s.d_lower);
+            drawing.horizontal(0.0, s.b_lower, 0.0, -DIMENSION_OFFSET, "b", s.b_lower);
+            drawing.horizontal(0.0, width, height, DIMENSION_OFFSET, "2b + s", width);
+            drawing.vertical(0.0, s.t_lower, width, DETAIL_OFFSET, "t", s.t_lower);
+            drawing.centroid(Point::new(axis, s.y_lower));
+            drawing.shear_center(Point::new(axis, s.t_lower / 2.0));
+        }
+        AiscShape::HollowStructuralSection(s) => {
+            drawing.vertical(0.0, s.ht, 0.0, -DIMENSION_OFFSET, "H", s.ht);
+            drawing.horizontal(0.0, s.b_upper, s.ht, DIMENSION_OFFSET, "B", s.b_upper);
+            let top = s.ht / 2.0;
+            drawing.horizontal(s.b_upper - s.tdes, s.b_upper, top, 0.0, "t", s.tdes);
+            drawing.centroid(Point::new(width / 2.0, height / 2.0));
+        }
+        AiscShape::RoundHollowStructuralSection(s) => drawing.round(s.od, s.tdes),
+        AiscShape::Pipe(s) => drawing.round(s.od, s.tdes),
+    }
+    drawing.finish()
+}
+
+/// An SVG document being drawn, mapping in. of the cross-section to px
+struct Drawing {
+    scale: f64,
+    height: f64,
+    body: String,
+    width_px: f64,
+    height_px: f64,
+}
+
+impl Drawing {
+    fn new(cross_section: &CrossSection) -> Self {
+        let bounds = cross_section.bounding_box();
+        let longest = bounds.width().max(bounds.height());
+        let scale = if longest > 0.0 {
+            DRAWING_SIZE / longest
+        } else {
+            1.0
+        };
+        Drawing {
+            scale,
+            height: bounds.height(),
+            body: String::new(),
+            width_px: bounds.width() * scale + 2.0 * MARGIN,
+            height_px: bounds.height() * scale + 2.0 * MARGIN,
+        }
+    }
+
+    /// The position of a point of the cross-section in the drawing, px
+    fn px(&self, point: Point) -> (f64, f64) {
+        (
+            MARGIN + point.x * self.scale,
+            MARGIN + (self.height - point.y) * self.scale,
+        )
+    }
+
+    fn title(&mut self, label: &str) {
+        let _ = writeln!(
+            self.body,
+            r#"<title>{}</title><text class="label" x="{}" y="24">{}</text>"#,
+            escape(label),
+            number(self.width_px / 2.0),
+            escape(label)
+        );
+    }
+
+    /// Draws the cross-section as a single path, holes are cut out by the even-odd rule
+    fn section(&mut self, cross_section: &CrossSection) {
+        let mut data = String::new();
+        for polygon in cross_section.polygons() {
+            for (i, point) in polygon.vertices.iter().enumerate() {
+                let (x, y) = self.px(*point);
+                let command = if i == 0 { 'M' } else { 'L' };
+                let _ = write!(data, "{command}{} {} ", number(x), number(y));
+            }
+            data.push_str("Z ");
+        }
+        let _ = writeln!(
+            self.body,
+            r#"<path class="section" fill-rule="evenodd" d="{}"/>"#,
+            data.trim_end()
+        );
+    }
+
+    /// Draws a dimension between `x1` and `x2` at the height `y` of the cross-section,
+    /// moved up by `offset` px (down when negative)
+    fn horizontal(&mut self, x1: f64, x2: f64, y: f64, offset: f64, name: &str, value: f64) {
+        let (left, base) = self.px(Point::new(x1, y));
+        let (right, _) = self.px(Point::new(x2, y));
+        let line = base - offset;
+        self.extension(left, base, left, line);
+        self.extension(right, base, right, line);
+        self.dimension_line(left, line, right, line);
+        let text_y = if offset < 0.0 {
+            line + 16.0
+        } else {
+            line - 6.0
+        };
+        self.text((left + right) / 2.0, text_y, "middle", name, value);
+    }
+
+    /// Draws a dimension between `y1` and `y2` at the horizontal position `x` of the
+    /// cross-section, moved right by `offset` px (left when negative)
+    fn vertical(&mut self, y1: f64, y2: f64, x: f64, offset: f64, name: &str, value: f64) {
+        let (base, bottom) = self.px(Point::new(x, y1));
+        let (_, top) = self.px(Point::new(x, y2));
+        let line = base + offset;
+        self.extension(base, bottom, line, bottom);
+        self.extension(base, top, line, top);
+        self.dimension_line(line, bottom, line, top);
+        let (text_x, anchor) = if offset < 0.0 {
+            (line - 6.0, "end")
+        } else {
+            (line + 6.0, "start")
+        };
+        self.text(text_x, (bottom + top) / 2.0 + 4.0, anchor, name, value);
+    }
+
+    fn extension(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
+        if (x1 - x2).abs() > f64::EPSILON || (y1 - y2).abs() > f64::EPSILON {
+            self.line("extension", x1, y1, x2, y2);
+        }
+    }
+
+    fn dimension_line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
+        let _ = writeln!(
+            self.body,
+            r#"<line class="dimension" x1="{}" y1="{}" x2="{}" y2="{}" marker-start="url(#tick)" marker-end="url(#tick)"/>"#,
+            number(x1),
+            number(y1),
+            number(x2),
+            number(y2)
+        );
+    }
+
+    fn line(&mut self, class: &str, x1: f64, y1: f64, x2: f64, y2: f64) {
+        let _ = writeln!(
+            self.body,
+            r#"<line class="{class}" x1="{}" y1="{}" x2="{}" y2="{}"/>"#,
+            number(x1),
+            number(y1),
+            number(x2),
+            number(y2)
+        );
+    }
+
+    fn text(&mut self, x: f64, y: f64, anchor: &str, name: &str, value: f64) {
+        let _ = writeln!(
+            self.body,
+            r#"<text class="dimension" x="{}" y="{}" text-anchor="{anchor}">{} = {} in.</text>"#,
+            number(x),
+            number(y),
+            escape(name),
+            number(value)
+        );
+    }
+
+    fn centroid(&mut self, point: Point) {
+        let (x, y) = self.px(point);
+        let _ = writeln!(
+            self.body,
+            r#"<g class="centroid"><circle cx="{x}" cy="{y}" r="{r}"/><line x1="{}" y1="{y}" x2="{}" y2="{y}"/><line x1="{x}" y1="{}" x2="{x}" y2="{}"/><text x="{}" y="{}">CG</text></g>"#,
+            number(x - 2.0 * MARKER_RADIUS),
+            number(x + 2.0 * MARKER_RADIUS),
+            number(y - 2.0 * MARKER_RADIUS),
+            number(y + 2.0 * MARKER_RADIUS),
+            number(x + 2.0 * MARKER_RADIUS),
+            number(y - 2.0 * MARKER_RADIUS),
+            x = number(x),
+            y = number(y),
+            r = number(MARKER_RADIUS),
+        );
+    }
+
+    fn shear_center(&mut self, point: Point) {
+        let (x, y) = self.px(point);
+        let _ = writeln!(
+            self.body,
+            r#"<g class="shear-center"><rect x="{}" y="{}" width="{size}" height="{size}"/><text x="{}" y="{}">SC</text></g>"#,
+            number(x - MARKER_RADIUS),
+            number(y - MARKER_RADIUS),
+            number(x + 2.0 * MARKER_RADIUS),
+            number(y + 4.0 * MARKER_RADIUS),
+            size = number(2.0 * MARKER_RADIUS),
+        );
+    }
+
+    /// The dimensions shared by shapes with flanges: d, bf, tf and k
+    fn flanges(&mut self, section: &dyn FlangedSection) {
+        let (d, bf) = (section.d_lower(), section.bf());
+        self.vertical(0.0, d, 0.0, -DIMENSION_OFFSET, "d", d);
+        self.horizontal(0.0, bf, d, DIMENSION_OFFSET, "bf", bf);
+        self.vertical(d - section.tf(), d, bf, DETAIL_OFFSET, "tf", section.tf());
+        let k = section.kdes();
+        self.vertical(d - k, d, bf, DIMENSION_OFFSET + DETAIL_OFFSET, "k", k);
+    }
+
+    /// Dimensions and markers of W, M, S and HP shapes
+    fn i_shape(&mut self, section: &dyn FlangedSection) {
+        self.flanges(section);
+        let (d, bf, tw) = (section.d_lower(), section.bf(), section.tw());
+        self.horizontal((bf - tw) / 2.0, (bf + tw) / 2.0, d / 2.0, 0.0, "tw", tw);
+        // Doubly symmetric, the shear center coincides with the centroid
+        self.centroid(Point::new(bf / 2.0, d / 2.0));
+    }
+
+    /// Dimensions and markers of C and MC shapes, `x` locates the centroid
+    /// and `eo` the shear center from the back of the web
+    fn channel(&mut self, section: &dyn FlangedSection, x: f64, eo: f64) {
+        self.flanges(section);
+        let (d, tw) = (section.d_lower(), section.tw());
+        self.horizontal(0.0, tw, d / 2.0, 0.0, "tw", tw);
+        self.centroid(Point::new(x, d / 2.0));
+        // The shear center lies outside the web, away from the flanges
+        self.shear_center(Point::new(-eo, d / 2.0));
+    }
+
+    /// Dimensions and markers of WT, MT and ST shapes,
+    /// `y` locates the centroid from the outside of the flange
+    fn tee(&mut self, section: &dyn FlangedSection, y: f64) {
+        self.flanges(section);
+        let (d, bf, tf, tw) = (section.d_lower(), section.bf(), section.tf(), section.tw());
+        self.horizontal(
+            (bf - tw) / 2.0,
+            (bf + tw) / 2.0,
+            0.0,
+            -DETAIL_OFFSET,
+            "tw",
+            tw,
+        );
+        self.centroid(Point::new(bf / 2.0, d - y));
+        // The shear center of a tee is where the centerlines of its flange and stem meet
+        self.shear_center(Point::new(bf / 2.0, d - tf / 2.0));
+    }
+
+    /// Dimensions and markers of round HSS and pipes
+    fn round(&mut self, od: f64, tdes: f64) {
+        self.horizontal(0.0, od, od, DIMENSION_OFFSET, "OD", od);
+        self.horizontal(od - tdes, od, od / 2.0, 0.0, "t", tdes);
+        self.centroid(Point::new(od / 2.0, od / 2.0));
+    }
+
+    fn finish(self) -> String {
+        format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#,
+                "\n<defs>",
+                r#"<marker id="tick" viewBox="-5 -5 10 10" markerWidth="10" markerHeight="10" orient="auto"><line x1="0" y1="-4" x2="0" y2="4" stroke="black"/></marker>"#,
+                "<style>",
+                ".section{{fill:#b0bec5;stroke:#263238;stroke-width:1}}",
+                ".dimension,.extension{{stroke:#37474f;stroke-width:0.75}}",
+                "text{{font-family:sans-serif;font-size:12px;fill:#263238;stroke:none}}",
+                ".label{{font-size:16px;text-anchor:middle}}",
+                ".centroid circle,.shear-center rect{{fill:none;stroke:#c62828;stroke-width:1.5}}",
+                ".centroid line{{stroke:#c62828}}",
+                "</style></defs>\n",
+                "{body}</svg>\n"
+            ),
+            width = number(self.width_px),
+            height = number(self.height_px),
+            body = self.body,
+        )
+    }
+}
+
+/// Formats a number with at most three decimals, without trailing zeros
+fn number(value: f64) -> String {
+    let formatted = format!("{value:.3}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" => String::from("0"),
+        _ => String::from(trimmed),
+    }
+}
+
+/// Escapes the characters of a designation that are markup in SVG
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::test_support::{flanged, shape};
+    use crate::aisc_shapes::{CeeChannel, DoubleAngle, ShapeProperty, WideFlange, WideFlangeTee};
+
+    #[test]
+    fn wide_flanges_are_dimensioned() {
+        let w14x90: WideFlange = shape("W14X90", &flanged(14.0, 14.5, 0.44, 0.71, 1.31));
+        let svg = section_svg(&w14x90.into());
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("<title>W14X90</title>"));
+        for dimension in [
+            "d = 14 in.",
+            "bf = 14.5 in.",
+            "tf = 0.71 in.",
+            "tw = 0.44 in.",
+            "k = 1.31 in.",
+        ] {
+            assert!(svg.contains(dimension), "missing {dimension}");
+        }
+        assert!(svg.contains("class=\"centroid\""));
+        assert!(!svg.contains("class=\"shear-center\""));
+        assert!(!svg.contains("NaN"));
+    }
+
+    #[test]
+    fn the_shear_center_of_channels_is_behind_the_web() {
+        let mut dimensions = flanged(15.0, 3.72, 0.716, 0.65, 1.44);
+        dimensions.push((ShapeProperty::XLower, 0.799));
+        dimensions.push((ShapeProperty::Eo, 0.583));
+        let c15x50: CeeChannel = shape("C15X50", &dimensions);
+        let svg = section_svg(&c15x50.into());
+        let x = svg
+            .split("<g class=\"shear-center\"><rect x=\"")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap()
+            .parse::<f64>()
+            .unwrap();
+        // The back of the web is at the margin of the drawing
+        assert!(x + 2.0 * MARKER_RADIUS < MARGIN);
+    }
+
+    #[test]
+    fn tees_and_double_angles_mark_their_centroid() {
+        let mut dimensions = flanged(7.01, 14.5, 0.44, 0.71, 1.31);
+        dimensions.push((ShapeProperty::YLower, 1.09));
+        let wt7x45: WideFlangeTee = shape("WT7X45", &dimensions);
+        let svg = section_svg(&wt7x45.into());
+        assert!(svg.contains("class=\"centroid\""));
+        assert!(svg.contains("class=\"shear-center\""));
+        let double_angle: DoubleAngle = shape(
+            "2L4X4X1/2X3/8",
+            &[
+                (ShapeProperty::DLower, 4.0),
+                (ShapeProperty::BLower, 4.0),
+                (ShapeProperty::TLower, 0.5),
+                (ShapeProperty::YLower, 1.18),
+            ],
+        );
+        let svg = section_svg(&double_angle.into());
+        assert!(svg.contains("2b + s = 8.375 in."));
+        // One closed outline per angle
+        assert_eq!(2, svg.matches('Z').count());
+    }
+
+    #[test]
+    fn numbers_are_written_without_trailing_zeros() {
+        assert_eq!("14", number(14.0));
+        assert_eq!("0.71", number(0.710));
+        assert_eq!("0.333", number(1.0 / 3.0));
+        assert_eq!("0", number(-0.0001));
+    }
+}
@@ -0,0 +1,74 @@
+//! Builders for the unit tests of modules working from the dimensions of shapes
+
+use crate::aisc_shapes::{ShapeBuilder, ShapeProperty, WideFlange};
+
+/// Builds a shape from the properties a test depends on,
+/// every other property is set to a placeholder of 1.0
+pub(crate) fn shape<T: TryFrom<ShapeBuilder>>(label: &str, properties: &[(ShapeProperty, f64)]) -> T
+where
+    T::Error: std::fmt::Debug,
+{
+    let mut builder = ShapeBuilder::new()
+        .with_edi_std_nomenclature(String::from(label))
+        .with_aisc_manual_label(String::from(label))
+        .with_t_f(false);
+    for property in ShapeProperty::ALL {
+        builder = builder.with_property(property, 1.0);
+    }
+    for (property, value) in properties {
+        builder = builder.with_property(*property, *value);
+    }
+    builder.try_build::<T>().unwrap()
+}
+
+/// The dimensions of a shape with flanges and a web
+pub(crate) fn flanged(d: f64, bf: f64, tw: f64, tf: f64, kdes: f64) -> Vec<(ShapeProperty, f64)> {
+    vec![
+        (ShapeProperty::DLower, d),
+        (ShapeProperty::Bf, bf),
+        (ShapeProperty::Tw, tw),
+        (ShapeProperty::Tf, tf),
+        (ShapeProperty::Kdes, kdes),
+    ]
+}
+
+/// Inches per foot, to write lengths in feet as the AISC Manual tabulates them
+pub(crate) const FT: f64 = 12.0;
+
+/// Asserts that the value is within the tolerance of the expected value,
+/// e.g. of a strength rounded in the AISC Manual
+pub(crate) fn assert_close(expected: f64, actual: f64, tolerance: f64) {
+    assert!(
+        (expected - actual).abs() <= tolerance,
+        "expected {expected}, got {actual}"
+    );
+}
+
+/// W14X90 with the properties of the AISC shapes database v16.0 the design modules depend on
+pub(crate) fn w14x90() -> WideFlange {
+    use ShapeProperty::*;
+    shape(
+        "W14X90",
+        &[
+            (AUpper, 26.5),
+            (DLower, 14.0),
+            (Bf, 14.5),
+            (Tf, 0.71),
+            (Tw, 0.44),
+            (Bf2tf, 10.2),
+            (HTw, 25.9),
+            (Ix, 999.0),
+            (Zx, 157.0),
+            (Sx, 143.0),
+            (Rx, 6.14),
+            (Zy, 75.6),
+            (Sy, 49.9),
+            (Iy, 362.0),
+            (Ry, 3.70),
+            (Rts, 4.10),
+            (Ho, 13.3),
+            (JUpper, 4.06),
+            (Cw, 16000.0),
+        ],
+    )
+}
@@ -0,0 +1,174 @@
+use crate::aisc_shapes::errors::UnknownUnitSystemError;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+/// The system of units shape properties are expressed in,
+/// the AISC shapes database tabulates both
+pub enum UnitSystem {
+    /// in., in.², in.³, in.⁴, in.⁶ and lb/ft, the units shapes are stored in
+    #[default]
+    UsCustomary,
+    /// mm, mm², 10³ mm³, 10⁶ mm⁴, 10⁹ mm⁶ and kg/m,
+    /// as tabulated in the metric sheet of the AISC shapes database
+    Metric,
+}
+
+impl UnitSystem {
+    /// The name of the unit system, as accepted by [UnitSystem::from_str]
+    pub fn name(&self) -> &'static str {
+        match self {
+            UnitSystem::UsCustomary => "us_customary",
+            UnitSystem::Metric => "metric",
+        }
+    }
+}
+
+impl fmt::Display for UnitSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for UnitSystem {
+    type Err = UnknownUnitSystemError;
+
+    /// Parses the name of a unit system, `us`, `us_customary` or `imperial`
+    /// for US customary units and `si` or `metric` for SI units (case-insensitive)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "us" | "us_customary" | "imperial" => Ok(UnitSystem::UsCustomary),
+            "si" | "metric" => Ok(UnitSystem::Metric),
+            _ => Err(UnknownUnitSystemError {
+                name: String::from(s),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The kind of quantity a shape property measures, which
+/// determines its units and how it is scaled between unit systems
+pub enum PropertyUnit {
+    /// A ratio or constant without units, e.g. bf/2tf or tan(α)
+    Dimensionless,
+    /// in. (mm)
+    Length,
+    /// in.² (mm²)
+    Area,
+    /// Section moduli and statical moments, in.³ (10³ mm³)
+    SectionModulus,
+    /// Moments of inertia and warping statical moments, in.⁴ (10⁶ mm⁴)
+    MomentOfInertia,
+    /// The torsional constant J, in.⁴ (10³ mm⁴)
+    TorsionalConstant,
+    /// The warping constant Cw, in.⁶ (10⁹ mm⁶)
+    WarpingConstant,
+    /// Nominal weight, lb/ft (kg/m)
+    LinearWeight,
+}
+
+impl PropertyUnit {
+    /// The factor a value in US customary units is multiplied by
+    /// to express it in the metric units of the AISC shapes database
+    pub fn metric_factor(&self) -> f64 {
+        const MM_PER_INCH: f64 = 25.4;
+        match self {
+            PropertyUnit::Dimensionless => 1.0,
+            PropertyUnit::Length => MM_PER_INCH,
+            PropertyUnit::Area => MM_PER_INCH.powi(2),
+            PropertyUnit::SectionModulus => MM_PER_INCH.powi(3) / 1e3,
+            PropertyUnit::MomentOfInertia => MM_PER_INCH.powi(4) / 1e6,
+            PropertyUnit::TorsionalConstant => MM_PER_INCH.powi(4) / 1e3,
+            PropertyUnit::WarpingConstant => MM_PER_INCH.powi(6) / 1e9,
+            // kg per lb / m per ft
+            PropertyUnit::LinearWeight => 0.45359237 / 0.3048,
+        }
+    }
+
+    /// Converts a value of the unit from one unit system to another
+    pub fn convert(&self, value: f64, from: UnitSystem, to: UnitSystem) -> f64 {
+        match (from, to) {
+            (UnitSystem::UsCustomary, UnitSystem::Metric) => value * self.metric_factor(),
+            (UnitSystem::Metric, UnitSystem::UsCustomary) => value / self.metric_factor(),
+            _ => value,
+        }
+    }
+
+    /// The symbol of the unit in the unit system, e.g. `in.⁴` or `10⁶ mm⁴`,
+    /// an empty string for dimensionless properties
+    pub fn symbol(&self, system: UnitSystem) -> &'static str {
+        match (self, system) {
+            (PropertyUnit::Dimensionless, _) => "",
+            (PropertyUnit::Length, UnitSystem::UsCustomary) => "in.",
+            (PropertyUnit::Length, UnitSystem::Metric) => "mm",
+            (PropertyUnit::Area, UnitSystem::UsCustomary) => "in.²",
+            (PropertyUnit::Area, UnitSystem::Metric) => "mm²",
+            (PropertyUnit::SectionModulus, UnitSystem::UsCustomary) => "in.³",
+            (PropertyUnit::SectionModulus, UnitSystem::Metric) => "10³ mm³",
+            (PropertyUnit::MomentOfInertia, UnitSystem::UsCustomary) => "in.⁴",
+            (PropertyUnit::MomentOfInertia, UnitSystem::Metric) => "10⁶ mm⁴",
+            (PropertyUnit::TorsionalConstant, UnitSystem::UsCustomary) => "in.⁴",
+            (PropertyUnit::TorsionalConstant, UnitSystem::Metric) => "10³ mm⁴",
+            (PropertyUnit::WarpingConstant, UnitSystem::UsCustomary) => "in.⁶",
+            (PropertyUnit::WarpingConstant, UnitSystem::Metric) => "10⁹ mm⁶",
+            (PropertyUnit::LinearWeight, UnitSystem::UsCustomary) => "lb/ft",
+            (PropertyUnit::LinearWeight, UnitSystem::Metric) => "kg/m",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::ShapeProperty;
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() <= expected.abs() * 0.005,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn w14x90_matches_the_metric_sheet() {
+        let metric = |property: ShapeProperty, value: f64| {
+            property
+                .unit()
+                .convert(value, UnitSystem::UsCustomary, UnitSystem::Metric)
+        };
+        // W14X90 is published as W360X134 in the metric sheet
+        assert_close(134.0, metric(ShapeProperty::WUpper, 90.0));
+        assert_close(17100.0, metric(ShapeProperty::AUpper, 26.5));
+        assert_close(356.0, metric(ShapeProperty::DLower, 14.0));
+        assert_close(416.0, metric(ShapeProperty::Ix, 999.0));
+        assert_close(2570.0, metric(ShapeProperty::Zx, 157.0));
+        assert_close(1690.0, metric(ShapeProperty::JUpper, 4.06));
+        assert_close(4300.0, metric(ShapeProperty::Cw, 16000.0));
+        assert_eq!(10.2, metric(ShapeProperty::Bf2tf, 10.2));
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        for property in ShapeProperty::ALL {
+            let unit = property.unit();
+            let metric = unit.convert(12.5, UnitSystem::UsCustomary, UnitSystem::Metric);
+            let us = unit.convert(metric, UnitSystem::Metric, UnitSystem::UsCustomary);
+            assert!((12.5 - us).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn unit_systems_are_parsed_from_their_names() {
+        assert_eq!(Ok(UnitSystem::Metric), "SI".parse());
+        assert_eq!(Ok(UnitSystem::Metric), "metric".parse());
+        assert_eq!(Ok(UnitSystem::UsCustomary), "us_customary".parse());
+        assert!("furlongs".parse::<UnitSystem>().is_err());
+        assert_eq!(UnitSystem::UsCustomary, UnitSystem::default());
+    }
+}
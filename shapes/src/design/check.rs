@@ -0,0 +1,391 @@
+use crate::aisc_shapes::Steel;
+use crate::design::{
+    Bending, BendingAxis, CompressionDesign, CompressiveLimitState, EffectiveLengths,
+    FlexuralDesign, FlexuralLimitState, InteractionEquation, NetSection, Shear, ShearDesign,
+    ShearLimitState, Strength, TensileLimitState, TensionDesign, interaction_ratio,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The design method the required strengths are compared with
+pub enum DesignMethod {
+    /// Load and Resistance Factor Design, with the design strengths φRn
+    #[default]
+    Lrfd,
+    /// Allowable Strength Design, with the allowable strengths Rn/Ω
+    Asd,
+}
+
+impl DesignMethod {
+    /// The available strength of the method: φRn for LRFD, Rn/Ω for ASD
+    pub fn available<L>(self, strength: &Strength<L>) -> f64 {
+        match self {
+            DesignMethod::Lrfd => strength.design,
+            DesignMethod::Asd => strength.allowable,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+/// The required strengths of a member from the LRFD or ASD load combinations,
+/// forces in kips and moments in kip-in.
+pub struct RequiredStrengths {
+    /// (Pr) Required axial strength, positive in tension and negative in compression, kips
+    pub pr: f64,
+    /// (Mrx) Required flexural strength about the major axis, kip-in.,
+    /// negative when it puts the stem of tees or the web legs of double angles in compression
+    pub mrx: f64,
+    /// (Mry) Required flexural strength about the minor axis, kip-in.
+    pub mry: f64,
+    /// (Vry) Required shear strength accompanying bending about the major axis, kips
+    pub vry: f64,
+    /// (Vrx) Required shear strength accompanying bending about the minor axis, kips
+    pub vrx: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The design parameters of a member, which the available strengths of its shape depend on
+pub struct MemberCheck {
+    /// The steel of the member
+    pub steel: Steel,
+    /// The design method of the required strengths
+    pub method: DesignMethod,
+    /// The effective lengths for compression
+    pub lengths: EffectiveLengths,
+    /// (Lb) Unbraced length for lateral-torsional buckling, in.
+    pub lb: f64,
+    /// (Cb) Lateral-torsional buckling modification factor
+    pub cb: f64,
+    /// The net section for tensile rupture
+    pub net_section: NetSection,
+    /// (Lv) Distance from maximum to zero shear force of round HSS and pipe, in.
+    pub lv: Option<f64>,
+}
+
+impl MemberCheck {
+    /// A member of the given steel braced along its length, connected through every element
+    pub fn new(steel: Steel, method: DesignMethod) -> Self {
+        MemberCheck {
+            steel,
+            method,
+            lengths: EffectiveLengths::uniform(0.0),
+            lb: 0.0,
+            cb: 1.0,
+            net_section: NetSection::gross(),
+            lv: None,
+        }
+    }
+
+    /// Sets the effective lengths for compression, in.
+    pub fn with_lengths(mut self, lengths: EffectiveLengths) -> Self {
+        self.lengths = lengths;
+        self
+    }
+
+    /// Sets the unbraced length for lateral-torsional buckling, in., and Cb
+    pub fn with_unbraced_length(mut self, lb: f64, cb: f64) -> Self {
+        self.lb = lb;
+        self.cb = cb;
+        self
+    }
+
+    /// Sets the net section for tensile rupture
+    pub fn with_net_section(mut self, net_section: NetSection) -> Self {
+        self.net_section = net_section;
+        self
+    }
+
+    /// Sets the distance from maximum to zero shear force of round HSS and pipe, in.
+    pub fn with_shear_span(mut self, lv: f64) -> Self {
+        self.lv = Some(lv);
+        self
+    }
+
+    /// Checks the shape for the required strengths: every limit state with a required strength,
+    /// and the interaction of axial force and flexure (H1) when both are present
+    /// or when the member is bent about both axes
+    pub fn check<S>(&self, shape: &S, forces: &RequiredStrengths) -> DesignCheck
+    where
+        S: TensionDesign + CompressionDesign + FlexuralDesign + ShearDesign,
+    {
+        let method = self.method;
+        let mut checks = Vec::new();
+        let mut ratio = |limit_state, required: f64, available: f64| {
+            let ratio = required.abs() / available;
+            checks.push(LimitStateCheck {
+                limit_state,
+                required: required.abs(),
+                available,
+                ratio,
+            });
+            ratio
+        };
+
+        let axial = if forces.pr > 0.0 {
+            let strength = shape
+                .tensile_strength(&self.steel, &self.net_section)
+                .strength;
+            ratio(
+                CheckedLimitState::Tension(strength.governing),
+                forces.pr,
+                method.available(&strength),
+            )
+        } else if forces.pr < 0.0 {
+            let strength = shape
+                .compressive_strength(&self.steel, &self.lengths)
+                .strength;
+            ratio(
+                CheckedLimitState::Compression(strength.governing),
+                forces.pr,
+                method.available(&strength),
+            )
+        } else {
+            0.0
+        };
+
+        let flexure = [
+            (BendingAxis::Major, forces.mrx),
+            (BendingAxis::Minor, forces.mry),
+        ]
+        .map(|(axis, mr)| {
+            if mr == 0.0 {
+                return 0.0;
+            }
+            let bending = match axis {
+                BendingAxis::Major if mr < 0.0 => {
+                    Bending::major(self.lb, self.cb).with_stem_in_compression()
+                }
+                BendingAxis::Major => Bending::major(self.lb, self.cb),
+                BendingAxis::Minor => Bending::minor(),
+            };
+            let strength = shape.flexural_strength(&self.steel, &bending).strength;
+            ratio(
+                CheckedLimitState::Flexure {
+                    axis,
+                    limit_state: strength.governing,
+                },
+                mr,
+                method.available(&strength),
+            )
+        });
+
+        for (axis, vr) in [
+            (BendingAxis::Major, forces.vry),
+            (BendingAxis::Minor, forces.vrx),
+        ] {
+            if vr == 0.0 {
+                continue;
+            }
+            let shear = Shear { axis, lv: self.lv };
+            let strength = shape.shear_strength(&self.steel, &shear).strength;
+            ratio(
+                CheckedLimitState::Shear {
+                    axis,
+                    limit_state: strength.governing,
+                },
+                vr,
+                method.available(&strength),
+            );
+        }
+
+        let actions = [axial, flexure[0], flexure[1]];
+        if actions.iter().filter(|ratio| **ratio != 0.0).count() >= 2 {
+            let (equation, interaction) = interaction_ratio(axial, flexure[0], flexure[1]);
+            ratio(CheckedLimitState::Interaction(equation), interaction, 1.0);
+        }
+
+        DesignCheck::new(checks)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// A limit state checked by a [MemberCheck]
+pub enum CheckedLimitState {
+    /// The governing limit state of the tensile strength (Chapter D)
+    Tension(TensileLimitState),
+    /// The governing limit state of the compressive strength (Chapter E)
+    Compression(CompressiveLimitState),
+    /// The governing limit state of the flexural strength about an axis (Chapter F)
+    Flexure {
+        /// The axis of bending
+        axis: BendingAxis,
+        /// The governing limit state
+        limit_state: FlexuralLimitState,
+    },
+    /// The governing limit state of the shear strength accompanying bending about an axis (Chapter G)
+    Shear {
+        /// The axis of the accompanying bending
+        axis: BendingAxis,
+        /// The governing limit state
+        limit_state: ShearLimitState,
+    },
+    /// The interaction of axial force and flexure (Chapter H)
+    Interaction(InteractionEquation),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A required strength compared with the available strength of a limit state
+pub struct LimitStateCheck {
+    /// The limit state checked
+    pub limit_state: CheckedLimitState,
+    /// The magnitude of the required strength, kips or kip-in., or the interaction value
+    pub required: f64,
+    /// The available strength, kips or kip-in., or 1.0 for the interaction equations
+    pub available: f64,
+    /// The utilization ratio of the required to the available strength
+    pub ratio: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The result of checking a member for a set of required strengths
+pub struct DesignCheck {
+    /// Every limit state checked
+    pub checks: Vec<LimitStateCheck>,
+    /// The limit state with the highest utilization ratio,
+    /// none when the member has no required strength
+    pub governing: Option<CheckedLimitState>,
+    /// The highest utilization ratio
+    pub utilization: f64,
+}
+
+impl DesignCheck {
+    fn new(checks: Vec<LimitStateCheck>) -> Self {
+        let governing =
+            checks
+                .iter()
+                .fold(None::<&LimitStateCheck>, |highest, check| match highest {
+                    Some(highest) if highest.ratio >= check.ratio => Some(highest),
+                    _ => Some(check),
+                });
+        DesignCheck {
+            governing: governing.map(|check| check.limit_state),
+            utilization: governing.map_or(0.0, |check| check.ratio),
+            checks,
+        }
+    }
+
+    /// Whether the available strengths are adequate, the utilization ratio not exceeding 1.0
+    pub fn passes(&self) -> bool {
+        self.utilization <= 1.0
+    }
+}
+
+#[cfg(all(test, feature = "snapshot"))]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::test_support::FT;
+    use crate::aisc_shapes::{AiscShape, SectionProperties};
+    use crate::design::BendingAxis::*;
+
+    fn snapshot_shape(label: &str) -> &'static AiscShape {
+        crate::aisc_shapes::aisc_database::snapshot()
+            .iter()
+            .find(|shape| shape.aisc_manual_label() == label)
+            .expect("the shape is in the snapshot")
+    }
+
+    fn beam_column() -> MemberCheck {
+        MemberCheck::new(Steel::A992, DesignMethod::Lrfd)
+            .with_lengths(EffectiveLengths::uniform(15.0 * FT))
+            .with_unbraced_length(15.0 * FT, 1.0)
+    }
+
+    #[test]
+    fn beam_columns_are_governed_by_the_interaction_equations() {
+        let forces = RequiredStrengths {
+            pr: -500.0,
+            mrx: 200.0 * FT,
+            vry: 50.0,
+            ..RequiredStrengths::default()
+        };
+        let check = beam_column().check(snapshot_shape("W14X90"), &forces);
+        assert_eq!(4, check.checks.len());
+        assert_eq!(
+            Some(CheckedLimitState::Interaction(
+                InteractionEquation::HighAxial
+            )),
+            check.governing
+        );
+        // φcPn = 1000 kips and φbMnx = 574 kip-ft (AISC Manual Tables 4-1a and 3-2)
+        let expected = 500.0 / 1000.0 + 8.0 / 9.0 * (200.0 / 574.0);
+        assert!((expected - check.utilization).abs() < 0.01);
+        assert!(check.passes());
+
+        let flexure = &check.checks[1];
+        assert_eq!(
+            CheckedLimitState::Flexure {
+                axis: Major,
+                limit_state: FlexuralLimitState::FlangeLocalBuckling
+            },
+            flexure.limit_state
+        );
+        assert_eq!(200.0 * FT, flexure.required);
+    }
+
+    #[test]
+    fn members_without_axial_force_are_checked_for_flexure_and_shear() {
+        let forces = RequiredStrengths {
+            mrx: 300.0 * FT,
+            vry: 200.0,
+            ..RequiredStrengths::default()
+        };
+        let check = beam_column().check(snapshot_shape("W14X90"), &forces);
+        assert_eq!(2, check.checks.len());
+        assert_eq!(
+            Some(CheckedLimitState::Shear {
+                axis: Major,
+                limit_state: ShearLimitState::ShearYielding
+            }),
+            check.governing
+        );
+        assert!(!check.passes());
+    }
+
+    #[test]
+    fn tension_members_use_the_net_section() {
+        let forces = RequiredStrengths {
+            pr: 60.0,
+            ..RequiredStrengths::default()
+        };
+        let member = MemberCheck::new(Steel::A36, DesignMethod::Asd)
+            .with_net_section(NetSection::new(3.31, 0.80));
+        let check = member.check(snapshot_shape("L4X4X1/2"), &forces);
+        assert_eq!(
+            Some(CheckedLimitState::Tension(
+                TensileLimitState::TensileRupture
+            )),
+            check.governing
+        );
+        assert!((60.0 / (58.0 * 3.31 * 0.80 / 2.0) - check.utilization).abs() < 1e-9);
+    }
+
+    #[test]
+    fn members_without_required_strengths_are_not_utilized() {
+        let check = beam_column().check(snapshot_shape("W14X90"), &RequiredStrengths::default());
+        assert!(check.checks.is_empty());
+        assert_eq!(None, check.governing);
+        assert_eq!(0.0, check.utilization);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn design_checks_serialize_for_review_tools() {
+        let forces = RequiredStrengths {
+            pr: -50.0,
+            mry: 20.0 * FT,
+            ..RequiredStrengths::default()
+        };
+        let check = beam_column().check(snapshot_shape("HSS8X8X1/2"), &forces);
+        let json = serde_json::to_value(&check).unwrap();
+        assert_eq!(3, json["checks"].as_array().unwrap().len());
+        assert_eq!("low_axial", json["governing"]["interaction"]);
+    }
+}
@@ -0,0 +1,943 @@
+use std::f64::consts::PI;
+
+use crate::aisc_shapes::{
+    AiscShape, Angle, CeeChannel, Classification, CompressionClass, DoubleAngle, Element, HPile,
+    HollowStructuralSection, MiscBeam, MiscChannel, MiscTee, Pipe, RoundHollowStructuralSection,
+    SlendernessClassification, Steel, StructuralBeam, StructuralTee, WideFlange, WideFlangeTee,
+};
+use crate::design::{ResistanceFactors, Strength, double_angle_torsional_constant};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The effective lengths (KL) of a compression member, in.
+///
+/// A length of zero braces the member against buckling about that axis,
+/// or against twisting for `lcz`.
+pub struct EffectiveLengths {
+    /// (Lcx = KxLx) Effective length for buckling about the x-axis,
+    /// or about the major principal (w) axis of single angles, in.
+    pub lcx: f64,
+    /// (Lcy = KyLy) Effective length for buckling about the y-axis,
+    /// or about the minor principal (z) axis of single angles, in.
+    pub lcy: f64,
+    /// (Lcz = KzLz) Effective length for buckling about the longitudinal axis, in.
+    pub lcz: f64,
+}
+
+impl EffectiveLengths {
+    /// The effective lengths about each axis, in.
+    pub fn new(lcx: f64, lcy: f64, lcz: f64) -> Self {
+        EffectiveLengths { lcx, lcy, lcz }
+    }
+
+    /// The same effective length about every axis, in.
+    pub fn uniform(lc: f64) -> Self {
+        EffectiveLengths::new(lc, lc, lc)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The limit states of AISC 360 Chapter E
+pub enum CompressiveLimitState {
+    /// Flexural buckling about the x-axis, or the w-axis of single angles (E3)
+    FlexuralBucklingX,
+    /// Flexural buckling about the y-axis, or the z-axis of single angles (E3)
+    FlexuralBucklingY,
+    /// Torsional buckling of doubly symmetric members (E4)
+    TorsionalBuckling,
+    /// Flexural-torsional buckling of singly symmetric and unsymmetric members (E4)
+    FlexuralTorsionalBuckling,
+    /// Buckling of single angles with the effective slenderness of E5
+    SingleAngleBuckling,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The compressive strength of a member, kips
+pub struct CompressiveStrength {
+    /// (Pn) The nominal compressive strength and the limit state governing it, kips
+    pub strength: Strength<CompressiveLimitState>,
+    /// (Fe) Elastic buckling stress of the governing limit state, ksi
+    pub fe: f64,
+    /// (Fcr) Critical stress of the governing limit state, ksi
+    pub fcr: f64,
+    /// (Ae) Effective area at the critical stress of the governing limit state (E7), in.²
+    pub ae: f64,
+}
+
+impl CompressiveStrength {
+    /// (Pn) Nominal compressive strength, kips
+    pub fn pn(&self) -> f64 {
+        self.strength.nominal
+    }
+
+    /// (φcPn) Design compressive strength for LRFD, kips
+    pub fn phi_pn(&self) -> f64 {
+        self.strength.design
+    }
+
+    /// (Pn/Ωc) Allowable compressive strength for ASD, kips
+    pub fn pn_omega(&self) -> f64 {
+        self.strength.allowable
+    }
+}
+
+/// Computes the nominal compressive strength of members per AISC 360 Chapter E
+/// from the tabulated properties of their shape:
+///
+/// - E3 flexural buckling about both axes, for every family
+/// - E4 torsional buckling of I-shapes, flexural-torsional buckling of channels, tees
+///   and double angles, and of single angles with b/t > 0.71√(E/Fy)
+/// - E7 effective area of members with slender elements
+///
+/// Double angles are designed as a single member, without the modified slenderness of E6.
+/// Torsional buckling of HSS, round HSS and pipe isn't considered.
+pub trait CompressionDesign {
+    /// The compressive strength of the member made of the given steel
+    fn compressive_strength(
+        &self,
+        steel: &Steel,
+        lengths: &EffectiveLengths,
+    ) -> CompressiveStrength;
+}
+
+/// (Fcr) Critical stress for an elastic buckling stress Fe (E3-2, E3-3), ksi
+fn critical_stress(steel: &Steel, fe: f64) -> f64 {
+    if steel.fy / fe <= 2.25 {
+        0.658_f64.powf(steel.fy / fe) * steel.fy
+    } else {
+        0.877 * fe
+    }
+}
+
+/// (Fe) Elastic flexural buckling stress (E3-4), ksi
+fn flexural_buckling_stress(steel: &Steel, lc: f64, r: f64) -> f64 {
+    PI * PI * steel.e / (lc / r).powi(2)
+}
+
+/// The buckling stress of a singly symmetric member (E4-3), from Fe about the axis of symmetry and Fez
+fn singly_symmetric_stress(fe_symmetric: f64, fez: f64, h: f64) -> f64 {
+    // The limits of E4-3 for members braced against flexural buckling or twisting
+    if !fe_symmetric.is_finite() {
+        return fez;
+    }
+    if !fez.is_finite() {
+        return fe_symmetric;
+    }
+    let sum = fe_symmetric + fez;
+    sum / (2.0 * h) * (1.0 - (1.0 - 4.0 * fe_symmetric * fez * h / sum.powi(2)).sqrt())
+}
+
+/// (Fez) Torsional buckling stress about the shear center, ksi
+fn torsional_stress(steel: &Steel, lcz: f64, cw: f64, j: f64, polar: f64) -> f64 {
+    (PI * PI * steel.e * cw / lcz.powi(2) + steel.g * j) / polar
+}
+
+/// The edge supports of an element, which set the adjustment factors of Table E7.1
+#[derive(Clone, Copy)]
+enum Support {
+    /// Stiffened elements except walls of square and rectangular HSS
+    Stiffened,
+    /// Walls of square and rectangular HSS
+    HssWall,
+    /// All other elements
+    Unstiffened,
+}
+
+impl Support {
+    /// (c1, c2)
+    fn adjustment_factors(self) -> (f64, f64) {
+        match self {
+            Support::Stiffened => (0.18, 1.31),
+            Support::HssWall => (0.20, 1.38),
+            Support::Unstiffened => (0.22, 1.49),
+        }
+    }
+}
+
+/// An element of the cross-section, repeated `count` times
+#[derive(Clone, Copy)]
+struct Plate {
+    element: Element,
+    count: f64,
+    t: f64,
+    support: Support,
+}
+
+impl Plate {
+    fn new(element: Element, count: f64, t: f64, support: Support) -> Self {
+        Plate {
+            element,
+            count,
+            t,
+            support,
+        }
+    }
+}
+
+/// How a member twists when buckling
+enum Torsion {
+    /// Torsional buckling isn't considered
+    None,
+    /// Doubly symmetric members (E4-2)
+    DoublySymmetric { cw: f64, j: f64, ix: f64, iy: f64 },
+    /// Members symmetric about the x-axis (channels) or the y-axis (tees and double angles) (E4-3)
+    SinglySymmetric {
+        about_x: bool,
+        cw: f64,
+        j: f64,
+        ro: f64,
+        h: f64,
+    },
+}
+
+/// The properties of a member used in E3, E4 and E7
+struct Column {
+    area: f64,
+    rx: f64,
+    ry: f64,
+    torsion: Torsion,
+    plates: Vec<Plate>,
+}
+
+/// The strength of each limit state, with its Fe, Fcr and Ae
+struct LimitStates {
+    limit_states: Vec<(CompressiveLimitState, f64, f64, f64)>,
+}
+
+impl LimitStates {
+    fn new() -> Self {
+        LimitStates {
+            limit_states: Vec::new(),
+        }
+    }
+
+    fn push(
+        &mut self,
+        limit_state: CompressiveLimitState,
+        fe: f64,
+        steel: &Steel,
+        ae: impl Fn(f64) -> f64,
+    ) {
+        let fcr = critical_stress(steel, fe);
+        self.limit_states.push((limit_state, fe, fcr, ae(fcr)));
+    }
+
+    fn strength(self) -> CompressiveStrength {
+        let strength = Strength::governed_by(
+            self.limit_states
+                .iter()
+                .map(|(limit_state, _, fcr, ae)| (*limit_state, fcr * ae))
+                .collect(),
+            ResistanceFactors::COMPRESSION,
+        );
+        let (_, fe, fcr, ae) = *self
+            .limit_states
+            .iter()
+            .find(|(limit_state, ..)| *limit_state == strength.governing)
+            .expect("the governing limit state was evaluated");
+        CompressiveStrength {
+            strength,
+            fe,
+            fcr,
+            ae,
+        }
+    }
+}
+
+/// (Ae) Effective area of the plates at the critical stress Fcr (E7.1), in.²
+fn effective_area(
+    area: f64,
+    plates: &[Plate],
+    classification: &Classification<CompressionClass>,
+    steel: &Steel,
+    fcr: f64,
+) -> f64 {
+    let removed: f64 = plates
+        .iter()
+        .map(|plate| {
+            let element = classification
+                .element(plate.element)
+                .expect("the element is classified for every shape of the family");
+            let (lambda, lambda_r) = (element.ratio, element.lambda_r);
+            if lambda <= lambda_r * (steel.fy / fcr).sqrt() {
+                return 0.0;
+            }
+            let (c1, c2) = plate.support.adjustment_factors();
+            let fel = (c2 * lambda_r / lambda).powi(2) * steel.fy;
+            let b = lambda * plate.t;
+            // Just beyond the limit the factors give be slightly greater than b
+            let be = (b * (1.0 - c1 * (fel / fcr).sqrt()) * (fel / fcr).sqrt()).min(b);
+            plate.count * (b - be) * plate.t
+        })
+        .sum();
+    area - removed
+}
+
+impl Column {
+    fn compressive_strength(
+        &self,
+        classification: &Classification<CompressionClass>,
+        steel: &Steel,
+        lengths: &EffectiveLengths,
+    ) -> CompressiveStrength {
+        let ae = |fcr| effective_area(self.area, &self.plates, classification, steel, fcr);
+        let fex = flexural_buckling_stress(steel, lengths.lcx, self.rx);
+        let fey = flexural_buckling_stress(steel, lengths.lcy, self.ry);
+        let mut limit_states = LimitStates::new();
+        limit_states.push(CompressiveLimitState::FlexuralBucklingX, fex, steel, ae);
+        limit_states.push(CompressiveLimitState::FlexuralBucklingY, fey, steel, ae);
+
+        if lengths.lcz > 0.0 {
+            match self.torsion {
+                Torsion::None => {}
+                Torsion::DoublySymmetric { cw, j, ix, iy } => limit_states.push(
+                    CompressiveLimitState::TorsionalBuckling,
+                    torsional_stress(steel, lengths.lcz, cw, j, ix + iy),
+                    steel,
+                    ae,
+                ),
+                Torsion::SinglySymmetric {
+                    about_x,
+                    cw,
+                    j,
+                    ro,
+                    h,
+                } => {
+                    let fez = torsional_stress(steel, lengths.lcz, cw, j, self.area * ro * ro);
+                    let fe_symmetric = if about_x { fex } else { fey };
+                    limit_states.push(
+                        CompressiveLimitState::FlexuralTorsionalBuckling,
+                        singly_symmetric_stress(fe_symmetric, fez, h),
+                        steel,
+                        ae,
+                    )
+                }
+            }
+        }
+        limit_states.strength()
+    }
+}
+
+/// Implements [CompressionDesign] for a family from the [Column] built by `$column`
+macro_rules! impl_compression {
+    ($shape:ident, $column:expr) => {
+        impl CompressionDesign for $shape {
+            fn compressive_strength(
+                &self,
+                steel: &Steel,
+                lengths: &EffectiveLengths,
+            ) -> CompressiveStrength {
+                let column: Column = ($column)(self);
+                column.compressive_strength(&self.compression_classification(steel), steel, lengths)
+            }
+        }
+    };
+}
+
+macro_rules! impl_i_shape_compression {
+    ($shape:ident) => {
+        impl_compression!($shape, |shape: &$shape| Column {
+            area: shape.a_upper,
+            rx: shape.rx,
+            ry: shape.ry,
+            torsion: Torsion::DoublySymmetric {
+                cw: shape.cw,
+                j: shape.j_upper,
+                ix: shape.ix,
+                iy: shape.iy,
+            },
+            plates: vec![
+                Plate::new(Element::Flange, 4.0, shape.tf, Support::Unstiffened),
+                Plate::new(Element::Web, 1.0, shape.tw, Support::Stiffened),
+            ],
+        });
+    };
+}
+
+macro_rules! impl_channel_compression {
+    ($shape:ident) => {
+        impl_compression!($shape, |shape: &$shape| Column {
+            area: shape.a_upper,
+            rx: shape.rx,
+            ry: shape.ry,
+            torsion: Torsion::SinglySymmetric {
+                about_x: true,
+                cw: shape.cw,
+                j: shape.j_upper,
+                ro: shape.ro,
+                h: shape.h_upper,
+            },
+            plates: vec![
+                Plate::new(Element::Flange, 2.0, shape.tf, Support::Unstiffened),
+                Plate::new(Element::Web, 1.0, shape.tw, Support::Stiffened),
+            ],
+        });
+    };
+}
+
+macro_rules! impl_tee_compression {
+    ($shape:ident) => {
+        impl_compression!($shape, |shape: &$shape| Column {
+            area: shape.a_upper,
+            rx: shape.rx,
+            ry: shape.ry,
+            torsion: Torsion::SinglySymmetric {
+                about_x: false,
+                cw: shape.cw,
+                j: shape.j_upper,
+                ro: shape.ro,
+                h: shape.h_upper,
+            },
+            plates: vec![
+                Plate::new(Element::Flange, 2.0, shape.tf, Support::Unstiffened),
+                Plate::new(Element::Stem, 1.0, shape.tw, Support::Unstiffened),
+            ],
+        });
+    };
+}
+
+impl_i_shape_compression!(WideFlange);
+impl_i_shape_compression!(MiscBeam);
+impl_i_shape_compression!(StructuralBeam);
+impl_i_shape_compression!(HPile);
+impl_channel_compression!(CeeChannel);
+impl_channel_compression!(MiscChannel);
+impl_tee_compression!(WideFlangeTee);
+impl_tee_compression!(MiscTee);
+impl_tee_compression!(StructuralTee);
+
+impl_compression!(DoubleAngle, |shape: &DoubleAngle| Column {
+    area: shape.a_upper,
+    rx: shape.rx,
+    ry: shape.ry,
+    torsion: Torsion::SinglySymmetric {
+        about_x: false,
+        cw: 0.0,
+        j: double_angle_torsional_constant(shape),
+        ro: shape.ro,
+        h: shape.h_upper,
+    },
+    plates: vec![
+        Plate::new(Element::LongLeg, 2.0, shape.t_lower, Support::Unstiffened),
+        Plate::new(Element::ShortLeg, 2.0, shape.t_lower, Support::Unstiffened),
+    ],
+});
+
+impl_compression!(
+    HollowStructuralSection,
+    |shape: &HollowStructuralSection| {
+        Column {
+            area: shape.a_upper,
+            rx: shape.rx,
+            ry: shape.ry,
+            torsion: Torsion::None,
+            plates: vec![
+                Plate::new(Element::Flange, 2.0, shape.tdes, Support::HssWall),
+                Plate::new(Element::Web, 2.0, shape.tdes, Support::HssWall),
+            ],
+        }
+    }
+);
+
+/// E3 and E7.2: flexural buckling of round HSS and pipe
+fn round_compressive_strength(
+    area: f64,
+    r: f64,
+    d_t: f64,
+    steel: &Steel,
+    lengths: &EffectiveLengths,
+) -> CompressiveStrength {
+    let ae = |_fcr| {
+        if d_t <= 0.11 * steel.e / steel.fy {
+            area
+        } else {
+            (0.038 * steel.e / (steel.fy * d_t) + 2.0 / 3.0) * area
+        }
+    };
+    let mut limit_states = LimitStates::new();
+    limit_states.push(
+        CompressiveLimitState::FlexuralBucklingX,
+        flexural_buckling_stress(steel, lengths.lcx, r),
+        steel,
+        ae,
+    );
+    limit_states.push(
+        CompressiveLimitState::FlexuralBucklingY,
+        flexural_buckling_stress(steel, lengths.lcy, r),
+        steel,
+        ae,
+    );
+    limit_states.strength()
+}
+
+macro_rules! impl_round_compression {
+    ($shape:ident) => {
+        impl CompressionDesign for $shape {
+            fn compressive_strength(
+                &self,
+                steel: &Steel,
+                lengths: &EffectiveLengths,
+            ) -> CompressiveStrength {
+                round_compressive_strength(self.a_upper, self.rx, self.d_t, steel, lengths)
+            }
+        }
+    };
+}
+
+impl_round_compression!(RoundHollowStructuralSection);
+impl_round_compression!(Pipe);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The leg of a single angle its end connections are welded or bolted to
+pub enum ConnectedLeg {
+    /// The longer leg, or either leg of equal leg angles
+    Long,
+    /// The shorter leg
+    Short,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The members the effective slenderness of single angles is given for (E5)
+pub enum AngleMember {
+    /// Individual members, or web members of planar trusses
+    /// with adjacent web members attached to the same side of the gusset plate or chord
+    Planar,
+    /// Web members of box or space trusses
+    /// with adjacent web members attached to the same side of the gusset plate or chord
+    BoxOrSpaceTruss,
+}
+
+impl Angle {
+    /// (rw) Radius of gyration about the major principal axis, in.
+    fn rw(&self) -> f64 {
+        (self.iw / self.a_upper).sqrt()
+    }
+
+    /// The coordinates of the shear center, at the intersection of the legs,
+    /// from the centroid along the principal axes (wo, zo), in.
+    fn shear_center(&self) -> (f64, f64) {
+        // The long leg along y and the short leg along x, with the heel at the origin,
+        // the w-axis is rotated by α from the x-axis towards the long leg
+        let alpha = self.tan_a.atan();
+        let (dx, dy) = (
+            self.t_lower / 2.0 - self.x_lower,
+            self.t_lower / 2.0 - self.y_lower,
+        );
+        (
+            dx * alpha.cos() + dy * alpha.sin(),
+            -dx * alpha.sin() + dy * alpha.cos(),
+        )
+    }
+
+    /// (Fe) Flexural-torsional buckling stress of unsymmetric members (E4-4),
+    /// the lowest root of the cubic equation found by bisection, ksi
+    fn flexural_torsional_stress(&self, steel: &Steel, few: f64, fez: f64, lcz: f64) -> f64 {
+        let ro2 = self.ro * self.ro;
+        let fet = torsional_stress(steel, lcz, self.cw, self.j_upper, self.a_upper * ro2);
+        let (wo, zo) = self.shear_center();
+        // The limits of E4-4 for members braced against flexural buckling about an axis
+        if !few.is_finite() {
+            return singly_symmetric_stress(fez, fet, 1.0 - zo * zo / ro2);
+        }
+        if !fez.is_finite() {
+            return singly_symmetric_stress(few, fet, 1.0 - wo * wo / ro2);
+        }
+        let cubic = |fe: f64| {
+            (fe - few) * (fe - fez) * (fe - fet)
+                - fe * fe * (fe - fez) * wo * wo / ro2
+                - fe * fe * (fe - few) * zo * zo / ro2
+        };
+        // The cubic is negative at zero and positive at the lowest of Few, Fez and Fet
+        let (mut low, mut high) = (0.0, few.min(fez).min(fet));
+        for _ in 0..100 {
+            let middle = (low + high) / 2.0;
+            if cubic(middle) < 0.0 {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        low
+    }
+
+    /// The compressive strength of a single angle loaded through one connected leg
+    /// per AISC 360 Section E5, with the length `l` between work points, in.
+    ///
+    /// Flexural buckling uses the effective slenderness of E5 in place of Lc/r,
+    /// flexural-torsional buckling isn't considered. Unequal leg angles connected through
+    /// the shorter leg should have a ratio of leg lengths below 1.7.
+    pub fn single_angle_compressive_strength(
+        &self,
+        steel: &Steel,
+        l: f64,
+        connected_leg: ConnectedLeg,
+        member: AngleMember,
+    ) -> CompressiveStrength {
+        let (long, short) = (
+            self.b_lower.max(self.d_lower),
+            self.b_lower.min(self.d_lower),
+        );
+        // The geometric axis parallel to the connected leg: the long leg is along y
+        let ra = match connected_leg {
+            ConnectedLeg::Long => self.ry,
+            ConnectedLeg::Short => self.rx,
+        };
+        let slenderness = l / ra;
+        let mut effective = match member {
+            AngleMember::Planar if slenderness <= 80.0 => 72.0 + 0.75 * slenderness,
+            AngleMember::Planar => (32.0 + 1.25 * slenderness).min(200.0),
+            AngleMember::BoxOrSpaceTruss if slenderness <= 75.0 => 60.0 + 0.8 * slenderness,
+            AngleMember::BoxOrSpaceTruss => (45.0 + slenderness).min(200.0),
+        };
+        if connected_leg == ConnectedLeg::Short && long > short {
+            effective = (effective + 4.0 * ((long / short).powi(2) - 1.0)).max(0.95 * l / self.rz);
+        }
+
+        let classification = self.compression_classification(steel);
+        let mut limit_states = LimitStates::new();
+        limit_states.push(
+            CompressiveLimitState::SingleAngleBuckling,
+            PI * PI * steel.e / effective.powi(2),
+            steel,
+            |fcr| effective_area(self.a_upper, &self.plates(), &classification, steel, fcr),
+        );
+        limit_states.strength()
+    }
+
+    fn plates(&self) -> [Plate; 2] {
+        [
+            Plate::new(Element::LongLeg, 1.0, self.t_lower, Support::Unstiffened),
+            Plate::new(Element::ShortLeg, 1.0, self.t_lower, Support::Unstiffened),
+        ]
+    }
+}
+
+impl CompressionDesign for Angle {
+    /// Flexural buckling about the principal axes, and flexural-torsional buckling
+    /// of angles with b/t > 0.71√(E/Fy), for angles loaded through their centroid.
+    /// See [Angle::single_angle_compressive_strength] for angles loaded through one leg.
+    fn compressive_strength(
+        &self,
+        steel: &Steel,
+        lengths: &EffectiveLengths,
+    ) -> CompressiveStrength {
+        let classification = self.compression_classification(steel);
+        let ae = |fcr| effective_area(self.a_upper, &self.plates(), &classification, steel, fcr);
+        let few = flexural_buckling_stress(steel, lengths.lcx, self.rw());
+        let fez = flexural_buckling_stress(steel, lengths.lcy, self.rz);
+        let mut limit_states = LimitStates::new();
+        limit_states.push(CompressiveLimitState::FlexuralBucklingX, few, steel, ae);
+        limit_states.push(CompressiveLimitState::FlexuralBucklingY, fez, steel, ae);
+        if lengths.lcz > 0.0 && self.b_t > 0.71 * steel.root_e_fy() {
+            limit_states.push(
+                CompressiveLimitState::FlexuralTorsionalBuckling,
+                self.flexural_torsional_stress(steel, few, fez, lengths.lcz),
+                steel,
+                ae,
+            );
+        }
+        limit_states.strength()
+    }
+}
+
+impl CompressionDesign for AiscShape {
+    fn compressive_strength(
+        &self,
+        steel: &Steel,
+        lengths: &EffectiveLengths,
+    ) -> CompressiveStrength {
+        match self {
+            AiscShape::WideFlange(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::MiscBeam(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::StructuralBeam(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::HPile(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::CeeChannel(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::MiscChannel(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::Angle(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::WideFlangeTee(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::MiscTee(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::StructuralTee(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::DoubleAngle(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::HollowStructuralSection(shape) => shape.compressive_strength(steel, lengths),
+            AiscShape::RoundHollowStructuralSection(shape) => {
+                shape.compressive_strength(steel, lengths)
+            }
+            AiscShape::Pipe(shape) => shape.compressive_strength(steel, lengths),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::ShapeProperty::*;
+    use crate::aisc_shapes::test_support::{FT, assert_close, shape, w14x90};
+
+    #[test]
+    fn w14x90_buckles_about_its_minor_axis() {
+        let strength =
+            w14x90().compressive_strength(&Steel::A992, &EffectiveLengths::uniform(15.0 * FT));
+        assert_eq!(
+            CompressiveLimitState::FlexuralBucklingY,
+            strength.strength.governing
+        );
+        // AISC Manual Table 4-1a: φcPn = 1000 kips, Pn/Ωc = 667 kips
+        assert_close(1000.0, strength.phi_pn(), 5.0);
+        assert_close(667.0, strength.pn_omega(), 3.0);
+        assert_eq!(26.5, strength.ae);
+        assert_eq!(3, strength.strength.limit_states.len());
+    }
+
+    #[test]
+    fn braced_members_reach_the_yield_stress() {
+        let strength = w14x90().compressive_strength(&Steel::A992, &EffectiveLengths::uniform(0.0));
+        assert_eq!(50.0, strength.fcr);
+        assert_eq!(50.0 * 26.5, strength.pn());
+        // Twisting is braced
+        assert_eq!(2, strength.strength.limit_states.len());
+    }
+
+    #[test]
+    fn elastic_buckling_beyond_the_transition_slenderness() {
+        let strength = w14x90()
+            .compressive_strength(&Steel::A992, &EffectiveLengths::new(0.0, 50.0 * FT, 0.0));
+        assert!(50.0 / strength.fe > 2.25);
+        assert_close(0.877 * strength.fe, strength.fcr, 1e-9);
+    }
+
+    #[test]
+    fn slender_webs_reduce_the_effective_area() {
+        let w21x48: WideFlange = shape(
+            "W21X48",
+            &[
+                (AUpper, 14.1),
+                (Tf, 0.43),
+                (Tw, 0.35),
+                (Bf2tf, 9.47),
+                (HTw, 53.6),
+                (Rx, 8.24),
+                (Ry, 1.66),
+            ],
+        );
+        let strength = w21x48.compressive_strength(&Steel::A992, &EffectiveLengths::uniform(0.0));
+        assert_close(12.38, strength.ae, 0.01);
+        assert_close(50.0 * strength.ae, strength.pn(), 1e-9);
+    }
+
+    #[test]
+    fn slender_hss_walls_reduce_the_effective_area() {
+        let hss8x4x1_8: HollowStructuralSection = shape(
+            "HSS8X4X1/8",
+            &[
+                (AUpper, 2.70),
+                (Tdes, 0.116),
+                (BTdes, 31.5),
+                (HTdes, 66.0),
+                (Rx, 2.92),
+                (Ry, 1.71),
+            ],
+        );
+        let strength = hss8x4x1_8.compressive_strength(
+            &Steel::A500_GR_C_RECTANGULAR,
+            &EffectiveLengths::uniform(10.0 * FT),
+        );
+        assert!(strength.ae < 2.70);
+        assert_eq!(
+            CompressiveLimitState::FlexuralBucklingY,
+            strength.strength.governing
+        );
+    }
+
+    #[test]
+    fn tees_buckle_in_flexural_torsional_modes() {
+        let wt7x45: WideFlangeTee = shape(
+            "WT7X45",
+            &[
+                (AUpper, 13.2),
+                (Tf, 0.71),
+                (Tw, 0.44),
+                (Bf2tf, 10.2),
+                (DT, 15.9),
+                (Rx, 1.66),
+                (Ry, 3.70),
+                (JUpper, 2.03),
+                (Cw, 8.31),
+                (Ro, 4.12),
+                (HUpper, 0.968),
+            ],
+        );
+        let strength = wt7x45.compressive_strength(
+            &Steel::A992,
+            &EffectiveLengths::new(5.0 * FT, 20.0 * FT, 20.0 * FT),
+        );
+        assert_eq!(
+            CompressiveLimitState::FlexuralTorsionalBuckling,
+            strength.strength.governing
+        );
+        assert_close(64.5, strength.fe, 0.2);
+    }
+
+    #[test]
+    fn double_angles_use_a_computed_torsional_constant() {
+        let double_angle: DoubleAngle = shape(
+            "2L4X4X1/2",
+            &[
+                (AUpper, 7.5),
+                (DLower, 4.0),
+                (BLower, 4.0),
+                (TLower, 0.5),
+                (BT, 8.0),
+                (Rx, 1.21),
+                (Ry, 1.69),
+                (Ro, 2.28),
+                (HUpper, 0.834),
+            ],
+        );
+        let braced_x = double_angle.compressive_strength(
+            &Steel::A36,
+            &EffectiveLengths::new(0.0, 10.0 * FT, 10.0 * FT),
+        );
+        assert_eq!(
+            CompressiveLimitState::FlexuralTorsionalBuckling,
+            braced_x.strength.governing
+        );
+        assert!(braced_x.fe < PI * PI * 29000.0 / (120.0_f64 / 1.69).powi(2));
+    }
+
+    fn l6x6x5_16() -> Angle {
+        shape(
+            "L6X6X5/16",
+            &[
+                (AUpper, 3.67),
+                (DLower, 6.0),
+                (BLower, 6.0),
+                (TLower, 0.3125),
+                (XLower, 1.60),
+                (YLower, 1.60),
+                (BT, 19.2),
+                (Rx, 1.88),
+                (Ry, 1.88),
+                (Rz, 1.19),
+                (Iw, 20.8),
+                (TanA, 1.0),
+                (JUpper, 0.129),
+                (Cw, 0.338),
+                (Ro, 3.35),
+            ],
+        )
+    }
+
+    #[test]
+    fn the_shear_center_of_equal_leg_angles_is_on_the_w_axis() {
+        let (wo, zo) = l6x6x5_16().shear_center();
+        assert_close(0.0, zo, 1e-9);
+        assert_close(-2.04, wo, 0.01);
+    }
+
+    #[test]
+    fn slender_angles_buckle_in_flexural_torsional_modes() {
+        let angle = l6x6x5_16();
+        let strength = angle.compressive_strength(
+            &Steel::new(50.0, 65.0),
+            &EffectiveLengths::uniform(4.0 * FT),
+        );
+        let ftb = strength
+            .strength
+            .limit_states
+            .iter()
+            .find(|(limit_state, _)| {
+                *limit_state == CompressiveLimitState::FlexuralTorsionalBuckling
+            })
+            .expect("b/t exceeds 0.71√(E/Fy)");
+        // Equal leg angles are singly symmetric about the w-axis
+        let few = PI * PI * 29000.0 / (48.0 / (20.8_f64 / 3.67).sqrt()).powi(2);
+        let fet = 11200.0 * 0.129 / (3.67 * 3.35 * 3.35)
+            + PI * PI * 29000.0 * 0.338 / (48.0 * 48.0 * 3.67 * 3.35 * 3.35);
+        let h = 1.0 - (2.0419_f64 / 3.35).powi(2);
+        let fe = singly_symmetric_stress(few, fet, h);
+        let fcr = critical_stress(&Steel::new(50.0, 65.0), fe);
+        assert_close(fcr * strength.ae, ftb.1, 0.5);
+
+        let stocky = angle.compressive_strength(&Steel::A36, &EffectiveLengths::uniform(4.0 * FT));
+        assert!(
+            stocky
+                .strength
+                .limit_states
+                .iter()
+                .all(|(limit_state, _)| *limit_state
+                    != CompressiveLimitState::FlexuralTorsionalBuckling),
+            "b/t is within 0.71√(E/Fy) at Fy = 36 ksi"
+        );
+    }
+
+    #[test]
+    fn single_angles_connected_by_one_leg_use_an_effective_slenderness() {
+        let l4x4x1_4: Angle = shape(
+            "L4X4X1/4",
+            &[
+                (AUpper, 1.93),
+                (DLower, 4.0),
+                (BLower, 4.0),
+                (TLower, 0.25),
+                (BT, 16.0),
+                (Rx, 1.25),
+                (Ry, 1.25),
+                (Rz, 0.783),
+            ],
+        );
+        let strength = l4x4x1_4.single_angle_compressive_strength(
+            &Steel::A36,
+            6.0 * FT,
+            ConnectedLeg::Long,
+            AngleMember::Planar,
+        );
+        assert_eq!(
+            CompressiveLimitState::SingleAngleBuckling,
+            strength.strength.governing
+        );
+        // Lc/r = 72 + 0.75 L/ra = 115.2
+        assert_close(PI * PI * 29000.0 / 115.2_f64.powi(2), strength.fe, 1e-9);
+        assert_close(31.1, strength.phi_pn(), 0.1);
+    }
+
+    #[test]
+    fn round_hss_walls_reduce_the_effective_area_beyond_the_limit() {
+        let pipe: Pipe = shape("Pipe", &[(AUpper, 10.0), (Rx, 4.0), (DT, 100.0)]);
+        let strength = pipe.compressive_strength(&Steel::A53_GR_B, &EffectiveLengths::uniform(0.0));
+        let expected = (0.038 * 29000.0 / (35.0 * 100.0) + 2.0 / 3.0) * 10.0;
+        assert_close(expected, strength.ae, 1e-9);
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn every_shape_has_a_compressive_strength() {
+        use crate::aisc_shapes::SectionProperties;
+
+        let steel = Steel::new(50.0, 65.0);
+        for lengths in [
+            EffectiveLengths::uniform(0.0),
+            EffectiveLengths::uniform(10.0 * FT),
+            EffectiveLengths::new(0.0, 10.0 * FT, 10.0 * FT),
+            EffectiveLengths::new(10.0 * FT, 0.0, 10.0 * FT),
+        ] {
+            for shape in crate::aisc_shapes::aisc_database::snapshot() {
+                let strength = shape.compressive_strength(&steel, &lengths);
+                assert!(
+                    strength.pn().is_finite()
+                        && strength.pn() > 0.0
+                        && strength.pn() <= steel.fy * shape.a_upper() + 1e-9,
+                    "{} has Pn = {} for {lengths:?}",
+                    shape.aisc_manual_label(),
+                    strength.pn()
+                );
+            }
+        }
+    }
+}
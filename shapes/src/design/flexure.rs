@@ -0,0 +1,1262 @@
+use std::f64::consts::PI;
+
+use crate::aisc_shapes::{
+    AiscShape, Angle, CeeChannel, Classification, DoubleAngle, Element, ElementClassification,
+    FlexureClass, HPile, HollowStructuralSection, MiscBeam, MiscChannel, MiscTee, Pipe,
+    RoundHollowStructuralSection, SlendernessClassification, Steel, StructuralBeam, StructuralTee,
+    WideFlange, WideFlangeTee,
+};
+use crate::design::{ResistanceFactors, Strength, double_angle_torsional_constant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The axis a shape is bent about
+pub enum BendingAxis {
+    /// The x-axis, or the major principal (w) axis of single angles
+    Major,
+    /// The y-axis, or the minor principal (z) axis of single angles
+    Minor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The bending a shape is designed for
+pub struct Bending {
+    /// The axis of bending
+    pub axis: BendingAxis,
+    /// (Lb) Length between points braced against lateral displacement of the compression flange
+    /// or twist of the cross-section, in.
+    pub lb: f64,
+    /// (Cb) Lateral-torsional buckling modification factor
+    pub cb: f64,
+    /// Whether the stem of a tee, or the web legs of double angles, are in compression
+    /// when bent about the x-axis
+    pub stem_in_compression: bool,
+}
+
+impl Bending {
+    /// Bending about the major axis with the given unbraced length, in., and Cb
+    pub fn major(lb: f64, cb: f64) -> Self {
+        Bending {
+            axis: BendingAxis::Major,
+            lb,
+            cb,
+            stem_in_compression: false,
+        }
+    }
+
+    /// Bending about the minor axis, which isn't subject to lateral-torsional buckling
+    pub fn minor() -> Self {
+        Bending {
+            axis: BendingAxis::Minor,
+            lb: 0.0,
+            cb: 1.0,
+            stem_in_compression: false,
+        }
+    }
+
+    /// Puts the stem of a tee, or the web legs of double angles, in compression
+    pub fn with_stem_in_compression(mut self) -> Self {
+        self.stem_in_compression = true;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The limit states of AISC 360 Chapter F
+pub enum FlexuralLimitState {
+    /// Yielding (plastic moment)
+    Yielding,
+    /// Compression flange yielding of I-shapes with noncompact or slender webs
+    CompressionFlangeYielding,
+    /// Lateral-torsional buckling
+    LateralTorsionalBuckling,
+    /// Compression flange local buckling, including the walls of HSS parallel to the axis
+    FlangeLocalBuckling,
+    /// Web local buckling of HSS
+    WebLocalBuckling,
+    /// Local buckling of tee stems in flexural compression
+    StemLocalBuckling,
+    /// Local buckling of the legs of single and double angles
+    LegLocalBuckling,
+    /// Local buckling of round HSS and pipe
+    LocalBuckling,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The flexural strength of a shape, kip-in.
+pub struct FlexuralStrength {
+    /// (Mn) The nominal flexural strength and the limit state governing it, kip-in.
+    pub strength: Strength<FlexuralLimitState>,
+    /// (Lp) The limiting laterally unbraced length for the limit state of yielding, in.,
+    /// for shapes subject to lateral-torsional buckling
+    pub lp: Option<f64>,
+    /// (Lr) The limiting laterally unbraced length for the limit state
+    /// of inelastic lateral-torsional buckling, in.
+    pub lr: Option<f64>,
+}
+
+impl FlexuralStrength {
+    /// (Mn) Nominal flexural strength, kip-in.
+    pub fn mn(&self) -> f64 {
+        self.strength.nominal
+    }
+
+    /// (φbMn) Design flexural strength for LRFD, kip-in.
+    pub fn phi_mn(&self) -> f64 {
+        self.strength.design
+    }
+
+    /// (Mn/Ωb) Allowable flexural strength for ASD, kip-in.
+    pub fn mn_omega(&self) -> f64 {
+        self.strength.allowable
+    }
+}
+
+/// Computes the nominal flexural strength of shapes per AISC 360 Chapter F
+/// from their tabulated properties:
+///
+/// - F2 to F5 for I-shapes and channels bent about their major axis,
+///   depending on the classification of the web and flanges
+/// - F6 for I-shapes, channels and tees bent about their minor axis
+/// - F7 for square and rectangular HSS and F8 for round HSS and pipe
+/// - F9 for tees and double angles bent about the x-axis, and leg local buckling
+///   of double angles bent about the y-axis
+/// - F10 for single angles bent about their principal axes, the long leg taken in compression
+///   for lateral-torsional buckling and the toes in compression for leg local buckling
+///
+/// Bending of single angles about their geometric axes should be resolved into principal axis components.
+pub trait FlexuralDesign {
+    /// The flexural strength of the shape made of the given steel
+    fn flexural_strength(&self, steel: &Steel, bending: &Bending) -> FlexuralStrength;
+}
+
+/// Collects the limit states that apply to a shape
+struct LimitStates {
+    limit_states: Vec<(FlexuralLimitState, f64)>,
+    lp: Option<f64>,
+    lr: Option<f64>,
+}
+
+impl LimitStates {
+    fn new(yielding: FlexuralLimitState, mn: f64) -> Self {
+        LimitStates {
+            limit_states: vec![(yielding, mn)],
+            lp: None,
+            lr: None,
+        }
+    }
+
+    fn push(&mut self, limit_state: FlexuralLimitState, mn: f64) {
+        self.limit_states.push((limit_state, mn));
+    }
+
+    fn unbraced_lengths(&mut self, lp: f64, lr: f64) {
+        self.lp = Some(lp);
+        self.lr = Some(lr);
+    }
+
+    fn strength(self) -> FlexuralStrength {
+        FlexuralStrength {
+            strength: Strength::governed_by(self.limit_states, ResistanceFactors::FLEXURE),
+            lp: self.lp,
+            lr: self.lr,
+        }
+    }
+}
+
+/// Interpolates linearly from `high` at λp to `low` at λr
+fn interpolate(high: f64, low: f64, element: &ElementClassification<FlexureClass>) -> f64 {
+    let lambda_p = element
+        .lambda_p
+        .expect("flexural classifications have a λp");
+    high - (high - low) * (element.ratio - lambda_p) / (element.lambda_r - lambda_p)
+}
+
+fn element(
+    classification: &Classification<FlexureClass>,
+    element: Element,
+) -> &ElementClassification<FlexureClass> {
+    classification
+        .element(element)
+        .expect("the element is classified for every shape of the family")
+}
+
+/// (kc) Local buckling coefficient of slender flanges
+fn kc(h_tw: f64) -> f64 {
+    (4.0 / h_tw.sqrt()).clamp(0.35, 0.76)
+}
+
+/// The properties of I-shapes and channels used in F2 to F6
+struct IShape {
+    d: f64,
+    bf: f64,
+    tf: f64,
+    tw: f64,
+    h_tw: f64,
+    zx: f64,
+    sx: f64,
+    zy: f64,
+    sy: f64,
+    ry: f64,
+    rts: f64,
+    ho: f64,
+    j: f64,
+    /// (c) 1.0 for I-shapes, (ho/2)√(Iy/Cw) for channels
+    c: f64,
+}
+
+impl IShape {
+    /// (rt) Radius of gyration of the compression flange plus one-sixth of the web
+    fn rt(&self) -> f64 {
+        let h = self.h_tw * self.tw;
+        let aw = h * self.tw / (self.bf * self.tf);
+        self.bf / (12.0 * (self.ho / self.d + aw * h * h / (6.0 * self.ho * self.d))).sqrt()
+    }
+
+    fn flexural_strength(
+        &self,
+        classification: &Classification<FlexureClass>,
+        steel: &Steel,
+        bending: &Bending,
+    ) -> FlexuralStrength {
+        let flange = element(classification, Element::Flange);
+        match bending.axis {
+            BendingAxis::Minor => self.minor_axis(flange, steel),
+            BendingAxis::Major => match element(classification, Element::Web).class {
+                FlexureClass::Compact => self.compact_web(flange, steel, bending),
+                FlexureClass::Noncompact => self.noncompact_web(
+                    flange,
+                    element(classification, Element::Web),
+                    steel,
+                    bending,
+                ),
+                FlexureClass::Slender => self.slender_web(flange, steel, bending),
+            },
+        }
+    }
+
+    /// F2 and F3: compact webs
+    fn compact_web(
+        &self,
+        flange: &ElementClassification<FlexureClass>,
+        steel: &Steel,
+        bending: &Bending,
+    ) -> FlexuralStrength {
+        let (fy, e) = (steel.fy, steel.e);
+        let mp = fy * self.zx;
+        let mut limit_states = LimitStates::new(FlexuralLimitState::Yielding, mp);
+
+        let lp = 1.76 * self.ry * steel.root_e_fy();
+        let jc = self.j * self.c / (self.sx * self.ho);
+        let lr = 1.95 * self.rts * e / (0.7 * fy)
+            * (jc + (jc * jc + 6.76 * (0.7 * fy / e).powi(2)).sqrt()).sqrt();
+        limit_states.unbraced_lengths(lp, lr);
+        let lb = bending.lb;
+        if lb > lp {
+            let mn = if lb <= lr {
+                bending.cb * (mp - (mp - 0.7 * fy * self.sx) * (lb - lp) / (lr - lp))
+            } else {
+                let slenderness = lb / self.rts;
+                let fcr = bending.cb * PI * PI * e / slenderness.powi(2)
+                    * (1.0 + 0.078 * jc * slenderness.powi(2)).sqrt();
+                fcr * self.sx
+            };
+            limit_states.push(FlexuralLimitState::LateralTorsionalBuckling, mn.min(mp));
+        }
+
+        match flange.class {
+            FlexureClass::Compact => {}
+            FlexureClass::Noncompact => limit_states.push(
+                FlexuralLimitState::FlangeLocalBuckling,
+                interpolate(mp, 0.7 * fy * self.sx, flange),
+            ),
+            FlexureClass::Slender => limit_states.push(
+                FlexuralLimitState::FlangeLocalBuckling,
+                0.9 * e * kc(self.h_tw) * self.sx / flange.ratio.powi(2),
+            ),
+        }
+        limit_states.strength()
+    }
+
+    /// F4: noncompact webs, with the compression and tension flanges alike
+    fn noncompact_web(
+        &self,
+        flange: &ElementClassification<FlexureClass>,
+        web: &ElementClassification<FlexureClass>,
+        steel: &Steel,
+        bending: &Bending,
+    ) -> FlexuralStrength {
+        let (fy, e) = (steel.fy, steel.e);
+        let myc = fy * self.sx;
+        let mp = (fy * self.zx).min(1.6 * myc);
+        let rpc = interpolate(mp / myc, 1.0, web).min(mp / myc);
+        let mut limit_states =
+            LimitStates::new(FlexuralLimitState::CompressionFlangeYielding, rpc * myc);
+
+        let rt = self.rt();
+        let fl = 0.7 * fy;
+        let lp = 1.1 * rt * steel.root_e_fy();
+        let jc = self.j / (self.sx * self.ho);
+        let lr = 1.95 * rt * e / fl * (jc + (jc * jc + 6.76 * (fl / e).powi(2)).sqrt()).sqrt();
+        limit_states.unbraced_lengths(lp, lr);
+        let lb = bending.lb;
+        if lb > lp {
+            let mn = if lb <= lr {
+                bending.cb * (rpc * myc - (rpc * myc - fl * self.sx) * (lb - lp) / (lr - lp))
+            } else {
+                let slenderness = lb / rt;
+                let fcr = bending.cb * PI * PI * e / slenderness.powi(2)
+                    * (1.0 + 0.078 * jc * slenderness.powi(2)).sqrt();
+                fcr * self.sx
+            };
+            limit_states.push(
+                FlexuralLimitState::LateralTorsionalBuckling,
+                mn.min(rpc * myc),
+            );
+        }
+
+        match flange.class {
+            FlexureClass::Compact => {}
+            FlexureClass::Noncompact => limit_states.push(
+                FlexuralLimitState::FlangeLocalBuckling,
+                interpolate(rpc * myc, fl * self.sx, flange),
+            ),
+            FlexureClass::Slender => limit_states.push(
+                FlexuralLimitState::FlangeLocalBuckling,
+                0.9 * e * kc(self.h_tw) * self.sx / flange.ratio.powi(2),
+            ),
+        }
+        limit_states.strength()
+    }
+
+    /// F5: slender webs
+    fn slender_web(
+        &self,
+        flange: &ElementClassification<FlexureClass>,
+        steel: &Steel,
+        bending: &Bending,
+    ) -> FlexuralStrength {
+        let (fy, e) = (steel.fy, steel.e);
+        let h = self.h_tw * self.tw;
+        let aw = (h * self.tw / (self.bf * self.tf)).min(10.0);
+        let rpg =
+            (1.0 - aw / (1200.0 + 300.0 * aw) * (self.h_tw - 5.7 * steel.root_e_fy())).min(1.0);
+        let mut limit_states = LimitStates::new(
+            FlexuralLimitState::CompressionFlangeYielding,
+            rpg * fy * self.sx,
+        );
+
+        let rt = self.rt();
+        let lp = 1.1 * rt * steel.root_e_fy();
+        let lr = PI * rt * (e / (0.7 * fy)).sqrt();
+        limit_states.unbraced_lengths(lp, lr);
+        let lb = bending.lb;
+        if lb > lp {
+            let fcr = if lb <= lr {
+                bending.cb * (fy - 0.3 * fy * (lb - lp) / (lr - lp))
+            } else {
+                bending.cb * PI * PI * e / (lb / rt).powi(2)
+            };
+            limit_states.push(
+                FlexuralLimitState::LateralTorsionalBuckling,
+                rpg * fcr.min(fy) * self.sx,
+            );
+        }
+
+        let fcr = match flange.class {
+            FlexureClass::Compact => None,
+            FlexureClass::Noncompact => Some(interpolate(fy, 0.7 * fy, flange)),
+            FlexureClass::Slender => Some(0.9 * e * kc(self.h_tw) / flange.ratio.powi(2)),
+        };
+        if let Some(fcr) = fcr {
+            limit_states.push(FlexuralLimitState::FlangeLocalBuckling, rpg * fcr * self.sx);
+        }
+        limit_states.strength()
+    }
+
+    /// F6: bending about the minor axis
+    fn minor_axis(
+        &self,
+        flange: &ElementClassification<FlexureClass>,
+        steel: &Steel,
+    ) -> FlexuralStrength {
+        minor_axis_flanges(self.zy, self.sy, flange, steel)
+    }
+}
+
+/// F6 (and tees bent about their y-axis): yielding and flange local buckling
+fn minor_axis_flanges(
+    zy: f64,
+    sy: f64,
+    flange: &ElementClassification<FlexureClass>,
+    steel: &Steel,
+) -> FlexuralStrength {
+    let fy = steel.fy;
+    let mp = (fy * zy).min(1.6 * fy * sy);
+    let mut limit_states = LimitStates::new(FlexuralLimitState::Yielding, mp);
+    match flange.class {
+        FlexureClass::Compact => {}
+        FlexureClass::Noncompact => limit_states.push(
+            FlexuralLimitState::FlangeLocalBuckling,
+            interpolate(mp, 0.7 * fy * sy, flange),
+        ),
+        FlexureClass::Slender => limit_states.push(
+            FlexuralLimitState::FlangeLocalBuckling,
+            0.69 * steel.e / flange.ratio.powi(2) * sy,
+        ),
+    }
+    limit_states.strength()
+}
+
+/// Implements [FlexuralDesign] for I-shapes and channels
+macro_rules! impl_i_shape_flexure {
+    ($shape:ident) => {
+        impl_i_shape_flexure!($shape, |_shape: &$shape| 1.0);
+    };
+    ($shape:ident, channel) => {
+        impl_i_shape_flexure!($shape, |shape: &$shape| shape.ho / 2.0
+            * (shape.iy / shape.cw).sqrt());
+    };
+    ($shape:ident, $c:expr) => {
+        impl FlexuralDesign for $shape {
+            fn flexural_strength(&self, steel: &Steel, bending: &Bending) -> FlexuralStrength {
+                IShape {
+                    d: self.d_lower,
+                    bf: self.bf,
+                    tf: self.tf,
+                    tw: self.tw,
+                    h_tw: self.h_tw,
+                    zx: self.zx,
+                    sx: self.sx,
+                    zy: self.zy,
+                    sy: self.sy,
+                    ry: self.ry,
+                    rts: self.rts,
+                    ho: self.ho,
+                    j: self.j_upper,
+                    c: ($c)(self),
+                }
+                .flexural_strength(&self.flexure_classification(steel), steel, bending)
+            }
+        }
+    };
+}
+
+impl_i_shape_flexure!(WideFlange);
+impl_i_shape_flexure!(MiscBeam);
+impl_i_shape_flexure!(StructuralBeam);
+impl_i_shape_flexure!(HPile);
+impl_i_shape_flexure!(CeeChannel, channel);
+impl_i_shape_flexure!(MiscChannel, channel);
+
+/// (Mcr) Elastic lateral-torsional buckling moment of tees and double angles (F9-10),
+/// with the stem or web legs in tension unless `stem_in_compression`
+fn tee_mcr(e: f64, lb: f64, iy: f64, j: f64, d: f64, stem_in_compression: bool) -> f64 {
+    let sign = if stem_in_compression { -1.0 } else { 1.0 };
+    let b = sign * 2.3 * d / lb * (iy / j).sqrt();
+    1.95 * e / lb * (iy * j).sqrt() * (b + (1.0 + b * b).sqrt())
+}
+
+/// F9.2 (a): lateral-torsional buckling of tees and double angles
+/// with the stem or web legs in tension
+fn tee_stem_in_tension(
+    limit_states: &mut LimitStates,
+    steel: &Steel,
+    lb: f64,
+    mp: f64,
+    my: f64,
+    section: TeeSection,
+) {
+    let (fy, e) = (steel.fy, steel.e);
+    let TeeSection { d, sx, iy, ry, j } = section;
+    let lp = 1.76 * ry * steel.root_e_fy();
+    let lr = 1.95 * (e / fy) * (iy * j).sqrt() / sx * (2.36 * (fy / e) * d * sx / j + 1.0).sqrt();
+    limit_states.unbraced_lengths(lp, lr);
+    if lb > lp {
+        let mn = if lb <= lr {
+            mp - (mp - my) * (lb - lp) / (lr - lp)
+        } else {
+            tee_mcr(e, lb, iy, j, d, false)
+        };
+        limit_states.push(FlexuralLimitState::LateralTorsionalBuckling, mn.min(mp));
+    }
+}
+
+/// The properties of tees and double angles used for lateral-torsional buckling
+#[derive(Clone, Copy)]
+struct TeeSection {
+    d: f64,
+    sx: f64,
+    iy: f64,
+    ry: f64,
+    j: f64,
+}
+
+/// F10.3: local buckling of angle legs with their toes in compression
+fn leg_local_buckling(
+    ratio: f64,
+    limits: &ElementClassification<FlexureClass>,
+    sc: f64,
+    steel: &Steel,
+) -> Option<f64> {
+    let lambda_p = limits.lambda_p.expect("flexural classifications have a λp");
+    if ratio <= lambda_p {
+        None
+    } else if ratio <= limits.lambda_r {
+        Some(steel.fy * sc * (2.43 - 1.72 * ratio * (steel.fy / steel.e).sqrt()))
+    } else {
+        Some(0.71 * steel.e / ratio.powi(2) * sc)
+    }
+}
+
+/// Implements [FlexuralDesign] for tees
+macro_rules! impl_tee_flexure {
+    ($shape:ident) => {
+        impl FlexuralDesign for $shape {
+            fn flexural_strength(&self, steel: &Steel, bending: &Bending) -> FlexuralStrength {
+                let classification = self.flexure_classification(steel);
+                let flange = element(&classification, Element::Flange);
+                if bending.axis == BendingAxis::Minor {
+                    return minor_axis_flanges(self.zy, self.sy, flange, steel);
+                }
+
+                let (fy, e) = (steel.fy, steel.e);
+                let my = fy * self.sx;
+                let section = TeeSection {
+                    d: self.d_lower,
+                    sx: self.sx,
+                    iy: self.iy,
+                    ry: self.ry,
+                    j: self.j_upper,
+                };
+                if bending.stem_in_compression {
+                    let mut limit_states = LimitStates::new(FlexuralLimitState::Yielding, my);
+                    if bending.lb > 0.0 {
+                        let mcr = tee_mcr(e, bending.lb, self.iy, self.j_upper, self.d_lower, true);
+                        limit_states
+                            .push(FlexuralLimitState::LateralTorsionalBuckling, mcr.min(my));
+                    }
+                    let ratio = self.d_t;
+                    let fcr = if ratio <= 0.84 * steel.root_e_fy() {
+                        fy
+                    } else if ratio <= 1.52 * steel.root_e_fy() {
+                        (1.43 - 0.515 * ratio * (fy / e).sqrt()) * fy
+                    } else {
+                        1.52 * e / ratio.powi(2)
+                    };
+                    limit_states.push(FlexuralLimitState::StemLocalBuckling, fcr * self.sx);
+                    return limit_states.strength();
+                }
+
+                let mp = (fy * self.zx).min(1.6 * my);
+                let mut limit_states = LimitStates::new(FlexuralLimitState::Yielding, mp);
+                tee_stem_in_tension(&mut limit_states, steel, bending.lb, mp, my, section);
+                // Elastic section modulus referred to the compression flange
+                let sxc = self.ix / self.y_lower;
+                match flange.class {
+                    FlexureClass::Compact => {}
+                    FlexureClass::Noncompact => limit_states.push(
+                        FlexuralLimitState::FlangeLocalBuckling,
+                        interpolate(mp, 0.7 * fy * sxc, flange).min(1.6 * my),
+                    ),
+                    FlexureClass::Slender => limit_states.push(
+                        FlexuralLimitState::FlangeLocalBuckling,
+                        0.7 * e * sxc / flange.ratio.powi(2),
+                    ),
+                }
+                limit_states.strength()
+            }
+        }
+    };
+}
+
+impl_tee_flexure!(WideFlangeTee);
+impl_tee_flexure!(MiscTee);
+impl_tee_flexure!(StructuralTee);
+
+impl FlexuralDesign for DoubleAngle {
+    /// The web legs are the legs back-to-back (of length d), the flange legs
+    /// are the outstanding legs (of length b).
+    fn flexural_strength(&self, steel: &Steel, bending: &Bending) -> FlexuralStrength {
+        let classification = self.flexure_classification(steel);
+        let limits = element(&classification, Element::LongLeg);
+        let fy = steel.fy;
+        let t = self.t_lower;
+        let flange_legs = self.b_lower / t;
+
+        if bending.axis == BendingAxis::Minor {
+            let mut limit_states = LimitStates::new(
+                FlexuralLimitState::Yielding,
+                (fy * self.zy).min(1.6 * fy * self.sy),
+            );
+            if let Some(mn) = leg_local_buckling(flange_legs, limits, self.sy, steel) {
+                limit_states.push(FlexuralLimitState::LegLocalBuckling, mn);
+            }
+            return limit_states.strength();
+        }
+
+        let my = fy * self.sx;
+        let j = double_angle_torsional_constant(self);
+        if bending.stem_in_compression {
+            let mp = 1.5 * my;
+            let mut limit_states = LimitStates::new(FlexuralLimitState::Yielding, mp);
+            if bending.lb > 0.0 {
+                let mcr = tee_mcr(steel.e, bending.lb, self.iy, j, self.d_lower, true);
+                limit_states.push(FlexuralLimitState::LateralTorsionalBuckling, mcr.min(mp));
+            }
+            if let Some(mn) = leg_local_buckling(self.d_lower / t, limits, self.sx, steel) {
+                limit_states.push(FlexuralLimitState::LegLocalBuckling, mn);
+            }
+            return limit_states.strength();
+        }
+
+        let mp = (fy * self.zx).min(1.6 * my);
+        let mut limit_states = LimitStates::new(FlexuralLimitState::Yielding, mp);
+        let section = TeeSection {
+            d: self.d_lower,
+            sx: self.sx,
+            iy: self.iy,
+            ry: self.ry,
+            j,
+        };
+        tee_stem_in_tension(&mut limit_states, steel, bending.lb, mp, my, section);
+        // Elastic section modulus referred to the back of the flange legs
+        let sc = self.ix / self.y_lower;
+        if let Some(mn) = leg_local_buckling(flange_legs, limits, sc, steel) {
+            limit_states.push(FlexuralLimitState::LegLocalBuckling, mn);
+        }
+        limit_states.strength()
+    }
+}
+
+/// (βw) Section property of unequal leg angles for bending about the major principal axis, in.,
+/// computed from two rectangular legs without fillets and given as a positive value
+/// (short leg in compression)
+fn beta_w(b: f64, d: f64, t: f64) -> f64 {
+    // The long leg along y and the short leg along x, with the heel at the origin
+    let legs = [(0.0, t, 0.0, b), (t, d, 0.0, t)];
+    // Two-point Gauss-Legendre quadrature integrates the cubic integrands exactly
+    let gauss = [-1.0 / 3f64.sqrt(), 1.0 / 3f64.sqrt()];
+    let integrate = |f: &dyn Fn(f64, f64) -> f64| -> f64 {
+        let mut integral = 0.0;
+        for (x0, x1, y0, y1) in legs {
+            for gx in gauss {
+                for gy in gauss {
+                    let x = (x0 + x1) / 2.0 + gx * (x1 - x0) / 2.0;
+                    let y = (y0 + y1) / 2.0 + gy * (y1 - y0) / 2.0;
+                    integral += f(x, y) * (x1 - x0) * (y1 - y0) / 4.0;
+                }
+            }
+        }
+        integral
+    };
+
+    let area = integrate(&|_, _| 1.0);
+    let xc = integrate(&|x, _| x) / area;
+    let yc = integrate(&|_, y| y) / area;
+    let ix = integrate(&|_, y| (y - yc).powi(2));
+    let iy = integrate(&|x, _| (x - xc).powi(2));
+    let ixy = integrate(&|x, y| (x - xc) * (y - yc));
+    let angle = 0.5 * (-2.0 * ixy).atan2(ix - iy);
+    let (w, z) = (
+        move |x: f64, y: f64| (x - xc) * angle.cos() + (y - yc) * angle.sin(),
+        move |x: f64, y: f64| -(x - xc) * angle.sin() + (y - yc) * angle.cos(),
+    );
+    let iw = integrate(&|x, y| z(x, y).powi(2));
+    // The shear center is at the intersection of the legs
+    let z0 = z(t / 2.0, t / 2.0);
+    (integrate(&|x, y| z(x, y) * (w(x, y).powi(2) + z(x, y).powi(2))) / iw - 2.0 * z0).abs()
+}
+
+impl FlexuralDesign for Angle {
+    fn flexural_strength(&self, steel: &Steel, bending: &Bending) -> FlexuralStrength {
+        let classification = self.flexure_classification(steel);
+        let limits = element(&classification, Element::LongLeg);
+        let fy = steel.fy;
+
+        let (my, sc) = match bending.axis {
+            BendingAxis::Major => (
+                fy * self
+                    .swa
+                    .min(self.swc)
+                    .min(self.swb.unwrap_or(f64::INFINITY)),
+                self.swa.min(self.swc),
+            ),
+            BendingAxis::Minor => (
+                fy * self.sza.min(self.szb).min(self.szc),
+                self.sza.min(self.szc),
+            ),
+        };
+        let mut limit_states = LimitStates::new(FlexuralLimitState::Yielding, 1.5 * my);
+
+        if bending.axis == BendingAxis::Major && bending.lb > 0.0 {
+            let (e, lb, t) = (steel.e, bending.lb, self.t_lower);
+            // The long leg in compression
+            let beta_w = -beta_w(
+                self.b_lower.max(self.d_lower),
+                self.b_lower.min(self.d_lower),
+                t,
+            );
+            let term = 4.4 * beta_w * self.rz / (lb * t);
+            let mcr = 9.0 * e * self.a_upper * self.rz * t * bending.cb / (8.0 * lb)
+                * ((1.0 + term * term).sqrt() + term);
+            let mn = if my / mcr <= 1.0 {
+                ((1.92 - 1.17 * (my / mcr).sqrt()) * my).min(1.5 * my)
+            } else {
+                (0.92 - 0.17 * mcr / my) * mcr
+            };
+            limit_states.push(FlexuralLimitState::LateralTorsionalBuckling, mn);
+        }
+
+        if let Some(mn) = leg_local_buckling(self.b_t, limits, sc, steel) {
+            limit_states.push(FlexuralLimitState::LegLocalBuckling, mn);
+        }
+        limit_states.strength()
+    }
+}
+
+/// The properties of a rectangular HSS about the axis of bending used in F7
+struct RectangularSection {
+    s: f64,
+    z: f64,
+    area: f64,
+    t: f64,
+    /// The outside dimension perpendicular to the axis of bending
+    depth: f64,
+    /// The flat width of the flanges
+    flange_width: f64,
+    /// The flat height of the webs
+    web_height: f64,
+    /// (ry) and (J), for rectangular HSS bent about their major axis
+    lateral_torsional: Option<(f64, f64)>,
+}
+
+impl RectangularSection {
+    /// (Se) Effective section modulus with the effective width of the compression flange (F7-4)
+    fn effective_section_modulus(&self, steel: &Steel, ratio: f64) -> f64 {
+        let root_e_fy = steel.root_e_fy();
+        let be = (1.92 * self.t * root_e_fy * (1.0 - 0.38 / ratio * root_e_fy))
+            .clamp(0.0, self.flange_width);
+        let removed = (self.flange_width - be) * self.t;
+        let distance = (self.depth - self.t) / 2.0;
+        let shift = removed * distance / (self.area - removed);
+        let i = self.s * self.depth / 2.0
+            - removed * distance.powi(2)
+            - (self.area - removed) * shift.powi(2);
+        i / (self.depth / 2.0 + shift)
+    }
+
+    fn flexural_strength(
+        &self,
+        classification: &Classification<FlexureClass>,
+        steel: &Steel,
+        bending: &Bending,
+    ) -> FlexuralStrength {
+        let (fy, e) = (steel.fy, steel.e);
+        let mp = fy * self.z;
+        let mut limit_states = LimitStates::new(FlexuralLimitState::Yielding, mp);
+
+        let flange = element(classification, Element::Flange);
+        match flange.class {
+            FlexureClass::Compact => {}
+            FlexureClass::Noncompact => limit_states.push(
+                FlexuralLimitState::FlangeLocalBuckling,
+                (mp - (mp - fy * self.s) * (3.57 * flange.ratio * (fy / e).sqrt() - 4.0)).min(mp),
+            ),
+            FlexureClass::Slender => limit_states.push(
+                FlexuralLimitState::FlangeLocalBuckling,
+                fy * self.effective_section_modulus(steel, flange.ratio),
+            ),
+        }
+
+        let web = element(classification, Element::Web);
+        match web.class {
+            FlexureClass::Compact => {}
+            FlexureClass::Noncompact => limit_states.push(
+                FlexuralLimitState::WebLocalBuckling,
+                (mp - (mp - fy * self.s) * (0.305 * web.ratio * (fy / e).sqrt() - 0.738)).min(mp),
+            ),
+            FlexureClass::Slender => {
+                // AISC 360-16 F7.3 (c)(1), compression flange yielding (F7-7)
+                let aw = 2.0 * self.web_height / self.flange_width;
+                let rpg = (1.0
+                    - aw / (1200.0 + 300.0 * aw) * (web.ratio - 5.7 * steel.root_e_fy()))
+                .min(1.0);
+                limit_states.push(FlexuralLimitState::WebLocalBuckling, rpg * fy * self.s)
+            }
+        }
+
+        if let Some((ry, j)) = self.lateral_torsional {
+            let root_ja = (j * self.area).sqrt();
+            let lp = 0.13 * e * ry * root_ja / mp;
+            let lr = 2.0 * e * ry * root_ja / (0.7 * fy * self.s);
+            limit_states.unbraced_lengths(lp, lr);
+            let lb = bending.lb;
+            if lb > lp {
+                let mn = if lb <= lr {
+                    bending.cb * (mp - (mp - 0.7 * fy * self.s) * (lb - lp) / (lr - lp))
+                } else {
+                    2.0 * e * bending.cb * root_ja / (lb / ry)
+                };
+                limit_states.push(FlexuralLimitState::LateralTorsionalBuckling, mn.min(mp));
+            }
+        }
+        limit_states.strength()
+    }
+}
+
+impl FlexuralDesign for HollowStructuralSection {
+    /// Lateral-torsional buckling only applies to rectangular HSS bent about their major axis
+    fn flexural_strength(&self, steel: &Steel, bending: &Bending) -> FlexuralStrength {
+        match bending.axis {
+            BendingAxis::Major => RectangularSection {
+                s: self.sx,
+                z: self.zx,
+                area: self.a_upper,
+                t: self.tdes,
+                depth: self.ht,
+                flange_width: self.b_lower,
+                web_height: self.h,
+                lateral_torsional: (self.ht > self.b_upper).then_some((self.ry, self.j_upper)),
+            }
+            .flexural_strength(&self.flexure_classification(steel), steel, bending),
+            BendingAxis::Minor => {
+                // The walls of height H become the flanges
+                let rotated = HollowStructuralSection {
+                    b_tdes: self.h_tdes,
+                    h_tdes: self.b_tdes,
+                    ..self.clone()
+                };
+                RectangularSection {
+                    s: self.sy,
+                    z: self.zy,
+                    area: self.a_upper,
+                    t: self.tdes,
+                    depth: self.b_upper,
+                    flange_width: self.h,
+                    web_height: self.b_lower,
+                    lateral_torsional: None,
+                }
+                .flexural_strength(
+                    &rotated.flexure_classification(steel),
+                    steel,
+                    bending,
+                )
+            }
+        }
+    }
+}
+
+/// F8: yielding and local buckling of round HSS and pipe, about either axis
+fn round_flexural_strength(
+    classification: &Classification<FlexureClass>,
+    z: f64,
+    s: f64,
+    steel: &Steel,
+) -> FlexuralStrength {
+    let (fy, e) = (steel.fy, steel.e);
+    let mut limit_states = LimitStates::new(FlexuralLimitState::Yielding, fy * z);
+    let wall = element(classification, Element::Wall);
+    match wall.class {
+        FlexureClass::Compact => {}
+        FlexureClass::Noncompact => limit_states.push(
+            FlexuralLimitState::LocalBuckling,
+            (0.021 * e / wall.ratio + fy) * s,
+        ),
+        FlexureClass::Slender => {
+            limit_states.push(FlexuralLimitState::LocalBuckling, 0.33 * e / wall.ratio * s)
+        }
+    }
+    limit_states.strength()
+}
+
+macro_rules! impl_round_flexure {
+    ($shape:ident) => {
+        impl FlexuralDesign for $shape {
+            fn flexural_strength(&self, steel: &Steel, _bending: &Bending) -> FlexuralStrength {
+                round_flexural_strength(
+                    &self.flexure_classification(steel),
+                    self.zx,
+                    self.sx,
+                    steel,
+                )
+            }
+        }
+    };
+}
+
+impl_round_flexure!(RoundHollowStructuralSection);
+impl_round_flexure!(Pipe);
+
+impl FlexuralDesign for AiscShape {
+    fn flexural_strength(&self, steel: &Steel, bending: &Bending) -> FlexuralStrength {
+        match self {
+            AiscShape::WideFlange(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::MiscBeam(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::StructuralBeam(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::HPile(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::CeeChannel(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::MiscChannel(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::Angle(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::WideFlangeTee(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::MiscTee(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::StructuralTee(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::DoubleAngle(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::HollowStructuralSection(shape) => shape.flexural_strength(steel, bending),
+            AiscShape::RoundHollowStructuralSection(shape) => {
+                shape.flexural_strength(steel, bending)
+            }
+            AiscShape::Pipe(shape) => shape.flexural_strength(steel, bending),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::ShapeProperty::*;
+    use crate::aisc_shapes::test_support::{FT, assert_close, shape, w14x90};
+
+    fn w18x50() -> WideFlange {
+        shape(
+            "W18X50",
+            &[
+                (DLower, 18.0),
+                (Bf, 7.5),
+                (Tf, 0.57),
+                (Tw, 0.355),
+                (Bf2tf, 6.57),
+                (HTw, 45.2),
+                (Zx, 101.0),
+                (Sx, 88.9),
+                (Zy, 20.2),
+                (Sy, 13.3),
+                (Iy, 40.1),
+                (Ry, 1.65),
+                (Rts, 1.98),
+                (Ho, 17.4),
+                (JUpper, 1.24),
+                (Cw, 3040.0),
+            ],
+        )
+    }
+
+    #[test]
+    fn noncompact_flanges_govern_braced_w14x90() {
+        let strength = w14x90().flexural_strength(&Steel::A992, &Bending::major(0.0, 1.0));
+        assert_eq!(
+            FlexuralLimitState::FlangeLocalBuckling,
+            strength.strength.governing
+        );
+        // AISC Manual Table 3-2: φMpx = 574 kip-ft, Mpx/Ω = 382 kip-ft
+        assert_close(574.0, strength.phi_mn() / FT, 0.5);
+        assert_close(382.0, strength.mn_omega() / FT, 0.5);
+        assert_eq!(2, strength.strength.limit_states.len());
+    }
+
+    #[test]
+    fn w18x50_buckles_laterally_between_lp_and_lr() {
+        let strength = w18x50().flexural_strength(&Steel::A992, &Bending::major(10.0 * FT, 1.0));
+        assert_close(5.83, strength.lp.unwrap() / FT, 0.01);
+        assert_close(16.9, strength.lr.unwrap() / FT, 0.1);
+        assert_eq!(
+            FlexuralLimitState::LateralTorsionalBuckling,
+            strength.strength.governing
+        );
+        assert_close(324.0, strength.phi_mn() / FT, 1.0);
+    }
+
+    #[test]
+    fn w18x50_buckles_elastically_beyond_lr() {
+        let strength = w18x50().flexural_strength(&Steel::A992, &Bending::major(30.0 * FT, 1.0));
+        assert_close(101.1, strength.phi_mn() / FT, 0.5);
+
+        let with_cb = w18x50().flexural_strength(&Steel::A992, &Bending::major(30.0 * FT, 1.5));
+        assert_close(1.5 * strength.mn(), with_cb.mn(), 1e-9);
+    }
+
+    #[test]
+    fn cb_does_not_raise_the_strength_above_mp() {
+        let strength = w18x50().flexural_strength(&Steel::A992, &Bending::major(6.0 * FT, 3.0));
+        assert_eq!(50.0 * 101.0, strength.mn());
+    }
+
+    #[test]
+    fn minor_axis_bending_is_limited_by_the_flanges() {
+        let strength = w14x90().flexural_strength(&Steel::A992, &Bending::minor());
+        assert_eq!(
+            FlexuralLimitState::FlangeLocalBuckling,
+            strength.strength.governing
+        );
+        // AISC Manual Table 3-4: φMpy = 273 kip-ft
+        assert_close(273.0, strength.phi_mn() / FT, 0.5);
+        assert_eq!(None, strength.lp);
+    }
+
+    #[test]
+    fn slender_webs_reduce_the_strength_of_i_shapes() {
+        let mut shape = w18x50();
+        shape.h_tw = 150.0;
+        let strength = shape.flexural_strength(&Steel::A992, &Bending::major(0.0, 1.0));
+        assert_eq!(
+            FlexuralLimitState::CompressionFlangeYielding,
+            strength.strength.governing
+        );
+        assert!(strength.mn() < 50.0 * shape.sx);
+    }
+
+    #[test]
+    fn channels_yield_when_braced() {
+        let c12x20_7: CeeChannel = shape(
+            "C12X20.7",
+            &[
+                (DLower, 12.0),
+                (Bf, 2.94),
+                (Tf, 0.501),
+                (Tw, 0.282),
+                (BT, 5.87),
+                (HTw, 35.2),
+                (Zx, 25.6),
+                (Sx, 21.5),
+                (Iy, 3.86),
+                (Ry, 0.797),
+                (Rts, 0.983),
+                (Ho, 11.5),
+                (JUpper, 0.369),
+                (Cw, 112.0),
+            ],
+        );
+        let braced = c12x20_7.flexural_strength(&Steel::A36, &Bending::major(0.0, 1.0));
+        assert_eq!(FlexuralLimitState::Yielding, braced.strength.governing);
+        assert_close(69.1, braced.phi_mn() / FT, 0.1);
+        assert!(braced.lr.unwrap() > braced.lp.unwrap());
+
+        let unbraced = c12x20_7.flexural_strength(&Steel::A36, &Bending::major(20.0 * FT, 1.0));
+        assert_eq!(
+            FlexuralLimitState::LateralTorsionalBuckling,
+            unbraced.strength.governing
+        );
+        assert!(unbraced.mn() < braced.mn());
+    }
+
+    fn hss8x4x1_8() -> HollowStructuralSection {
+        shape(
+            "HSS8X4X1/8",
+            &[
+                (Ht, 8.0),
+                (H, 7.65),
+                (BUpper, 4.0),
+                (BLower, 3.65),
+                (Tdes, 0.116),
+                (AUpper, 2.70),
+                (BTdes, 31.5),
+                (HTdes, 66.0),
+                (Zx, 7.02),
+                (Sx, 5.73),
+                (Zy, 4.36),
+                (Sy, 3.95),
+                (Ry, 1.71),
+                (JUpper, 18.7),
+            ],
+        )
+    }
+
+    #[test]
+    fn hss_flanges_and_webs_buckle_locally() {
+        let strength = hss8x4x1_8()
+            .flexural_strength(&Steel::A500_GR_C_RECTANGULAR, &Bending::major(0.0, 1.0));
+        assert_eq!(
+            FlexuralLimitState::FlangeLocalBuckling,
+            strength.strength.governing
+        );
+        assert_close(307.8, strength.mn(), 0.2);
+        let web = strength
+            .strength
+            .limit_states
+            .iter()
+            .find(|(limit_state, _)| *limit_state == FlexuralLimitState::WebLocalBuckling)
+            .unwrap();
+        assert_close(344.7, web.1, 0.2);
+    }
+
+    #[test]
+    fn slender_hss_flanges_use_an_effective_width() {
+        // The walls of height H are the flanges in minor axis bending
+        let strength =
+            hss8x4x1_8().flexural_strength(&Steel::A500_GR_C_RECTANGULAR, &Bending::minor());
+        assert_eq!(
+            FlexuralLimitState::FlangeLocalBuckling,
+            strength.strength.governing
+        );
+        assert!(strength.mn() < 50.0 * 3.95);
+        assert!(strength.mn() > 0.5 * 50.0 * 3.95);
+        assert_eq!(None, strength.lp);
+    }
+
+    #[test]
+    fn compact_pipe_reaches_its_plastic_moment() {
+        let pipe: Pipe = shape("Pipe12STD", &[(DT, 36.5), (Zx, 57.4), (Sx, 43.8)]);
+        let strength = pipe.flexural_strength(&Steel::A53_GR_B, &Bending::major(20.0 * FT, 1.0));
+        assert_eq!(FlexuralLimitState::Yielding, strength.strength.governing);
+        assert_eq!(35.0 * 57.4, strength.mn());
+    }
+
+    fn wt7x45() -> WideFlangeTee {
+        shape(
+            "WT7X45",
+            &[
+                (DLower, 7.01),
+                (Bf2tf, 10.2),
+                (DT, 15.9),
+                (YLower, 1.09),
+                (Ix, 36.5),
+                (Zx, 11.5),
+                (Sx, 6.16),
+                (Iy, 181.0),
+                (Ry, 3.70),
+                (JUpper, 2.03),
+                (Zy, 37.8),
+                (Sy, 25.0),
+            ],
+        )
+    }
+
+    #[test]
+    fn tee_strength_depends_on_the_side_in_compression() {
+        let stem_in_tension = wt7x45().flexural_strength(&Steel::A992, &Bending::major(0.0, 1.0));
+        assert_eq!(
+            FlexuralLimitState::Yielding,
+            stem_in_tension.strength.governing
+        );
+        assert_close(1.6 * 50.0 * 6.16, stem_in_tension.mn(), 1e-9);
+
+        let stem_in_compression = wt7x45().flexural_strength(
+            &Steel::A992,
+            &Bending::major(0.0, 1.0).with_stem_in_compression(),
+        );
+        assert_close(50.0 * 6.16, stem_in_compression.mn(), 1e-9);
+
+        // Too stocky for lateral-torsional buckling to govern
+        let unbraced = wt7x45().flexural_strength(
+            &Steel::A992,
+            &Bending::major(20.0 * FT, 1.0).with_stem_in_compression(),
+        );
+        assert!(
+            unbraced
+                .strength
+                .limit_states
+                .contains(&(FlexuralLimitState::LateralTorsionalBuckling, 50.0 * 6.16))
+        );
+    }
+
+    #[test]
+    fn beta_w_matches_the_commentary() {
+        // AISC 360 Commentary Table C-F10.1
+        assert_close(3.31, beta_w(8.0, 6.0, 1.0), 0.05);
+        assert_close(5.48, beta_w(8.0, 4.0, 1.0), 0.1);
+        assert_close(1.65, beta_w(4.0, 3.0, 0.25), 0.01);
+        assert_close(0.0, beta_w(4.0, 4.0, 0.5), 1e-9);
+    }
+
+    #[test]
+    fn angles_are_limited_by_leg_local_buckling() {
+        let mut l4x4x1_4: Angle = shape(
+            "L4X4X1/4",
+            &[
+                (DLower, 4.0),
+                (BLower, 4.0),
+                (TLower, 0.25),
+                (BT, 16.0),
+                (AUpper, 1.93),
+                (Rz, 0.783),
+                (Swa, 1.76),
+                (Swc, 1.76),
+                (Sza, 0.856),
+                (Szb, 0.778),
+                (Szc, 0.856),
+            ],
+        );
+        l4x4x1_4.swb = None;
+        let braced = l4x4x1_4.flexural_strength(&Steel::A36, &Bending::major(0.0, 1.0));
+        assert_eq!(
+            FlexuralLimitState::LegLocalBuckling,
+            braced.strength.governing
+        );
+        let expected = 36.0 * 1.76 * (2.43 - 1.72 * 16.0 * (36.0_f64 / 29000.0).sqrt());
+        assert_close(expected, braced.mn(), 1e-9);
+
+        let unbraced = l4x4x1_4.flexural_strength(&Steel::A36, &Bending::major(10.0 * FT, 1.0));
+        assert_eq!(
+            FlexuralLimitState::LateralTorsionalBuckling,
+            unbraced.strength.governing
+        );
+
+        let minor = l4x4x1_4.flexural_strength(&Steel::A36, &Bending::minor());
+        assert!(minor.mn() <= 1.5 * 36.0 * 0.778);
+    }
+
+    #[test]
+    fn double_angles_check_the_legs_in_compression() {
+        let double_angle: DoubleAngle = shape(
+            "2L5X3X5/16LLBB",
+            &[
+                (DLower, 5.0),
+                (BLower, 3.0),
+                (TLower, 0.3125),
+                (BT, 16.0),
+                (YLower, 1.67),
+                (Ix, 12.5),
+                (Zx, 6.64),
+                (Sx, 3.74),
+                (Iy, 5.62),
+                (Ry, 1.08),
+            ],
+        );
+        let web_legs_in_compression = double_angle.flexural_strength(
+            &Steel::A36,
+            &Bending::major(0.0, 1.0).with_stem_in_compression(),
+        );
+        assert_eq!(
+            FlexuralLimitState::LegLocalBuckling,
+            web_legs_in_compression.strength.governing
+        );
+
+        let flange_legs_in_compression =
+            double_angle.flexural_strength(&Steel::A36, &Bending::major(0.0, 1.0));
+        assert_eq!(
+            FlexuralLimitState::Yielding,
+            flange_legs_in_compression.strength.governing
+        );
+        assert!(flange_legs_in_compression.mn() > web_legs_in_compression.mn());
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn every_shape_has_a_flexural_strength() {
+        use crate::aisc_shapes::SectionProperties;
+
+        let steel = Steel::new(50.0, 65.0);
+        let bendings = [
+            Bending::major(0.0, 1.0),
+            Bending::major(15.0 * FT, 1.0),
+            Bending::major(15.0 * FT, 1.0).with_stem_in_compression(),
+            Bending::minor(),
+        ];
+        for shape in crate::aisc_shapes::aisc_database::snapshot() {
+            for bending in &bendings {
+                let strength = shape.flexural_strength(&steel, bending);
+                assert!(
+                    strength.mn().is_finite() && strength.mn() > 0.0,
+                    "{} has Mn = {}",
+                    shape.aisc_manual_label(),
+                    strength.mn()
+                );
+            }
+        }
+    }
+}
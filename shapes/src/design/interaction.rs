@@ -0,0 +1,44 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The interaction equations of AISC 360 H1.1 and H1.2
+pub enum InteractionEquation {
+    /// Pr/Pc + 8/9 (Mrx/Mcx + Mry/Mcy) ≤ 1.0 when Pr/Pc ≥ 0.2 (H1-1a)
+    HighAxial,
+    /// Pr/(2Pc) + (Mrx/Mcx + Mry/Mcy) ≤ 1.0 when Pr/Pc < 0.2 (H1-1b)
+    LowAxial,
+}
+
+/// The interaction ratio of doubly and singly symmetric members subject to flexure
+/// and axial force (H1-1), from the ratios of the required to the available axial strength
+/// Pr/Pc and flexural strengths Mrx/Mcx and Mry/Mcy, with the equation that applies.
+///
+/// The ratios are taken as absolute values, so the axial ratio applies to tension (H1.2)
+/// as well as compression (H1.1). Single angles should be checked with their principal axis moments.
+pub fn interaction_ratio(pr_pc: f64, mrx_mcx: f64, mry_mcy: f64) -> (InteractionEquation, f64) {
+    let (axial, flexure) = (pr_pc.abs(), mrx_mcx.abs() + mry_mcy.abs());
+    if axial >= 0.2 {
+        (InteractionEquation::HighAxial, axial + 8.0 / 9.0 * flexure)
+    } else {
+        (InteractionEquation::LowAxial, axial / 2.0 + flexure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn high_axial_force_uses_h1_1a() {
+        let (equation, ratio) = interaction_ratio(0.5, 0.3, 0.15);
+        assert_eq!(InteractionEquation::HighAxial, equation);
+        assert!((0.9 - ratio).abs() < 1e-12);
+    }
+
+    #[test]
+    fn low_axial_force_uses_h1_1b() {
+        let (equation, ratio) = interaction_ratio(-0.1, 0.5, -0.2);
+        assert_eq!(InteractionEquation::LowAxial, equation);
+        assert!((0.75 - ratio).abs() < 1e-12);
+    }
+}
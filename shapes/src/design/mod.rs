@@ -0,0 +1,159 @@
+//! This module is responsible for the design strength of steel shapes
+//! per the AISC Specification for Structural Steel Buildings (AISC 360-16).
+//!
+//! Strengths are computed from the properties of the shapes in US customary
+//! units: forces in kips, moments in kip-in. and lengths in in.
+
+/// Checks members for combinations of required strengths
+pub mod check;
+/// Computes the compressive strength of members per AISC 360 Chapter E
+pub mod compression;
+/// Computes the flexural strength of shapes per AISC 360 Chapter F
+pub mod flexure;
+/// Combines axial force and flexure per AISC 360 Chapter H
+pub mod interaction;
+/// Computes the shear strength of shapes per AISC 360 Chapter G
+pub mod shear;
+/// Generates the available strength tables of the AISC Steel Construction Manual
+pub mod tables;
+/// Computes the tensile strength of members per AISC 360 Chapter D
+pub mod tension;
+
+pub use self::check::{
+    CheckedLimitState, DesignCheck, DesignMethod, LimitStateCheck, MemberCheck, RequiredStrengths,
+};
+pub use self::compression::{
+    AngleMember, CompressionDesign, CompressiveLimitState, CompressiveStrength, ConnectedLeg,
+    EffectiveLengths,
+};
+pub use self::flexure::{
+    Bending, BendingAxis, FlexuralDesign, FlexuralLimitState, FlexuralStrength,
+};
+pub use self::interaction::{InteractionEquation, interaction_ratio};
+pub use self::shear::{Shear, ShearDesign, ShearLimitState, ShearStrength};
+pub use self::tables::{DesignTable, DesignTableKind, LengthRange, TableValue, design_table};
+pub use self::tension::{NetSection, TensileLimitState, TensileStrength, TensionDesign};
+
+use crate::aisc_shapes::DoubleAngle;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The resistance factor (LRFD) and safety factor (ASD) of a limit state
+pub struct ResistanceFactors {
+    /// (φ) Resistance factor for LRFD
+    pub phi: f64,
+    /// (Ω) Safety factor for ASD
+    pub omega: f64,
+}
+
+impl ResistanceFactors {
+    /// Flexure (Chapter F)
+    pub const FLEXURE: ResistanceFactors = ResistanceFactors {
+        phi: 0.90,
+        omega: 1.67,
+    };
+    /// Compression (Chapter E)
+    pub const COMPRESSION: ResistanceFactors = ResistanceFactors {
+        phi: 0.90,
+        omega: 1.67,
+    };
+    /// Shear (Chapter G)
+    pub const SHEAR: ResistanceFactors = ResistanceFactors {
+        phi: 0.90,
+        omega: 1.67,
+    };
+    /// Shear of the webs of rolled I-shapes with h/tw ≤ 2.24√(E/Fy) (G2.1(a))
+    pub const SHEAR_ROLLED_I: ResistanceFactors = ResistanceFactors {
+        phi: 1.00,
+        omega: 1.50,
+    };
+    /// Tensile yielding in the gross section (D2(a))
+    pub const TENSILE_YIELDING: ResistanceFactors = ResistanceFactors {
+        phi: 0.90,
+        omega: 1.67,
+    };
+    /// Tensile rupture in the net section (D2(b))
+    pub const TENSILE_RUPTURE: ResistanceFactors = ResistanceFactors {
+        phi: 0.75,
+        omega: 2.00,
+    };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The nominal strength of a member, governed by the weakest of the limit states
+/// that apply to it, with its design (LRFD) and allowable (ASD) strengths
+pub struct Strength<L> {
+    /// (Rn) Nominal strength
+    pub nominal: f64,
+    /// (φRn) Design strength for LRFD
+    pub design: f64,
+    /// (Rn/Ω) Allowable strength for ASD
+    pub allowable: f64,
+    /// The limit state with the lowest nominal strength
+    pub governing: L,
+    /// Every limit state that applies to the member, with its nominal strength
+    pub limit_states: Vec<(L, f64)>,
+}
+
+impl<L: Copy> Strength<L> {
+    /// The strength governed by the lowest of the nominal strengths of the limit states,
+    /// the first limit state governs when several have the same strength
+    pub fn governed_by(limit_states: Vec<(L, f64)>, factors: ResistanceFactors) -> Self {
+        let (governing, nominal) = limit_states
+            .iter()
+            .copied()
+            .reduce(|lowest, limit_state| {
+                if limit_state.1 < lowest.1 {
+                    limit_state
+                } else {
+                    lowest
+                }
+            })
+            .expect("at least one limit state applies");
+        Strength {
+            nominal,
+            design: factors.phi * nominal,
+            allowable: nominal / factors.omega,
+            governing,
+            limit_states,
+        }
+    }
+
+    /// The strength governed by the lowest of the design strengths of limit states
+    /// with different resistance factors, with the lowest of their allowable strengths
+    pub fn governed_by_design(limit_states: Vec<(L, f64, ResistanceFactors)>) -> Self {
+        let (governing, nominal, factors) = limit_states
+            .iter()
+            .copied()
+            .reduce(|lowest, limit_state| {
+                if limit_state.2.phi * limit_state.1 < lowest.2.phi * lowest.1 {
+                    limit_state
+                } else {
+                    lowest
+                }
+            })
+            .expect("at least one limit state applies");
+        let allowable = limit_states
+            .iter()
+            .map(|(_, nominal, factors)| nominal / factors.omega)
+            .fold(f64::INFINITY, f64::min);
+        Strength {
+            nominal,
+            design: factors.phi * nominal,
+            allowable,
+            governing,
+            limit_states: limit_states
+                .into_iter()
+                .map(|(limit_state, nominal, _)| (limit_state, nominal))
+                .collect(),
+        }
+    }
+}
+
+/// (J) Torsional constant of double angles, which don't tabulate one,
+/// taken as that of four thin legs of length b - t/2, in.⁴
+pub(crate) fn double_angle_torsional_constant(shape: &DoubleAngle) -> f64 {
+    let t = shape.t_lower;
+    2.0 * (shape.d_lower + shape.b_lower - t) * t.powi(3) / 3.0
+}
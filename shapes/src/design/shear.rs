@@ -0,0 +1,412 @@
+use crate::aisc_shapes::{
+    AiscShape, Angle, CeeChannel, DoubleAngle, HPile, HollowStructuralSection, MiscBeam,
+    MiscChannel, MiscTee, Pipe, RoundHollowStructuralSection, Steel, StructuralBeam, StructuralTee,
+    WideFlange, WideFlangeTee,
+};
+use crate::design::{BendingAxis, ResistanceFactors, Strength};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The shear a shape is designed for
+pub struct Shear {
+    /// The axis of the bending accompanying the shear: shear along the web of I-shapes,
+    /// channels and tees and along the walls of HSS of depth H for the major axis,
+    /// and along the flanges for the minor axis.
+    /// Single angles resist major axis shear with the long leg, double angles with the back-to-back legs.
+    pub axis: BendingAxis,
+    /// (Lv) Distance from maximum to zero shear force of round HSS and pipe, in.,
+    /// taken as infinite when omitted
+    pub lv: Option<f64>,
+}
+
+impl Shear {
+    /// Shear accompanying bending about the major axis
+    pub fn major() -> Self {
+        Shear {
+            axis: BendingAxis::Major,
+            lv: None,
+        }
+    }
+
+    /// Shear accompanying bending about the minor axis
+    pub fn minor() -> Self {
+        Shear {
+            axis: BendingAxis::Minor,
+            lv: None,
+        }
+    }
+
+    /// Sets the distance from maximum to zero shear force of round HSS and pipe, in.
+    pub fn with_shear_span(mut self, lv: f64) -> Self {
+        self.lv = Some(lv);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The limit states of AISC 360 Chapter G
+pub enum ShearLimitState {
+    /// Shear yielding, when the web shear strength coefficient Cv is 1.0
+    ShearYielding,
+    /// Shear buckling, when the web shear strength coefficient Cv is less than 1.0
+    ShearBuckling,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The shear strength of a shape, kips
+pub struct ShearStrength {
+    /// (Vn) The nominal shear strength and the limit state governing it, kips
+    pub strength: Strength<ShearLimitState>,
+    /// (Aw) Area resisting the shear, in.²
+    pub aw: f64,
+    /// (Cv) Web shear strength coefficient, or Fcr/(0.6Fy) for round HSS and pipe
+    pub cv: f64,
+}
+
+impl ShearStrength {
+    /// (Vn) Nominal shear strength, kips
+    pub fn vn(&self) -> f64 {
+        self.strength.nominal
+    }
+
+    /// (φvVn) Design shear strength for LRFD, kips
+    pub fn phi_vn(&self) -> f64 {
+        self.strength.design
+    }
+
+    /// (Vn/Ωv) Allowable shear strength for ASD, kips
+    pub fn vn_omega(&self) -> f64 {
+        self.strength.allowable
+    }
+}
+
+/// Computes the nominal shear strength of shapes per AISC 360 Chapter G
+/// from their tabulated properties:
+///
+/// - G2.1 for the webs of I-shapes and channels without transverse stiffeners,
+///   with the resistance factors of G2.1(a) for rolled I-shapes with h/tw ≤ 2.24√(E/Fy)
+/// - G3 for the stems of tees and the legs of single and double angles
+/// - G4 for the walls of square and rectangular HSS, with h and b the flat widths
+/// - G5 for round HSS and pipe
+/// - G6 for the flanges of I-shapes, channels and tees under minor axis shear
+///
+/// Tension field action (G2.2) isn't considered.
+pub trait ShearDesign {
+    /// The shear strength of the shape made of the given steel
+    fn shear_strength(&self, steel: &Steel, shear: &Shear) -> ShearStrength;
+}
+
+/// The web shear buckling coefficient of unstiffened webs (G2.1(b)(2)),
+/// webs of HSS (G4) and unstiffened elements (G3, G6)
+#[derive(Clone, Copy)]
+enum BucklingCoefficient {
+    UnstiffenedWeb,
+    HssWall,
+    Unstiffened,
+}
+
+impl BucklingCoefficient {
+    /// (kv)
+    fn kv(self) -> f64 {
+        match self {
+            BucklingCoefficient::UnstiffenedWeb => 5.34,
+            BucklingCoefficient::HssWall => 5.0,
+            BucklingCoefficient::Unstiffened => 1.2,
+        }
+    }
+}
+
+/// (Cv1) Web shear strength coefficient of I-shapes and channels (G2-3, G2-4)
+fn cv1(steel: &Steel, h_tw: f64) -> f64 {
+    let limit = 1.10 * (BucklingCoefficient::UnstiffenedWeb.kv() * steel.e / steel.fy).sqrt();
+    if h_tw <= limit { 1.0 } else { limit / h_tw }
+}
+
+/// (Cv2) Web shear buckling coefficient (G2-9 to G2-11)
+fn cv2(steel: &Steel, h_tw: f64, kv: BucklingCoefficient) -> f64 {
+    let kv = kv.kv();
+    let root = (kv * steel.e / steel.fy).sqrt();
+    if h_tw <= 1.10 * root {
+        1.0
+    } else if h_tw <= 1.37 * root {
+        1.10 * root / h_tw
+    } else {
+        1.51 * kv * steel.e / (h_tw.powi(2) * steel.fy)
+    }
+}
+
+/// (Vn = 0.6FyAwCv) The shear strength of an area with the given coefficient
+fn shear_strength(steel: &Steel, aw: f64, cv: f64, factors: ResistanceFactors) -> ShearStrength {
+    let limit_state = if cv < 1.0 {
+        ShearLimitState::ShearBuckling
+    } else {
+        ShearLimitState::ShearYielding
+    };
+    ShearStrength {
+        strength: Strength::governed_by(vec![(limit_state, 0.6 * steel.fy * aw * cv)], factors),
+        aw,
+        cv,
+    }
+}
+
+/// G2.1 and G6 for I-shapes and channels, whose two flanges both resist minor axis shear
+macro_rules! impl_i_shape_shear {
+    ($shape:ident, $b_t:expr, $rolled_i_shape:expr) => {
+        impl ShearDesign for $shape {
+            fn shear_strength(&self, steel: &Steel, shear: &Shear) -> ShearStrength {
+                let b_t: fn(&$shape) -> f64 = $b_t;
+                match shear.axis {
+                    BendingAxis::Major => {
+                        let aw = self.d_lower * self.tw;
+                        if $rolled_i_shape && self.h_tw <= 2.24 * steel.root_e_fy() {
+                            shear_strength(steel, aw, 1.0, ResistanceFactors::SHEAR_ROLLED_I)
+                        } else {
+                            shear_strength(
+                                steel,
+                                aw,
+                                cv1(steel, self.h_tw),
+                                ResistanceFactors::SHEAR,
+                            )
+                        }
+                    }
+                    BendingAxis::Minor => shear_strength(
+                        steel,
+                        2.0 * self.bf * self.tf,
+                        cv2(steel, b_t(self), BucklingCoefficient::Unstiffened),
+                        ResistanceFactors::SHEAR,
+                    ),
+                }
+            }
+        }
+    };
+}
+
+impl_i_shape_shear!(WideFlange, |shape| shape.bf_2tf, true);
+impl_i_shape_shear!(MiscBeam, |shape| shape.bf_2tf, true);
+impl_i_shape_shear!(StructuralBeam, |shape| shape.bf_2tf, true);
+impl_i_shape_shear!(HPile, |shape| shape.bf_2tf, true);
+impl_i_shape_shear!(CeeChannel, |shape| shape.b_t, false);
+impl_i_shape_shear!(MiscChannel, |shape| shape.b_t, false);
+
+/// G3 for the stem and G6 for the flange of tees
+macro_rules! impl_tee_shear {
+    ($shape:ident) => {
+        impl ShearDesign for $shape {
+            fn shear_strength(&self, steel: &Steel, shear: &Shear) -> ShearStrength {
+                let (aw, b_t) = match shear.axis {
+                    BendingAxis::Major => (self.d_lower * self.tw, self.d_lower / self.tw),
+                    BendingAxis::Minor => (self.bf * self.tf, self.bf_2tf),
+                };
+                shear_strength(
+                    steel,
+                    aw,
+                    cv2(steel, b_t, BucklingCoefficient::Unstiffened),
+                    ResistanceFactors::SHEAR,
+                )
+            }
+        }
+    };
+}
+
+impl_tee_shear!(WideFlangeTee);
+impl_tee_shear!(MiscTee);
+impl_tee_shear!(StructuralTee);
+
+impl ShearDesign for Angle {
+    fn shear_strength(&self, steel: &Steel, shear: &Shear) -> ShearStrength {
+        let b = match shear.axis {
+            BendingAxis::Major => self.b_lower,
+            BendingAxis::Minor => self.d_lower,
+        };
+        shear_strength(
+            steel,
+            b * self.t_lower,
+            cv2(steel, b / self.t_lower, BucklingCoefficient::Unstiffened),
+            ResistanceFactors::SHEAR,
+        )
+    }
+}
+
+impl ShearDesign for DoubleAngle {
+    fn shear_strength(&self, steel: &Steel, shear: &Shear) -> ShearStrength {
+        let b = match shear.axis {
+            BendingAxis::Major => self.d_lower,
+            BendingAxis::Minor => self.b_lower,
+        };
+        shear_strength(
+            steel,
+            2.0 * b * self.t_lower,
+            cv2(steel, b / self.t_lower, BucklingCoefficient::Unstiffened),
+            ResistanceFactors::SHEAR,
+        )
+    }
+}
+
+impl ShearDesign for HollowStructuralSection {
+    fn shear_strength(&self, steel: &Steel, shear: &Shear) -> ShearStrength {
+        let (h, h_t) = match shear.axis {
+            BendingAxis::Major => (self.h, self.h_tdes),
+            BendingAxis::Minor => (self.b_lower, self.b_tdes),
+        };
+        shear_strength(
+            steel,
+            2.0 * h * self.tdes,
+            cv2(steel, h_t, BucklingCoefficient::HssWall),
+            ResistanceFactors::SHEAR,
+        )
+    }
+}
+
+/// G5: the shear strength of round HSS and pipe, which doesn't depend on the axis
+fn round_shear_strength(
+    area: f64,
+    od: f64,
+    d_t: f64,
+    steel: &Steel,
+    shear: &Shear,
+) -> ShearStrength {
+    let long = 0.78 * steel.e / d_t.powf(1.5);
+    let fcr = match shear.lv {
+        Some(lv) => (1.60 * steel.e / ((lv / od).sqrt() * d_t.powf(1.25))).max(long),
+        None => long,
+    }
+    .min(0.6 * steel.fy);
+    shear_strength(
+        steel,
+        area / 2.0,
+        fcr / (0.6 * steel.fy),
+        ResistanceFactors::SHEAR,
+    )
+}
+
+impl ShearDesign for RoundHollowStructuralSection {
+    fn shear_strength(&self, steel: &Steel, shear: &Shear) -> ShearStrength {
+        round_shear_strength(self.a_upper, self.od, self.d_t, steel, shear)
+    }
+}
+
+impl ShearDesign for Pipe {
+    fn shear_strength(&self, steel: &Steel, shear: &Shear) -> ShearStrength {
+        round_shear_strength(self.a_upper, self.od, self.d_t, steel, shear)
+    }
+}
+
+impl ShearDesign for AiscShape {
+    fn shear_strength(&self, steel: &Steel, shear: &Shear) -> ShearStrength {
+        match self {
+            AiscShape::WideFlange(shape) => shape.shear_strength(steel, shear),
+            AiscShape::MiscBeam(shape) => shape.shear_strength(steel, shear),
+            AiscShape::StructuralBeam(shape) => shape.shear_strength(steel, shear),
+            AiscShape::HPile(shape) => shape.shear_strength(steel, shear),
+            AiscShape::CeeChannel(shape) => shape.shear_strength(steel, shear),
+            AiscShape::MiscChannel(shape) => shape.shear_strength(steel, shear),
+            AiscShape::Angle(shape) => shape.shear_strength(steel, shear),
+            AiscShape::WideFlangeTee(shape) => shape.shear_strength(steel, shear),
+            AiscShape::MiscTee(shape) => shape.shear_strength(steel, shear),
+            AiscShape::StructuralTee(shape) => shape.shear_strength(steel, shear),
+            AiscShape::DoubleAngle(shape) => shape.shear_strength(steel, shear),
+            AiscShape::HollowStructuralSection(shape) => shape.shear_strength(steel, shear),
+            AiscShape::RoundHollowStructuralSection(shape) => shape.shear_strength(steel, shear),
+            AiscShape::Pipe(shape) => shape.shear_strength(steel, shear),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::ShapeProperty::*;
+    use crate::aisc_shapes::test_support::{assert_close, shape};
+
+    #[test]
+    fn rolled_i_shape_webs_yield_with_a_resistance_factor_of_one() {
+        let w14x90: WideFlange = shape("W14X90", &[(DLower, 14.0), (Tw, 0.44), (HTw, 25.9)]);
+        let strength = w14x90.shear_strength(&Steel::A992, &Shear::major());
+        assert_eq!(ShearLimitState::ShearYielding, strength.strength.governing);
+        // AISC Manual Table 3-2: φvVnx = 185 kips, Vnx/Ωv = 123 kips
+        assert_close(185.0, strength.phi_vn(), 0.5);
+        assert_close(123.0, strength.vn_omega(), 0.5);
+    }
+
+    #[test]
+    fn channel_webs_use_the_general_resistance_factor() {
+        let channel: CeeChannel = shape("C", &[(DLower, 12.0), (Tw, 0.282), (HTw, 36.7)]);
+        let strength = channel.shear_strength(&Steel::A36, &Shear::major());
+        assert_close(0.9 * 0.6 * 36.0 * 12.0 * 0.282, strength.phi_vn(), 1e-9);
+    }
+
+    #[test]
+    fn slender_webs_buckle_in_shear() {
+        let beam: WideFlange = shape("W", &[(DLower, 20.0), (Tw, 0.25), (HTw, 70.0)]);
+        let strength = beam.shear_strength(&Steel::A992, &Shear::major());
+        let cv1 = 1.10 * (5.34 * 29000.0 / 50.0_f64).sqrt() / 70.0;
+        assert_eq!(ShearLimitState::ShearBuckling, strength.strength.governing);
+        assert_close(cv1, strength.cv, 1e-12);
+        assert_close(0.6 * 50.0 * 5.0 * cv1, strength.vn(), 1e-9);
+    }
+
+    #[test]
+    fn flanges_resist_minor_axis_shear() {
+        let w14x90: WideFlange = shape("W14X90", &[(Bf, 14.5), (Tf, 0.71), (Bf2tf, 10.2)]);
+        let strength = w14x90.shear_strength(&Steel::A992, &Shear::minor());
+        assert_close(0.6 * 50.0 * 2.0 * 14.5 * 0.71, strength.vn(), 1e-9);
+        assert_close(0.9, strength.phi_vn() / strength.vn(), 1e-12);
+    }
+
+    #[test]
+    fn both_channel_flanges_resist_minor_axis_shear() {
+        let c12x20_7: CeeChannel = shape("C12X20.7", &[(Bf, 2.94), (Tf, 0.501), (BT, 5.87)]);
+        let strength = c12x20_7.shear_strength(&Steel::A36, &Shear::minor());
+        // G6-1 for each flange, Cv2 = 1.0 as b/t = 5.87 <= 1.10√(1.2E/Fy) = 34.2
+        assert_eq!(ShearLimitState::ShearYielding, strength.strength.governing);
+        assert_close(1.0, strength.cv, 1e-12);
+        assert_close(2.0 * 2.94 * 0.501, strength.aw, 1e-12);
+        assert_close(0.6 * 36.0 * 2.0 * 2.94 * 0.501, strength.vn(), 1e-9);
+    }
+
+    #[test]
+    fn slender_hss_walls_buckle_in_shear() {
+        let hss8x4x1_8: HollowStructuralSection =
+            shape("HSS8X4X1/8", &[(H, 7.66), (Tdes, 0.116), (HTdes, 66.0)]);
+        let strength = hss8x4x1_8.shear_strength(&Steel::A500_GR_C_RECTANGULAR, &Shear::major());
+        let root = (5.0 * 29000.0 / 50.0_f64).sqrt();
+        assert_close(1.10 * root / 66.0, strength.cv, 1e-12);
+        assert_close(2.0 * 7.66 * 0.116, strength.aw, 1e-12);
+    }
+
+    #[test]
+    fn short_round_hss_use_the_shear_span() {
+        let pipe: Pipe = shape("Pipe", &[(AUpper, 10.0), (Od, 24.0), (DT, 150.0)]);
+        let steel = Steel::A500_GR_C_ROUND;
+        let long = pipe.shear_strength(&steel, &Shear::major());
+        assert_close(0.78 * 29000.0 / 150.0_f64.powf(1.5) * 5.0, long.vn(), 1e-9);
+        let short = pipe.shear_strength(&steel, &Shear::major().with_shear_span(50.0 * 24.0));
+        let fcr = 1.60 * 29000.0 / (50.0_f64.sqrt() * 150.0_f64.powf(1.25));
+        assert_close(fcr * 5.0, short.vn(), 1e-9);
+        assert!(short.vn() > long.vn());
+        let shorter = pipe.shear_strength(&steel, &Shear::major().with_shear_span(24.0));
+        assert_close(0.6 * 46.0 * 5.0, shorter.vn(), 1e-9);
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn every_shape_has_a_shear_strength() {
+        use crate::aisc_shapes::SectionProperties;
+
+        let steel = Steel::new(50.0, 65.0);
+        for shear in [Shear::major(), Shear::minor()] {
+            for shape in crate::aisc_shapes::aisc_database::snapshot() {
+                let strength = shape.shear_strength(&steel, &shear);
+                assert!(
+                    strength.vn().is_finite() && strength.vn() > 0.0 && strength.cv <= 1.0,
+                    "{} has Vn = {} for {shear:?}",
+                    shape.aisc_manual_label(),
+                    strength.vn()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,554 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::aisc_shapes::{
+    InvalidLengthRangeError, SectionProperties, Steel, UnknownDesignTableError,
+};
+use crate::design::{
+    Bending, CompressionDesign, EffectiveLengths, FlexuralDesign, ResistanceFactors, Shear,
+    ShearDesign,
+};
+
+/// The tables are given in ft and kip-ft, like those of the Manual
+const INCHES_PER_FOOT: f64 = 12.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The available strength tables of the AISC Steel Construction Manual
+pub enum DesignTableKind {
+    /// Table 3-2, W-shapes selection by Zx
+    ZxSelection,
+    /// Table 4-1, available strength in axial compression versus effective length
+    ColumnStrength,
+    /// Table 3-10, available moment versus unbraced length
+    MomentVsUnbracedLength,
+}
+
+impl DesignTableKind {
+    /// Every design table
+    pub const ALL: [DesignTableKind; 3] = [
+        DesignTableKind::ZxSelection,
+        DesignTableKind::ColumnStrength,
+        DesignTableKind::MomentVsUnbracedLength,
+    ];
+
+    /// The number of the table in the Manual, e.g. `3-2`
+    pub fn number(&self) -> &'static str {
+        match self {
+            DesignTableKind::ZxSelection => "3-2",
+            DesignTableKind::ColumnStrength => "4-1",
+            DesignTableKind::MomentVsUnbracedLength => "3-10",
+        }
+    }
+
+    /// The name of the table in snake case, e.g. `zx_selection`
+    pub fn name(&self) -> &'static str {
+        match self {
+            DesignTableKind::ZxSelection => "zx_selection",
+            DesignTableKind::ColumnStrength => "column_strength",
+            DesignTableKind::MomentVsUnbracedLength => "moment_vs_unbraced_length",
+        }
+    }
+}
+
+impl FromStr for DesignTableKind {
+    type Err = UnknownDesignTableError;
+    /// Parses the number of the table (e.g. `4-1`) or its name (e.g. `column-strength`)
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.trim().to_lowercase();
+        DesignTableKind::ALL
+            .into_iter()
+            .find(|kind| kind.number() == name || kind.name() == name.replace('-', "_"))
+            .ok_or(UnknownDesignTableError { name })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The lengths tabulated by Tables 4-1 and 3-10, ft
+pub struct LengthRange {
+    /// The first length, ft
+    pub start: f64,
+    /// The last length, ft
+    pub end: f64,
+    /// The increment between lengths, ft
+    pub step: f64,
+}
+
+impl LengthRange {
+    /// The lengths from `start` to `end` by `step`, ft
+    pub fn new(start: f64, end: f64, step: f64) -> Self {
+        LengthRange { start, end, step }
+    }
+
+    /// The largest number of lengths of a range
+    pub const MAX_LENGTHS: usize = 100;
+
+    /// Checks that the bounds are finite, that `start` isn't negative, that `step` is positive,
+    /// that `end` isn't before `start` and that the range has at most [LengthRange::MAX_LENGTHS] lengths
+    pub fn validate(&self) -> Result<(), InvalidLengthRangeError> {
+        let invalid = |bound, value| Err(InvalidLengthRangeError { bound, value });
+        if !self.start.is_finite() || self.start < 0.0 {
+            return invalid("start", self.start);
+        }
+        if !self.end.is_finite() || self.end < self.start {
+            return invalid("end", self.end);
+        }
+        if !self.step.is_finite() || self.step <= 0.0 || self.steps() >= Self::MAX_LENGTHS as f64 {
+            return invalid("step", self.step);
+        }
+        Ok(())
+    }
+
+    /// The lengths of the range, ft, `end` included when it falls on a step,
+    /// none when the range isn't valid, see [LengthRange::validate]
+    pub fn lengths(&self) -> Vec<f64> {
+        if self.validate().is_err() {
+            return Vec::new();
+        }
+        let count = self.steps() as usize;
+        (0..=count)
+            .map(|i| self.start + i as f64 * self.step)
+            .collect()
+    }
+
+    /// The number of whole steps from `start` to `end`
+    fn steps(&self) -> f64 {
+        ((self.end - self.start) / self.step + 1e-9).floor()
+    }
+}
+
+impl Default for LengthRange {
+    /// 0 to 40 ft by 2 ft
+    fn default() -> Self {
+        LengthRange::new(0.0, 40.0, 2.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+/// A cell of a design table
+pub enum TableValue {
+    /// A designation or a heading
+    Text(String),
+    /// A property or a strength
+    Number(f64),
+    /// A value that doesn't apply to the shape
+    Empty,
+}
+
+impl TableValue {
+    fn optional(value: Option<f64>) -> Self {
+        value.map_or(TableValue::Empty, TableValue::Number)
+    }
+
+    /// The value as text, numbers with at most three decimals
+    pub fn text(&self) -> String {
+        match self {
+            TableValue::Text(text) => text.clone(),
+            TableValue::Number(value) => number(*value),
+            TableValue::Empty => String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A design table, with a row per shape for Table 3-2 and a row per length for Tables 4-1 and 3-10
+pub struct DesignTable {
+    /// The table
+    pub kind: DesignTableKind,
+    /// The title of the table, with its steel
+    pub title: String,
+    /// The heading of each column, with its units
+    pub columns: Vec<String>,
+    /// The values of each row, in the order of the columns
+    pub rows: Vec<Vec<TableValue>>,
+}
+
+impl DesignTable {
+    /// Writes the table as CSV, with the headings as the first record
+    #[cfg(feature = "csv")]
+    pub fn to_csv(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record(&self.columns)?;
+        for row in &self.rows {
+            writer.write_record(row.iter().map(TableValue::text))?;
+        }
+        Ok(String::from_utf8(writer.into_inner()?)?)
+    }
+
+    /// Writes the table as an HTML `<table>` captioned with its title
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<table>\n");
+        let _ = writeln!(html, "<caption>{}</caption>", escape(&self.title));
+        html.push_str("<thead><tr>");
+        for column in &self.columns {
+            let _ = write!(html, "<th>{}</th>", escape(column));
+        }
+        html.push_str("</tr></thead>\n<tbody>\n");
+        for row in &self.rows {
+            html.push_str("<tr>");
+            for value in row {
+                let _ = write!(html, "<td>{}</td>", escape(&value.text()));
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</tbody>\n</table>\n");
+        html
+    }
+}
+
+/// Generates a design table from the given shapes,
+/// with the lengths of the range for Tables 4-1 and 3-10
+pub fn design_table<T>(
+    kind: DesignTableKind,
+    shapes: &[T],
+    steel: &Steel,
+    lengths: &LengthRange,
+) -> DesignTable
+where
+    T: SectionProperties + FlexuralDesign + CompressionDesign + ShearDesign,
+{
+    match kind {
+        DesignTableKind::ZxSelection => zx_selection_table(shapes, steel),
+        DesignTableKind::ColumnStrength => column_strength_table(shapes, steel, lengths),
+        DesignTableKind::MomentVsUnbracedLength => {
+            moment_vs_unbraced_length_table(shapes, steel, lengths)
+        }
+    }
+}
+
+/// Table 3-2: the shapes ordered by Zx, with their available flexural strengths at Lb ≤ Lp,
+/// which account for flange local buckling, and at Lr with Cb = 1,
+/// the factor BF = (Mp - Mr)/(Lr - Lp), Lp, Lr, Ix and the available shear strengths
+pub fn zx_selection_table<T>(shapes: &[T], steel: &Steel) -> DesignTable
+where
+    T: SectionProperties + FlexuralDesign + ShearDesign,
+{
+    let factors = ResistanceFactors::FLEXURE;
+    let mut shapes: Vec<&T> = shapes.iter().collect();
+    shapes.sort_by(|a, b| b.zx().total_cmp(&a.zx()));
+    let rows = shapes
+        .into_iter()
+        .map(|shape| {
+            let flexure = shape.flexural_strength(steel, &Bending::major(0.0, 1.0));
+            let shear = shape.shear_strength(steel, &Shear::major());
+            let mp = flexure.mn() / INCHES_PER_FOOT;
+            let mr = 0.7 * steel.fy * shape.sx() / INCHES_PER_FOOT;
+            let bf = match (flexure.lp, flexure.lr) {
+                (Some(lp), Some(lr)) if lr > lp => Some((mp - mr) / (lr - lp) * INCHES_PER_FOOT),
+                _ => None,
+            };
+            vec![
+                TableValue::Text(String::from(shape.aisc_manual_label())),
+                TableValue::Number(shape.zx()),
+                TableValue::Number(mp / factors.omega),
+                TableValue::Number(factors.phi * mp),
+                TableValue::Number(mr / factors.omega),
+                TableValue::Number(factors.phi * mr),
+                TableValue::optional(bf.map(|bf| bf / factors.omega)),
+                TableValue::optional(bf.map(|bf| factors.phi * bf)),
+                TableValue::optional(flexure.lp.map(|lp| lp / INCHES_PER_FOOT)),
+                TableValue::optional(flexure.lr.map(|lr| lr / INCHES_PER_FOOT)),
+                TableValue::Number(shape.ix()),
+                TableValue::Number(shear.vn_omega()),
+                TableValue::Number(shear.phi_vn()),
+            ]
+        })
+        .collect();
+    DesignTable {
+        kind: DesignTableKind::ZxSelection,
+        title: format!("Table 3-2, Selection by Zx, Fy = {} ksi", number(steel.fy)),
+        columns: [
+            "Shape",
+            "Zx, in.³",
+            "Mpx/Ωb, kip-ft",
+            "φbMpx, kip-ft",
+            "Mrx/Ωb, kip-ft",
+            "φbMrx, kip-ft",
+            "BFx/Ωb, kips",
+            "φbBFx, kips",
+            "Lp, ft",
+            "Lr, ft",
+            "Ix, in.⁴",
+            "Vnx/Ωv, kips",
+            "φvVnx, kips",
+        ]
+        .map(String::from)
+        .to_vec(),
+        rows,
+    }
+}
+
+/// Table 4-1: the available compressive strength of each shape at each effective length,
+/// the same about every axis
+pub fn column_strength_table<T>(shapes: &[T], steel: &Steel, lengths: &LengthRange) -> DesignTable
+where
+    T: SectionProperties + CompressionDesign,
+{
+    strength_vs_length_table(
+        DesignTableKind::ColumnStrength,
+        format!(
+            "Table 4-1, Available Strength in Axial Compression, kips, Fy = {} ksi",
+            number(steel.fy)
+        ),
+        "Lc, ft",
+        ["Pn/Ωc", "φcPn"],
+        shapes,
+        lengths,
+        |shape, lc| {
+            let strength = shape.compressive_strength(steel, &EffectiveLengths::uniform(lc));
+            (strength.pn_omega(), strength.phi_pn())
+        },
+    )
+}
+
+/// Table 3-10: the available flexural strength of each shape at each unbraced length, with Cb = 1
+pub fn moment_vs_unbraced_length_table<T>(
+    shapes: &[T],
+    steel: &Steel,
+    lengths: &LengthRange,
+) -> DesignTable
+where
+    T: SectionProperties + FlexuralDesign,
+{
+    strength_vs_length_table(
+        DesignTableKind::MomentVsUnbracedLength,
+        format!(
+            "Table 3-10, Available Moment vs. Unbraced Length, kip-ft, Fy = {} ksi, Cb = 1",
+            number(steel.fy)
+        ),
+        "Lb, ft",
+        ["Mn/Ωb", "φbMn"],
+        shapes,
+        lengths,
+        |shape, lb| {
+            let strength = shape.flexural_strength(steel, &Bending::major(lb, 1.0));
+            (
+                strength.mn_omega() / INCHES_PER_FOOT,
+                strength.phi_mn() / INCHES_PER_FOOT,
+            )
+        },
+    )
+}
+
+/// A table with a row per length and the ASD and LRFD strengths of each shape as columns
+fn strength_vs_length_table<T: SectionProperties>(
+    kind: DesignTableKind,
+    title: String,
+    length_column: &str,
+    strength_columns: [&str; 2],
+    shapes: &[T],
+    lengths: &LengthRange,
+    strength: impl Fn(&T, f64) -> (f64, f64),
+) -> DesignTable {
+    let mut columns = vec![String::from(length_column)];
+    for shape in shapes {
+        for column in strength_columns {
+            columns.push(format!("{} {column}", shape.aisc_manual_label()));
+        }
+    }
+    let rows = lengths
+        .lengths()
+        .into_iter()
+        .map(|length| {
+            let mut row = vec![TableValue::Number(length)];
+            for shape in shapes {
+                let (allowable, design) = strength(shape, length * INCHES_PER_FOOT);
+                row.push(TableValue::Number(allowable));
+                row.push(TableValue::Number(design));
+            }
+            row
+        })
+        .collect();
+    DesignTable {
+        kind,
+        title,
+        columns,
+        rows,
+    }
+}
+
+/// Formats a number with at most three decimals, without trailing zeros
+fn number(value: f64) -> String {
+    let formatted = format!("{value:.3}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" => String::from("0"),
+        _ => String::from(trimmed),
+    }
+}
+
+/// Escapes the characters of a designation or heading that are markup in HTML
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_parse_from_their_number_or_name() {
+        assert_eq!(Ok(DesignTableKind::ColumnStrength), "4-1".parse());
+        assert_eq!(
+            Ok(DesignTableKind::MomentVsUnbracedLength),
+            "Moment-Vs-Unbraced-Length".parse()
+        );
+        assert_eq!(
+            Err(UnknownDesignTableError {
+                name: String::from("6-1")
+            }),
+            "6-1".parse::<DesignTableKind>()
+        );
+    }
+
+    #[test]
+    fn length_ranges_include_their_end() {
+        assert_eq!(
+            vec![0.0, 2.5, 5.0],
+            LengthRange::new(0.0, 5.0, 2.5).lengths()
+        );
+        assert_eq!(vec![6.0, 10.0], LengthRange::new(6.0, 12.0, 4.0).lengths());
+        assert_eq!(21, LengthRange::default().lengths().len());
+        assert_eq!(vec![4.0], LengthRange::new(4.0, 4.0, 1.0).lengths());
+    }
+
+    #[test]
+    fn invalid_length_ranges_have_no_lengths() {
+        let invalid = |bound, value| Err(InvalidLengthRangeError { bound, value });
+        assert_eq!(Ok(()), LengthRange::new(0.0, 99.0, 1.0).validate());
+        assert_eq!(
+            invalid("step", 1.0),
+            LengthRange::new(0.0, 100.0, 1.0).validate()
+        );
+        assert_eq!(
+            invalid("step", 0.0),
+            LengthRange::new(0.0, 10.0, 0.0).validate()
+        );
+        assert_eq!(
+            invalid("end", 5.0),
+            LengthRange::new(10.0, 5.0, 1.0).validate()
+        );
+        assert_eq!(
+            invalid("start", -2.0),
+            LengthRange::new(-2.0, 5.0, 1.0).validate()
+        );
+        let range = LengthRange::new(0.0, f64::INFINITY, 1.0);
+        assert_eq!(invalid("end", f64::INFINITY), range.validate());
+        assert!(range.lengths().is_empty());
+        assert!(LengthRange::new(0.0, 1e12, 1e-3).lengths().is_empty());
+    }
+
+    fn table() -> DesignTable {
+        DesignTable {
+            kind: DesignTableKind::ZxSelection,
+            title: String::from("Shapes < 10 in."),
+            columns: vec![String::from("Shape"), String::from("Lp, ft")],
+            rows: vec![
+                vec![
+                    TableValue::Text(String::from("W8X10")),
+                    TableValue::Number(2.5),
+                ],
+                vec![
+                    TableValue::Text(String::from("HSS4X4X1/4")),
+                    TableValue::Empty,
+                ],
+            ],
+        }
+    }
+
+    #[test]
+    fn tables_render_as_html() {
+        let html = table().to_html();
+        assert!(html.starts_with("<table>\n<caption>Shapes &lt; 10 in.</caption>"));
+        assert!(html.contains("<tr><td>W8X10</td><td>2.5</td></tr>"));
+        assert!(html.contains("<tr><td>HSS4X4X1/4</td><td></td></tr>"));
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn tables_export_as_csv() {
+        assert_eq!(
+            "Shape,\"Lp, ft\"\nW8X10,2.5\nHSS4X4X1/4,\n",
+            table().to_csv().unwrap()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn tables_export_as_json() {
+        let json = serde_json::to_value(table()).unwrap();
+        assert_eq!("zx_selection", json["kind"]);
+        assert_eq!(serde_json::json!(["W8X10", 2.5]), json["rows"][0]);
+        assert_eq!(serde_json::Value::Null, json["rows"][1][1]);
+    }
+
+    #[cfg(feature = "snapshot")]
+    mod manual {
+        use super::*;
+        use crate::aisc_shapes::WideFlange;
+        use crate::aisc_shapes::aisc_database::{shapes_of, snapshot};
+
+        fn wide_flanges(labels: &[&str]) -> Vec<WideFlange> {
+            shapes_of::<WideFlange>(snapshot().iter().cloned())
+                .into_iter()
+                .filter(|shape| labels.contains(&shape.aisc_manual_label.as_str()))
+                .collect()
+        }
+
+        fn assert_close(expected: f64, actual: &TableValue, tolerance: f64) {
+            let TableValue::Number(actual) = actual else {
+                panic!("expected {expected}, got {actual:?}");
+            };
+            assert!(
+                (expected - actual).abs() <= tolerance,
+                "expected {expected}, got {actual}"
+            );
+        }
+
+        #[test]
+        fn table_3_2_orders_shapes_by_zx() {
+            let shapes = wide_flanges(&["W14X90", "W18X50", "W21X48"]);
+            let table = zx_selection_table(&shapes, &Steel::A992);
+            assert_eq!(13, table.columns.len());
+            let labels: Vec<String> = table.rows.iter().map(|row| row[0].text()).collect();
+            assert_eq!(vec!["W14X90", "W21X48", "W18X50"], labels);
+            // W14X90: φbMpx = 574 kip-ft (flange local buckling), φbMrx = 375 kip-ft, φvVnx = 185 kips
+            let w14x90 = &table.rows[0];
+            assert_close(574.0, &w14x90[3], 1.0);
+            assert_close(375.0, &w14x90[5], 1.0);
+            assert_close(185.0, &w14x90[12], 0.5);
+        }
+
+        #[test]
+        fn table_4_1_tabulates_strength_against_effective_length() {
+            let shapes = wide_flanges(&["W14X90"]);
+            let table =
+                column_strength_table(&shapes, &Steel::A992, &LengthRange::new(0.0, 15.0, 15.0));
+            assert_eq!(vec!["Lc, ft", "W14X90 Pn/Ωc", "W14X90 φcPn"], table.columns);
+            assert_eq!(2, table.rows.len());
+            assert_close(50.0 * 26.5 * 0.9, &table.rows[0][2], 1e-9);
+            assert_close(15.0, &table.rows[1][0], 0.0);
+            assert_close(1000.0, &table.rows[1][2], 5.0);
+        }
+
+        #[test]
+        fn table_3_10_tabulates_moment_against_unbraced_length() {
+            let shapes = wide_flanges(&["W18X50"]);
+            let table = design_table(
+                DesignTableKind::MomentVsUnbracedLength,
+                &shapes,
+                &Steel::A992,
+                &LengthRange::new(10.0, 30.0, 20.0),
+            );
+            assert_close(324.0, &table.rows[0][2], 1.0);
+            assert_close(101.1, &table.rows[1][2], 0.5);
+        }
+    }
+}
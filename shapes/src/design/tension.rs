@@ -0,0 +1,133 @@
+use crate::aisc_shapes::{SectionProperties, Steel};
+use crate::design::{ResistanceFactors, Strength};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The net section of a tension member at its connections, which depends on the
+/// holes and the layout of the connection rather than on the shape alone
+pub struct NetSection {
+    /// (An) Net area, in.², taken as the gross area when omitted
+    pub an: Option<f64>,
+    /// (U) Shear lag factor of Table D3.1
+    pub u: f64,
+}
+
+impl NetSection {
+    /// A net section with the given net area, in.², and shear lag factor
+    pub fn new(an: f64, u: f64) -> Self {
+        NetSection { an: Some(an), u }
+    }
+
+    /// The gross section of members connected through every element without holes (U = 1.0)
+    pub fn gross() -> Self {
+        NetSection { an: None, u: 1.0 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The limit states of AISC 360 Chapter D
+pub enum TensileLimitState {
+    /// Tensile yielding in the gross section (D2-1)
+    TensileYielding,
+    /// Tensile rupture in the net section (D2-2)
+    TensileRupture,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The tensile strength of a member, kips
+pub struct TensileStrength {
+    /// (Pn) The nominal tensile strength and the limit state governing it, kips
+    pub strength: Strength<TensileLimitState>,
+    /// (Ae = AnU) Effective net area (D3-1), in.²
+    pub ae: f64,
+}
+
+impl TensileStrength {
+    /// (Pn) Nominal tensile strength, kips
+    pub fn pn(&self) -> f64 {
+        self.strength.nominal
+    }
+
+    /// (φtPn) Design tensile strength for LRFD, kips
+    pub fn phi_pn(&self) -> f64 {
+        self.strength.design
+    }
+
+    /// (Pn/Ωt) Allowable tensile strength for ASD, kips
+    pub fn pn_omega(&self) -> f64 {
+        self.strength.allowable
+    }
+}
+
+/// Computes the nominal tensile strength of members per AISC 360 Chapter D:
+/// yielding of the gross section and rupture of the effective net section.
+///
+/// The limit states have different resistance factors, so the governing limit state is
+/// the one with the lowest design strength. Block shear (J4.3) is a limit state of the connection.
+pub trait TensionDesign {
+    /// The tensile strength of the member made of the given steel
+    fn tensile_strength(&self, steel: &Steel, net_section: &NetSection) -> TensileStrength;
+}
+
+impl<T: SectionProperties> TensionDesign for T {
+    fn tensile_strength(&self, steel: &Steel, net_section: &NetSection) -> TensileStrength {
+        let ag = self.a_upper();
+        let ae = net_section.an.unwrap_or(ag) * net_section.u;
+        TensileStrength {
+            strength: Strength::governed_by_design(vec![
+                (
+                    TensileLimitState::TensileYielding,
+                    steel.fy * ag,
+                    ResistanceFactors::TENSILE_YIELDING,
+                ),
+                (
+                    TensileLimitState::TensileRupture,
+                    steel.fu * ae,
+                    ResistanceFactors::TENSILE_RUPTURE,
+                ),
+            ]),
+            ae,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aisc_shapes::Angle;
+    use crate::aisc_shapes::ShapeProperty::*;
+    use crate::aisc_shapes::test_support::shape;
+
+    fn l4x4x1_2() -> Angle {
+        shape("L4X4X1/2", &[(AUpper, 3.75)])
+    }
+
+    #[test]
+    fn gross_sections_yield() {
+        let strength = l4x4x1_2().tensile_strength(&Steel::A36, &NetSection::gross());
+        assert_eq!(
+            TensileLimitState::TensileYielding,
+            strength.strength.governing
+        );
+        assert_eq!(36.0 * 3.75, strength.pn());
+        assert_eq!(3.75, strength.ae);
+    }
+
+    #[test]
+    fn net_sections_rupture() {
+        // One line of 3/4 in. bolts with holes 1/16 in. larger, and U = 0.80
+        let net_section = NetSection::new(3.75 - 0.5 * (13.0 / 16.0 + 1.0 / 16.0), 0.80);
+        let strength = l4x4x1_2().tensile_strength(&Steel::A36, &net_section);
+        assert_eq!(
+            TensileLimitState::TensileRupture,
+            strength.strength.governing
+        );
+        assert!((2.65 - strength.ae).abs() < 1e-12);
+        assert!((0.75 * 58.0 * 2.65 - strength.phi_pn()).abs() < 1e-9);
+        assert!((58.0 * 2.65 / 2.0 - strength.pn_omega()).abs() < 1e-9);
+        assert_eq!(2, strength.strength.limit_states.len());
+    }
+}
@@ -1,4 +1,26 @@
 #![deny(missing_docs)]
 //! This module is responsible for creating and modeling
 //! AISC steel shapes
+//!
+//! # Features
+//!
+//! - `serde`: derives `Serialize` and `Deserialize` for every shape
+//!   and for [aisc_shapes::ShapeBuilder].
+//! - `csv`: adds `aisc_shapes::aisc_database`, which reads shapes from
+//!   a CSV export of the AISC shapes database.
+//! - `snapshot`: compiles the AISC shapes database v16.0 into the library,
+//!   available through `aisc_shapes::aisc_database::snapshot`. Implies `csv`.
+//!
+//! # Wire format
+//!
+//! With the `serde` feature enabled each shape serializes as a flat map
+//! whose keys are the snake_case field names of the struct, exactly as
+//! they appear in the AISC shapes database columns (e.g. `edi_std_nomenclature`,
+//! `w_upper`, `bf_2tf`, `j_upper`). Values are in US customary units as
+//! documented on each field. Optional properties such as `wgo`, `wgi`
+//! or `swb` are always present and serialize as `null` when the shape
+//! does not define them; when deserializing they may also be omitted.
+//! Field names are part of the public API and serializing then deserializing
+//! a shape yields an identical value.
 pub mod aisc_shapes;
+pub mod design;
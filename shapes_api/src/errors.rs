@@ -0,0 +1,234 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use shapes::aisc_shapes::{
+    RepositoryError, UnknownDesignTableError, UnknownShapeFamilyError, UnknownShapePropertyError,
+    UnknownSortKeyError,
+};
+use std::error::Error;
+use std::fmt;
+
+/// An error returned by a route handler, wraps the
+/// error returned by the underlying shape repository
+#[derive(Debug)]
+pub struct ApiError(Box<dyn Error>);
+
+/// An error returned when a query parameter has a value that cannot be parsed
+#[derive(Debug)]
+pub struct InvalidQueryParameterError {
+    /// The name of the query parameter
+    pub name: String,
+    /// The value that could not be parsed
+    pub value: String,
+}
+
+impl fmt::Display for InvalidQueryParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = &self.name;
+        let value = &self.value;
+        write!(f, "{value} is not a valid value for the {name} parameter.")
+    }
+}
+
+impl Error for InvalidQueryParameterError {}
+
+/// An error returned when no shape satisfies the requirements of a request
+#[derive(Debug)]
+pub struct NoMatchingShapeError;
+
+impl fmt::Display for NoMatchingShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No shape satisfies the requirements.")
+    }
+}
+
+impl Error for NoMatchingShapeError {}
+
+/// An error returned when no shape has a designation, suggesting the closest designations
+#[derive(Debug)]
+pub struct ShapeNotFoundError {
+    /// The designation that was looked up
+    pub designation: String,
+    /// The AISC Manual Labels of the shapes closest to the designation
+    pub suggestions: Vec<String>,
+}
+
+impl fmt::Display for ShapeNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let designation = &self.designation;
+        match self.suggestions.as_slice() {
+            [] => write!(f, "No shape was found for {designation}."),
+            suggestions => write!(
+                f,
+                "No shape was found for {designation}, did you mean {}?",
+                suggestions.join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for ShapeNotFoundError {}
+
+/// The JSON body returned to the client when a request fails,
+/// with the designations to suggest when a shape could not be found
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<String>,
+}
+
+/// The message returned in place of errors that are not caused by the request
+const INTERNAL_SERVER_ERROR_MESSAGE: &str = "The request could not be completed.";
+
+impl ApiError {
+    /// The HTTP status code that best describes the error,
+    /// a shape that could not be found maps to `404 Not Found`
+    pub fn status_code(&self) -> StatusCode {
+        if self.0.is::<UnknownShapeFamilyError>()
+            || self.0.is::<UnknownShapePropertyError>()
+            || self.0.is::<UnknownDesignTableError>()
+            || self.0.is::<UnknownSortKeyError>()
+            || self.0.is::<InvalidQueryParameterError>()
+        {
+            return StatusCode::BAD_REQUEST;
+        }
+        if self.0.is::<NoMatchingShapeError>() || self.0.is::<ShapeNotFoundError>() {
+            return StatusCode::NOT_FOUND;
+        }
+        match self.0.downcast_ref::<RepositoryError>() {
+            Some(RepositoryError::NotFound { .. }) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<Box<dyn Error>> for ApiError {
+    fn from(err: Box<dyn Error>) -> Self {
+        ApiError(err)
+    }
+}
+
+impl From<RepositoryError> for ApiError {
+    fn from(err: RepositoryError) -> Self {
+        ApiError(Box::new(err))
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+        let error = if status == StatusCode::INTERNAL_SERVER_ERROR {
+            // The underlying error may describe the database, it is only logged
+            eprintln!("error: {}", self.0);
+            String::from(INTERNAL_SERVER_ERROR_MESSAGE)
+        } else {
+            self.0.to_string()
+        };
+        let body = ErrorBody {
+            error,
+            suggestions: self
+                .0
+                .downcast_ref::<ShapeNotFoundError>()
+                .map(|err| err.suggestions.clone())
+                .unwrap_or_default(),
+        };
+        (status, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shapes::aisc_shapes::MissingPropertyError;
+
+    #[test]
+    fn shape_not_found_maps_to_not_found() {
+        let err = ApiError::from(RepositoryError::NotFound {
+            key: String::from("W14X91"),
+        });
+        assert_eq!(StatusCode::NOT_FOUND, err.status_code());
+    }
+
+    #[test]
+    fn no_matching_shape_maps_to_not_found() {
+        let err = ApiError::from(Box::new(NoMatchingShapeError) as Box<dyn Error>);
+        assert_eq!(StatusCode::NOT_FOUND, err.status_code());
+    }
+
+    #[tokio::test]
+    async fn shapes_not_found_suggest_the_closest_designations() {
+        let err = ApiError::from(Box::new(ShapeNotFoundError {
+            designation: String::from("W14X91"),
+            suggestions: vec![String::from("W14X90"), String::from("W14X99")],
+        }) as Box<dyn Error>);
+        assert_eq!(StatusCode::NOT_FOUND, err.status_code());
+        let response = err.into_response();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(
+            r#"{"error":"No shape was found for W14X91, did you mean W14X90, W14X99?","suggestions":["W14X90","W14X99"]}"#,
+            String::from_utf8(body.to_vec()).unwrap()
+        );
+
+        let err = ShapeNotFoundError {
+            designation: String::from("X"),
+            suggestions: Vec::new(),
+        };
+        assert_eq!("No shape was found for X.", err.to_string());
+    }
+
+    #[test]
+    fn unknown_family_maps_to_bad_request() {
+        let err = "beams"
+            .parse::<shapes::aisc_shapes::ShapeFamily>()
+            .unwrap_err();
+        let err = ApiError::from(Box::new(err) as Box<dyn Error>);
+        assert_eq!(StatusCode::BAD_REQUEST, err.status_code());
+    }
+
+    #[test]
+    fn invalid_query_parameters_map_to_bad_request() {
+        let err = "depth"
+            .parse::<shapes::aisc_shapes::ShapeProperty>()
+            .unwrap_err();
+        let err = ApiError::from(Box::new(err) as Box<dyn Error>);
+        assert_eq!(StatusCode::BAD_REQUEST, err.status_code());
+        let err = "6-1"
+            .parse::<shapes::design::DesignTableKind>()
+            .unwrap_err();
+        let err = ApiError::from(Box::new(err) as Box<dyn Error>);
+        assert_eq!(StatusCode::BAD_REQUEST, err.status_code());
+        let err = InvalidQueryParameterError {
+            name: String::from("limit"),
+            value: String::from("ten"),
+        };
+        let err = ApiError::from(Box::new(err) as Box<dyn Error>);
+        assert_eq!(StatusCode::BAD_REQUEST, err.status_code());
+    }
+
+    #[tokio::test]
+    async fn other_errors_map_to_internal_server_error() {
+        let err = ApiError::from(RepositoryError::Database(Box::new(
+            sqlx::Error::PoolTimedOut,
+        )));
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, err.status_code());
+        let response = err.into_response();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(
+            r#"{"error":"The request could not be completed."}"#,
+            String::from_utf8(body.to_vec()).unwrap()
+        );
+        let err = ApiError::from(RepositoryError::from(MissingPropertyError::from("W")));
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, err.status_code());
+        let err = ApiError::from(RepositoryError::InvalidRow {
+            column: String::from("w_upper"),
+            source: "not a number".into(),
+        });
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, err.status_code());
+    }
+}
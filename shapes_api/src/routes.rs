@@ -0,0 +1,1488 @@
+use crate::errors::{
+    ApiError, InvalidQueryParameterError, NoMatchingShapeError, ShapeNotFoundError,
+};
+use axum::Json;
+use axum::Router;
+use axum::extract::{Path, Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use serde::{Deserialize, Serialize};
+use shape_repositories::repositories::*;
+use shapes::aisc_shapes::{
+    AiscShape, DesignationMatch, DesignationSearch, PageRequest, RepositoryError,
+    RoundShapeRepository, SectionProperties, ShapeComparison, ShapeFamily, ShapePage,
+    ShapeProperty, ShapeQuery, ShapeRepository, ShapeSelection, SortDirection, SortKey, Steel, svg,
+};
+use shapes::design::{
+    DesignCheck, DesignMethod, DesignTableKind, EffectiveLengths, LengthRange, MemberCheck,
+    NetSection, RequiredStrengths, design_table,
+};
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::sync::Arc;
+
+/// Builds the application router, nesting the routes
+/// for every shape family under its own path
+pub fn app(pool: Arc<PgPool>) -> Router {
+    let catalog = Arc::new(ShapeCatalogRepository::new(Arc::clone(&pool)));
+    Router::new()
+        .route("/shapes", get(search_shapes))
+        .route("/shapes/compare", get(compare_shapes))
+        .route("/shapes/search", get(search_designations))
+        .route("/shapes/{aisc_manual_label}/section.svg", get(section_svg))
+        .route("/shapes/{aisc_manual_label}/check", post(check_member))
+        .route("/tables/{table}", get(design_table_route))
+        .with_state(Arc::clone(&catalog))
+        .nest(
+            "/wide-flanges",
+            shape_routes(
+                WideFlangeRepository::new(Arc::clone(&pool)),
+                ShapeFamily::WideFlange,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/misc-beams",
+            shape_routes(
+                MiscBeamRepository::new(Arc::clone(&pool)),
+                ShapeFamily::MiscBeam,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/structural-beams",
+            shape_routes(
+                StructuralBeamRepository::new(Arc::clone(&pool)),
+                ShapeFamily::StructuralBeam,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/h-piles",
+            shape_routes(
+                HPileRepository::new(Arc::clone(&pool)),
+                ShapeFamily::HPile,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/cee-channels",
+            shape_routes(
+                CeeChannelRepository::new(Arc::clone(&pool)),
+                ShapeFamily::CeeChannel,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/misc-channels",
+            shape_routes(
+                MiscChannelRepository::new(Arc::clone(&pool)),
+                ShapeFamily::MiscChannel,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/angles",
+            shape_routes(
+                AngleRepository::new(Arc::clone(&pool)),
+                ShapeFamily::Angle,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/wide-flange-tees",
+            shape_routes(
+                WideFlangeTeeRepository::new(Arc::clone(&pool)),
+                ShapeFamily::WideFlangeTee,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/misc-tees",
+            shape_routes(
+                MiscTeeRepository::new(Arc::clone(&pool)),
+                ShapeFamily::MiscTee,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/structural-tees",
+            shape_routes(
+                StructuralTeeRepository::new(Arc::clone(&pool)),
+                ShapeFamily::StructuralTee,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/double-angles",
+            shape_routes(
+                DoubleAngleRepository::new(Arc::clone(&pool)),
+                ShapeFamily::DoubleAngle,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/hollow-structural-sections",
+            shape_routes(
+                HollowStructuralSectionRepository::new(Arc::clone(&pool)),
+                ShapeFamily::HollowStructuralSection,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/round-hollow-structural-sections",
+            round_shape_routes(
+                RoundHollowStructuralSectionRepository::new(Arc::clone(&pool)),
+                ShapeFamily::RoundHollowStructuralSection,
+                Arc::clone(&catalog),
+            ),
+        )
+        .nest(
+            "/pipes",
+            round_shape_routes(
+                PipeRepository::new(Arc::clone(&pool)),
+                ShapeFamily::Pipe,
+                Arc::clone(&catalog),
+            ),
+        )
+}
+
+/// Builds the routes for a shape family backed by a [ShapeRepository]
+fn shape_routes<R, T>(
+    repository: R,
+    family: ShapeFamily,
+    catalog: Arc<ShapeCatalogRepository>,
+) -> Router
+where
+    R: ShapeRepository<T> + Send + Sync + 'static,
+    T: Serialize + Send + 'static,
+{
+    let repository = Arc::new(repository);
+    Router::new()
+        .route("/", get(all_shapes::<R, T>))
+        .route("/lightest", get(lightest_shape::<R, T>))
+        .route("/page", get(page_shapes::<R, T>))
+        .route("/depth/{depth}", get(shapes_with_depth::<R, T>))
+        .route("/width/{width}", get(shapes_with_width::<R, T>))
+        .with_state(Arc::clone(&repository))
+        .merge(
+            Router::new()
+                .route(
+                    "/edi/{edi_std_nomenclature}",
+                    get(shape_with_edi_std_nomenclature::<R, T>),
+                )
+                .route(
+                    "/label/{aisc_manual_label}",
+                    get(shape_with_aisc_manual_label::<R, T>),
+                )
+                .with_state(Arc::new(FamilyLookup {
+                    repository,
+                    family,
+                    catalog,
+                })),
+        )
+}
+
+/// Builds the routes for a shape family backed by a [RoundShapeRepository]
+fn round_shape_routes<R, T>(
+    repository: R,
+    family: ShapeFamily,
+    catalog: Arc<ShapeCatalogRepository>,
+) -> Router
+where
+    R: RoundShapeRepository<T> + Send + Sync + 'static,
+    T: Serialize + Send + 'static,
+{
+    let repository = Arc::new(repository);
+    Router::new()
+        .route("/", get(all_round_shapes::<R, T>))
+        .route("/lightest", get(lightest_round_shape::<R, T>))
+        .route("/page", get(page_round_shapes::<R, T>))
+        .route("/diameter/{diameter}", get(shapes_with_diameter::<R, T>))
+        .with_state(Arc::clone(&repository))
+        .merge(
+            Router::new()
+                .route(
+                    "/edi/{edi_std_nomenclature}",
+                    get(round_shape_with_edi_std_nomenclature::<R, T>),
+                )
+                .route(
+                    "/label/{aisc_manual_label}",
+                    get(round_shape_with_aisc_manual_label::<R, T>),
+                )
+                .with_state(Arc::new(FamilyLookup {
+                    repository,
+                    family,
+                    catalog,
+                })),
+        )
+}
+
+/// The state of the designation lookups of a shape family: the repository of the family
+/// and the catalog suggesting the closest designations of the family when a lookup fails
+struct FamilyLookup<R> {
+    repository: Arc<R>,
+    family: ShapeFamily,
+    catalog: Arc<ShapeCatalogRepository>,
+}
+
+impl<R> FamilyLookup<R> {
+    /// The shape that was found, or a [ShapeNotFoundError] suggesting
+    /// the closest designations of the family when there is none
+    async fn found<T>(
+        &self,
+        result: Result<T, RepositoryError>,
+        designation: String,
+    ) -> Result<T, ApiError> {
+        match result {
+            Err(RepositoryError::NotFound { .. }) => Err(shape_not_found(
+                self.catalog
+                    .suggestions_in_family(&designation, self.family)
+                    .await?,
+                designation,
+            )),
+            result => Ok(result?),
+        }
+    }
+}
+
+/// Query parameters accepted when searching shapes across every family,
+/// `families` is a comma separated list of family names (e.g. `wide_flange,h_pile`).
+/// The bounds are parsed by [CatalogSearch::try_from] to reject them with a JSON body.
+#[derive(Deserialize)]
+struct CatalogSearchParams {
+    label_prefix: Option<String>,
+    families: Option<String>,
+    min_depth: Option<String>,
+    max_depth: Option<String>,
+    min_width: Option<String>,
+    max_width: Option<String>,
+    min_weight: Option<String>,
+    max_weight: Option<String>,
+}
+
+impl TryFrom<CatalogSearchParams> for CatalogSearch {
+    type Error = Box<dyn Error>;
+    fn try_from(params: CatalogSearchParams) -> Result<Self, Self::Error> {
+        Ok(CatalogSearch {
+            label_prefix: params.label_prefix,
+            families: parse_families(params.families.as_deref())?,
+            min_depth: parse_bound("min_depth", params.min_depth.as_deref())?,
+            max_depth: parse_bound("max_depth", params.max_depth.as_deref())?,
+            min_width: parse_bound("min_width", params.min_width.as_deref())?,
+            max_width: parse_bound("max_width", params.max_width.as_deref())?,
+            min_weight: parse_bound("min_weight", params.min_weight.as_deref())?,
+            max_weight: parse_bound("max_weight", params.max_weight.as_deref())?,
+        })
+    }
+}
+
+/// Parses an optional bound of a search, rejecting NaN which no value would satisfy
+fn parse_bound(name: &str, value: Option<&str>) -> Result<Option<f64>, Box<dyn Error>> {
+    match value {
+        Some(value) => match parse_param::<f64>(name, value)? {
+            bound if bound.is_nan() => Err(Box::new(InvalidQueryParameterError {
+                name: String::from(name),
+                value: String::from(value),
+            })),
+            bound => Ok(Some(bound)),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Parses a comma separated list of family names
+fn parse_families(families: Option<&str>) -> Result<Option<Vec<ShapeFamily>>, Box<dyn Error>> {
+    match families {
+        Some(families) => Ok(Some(
+            families
+                .split(',')
+                .map(|f| f.parse::<ShapeFamily>())
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
+        None => Ok(None),
+    }
+}
+
+/// Query parameters of the design tables: the yield stress `fy` (default 50 ksi),
+/// the shapes as for the catalog search (default the wide flanges), the lengths of
+/// Tables 4-1 and 3-10 in ft (`start`, `end` and `step`, default 0 to 40 by 2)
+/// and the `format` of the response (`json`, `csv` or `html`, default `json`).
+/// The numbers are parsed by [parse_param] to reject them with a JSON body.
+#[derive(Deserialize)]
+struct DesignTableParams {
+    fy: Option<String>,
+    label_prefix: Option<String>,
+    families: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    step: Option<String>,
+    format: Option<String>,
+}
+
+/// The formats design tables are exported as
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TableFormat {
+    Json,
+    Csv,
+    Html,
+}
+
+impl DesignTableParams {
+    /// The search for the shapes of the table
+    fn search(&self) -> Result<CatalogSearch, Box<dyn Error>> {
+        Ok(CatalogSearch {
+            label_prefix: self.label_prefix.clone(),
+            families: parse_families(self.families.as_deref())?
+                .or(Some(vec![ShapeFamily::WideFlange])),
+            ..CatalogSearch::new()
+        })
+    }
+
+    /// The lengths of Tables 4-1 and 3-10, rejecting ranges with no lengths
+    /// or more than [LengthRange::MAX_LENGTHS] of them
+    fn lengths(&self) -> Result<LengthRange, Box<dyn Error>> {
+        let defaults = LengthRange::default();
+        let length = |name: &str, value: Option<&str>, default: f64| match value {
+            Some(value) => parse_param(name, value),
+            None => Ok(default),
+        };
+        let lengths = LengthRange::new(
+            length("start", self.start.as_deref(), defaults.start)?,
+            length("end", self.end.as_deref(), defaults.end)?,
+            length("step", self.step.as_deref(), defaults.step)?,
+        );
+        match lengths.validate() {
+            Ok(()) => Ok(lengths),
+            Err(err) => Err(Box::new(InvalidQueryParameterError {
+                name: String::from(err.bound),
+                value: err.value.to_string(),
+            })),
+        }
+    }
+
+    /// The steel of the table, rejecting yield stresses that aren't positive
+    fn steel(&self) -> Result<Steel, Box<dyn Error>> {
+        match self.fy.as_deref() {
+            None => Ok(Steel::A992),
+            Some(fy) => {
+                let fy = positive_param("fy", parse_param("fy", fy)?)?;
+                Ok(Steel::new(fy, Steel::A992.fu))
+            }
+        }
+    }
+
+    fn format(&self) -> Result<TableFormat, Box<dyn Error>> {
+        match self.format.as_deref() {
+            None | Some("json") => Ok(TableFormat::Json),
+            Some("csv") => Ok(TableFormat::Csv),
+            Some("html") => Ok(TableFormat::Html),
+            Some(value) => Err(Box::new(InvalidQueryParameterError {
+                name: String::from("format"),
+                value: String::from(value),
+            })),
+        }
+    }
+}
+
+/// Query parameters of a comparison: `labels` is a comma separated list of
+/// the AISC Manual Labels of at least two shapes, the first being the baseline
+#[derive(Deserialize)]
+struct ComparisonParams {
+    labels: String,
+}
+
+impl ComparisonParams {
+    fn labels(&self) -> Result<Vec<String>, Box<dyn Error>> {
+        let labels: Vec<String> = self
+            .labels
+            .split(',')
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(String::from)
+            .collect();
+        if labels.len() < 2 {
+            return Err(Box::new(InvalidQueryParameterError {
+                name: String::from("labels"),
+                value: self.labels.clone(),
+            }));
+        }
+        Ok(labels)
+    }
+}
+
+/// Query parameters of a designation search: the partial or misspelled designation `q`,
+/// the largest number of matches `limit` and the largest edit distance `max_distance`
+/// of fuzzy matches, at most the number of characters of `q` besides whitespace,
+/// see [DesignationSearch::new] for their defaults.
+/// The numbers are parsed by [DesignationSearch::try_from] to reject them with a JSON body.
+#[derive(Deserialize)]
+struct DesignationSearchParams {
+    q: String,
+    limit: Option<String>,
+    max_distance: Option<String>,
+}
+
+impl TryFrom<DesignationSearchParams> for DesignationSearch {
+    type Error = Box<dyn Error>;
+    fn try_from(params: DesignationSearchParams) -> Result<Self, Self::Error> {
+        let mut search = DesignationSearch::new(&params.q);
+        if let Some(limit) = params.limit.as_deref() {
+            search = search.with_limit(parse_param("limit", limit)?);
+        }
+        if let Some(max_distance) = params.max_distance.as_deref() {
+            let length = params.q.chars().filter(|c| !c.is_whitespace()).count();
+            let max_distance: usize = parse_param("max_distance", max_distance)?;
+            if max_distance > length {
+                return Err(Box::new(InvalidQueryParameterError {
+                    name: String::from("max_distance"),
+                    value: max_distance.to_string(),
+                }));
+            }
+            search = search.with_max_distance(max_distance);
+        }
+        Ok(search)
+    }
+}
+
+/// The body of a member check: the steel as `fy` and `fu` in ksi, the design method
+/// (`lrfd` or `asd`), the design parameters of the member and its required strengths
+/// in kips and kip-in., parameters that are omitted take the defaults of [MemberCheck::new]
+#[derive(Deserialize)]
+struct MemberCheckRequest {
+    fy: f64,
+    fu: f64,
+    #[serde(default)]
+    method: DesignMethod,
+    lengths: Option<EffectiveLengths>,
+    lb: Option<f64>,
+    cb: Option<f64>,
+    net_section: Option<NetSection>,
+    lv: Option<f64>,
+    #[serde(default)]
+    forces: RequiredStrengths,
+}
+
+impl TryFrom<&MemberCheckRequest> for MemberCheck {
+    type Error = Box<dyn Error>;
+    /// Rejects stresses, `cb`, `an` and `lv` that aren't positive, lengths that are negative
+    /// and `u` outside (0, 1], see [net_area_param] for net areas larger than the gross area
+    fn try_from(request: &MemberCheckRequest) -> Result<Self, Self::Error> {
+        let fy = positive_param("fy", request.fy)?;
+        let fu = positive_param("fu", request.fu)?;
+        let defaults = MemberCheck::new(Steel::new(fy, fu), request.method);
+        let lengths = request.lengths.unwrap_or(defaults.lengths);
+        non_negative_param("lcx", lengths.lcx)?;
+        non_negative_param("lcy", lengths.lcy)?;
+        non_negative_param("lcz", lengths.lcz)?;
+        Ok(MemberCheck {
+            lengths,
+            lb: non_negative_param("lb", request.lb.unwrap_or(defaults.lb))?,
+            cb: positive_param("cb", request.cb.unwrap_or(defaults.cb))?,
+            net_section: net_section_param(request.net_section.unwrap_or(defaults.net_section))?,
+            lv: request.lv.map(|lv| positive_param("lv", lv)).transpose()?,
+            ..defaults
+        })
+    }
+}
+
+/// The net section if its net area is positive and its shear lag factor is in (0, 1]
+fn net_section_param(net_section: NetSection) -> Result<NetSection, Box<dyn Error>> {
+    if let Some(an) = net_section.an {
+        positive_param("an", an)?;
+    }
+    if positive_param("u", net_section.u)? > 1.0 {
+        return Err(Box::new(InvalidQueryParameterError {
+            name: String::from("u"),
+            value: net_section.u.to_string(),
+        }));
+    }
+    Ok(net_section)
+}
+
+/// Rejects a net area larger than the gross area of the shape
+fn net_area_param(net_section: &NetSection, shape: &AiscShape) -> Result<(), Box<dyn Error>> {
+    match net_section.an {
+        Some(an) if an > shape.a_upper() => Err(Box::new(InvalidQueryParameterError {
+            name: String::from("an"),
+            value: an.to_string(),
+        })),
+        _ => Ok(()),
+    }
+}
+
+/// The value of the parameter if it is finite and greater than zero
+fn positive_param(name: &str, value: f64) -> Result<f64, Box<dyn Error>> {
+    if value.is_finite() && value > 0.0 {
+        Ok(value)
+    } else {
+        Err(Box::new(InvalidQueryParameterError {
+            name: String::from(name),
+            value: value.to_string(),
+        }))
+    }
+}
+
+/// The value of the parameter if it is finite and not negative
+fn non_negative_param(name: &str, value: f64) -> Result<f64, Box<dyn Error>> {
+    if value.is_finite() && value >= 0.0 {
+        Ok(value)
+    } else {
+        Err(Box::new(InvalidQueryParameterError {
+            name: String::from(name),
+            value: value.to_string(),
+        }))
+    }
+}
+
+/// Query parameters of the `/page` routes: the `offset` of the page (default 0), the largest
+/// number of shapes `limit` (see [PageRequest::DEFAULT_LIMIT]), the `sort` key (`label`, `weight`,
+/// `depth` or `ix`, default `label`) and the `direction` (`asc` or `desc`, default `asc`).
+/// The numbers are parsed by [PageRequest::try_from] to reject them with a JSON body.
+#[derive(Deserialize)]
+struct PageParams {
+    offset: Option<String>,
+    limit: Option<String>,
+    sort: Option<String>,
+    direction: Option<String>,
+}
+
+impl TryFrom<PageParams> for PageRequest {
+    type Error = Box<dyn Error>;
+    fn try_from(params: PageParams) -> Result<Self, Self::Error> {
+        let sort = match params.sort.as_deref() {
+            Some(sort) => sort.parse::<SortKey>()?,
+            None => SortKey::default(),
+        };
+        let direction = match params.direction.as_deref() {
+            None | Some("asc") => SortDirection::Ascending,
+            Some("desc") => SortDirection::Descending,
+            Some(value) => {
+                return Err(Box::new(InvalidQueryParameterError {
+                    name: String::from("direction"),
+                    value: String::from(value),
+                }));
+            }
+        };
+        let offset = match params.offset.as_deref() {
+            Some(offset) => parse_param("offset", offset)?,
+            None => 0,
+        };
+        let limit = match params.limit.as_deref() {
+            Some(limit) => parse_param("limit", limit)?,
+            None => PageRequest::DEFAULT_LIMIT,
+        };
+        Ok(PageRequest::new()
+            .with_offset(offset)
+            .with_limit(limit)
+            .sorted_by(sort, direction))
+    }
+}
+
+/// The number of alternatives returned by the `/lightest` routes
+/// when the `alternatives` query parameter is omitted
+const DEFAULT_ALTERNATIVES: usize = 3;
+
+/// Builds a [ShapeQuery] from the query parameters of a family route:
+/// `min_{property}` and `max_{property}` bound a property, `{property}` matches a
+/// value within `tolerance`, `order_by` is a comma separated list of properties
+/// (prefixed with `-` for descending order, `label` sorting by AISC Manual Label
+/// after the properties), `offset`, `limit` and `label_prefix` are passed through as is
+fn shape_query_from_params(
+    params: &BTreeMap<String, String>,
+) -> Result<ShapeQuery, Box<dyn Error>> {
+    let tolerance = match params.get("tolerance") {
+        Some(value) => parse_param::<f64>("tolerance", value)?,
+        None => ShapeQuery::DEFAULT_TOLERANCE,
+    };
+    let mut query = ShapeQuery::new();
+    for (name, value) in params {
+        match name.as_str() {
+            "tolerance" => {}
+            "label_prefix" => query = query.with_label_prefix(value),
+            "offset" => query = query.with_offset(parse_param(name, value)?),
+            "limit" => query = query.with_limit(parse_param(name, value)?),
+            "order_by" => {
+                for key in value.split(',') {
+                    let (key, direction) = match key.strip_prefix('-') {
+                        Some(key) => (key, SortDirection::Descending),
+                        None => (key, SortDirection::Ascending),
+                    };
+                    query = match key {
+                        "label" => query.with_label_order(direction),
+                        key => query.with_order(key.parse()?, direction),
+                    };
+                }
+            }
+            _ => {
+                if let Some(property) = name.strip_prefix("min_") {
+                    query = query.with_min(property.parse()?, parse_param(name, value)?);
+                } else if let Some(property) = name.strip_prefix("max_") {
+                    query = query.with_max(property.parse()?, parse_param(name, value)?);
+                } else {
+                    let property = name.parse::<ShapeProperty>()?;
+                    query = query.with_approximate_value(
+                        property,
+                        parse_param(name, value)?,
+                        tolerance,
+                    );
+                }
+            }
+        }
+    }
+    Ok(query)
+}
+
+/// Splits the `alternatives` query parameter of the `/lightest` routes
+/// from the parameters describing the requirements
+fn selection_from_params(
+    mut params: BTreeMap<String, String>,
+) -> Result<(ShapeQuery, usize), Box<dyn Error>> {
+    let alternatives = match params.remove("alternatives") {
+        Some(value) => parse_param("alternatives", &value)?,
+        None => DEFAULT_ALTERNATIVES,
+    };
+    Ok((shape_query_from_params(&params)?, alternatives))
+}
+
+fn parse_param<V: std::str::FromStr>(name: &str, value: &str) -> Result<V, Box<dyn Error>> {
+    value.parse::<V>().map_err(|_| {
+        Box::new(InvalidQueryParameterError {
+            name: String::from(name),
+            value: String::from(value),
+        }) as Box<dyn Error>
+    })
+}
+
+/// Retrieves the shape of any family with the AISC Manual Label,
+/// suggesting the closest designations when there is none
+async fn catalog_shape(
+    repository: &ShapeCatalogRepository,
+    aisc_manual_label: String,
+) -> Result<AiscShape, ApiError> {
+    match repository
+        .shape_with_aisc_manual_label(aisc_manual_label.clone())
+        .await
+    {
+        Err(RepositoryError::NotFound { .. }) => Err(shape_not_found(
+            repository.suggestions(&aisc_manual_label).await?,
+            aisc_manual_label,
+        )),
+        result => Ok(result?),
+    }
+}
+
+/// The error returned when no shape has the designation, with the designations to suggest
+fn shape_not_found(suggestions: Vec<String>, designation: String) -> ApiError {
+    ApiError::from(Box::new(ShapeNotFoundError {
+        designation,
+        suggestions,
+    }) as Box<dyn Error>)
+}
+
+// Handlers for the shape catalog
+async fn search_shapes(
+    State(repository): State<Arc<ShapeCatalogRepository>>,
+    Query(params): Query<CatalogSearchParams>,
+) -> Result<Json<Vec<AiscShape>>, ApiError> {
+    let search = CatalogSearch::try_from(params)?;
+    Ok(Json(repository.search(&search).await?))
+}
+
+async fn search_designations(
+    State(repository): State<Arc<ShapeCatalogRepository>>,
+    Query(params): Query<DesignationSearchParams>,
+) -> Result<Json<Vec<DesignationMatch>>, ApiError> {
+    let search = DesignationSearch::try_from(params)?;
+    Ok(Json(repository.search_designations(&search).await?))
+}
+
+async fn compare_shapes(
+    State(repository): State<Arc<ShapeCatalogRepository>>,
+    Query(params): Query<ComparisonParams>,
+) -> Result<Json<ShapeComparison>, ApiError> {
+    let labels = params.labels()?;
+    let mut shapes = Vec::with_capacity(labels.len());
+    for label in labels {
+        shapes.push(catalog_shape(&repository, label).await?);
+    }
+    Ok(Json(ShapeComparison::new(&shapes)))
+}
+
+async fn section_svg(
+    State(repository): State<Arc<ShapeCatalogRepository>>,
+    Path(aisc_manual_label): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let shape = catalog_shape(&repository, aisc_manual_label).await?;
+    Ok((
+        [(header::CONTENT_TYPE, "image/svg+xml")],
+        svg::section_svg(&shape),
+    ))
+}
+
+async fn check_member(
+    State(repository): State<Arc<ShapeCatalogRepository>>,
+    Path(aisc_manual_label): Path<String>,
+    Json(request): Json<MemberCheckRequest>,
+) -> Result<Json<DesignCheck>, ApiError> {
+    let member = MemberCheck::try_from(&request)?;
+    let shape = catalog_shape(&repository, aisc_manual_label).await?;
+    net_area_param(&member.net_section, &shape)?;
+    Ok(Json(member.check(&shape, &request.forces)))
+}
+
+async fn design_table_route(
+    State(repository): State<Arc<ShapeCatalogRepository>>,
+    Path(table): Path<String>,
+    Query(params): Query<DesignTableParams>,
+) -> Result<Response, ApiError> {
+    let kind = table
+        .parse::<DesignTableKind>()
+        .map_err(|err| Box::new(err) as Box<dyn Error>)?;
+    let format = params.format()?;
+    let steel = params.steel()?;
+    let lengths = params.lengths()?;
+    let search = params.search()?;
+    let shapes = repository.search(&search).await?;
+    let table = design_table(kind, &shapes, &steel, &lengths);
+    Ok(match format {
+        TableFormat::Json => Json(table).into_response(),
+        TableFormat::Csv => (
+            [(header::CONTENT_TYPE, "text/csv; charset=utf-8")],
+            table.to_csv()?,
+        )
+            .into_response(),
+        TableFormat::Html => (
+            [(header::CONTENT_TYPE, "text/html; charset=utf-8")],
+            table.to_html(),
+        )
+            .into_response(),
+    })
+}
+
+// Handlers for shapes
+async fn all_shapes<R, T>(
+    State(repository): State<Arc<R>>,
+    Query(params): Query<BTreeMap<String, String>>,
+) -> Result<Json<Vec<T>>, ApiError>
+where
+    R: ShapeRepository<T>,
+{
+    if params.is_empty() {
+        return Ok(Json(repository.all().await?));
+    }
+    let query = shape_query_from_params(&params)?;
+    Ok(Json(repository.shapes_matching(query).await?))
+}
+
+async fn page_shapes<R, T>(
+    State(repository): State<Arc<R>>,
+    Query(params): Query<PageParams>,
+) -> Result<Json<ShapePage<T>>, ApiError>
+where
+    R: ShapeRepository<T> + Sync,
+    T: Send,
+{
+    let request = PageRequest::try_from(params)?;
+    Ok(Json(repository.page(request).await?))
+}
+
+async fn lightest_shape<R, T>(
+    State(repository): State<Arc<R>>,
+    Query(params): Query<BTreeMap<String, String>>,
+) -> Result<Json<ShapeSelection<T>>, ApiError>
+where
+    R: ShapeRepository<T> + Sync,
+    T: Send,
+{
+    let (requirements, alternatives) = selection_from_params(params)?;
+    match repository
+        .lightest_shape_matching(requirements, alternatives)
+        .await?
+    {
+        Some(selection) => Ok(Json(selection)),
+        None => Err(ApiError::from(
+            Box::new(NoMatchingShapeError) as Box<dyn Error>
+        )),
+    }
+}
+
+async fn shape_with_edi_std_nomenclature<R, T>(
+    State(lookup): State<Arc<FamilyLookup<R>>>,
+    Path(edi_std_nomenclature): Path<String>,
+) -> Result<Json<T>, ApiError>
+where
+    R: ShapeRepository<T>,
+{
+    let result = lookup
+        .repository
+        .shape_with_edi_std_nomenclature(edi_std_nomenclature.clone())
+        .await;
+    Ok(Json(lookup.found(result, edi_std_nomenclature).await?))
+}
+
+async fn shape_with_aisc_manual_label<R, T>(
+    State(lookup): State<Arc<FamilyLookup<R>>>,
+    Path(aisc_manual_label): Path<String>,
+) -> Result<Json<T>, ApiError>
+where
+    R: ShapeRepository<T>,
+{
+    let result = lookup
+        .repository
+        .shape_with_aisc_manual_label(aisc_manual_label.clone())
+        .await;
+    Ok(Json(lookup.found(result, aisc_manual_label).await?))
+}
+
+async fn shapes_with_depth<R, T>(
+    State(repository): State<Arc<R>>,
+    Path(depth): Path<f64>,
+) -> Result<Json<Vec<T>>, ApiError>
+where
+    R: ShapeRepository<T>,
+{
+    Ok(Json(repository.shapes_with_depth(depth).await?))
+}
+
+async fn shapes_with_width<R, T>(
+    State(repository): State<Arc<R>>,
+    Path(width): Path<f64>,
+) -> Result<Json<Vec<T>>, ApiError>
+where
+    R: ShapeRepository<T>,
+{
+    Ok(Json(repository.shapes_with_width(width).await?))
+}
+
+// Handlers for round shapes
+async fn all_round_shapes<R, T>(
+    State(repository): State<Arc<R>>,
+    Query(params): Query<BTreeMap<String, String>>,
+) -> Result<Json<Vec<T>>, ApiError>
+where
+    R: RoundShapeRepository<T>,
+{
+    if params.is_empty() {
+        return Ok(Json(repository.all().await?));
+    }
+    let query = shape_query_from_params(&params)?;
+    Ok(Json(repository.shapes_matching(query).await?))
+}
+
+async fn page_round_shapes<R, T>(
+    State(repository): State<Arc<R>>,
+    Query(params): Query<PageParams>,
+) -> Result<Json<ShapePage<T>>, ApiError>
+where
+    R: RoundShapeRepository<T> + Sync,
+    T: Send,
+{
+    let request = PageRequest::try_from(params)?;
+    Ok(Json(repository.page(request).await?))
+}
+
+async fn lightest_round_shape<R, T>(
+    State(repository): State<Arc<R>>,
+    Query(params): Query<BTreeMap<String, String>>,
+) -> Result<Json<ShapeSelection<T>>, ApiError>
+where
+    R: RoundShapeRepository<T> + Sync,
+    T: Send,
+{
+    let (requirements, alternatives) = selection_from_params(params)?;
+    match repository
+        .lightest_shape_matching(requirements, alternatives)
+        .await?
+    {
+        Some(selection) => Ok(Json(selection)),
+        None => Err(ApiError::from(
+            Box::new(NoMatchingShapeError) as Box<dyn Error>
+        )),
+    }
+}
+
+async fn round_shape_with_edi_std_nomenclature<R, T>(
+    State(lookup): State<Arc<FamilyLookup<R>>>,
+    Path(edi_std_nomenclature): Path<String>,
+) -> Result<Json<T>, ApiError>
+where
+    R: RoundShapeRepository<T>,
+{
+    let result = lookup
+        .repository
+        .shape_with_edi_std_nomenclature(edi_std_nomenclature.clone())
+        .await;
+    Ok(Json(lookup.found(result, edi_std_nomenclature).await?))
+}
+
+async fn round_shape_with_aisc_manual_label<R, T>(
+    State(lookup): State<Arc<FamilyLookup<R>>>,
+    Path(aisc_manual_label): Path<String>,
+) -> Result<Json<T>, ApiError>
+where
+    R: RoundShapeRepository<T>,
+{
+    let result = lookup
+        .repository
+        .shape_with_aisc_manual_label(aisc_manual_label.clone())
+        .await;
+    Ok(Json(lookup.found(result, aisc_manual_label).await?))
+}
+
+async fn shapes_with_diameter<R, T>(
+    State(repository): State<Arc<R>>,
+    Path(diameter): Path<f64>,
+) -> Result<Json<Vec<T>>, ApiError>
+where
+    R: RoundShapeRepository<T>,
+{
+    Ok(Json(repository.shapes_with_diameter(diameter).await?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shapes::aisc_shapes::{PropertyFilter, UnknownShapePropertyError};
+
+    fn params(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (String::from(*k), String::from(*v)))
+            .collect()
+    }
+
+    #[test]
+    fn designation_search_parameters_override_the_defaults() {
+        let search = DesignationSearch::try_from(DesignationSearchParams {
+            q: String::from("W14"),
+            limit: None,
+            max_distance: None,
+        })
+        .unwrap();
+        assert_eq!(DesignationSearch::new("W14"), search);
+        let search = DesignationSearch::try_from(DesignationSearchParams {
+            q: String::from("HSS6X"),
+            limit: Some(String::from("25")),
+            max_distance: Some(String::from("0")),
+        })
+        .unwrap();
+        assert_eq!(Some(25), search.limit);
+        assert_eq!(0, search.max_distance);
+    }
+
+    #[test]
+    fn designation_searches_reject_distances_beyond_the_text() {
+        let params = |max_distance: &str| DesignationSearchParams {
+            q: String::from("W 14"),
+            limit: None,
+            max_distance: Some(String::from(max_distance)),
+        };
+        let search = DesignationSearch::try_from(params("3")).unwrap();
+        assert_eq!(3, search.max_distance);
+        assert_bad_request(
+            DesignationSearch::try_from(params("4")).unwrap_err(),
+            "4 is not a valid value for the max_distance parameter.",
+        );
+        assert_bad_request(
+            DesignationSearch::try_from(params("-1")).unwrap_err(),
+            "-1 is not a valid value for the max_distance parameter.",
+        );
+    }
+
+    #[tokio::test]
+    async fn designation_searches_reject_invalid_numbers_with_a_json_body() {
+        use tower::ServiceExt;
+        let pool = PgPool::connect_lazy("postgres://localhost/shapes").unwrap();
+        let request = axum::http::Request::builder()
+            .uri("/shapes/search?q=W14&limit=ten")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = app(Arc::new(pool)).oneshot(request).await.unwrap();
+        assert_eq!(axum::http::StatusCode::BAD_REQUEST, response.status());
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(
+            r#"{"error":"ten is not a valid value for the limit parameter."}"#,
+            String::from_utf8(body.to_vec()).unwrap()
+        );
+    }
+
+    #[test]
+    fn page_parameters_build_a_page_request() {
+        let params = |offset: Option<&str>,
+                      limit: Option<&str>,
+                      sort: Option<&str>,
+                      direction: Option<&str>| PageParams {
+            offset: offset.map(String::from),
+            limit: limit.map(String::from),
+            sort: sort.map(String::from),
+            direction: direction.map(String::from),
+        };
+        assert_eq!(
+            PageRequest::new(),
+            PageRequest::try_from(params(None, None, None, None)).unwrap()
+        );
+        let request = PageRequest::try_from(params(
+            Some("100"),
+            Some("25"),
+            Some("weight"),
+            Some("desc"),
+        ))
+        .unwrap();
+        assert_eq!(
+            PageRequest::new()
+                .with_offset(100)
+                .with_limit(25)
+                .sorted_by(SortKey::Weight, SortDirection::Descending),
+            request
+        );
+        let err = PageRequest::try_from(params(None, None, Some("zx"), None)).unwrap_err();
+        assert_eq!(
+            axum::http::StatusCode::BAD_REQUEST,
+            ApiError::from(err).status_code()
+        );
+        let err = PageRequest::try_from(params(None, None, None, Some("up"))).unwrap_err();
+        assert!(err.is::<InvalidQueryParameterError>());
+    }
+
+    #[tokio::test]
+    async fn unparsable_page_numbers_are_rejected_with_a_json_body() {
+        for (offset, limit, message) in [
+            (
+                None,
+                Some("ten"),
+                "ten is not a valid value for the limit parameter.",
+            ),
+            (
+                Some("-1"),
+                None,
+                "-1 is not a valid value for the offset parameter.",
+            ),
+        ] {
+            let err = PageRequest::try_from(PageParams {
+                offset: offset.map(String::from),
+                limit: limit.map(String::from),
+                sort: None,
+                direction: None,
+            })
+            .unwrap_err();
+            let response = ApiError::from(err).into_response();
+            assert_eq!(axum::http::StatusCode::BAD_REQUEST, response.status());
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            assert_eq!(
+                format!("{{\"error\":\"{message}\"}}"),
+                String::from_utf8(body.to_vec()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn offsets_and_label_orders_are_query_parameters() {
+        let query = shape_query_from_params(&params(&[
+            ("order_by", "-w_upper,label"),
+            ("offset", "20"),
+            ("limit", "10"),
+        ]))
+        .unwrap();
+        assert_eq!(1, query.order.len());
+        assert_eq!(Some(SortDirection::Ascending), query.label_order);
+        assert_eq!((Some(20), Some(10)), (query.offset, query.limit));
+    }
+
+    #[test]
+    fn query_parameters_build_a_shape_query() {
+        let query = shape_query_from_params(&params(&[
+            ("min_zx", "100"),
+            ("max_d_lower", "14.5"),
+            ("bf", "10"),
+            ("tolerance", "0.1"),
+            ("order_by", "-d_lower,w_upper"),
+            ("limit", "10"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            vec![
+                PropertyFilter::Approximately {
+                    property: ShapeProperty::Bf,
+                    value: 10.0,
+                    tolerance: 0.1
+                },
+                PropertyFilter::AtMost(ShapeProperty::DLower, 14.5),
+                PropertyFilter::AtLeast(ShapeProperty::Zx, 100.0),
+            ],
+            query.filters
+        );
+        assert_eq!(SortDirection::Descending, query.order[0].direction);
+        assert_eq!(ShapeProperty::WUpper, query.order[1].property);
+        assert_eq!(Some(10), query.limit);
+    }
+
+    #[test]
+    fn alternatives_are_not_treated_as_a_property() {
+        let (query, alternatives) =
+            selection_from_params(params(&[("min_zx", "120"), ("alternatives", "5")])).unwrap();
+        assert_eq!(5, alternatives);
+        assert_eq!(1, query.filters.len());
+        let (_, alternatives) = selection_from_params(params(&[])).unwrap();
+        assert_eq!(DEFAULT_ALTERNATIVES, alternatives);
+    }
+
+    #[test]
+    fn member_check_requests_default_omitted_parameters() {
+        let request = MemberCheckRequest {
+            fy: 50.0,
+            fu: 65.0,
+            method: DesignMethod::Asd,
+            lengths: None,
+            lb: Some(120.0),
+            cb: None,
+            net_section: None,
+            lv: None,
+            forces: RequiredStrengths::default(),
+        };
+        let member = MemberCheck::try_from(&request).unwrap();
+        assert_eq!(Steel::A992, member.steel);
+        assert_eq!(DesignMethod::Asd, member.method);
+        assert_eq!(120.0, member.lb);
+        assert_eq!(1.0, member.cb);
+        assert_eq!(EffectiveLengths::uniform(0.0), member.lengths);
+        assert_eq!(NetSection::gross(), member.net_section);
+    }
+
+    #[test]
+    fn comparisons_need_at_least_two_labels() {
+        let params = ComparisonParams {
+            labels: String::from("W16X31, W14X34,,L4X4X1/2"),
+        };
+        assert_eq!(
+            vec!["W16X31", "W14X34", "L4X4X1/2"],
+            params.labels().unwrap()
+        );
+        let params = ComparisonParams {
+            labels: String::from("W16X31,"),
+        };
+        let err = params.labels().unwrap_err();
+        assert!(err.is::<InvalidQueryParameterError>());
+    }
+
+    #[test]
+    fn catalog_searches_reject_invalid_bounds() {
+        let params = |min_depth: &str| CatalogSearchParams {
+            label_prefix: None,
+            families: Some(String::from("wide_flange")),
+            min_depth: Some(String::from(min_depth)),
+            max_depth: None,
+            min_width: None,
+            max_width: None,
+            min_weight: None,
+            max_weight: Some(String::from("40")),
+        };
+        let search = CatalogSearch::try_from(params("12.5")).unwrap();
+        assert_eq!(Some(12.5), search.min_depth);
+        assert_eq!(Some(40.0), search.max_weight);
+        for min_depth in ["abc", "NaN"] {
+            assert_bad_request(
+                CatalogSearch::try_from(params(min_depth)).unwrap_err(),
+                &format!("{min_depth} is not a valid value for the min_depth parameter."),
+            );
+        }
+    }
+
+    fn table_params(format: Option<&str>) -> DesignTableParams {
+        DesignTableParams {
+            fy: None,
+            label_prefix: Some(String::from("W14")),
+            families: None,
+            start: Some(String::from("10")),
+            end: None,
+            step: None,
+            format: format.map(String::from),
+        }
+    }
+
+    #[test]
+    fn design_tables_default_to_wide_flanges_in_json() {
+        let params = table_params(None);
+        let search = params.search().unwrap();
+        assert_eq!(Some(vec![ShapeFamily::WideFlange]), search.families);
+        assert_eq!(Some(String::from("W14")), search.label_prefix);
+        assert_eq!(LengthRange::new(10.0, 40.0, 2.0), params.lengths().unwrap());
+        assert_eq!(Steel::A992, params.steel().unwrap());
+        assert_eq!(TableFormat::Json, params.format().unwrap());
+        assert_eq!(
+            TableFormat::Html,
+            table_params(Some("html")).format().unwrap()
+        );
+        let err = table_params(Some("pdf")).format().unwrap_err();
+        assert!(err.is::<InvalidQueryParameterError>());
+    }
+
+    fn assert_bad_request(err: Box<dyn Error>, message: &str) {
+        assert!(err.is::<InvalidQueryParameterError>());
+        assert_eq!(message, err.to_string());
+        assert_eq!(
+            axum::http::StatusCode::BAD_REQUEST,
+            ApiError::from(err).status_code()
+        );
+    }
+
+    #[test]
+    fn design_tables_reject_non_finite_lengths() {
+        let params = DesignTableParams {
+            end: Some(String::from("inf")),
+            ..table_params(None)
+        };
+        assert_bad_request(
+            params.lengths().unwrap_err(),
+            "inf is not a valid value for the end parameter.",
+        );
+        let params = DesignTableParams {
+            step: Some(String::from("NaN")),
+            ..table_params(None)
+        };
+        assert_bad_request(
+            params.lengths().unwrap_err(),
+            "NaN is not a valid value for the step parameter.",
+        );
+    }
+
+    #[test]
+    fn design_tables_reject_steps_that_are_not_positive() {
+        let params = DesignTableParams {
+            step: Some(String::from("0")),
+            ..table_params(None)
+        };
+        assert_bad_request(
+            params.lengths().unwrap_err(),
+            "0 is not a valid value for the step parameter.",
+        );
+    }
+
+    #[test]
+    fn design_tables_reject_ranges_ending_before_their_start() {
+        let params = DesignTableParams {
+            end: Some(String::from("5")),
+            ..table_params(None)
+        };
+        assert_bad_request(
+            params.lengths().unwrap_err(),
+            "5 is not a valid value for the end parameter.",
+        );
+    }
+
+    #[test]
+    fn design_tables_reject_too_many_lengths() {
+        let params = DesignTableParams {
+            end: Some(String::from("1e9")),
+            step: Some(String::from("0.5")),
+            ..table_params(None)
+        };
+        assert_bad_request(
+            params.lengths().unwrap_err(),
+            "0.5 is not a valid value for the step parameter.",
+        );
+    }
+
+    #[test]
+    fn design_tables_reject_yield_stresses_that_are_not_positive() {
+        for fy in [0.0, -50.0] {
+            let params = DesignTableParams {
+                fy: Some(fy.to_string()),
+                ..table_params(None)
+            };
+            assert_bad_request(
+                params.steel().unwrap_err(),
+                &format!("{fy} is not a valid value for the fy parameter."),
+            );
+        }
+        let params = DesignTableParams {
+            fy: Some(String::from("36")),
+            ..table_params(None)
+        };
+        assert_eq!(Steel::new(36.0, 65.0), params.steel().unwrap());
+    }
+
+    #[test]
+    fn design_tables_reject_numbers_that_do_not_parse() {
+        let params = DesignTableParams {
+            fy: Some(String::from("fifty")),
+            ..table_params(None)
+        };
+        assert_bad_request(
+            params.steel().unwrap_err(),
+            "fifty is not a valid value for the fy parameter.",
+        );
+        let params = DesignTableParams {
+            start: Some(String::from("10ft")),
+            ..table_params(None)
+        };
+        assert_bad_request(
+            params.lengths().unwrap_err(),
+            "10ft is not a valid value for the start parameter.",
+        );
+    }
+
+    #[test]
+    fn member_checks_reject_invalid_steels_and_lengths() {
+        let request = || MemberCheckRequest {
+            fy: 50.0,
+            fu: 65.0,
+            method: DesignMethod::Lrfd,
+            lengths: None,
+            lb: None,
+            cb: None,
+            net_section: None,
+            lv: None,
+            forces: RequiredStrengths::default(),
+        };
+        for (name, value, request) in [
+            (
+                "fy",
+                0.0,
+                MemberCheckRequest {
+                    fy: 0.0,
+                    ..request()
+                },
+            ),
+            (
+                "fy",
+                f64::NAN,
+                MemberCheckRequest {
+                    fy: f64::NAN,
+                    ..request()
+                },
+            ),
+            (
+                "fu",
+                -65.0,
+                MemberCheckRequest {
+                    fu: -65.0,
+                    ..request()
+                },
+            ),
+            (
+                "cb",
+                0.0,
+                MemberCheckRequest {
+                    cb: Some(0.0),
+                    ..request()
+                },
+            ),
+            (
+                "lb",
+                -1.0,
+                MemberCheckRequest {
+                    lb: Some(-1.0),
+                    ..request()
+                },
+            ),
+            (
+                "lcy",
+                -120.0,
+                MemberCheckRequest {
+                    lengths: Some(EffectiveLengths::new(120.0, -120.0, 120.0)),
+                    ..request()
+                },
+            ),
+            (
+                "an",
+                0.0,
+                MemberCheckRequest {
+                    net_section: Some(NetSection::new(0.0, 0.8)),
+                    ..request()
+                },
+            ),
+            (
+                "an",
+                f64::INFINITY,
+                MemberCheckRequest {
+                    net_section: Some(NetSection::new(f64::INFINITY, 0.8)),
+                    ..request()
+                },
+            ),
+            (
+                "u",
+                0.0,
+                MemberCheckRequest {
+                    net_section: Some(NetSection::new(3.31, 0.0)),
+                    ..request()
+                },
+            ),
+            (
+                "u",
+                1.2,
+                MemberCheckRequest {
+                    net_section: Some(NetSection::new(3.31, 1.2)),
+                    ..request()
+                },
+            ),
+            (
+                "u",
+                f64::NAN,
+                MemberCheckRequest {
+                    net_section: Some(NetSection::new(3.31, f64::NAN)),
+                    ..request()
+                },
+            ),
+            (
+                "lv",
+                0.0,
+                MemberCheckRequest {
+                    lv: Some(0.0),
+                    ..request()
+                },
+            ),
+            (
+                "lv",
+                f64::NAN,
+                MemberCheckRequest {
+                    lv: Some(f64::NAN),
+                    ..request()
+                },
+            ),
+        ] {
+            assert_bad_request(
+                MemberCheck::try_from(&request).unwrap_err(),
+                &format!("{value} is not a valid value for the {name} parameter."),
+            );
+        }
+        let member = MemberCheck::try_from(&MemberCheckRequest {
+            lb: Some(0.0),
+            ..request()
+        })
+        .unwrap();
+        assert_eq!(0.0, member.lb);
+    }
+
+    #[test]
+    fn member_checks_reject_net_areas_larger_than_the_gross_area() {
+        let shape = shapes::aisc_shapes::aisc_database::snapshot()
+            .iter()
+            .find(|shape| shape.aisc_manual_label() == "L4X4X1/2")
+            .unwrap();
+        let net_section = NetSection::new(shape.a_upper() + 0.01, 0.8);
+        assert_bad_request(
+            net_area_param(&net_section, shape).unwrap_err(),
+            &format!(
+                "{} is not a valid value for the an parameter.",
+                shape.a_upper() + 0.01
+            ),
+        );
+        net_area_param(&NetSection::new(shape.a_upper(), 1.0), shape).unwrap();
+        net_area_param(&NetSection::gross(), shape).unwrap();
+    }
+
+    #[test]
+    fn invalid_query_parameters_are_rejected() {
+        let err = shape_query_from_params(&params(&[("min_depth", "12")])).unwrap_err();
+        assert!(err.is::<UnknownShapePropertyError>());
+        let err = shape_query_from_params(&params(&[("limit", "ten")])).unwrap_err();
+        assert!(err.is::<InvalidQueryParameterError>());
+    }
+}
@@ -0,0 +1,382 @@
+//! Looks up AISC steel shapes from the command line, against the shapes API,
+//! the shapes database or the snapshot of the AISC Shapes Database compiled into the library.
+mod output;
+mod source;
+
+use clap::{Args, Parser, Subcommand};
+use output::Format;
+use shapes::aisc_shapes::{
+    AiscShape, DesignationMatch, DesignationSearch, RepositoryError, ShapeComparison, ShapeFamily,
+    ShapeProperty, ShapeQuery, SortDirection, SortOrder, aisc_database,
+};
+use source::ShapeSource;
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Debug, Parser)]
+#[command(name = "shapes", version, about)]
+/// Looks up AISC steel shapes and their properties
+struct Cli {
+    /// Base URL of a running shapes API, e.g. http://localhost:3000
+    #[arg(long, env = "SHAPES_API_URL", global = true, conflicts_with_all = ["database_url", "csv"])]
+    api_url: Option<String>,
+    /// Postgres connection string of the shapes database
+    #[arg(long, global = true, conflicts_with = "csv")]
+    database_url: Option<String>,
+    /// An export of the AISC Shapes Database in CSV format,
+    /// the compiled-in snapshot is used when no source is given
+    #[arg(long, global = true)]
+    csv: Option<PathBuf>,
+    /// How the shapes are printed
+    #[arg(long, short, value_enum, default_value_t, global = true)]
+    format: Format,
+    /// Comma separated properties to print, e.g. zx,sx,ix
+    #[arg(long, short, value_delimiter = ',', global = true)]
+    properties: Vec<ShapeProperty>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Prints the shape with an AISC Manual Label, e.g. W18X35
+    Lookup {
+        /// The AISC Manual Label or EDI Std Nomenclature of the shape
+        label: String,
+    },
+    /// Lists the shapes of a family, optionally filtered by property ranges
+    #[command(visible_alias = "filter")]
+    List(ListArgs),
+    /// Compares the common properties of shapes with the first one, e.g. W16X31 W14X34 W12X35
+    Compare {
+        /// The AISC Manual Labels of the shapes, possibly of different families
+        #[arg(num_args = 2.., required = true)]
+        labels: Vec<String>,
+    },
+    /// Searches every family for partial or misspelled designations, e.g. W14 or HSS6X
+    Search {
+        /// The start of a designation, or a designation within a few edits of one
+        text: String,
+        /// Largest number of shapes printed
+        #[arg(long, default_value_t = shapes::aisc_shapes::designation_search::DEFAULT_SEARCH_LIMIT)]
+        limit: usize,
+        /// Largest number of characters inserted, removed or substituted in fuzzy matches,
+        /// about one per four characters of the text by default
+        #[arg(long)]
+        max_distance: Option<usize>,
+    },
+}
+
+#[derive(Debug, Args)]
+struct ListArgs {
+    /// The shape family, e.g. wide_flange or hollow_structural_section
+    family: ShapeFamily,
+    /// Lower bound on a property, e.g. zx=100, may be repeated
+    #[arg(long, value_name = "PROPERTY=VALUE", value_parser = parse_bound)]
+    min: Vec<(ShapeProperty, f64)>,
+    /// Upper bound on a property, e.g. d_lower=18.5, may be repeated
+    #[arg(long, value_name = "PROPERTY=VALUE", value_parser = parse_bound)]
+    max: Vec<(ShapeProperty, f64)>,
+    /// Only shapes whose AISC Manual Label starts with the prefix, e.g. W14
+    #[arg(long)]
+    label_prefix: Option<String>,
+    /// Comma separated properties to sort by, descending when prefixed with '-', e.g. -zx
+    #[arg(long, value_delimiter = ',', allow_hyphen_values = true, value_parser = parse_order)]
+    order_by: Vec<SortOrder>,
+    /// Number of shapes skipped before printing, e.g. to print the next page of a listing
+    #[arg(long)]
+    offset: Option<usize>,
+    /// Largest number of shapes printed
+    #[arg(long)]
+    limit: Option<usize>,
+}
+
+impl ListArgs {
+    fn query(&self) -> ShapeQuery {
+        let mut query = ShapeQuery::new();
+        for (property, min) in &self.min {
+            query = query.with_min(*property, *min);
+        }
+        for (property, max) in &self.max {
+            query = query.with_max(*property, *max);
+        }
+        if let Some(prefix) = &self.label_prefix {
+            query = query.with_label_prefix(prefix);
+        }
+        for order in &self.order_by {
+            query = query.with_order(order.property, order.direction);
+        }
+        if let Some(offset) = self.offset {
+            query = query.with_offset(offset);
+        }
+        if let Some(limit) = self.limit {
+            query = query.with_limit(limit);
+        }
+        query
+    }
+}
+
+fn parse_bound(bound: &str) -> Result<(ShapeProperty, f64), String> {
+    let (property, value) = bound
+        .split_once('=')
+        .ok_or_else(|| format!("expected PROPERTY=VALUE, found {bound}"))?;
+    let property = property
+        .parse::<ShapeProperty>()
+        .map_err(|e| e.to_string())?;
+    let value = value
+        .trim()
+        .parse::<f64>()
+        .map_err(|e| format!("{value} is not a number: {e}"))?;
+    Ok((property, value))
+}
+
+fn parse_order(order: &str) -> Result<SortOrder, String> {
+    let (property, direction) = match order.strip_prefix('-') {
+        Some(property) => (property, SortDirection::Descending),
+        None => (order, SortDirection::Ascending),
+    };
+    Ok(SortOrder {
+        property: property
+            .parse()
+            .map_err(|e: shapes::aisc_shapes::UnknownShapePropertyError| e.to_string())?,
+        direction,
+    })
+}
+
+/// The properties printed when none are requested: every property of a single shape,
+/// and the properties most often used for selection when listing a family
+fn default_properties(
+    family: ShapeFamily,
+    command: &Command,
+    format: Format,
+) -> Vec<ShapeProperty> {
+    if matches!(command, Command::Lookup { .. }) || format == Format::Csv {
+        return family.properties().to_vec();
+    }
+    let mut properties = Vec::new();
+    for property in [
+        family.depth_property(),
+        family.width_property(),
+        ShapeProperty::WUpper,
+        ShapeProperty::AUpper,
+        ShapeProperty::Ix,
+        ShapeProperty::Zx,
+        ShapeProperty::Sx,
+        ShapeProperty::Iy,
+    ] {
+        if family.has_property(property) && !properties.contains(&property) {
+            properties.push(property);
+        }
+    }
+    properties
+}
+
+/// Looks up a shape, suggesting the closest designations when there is none
+async fn lookup(source: &ShapeSource, label: &str) -> Result<AiscShape, Box<dyn Error>> {
+    match source.shape(label).await {
+        Err(err)
+            if err
+                .downcast_ref::<RepositoryError>()
+                .is_some_and(RepositoryError::is_not_found) =>
+        {
+            let matches = source
+                .search(&DesignationSearch::suggestions_for(label))
+                .await?;
+            match DesignationMatch::labels(&matches).as_slice() {
+                [] => Err(err),
+                suggestions => {
+                    Err(format!("{err} Did you mean {}?", suggestions.join(", ")).into())
+                }
+            }
+        }
+        result => result,
+    }
+}
+
+async fn run(cli: Cli) -> Result<String, Box<dyn Error>> {
+    let source = if let Some(url) = &cli.api_url {
+        ShapeSource::api(url)?
+    } else if let Some(url) = &cli.database_url {
+        ShapeSource::database(url).await?
+    } else if let Some(path) = &cli.csv {
+        ShapeSource::Memory(aisc_database::read_shapes_from_path(path)?)
+    } else {
+        ShapeSource::Memory(aisc_database::snapshot().to_vec())
+    };
+    let properties = |family| match cli.properties.as_slice() {
+        [] => default_properties(family, &cli.command, cli.format),
+        properties => properties.to_vec(),
+    };
+    match &cli.command {
+        Command::Lookup { label } => {
+            let shape = lookup(&source, label).await?;
+            output::shape(&shape, &properties(shape.family()), cli.format)
+        }
+        Command::List(args) => {
+            let shapes = source.shapes(args.family, args.query()).await?;
+            output::shapes(&shapes, &properties(args.family), cli.format)
+        }
+        Command::Compare { labels } => {
+            let mut shapes = Vec::with_capacity(labels.len());
+            for label in labels {
+                shapes.push(lookup(&source, label).await?);
+            }
+            let mut comparison = ShapeComparison::new(&shapes);
+            if !cli.properties.is_empty() {
+                comparison
+                    .properties
+                    .retain(|property| cli.properties.contains(&property.property));
+            }
+            output::comparison(&comparison, cli.format)
+        }
+        Command::Search {
+            text,
+            limit,
+            max_distance,
+        } => {
+            let mut search = DesignationSearch::new(text).with_limit(*limit);
+            if let Some(max_distance) = max_distance {
+                search = search.with_max_distance(*max_distance);
+            }
+            output::matches(&source.search(&search).await?, cli.format)
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run(Cli::parse()).await {
+        Ok(output) => {
+            println!("{}", output.trim_end());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Cli {
+        Cli::try_parse_from(std::iter::once("shapes").chain(args.iter().copied())).unwrap()
+    }
+
+    #[test]
+    fn list_arguments_build_a_query() {
+        let cli = parse(&[
+            "filter",
+            "wide_flange",
+            "--min",
+            "zx=100",
+            "--max",
+            "d_lower=14.5",
+            "--label-prefix",
+            "W14",
+            "--order-by",
+            "-w_upper,zx",
+            "--offset",
+            "6",
+            "--limit",
+            "3",
+        ]);
+        let Command::List(args) = &cli.command else {
+            panic!("filter is an alias of list");
+        };
+        let expected = ShapeQuery::new()
+            .with_min(ShapeProperty::Zx, 100.0)
+            .with_max(ShapeProperty::DLower, 14.5)
+            .with_label_prefix("W14")
+            .with_order(ShapeProperty::WUpper, SortDirection::Descending)
+            .with_order(ShapeProperty::Zx, SortDirection::Ascending)
+            .with_offset(6)
+            .with_limit(3);
+        assert_eq!(expected, args.query());
+    }
+
+    #[test]
+    fn invalid_arguments_are_rejected() {
+        let cli = |args: &[&str]| {
+            Cli::try_parse_from(std::iter::once("shapes").chain(args.iter().copied()))
+        };
+        assert!(cli(&["list", "wide_flange", "--min", "zx"]).is_err());
+        assert!(cli(&["list", "wide_flange", "--min", "zz=1"]).is_err());
+        assert!(cli(&["list", "wide-flanges"]).is_err());
+        assert!(
+            cli(&[
+                "--api-url",
+                "http://localhost:3000",
+                "--csv",
+                "a.csv",
+                "lookup",
+                "W18X35"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn lists_print_selection_properties_by_default() {
+        let cli = parse(&["list", "pipe"]);
+        assert_eq!(
+            vec![
+                ShapeProperty::Od,
+                ShapeProperty::WUpper,
+                ShapeProperty::AUpper,
+                ShapeProperty::Ix,
+                ShapeProperty::Zx,
+                ShapeProperty::Sx,
+                ShapeProperty::Iy,
+            ],
+            default_properties(ShapeFamily::Pipe, &cli.command, cli.format)
+        );
+    }
+
+    #[tokio::test]
+    async fn comparisons_need_two_shapes() {
+        assert!(Cli::try_parse_from(["shapes", "compare", "W16X31"]).is_err());
+        let output = run(parse(&["compare", "W16X31", "L4X4X1/2", "-p", "w_upper"]))
+            .await
+            .unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(7, lines.len(), "{output}");
+        assert!(lines[2].starts_with("w_upper"));
+        assert_eq!("Lightest: L4X4X1/2", lines[4]);
+        assert!(lines[5].starts_with("Lightest for equal zx: W16X31"));
+    }
+
+    #[tokio::test]
+    async fn lookups_answer_from_the_snapshot() {
+        let output = run(parse(&["lookup", "W18X35", "--properties", "zx"]))
+            .await
+            .unwrap();
+        assert!(
+            output
+                .lines()
+                .any(|line| line == "zx                 66.5    in.³")
+        );
+        let err = run(parse(&["lookup", "W18X36"])).await.unwrap_err();
+        assert_eq!(
+            "No shape was found for W18X36. Did you mean W18X35, W18X46, W18X76?",
+            err.to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn searches_rank_partial_designations() {
+        let output = run(parse(&["search", "w14x1", "--limit", "3"]))
+            .await
+            .unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(5, lines.len(), "{output}");
+        assert!(lines[2].starts_with("W14X109"));
+        let output = run(parse(&["search", "W14X91", "--max-distance", "0"]))
+            .await
+            .unwrap();
+        assert_eq!(2, output.lines().count(), "{output}");
+    }
+}
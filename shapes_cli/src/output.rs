@@ -0,0 +1,373 @@
+use shapes::aisc_shapes::{
+    AiscShape, DesignationMatch, MatchKind, SectionProperties, ShapeComparison, ShapeProperty,
+    UnitSystem,
+};
+use std::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+/// How the shapes are printed
+pub enum Format {
+    /// Columns aligned for reading in a terminal
+    #[default]
+    Table,
+    /// The shapes as returned by the API, tagged with their family
+    Json,
+    /// One row per shape, for spreadsheets
+    Csv,
+}
+
+/// Prints a single shape, one row per property for tables
+pub fn shape(
+    shape: &AiscShape,
+    properties: &[ShapeProperty],
+    format: Format,
+) -> Result<String, Box<dyn Error>> {
+    match format {
+        Format::Table => {
+            let mut rows = vec![vec![
+                String::from("Property"),
+                String::from("Value"),
+                String::from("Unit"),
+            ]];
+            rows.push(vec![
+                String::from("aisc_manual_label"),
+                String::from(shape.aisc_manual_label()),
+                String::new(),
+            ]);
+            rows.extend(properties.iter().map(|property| {
+                vec![
+                    String::from(property.column_name()),
+                    value(shape, *property),
+                    String::from(property.unit().symbol(UnitSystem::UsCustomary)),
+                ]
+            }));
+            Ok(aligned(&rows))
+        }
+        Format::Json => Ok(serde_json::to_string_pretty(shape)?),
+        Format::Csv => shapes(std::slice::from_ref(shape), properties, format),
+    }
+}
+
+/// Prints a list of shapes, one row per shape for tables and CSV
+pub fn shapes(
+    shapes: &[AiscShape],
+    properties: &[ShapeProperty],
+    format: Format,
+) -> Result<String, Box<dyn Error>> {
+    match format {
+        Format::Table => {
+            let mut header = vec![String::from("aisc_manual_label")];
+            header.extend(properties.iter().map(|property| {
+                match property.unit().symbol(UnitSystem::UsCustomary) {
+                    "" => String::from(property.column_name()),
+                    unit => format!("{} ({unit})", property.column_name()),
+                }
+            }));
+            let mut rows = vec![header];
+            rows.extend(shapes.iter().map(|shape| row(shape, properties)));
+            Ok(aligned(&rows))
+        }
+        Format::Json => Ok(serde_json::to_string_pretty(shapes)?),
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer.write_record(
+                std::iter::once("aisc_manual_label")
+                    .chain(properties.iter().map(|property| property.column_name())),
+            )?;
+            for shape in shapes {
+                writer.write_record(row(shape, properties).iter().map(|value| match value {
+                    value if value == MISSING => "",
+                    value => value.as_str(),
+                }))?;
+            }
+            Ok(String::from_utf8(writer.into_inner()?)?)
+        }
+    }
+}
+
+/// Prints a comparison, one row per property with the differences from the first shape,
+/// followed by the lightest shapes for tables
+pub fn comparison(comparison: &ShapeComparison, format: Format) -> Result<String, Box<dyn Error>> {
+    let others = comparison.labels.iter().skip(1);
+    match format {
+        Format::Table => {
+            let mut header = vec![String::from("Property"), String::from("Unit")];
+            header.extend(comparison.labels.iter().cloned());
+            header.extend(others.map(|label| format!("{label} vs {}", comparison.labels[0])));
+            let mut rows = vec![header];
+            rows.extend(comparison.properties.iter().map(|property| {
+                let mut row = vec![
+                    String::from(property.property.column_name()),
+                    String::from(property.property.unit().symbol(UnitSystem::UsCustomary)),
+                ];
+                row.extend(property.values.iter().map(|value| value.to_string()));
+                row.extend(
+                    property
+                        .differences
+                        .iter()
+                        .zip(&property.percent_differences)
+                        .skip(1)
+                        .map(|(difference, percent)| match percent {
+                            Some(percent) => format!("{:+} ({percent:+.1}%)", rounded(*difference)),
+                            None => format!("{:+}", rounded(*difference)),
+                        }),
+                );
+                row
+            }));
+            let mut lines = vec![aligned(&rows), String::new()];
+            if let Some(lightest) = &comparison.lightest {
+                lines.push(format!("Lightest: {lightest}"));
+            }
+            for efficiency in &comparison.efficiencies {
+                let index = comparison
+                    .labels
+                    .iter()
+                    .position(|label| *label == efficiency.lightest)
+                    .unwrap_or_default();
+                lines.push(format!(
+                    "Lightest for equal {}: {} ({} lb/ft per {})",
+                    efficiency.property.column_name(),
+                    efficiency.lightest,
+                    rounded(efficiency.weight_ratios[index]),
+                    efficiency.property.unit().symbol(UnitSystem::UsCustomary),
+                ));
+            }
+            Ok(lines.join("\n"))
+        }
+        Format::Json => Ok(serde_json::to_string_pretty(comparison)?),
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            let mut header = vec![String::from("property")];
+            header.extend(comparison.labels.iter().cloned());
+            for label in others {
+                header.push(format!("{label}_difference"));
+                header.push(format!("{label}_percent_difference"));
+            }
+            writer.write_record(&header)?;
+            for property in &comparison.properties {
+                let mut record = vec![String::from(property.property.column_name())];
+                record.extend(property.values.iter().map(|value| value.to_string()));
+                for (difference, percent) in property
+                    .differences
+                    .iter()
+                    .zip(&property.percent_differences)
+                    .skip(1)
+                {
+                    record.push(difference.to_string());
+                    record.push(percent.map_or_else(String::new, |percent| percent.to_string()));
+                }
+                writer.write_record(&record)?;
+            }
+            Ok(String::from_utf8(writer.into_inner()?)?)
+        }
+    }
+}
+
+/// Prints the matches of a designation search, closest first
+pub fn matches(matches: &[DesignationMatch], format: Format) -> Result<String, Box<dyn Error>> {
+    let header = [
+        "aisc_manual_label",
+        "edi_std_nomenclature",
+        "family",
+        "match",
+        "distance",
+    ];
+    let rows = matches.iter().map(|m| {
+        vec![
+            m.aisc_manual_label.clone(),
+            m.edi_std_nomenclature.clone(),
+            String::from(m.family.name()),
+            String::from(match m.kind {
+                MatchKind::Exact => "exact",
+                MatchKind::Prefix => "prefix",
+                MatchKind::Fuzzy => "fuzzy",
+            }),
+            m.distance.to_string(),
+        ]
+    });
+    match format {
+        Format::Table => {
+            let mut table = vec![header.map(String::from).to_vec()];
+            table.extend(rows);
+            Ok(aligned(&table))
+        }
+        Format::Json => Ok(serde_json::to_string_pretty(matches)?),
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer.write_record(header)?;
+            for row in rows {
+                writer.write_record(&row)?;
+            }
+            Ok(String::from_utf8(writer.into_inner()?)?)
+        }
+    }
+}
+
+/// Rounds computed values to three decimals, the precision of the tabulated properties
+fn rounded(value: f64) -> f64 {
+    (value * 1000.0).round() / 1000.0
+}
+
+/// Printed in place of properties the shape does not define
+const MISSING: &str = "-";
+
+fn value(shape: &AiscShape, property: ShapeProperty) -> String {
+    shape
+        .property(property)
+        .map_or_else(|| String::from(MISSING), |value| value.to_string())
+}
+
+fn row(shape: &AiscShape, properties: &[ShapeProperty]) -> Vec<String> {
+    std::iter::once(String::from(shape.aisc_manual_label()))
+        .chain(properties.iter().map(|property| value(shape, *property)))
+        .collect()
+}
+
+/// Pads the cells of each column to its widest cell, the first row being the header
+fn aligned(rows: &[Vec<String>]) -> String {
+    let columns = rows.first().map_or(0, Vec::len);
+    let widths: Vec<usize> = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let line = |row: &Vec<String>| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+    let mut lines: Vec<String> = rows.iter().map(line).collect();
+    let rule = widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<_>>()
+        .join("  ");
+    lines.insert(1.min(lines.len()), rule);
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shapes::aisc_shapes::ShapeProperty::*;
+
+    fn snapshot_shape(label: &str) -> AiscShape {
+        shapes::aisc_shapes::aisc_database::snapshot()
+            .iter()
+            .find(|shape| shape.aisc_manual_label() == label)
+            .cloned()
+            .expect("the shape is in the snapshot")
+    }
+
+    #[test]
+    fn shapes_print_as_aligned_tables() {
+        let shapes = [snapshot_shape("W18X35"), snapshot_shape("W8X10")];
+        let table = super::shapes(&shapes, &[WUpper, Zx], Format::Table).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            vec![
+                "aisc_manual_label  w_upper (lb/ft)  zx (in.³)",
+                "-----------------  ---------------  ---------",
+                "W18X35             35               66.5",
+                "W8X10              10               8.87",
+            ],
+            lines
+        );
+    }
+
+    #[test]
+    fn single_shapes_print_one_property_per_row() {
+        let table = shape(&snapshot_shape("W18X35"), &[Zx, Bf2tf], Format::Table).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!("aisc_manual_label  W18X35", lines[2]);
+        assert_eq!("zx                 66.5    in.³", lines[3]);
+        assert_eq!(
+            Some(Bf2tf.column_name()),
+            lines[4].split_whitespace().next()
+        );
+        assert_eq!(2, lines[4].split_whitespace().count());
+    }
+
+    #[test]
+    fn missing_properties_are_empty_in_csv() {
+        let shapes = [snapshot_shape("W18X35"), snapshot_shape("HSS6X4X1/4")];
+        let csv = super::shapes(&shapes, &[Zx, Tdes], Format::Csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            vec![
+                "aisc_manual_label,zx,tdes",
+                "W18X35,66.5,",
+                "HSS6X4X1/4,8.53,0.233"
+            ],
+            lines
+        );
+    }
+
+    #[test]
+    fn comparisons_print_differences_from_the_first_shape() {
+        let shapes = [
+            snapshot_shape("W16X31"),
+            snapshot_shape("W14X34"),
+            snapshot_shape("W12X35"),
+        ];
+        let mut comparison = ShapeComparison::new(&shapes);
+        comparison
+            .properties
+            .retain(|property| [WUpper, Zx].contains(&property.property));
+        let table = super::comparison(&comparison, Format::Table).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            vec![
+                "Property  Unit   W16X31  W14X34  W12X35  W14X34 vs W16X31  W12X35 vs W16X31",
+                "--------  -----  ------  ------  ------  ----------------  ----------------",
+                "w_upper   lb/ft  31      34      35      +3 (+9.7%)        +4 (+12.9%)",
+                "zx        in.³   54      54.6    51.2    +0.6 (+1.1%)      -2.8 (-5.2%)",
+                "",
+                "Lightest: W16X31",
+                "Lightest for equal zx: W16X31 (0.574 lb/ft per in.³)",
+                "Lightest for equal ix: W16X31 (0.083 lb/ft per in.⁴)",
+            ],
+            lines
+        );
+        let csv = super::comparison(&comparison, Format::Csv).unwrap();
+        assert!(csv.starts_with(
+            "property,W16X31,W14X34,W12X35,W14X34_difference,W14X34_percent_difference,"
+        ));
+    }
+
+    #[test]
+    fn matches_print_closest_first() {
+        let shapes = [snapshot_shape("W14X90"), snapshot_shape("HSS6X4X1/4")];
+        let matches = shapes::aisc_shapes::DesignationSearch::new("W14X91").rank_shapes(&shapes);
+        let table = super::matches(&matches, Format::Table).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            vec![
+                "aisc_manual_label  edi_std_nomenclature  family       match  distance",
+                "-----------------  --------------------  -----------  -----  --------",
+                "W14X90             W14X90                wide_flange  fuzzy  1",
+            ],
+            lines
+        );
+        let csv = super::matches(&matches, Format::Csv).unwrap();
+        assert_eq!(
+            "aisc_manual_label,edi_std_nomenclature,family,match,distance\nW14X90,W14X90,wide_flange,fuzzy,1\n",
+            csv
+        );
+    }
+
+    #[test]
+    fn json_shapes_are_tagged_with_their_family() {
+        let json = shape(&snapshot_shape("W18X35"), &[], Format::Json).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!("wide_flange", json["family"]);
+        assert_eq!("W18X35", json["aisc_manual_label"]);
+    }
+}
@@ -0,0 +1,432 @@
+use reqwest::Url;
+use shape_repositories::repositories::ShapeCatalogRepository;
+use shapes::aisc_shapes::{
+    AiscShape, Designation, DesignationMatch, DesignationSearch, MetricDesignation, PropertyFilter,
+    RepositoryError, SectionProperties, ShapeFamily, ShapeQuery, SortDirection,
+};
+use sqlx::PgPool;
+use std::error::Error;
+use std::sync::Arc;
+
+/// Where the shapes are retrieved from
+pub enum ShapeSource {
+    /// A running shapes API, at its base URL
+    Api { client: reqwest::Client, url: Url },
+    /// The Postgres database, through the shape repositories
+    Database(ShapeCatalogRepository),
+    /// Shapes held in memory, from the compiled-in snapshot or a CSV export
+    Memory(Vec<AiscShape>),
+}
+
+impl ShapeSource {
+    /// A source backed by the shapes API at the base URL
+    pub fn api(url: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(ShapeSource::Api {
+            client: reqwest::Client::new(),
+            url: Url::parse(url)?,
+        })
+    }
+
+    /// A source backed by the Postgres database at the connection string
+    pub async fn database(url: &str) -> Result<Self, Box<dyn Error>> {
+        let pool = PgPool::connect(url).await?;
+        Ok(ShapeSource::Database(ShapeCatalogRepository::new(
+            Arc::new(pool),
+        )))
+    }
+
+    /// Retrieves the shape with the AISC Manual Label or EDI Std Nomenclature
+    pub async fn shape(&self, label: &str) -> Result<AiscShape, Box<dyn Error>> {
+        let not_found = || RepositoryError::NotFound {
+            key: String::from(label),
+        };
+        match self {
+            ShapeSource::Api { client, url } => {
+                let family = ShapeFamily::from_designation(label).ok_or_else(not_found)?;
+                // Labels and EDI names differ for some shapes, e.g. Pipe2STD and Pipe2SCH40
+                for route in ["label", "edi"] {
+                    let mut url = url.clone();
+                    url.path_segments_mut()
+                        .map_err(|_| "The API URL cannot be a base URL.")?
+                        .pop_if_empty()
+                        .extend([family_route(family), route, label]);
+                    let response = client.get(url).send().await?;
+                    if response.status() == reqwest::StatusCode::NOT_FOUND {
+                        continue;
+                    }
+                    let shape = response.error_for_status()?.json().await?;
+                    return Ok(tagged_shape(family, shape)?);
+                }
+                Err(Box::new(not_found()))
+            }
+            ShapeSource::Database(repository) => {
+                match repository
+                    .shape_with_aisc_manual_label(String::from(label))
+                    .await
+                {
+                    Err(err) if err.is_not_found() => Ok(repository
+                        .shape_with_edi_std_nomenclature(String::from(label))
+                        .await?),
+                    result => Ok(result?),
+                }
+            }
+            ShapeSource::Memory(shapes) => {
+                let aisc_manual_label = Designation::normalize_aisc_manual_label(label);
+                let edi_std_nomenclature = Designation::normalize_edi_std_nomenclature(label);
+                let metric_designation = label.to_ascii_uppercase();
+                shapes
+                    .iter()
+                    .find(|shape| {
+                        shape.aisc_manual_label().eq_ignore_ascii_case(label)
+                            || shape.aisc_manual_label() == aisc_manual_label
+                            || shape.edi_std_nomenclature().eq_ignore_ascii_case(label)
+                            || shape.edi_std_nomenclature() == edi_std_nomenclature
+                            || MetricDesignation::derive(
+                                shape.edi_std_nomenclature(),
+                                shape.aisc_manual_label(),
+                            )
+                            .is_some_and(|metric| metric.matches(&metric_designation))
+                    })
+                    .cloned()
+                    .ok_or_else(|| Box::new(not_found()) as Box<dyn Error>)
+            }
+        }
+    }
+
+    /// Retrieves the shapes of the family matching the query
+    pub async fn shapes(
+        &self,
+        family: ShapeFamily,
+        query: ShapeQuery,
+    ) -> Result<Vec<AiscShape>, Box<dyn Error>> {
+        match self {
+            ShapeSource::Api { client, url } => {
+                let mut url = url.clone();
+                url.path_segments_mut()
+                    .map_err(|_| "The API URL cannot be a base URL.")?
+                    .pop_if_empty()
+                    .push(family_route(family));
+                url.query_pairs_mut().extend_pairs(query_params(&query));
+                let shapes: Vec<serde_json::Value> = client
+                    .get(url)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                Ok(shapes
+                    .into_iter()
+                    .map(|shape| tagged_shape(family, shape))
+                    .collect::<Result<_, _>>()?)
+            }
+            ShapeSource::Database(repository) => {
+                Ok(repository.shapes_in_family(family, query).await?)
+            }
+            ShapeSource::Memory(shapes) => Ok(query.apply(
+                shapes
+                    .iter()
+                    .filter(|shape| shape.family() == family)
+                    .cloned()
+                    .collect(),
+            )),
+        }
+    }
+
+    /// Ranks the shapes of every family whose designations match the search
+    pub async fn search(
+        &self,
+        search: &DesignationSearch,
+    ) -> Result<Vec<DesignationMatch>, Box<dyn Error>> {
+        match self {
+            ShapeSource::Api { client, url } => {
+                let mut url = url.clone();
+                url.path_segments_mut()
+                    .map_err(|_| "The API URL cannot be a base URL.")?
+                    .pop_if_empty()
+                    .extend(["shapes", "search"]);
+                url.query_pairs_mut()
+                    .append_pair("q", &search.text)
+                    .append_pair("max_distance", &search.max_distance.to_string());
+                if let Some(limit) = search.limit {
+                    url.query_pairs_mut()
+                        .append_pair("limit", &limit.to_string());
+                }
+                Ok(client
+                    .get(url)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?)
+            }
+            ShapeSource::Database(repository) => Ok(repository.search_designations(search).await?),
+            ShapeSource::Memory(shapes) => Ok(search.rank_shapes(shapes)),
+        }
+    }
+}
+
+/// The path the API nests the routes of the family under
+fn family_route(family: ShapeFamily) -> &'static str {
+    match family {
+        ShapeFamily::WideFlange => "wide-flanges",
+        ShapeFamily::MiscBeam => "misc-beams",
+        ShapeFamily::StructuralBeam => "structural-beams",
+        ShapeFamily::HPile => "h-piles",
+        ShapeFamily::CeeChannel => "cee-channels",
+        ShapeFamily::MiscChannel => "misc-channels",
+        ShapeFamily::Angle => "angles",
+        ShapeFamily::WideFlangeTee => "wide-flange-tees",
+        ShapeFamily::MiscTee => "misc-tees",
+        ShapeFamily::StructuralTee => "structural-tees",
+        ShapeFamily::DoubleAngle => "double-angles",
+        ShapeFamily::HollowStructuralSection => "hollow-structural-sections",
+        ShapeFamily::RoundHollowStructuralSection => "round-hollow-structural-sections",
+        ShapeFamily::Pipe => "pipes",
+    }
+}
+
+/// The family routes of the API return untagged shapes, tags them with their family
+fn tagged_shape(
+    family: ShapeFamily,
+    mut shape: serde_json::Value,
+) -> Result<AiscShape, serde_json::Error> {
+    if let Some(fields) = shape.as_object_mut() {
+        fields.insert(String::from("family"), family.name().into());
+    }
+    serde_json::from_value(shape)
+}
+
+/// The query parameters of the family routes of the API expressing the query
+fn query_params(query: &ShapeQuery) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    for filter in &query.filters {
+        match filter {
+            PropertyFilter::AtLeast(property, min) => {
+                params.push((format!("min_{}", property.column_name()), min.to_string()))
+            }
+            PropertyFilter::AtMost(property, max) => {
+                params.push((format!("max_{}", property.column_name()), max.to_string()))
+            }
+            PropertyFilter::Approximately {
+                property, value, ..
+            } => params.push((String::from(property.column_name()), value.to_string())),
+        }
+    }
+    if let Some(tolerance) = query.filters.iter().find_map(|filter| match filter {
+        PropertyFilter::Approximately { tolerance, .. } => Some(tolerance),
+        _ => None,
+    }) {
+        params.push((String::from("tolerance"), tolerance.to_string()));
+    }
+    if let Some(prefix) = &query.label_prefix {
+        params.push((String::from("label_prefix"), prefix.clone()));
+    }
+    let order_by = query
+        .order
+        .iter()
+        .map(|order| (order.property.column_name(), order.direction))
+        .chain(query.label_order.map(|direction| ("label", direction)))
+        .map(|(key, direction)| match direction {
+            SortDirection::Ascending => String::from(key),
+            SortDirection::Descending => format!("-{key}"),
+        })
+        .collect::<Vec<_>>();
+    if !order_by.is_empty() {
+        params.push((String::from("order_by"), order_by.join(",")));
+    }
+    if let Some(offset) = query.offset {
+        params.push((String::from("offset"), offset.to_string()));
+    }
+    if let Some(limit) = query.limit {
+        params.push((String::from("limit"), limit.to_string()));
+    }
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shapes::aisc_shapes::ShapeProperty;
+
+    fn snapshot() -> ShapeSource {
+        ShapeSource::Memory(shapes::aisc_shapes::aisc_database::snapshot().to_vec())
+    }
+
+    #[tokio::test]
+    async fn memory_sources_look_up_either_designation() {
+        let source = snapshot();
+        let shape = source.shape("w18x35").await.unwrap();
+        assert_eq!("W18X35", shape.aisc_manual_label());
+        let shape = source.shape("L4X4X.5").await.unwrap();
+        assert_eq!("L4X4X1/2", shape.aisc_manual_label());
+        let shape = source.shape("W 18 x 35").await.unwrap();
+        assert_eq!("W18X35", shape.aisc_manual_label());
+        assert_eq!(Some(66.5), shape.property(ShapeProperty::Zx));
+        let shape = source.shape("Pipe2SCH40").await.unwrap();
+        assert_eq!("Pipe2STD", shape.aisc_manual_label());
+        let shape = source.shape("w360x134").await.unwrap();
+        assert_eq!("W14X90", shape.aisc_manual_label());
+        let err = source.shape("W18X36").await.unwrap_err();
+        assert!(
+            err.downcast_ref::<RepositoryError>()
+                .unwrap()
+                .is_not_found()
+        );
+    }
+
+    #[tokio::test]
+    async fn memory_sources_query_a_single_family() {
+        let query = ShapeQuery::new()
+            .with_min(ShapeProperty::Zx, 100.0)
+            .with_label_prefix("W14")
+            .with_order(ShapeProperty::WUpper, SortDirection::Ascending)
+            .with_limit(2);
+        let shapes = snapshot()
+            .shapes(ShapeFamily::WideFlange, query)
+            .await
+            .unwrap();
+        let labels: Vec<&str> = shapes.iter().map(|s| s.aisc_manual_label()).collect();
+        assert_eq!(vec!["W14X61", "W14X68"], labels);
+    }
+
+    #[tokio::test]
+    async fn memory_sources_search_every_family() {
+        let matches = snapshot()
+            .search(&DesignationSearch::new("hss6x").without_limit())
+            .await
+            .unwrap();
+        assert!(matches.len() > DesignationSearch::new("hss6x").limit.unwrap());
+        assert!(
+            matches
+                .iter()
+                .any(|m| m.family == ShapeFamily::RoundHollowStructuralSection)
+        );
+        assert!(
+            matches
+                .iter()
+                .any(|m| m.family == ShapeFamily::HollowStructuralSection)
+        );
+        let matches = snapshot()
+            .search(&DesignationSearch::new("W360X134"))
+            .await
+            .unwrap();
+        assert_eq!("W14X90", matches[0].aisc_manual_label);
+    }
+
+    #[tokio::test]
+    async fn api_sources_list_a_family_from_its_nested_route() {
+        // Nests the family route the way the shapes API does
+        let family_routes = axum::Router::new().route(
+            "/",
+            axum::routing::get(|| async {
+                let shapes: Vec<serde_json::Value> = shapes::aisc_shapes::aisc_database::snapshot()
+                    .iter()
+                    .filter_map(|shape| match shape {
+                        AiscShape::WideFlange(shape) => serde_json::to_value(shape).ok(),
+                        _ => None,
+                    })
+                    .take(3)
+                    .collect();
+                axum::Json(shapes)
+            }),
+        );
+        let app = axum::Router::new().nest("/wide-flanges", family_routes);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let source = ShapeSource::api(&format!("http://{address}/")).unwrap();
+        let shapes = source
+            .shapes(ShapeFamily::WideFlange, ShapeQuery::new().with_limit(3))
+            .await
+            .unwrap();
+        assert_eq!(3, shapes.len());
+        assert!(
+            shapes
+                .iter()
+                .all(|shape| shape.family() == ShapeFamily::WideFlange)
+        );
+    }
+
+    #[tokio::test]
+    async fn api_sources_fall_back_to_the_edi_std_nomenclature() {
+        // Serves pipes by EDI Std Nomenclature only, as Pipe2SCH40 is labeled Pipe2STD
+        let pipe = || {
+            shapes::aisc_shapes::aisc_database::snapshot()
+                .iter()
+                .find_map(|shape| match shape {
+                    AiscShape::Pipe(shape) if shape.edi_std_nomenclature == "Pipe2SCH40" => {
+                        Some(shape.clone())
+                    }
+                    _ => None,
+                })
+                .unwrap()
+        };
+        let pipe_routes = axum::Router::new()
+            .route(
+                "/label/{aisc_manual_label}",
+                axum::routing::get(|| async { axum::http::StatusCode::NOT_FOUND }),
+            )
+            .route(
+                "/edi/{edi_std_nomenclature}",
+                axum::routing::get(move || async move { axum::Json(pipe()) }),
+            );
+        let app = axum::Router::new().nest("/pipes", pipe_routes);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let source = ShapeSource::api(&format!("http://{address}")).unwrap();
+        let shape = source.shape("Pipe2SCH40").await.unwrap();
+        assert_eq!(ShapeFamily::Pipe, shape.family());
+        assert_eq!("Pipe2STD", shape.aisc_manual_label());
+        assert_eq!("Pipe2SCH40", shape.edi_std_nomenclature());
+    }
+
+    #[test]
+    fn queries_map_to_api_query_parameters() {
+        let query = ShapeQuery::new()
+            .with_min(ShapeProperty::Zx, 100.0)
+            .with_max(ShapeProperty::DLower, 14.5)
+            .with_approximate_value(ShapeProperty::Bf, 10.0, 0.1)
+            .with_label_prefix("W14")
+            .with_order(ShapeProperty::WUpper, SortDirection::Descending)
+            .with_label_order(SortDirection::Ascending)
+            .with_offset(10)
+            .with_limit(5);
+        let params = query_params(&query);
+        let params: Vec<(&str, &str)> = params
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("min_zx", "100"),
+                ("max_d_lower", "14.5"),
+                ("bf", "10"),
+                ("tolerance", "0.1"),
+                ("label_prefix", "W14"),
+                ("order_by", "-w_upper,label"),
+                ("offset", "10"),
+                ("limit", "5"),
+            ],
+            params
+        );
+    }
+
+    #[test]
+    fn api_shapes_are_tagged_with_their_family() {
+        let shape = shapes::aisc_shapes::aisc_database::snapshot()
+            .iter()
+            .find(|shape| shape.aisc_manual_label() == "HSS6X4X1/4")
+            .unwrap();
+        let AiscShape::HollowStructuralSection(hss) = shape else {
+            panic!("HSS6X4X1/4 is a rectangular HSS");
+        };
+        let untagged = serde_json::to_value(hss).unwrap();
+        assert_eq!(
+            *shape,
+            tagged_shape(ShapeFamily::HollowStructuralSection, untagged).unwrap()
+        );
+    }
+}
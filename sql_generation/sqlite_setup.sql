@@ -0,0 +1,560 @@
+DROP TABLE IF EXISTS wide_flanges;
+DROP TABLE IF EXISTS misc_beams;
+DROP TABLE IF EXISTS structural_beams;
+DROP TABLE IF EXISTS h_piles;
+DROP TABLE IF EXISTS cee_channels;
+DROP TABLE IF EXISTS misc_channels;
+DROP TABLE IF EXISTS angles;
+DROP TABLE IF EXISTS wide_flange_tees;
+DROP TABLE IF EXISTS misc_tees;
+DROP TABLE IF EXISTS structural_tees;
+DROP TABLE IF EXISTS double_angles;
+DROP TABLE IF EXISTS hollow_structural_sections;
+DROP TABLE IF EXISTS round_hollow_structural_sections;
+DROP TABLE IF EXISTS pipes;
+DROP TABLE IF EXISTS metric_designations;
+
+CREATE TABLE wide_flanges (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    t_f BOOLEAN NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    d_lower REAL NOT NULL,
+    ddet REAL NOT NULL,
+    bf REAL NOT NULL,
+    bfdet REAL NOT NULL,
+    tw REAL NOT NULL,
+    twdet REAL NOT NULL,
+    twdet_2 REAL NOT NULL,
+    tf REAL NOT NULL,
+    tfdet REAL NOT NULL,
+    kdes REAL NOT NULL,
+    kdet REAL NOT NULL,
+    k1 REAL NOT NULL,
+    bf_2tf REAL NOT NULL,
+    h_tw REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    cw REAL NOT NULL,
+    wno REAL NOT NULL,
+    sw1 REAL NOT NULL,
+    qf REAL NOT NULL,
+    qw REAL NOT NULL,
+    rts REAL NOT NULL,
+    ho REAL NOT NULL,
+    pa REAL NOT NULL,
+    pb REAL NOT NULL,
+    pc REAL NOT NULL,
+    pd REAL NOT NULL,
+    t REAL NOT NULL,
+    wgi REAL NOT NULL,
+    wgo REAL
+);
+
+CREATE TABLE misc_beams (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    t_f BOOLEAN,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    d_lower REAL NOT NULL,
+    ddet REAL NOT NULL,
+    bf REAL NOT NULL,
+    bfdet REAL NOT NULL,
+    tw REAL NOT NULL,
+    twdet REAL NOT NULL,
+    twdet_2 REAL NOT NULL,
+    tf REAL NOT NULL,
+    tfdet REAL NOT NULL,
+    kdes REAL NOT NULL,
+    kdet REAL NOT NULL,
+    k1 REAL NOT NULL,
+    bf_2tf REAL NOT NULL,
+    h_tw REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    cw REAL NOT NULL,
+    wno REAL NOT NULL,
+    sw1 REAL NOT NULL,
+    qf REAL NOT NULL,
+    qw REAL NOT NULL,
+    rts REAL NOT NULL,
+    ho REAL NOT NULL,
+    pa REAL NOT NULL,
+    pb REAL NOT NULL,
+    pc REAL NOT NULL,
+    pd REAL NOT NULL,
+    t REAL NOT NULL,
+    wgi REAL
+);
+
+CREATE TABLE structural_beams (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    d_lower REAL NOT NULL,
+    ddet REAL NOT NULL,
+    bf REAL NOT NULL,
+    bfdet REAL NOT NULL,
+    tw REAL NOT NULL,
+    twdet REAL NOT NULL,
+    twdet_2 REAL NOT NULL,
+    tf REAL NOT NULL,
+    tfdet REAL NOT NULL,
+    kdes REAL NOT NULL,
+    kdet REAL NOT NULL,
+    bf_2tf REAL NOT NULL,
+    h_tw REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    cw REAL NOT NULL,
+    wno REAL NOT NULL,
+    sw1 REAL NOT NULL,
+    qf REAL NOT NULL,
+    qw REAL NOT NULL,
+    rts REAL NOT NULL,
+    ho REAL NOT NULL,
+    pa REAL NOT NULL,
+    pb REAL NOT NULL,
+    pc REAL NOT NULL,
+    pd REAL NOT NULL,
+    t REAL NOT NULL,
+    wgi REAL
+);
+
+CREATE TABLE h_piles (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    d_lower REAL NOT NULL,
+    ddet REAL NOT NULL,
+    bf REAL NOT NULL,
+    bfdet REAL NOT NULL,
+    tw REAL NOT NULL,
+    twdet REAL NOT NULL,
+    twdet_2 REAL NOT NULL,
+    tf REAL NOT NULL,
+    tfdet REAL NOT NULL,
+    kdes REAL NOT NULL,
+    kdet REAL NOT NULL,
+    k1 REAL NOT NULL,
+    bf_2tf REAL NOT NULL,
+    h_tw REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    cw REAL NOT NULL,
+    wno REAL NOT NULL,
+    sw1 REAL NOT NULL,
+    qf REAL NOT NULL,
+    qw REAL NOT NULL,
+    rts REAL NOT NULL,
+    ho REAL NOT NULL,
+    pa REAL NOT NULL,
+    pb REAL NOT NULL,
+    pc REAL NOT NULL,
+    pd REAL NOT NULL,
+    t REAL NOT NULL,
+    wgi REAL NOT NULL
+);
+
+CREATE TABLE cee_channels (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    d_lower REAL NOT NULL,
+    ddet REAL NOT NULL,
+    bf REAL NOT NULL,
+    bfdet REAL NOT NULL,
+    tw REAL NOT NULL,
+    twdet REAL NOT NULL,
+    twdet_2 REAL NOT NULL,
+    tf REAL NOT NULL,
+    tfdet REAL NOT NULL,
+    kdes REAL NOT NULL,
+    kdet REAL NOT NULL,
+    x_lower REAL NOT NULL,
+    eo REAL NOT NULL,
+    xp REAL NOT NULL,
+    b_t REAL NOT NULL,
+    h_tw REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    cw REAL NOT NULL,
+    wno REAL NOT NULL,
+    sw1 REAL NOT NULL,
+    sw2 REAL NOT NULL,
+    sw3 REAL NOT NULL,
+    qf REAL NOT NULL,
+    qw REAL NOT NULL,
+    ro REAL NOT NULL,
+    h_upper REAL NOT NULL,
+    rts REAL NOT NULL,
+    ho REAL NOT NULL,
+    pa REAL NOT NULL,
+    pb REAL NOT NULL,
+    pc REAL NOT NULL,
+    pd REAL NOT NULL,
+    t REAL NOT NULL,
+    wgi REAL
+);
+
+CREATE TABLE misc_channels (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    d_lower REAL NOT NULL,
+    ddet REAL NOT NULL,
+    bf REAL NOT NULL,
+    bfdet REAL NOT NULL,
+    tw REAL NOT NULL,
+    twdet REAL NOT NULL,
+    twdet_2 REAL NOT NULL,
+    tf REAL NOT NULL,
+    tfdet REAL NOT NULL,
+    kdes REAL NOT NULL,
+    kdet REAL NOT NULL,
+    x_lower REAL NOT NULL,
+    eo REAL NOT NULL,
+    xp REAL NOT NULL,
+    b_t REAL NOT NULL,
+    h_tw REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    cw REAL NOT NULL,
+    wno REAL NOT NULL,
+    sw1 REAL NOT NULL,
+    sw2 REAL NOT NULL,
+    sw3 REAL NOT NULL,
+    qf REAL NOT NULL,
+    qw REAL NOT NULL,
+    ro REAL NOT NULL,
+    h_upper REAL NOT NULL,
+    rts REAL NOT NULL,
+    ho REAL NOT NULL,
+    pa REAL NOT NULL,
+    pb REAL NOT NULL,
+    pc REAL NOT NULL,
+    pd REAL NOT NULL,
+    t REAL NOT NULL,
+    wgi REAL
+);
+
+CREATE TABLE angles (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    d_lower REAL NOT NULL,
+    b_lower REAL NOT NULL,
+    t_lower REAL NOT NULL,
+    kdes REAL NOT NULL,
+    kdet REAL NOT NULL,
+    x_lower REAL NOT NULL,
+    y_lower REAL NOT NULL,
+    xp REAL NOT NULL,
+    yp REAL NOT NULL,
+    b_t REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    iz REAL NOT NULL,
+    rz REAL NOT NULL,
+    sz REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    cw REAL NOT NULL,
+    ro REAL NOT NULL,
+    h_upper REAL,
+    tan_a REAL NOT NULL,
+    iw REAL NOT NULL,
+    za REAL NOT NULL,
+    zb REAL NOT NULL,
+    zc REAL NOT NULL,
+    wa REAL NOT NULL,
+    wb REAL NOT NULL,
+    wc REAL NOT NULL,
+    swa REAL NOT NULL,
+    swb REAL,
+    swc REAL NOT NULL,
+    sza REAL NOT NULL,
+    szb REAL NOT NULL,
+    szc REAL NOT NULL,
+    pa REAL NOT NULL,
+    pa_2 REAL NOT NULL,
+    pb REAL NOT NULL
+);
+
+CREATE TABLE wide_flange_tees (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    t_f BOOLEAN NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    d_lower REAL NOT NULL,
+    ddet REAL NOT NULL,
+    bf REAL NOT NULL,
+    bfdet REAL NOT NULL,
+    tw REAL NOT NULL,
+    twdet REAL NOT NULL,
+    twdet_2 REAL NOT NULL,
+    tf REAL NOT NULL,
+    tfdet REAL NOT NULL,
+    kdes REAL NOT NULL,
+    kdet REAL NOT NULL,
+    y_lower REAL NOT NULL,
+    yp REAL NOT NULL,
+    bf_2tf REAL NOT NULL,
+    d_t REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    cw REAL NOT NULL,
+    ro REAL NOT NULL,
+    h_upper REAL NOT NULL,
+    pa REAL NOT NULL,
+    pb REAL NOT NULL,
+    pc REAL NOT NULL,
+    pd REAL NOT NULL,
+    wgi REAL NOT NULL,
+    wgo REAL
+);
+
+CREATE TABLE misc_tees (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    t_f BOOLEAN NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    d_lower REAL NOT NULL,
+    ddet REAL NOT NULL,
+    bf REAL NOT NULL,
+    bfdet REAL NOT NULL,
+    tw REAL NOT NULL,
+    twdet REAL NOT NULL,
+    twdet_2 REAL NOT NULL,
+    tf REAL NOT NULL,
+    tfdet REAL NOT NULL,
+    kdes REAL NOT NULL,
+    kdet REAL NOT NULL,
+    y_lower REAL NOT NULL,
+    yp REAL NOT NULL,
+    bf_2tf REAL NOT NULL,
+    d_t REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    cw REAL NOT NULL,
+    ro REAL NOT NULL,
+    h_upper REAL NOT NULL,
+    wgi REAL
+);
+
+CREATE TABLE structural_tees (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    d_lower REAL NOT NULL,
+    ddet REAL NOT NULL,
+    bf REAL NOT NULL,
+    bfdet REAL NOT NULL,
+    tw REAL NOT NULL,
+    twdet REAL NOT NULL,
+    twdet_2 REAL NOT NULL,
+    tf REAL NOT NULL,
+    tfdet REAL NOT NULL,
+    kdes REAL NOT NULL,
+    kdet REAL NOT NULL,
+    y_lower REAL NOT NULL,
+    yp REAL NOT NULL,
+    bf_2tf REAL NOT NULL,
+    d_t REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    cw REAL NOT NULL,
+    ro REAL NOT NULL,
+    h_upper REAL NOT NULL,
+    wgi REAL
+);
+
+CREATE TABLE double_angles (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    d_lower REAL NOT NULL,
+    b_lower REAL NOT NULL,
+    t_lower REAL NOT NULL,
+    y_lower REAL NOT NULL,
+    yp REAL NOT NULL,
+    b_t REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    ro REAL NOT NULL,
+    h_upper REAL NOT NULL
+);
+
+CREATE TABLE hollow_structural_sections (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    ht REAL NOT NULL,
+    h REAL NOT NULL,
+    b_upper REAL NOT NULL,
+    b_lower REAL NOT NULL,
+    t_nom REAL NOT NULL,
+    tdes REAL NOT NULL,
+    b_tdes REAL NOT NULL,
+    h_tdes REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    c_upper REAL NOT NULL
+);
+
+CREATE TABLE round_hollow_structural_sections (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    od REAL NOT NULL,
+    t_nom REAL NOT NULL,
+    tdes REAL NOT NULL,
+    d_t REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL,
+    c_upper REAL NOT NULL
+);
+
+CREATE TABLE pipes (
+    shape_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    w_upper REAL NOT NULL,
+    a_upper REAL NOT NULL,
+    od REAL NOT NULL,
+    id REAL NOT NULL,
+    t_nom REAL NOT NULL,
+    tdes REAL NOT NULL,
+    d_t REAL NOT NULL,
+    ix REAL NOT NULL,
+    zx REAL NOT NULL,
+    sx REAL NOT NULL,
+    rx REAL NOT NULL,
+    iy REAL NOT NULL,
+    zy REAL NOT NULL,
+    sy REAL NOT NULL,
+    ry REAL NOT NULL,
+    j_upper REAL NOT NULL
+);
+
+CREATE TABLE metric_designations (
+    designation_id INTEGER PRIMARY KEY,
+    edi_std_nomenclature TEXT NOT NULL,
+    aisc_manual_label TEXT NOT NULL,
+    metric_edi_std_nomenclature TEXT NOT NULL,
+    metric_aisc_manual_label TEXT NOT NULL
+);
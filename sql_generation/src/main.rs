@@ -1,76 +1,71 @@
-use shapes::aisc_shapes::{self, *};
+use shape_repositories::repositories::sqlite_schema;
+use shapes::aisc_shapes::aisc_database::{
+    read_metric_designations_from_path, read_shapes_from_path, shapes_of,
+};
+use shapes::aisc_shapes::*;
 use std::{error::Error, fs::File, io::Write};
 
 fn main() {
-    let result = parse_csv_to_sql();
+    let metric_csv = std::env::args().nth(1);
+    let result = parse_csv_to_sql(metric_csv.as_deref());
     match result {
         Ok(()) => (),
         Err(err) => {
             println!("{:?}", err);
+            std::process::exit(1);
         }
     }
 }
 
-fn parse_csv_to_sql() -> Result<(), Box<dyn Error>> {
-    let wide_flange_shapes = get_shapes::<WideFlange>(|r| r[TYPE_INDEX].eq("W"), parse_wide_flange);
+/// Writes the insert scripts of the shapes and their metric designations, read from
+/// the metric sheet at `metric_csv` if given or derived from the US customary designations
+fn parse_csv_to_sql(metric_csv: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let shapes = read_shapes_from_path(SHAPES_CSV)?;
+    // The metric sheet is exported with the same header as the US customary sheet
+    let designations = match metric_csv {
+        Some(path) => read_metric_designations_from_path(&shapes, path)?,
+        None => MetricDesignation::derive_all(&shapes),
+    };
+    println!("There are {} metric designations", &designations.len());
+    let wide_flange_shapes = shapes_of::<WideFlange>(shapes.clone());
     println!("There are {} wide flange beams", &wide_flange_shapes.len());
-    let misc_beam_shapes = get_shapes::<MiscBeam>(|r| r[TYPE_INDEX].eq("M"), parse_misc_beam);
+    let misc_beam_shapes = shapes_of::<MiscBeam>(shapes.clone());
     println!("There are {} misc beams", &misc_beam_shapes.len());
-    let structural_beam_shapes =
-        get_shapes::<StructuralBeam>(|r| r[TYPE_INDEX].eq("S"), parse_structural_beam);
+    let structural_beam_shapes = shapes_of::<StructuralBeam>(shapes.clone());
     println!(
         "There are {} structural beams",
         &structural_beam_shapes.len()
     );
-    let h_pile_shapes = get_shapes::<HPile>(|r| r[TYPE_INDEX].eq("HP"), parse_h_pile);
+    let h_pile_shapes = shapes_of::<HPile>(shapes.clone());
     println!("There are {} h-piles", &h_pile_shapes.len());
-    let cee_channels = get_shapes::<CeeChannel>(|r| r[TYPE_INDEX].eq("C"), parse_cee_channel);
+    let cee_channels = shapes_of::<CeeChannel>(shapes.clone());
     println!("There are {} cee channels", &cee_channels.len());
-    let misc_channels = get_shapes::<MiscChannel>(|r| r[TYPE_INDEX].eq("MC"), parse_misc_channel);
+    let misc_channels = shapes_of::<MiscChannel>(shapes.clone());
     println!("There are {} misc. channels", &misc_channels.len());
-    let angles = get_shapes::<Angle>(|r| r[TYPE_INDEX].eq("L"), parse_angles);
+    let angles = shapes_of::<Angle>(shapes.clone());
     println!("There are {} angles", &angles.len());
-    let wide_flange_tee_shapes =
-        get_shapes::<WideFlangeTee>(|r| r[TYPE_INDEX].eq("WT"), parse_wide_flange_tee);
+    let wide_flange_tee_shapes = shapes_of::<WideFlangeTee>(shapes.clone());
     println!(
         "There are {} wide flange tee shapes",
         &wide_flange_tee_shapes.len()
     );
-    let misc_tee_shapes = get_shapes::<MiscTee>(|r| r[TYPE_INDEX].eq("MT"), parse_misc_tee);
+    let misc_tee_shapes = shapes_of::<MiscTee>(shapes.clone());
     println!("There are {} misc. tee shapes", &misc_tee_shapes.len());
-    let structural_tee_shapes =
-        get_shapes::<StructuralTee>(|r| r[TYPE_INDEX].eq("ST"), parse_structural_tee);
+    let structural_tee_shapes = shapes_of::<StructuralTee>(shapes.clone());
     println!(
         "There are {} structural tee shapes",
         &structural_tee_shapes.len()
     );
-    let double_angle_shapes =
-        get_shapes::<DoubleAngle>(|r| r[TYPE_INDEX].eq("2L"), parse_double_angle);
+    let double_angle_shapes = shapes_of::<DoubleAngle>(shapes.clone());
     println!(
         "There are {} double angle shapes",
         &double_angle_shapes.len()
     );
-    let hss_shapes = get_shapes::<HollowStructuralSection>(
-        |r| {
-            let shape_type = r[TYPE_INDEX].to_owned();
-            let edi_nom = r[EDI_NOM].to_owned();
-            return shape_type.eq("HSS")
-                && edi_nom.chars().filter(|c| c.eq(&'X')).count() == (2 as usize);
-        },
-        parse_sq_rec_hss,
-    );
+    let hss_shapes = shapes_of::<HollowStructuralSection>(shapes.clone());
     println!("There are {} HSS shapes", &hss_shapes.len());
-    let hss_round_shapes = get_shapes(
-        |r| {
-            let shape_type = r[TYPE_INDEX].to_owned();
-            let edi_nom = r[EDI_NOM].to_owned();
-            return shape_type.eq("HSS")
-                && edi_nom.chars().filter(|c| c.eq(&'X')).count() == (1 as usize);
-        },
-        parse_hss_round,
-    );
+    let hss_round_shapes = shapes_of::<RoundHollowStructuralSection>(shapes.clone());
     println!("There are {} HSS round shapes", &hss_round_shapes.len());
-    let pipe_shapes = get_shapes(|p| p[TYPE_INDEX].eq("PIPE"), parse_pipe);
+    let pipe_shapes = shapes_of::<Pipe>(shapes.clone());
     println!("There are {} Pipe shapes", &pipe_shapes.len());
     let wide_flange_sql = sql_from_wide_flange(wide_flange_shapes);
     write_sql_to_file(String::from("wide_flanges.sql"), wide_flange_sql)?;
@@ -103,9 +98,20 @@ fn parse_csv_to_sql() -> Result<(), Box<dyn Error>> {
     )?;
     let pipe_sql = sql_from_pipe(pipe_shapes);
     write_sql_to_file(String::from("pipes.sql"), pipe_sql)?;
+    write_sql_to_file(String::from("sqlite_setup.sql"), sqlite_schema())?;
+    let metric_designation_sql = sql_from_metric_designations(designations);
+    write_sql_to_file(
+        String::from("metric_designations.sql"),
+        metric_designation_sql,
+    )?;
     Ok(())
 }
 
+const SHAPES_CSV: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../shapes/aisc-shapes-database-v16.0.csv"
+);
+
 fn write_sql_to_file(file_name: String, sql: String) -> Result<(), std::io::Error> {
     let mut file = File::create(file_name)?;
     file.write_all(sql.as_bytes())?;
@@ -114,12 +120,42 @@ fn write_sql_to_file(file_name: String, sql: String) -> Result<(), std::io::Erro
 
 fn nullable_sql_string<T: std::fmt::Display>(maybe_value: Option<T>) -> String {
     match maybe_value {
-        Some(val) => String::from(format!("{}", val)),
+        Some(val) => format!("{}", val),
         None => String::from("NULL"),
     }
 }
 
 // sql generation methods
+fn sql_from_metric_designations(designations: Vec<MetricDesignation>) -> String {
+    let mut sql = String::new();
+    sql.push_str(
+        "INSERT INTO metric_designations (
+    edi_std_nomenclature,
+    aisc_manual_label,
+    metric_edi_std_nomenclature,
+    metric_aisc_manual_label
+    ) \nVALUES \n",
+    );
+    let rows = designations
+        .iter()
+        .map(metric_designation_to_row)
+        .collect::<Vec<_>>();
+    let row_string = rows.join(", \n");
+    sql.push_str(&row_string);
+    sql.push(';');
+    sql
+}
+
+fn metric_designation_to_row(designation: &MetricDesignation) -> String {
+    format!(
+        "('{}','{}','{}','{}')",
+        designation.edi_std_nomenclature,
+        designation.aisc_manual_label,
+        designation.metric_edi_std_nomenclature,
+        designation.metric_aisc_manual_label
+    )
+}
+
 fn sql_from_pipe(shapes: Vec<Pipe>) -> String {
     let mut sql = String::new();
     sql.push_str(
@@ -144,15 +180,15 @@ fn sql_from_pipe(shapes: Vec<Pipe>) -> String {
     j_upper
     ) \nVALUES \n",
     );
-    let rows = shapes.iter().map(|h| pipe_to_row(h)).collect::<Vec<_>>();
+    let rows = shapes.iter().map(pipe_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn pipe_to_row(shape: &Pipe) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -172,7 +208,7 @@ fn pipe_to_row(shape: &Pipe) -> String {
         shape.sy,
         shape.ry,
         shape.j_upper,
-    ))
+    )
 }
 
 fn sql_from_hss_round(shapes: Vec<RoundHollowStructuralSection>) -> String {
@@ -199,18 +235,15 @@ fn sql_from_hss_round(shapes: Vec<RoundHollowStructuralSection>) -> String {
     c_upper
     ) \nVALUES \n",
     );
-    let rows = shapes
-        .iter()
-        .map(|h| hss_round_to_row(h))
-        .collect::<Vec<_>>();
+    let rows = shapes.iter().map(hss_round_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn hss_round_to_row(shape: &RoundHollowStructuralSection) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -230,7 +263,7 @@ fn hss_round_to_row(shape: &RoundHollowStructuralSection) -> String {
         shape.ry,
         shape.j_upper,
         shape.c_upper
-    ))
+    )
 }
 
 fn sql_from_hss(shapes: Vec<HollowStructuralSection>) -> String {
@@ -261,15 +294,15 @@ fn sql_from_hss(shapes: Vec<HollowStructuralSection>) -> String {
     c_upper
     ) \nVALUES \n",
     );
-    let rows = shapes.iter().map(|h| hss_to_row(h)).collect::<Vec<_>>();
+    let rows = shapes.iter().map(hss_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn hss_to_row(shape: &HollowStructuralSection) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -293,7 +326,7 @@ fn hss_to_row(shape: &HollowStructuralSection) -> String {
         shape.ry,
         shape.j_upper,
         shape.c_upper
-    ))
+    )
 }
 
 fn sql_from_double_angles(shapes: Vec<DoubleAngle>) -> String {
@@ -322,18 +355,15 @@ fn sql_from_double_angles(shapes: Vec<DoubleAngle>) -> String {
     h_upper
     ) \nVALUES \n",
     );
-    let rows = shapes
-        .iter()
-        .map(|a| double_angle_to_row(a))
-        .collect::<Vec<_>>();
+    let rows = shapes.iter().map(double_angle_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn double_angle_to_row(shape: &DoubleAngle) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -355,7 +385,7 @@ fn double_angle_to_row(shape: &DoubleAngle) -> String {
         shape.ry,
         shape.ro,
         shape.h_upper,
-    ))
+    )
 }
 
 fn sql_from_structural_tees(shapes: Vec<StructuralTee>) -> String {
@@ -396,18 +426,15 @@ fn sql_from_structural_tees(shapes: Vec<StructuralTee>) -> String {
     wgi
     ) \nVALUES \n",
     );
-    let rows = shapes
-        .iter()
-        .map(|m| structural_tee_to_row(m))
-        .collect::<Vec<_>>();
+    let rows = shapes.iter().map(structural_tee_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn structural_tee_to_row(shape: &StructuralTee) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -441,7 +468,7 @@ fn structural_tee_to_row(shape: &StructuralTee) -> String {
         shape.ro,
         shape.h_upper,
         nullable_sql_string(shape.wgi)
-    ))
+    )
 }
 
 fn sql_from_misc_tees(shapes: Vec<MiscTee>) -> String {
@@ -483,18 +510,15 @@ fn sql_from_misc_tees(shapes: Vec<MiscTee>) -> String {
     wgi
     ) \nVALUES \n",
     );
-    let rows = shapes
-        .iter()
-        .map(|m| misc_tee_to_row(m))
-        .collect::<Vec<_>>();
+    let rows = shapes.iter().map(misc_tee_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn misc_tee_to_row(shape: &MiscTee) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -529,7 +553,7 @@ fn misc_tee_to_row(shape: &MiscTee) -> String {
         shape.ro,
         shape.h_upper,
         nullable_sql_string(shape.wgi)
-    ))
+    )
 }
 
 fn sql_from_wide_flange_tees(shapes: Vec<WideFlangeTee>) -> String {
@@ -578,16 +602,16 @@ fn sql_from_wide_flange_tees(shapes: Vec<WideFlangeTee>) -> String {
     );
     let rows = shapes
         .iter()
-        .map(|w| wide_flange_tee_to_row(w))
+        .map(wide_flange_tee_to_row)
         .collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn wide_flange_tee_to_row(shape: &WideFlangeTee) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -627,7 +651,7 @@ fn wide_flange_tee_to_row(shape: &WideFlangeTee) -> String {
         shape.pd,
         shape.wgi,
         nullable_sql_string(shape.wgo)
-    ))
+    )
 }
 
 fn sql_from_angles(shapes: Vec<Angle>) -> String {
@@ -682,15 +706,15 @@ fn sql_from_angles(shapes: Vec<Angle>) -> String {
     pb
     ) \nVALUES \n",
     );
-    let rows = shapes.iter().map(|c| angle_to_row(c)).collect::<Vec<_>>();
+    let rows = shapes.iter().map(angle_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn angle_to_row(shape: &Angle) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -738,7 +762,7 @@ fn angle_to_row(shape: &Angle) -> String {
         shape.pa,
         shape.pa_2,
         shape.pb,
-    ))
+    )
 }
 
 fn sql_from_misc_channels(shapes: Vec<MiscChannel>) -> String {
@@ -793,18 +817,15 @@ fn sql_from_misc_channels(shapes: Vec<MiscChannel>) -> String {
     wgi
     ) \nVALUES \n",
     );
-    let rows = shapes
-        .iter()
-        .map(|c| misc_channel_to_row(c))
-        .collect::<Vec<_>>();
+    let rows = shapes.iter().map(misc_channel_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn misc_channel_to_row(shape: &MiscChannel) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -852,7 +873,7 @@ fn misc_channel_to_row(shape: &MiscChannel) -> String {
         shape.pd,
         shape.t,
         nullable_sql_string(shape.wgi)
-    ))
+    )
 }
 
 fn sql_from_cee_channels(shapes: Vec<CeeChannel>) -> String {
@@ -907,18 +928,15 @@ fn sql_from_cee_channels(shapes: Vec<CeeChannel>) -> String {
     wgi
     ) \nVALUES \n",
     );
-    let rows = shapes
-        .iter()
-        .map(|c| cee_channel_to_row(c))
-        .collect::<Vec<_>>();
+    let rows = shapes.iter().map(cee_channel_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn cee_channel_to_row(shape: &CeeChannel) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -966,7 +984,7 @@ fn cee_channel_to_row(shape: &CeeChannel) -> String {
         shape.pd,
         shape.t,
         nullable_sql_string(shape.wgi)
-    ))
+    )
 }
 
 fn sql_from_h_piles(shapes: Vec<HPile>) -> String {
@@ -1015,15 +1033,15 @@ fn sql_from_h_piles(shapes: Vec<HPile>) -> String {
     wgi
     ) \nVALUES \n",
     );
-    let rows = shapes.iter().map(|h| h_pile_to_row(h)).collect::<Vec<_>>();
+    let rows = shapes.iter().map(h_pile_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn h_pile_to_row(shape: &HPile) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -1065,7 +1083,7 @@ fn h_pile_to_row(shape: &HPile) -> String {
         shape.pd,
         shape.t,
         shape.wgi
-    ))
+    )
 }
 
 fn sql_from_structural_beam(shapes: Vec<StructuralBeam>) -> String {
@@ -1115,16 +1133,16 @@ fn sql_from_structural_beam(shapes: Vec<StructuralBeam>) -> String {
     );
     let rows = shapes
         .iter()
-        .map(|s| structural_beam_to_row(s))
+        .map(structural_beam_to_row)
         .collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn structural_beam_to_row(shape: &StructuralBeam) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         shape.edi_std_nomenclature,
         shape.aisc_manual_label,
@@ -1165,7 +1183,7 @@ fn structural_beam_to_row(shape: &StructuralBeam) -> String {
         shape.pd,
         shape.t,
         nullable_sql_string(shape.wgi)
-    ))
+    )
 }
 
 fn sql_from_misc_beam(shapes: Vec<MiscBeam>) -> String {
@@ -1215,18 +1233,15 @@ fn sql_from_misc_beam(shapes: Vec<MiscBeam>) -> String {
     wgi
     ) \nVALUES \n",
     );
-    let rows = shapes
-        .iter()
-        .map(|m| misc_beam_to_row(m))
-        .collect::<Vec<_>>();
+    let rows = shapes.iter().map(misc_beam_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn misc_beam_to_row(misc_beam: &MiscBeam) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         misc_beam.edi_std_nomenclature,
         misc_beam.aisc_manual_label,
@@ -1269,7 +1284,7 @@ fn misc_beam_to_row(misc_beam: &MiscBeam) -> String {
         misc_beam.pd,
         misc_beam.t,
         nullable_sql_string(misc_beam.wgi)
-    ))
+    )
 }
 
 fn sql_from_wide_flange(shapes: Vec<WideFlange>) -> String {
@@ -1320,18 +1335,15 @@ fn sql_from_wide_flange(shapes: Vec<WideFlange>) -> String {
     wgo
     ) \nVALUES \n",
     );
-    let rows = shapes
-        .iter()
-        .map(|w| wide_flange_to_row(w))
-        .collect::<Vec<_>>();
+    let rows = shapes.iter().map(wide_flange_to_row).collect::<Vec<_>>();
     let row_string = rows.join(", \n");
     sql.push_str(&row_string);
-    sql.push_str(";");
+    sql.push(';');
     sql
 }
 
 fn wide_flange_to_row(wide_flange: &WideFlange) -> String {
-    String::from(format!(
+    format!(
         "('{}','{}',{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{})",
         wide_flange.edi_std_nomenclature,
         wide_flange.aisc_manual_label,
@@ -1375,850 +1387,5 @@ fn wide_flange_to_row(wide_flange: &WideFlange) -> String {
         wide_flange.t,
         wide_flange.wgi,
         nullable_sql_string(wide_flange.wgo)
-    ))
-}
-
-// higher level function to extract shapes of a given type from the
-// csv file and map the data to a list of the corresponding shape
-fn get_shapes<T>(
-    condition: fn(&csv::StringRecord) -> bool,
-    parse: fn(&csv::StringRecord) -> Result<T, MissingPropertyError>,
-) -> Vec<T> {
-    let mut rdr = get_csv_reader();
-    let records = &rdr
-        .records()
-        .filter(|r: &Result<csv::StringRecord, csv::Error>| r.is_ok())
-        .filter(|r| condition(*&r.as_ref().unwrap()))
-        .map(|r| r.unwrap())
-        .collect::<Vec<_>>();
-
-    records
-        .iter()
-        .map(|r| parse(r).unwrap())
-        .collect::<Vec<_>>()
-}
-
-// parse one shape from one csv string record
-fn parse_pipe(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::Pipe, aisc_shapes::errors::MissingPropertyError> {
-    ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_od(maybe_float(&record[OD]).unwrap())
-        .with_id(maybe_float(&record[ID]).unwrap())
-        .with_t_nom(maybe_float(&record[T_NOM]).unwrap())
-        .with_tdes(maybe_float(&record[T_DES]).unwrap())
-        .with_d_t(maybe_float(&record[D_T]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .try_into()
-}
-
-fn parse_hss_round(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::RoundHollowStructuralSection, aisc_shapes::errors::MissingPropertyError> {
-    ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_od(maybe_float(&record[OD]).unwrap())
-        .with_t_nom(maybe_float(&record[T_NOM]).unwrap())
-        .with_tdes(maybe_float(&record[T_DES]).unwrap())
-        .with_d_t(maybe_float(&record[D_T]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_c_upper(maybe_float(&record[C_UPPER]).unwrap())
-        .try_into()
-}
-
-fn parse_sq_rec_hss(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::HollowStructuralSection, aisc_shapes::errors::MissingPropertyError> {
-    ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_ht(maybe_float(&record[HT]).unwrap())
-        .with_h(maybe_float(&record[H]).unwrap())
-        .with_b_upper(maybe_float(&record[B_UPPER]).unwrap())
-        .with_b_lower(maybe_float(&record[B_LOWER]).unwrap())
-        .with_t_nom(maybe_float(&record[T_NOM]).unwrap())
-        .with_tdes(maybe_float(&record[T_DES]).unwrap())
-        .with_b_tdes(maybe_float(&record[B_TDES]).unwrap())
-        .with_h_tdes(maybe_float(&record[H_TDES]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_c_upper(maybe_float(&record[C_UPPER]).unwrap())
-        .try_into()
-}
-
-fn parse_double_angle(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::DoubleAngle, aisc_shapes::errors::MissingPropertyError> {
-    ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_d_lower(maybe_float(&record[D_LOWER]).unwrap())
-        .with_b_lower(maybe_float(&record[B_LOWER]).unwrap())
-        .with_t_lower(maybe_float(&record[T_LOWER]).unwrap())
-        .with_y_lower(maybe_float(&record[Y_LOWER]).unwrap())
-        .with_yp(maybe_float(&record[YP]).unwrap())
-        .with_b_t(maybe_float(&record[B_T]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_ro(maybe_float(&record[RO]).unwrap())
-        .with_h_upper(maybe_float(&record[H_UPPER]).unwrap())
-        .try_into()
-}
-fn parse_structural_tee(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::StructuralTee, aisc_shapes::errors::MissingPropertyError> {
-    let maybe_wgi = maybe_float(&record[WGI]);
-
-    let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_d_lower(maybe_float(&record[D_LOWER]).unwrap())
-        .with_ddet(maybe_float(&record[DDET]).unwrap())
-        .with_bf(maybe_float(&record[BF]).unwrap())
-        .with_bfdet(maybe_float(&record[BFDET]).unwrap())
-        .with_tw(maybe_float(&record[TW]).unwrap())
-        .with_twdet(maybe_float(&record[TWDET]).unwrap())
-        .with_twdet_2(maybe_float(&record[TWDET_2]).unwrap())
-        .with_tf(maybe_float(&record[TF]).unwrap())
-        .with_tfdet(maybe_float(&record[TFDET]).unwrap())
-        .with_kdes(maybe_float(&record[K_DES]).unwrap())
-        .with_kdet(maybe_float(&record[K_DET]).unwrap())
-        .with_y_lower(maybe_float(&record[Y_LOWER]).unwrap())
-        .with_yp(maybe_float(&record[YP]).unwrap())
-        .with_bf_2tf(maybe_float(&record[BF_2TF]).unwrap())
-        .with_d_t(maybe_float(&record[D_T]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_cw(maybe_float(&record[CW]).unwrap())
-        .with_ro(maybe_float(&record[RO]).unwrap())
-        .with_h_upper(maybe_float(&record[H_UPPER]).unwrap());
-
-    if let Some(wgi) = maybe_wgi {
-        return builder.with_wgi(wgi).try_into();
-    }
-    builder.try_into()
-}
-
-fn parse_misc_tee(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::MiscTee, aisc_shapes::errors::MissingPropertyError> {
-    let maybe_wgi = maybe_float(&record[WGI]);
-
-    let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_t_f(maybe_bool(&record[T_F]).unwrap())
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_d_lower(maybe_float(&record[D_LOWER]).unwrap())
-        .with_ddet(maybe_float(&record[DDET]).unwrap())
-        .with_bf(maybe_float(&record[BF]).unwrap())
-        .with_bfdet(maybe_float(&record[BFDET]).unwrap())
-        .with_tw(maybe_float(&record[TW]).unwrap())
-        .with_twdet(maybe_float(&record[TWDET]).unwrap())
-        .with_twdet_2(maybe_float(&record[TWDET_2]).unwrap())
-        .with_tf(maybe_float(&record[TF]).unwrap())
-        .with_tfdet(maybe_float(&record[TFDET]).unwrap())
-        .with_kdes(maybe_float(&record[K_DES]).unwrap())
-        .with_kdet(maybe_float(&record[K_DET]).unwrap())
-        .with_y_lower(maybe_float(&record[Y_LOWER]).unwrap())
-        .with_yp(maybe_float(&record[YP]).unwrap())
-        .with_bf_2tf(maybe_float(&record[BF_2TF]).unwrap())
-        .with_d_t(maybe_float(&record[D_T]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_cw(maybe_float(&record[CW]).unwrap())
-        .with_ro(maybe_float(&record[RO]).unwrap())
-        .with_h_upper(maybe_float(&record[H_UPPER]).unwrap());
-
-    if let Some(wgi) = maybe_wgi {
-        return builder.with_wgi(wgi).try_into();
-    }
-    builder.try_into()
+    )
 }
-
-fn parse_wide_flange_tee(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::WideFlangeTee, aisc_shapes::errors::MissingPropertyError> {
-    let maybe_wgo = maybe_float(&record[WGO]);
-    let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_t_f(maybe_bool(&record[T_F]).unwrap())
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_d_lower(maybe_float(&record[D_LOWER]).unwrap())
-        .with_ddet(maybe_float(&record[DDET]).unwrap())
-        .with_bf(maybe_float(&record[BF]).unwrap())
-        .with_bfdet(maybe_float(&record[BFDET]).unwrap())
-        .with_tw(maybe_float(&record[TW]).unwrap())
-        .with_twdet(maybe_float(&record[TWDET]).unwrap())
-        .with_twdet_2(maybe_float(&record[TWDET_2]).unwrap())
-        .with_tf(maybe_float(&record[TF]).unwrap())
-        .with_tfdet(maybe_float(&record[TFDET]).unwrap())
-        .with_kdes(maybe_float(&record[K_DES]).unwrap())
-        .with_kdet(maybe_float(&record[K_DET]).unwrap())
-        .with_y_lower(maybe_float(&record[Y_LOWER]).unwrap())
-        .with_yp(maybe_float(&record[YP]).unwrap())
-        .with_bf_2tf(maybe_float(&record[BF_2TF]).unwrap())
-        .with_d_t(maybe_float(&record[D_T]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_cw(maybe_float(&record[CW]).unwrap())
-        .with_ro(maybe_float(&record[RO]).unwrap())
-        .with_h_upper(maybe_float(&record[H_UPPER]).unwrap())
-        .with_pa(maybe_float(&record[PA]).unwrap())
-        .with_pb(maybe_float(&record[PB]).unwrap())
-        .with_pc(maybe_float(&record[PC]).unwrap())
-        .with_pd(maybe_float(&record[PD]).unwrap())
-        .with_wgi(maybe_float(&record[WGI]).unwrap());
-
-    if let Some(wgo) = maybe_wgo {
-        return builder.with_wgo(wgo).try_into();
-    }
-    builder.try_into()
-}
-
-fn parse_misc_channel(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::MiscChannel, aisc_shapes::errors::MissingPropertyError> {
-    let maybe_wgi = maybe_float(&record[WGI]);
-
-    let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_d_lower(maybe_float(&record[D_LOWER]).unwrap())
-        .with_ddet(maybe_float(&record[DDET]).unwrap())
-        .with_bf(maybe_float(&record[BF]).unwrap())
-        .with_bfdet(maybe_float(&record[BFDET]).unwrap())
-        .with_tw(maybe_float(&record[TW]).unwrap())
-        .with_twdet(maybe_float(&record[TWDET]).unwrap())
-        .with_twdet_2(maybe_float(&record[TWDET_2]).unwrap())
-        .with_tf(maybe_float(&record[TF]).unwrap())
-        .with_tfdet(maybe_float(&record[TFDET]).unwrap())
-        .with_kdes(maybe_float(&record[K_DES]).unwrap())
-        .with_kdet(maybe_float(&record[K_DET]).unwrap())
-        .with_x_lower(maybe_float(&record[X_LOWER]).unwrap())
-        .with_eo(maybe_float(&record[EO]).unwrap())
-        .with_xp(maybe_float(&record[XP]).unwrap())
-        .with_b_t(maybe_float(&record[B_T]).unwrap())
-        .with_h_tw(maybe_float(&record[H_TW]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_cw(maybe_float(&record[CW]).unwrap())
-        .with_wno(maybe_float(&record[WNO]).unwrap())
-        .with_sw1(maybe_float(&record[SW1]).unwrap())
-        .with_sw2(maybe_float(&record[SW2]).unwrap())
-        .with_sw3(maybe_float(&record[SW3]).unwrap())
-        .with_qf(maybe_float(&record[QF]).unwrap())
-        .with_qw(maybe_float(&record[QW]).unwrap())
-        .with_ro(maybe_float(&record[RO]).unwrap())
-        .with_h_upper(maybe_float(&record[H_UPPER]).unwrap())
-        .with_rts(maybe_float(&record[RTS]).unwrap())
-        .with_ho(maybe_float(&record[HO]).unwrap())
-        .with_pa(maybe_float(&record[PA]).unwrap())
-        .with_pb(maybe_float(&record[PB]).unwrap())
-        .with_pc(maybe_float(&record[PC]).unwrap())
-        .with_pd(maybe_float(&record[PD]).unwrap())
-        .with_t(maybe_float(&record[T]).unwrap());
-
-    if let Some(wgi) = maybe_wgi {
-        return builder.with_wgi(wgi).try_into();
-    }
-    builder.try_into()
-}
-
-fn parse_cee_channel(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::CeeChannel, aisc_shapes::errors::MissingPropertyError> {
-    let maybe_wgi = maybe_float(&record[WGI]);
-    let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_d_lower(maybe_float(&record[D_LOWER]).unwrap())
-        .with_ddet(maybe_float(&record[DDET]).unwrap())
-        .with_bf(maybe_float(&record[BF]).unwrap())
-        .with_bfdet(maybe_float(&record[BFDET]).unwrap())
-        .with_tw(maybe_float(&record[TW]).unwrap())
-        .with_twdet(maybe_float(&record[TWDET]).unwrap())
-        .with_twdet_2(maybe_float(&record[TWDET_2]).unwrap())
-        .with_tf(maybe_float(&record[TF]).unwrap())
-        .with_tfdet(maybe_float(&record[TFDET]).unwrap())
-        .with_kdes(maybe_float(&record[K_DES]).unwrap())
-        .with_kdet(maybe_float(&record[K_DET]).unwrap())
-        .with_x_lower(maybe_float(&record[X_LOWER]).unwrap())
-        .with_eo(maybe_float(&record[EO]).unwrap())
-        .with_xp(maybe_float(&record[XP]).unwrap())
-        .with_b_t(maybe_float(&record[B_T]).unwrap())
-        .with_h_tw(maybe_float(&record[H_TW]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_cw(maybe_float(&record[CW]).unwrap())
-        .with_wno(maybe_float(&record[WNO]).unwrap())
-        .with_sw1(maybe_float(&record[SW1]).unwrap())
-        .with_sw2(maybe_float(&record[SW2]).unwrap())
-        .with_sw3(maybe_float(&record[SW3]).unwrap())
-        .with_qf(maybe_float(&record[QF]).unwrap())
-        .with_qw(maybe_float(&record[QW]).unwrap())
-        .with_ro(maybe_float(&record[RO]).unwrap())
-        .with_h_upper(maybe_float(&record[H_UPPER]).unwrap())
-        .with_rts(maybe_float(&record[RTS]).unwrap())
-        .with_ho(maybe_float(&record[HO]).unwrap())
-        .with_pa(maybe_float(&record[PA]).unwrap())
-        .with_pb(maybe_float(&record[PB]).unwrap())
-        .with_pc(maybe_float(&record[PC]).unwrap())
-        .with_pd(maybe_float(&record[PD]).unwrap())
-        .with_t(maybe_float(&record[T]).unwrap());
-
-    if let Some(wgi) = maybe_wgi {
-        return builder.with_wgi(wgi).try_into();
-    }
-    builder.try_into()
-}
-
-fn parse_h_pile(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::HPile, aisc_shapes::errors::MissingPropertyError> {
-    ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_d_lower(maybe_float(&record[D_LOWER]).unwrap())
-        .with_ddet(maybe_float(&record[DDET]).unwrap())
-        .with_bf(maybe_float(&record[BF]).unwrap())
-        .with_bfdet(maybe_float(&record[BFDET]).unwrap())
-        .with_tw(maybe_float(&record[TW]).unwrap())
-        .with_twdet(maybe_float(&record[TWDET]).unwrap())
-        .with_twdet_2(maybe_float(&record[TWDET_2]).unwrap())
-        .with_tf(maybe_float(&record[TF]).unwrap())
-        .with_tfdet(maybe_float(&record[TFDET]).unwrap())
-        .with_kdes(maybe_float(&record[K_DES]).unwrap())
-        .with_kdet(maybe_float(&record[K_DET]).unwrap())
-        .with_k1(maybe_float(&record[K1]).unwrap())
-        .with_bf_2tf(maybe_float(&record[BF_2TF]).unwrap())
-        .with_h_tw(maybe_float(&record[H_TW]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_cw(maybe_float(&record[CW]).unwrap())
-        .with_wno(maybe_float(&record[WNO]).unwrap())
-        .with_sw1(maybe_float(&record[SW1]).unwrap())
-        .with_qf(maybe_float(&record[QF]).unwrap())
-        .with_qw(maybe_float(&record[QW]).unwrap())
-        .with_rts(maybe_float(&record[RTS]).unwrap())
-        .with_ho(maybe_float(&record[HO]).unwrap())
-        .with_pa(maybe_float(&record[PA]).unwrap())
-        .with_pb(maybe_float(&record[PB]).unwrap())
-        .with_pc(maybe_float(&record[PC]).unwrap())
-        .with_pd(maybe_float(&record[PD]).unwrap())
-        .with_t(maybe_float(&record[T]).unwrap())
-        .with_wgi(maybe_float(&record[WGI]).unwrap())
-        .try_into()
-}
-
-fn parse_structural_beam(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::StructuralBeam, aisc_shapes::errors::MissingPropertyError> {
-    let maybe_wgi = maybe_float(&record[WGI]);
-
-    let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_d_lower(maybe_float(&record[D_LOWER]).unwrap())
-        .with_ddet(maybe_float(&record[DDET]).unwrap())
-        .with_bf(maybe_float(&record[BF]).unwrap())
-        .with_bfdet(maybe_float(&record[BFDET]).unwrap())
-        .with_tw(maybe_float(&record[TW]).unwrap())
-        .with_twdet(maybe_float(&record[TWDET]).unwrap())
-        .with_twdet_2(maybe_float(&record[TWDET_2]).unwrap())
-        .with_tf(maybe_float(&record[TF]).unwrap())
-        .with_tfdet(maybe_float(&record[TFDET]).unwrap())
-        .with_kdes(maybe_float(&record[K_DES]).unwrap())
-        .with_kdet(maybe_float(&record[K_DET]).unwrap())
-        .with_bf_2tf(maybe_float(&record[BF_2TF]).unwrap())
-        .with_h_tw(maybe_float(&record[H_TW]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_cw(maybe_float(&record[CW]).unwrap())
-        .with_wno(maybe_float(&record[WNO]).unwrap())
-        .with_sw1(maybe_float(&record[SW1]).unwrap())
-        .with_qf(maybe_float(&record[QF]).unwrap())
-        .with_qw(maybe_float(&record[QW]).unwrap())
-        .with_rts(maybe_float(&record[RTS]).unwrap())
-        .with_ho(maybe_float(&record[HO]).unwrap())
-        .with_pa(maybe_float(&record[PA]).unwrap())
-        .with_pb(maybe_float(&record[PB]).unwrap())
-        .with_pc(maybe_float(&record[PC]).unwrap())
-        .with_pd(maybe_float(&record[PD]).unwrap())
-        .with_t(maybe_float(&record[T]).unwrap());
-
-    if let Some(wgi) = maybe_wgi {
-        return builder.with_wgi(wgi).try_into();
-    }
-    builder.try_into()
-}
-
-fn parse_misc_beam(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::MiscBeam, aisc_shapes::errors::MissingPropertyError> {
-    let maybe_wgi = maybe_float(&record[WGI]);
-    let maybe_t_f = maybe_bool(&record[T_F]);
-
-    let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_d_lower(maybe_float(&record[D_LOWER]).unwrap())
-        .with_ddet(maybe_float(&record[DDET]).unwrap())
-        .with_bf(maybe_float(&record[BF]).unwrap())
-        .with_bfdet(maybe_float(&record[BFDET]).unwrap())
-        .with_tw(maybe_float(&record[TW]).unwrap())
-        .with_twdet(maybe_float(&record[TWDET]).unwrap())
-        .with_twdet_2(maybe_float(&record[TWDET_2]).unwrap())
-        .with_tf(maybe_float(&record[TF]).unwrap())
-        .with_tfdet(maybe_float(&record[TFDET]).unwrap())
-        .with_kdes(maybe_float(&record[K_DES]).unwrap())
-        .with_kdet(maybe_float(&record[K_DET]).unwrap())
-        .with_k1(maybe_float(&record[K1]).unwrap())
-        .with_bf_2tf(maybe_float(&record[BF_2TF]).unwrap())
-        .with_h_tw(maybe_float(&record[H_TW]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_cw(maybe_float(&record[CW]).unwrap())
-        .with_wno(maybe_float(&record[WNO]).unwrap())
-        .with_sw1(maybe_float(&record[SW1]).unwrap())
-        .with_qf(maybe_float(&record[QF]).unwrap())
-        .with_qw(maybe_float(&record[QW]).unwrap())
-        .with_rts(maybe_float(&record[RTS]).unwrap())
-        .with_ho(maybe_float(&record[HO]).unwrap())
-        .with_pa(maybe_float(&record[PA]).unwrap())
-        .with_pb(maybe_float(&record[PB]).unwrap())
-        .with_pc(maybe_float(&record[PC]).unwrap())
-        .with_pd(maybe_float(&record[PD]).unwrap())
-        .with_t(maybe_float(&record[T]).unwrap());
-
-    match (&maybe_wgi.is_some(), &maybe_t_f.is_some()) {
-        (true, true) => {
-            return builder
-                .with_wgi(maybe_wgi.unwrap())
-                .with_t_f(maybe_t_f.unwrap())
-                .try_into();
-        }
-        (true, false) => {
-            return builder.with_wgi(maybe_wgi.unwrap()).try_into();
-        }
-        (false, true) => {
-            return builder.with_t_f(maybe_t_f.unwrap()).try_into();
-        }
-        (false, false) => {
-            return builder.try_into();
-        }
-    }
-}
-
-fn parse_angles(
-    record: &csv::StringRecord,
-) -> Result<aisc_shapes::Angle, aisc_shapes::errors::MissingPropertyError> {
-    let maybe_h_upper = maybe_float(&record[H_UPPER]);
-    let maybe_swb = maybe_float(&record[SWB]);
-
-    let builder = ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_d_lower(maybe_float(&record[D_LOWER]).unwrap())
-        .with_b_lower(maybe_float(&record[B_LOWER]).unwrap())
-        .with_t_lower(maybe_float(&record[T_LOWER]).unwrap())
-        .with_kdes(maybe_float(&record[K_DES]).unwrap())
-        .with_kdet(maybe_float(&record[K_DET]).unwrap())
-        .with_x_lower(maybe_float(&record[X_LOWER]).unwrap())
-        .with_y_lower(maybe_float(&record[Y_LOWER]).unwrap())
-        .with_xp(maybe_float(&record[XP]).unwrap())
-        .with_yp(maybe_float(&record[YP]).unwrap())
-        .with_b_t(maybe_float(&record[B_T]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_iz(maybe_float(&record[IZ]).unwrap())
-        .with_rz(maybe_float(&record[RZ]).unwrap())
-        .with_sz(maybe_float(&record[SZ]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_cw(maybe_float(&record[CW]).unwrap())
-        .with_ro(maybe_float(&record[RO]).unwrap())
-        .with_tan_a(maybe_float(&record[TAN_A]).unwrap())
-        .with_iw(maybe_float(&record[IW]).unwrap())
-        .with_za(maybe_float(&record[ZA]).unwrap())
-        .with_zb(maybe_float(&record[ZB]).unwrap())
-        .with_zc(maybe_float(&record[ZC]).unwrap())
-        .with_wa(maybe_float(&record[WA]).unwrap())
-        .with_wb(maybe_float(&record[WB]).unwrap())
-        .with_wc(maybe_float(&record[WC]).unwrap())
-        .with_swa(maybe_float(&record[SWA]).unwrap())
-        .with_swc(maybe_float(&record[SWC]).unwrap())
-        .with_sza(maybe_float(&record[SZA]).unwrap())
-        .with_szb(maybe_float(&record[SZB]).unwrap())
-        .with_szc(maybe_float(&record[SZC]).unwrap())
-        .with_pa(maybe_float(&record[PA]).unwrap())
-        .with_pa_2(maybe_float(&record[PA2]).unwrap())
-        .with_pb(maybe_float(&record[PB]).unwrap());
-
-    match (&maybe_h_upper.is_some(), &maybe_swb.is_some()) {
-        (true, true) => builder
-            .with_h_upper(maybe_h_upper.unwrap())
-            .with_swb(maybe_swb.unwrap())
-            .try_into(),
-        (true, false) => builder.with_h_upper(maybe_h_upper.unwrap()).try_into(),
-        (false, true) => builder.with_swb(maybe_swb.unwrap()).try_into(),
-        (false, false) => builder.try_into(),
-    }
-}
-
-fn parse_wide_flange(
-    record: &csv::StringRecord,
-) -> Result<shapes::aisc_shapes::WideFlange, aisc_shapes::errors::MissingPropertyError> {
-    let builder = shape_builder::ShapeBuilder::new()
-        .with_edi_std_nomenclature(String::from(&record[EDI_NOM]))
-        .with_aisc_manual_label(String::from(&record[AISC_MAN_LBL]))
-        .with_t_f(maybe_bool(&record[T_F]).unwrap())
-        .with_w_upper(maybe_float(&record[W_UPPER]).unwrap())
-        .with_a_upper(maybe_float(&record[A_UPPER]).unwrap())
-        .with_d_lower(maybe_float(&record[D_LOWER]).unwrap())
-        .with_ddet(maybe_float(&record[DDET]).unwrap())
-        .with_bf(maybe_float(&record[BF]).unwrap())
-        .with_bfdet(maybe_float(&record[BFDET]).unwrap())
-        .with_tw(maybe_float(&record[TW]).unwrap())
-        .with_twdet(maybe_float(&record[TWDET]).unwrap())
-        .with_twdet_2(maybe_float(&record[TWDET_2]).unwrap())
-        .with_tf(maybe_float(&record[TF]).unwrap())
-        .with_tfdet(maybe_float(&record[TFDET]).unwrap())
-        .with_kdes(maybe_float(&record[K_DES]).unwrap())
-        .with_kdet(maybe_float(&record[K_DET]).unwrap())
-        .with_k1(maybe_float(&record[K1]).unwrap())
-        .with_bf_2tf(maybe_float(&record[BF_2TF]).unwrap())
-        .with_h_tw(maybe_float(&record[H_TW]).unwrap())
-        .with_ix(maybe_float(&record[IX]).unwrap())
-        .with_zx(maybe_float(&record[ZX]).unwrap())
-        .with_sx(maybe_float(&record[SX]).unwrap())
-        .with_rx(maybe_float(&record[RX]).unwrap())
-        .with_iy(maybe_float(&record[IY]).unwrap())
-        .with_zy(maybe_float(&record[ZY]).unwrap())
-        .with_sy(maybe_float(&record[SY]).unwrap())
-        .with_ry(maybe_float(&record[RY]).unwrap())
-        .with_j_upper(maybe_float(&record[J_UPPER]).unwrap())
-        .with_cw(maybe_float(&record[CW]).unwrap())
-        .with_wno(maybe_float(&record[WNO]).unwrap())
-        .with_sw1(maybe_float(&record[SW1]).unwrap())
-        .with_qf(maybe_float(&record[QF]).unwrap())
-        .with_qw(maybe_float(&record[QW]).unwrap())
-        .with_rts(maybe_float(&record[RTS]).unwrap())
-        .with_ho(maybe_float(&record[HO]).unwrap())
-        .with_pa(maybe_float(&record[PA]).unwrap())
-        .with_pb(maybe_float(&record[PB]).unwrap())
-        .with_pc(maybe_float(&record[PC]).unwrap())
-        .with_pd(maybe_float(&record[PD]).unwrap())
-        .with_t(maybe_float(&record[T]).unwrap())
-        .with_wgi(maybe_float(&record[WGI]).unwrap());
-
-    if let Some(num) = maybe_float(&record[WGO]) {
-        return builder.with_wgo(num).try_into();
-    }
-    builder.try_into()
-}
-
-// helper functions
-fn get_csv_reader() -> csv::Reader<File> {
-    let file_result = File::open("aisc-shapes-database-v16.0.csv");
-    let file = file_result.unwrap();
-    csv::Reader::from_reader(file)
-}
-
-fn maybe_bool(data: &str) -> Option<bool> {
-    if data.trim().eq("T") {
-        return Some(true);
-    } else if data.trim().eq("F") {
-        return Some(false);
-    }
-    None
-}
-
-fn maybe_float(data: &str) -> Option<f64> {
-    if *&data.trim().eq("–") {
-        return None;
-    } else {
-        let segments: Vec<&str> = data.trim().split_whitespace().collect();
-        if &segments.len() == &(0 as usize) {
-            panic!("Invalid value for fraction");
-        } else if &segments.len() == &(1 as usize) {
-            if *&segments[0].contains("/") {
-                match maybe_get_float_from_fraction("0.0", segments[0]) {
-                    Some(num) => return Some(num),
-                    None => {
-                        dbg!(&data);
-                        dbg!(&segments);
-                        panic!("Could not parse fraction {}", segments[0])
-                    }
-                }
-            } else {
-                return Some(segments[0].parse::<f64>().unwrap());
-            }
-        } else {
-            // more than one segment
-            match maybe_get_float_from_fraction(segments[0], segments[1]) {
-                Some(num) => return Some(num),
-                None => {
-                    dbg!(&data);
-                    dbg!(&segments);
-                    panic!("Could not parse fraction {}", segments[0])
-                }
-            }
-        }
-    }
-}
-
-fn maybe_get_float_from_fraction(whole_num: &str, fraction: &str) -> Option<f64> {
-    let parsed_num: f64 = whole_num.parse().expect("Failed to parse f64");
-
-    if fraction.trim().eq("1/16") {
-        return Some(parsed_num + 0.0625);
-    }
-    if fraction.trim().eq("1/8") {
-        return Some(parsed_num + 0.125);
-    }
-    if fraction.trim().eq("3/16") {
-        return Some(parsed_num + 0.1875);
-    }
-    if fraction.trim().eq("1/4") {
-        return Some(parsed_num + 0.25);
-    }
-    if fraction.trim().eq("5/16") {
-        return Some(parsed_num + 0.3125);
-    }
-    if fraction.trim().eq("3/8") {
-        return Some(parsed_num + 0.375);
-    }
-    if fraction.trim().eq("7/16") {
-        return Some(parsed_num + 0.4375);
-    }
-    if fraction.trim().eq("1/2") {
-        return Some(parsed_num + 0.5);
-    }
-    if fraction.trim().eq("9/16") {
-        return Some(parsed_num + 0.5625);
-    }
-    if fraction.trim().eq("5/8") {
-        return Some(parsed_num + 0.625);
-    }
-    if fraction.trim().eq("3/4") {
-        return Some(parsed_num + 0.75);
-    }
-    if fraction.trim().eq("7/8") {
-        return Some(parsed_num + 0.875);
-    }
-    if fraction.trim().eq("11/16") {
-        return Some(parsed_num + 0.6875);
-    }
-    if fraction.trim().eq("13/16") {
-        return Some(parsed_num + 0.8125);
-    }
-    if fraction.trim().eq("15/16") {
-        return Some(parsed_num + 0.9375);
-    }
-    return None;
-}
-
-// indices of properties in the CSV file
-static TYPE_INDEX: usize = 0;
-static EDI_NOM: usize = 1;
-static AISC_MAN_LBL: usize = 2;
-static T_F: usize = 3;
-static W_UPPER: usize = 4;
-static A_UPPER: usize = 5;
-static D_LOWER: usize = 6;
-static DDET: usize = 7;
-static HT: usize = 8;
-static H: usize = 9;
-static OD: usize = 10;
-static BF: usize = 11;
-static BFDET: usize = 12;
-static B_UPPER: usize = 13;
-static B_LOWER: usize = 14;
-static ID: usize = 15;
-static TW: usize = 16;
-static TWDET: usize = 17;
-static TWDET_2: usize = 18;
-static TF: usize = 19;
-static TFDET: usize = 20;
-static T_LOWER: usize = 21;
-static T_NOM: usize = 22;
-static T_DES: usize = 23;
-static K_DES: usize = 24;
-static K_DET: usize = 25;
-static K1: usize = 26;
-static X_LOWER: usize = 27;
-static Y_LOWER: usize = 28;
-static EO: usize = 29;
-static XP: usize = 30;
-static YP: usize = 31;
-static BF_2TF: usize = 32;
-static B_T: usize = 33;
-static B_TDES: usize = 34;
-static H_TW: usize = 35;
-static H_TDES: usize = 36;
-static D_T: usize = 37;
-static IX: usize = 38;
-static ZX: usize = 39;
-static SX: usize = 40;
-static RX: usize = 41;
-static IY: usize = 42;
-static ZY: usize = 43;
-static SY: usize = 44;
-static RY: usize = 45;
-static IZ: usize = 46;
-static RZ: usize = 47;
-static SZ: usize = 48;
-static J_UPPER: usize = 49;
-static CW: usize = 50;
-static C_UPPER: usize = 51;
-static WNO: usize = 52;
-static SW1: usize = 53;
-static SW2: usize = 54;
-static SW3: usize = 55;
-static QF: usize = 56;
-static QW: usize = 57;
-static RO: usize = 58;
-static H_UPPER: usize = 59;
-static TAN_A: usize = 60;
-static IW: usize = 61;
-static ZA: usize = 62;
-static ZB: usize = 63;
-static ZC: usize = 64;
-static WA: usize = 65;
-static WB: usize = 66;
-static WC: usize = 67;
-static SWA: usize = 68;
-static SWB: usize = 69;
-static SWC: usize = 70;
-static SZA: usize = 71;
-static SZB: usize = 72;
-static SZC: usize = 73;
-static RTS: usize = 74;
-static HO: usize = 75;
-static PA: usize = 76;
-static PA2: usize = 77;
-static PB: usize = 78;
-static PC: usize = 79;
-static PD: usize = 80;
-static T: usize = 81;
-static WGI: usize = 82;
-static WGO: usize = 83;